@@ -0,0 +1,75 @@
+//! Base64 (RFC 4648) with padding, the encoding of `base64Binary` values
+//! such as `Binary.data` and `Attachment.data`.
+
+const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn encode(bytes: &[u8]) -> String {
+  let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+  for chunk in bytes.chunks(3) {
+    let word = chunk.iter().enumerate().fold(0u32, |word, (i, &byte)| {
+      word | (byte as u32) << (16 - 8 * i)
+    });
+    for i in 0..4 {
+      if i <= chunk.len() {
+        encoded.push(ALPHABET[(word >> (18 - 6 * i) & 63) as usize] as char);
+      } else {
+        encoded.push('=');
+      }
+    }
+  }
+  encoded
+}
+
+/// Decodes padded base64, ignoring whitespace. `None` if a character is
+/// not in the alphabet, or the padding is missing or not at the end.
+pub fn decode(text: &str) -> Option<Vec<u8>> {
+  let text: Vec<u8> = text.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+  if !text.len().is_multiple_of(4) {
+    return None;
+  }
+  let data = text
+    .strip_suffix(b"==")
+    .or_else(|| text.strip_suffix(b"="))
+    .unwrap_or(&text);
+  let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+  let (mut word, mut bits) = (0u32, 0);
+  for c in data {
+    let value = ALPHABET.iter().position(|b| b == c)? as u32;
+    word = word << 6 | value;
+    bits += 6;
+    if bits >= 8 {
+      bits -= 8;
+      bytes.push((word >> bits & 255) as u8);
+    }
+  }
+  Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_base64() {
+    let examples = [
+      ("", ""),
+      ("f", "Zg=="),
+      ("fo", "Zm8="),
+      ("foo", "Zm9v"),
+      ("foob", "Zm9vYg=="),
+      ("fooba", "Zm9vYmE="),
+      ("foobar", "Zm9vYmFy"),
+    ];
+    for (text, encoded) in examples {
+      assert_eq!(encode(text.as_bytes()), encoded);
+      assert_eq!(decode(encoded), Some(text.as_bytes().to_vec()));
+    }
+    assert_eq!(decode("Zm9v\nYmFy"), Some(b"foobar".to_vec()));
+    assert_eq!(encode(&[0xfb, 0xff]), "+/8=");
+    assert_eq!(decode("+/8="), Some(vec![0xfb, 0xff]));
+    assert_eq!(decode("Zg"), None);
+    assert_eq!(decode("Zg=a"), None);
+    assert_eq!(decode("Z==="), None);
+    assert_eq!(decode("Zm9v!A=="), None);
+  }
+}
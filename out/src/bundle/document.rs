@@ -0,0 +1,403 @@
+//! Assembling and checking documents: Bundles of type `document` whose first
+//! entry is a `Composition`.
+//!
+//! `DocumentAssembler` follows every reference of the Composition, and of
+//! each resource it brings in, through a `ReferenceResolver`. Each resource
+//! gets the absolute URL it was referenced by as its fullUrl; resources that
+//! only have a relative reference and no base get a `urn:uuid:`, and the
+//! references to them are rewritten.
+
+use super::new_urn_uuid;
+use super::transaction::rewrite_references;
+use super::validation::{validate_bundle, BundleIssue};
+use crate::model::Bundle::Bundle;
+use crate::model::Composition::Composition;
+use crate::reference::graph::collect_references;
+use crate::reference::{
+  BundleGraph, GraphIssueKind, ReferenceKind, ReferenceResolver, ReferenceTarget,
+};
+use crate::temporal::{DateTime, Precision};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentError {
+  /// References that the resolver could not resolve, as `[path]: [reference]`.
+  Unresolved(Vec<String>),
+  /// The assembled Bundle is not a valid document.
+  Invalid(Vec<BundleIssue>),
+}
+
+impl fmt::Display for DocumentError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      DocumentError::Unresolved(references) => {
+        write!(f, "Unresolved references: {}", references.join("; "))
+      }
+      DocumentError::Invalid(issues) => {
+        let messages: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        write!(f, "Invalid document: {}", messages.join("; "))
+      }
+    }
+  }
+}
+
+impl std::error::Error for DocumentError {}
+
+/// Assembles a document from a Composition and the resources it leads to.
+pub struct DocumentAssembler<'r> {
+  resolver: &'r dyn ReferenceResolver,
+  base: Option<String>,
+  identifier: Option<(String, String)>,
+  timestamp: Option<String>,
+}
+
+/// The base of an absolute `[base]/[type]/[id]` URL.
+fn base_of(full_url: &str) -> Option<String> {
+  let target = ReferenceTarget::parse(full_url)?;
+  match target.kind {
+    ReferenceKind::Absolute => target.base,
+    _ => None,
+  }
+}
+
+impl<'r> DocumentAssembler<'r> {
+  pub fn new(resolver: &'r dyn ReferenceResolver) -> Self {
+    DocumentAssembler {
+      resolver,
+      base: None,
+      identifier: None,
+      timestamp: None,
+    }
+  }
+
+  /// The base URL of the Composition, which relative references resolve
+  /// against.
+  pub fn base(&mut self, base: &str) -> &mut Self {
+    self.base = Some(base.trim_end_matches('/').to_string());
+    self
+  }
+
+  /// The document identifier. Defaults to a new `urn:uuid:`.
+  pub fn identifier(&mut self, system: &str, value: &str) -> &mut Self {
+    self.identifier = Some((system.to_string(), value.to_string()));
+    self
+  }
+
+  /// When the document was assembled. Defaults to now.
+  pub fn timestamp(&mut self, timestamp: &str) -> &mut Self {
+    self.timestamp = Some(timestamp.to_string());
+    self
+  }
+
+  /// The document Bundle, checked with `validate_document`.
+  pub fn assemble(&self, composition: &Composition) -> Result<Bundle<'static>, DocumentError> {
+    let composition = composition.value.clone().into_owned();
+    let mut aliases = HashMap::new();
+    let full_url = match (&self.base, composition["id"].as_str()) {
+      (Some(base), Some(id)) => format!("{}/Composition/{}", base, id),
+      (_, id) => {
+        let urn = new_urn_uuid();
+        if let Some(id) = id {
+          aliases.insert(format!("Composition/{}", id), urn.clone());
+        }
+        urn
+      }
+    };
+    let mut entries: Vec<(String, Value)> = vec![(full_url, composition)];
+    let mut unresolved = vec![];
+    let mut next = 0;
+    while next < entries.len() {
+      let (full_url, resource) = &entries[next];
+      let base = base_of(full_url).or_else(|| self.base.clone());
+      let mut references = vec![];
+      let resource_type = resource["resourceType"].as_str().unwrap_or_default();
+      collect_references(resource, resource_type, &mut references);
+      let mut found = vec![];
+      for (path, reference) in references {
+        if reference.starts_with('#') {
+          continue;
+        }
+        let target = ReferenceTarget::parse(&reference);
+        let resolved = target
+          .as_ref()
+          .and_then(|t| Some((t, self.resolver.resolve(t)?)));
+        let (target, resolved) = match resolved {
+          Some(resolved) => resolved,
+          None => {
+            unresolved.push(format!("{}: {}", path, reference));
+            continue;
+          }
+        };
+        let full_url = match (&target.kind, &base) {
+          (ReferenceKind::Relative, None) => aliases
+            .entry(target.relative().unwrap_or_default())
+            .or_insert_with(new_urn_uuid)
+            .clone(),
+          (_, base) => target
+            .absolute(base.as_deref().unwrap_or_default())
+            .unwrap_or_default(),
+        };
+        found.push((full_url, resolved.to_json()));
+      }
+      for (full_url, resource) in found {
+        if !entries.iter().any(|(url, _)| *url == full_url) {
+          entries.push((full_url, resource));
+        }
+      }
+      next += 1;
+    }
+    if !unresolved.is_empty() {
+      return Err(DocumentError::Unresolved(unresolved));
+    }
+
+    let (system, value) = self
+      .identifier
+      .clone()
+      .unwrap_or_else(|| ("urn:ietf:rfc:3986".to_string(), new_urn_uuid()));
+    let timestamp = self
+      .timestamp
+      .clone()
+      .unwrap_or_else(|| DateTime::now(Precision::Second).to_string());
+    let entries: Vec<Value> = entries
+      .into_iter()
+      .map(|(full_url, mut resource)| {
+        if full_url.starts_with("urn:") {
+          rewrite_references(&mut resource, &aliases);
+        }
+        json!({"fullUrl": full_url, "resource": resource})
+      })
+      .collect();
+    let bundle = Bundle {
+      value: Cow::Owned(json!({
+        "resourceType": "Bundle",
+        "identifier": {"system": system, "value": value},
+        "type": "document",
+        "timestamp": timestamp,
+        "entry": entries,
+      })),
+    };
+    let issues = validate_document(&bundle);
+    if !issues.is_empty() {
+      return Err(DocumentError::Invalid(issues));
+    }
+    Ok(bundle)
+  }
+}
+
+fn has_content(value: &Value) -> bool {
+  match value {
+    Value::Null => false,
+    Value::Array(items) => !items.is_empty(),
+    _ => true,
+  }
+}
+
+/// Checks the Composition section invariants `cmp-1` and `cmp-2`.
+fn check_sections(sections: &Value, path: &str, issues: &mut Vec<BundleIssue>) {
+  for (i, section) in sections.as_array().into_iter().flatten().enumerate() {
+    let path = format!("{}.section[{}]", path, i);
+    let empty = !has_content(&section["entry"]);
+    if !has_content(&section["text"]) && empty && !has_content(&section["section"]) {
+      issues.push(BundleIssue {
+        key: "cmp-1",
+        entry: Some(0),
+        message: format!(
+          "{}: A section must contain at least one of text, entries, or sub-sections",
+          path
+        ),
+      });
+    }
+    if has_content(&section["emptyReason"]) && !empty {
+      issues.push(BundleIssue {
+        key: "cmp-2",
+        entry: Some(0),
+        message: format!(
+          "{}: A section can only have an emptyReason if it is empty",
+          path
+        ),
+      });
+    }
+    check_sections(&section["section"], &path, issues);
+  }
+}
+
+/// Checks a document: the Bundle invariants, the Composition section
+/// invariants, that every entry has a fullUrl, that every reference resolves
+/// within the document and that every entry is reachable from the
+/// Composition.
+pub fn validate_document(bundle: &Bundle) -> Vec<BundleIssue> {
+  let mut issues = validate_bundle(bundle);
+  let value = &bundle.value;
+  if value["type"].as_str() != Some("document") {
+    issues.push(BundleIssue {
+      key: "doc-type",
+      entry: None,
+      message: "A document must be a Bundle of type document".to_string(),
+    });
+    return issues;
+  }
+  let entries = value["entry"]
+    .as_array()
+    .map(|e| e.as_slice())
+    .unwrap_or_default();
+  for (i, entry) in entries.iter().enumerate() {
+    if entry["fullUrl"].as_str().is_none() {
+      issues.push(BundleIssue {
+        key: "doc-full-url",
+        entry: Some(i),
+        message: "Every entry of a document must have a fullUrl".to_string(),
+      });
+    }
+  }
+  if let Some(composition) = entries.first() {
+    check_sections(
+      &composition["resource"]["section"],
+      "Composition",
+      &mut issues,
+    );
+  }
+  for issue in BundleGraph::new(bundle).check() {
+    let key = match issue.kind {
+      GraphIssueKind::Dangling => "doc-reference",
+      GraphIssueKind::Unreferenced => "doc-unreferenced",
+    };
+    let message = match issue.path.as_str() {
+      "" => issue.message,
+      path => format!("{}: {}", path, issue.message),
+    };
+    issues.push(BundleIssue {
+      key,
+      entry: Some(issue.entry),
+      message,
+    });
+  }
+  issues
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::reference::BundleResolver;
+  use std::fs;
+
+  fn discharge_summary() -> Value {
+    let contents =
+      fs::read_to_string("examples-json/document-example-dischargesummary.json").unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_assemble_document() {
+    let value = discharge_summary();
+    let source = Bundle::new(&value);
+    let resolver = BundleResolver::new(&source);
+    let composition = Composition::new(&value["entry"][0]["resource"]);
+    let document = DocumentAssembler::new(&resolver)
+      .base("http://fhir.healthintersections.com.au/open/")
+      .identifier(
+        "urn:ietf:rfc:3986",
+        "urn:uuid:0c3151bd-1cbf-4d64-b04d-cd9187a4c6e0",
+      )
+      .timestamp("2013-05-28T22:12:21Z")
+      .assemble(&composition)
+      .unwrap();
+    let full_urls = |bundle: &Value| -> Vec<String> {
+      let mut urls: Vec<String> = bundle["entry"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["fullUrl"].as_str().unwrap().to_string())
+        .collect();
+      urls.sort();
+      urls
+    };
+    assert_eq!(full_urls(&document.value), full_urls(&value));
+    assert_eq!(
+      document.value["entry"][0]["resource"],
+      value["entry"][0]["resource"]
+    );
+    assert_eq!(document.value["timestamp"], "2013-05-28T22:12:21Z");
+
+    let encounter = json!({"resourceType": "Encounter", "id": "e1", "status": "finished",
+      "class": {"code": "IMP"}, "subject": {"reference": "Patient/d1"}});
+    let mut composition = json!({
+      "resourceType": "Composition",
+      "status": "final",
+      "type": {"text": "Note"},
+      "date": "2020-01-01",
+      "title": "Note",
+      "author": [{"reference": "Practitioner/example"}],
+      "encounter": {"reference": "Encounter/e1"},
+      "section": [{"title": "Missing", "entry": [{"reference": "Observation/unknown"}]}]
+    });
+    let mut store: HashMap<String, Value> = HashMap::new();
+    store.insert("Encounter/e1".to_string(), encounter);
+    store.insert(
+      "Patient/d1".to_string(),
+      json!({"resourceType": "Patient", "id": "d1"}),
+    );
+    store.insert(
+      "Practitioner/example".to_string(),
+      json!({"resourceType": "Practitioner", "id": "example"}),
+    );
+    assert_eq!(
+      DocumentAssembler::new(&store)
+        .assemble(&Composition::new(&composition))
+        .unwrap_err(),
+      DocumentError::Unresolved(vec![
+        "Composition.section[0].entry[0]: Observation/unknown".to_string()
+      ])
+    );
+
+    composition["section"][0] =
+      json!({"title": "Empty", "text": {"status": "empty", "div": "<div/>"}});
+    let document = DocumentAssembler::new(&store)
+      .assemble(&Composition::new(&composition))
+      .unwrap();
+    let entries = document.value["entry"].as_array().unwrap();
+    assert_eq!(entries.len(), 4);
+    assert!(entries
+      .iter()
+      .all(|e| e["fullUrl"].as_str().unwrap().starts_with("urn:uuid:")));
+    assert_eq!(
+      entries[0]["resource"]["encounter"]["reference"],
+      entries[2]["fullUrl"]
+    );
+  }
+
+  #[test]
+  fn test_validate_document() {
+    let mut value = discharge_summary();
+    assert_eq!(validate_document(&Bundle::new(&value)), vec![]);
+
+    let entries = value["entry"].as_array_mut().unwrap();
+    entries.remove(2);
+    entries.push(json!({"fullUrl": "urn:uuid:1", "resource": {"resourceType": "Basic"}}));
+    entries.push(json!({"resource": {"resourceType": "Basic"}}));
+    value["entry"][0]["resource"]["section"][0]["emptyReason"] = json!({"text": "withheld"});
+    value["entry"][0]["resource"]["section"][1]["section"] = json!([{"title": "Empty"}]);
+    let keys: Vec<(&str, Option<usize>)> = validate_document(&Bundle::new(&value))
+      .iter()
+      .map(|i| (i.key, i.entry))
+      .collect();
+    assert_eq!(
+      keys,
+      vec![
+        ("doc-full-url", Some(8)),
+        ("cmp-2", Some(0)),
+        ("cmp-1", Some(0)),
+        ("doc-reference", Some(0)),
+        ("doc-reference", Some(2)),
+        ("doc-reference", Some(3)),
+        ("doc-reference", Some(4)),
+        ("doc-reference", Some(5)),
+        ("doc-reference", Some(6)),
+        ("doc-unreferenced", Some(7)),
+        ("doc-unreferenced", Some(8)),
+      ]
+    );
+  }
+}
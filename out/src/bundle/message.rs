@@ -0,0 +1,475 @@
+//! FHIR messaging: Bundles of type `message` whose first entry is a
+//! `MessageHeader`.
+//!
+//! `MessageBuilder` builds request and response messages.
+//! `MessageDispatcher` routes received messages to handlers registered per
+//! event code and answers each with a response message whose
+//! `MessageHeader.response.identifier` is the id of the request's header.
+
+use super::new_urn_uuid;
+use super::processor::error_outcome;
+use super::transaction::EntryHandle;
+use super::validation::{validate_bundle, BundleIssue};
+use crate::model::Bundle::Bundle;
+use crate::model::MessageHeader::MessageHeader;
+use crate::model::MessageHeader_Response::MessageHeader_ResponseCode;
+use crate::model::OperationOutcome::OperationOutcome;
+use crate::model::ResourceList::ResourceList;
+use crate::reference::{BundleResolver, ReferenceTarget};
+use crate::temporal::{DateTime, Precision};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageError {
+  /// The resource has no `resourceType`.
+  MissingResourceType,
+  /// The message has no `source.endpoint`.
+  MissingSource,
+  /// The Bundle is not a message starting with a MessageHeader.
+  NotAMessage,
+  /// The built Bundle breaks the Bundle invariants.
+  Invalid(Vec<BundleIssue>),
+}
+
+impl fmt::Display for MessageError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      MessageError::MissingResourceType => write!(f, "The resource has no resourceType"),
+      MessageError::MissingSource => write!(f, "The message has no source endpoint"),
+      MessageError::NotAMessage => write!(
+        f,
+        "The Bundle is not a message with a MessageHeader as its first entry"
+      ),
+      MessageError::Invalid(issues) => {
+        let messages: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        write!(f, "Invalid message: {}", messages.join("; "))
+      }
+    }
+  }
+}
+
+impl std::error::Error for MessageError {}
+
+fn new_id() -> String {
+  new_urn_uuid().trim_start_matches("urn:uuid:").to_string()
+}
+
+/// Builds a message Bundle.
+#[derive(Debug, Clone)]
+pub struct MessageBuilder {
+  header: Value,
+  base: Option<String>,
+  entries: Vec<Value>,
+}
+
+impl MessageBuilder {
+  fn with_event(element: &str, event: Value) -> Self {
+    let mut header = json!({"resourceType": "MessageHeader", "id": new_id()});
+    header[element] = event;
+    MessageBuilder {
+      header,
+      base: None,
+      entries: vec![],
+    }
+  }
+
+  /// A message for the event `code` of the code system `system`, e.g.
+  /// `http://example.org/fhir/message-events` and `admin-notify`.
+  pub fn new(system: &str, code: &str) -> Self {
+    Self::with_event("eventCoding", json!({"system": system, "code": code}))
+  }
+
+  /// A message for an event defined by a URI.
+  pub fn event_uri(uri: &str) -> Self {
+    Self::with_event("eventUri", json!(uri))
+  }
+
+  /// The sender's base URL. Resources with an id then get
+  /// `[base]/[type]/[id]` as their fullUrl instead of a `urn:uuid:`.
+  pub fn base(&mut self, base: &str) -> &mut Self {
+    self.base = Some(base.trim_end_matches('/').to_string());
+    self
+  }
+
+  pub fn source(&mut self, endpoint: &str, name: Option<&str>) -> &mut Self {
+    let mut source = json!({"endpoint": endpoint});
+    if let Some(name) = name {
+      source["name"] = json!(name);
+    }
+    self.header["source"] = source;
+    self
+  }
+
+  /// Adds a destination; a message may have several.
+  pub fn destination(&mut self, endpoint: &str, name: Option<&str>) -> &mut Self {
+    let mut destination = json!({"endpoint": endpoint});
+    if let Some(name) = name {
+      destination["name"] = json!(name);
+    }
+    match self.header["destination"].as_array_mut() {
+      Some(destinations) => destinations.push(destination),
+      None => self.header["destination"] = json!([destination]),
+    }
+    self
+  }
+
+  fn add(&mut self, resource: &ResourceList) -> Result<EntryHandle, MessageError> {
+    let resource = resource.to_json();
+    let resource_type = resource["resourceType"]
+      .as_str()
+      .ok_or(MessageError::MissingResourceType)?
+      .to_string();
+    let full_url = match (&self.base, resource["id"].as_str()) {
+      (Some(base), Some(id)) => format!("{}/{}/{}", base, resource_type, id),
+      _ => new_urn_uuid(),
+    };
+    self
+      .entries
+      .push(json!({"fullUrl": full_url, "resource": resource}));
+    Ok(EntryHandle::new(&full_url, &resource_type))
+  }
+
+  /// Adds a resource the message is about, and references it from
+  /// `MessageHeader.focus`.
+  pub fn focus(&mut self, resource: &ResourceList) -> Result<EntryHandle, MessageError> {
+    let handle = self.add(resource)?;
+    let reference = handle.reference().to_json();
+    match self.header["focus"].as_array_mut() {
+      Some(focus) => focus.push(reference),
+      None => self.header["focus"] = json!([reference]),
+    }
+    Ok(handle)
+  }
+
+  /// Adds a resource that the focus resources refer to.
+  pub fn attach(&mut self, resource: &ResourceList) -> Result<EntryHandle, MessageError> {
+    self.add(resource)
+  }
+
+  /// Makes this a response to the message whose header has `request_id`.
+  /// `details` is attached and referenced from `response.details`.
+  pub fn response_to(
+    &mut self,
+    request_id: &str,
+    code: MessageHeader_ResponseCode,
+    details: Option<&OperationOutcome>,
+  ) -> Result<&mut Self, MessageError> {
+    let mut response = json!({"identifier": request_id, "code": code.to_string()});
+    if let Some(details) = details {
+      let handle = self.add(&ResourceList::new(&details.to_json()))?;
+      response["details"] = handle.reference().to_json();
+    }
+    self.header["response"] = response;
+    Ok(self)
+  }
+
+  /// The message, checked against the Bundle invariants.
+  pub fn build(&self) -> Result<Bundle<'static>, MessageError> {
+    if self.header["source"]["endpoint"].as_str().is_none() {
+      return Err(MessageError::MissingSource);
+    }
+    let header_url = format!(
+      "urn:uuid:{}",
+      self.header["id"].as_str().unwrap_or_default()
+    );
+    let mut entries = vec![json!({"fullUrl": header_url, "resource": self.header})];
+    entries.extend(self.entries.iter().cloned());
+    let bundle = Bundle {
+      value: Cow::Owned(json!({
+        "resourceType": "Bundle",
+        "id": new_id(),
+        "type": "message",
+        "timestamp": DateTime::now(Precision::Second).to_string(),
+        "entry": entries,
+      })),
+    };
+    let issues = validate_bundle(&bundle);
+    if !issues.is_empty() {
+      return Err(MessageError::Invalid(issues));
+    }
+    Ok(bundle)
+  }
+}
+
+/// A received message Bundle.
+pub struct ReceivedMessage<'a> {
+  bundle: &'a Bundle<'a>,
+}
+
+impl<'a> ReceivedMessage<'a> {
+  pub fn new(bundle: &'a Bundle<'a>) -> Result<Self, MessageError> {
+    let value = &bundle.value;
+    if value["type"].as_str() != Some("message")
+      || value["entry"][0]["resource"]["resourceType"].as_str() != Some("MessageHeader")
+    {
+      return Err(MessageError::NotAMessage);
+    }
+    Ok(ReceivedMessage { bundle })
+  }
+
+  pub fn bundle(&self) -> &Bundle<'a> {
+    self.bundle
+  }
+
+  pub fn header(&self) -> MessageHeader<'_> {
+    MessageHeader::new(&self.bundle.value["entry"][0]["resource"])
+  }
+
+  /// The event: `eventCoding.code`, or else `eventUri`.
+  pub fn event(&self) -> Option<&str> {
+    let header = &self.bundle.value["entry"][0]["resource"];
+    header["eventCoding"]["code"]
+      .as_str()
+      .or_else(|| header["eventUri"].as_str())
+  }
+
+  /// The focus resources that are in the message.
+  pub fn focus(&self) -> Vec<ResourceList<'_>> {
+    let value = &self.bundle.value;
+    let header_url = value["entry"][0]["fullUrl"].as_str().unwrap_or_default();
+    let resolver = BundleResolver::new(self.bundle).for_entry(header_url);
+    self
+      .header()
+      .focus()
+      .unwrap_or_default()
+      .iter()
+      .filter_map(|focus| resolver.resolve_entry(&ReferenceTarget::parse(focus.reference()?)?))
+      .map(|i| ResourceList::new(&value["entry"][i]["resource"]))
+      .collect()
+  }
+}
+
+/// Why a handler could not process a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerError {
+  /// Whether sending the message again may succeed.
+  pub transient: bool,
+  pub message: String,
+}
+
+impl HandlerError {
+  pub fn transient(message: &str) -> HandlerError {
+    HandlerError {
+      transient: true,
+      message: message.to_string(),
+    }
+  }
+
+  pub fn fatal(message: &str) -> HandlerError {
+    HandlerError {
+      transient: false,
+      message: message.to_string(),
+    }
+  }
+}
+
+impl fmt::Display for HandlerError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+
+impl std::error::Error for HandlerError {}
+
+/// Handles a message and returns the resources to send back as the focus of
+/// the response.
+pub type MessageHandler<'h> =
+  Box<dyn FnMut(&ReceivedMessage) -> Result<Vec<ResourceList<'static>>, HandlerError> + 'h>;
+
+/// Routes messages to handlers by event code.
+pub struct MessageDispatcher<'h> {
+  endpoint: String,
+  handlers: HashMap<String, MessageHandler<'h>>,
+}
+
+impl<'h> MessageDispatcher<'h> {
+  /// A dispatcher whose responses have `endpoint` as their source.
+  pub fn new(endpoint: &str) -> Self {
+    MessageDispatcher {
+      endpoint: endpoint.to_string(),
+      handlers: HashMap::new(),
+    }
+  }
+
+  /// Registers the handler for an event code or event URI.
+  pub fn on<F>(&mut self, event: &str, handler: F) -> &mut Self
+  where
+    F: FnMut(&ReceivedMessage) -> Result<Vec<ResourceList<'static>>, HandlerError> + 'h,
+  {
+    self.handlers.insert(event.to_string(), Box::new(handler));
+    self
+  }
+
+  /// Handles a message and returns the response message. Events without a
+  /// handler get a `fatal-error` response.
+  pub fn dispatch(&mut self, bundle: &Bundle) -> Result<Bundle<'static>, MessageError> {
+    let message = ReceivedMessage::new(bundle)?;
+    let event = message.event().unwrap_or_default().to_string();
+    let result = match self.handlers.get_mut(&event) {
+      Some(handler) => handler(&message),
+      None => Err(HandlerError::fatal(&format!(
+        "No handler for event {}",
+        event
+      ))),
+    };
+
+    let header = &bundle.value["entry"][0]["resource"];
+    let mut response = match (header.get("eventCoding"), header.get("eventUri")) {
+      (Some(coding), _) => MessageBuilder::with_event("eventCoding", coding.clone()),
+      (None, Some(uri)) => MessageBuilder::with_event("eventUri", uri.clone()),
+      (None, None) => MessageBuilder::event_uri(""),
+    };
+    response.source(&self.endpoint, None);
+    if let Some(endpoint) = header["source"]["endpoint"].as_str() {
+      response.destination(endpoint, header["source"]["name"].as_str());
+    }
+    let request_id = header["id"]
+      .as_str()
+      .or_else(|| bundle.value["id"].as_str())
+      .unwrap_or_default();
+    match result {
+      Ok(resources) => {
+        response.response_to(request_id, MessageHeader_ResponseCode::Ok, None)?;
+        for resource in &resources {
+          response.focus(resource)?;
+        }
+      }
+      Err(error) => {
+        // The outcome is the one a transaction entry would carry for the
+        // equivalent HTTP status.
+        let (code, status) = match (error.transient, self.handlers.contains_key(&event)) {
+          (true, _) => (MessageHeader_ResponseCode::TransientError, 503),
+          (false, true) => (MessageHeader_ResponseCode::FatalError, 500),
+          (false, false) => (MessageHeader_ResponseCode::FatalError, 501),
+        };
+        let outcome = error_outcome(status, &error.message);
+        response.response_to(request_id, code, Some(&outcome))?;
+      }
+    }
+    response.build()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::fs;
+
+  #[test]
+  fn test_build_message() {
+    let patient = json!({"resourceType": "Patient", "id": "example"});
+    let organization = json!({"resourceType": "Organization", "id": "1"});
+    let mut builder = MessageBuilder::new("http://example.org/fhir/message-events", "admin-notify");
+    builder
+      .base("http://acme.com/ehr/fhir/")
+      .source(
+        "llp:10.11.12.13:5432",
+        Some("Acme Central Patient Registry"),
+      )
+      .destination("llp:10.11.12.14:5432", Some("Acme Message Gateway"));
+    assert_eq!(
+      builder.build().unwrap().value["entry"]
+        .as_array()
+        .unwrap()
+        .len(),
+      1
+    );
+    let focus = builder.focus(&ResourceList::new(&patient)).unwrap();
+    builder.attach(&ResourceList::new(&organization)).unwrap();
+    let message = builder.build().unwrap();
+    let entries = message.value["entry"].as_array().unwrap();
+    assert_eq!(entries.len(), 3);
+    let header = MessageHeader::new(&entries[0]["resource"]);
+    assert_eq!(header.event_coding().unwrap().code(), Some("admin-notify"));
+    assert_eq!(header.source().endpoint(), Some("llp:10.11.12.13:5432"));
+    assert_eq!(
+      header.destination().unwrap()[0].name(),
+      Some("Acme Message Gateway")
+    );
+    assert_eq!(
+      header.focus().unwrap()[0].reference(),
+      Some("http://acme.com/ehr/fhir/Patient/example")
+    );
+    assert_eq!(entries[1]["fullUrl"], focus.full_url());
+
+    let received = ReceivedMessage::new(&message).unwrap();
+    assert_eq!(received.event(), Some("admin-notify"));
+    assert_eq!(received.focus().len(), 1);
+    assert_eq!(
+      MessageBuilder::event_uri("http://example.org/event")
+        .build()
+        .unwrap_err(),
+      MessageError::MissingSource
+    );
+  }
+
+  #[test]
+  fn test_dispatch_message() {
+    let contents = fs::read_to_string("examples-json/message-request-link.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let request = Bundle::new(&value);
+    let mut linked = 0;
+    let mut dispatcher = MessageDispatcher::new("http://example.org/fhir");
+    dispatcher.on("patient-link", |message| {
+      let focus = message.focus();
+      linked += focus.len();
+      Ok(
+        focus
+          .iter()
+          .map(|r| ResourceList {
+            value: Cow::Owned(r.to_json()),
+          })
+          .collect(),
+      )
+    });
+    dispatcher.on("patient-unlink", |_| {
+      Err(HandlerError::transient("Try again later"))
+    });
+
+    let response = dispatcher.dispatch(&request).unwrap();
+    let header = &response.value["entry"][0]["resource"];
+    assert_eq!(
+      header["response"]["identifier"],
+      "267b18ce-3d37-4581-9baa-6fada338038b"
+    );
+    assert_eq!(header["response"]["code"], "ok");
+    assert_eq!(
+      header["destination"][0]["endpoint"],
+      "http://example.org/clients/ehr-lite"
+    );
+    assert_eq!(header["eventCoding"]["code"], "patient-link");
+    assert_eq!(header["focus"].as_array().unwrap().len(), 2);
+
+    let mut other = value.clone();
+    other["entry"][0]["resource"]["eventCoding"]["code"] = json!("patient-unlink");
+    let response = dispatcher.dispatch(&Bundle::new(&other)).unwrap();
+    let header = &response.value["entry"][0]["resource"];
+    assert_eq!(header["response"]["code"], "transient-error");
+    let details = header["response"]["details"]["reference"].clone();
+    assert_eq!(response.value["entry"][1]["fullUrl"], details);
+    assert_eq!(
+      response.value["entry"][1]["resource"]["issue"][0]["code"],
+      "transient"
+    );
+
+    other["entry"][0]["resource"]["eventCoding"]["code"] = json!("admin-notify");
+    let response = dispatcher.dispatch(&Bundle::new(&other)).unwrap();
+    let header = &response.value["entry"][0]["resource"];
+    assert_eq!(header["response"]["code"], "fatal-error");
+    assert_eq!(
+      response.value["entry"][1]["resource"]["issue"][0]["code"],
+      "not-supported"
+    );
+
+    let transaction = json!({"resourceType": "Bundle", "type": "transaction"});
+    assert_eq!(
+      dispatcher.dispatch(&Bundle::new(&transaction)).unwrap_err(),
+      MessageError::NotAMessage
+    );
+    drop(dispatcher);
+    assert_eq!(linked, 2);
+  }
+}
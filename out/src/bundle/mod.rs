@@ -0,0 +1,54 @@
+//! Building, checking and processing Bundles.
+
+pub mod document;
+pub mod message;
+pub mod processor;
+pub mod transaction;
+pub mod validation;
+
+pub use document::{validate_document, DocumentAssembler, DocumentError};
+pub use message::{
+  HandlerError, MessageBuilder, MessageDispatcher, MessageError, MessageHandler, ReceivedMessage,
+};
+pub use processor::{error_outcome, status_line, BundleProcessor, ProcessError};
+pub use transaction::{EntryHandle, TransactionBuilder, TransactionError};
+pub use validation::{validate_bundle, BundleIssue};
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static UUID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn random_u64(salt: u64) -> u64 {
+  let mut hasher = RandomState::new().build_hasher();
+  let nanos = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_nanos() as u64)
+    .unwrap_or(0);
+  hasher.write_u64(nanos);
+  hasher.write_u64(UUID_COUNTER.fetch_add(1, Ordering::Relaxed));
+  hasher.write_u64(salt);
+  hasher.finish()
+}
+
+/// A new random (version 4) UUID as a `urn:uuid:` URI, for the `fullUrl` of
+/// an entry that has no id yet.
+pub fn new_urn_uuid() -> String {
+  let high = random_u64(0);
+  let low = random_u64(high);
+  let mut bytes: [u8; 16] = [0; 16];
+  bytes.copy_from_slice(&[high.to_be_bytes(), low.to_be_bytes()].concat());
+  bytes[6] = (bytes[6] & 0x0f) | 0x40;
+  bytes[8] = (bytes[8] & 0x3f) | 0x80;
+  let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+  format!(
+    "urn:uuid:{}-{}-{}-{}-{}",
+    &hex[0..8],
+    &hex[8..12],
+    &hex[12..16],
+    &hex[16..20],
+    &hex[20..32]
+  )
+}
@@ -0,0 +1,955 @@
+//! Processing transaction and batch Bundles against a `ResourceStore`.
+//!
+//! Entries are processed in the order the specification gives (DELETE, then
+//! POST, then PUT and PATCH, then GET and HEAD) but the response keeps the
+//! order of the request. Before anything is created or updated, new
+//! resources get their ids and every `fullUrl` (`urn:uuid:` or absolute) is
+//! rewritten to `[type]/[id]` in the resources and conditional URLs. A
+//! transaction succeeds or fails as a whole; each entry of a batch has its
+//! own outcome. The resource of a PATCH entry is the patch: a `Binary`
+//! holding a JSON Patch, or a FHIRPath Patch `Parameters`.
+
+use super::new_urn_uuid;
+use super::transaction::rewrite_references;
+use super::validation::{validate_bundle, BundleIssue};
+use crate::model::Bundle::Bundle;
+use crate::model::OperationOutcome::OperationOutcome;
+use crate::patch::{self, PatchError};
+use crate::rest::below_base;
+use crate::store::criteria::{parse_query, percent_decode};
+use crate::store::{etag_version, Change, ResourceStore, StoreError};
+use crate::temporal::DateTime;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProcessError {
+  /// The Bundle is neither a transaction nor a batch.
+  NotABatch(String),
+  /// The Bundle breaks the Bundle invariants.
+  Invalid(Vec<BundleIssue>),
+  /// An entry of a transaction failed, so nothing was written.
+  Failed {
+    entry: usize,
+    status: u16,
+    message: String,
+  },
+  /// The store could not commit the writes, so nothing was written.
+  Store(StoreError),
+}
+
+impl ProcessError {
+  /// The HTTP status code for the error.
+  pub fn status(&self) -> u16 {
+    match self {
+      ProcessError::NotABatch(_) | ProcessError::Invalid(_) => 400,
+      ProcessError::Failed { status, .. } => *status,
+      ProcessError::Store(error) => error.status(),
+    }
+  }
+
+  pub fn outcome(&self) -> OperationOutcome<'static> {
+    error_outcome(self.status(), &self.to_string())
+  }
+}
+
+impl fmt::Display for ProcessError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ProcessError::NotABatch(bundle_type) => {
+        write!(f, "Cannot process a Bundle of type {}", bundle_type)
+      }
+      ProcessError::Invalid(issues) => {
+        let messages: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        write!(f, "Invalid Bundle: {}", messages.join("; "))
+      }
+      ProcessError::Failed {
+        entry,
+        status,
+        message,
+      } => write!(f, "entry[{}] failed ({}): {}", entry, status, message),
+      ProcessError::Store(error) => write!(f, "The writes were not committed: {}", error),
+    }
+  }
+}
+
+impl std::error::Error for ProcessError {}
+
+/// The status line for an HTTP status code, e.g. `201 Created`.
+pub fn status_line(status: u16) -> String {
+  let reason = match status {
+    200 => "OK",
+    201 => "Created",
+    204 => "No Content",
+    304 => "Not Modified",
+    400 => "Bad Request",
+    404 => "Not Found",
+    405 => "Method Not Allowed",
+    406 => "Not Acceptable",
+    409 => "Conflict",
+    410 => "Gone",
+    412 => "Precondition Failed",
+    415 => "Unsupported Media Type",
+    422 => "Unprocessable Entity",
+    501 => "Not Implemented",
+    503 => "Service Unavailable",
+    _ => "Internal Server Error",
+  };
+  format!("{} {}", status, reason)
+}
+
+/// An OperationOutcome with one error issue, its code chosen from the HTTP
+/// status.
+pub fn error_outcome(status: u16, message: &str) -> OperationOutcome<'static> {
+  let code = match status {
+    400 | 422 => "invalid",
+    404 => "not-found",
+    405 | 406 | 415 | 501 => "not-supported",
+    409 | 412 => "conflict",
+    410 => "deleted",
+    503 => "transient",
+    _ => "exception",
+  };
+  OperationOutcome {
+    value: Cow::Owned(json!({
+      "resourceType": "OperationOutcome",
+      "issue": [{
+        "severity": "error",
+        "code": code,
+        "details": {"text": message}
+      }]
+    })),
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Failure {
+  status: u16,
+  message: String,
+}
+
+impl Failure {
+  fn new(status: u16, message: &str) -> Failure {
+    Failure {
+      status,
+      message: message.to_string(),
+    }
+  }
+
+  fn entry(&self) -> Value {
+    json!({
+      "response": {
+        "status": status_line(self.status),
+        "outcome": error_outcome(self.status, &self.message).to_json()
+      }
+    })
+  }
+}
+
+impl From<StoreError> for Failure {
+  fn from(error: StoreError) -> Failure {
+    Failure {
+      status: error.status(),
+      message: error.to_string(),
+    }
+  }
+}
+
+impl From<PatchError> for Failure {
+  fn from(error: PatchError) -> Failure {
+    Failure {
+      status: error.status(),
+      message: error.to_string(),
+    }
+  }
+}
+
+/// The request of one entry, with the URL made local.
+struct Request {
+  method: String,
+  path: String,
+  query: Option<String>,
+  full_url: Option<String>,
+  resource: Option<Value>,
+  if_match: Option<String>,
+  if_none_match: Option<String>,
+  if_none_exist: Option<String>,
+  if_modified_since: Option<String>,
+}
+
+impl Request {
+  fn segments(&self) -> Vec<&str> {
+    self.path.split('/').collect()
+  }
+
+  fn resource_type(&self) -> &str {
+    self.path.split('/').next().unwrap_or_default()
+  }
+
+  fn params(&self) -> Vec<(String, String)> {
+    parse_query(self.query.as_deref().unwrap_or_default())
+  }
+}
+
+/// Where a POST or PUT writes to: an existing resource matched by
+/// `ifNoneExist`, or an id.
+enum Target {
+  Existing(Value),
+  Id(String),
+}
+
+fn rank(method: &str) -> usize {
+  match method {
+    "DELETE" => 0,
+    "POST" => 1,
+    "PUT" | "PATCH" => 2,
+    _ => 3,
+  }
+}
+
+fn new_id() -> String {
+  new_urn_uuid().trim_start_matches("urn:uuid:").to_string()
+}
+
+/// The first `urn:` reference below `value`, which no entry resolves.
+fn urn_reference(value: &Value) -> Option<&str> {
+  match value {
+    Value::Object(map) => {
+      if let Some(Value::String(reference)) = map.get("reference") {
+        if reference.starts_with("urn:") {
+          return Some(reference);
+        }
+      }
+      map.values().find_map(urn_reference)
+    }
+    Value::Array(items) => items.iter().find_map(urn_reference),
+    _ => None,
+  }
+}
+
+/// `query` with each parameter value that is exactly the fullUrl of an
+/// entry replaced by the entry's reference. A value of several
+/// comma-separated alternatives is matched alternative by alternative.
+fn resolve_aliases(query: &str, aliases: &HashMap<String, String>) -> String {
+  query
+    .split('&')
+    .map(|pair| match pair.split_once('=') {
+      Some((name, value)) => {
+        let values: Vec<String> = value
+          .split(',')
+          .map(|value| match aliases.get(&percent_decode(value)) {
+            Some(reference) => reference.clone(),
+            None => value.to_string(),
+          })
+          .collect();
+        format!("{}={}", name, values.join(","))
+      }
+      None => pair.to_string(),
+    })
+    .collect::<Vec<String>>()
+    .join("&")
+}
+
+fn record(
+  results: &mut [Option<Result<Value, Failure>>],
+  transaction: bool,
+  entry: usize,
+  result: Result<Value, Failure>,
+) -> Result<(), ProcessError> {
+  if let (true, Err(failure)) = (transaction, &result) {
+    return Err(ProcessError::Failed {
+      entry,
+      status: failure.status,
+      message: failure.message.clone(),
+    });
+  }
+  results[entry] = Some(result);
+  Ok(())
+}
+
+/// Processes transaction and batch Bundles against a store.
+pub struct BundleProcessor<'s, S: ResourceStore> {
+  store: &'s mut S,
+  base: Option<String>,
+}
+
+impl<'s, S: ResourceStore> BundleProcessor<'s, S> {
+  pub fn new(store: &'s mut S) -> Self {
+    BundleProcessor { store, base: None }
+  }
+
+  /// The base URL of the store: absolute request URLs must start with it,
+  /// and response entries get fullUrls below it.
+  pub fn base(&mut self, base: &str) -> &mut Self {
+    self.base = Some(base.trim_end_matches('/').to_string());
+    self
+  }
+
+  fn local_url<'u>(&self, url: &'u str) -> Result<&'u str, Failure> {
+    match self.base.as_deref().and_then(|base| below_base(url, base)) {
+      Some(rest) => Ok(rest.trim_matches('/')),
+      None if url.contains("://") => {
+        Err(Failure::new(400, &format!("{} is not on this server", url)))
+      }
+      None => Ok(url.trim_matches('/')),
+    }
+  }
+
+  fn parse(&self, entry: &Value) -> Result<Request, Failure> {
+    let request = &entry["request"];
+    let text = |name: &str| request[name].as_str().map(|s| s.to_string());
+    let method = text("method").unwrap_or_default();
+    let url = self.local_url(request["url"].as_str().unwrap_or_default())?;
+    let mut parts = url.splitn(2, '?');
+    let path = parts.next().unwrap_or_default().to_string();
+    let query = parts.next().map(|q| q.to_string());
+    if path.split('/').any(|segment| segment.starts_with('$')) {
+      return Err(Failure::new(501, "Operations are not supported"));
+    }
+    if !matches!(
+      method.as_str(),
+      "GET" | "HEAD" | "POST" | "PUT" | "PATCH" | "DELETE"
+    ) {
+      return Err(Failure::new(405, &format!("Unknown method {}", method)));
+    }
+    // The resource of a PATCH is the patch: a Binary or a Parameters.
+    if let Some(resource) = entry.get("resource").filter(|_| method != "PATCH") {
+      if resource["resourceType"].as_str() != path.split('/').next() {
+        return Err(Failure::new(
+          400,
+          "The resourceType does not match the request URL",
+        ));
+      }
+    }
+    Ok(Request {
+      method,
+      path,
+      query,
+      full_url: entry["fullUrl"].as_str().map(|s| s.to_string()),
+      resource: entry.get("resource").cloned(),
+      if_match: text("ifMatch"),
+      if_none_match: text("ifNoneMatch"),
+      if_none_exist: text("ifNoneExist"),
+      if_modified_since: text("ifModifiedSince"),
+    })
+  }
+
+  /// A response entry for a resource, with its location and ETag.
+  fn response(&self, status: u16, resource: Option<Value>) -> Value {
+    let mut entry = json!({"response": {"status": status_line(status)}});
+    if let Some(resource) = resource {
+      let resource_type = resource["resourceType"].as_str().unwrap_or_default();
+      if let Some(id) = resource["id"].as_str() {
+        if let Some(base) = &self.base {
+          entry["fullUrl"] = json!(format!("{}/{}/{}", base, resource_type, id));
+        }
+        let meta = &resource["meta"];
+        if let Some(version) = meta["versionId"].as_str() {
+          let response = &mut entry["response"];
+          response["location"] = json!(format!("{}/{}/_history/{}", resource_type, id, version));
+          response["etag"] = json!(format!("W/\"{}\"", version));
+          if let Some(last_updated) = meta["lastUpdated"].as_str() {
+            response["lastModified"] = json!(last_updated);
+          }
+        }
+      }
+      entry["resource"] = resource;
+    }
+    entry
+  }
+
+  fn full_url(&self, resource_type: &str, id: &str) -> Option<String> {
+    self
+      .base
+      .as_ref()
+      .map(|base| format!("{}/{}/{}", base, resource_type, id))
+  }
+
+  fn bundle(&self, bundle_type: &str, entries: Vec<Value>) -> Value {
+    json!({
+      "resourceType": "Bundle",
+      "type": bundle_type,
+      "total": entries.len(),
+      "entry": entries,
+    })
+  }
+
+  fn delete(&mut self, request: &Request) -> Result<Value, Failure> {
+    let resource_type = request.resource_type();
+    let ids: Vec<String> = match request.segments().as_slice() {
+      [_, id] => vec![id.to_string()],
+      [_] if request.query.is_some() => self
+        .store
+        .search(resource_type, &request.params())?
+        .iter()
+        .filter_map(|r| r["id"].as_str().map(|id| id.to_string()))
+        .collect(),
+      _ => {
+        return Err(Failure::new(
+          400,
+          "DELETE needs [type]/[id] or [type]?[criteria]",
+        ))
+      }
+    };
+    if ids.len() > 1 {
+      return Err(Failure::new(
+        412,
+        "The criteria match more than one resource",
+      ));
+    }
+    for id in &ids {
+      if let Some(expected) = &request.if_match {
+        let current = self.store.read(resource_type, id)?;
+        if current["meta"]["versionId"].as_str() != Some(etag_version(expected)) {
+          return Err(
+            StoreError::VersionConflict {
+              expected: etag_version(expected).to_string(),
+              actual: current["meta"]["versionId"].as_str().map(|v| v.to_string()),
+            }
+            .into(),
+          );
+        }
+      }
+      self.store.delete(resource_type, id)?;
+    }
+    Ok(self.response(204, None))
+  }
+
+  /// Decides where a POST or PUT writes to, before references are rewritten.
+  fn target(&self, request: &Request) -> Result<Target, Failure> {
+    let resource_type = request.resource_type();
+    let resource_id = request
+      .resource
+      .as_ref()
+      .and_then(|r| r["id"].as_str())
+      .map(|id| id.to_string());
+    let criteria = match request.method.as_str() {
+      "POST" => request.if_none_exist.clone(),
+      _ => request.query.clone(),
+    };
+    let segments = request.segments();
+    if request.method == "PUT" && criteria.is_none() {
+      return match segments.as_slice() {
+        [_, id] if resource_id.as_deref().is_none_or(|r| r == *id) => {
+          Ok(Target::Id(id.to_string()))
+        }
+        [_, _] => Err(Failure::new(
+          400,
+          "The resource id does not match the request URL",
+        )),
+        _ => Err(Failure::new(
+          400,
+          "PUT needs [type]/[id] or [type]?[criteria]",
+        )),
+      };
+    }
+    if segments.len() != 1 {
+      return Err(Failure::new(400, "POST needs [type]"));
+    }
+    let matches = match criteria {
+      Some(criteria) => self.store.search(resource_type, &parse_query(&criteria))?,
+      None => vec![],
+    };
+    match (request.method.as_str(), matches.as_slice()) {
+      (_, [_, _, ..]) => Err(Failure::new(
+        412,
+        "The criteria match more than one resource",
+      )),
+      ("POST", [existing]) => Ok(Target::Existing(existing.clone())),
+      ("POST", []) => Ok(Target::Id(new_id())),
+      (_, [existing]) => {
+        let id = existing["id"].as_str().unwrap_or_default().to_string();
+        if resource_id.is_some_and(|r| r != id) {
+          return Err(Failure::new(
+            409,
+            "The resource id does not match the criteria",
+          ));
+        }
+        Ok(Target::Id(id))
+      }
+      _ => Ok(Target::Id(resource_id.unwrap_or_else(new_id))),
+    }
+  }
+
+  fn write(&mut self, request: &Request, target: Target) -> Result<Value, Failure> {
+    match target {
+      Target::Existing(existing) => Ok(self.response(200, Some(existing))),
+      Target::Id(id) => {
+        let mut resource = request.resource.clone().unwrap_or_default();
+        resource["id"] = json!(id);
+        let if_match = match request.method.as_str() {
+          "PUT" => request.if_match.as_deref(),
+          _ => None,
+        };
+        let (stored, created) = self.store.update(resource, if_match)?;
+        Ok(self.response(if created { 201 } else { 200 }, Some(stored)))
+      }
+    }
+  }
+
+  /// Applies the JSON Patch (in a `Binary`) or FHIRPath Patch of the
+  /// entry to the resource its URL names.
+  fn patch(&mut self, request: &Request) -> Result<Value, Failure> {
+    let resource_type = request.resource_type();
+    let current = match request.segments().as_slice() {
+      [_, id] => self.store.read(resource_type, id)?,
+      [_] if request.query.is_some() => {
+        match self
+          .store
+          .search(resource_type, &request.params())?
+          .as_slice()
+        {
+          [current] => current.clone(),
+          [] => return Err(Failure::new(404, "The criteria match no resource")),
+          _ => {
+            return Err(Failure::new(
+              412,
+              "The criteria match more than one resource",
+            ))
+          }
+        }
+      }
+      _ => {
+        return Err(Failure::new(
+          400,
+          "PATCH needs [type]/[id] or [type]?[criteria]",
+        ))
+      }
+    };
+    let patch = request
+      .resource
+      .as_ref()
+      .ok_or_else(|| Failure::new(400, "A patch is needed"))?;
+    let patched = patch::apply(&current, patch)?;
+    if patched["resourceType"] != current["resourceType"] || patched["id"] != current["id"] {
+      return Err(Failure::new(
+        422,
+        "A patch cannot change the resourceType or id",
+      ));
+    }
+    let (stored, _) = self.store.update(patched, request.if_match.as_deref())?;
+    Ok(self.response(200, Some(stored)))
+  }
+
+  /// A history Bundle of versions, newest first.
+  fn history(&self, changes: Vec<Change>) -> Value {
+    let entries = changes
+      .into_iter()
+      .map(|change| {
+        let (resource_type, id, version) = (&change.resource_type, &change.id, change.version);
+        let (method, url) = match (&version.resource, version.version_id.as_str()) {
+          (None, _) => ("DELETE", format!("{}/{}", resource_type, id)),
+          (Some(_), "1") => ("POST", resource_type.to_string()),
+          (Some(_), _) => ("PUT", format!("{}/{}", resource_type, id)),
+        };
+        let mut entry = json!({
+          "request": {"method": method, "url": url},
+          "response": {
+            "status": status_line(if method == "POST" { 201 } else { 200 }),
+            "etag": format!("W/\"{}\"", version.version_id),
+            "lastModified": version.last_updated
+          }
+        });
+        if let Some(full_url) = self.full_url(resource_type, id) {
+          entry["fullUrl"] = json!(full_url);
+        }
+        if let Some(resource) = version.resource {
+          entry["resource"] = resource;
+        }
+        entry
+      })
+      .collect();
+    self.bundle("history", entries)
+  }
+
+  fn read(&self, request: &Request) -> Result<Value, Failure> {
+    let resource_type = request.resource_type();
+    let result = match request.segments().as_slice() {
+      ["_history"] => self.history(self.store.system_history()?),
+      [_, "_history"] => self.history(self.store.type_history(resource_type)?),
+      [_] => {
+        let entries = self
+          .store
+          .search(resource_type, &request.params())?
+          .into_iter()
+          .map(|resource| {
+            let id = resource["id"].as_str().unwrap_or_default();
+            let mut entry = json!({"search": {"mode": "match"}});
+            if let Some(full_url) = self.full_url(resource_type, id) {
+              entry["fullUrl"] = json!(full_url);
+            }
+            entry["resource"] = resource;
+            entry
+          })
+          .collect();
+        self.bundle("searchset", entries)
+      }
+      [_, id] => {
+        let resource = self.store.read(resource_type, id)?;
+        let meta = &resource["meta"];
+        let unchanged = request.if_none_match.as_deref().is_some_and(|etag| {
+          etag == "*" || meta["versionId"].as_str() == Some(etag_version(etag))
+        });
+        let since = request
+          .if_modified_since
+          .as_deref()
+          .and_then(DateTime::parse);
+        let modified = meta["lastUpdated"].as_str().and_then(DateTime::parse);
+        let not_modified = match (since, modified) {
+          (Some(since), Some(modified)) => modified <= since,
+          _ => false,
+        };
+        if unchanged || not_modified {
+          return Ok(self.response(304, None));
+        }
+        resource
+      }
+      [_, id, "_history"] => {
+        let changes = self
+          .store
+          .history(resource_type, id)?
+          .into_iter()
+          .map(|version| Change {
+            resource_type: resource_type.to_string(),
+            id: id.to_string(),
+            version,
+          })
+          .collect();
+        self.history(changes)
+      }
+      [_, id, "_history", version] => self.store.vread(resource_type, id, version)?,
+      _ => return Err(Failure::new(400, "Unsupported read URL")),
+    };
+    let mut entry = self.response(200, Some(result));
+    if request.method == "HEAD" {
+      if let Some(entry) = entry.as_object_mut() {
+        entry.remove("resource");
+      }
+    }
+    Ok(entry)
+  }
+
+  /// Runs the parsed entries in `order`: the deletes, then the writes once
+  /// every fullUrl is resolved, then the rest.
+  fn run(
+    &mut self,
+    requests: &mut [Option<Request>],
+    order: &[usize],
+    results: &mut [Option<Result<Value, Failure>>],
+    transaction: bool,
+  ) -> Result<(), ProcessError> {
+    for &i in order {
+      if let Some(request) = requests[i].as_ref().filter(|r| r.method == "DELETE") {
+        let result = self.delete(request);
+        record(results, transaction, i, result)?;
+      }
+    }
+
+    let mut targets: HashMap<usize, Target> = HashMap::new();
+    let mut aliases = HashMap::new();
+    for &i in order {
+      let request = match requests[i].as_ref() {
+        Some(request) if matches!(request.method.as_str(), "POST" | "PUT") => request,
+        _ => continue,
+      };
+      match self.target(request) {
+        Ok(target) => {
+          let id = match &target {
+            Target::Existing(existing) => existing["id"].as_str().unwrap_or_default(),
+            Target::Id(id) => id,
+          };
+          if let Some(full_url) = &request.full_url {
+            aliases.insert(
+              full_url.clone(),
+              format!("{}/{}", request.resource_type(), id),
+            );
+          }
+          targets.insert(i, target);
+        }
+        Err(failure) => record(results, transaction, i, Err(failure))?,
+      }
+    }
+
+    for &i in order {
+      let request = match requests[i].as_mut() {
+        Some(request) if results[i].is_none() => request,
+        _ => continue,
+      };
+      if let Some(query) = &mut request.query {
+        *query = resolve_aliases(query, &aliases);
+      }
+      if let Some(resource) = &mut request.resource {
+        rewrite_references(resource, &aliases);
+        if let Some(urn) = urn_reference(resource) {
+          let message = format!("{} does not resolve to an entry", urn);
+          record(results, transaction, i, Err(Failure::new(400, &message)))?;
+        }
+      }
+    }
+
+    for &i in order {
+      let request = match requests[i].as_ref() {
+        Some(request) if results[i].is_none() => request,
+        _ => continue,
+      };
+      let result = match (request.method.as_str(), targets.remove(&i)) {
+        ("POST", Some(target)) | ("PUT", Some(target)) => self.write(request, target),
+        ("PATCH", _) => self.patch(request),
+        _ => self.read(request),
+      };
+      record(results, transaction, i, result)?;
+    }
+    Ok(())
+  }
+
+  /// Processes a transaction or batch and returns the transaction-response
+  /// or batch-response. A failed transaction leaves the store unchanged.
+  pub fn process(&mut self, bundle: &Bundle) -> Result<Bundle<'static>, ProcessError> {
+    let value = &bundle.value;
+    let transaction = match value["type"].as_str().unwrap_or_default() {
+      "transaction" => true,
+      "batch" => false,
+      other => return Err(ProcessError::NotABatch(other.to_string())),
+    };
+    let issues = validate_bundle(bundle);
+    if !issues.is_empty() {
+      return Err(ProcessError::Invalid(issues));
+    }
+    let entries = value["entry"]
+      .as_array()
+      .map(|e| e.as_slice())
+      .unwrap_or_default();
+    let mut results: Vec<Option<Result<Value, Failure>>> = entries.iter().map(|_| None).collect();
+    let mut requests: Vec<Option<Request>> = vec![];
+    for (i, entry) in entries.iter().enumerate() {
+      match self.parse(entry) {
+        Ok(request) => requests.push(Some(request)),
+        Err(failure) => {
+          record(&mut results, transaction, i, Err(failure))?;
+          requests.push(None);
+        }
+      }
+    }
+    let mut order: Vec<usize> = (0..entries.len())
+      .filter(|&i| requests[i].is_some())
+      .collect();
+    order.sort_by_key(|&i| requests[i].as_ref().map_or(0, |r| rank(&r.method)));
+    if transaction {
+      self.store.begin().map_err(ProcessError::Store)?;
+    }
+    match self.run(&mut requests, &order, &mut results, transaction) {
+      Ok(()) if transaction => self.store.commit().map_err(ProcessError::Store)?,
+      Ok(()) => {}
+      Err(error) => {
+        if transaction {
+          self.store.rollback().map_err(ProcessError::Store)?;
+        }
+        return Err(error);
+      }
+    }
+    let entries: Vec<Value> = results
+      .into_iter()
+      .flatten()
+      .map(|result| match result {
+        Ok(entry) => entry,
+        Err(failure) => failure.entry(),
+      })
+      .collect();
+    let response_type = if transaction {
+      "transaction-response"
+    } else {
+      "batch-response"
+    };
+    Ok(Bundle {
+      value: Cow::Owned(json!({
+        "resourceType": "Bundle",
+        "type": response_type,
+        "entry": entries,
+      })),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::bundle::TransactionBuilder;
+  use crate::model::ResourceList::ResourceList;
+  use crate::store::MemoryStore;
+  use std::fs;
+
+  fn statuses(bundle: &Bundle) -> Vec<String> {
+    bundle.value["entry"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|e| e["response"]["status"].as_str().unwrap().to_string())
+      .collect()
+  }
+
+  #[test]
+  fn test_process_batch() {
+    let contents = fs::read_to_string("examples-json/bundle-transaction.json").unwrap();
+    let mut value: Value = serde_json::from_str(&contents).unwrap();
+    value["type"] = json!("batch");
+    value["entry"][9]["request"]["ifNoneMatch"] = json!("W/\"1\"");
+
+    let mut store = MemoryStore::new();
+    for resource in &[
+      json!({"resourceType": "Patient", "id": "123"}),
+      json!({"resourceType": "Patient", "id": "234"}),
+      json!({"resourceType": "Patient", "id": "12334"}),
+      json!({"resourceType": "Patient", "id": "existing",
+        "identifier": [{"system": "http:/example.org/fhir/ids", "value": "234234"}]}),
+    ] {
+      store.update(resource.clone(), None).unwrap();
+    }
+    let response = BundleProcessor::new(&mut store)
+      .base("http://example.org/fhir/")
+      .process(&Bundle::new(&value))
+      .unwrap();
+    assert_eq!(
+      statuses(&response),
+      vec![
+        "201 Created",
+        "200 OK",
+        "200 OK",
+        "201 Created",
+        "412 Precondition Failed",
+        "204 No Content",
+        "204 No Content",
+        "501 Not Implemented",
+        "200 OK",
+        "304 Not Modified",
+      ]
+    );
+    let entries = response.value["entry"].as_array().unwrap();
+    assert_eq!(
+      entries[1]["response"]["location"],
+      "Patient/existing/_history/1"
+    );
+    assert_eq!(entries[2]["response"]["etag"], "W/\"2\"");
+    assert_eq!(entries[2]["fullUrl"], "http://example.org/fhir/Patient/123");
+    assert_eq!(
+      entries[4]["response"]["outcome"]["issue"][0]["code"],
+      "conflict"
+    );
+    assert_eq!(entries[8]["resource"]["type"], "searchset");
+    assert_eq!(entries[8]["resource"]["total"], 3);
+    assert_eq!(store.read("Patient", "234").unwrap_err().status(), 410);
+    assert_eq!(validate_bundle(&response), vec![]);
+  }
+
+  #[test]
+  fn test_local_urls_and_aliases() {
+    let mut store = MemoryStore::new();
+    let mut processor = BundleProcessor::new(&mut store);
+    processor.base("http://x/fhir");
+    assert_eq!(
+      processor.local_url("http://x/fhir/Patient/1").ok(),
+      Some("Patient/1")
+    );
+    assert_eq!(
+      processor.local_url("http://x/fhir?_id=1").ok(),
+      Some("?_id=1")
+    );
+    assert_eq!(processor.local_url("http://x/fhir").ok(), Some(""));
+    assert!(processor.local_url("http://x/fhir2/Patient/1").is_err());
+
+    let mut aliases = HashMap::new();
+    aliases.insert("http://x/Patient/1".to_string(), "Patient/a".to_string());
+    aliases.insert("http://x/Patient/12".to_string(), "Patient/b".to_string());
+    assert_eq!(
+      resolve_aliases(
+        "subject=http://x/Patient/12&patient=http%3A%2F%2Fx%2FPatient%2F1,Patient/c&_count=1",
+        &aliases
+      ),
+      "subject=Patient/b&patient=Patient/a,Patient/c&_count=1"
+    );
+  }
+
+  #[test]
+  fn test_process_transaction() {
+    let patient =
+      json!({"resourceType": "Patient", "id": "local", "name": [{"family": "Chalmers"}]});
+    let observation = json!({
+      "resourceType": "Observation",
+      "status": "final",
+      "code": {"text": "weight"},
+      "subject": {"reference": "Patient/local"}
+    });
+    let mut builder = TransactionBuilder::new();
+    builder.create(&ResourceList::new(&patient)).unwrap();
+    builder.create(&ResourceList::new(&observation)).unwrap();
+    builder.read("Observation?subject:missing=false");
+    let mut store = MemoryStore::new();
+    let response = BundleProcessor::new(&mut store)
+      .process(&builder.build().unwrap())
+      .unwrap();
+    assert_eq!(response.value["type"], "transaction-response");
+    let entries = response.value["entry"].as_array().unwrap();
+    let patient_id = entries[0]["resource"]["id"].as_str().unwrap();
+    assert_eq!(
+      entries[1]["resource"]["subject"]["reference"],
+      json!(format!("Patient/{}", patient_id))
+    );
+    assert_eq!(entries[2]["resource"]["total"], 1);
+    assert_eq!(store.len(), 2);
+
+    let mut failing = TransactionBuilder::new();
+    failing.create(&ResourceList::new(&patient)).unwrap();
+    failing
+      .update_if_match(
+        &ResourceList::new(&json!({"resourceType": "Patient", "id": patient_id})),
+        "7",
+      )
+      .unwrap();
+    let error = BundleProcessor::new(&mut store)
+      .process(&failing.build().unwrap())
+      .unwrap_err();
+    assert_eq!(error.status(), 412);
+    assert!(matches!(error, ProcessError::Failed { entry: 1, .. }));
+    assert_eq!(error.outcome().issue().len(), 1);
+    assert_eq!(store.len(), 2);
+  }
+
+  #[test]
+  fn test_process_patch() {
+    let mut store = MemoryStore::new();
+    let patient = json!({"resourceType": "Patient", "id": "1", "name": [{"family": "Chalmers"}]});
+    store.update(patient.clone(), None).unwrap();
+    let mut changed = patient.clone();
+    changed["name"][0]["given"] = json!(["Peter"]);
+    let json_patch = patch::json_patch::diff(&patient, &changed);
+    let mut active = changed.clone();
+    active["active"] = json!(true);
+    let fhirpath_patch = patch::fhirpath_patch::diff(&changed, &active);
+
+    let mut builder = TransactionBuilder::new();
+    builder.patch("Patient", "1", &patch::binary(&json_patch));
+    builder.patch(
+      "Patient",
+      "1",
+      &ResourceList::new(&fhirpath_patch.to_json()),
+    );
+    builder.patch("Patient", "2", &patch::binary(&json_patch));
+    let mut batch = builder.build().unwrap().to_json();
+    batch["type"] = json!("batch");
+    let response = BundleProcessor::new(&mut store)
+      .process(&Bundle::new(&batch))
+      .unwrap();
+    assert_eq!(
+      statuses(&response),
+      vec!["200 OK", "200 OK", "404 Not Found"]
+    );
+    assert_eq!(
+      response.value["entry"][0]["resource"]["name"][0]["given"],
+      json!(["Peter"])
+    );
+    let stored = store.read("Patient", "1").unwrap();
+    assert_eq!(stored["meta"]["versionId"], "3");
+    assert_eq!(stored["active"], true);
+  }
+}
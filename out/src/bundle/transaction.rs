@@ -0,0 +1,389 @@
+//! Building transaction and batch Bundles.
+//!
+//! New resources get `urn:uuid:` fullUrls. Other entries refer to them
+//! through the `EntryHandle` returned when they were added, or by their
+//! local `[type]/[id]`: ids of created resources are only placeholders, so
+//! `build` rewrites references to them to the entry's fullUrl and removes the
+//! id.
+
+use super::new_urn_uuid;
+use super::validation::{validate_bundle, BundleIssue};
+use crate::model::Bundle::Bundle;
+use crate::model::Reference::Reference;
+use crate::model::ResourceList::ResourceList;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionError {
+  /// The resource has no `resourceType`.
+  MissingResourceType,
+  /// An update of a resource of this type that has no `id`.
+  MissingId(String),
+  /// The built Bundle breaks the Bundle invariants.
+  Invalid(Vec<BundleIssue>),
+}
+
+impl fmt::Display for TransactionError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TransactionError::MissingResourceType => write!(f, "The resource has no resourceType"),
+      TransactionError::MissingId(resource_type) => {
+        write!(f, "Cannot update a {} without an id", resource_type)
+      }
+      TransactionError::Invalid(issues) => {
+        let messages: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        write!(f, "Invalid Bundle: {}", messages.join("; "))
+      }
+    }
+  }
+}
+
+impl std::error::Error for TransactionError {}
+
+/// An entry added to a `TransactionBuilder` or `MessageBuilder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryHandle {
+  full_url: String,
+  resource_type: String,
+}
+
+impl EntryHandle {
+  pub(super) fn new(full_url: &str, resource_type: &str) -> EntryHandle {
+    EntryHandle {
+      full_url: full_url.to_string(),
+      resource_type: resource_type.to_string(),
+    }
+  }
+
+  pub fn full_url(&self) -> &str {
+    &self.full_url
+  }
+
+  pub fn resource_type(&self) -> &str {
+    &self.resource_type
+  }
+
+  /// A reference to the entry's resource, for use in other entries.
+  pub fn reference(&self) -> Reference<'static> {
+    Reference {
+      value: Cow::Owned(json!({
+        "reference": self.full_url,
+        "type": self.resource_type,
+      })),
+    }
+  }
+}
+
+/// Builds a transaction or batch Bundle.
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+  bundle_type: &'static str,
+  base: Option<String>,
+  entries: Vec<Value>,
+  /// Local `[type]/[id]` of created resources to their fullUrl.
+  aliases: HashMap<String, String>,
+}
+
+impl Default for TransactionBuilder {
+  fn default() -> Self {
+    TransactionBuilder {
+      bundle_type: "transaction",
+      base: None,
+      entries: vec![],
+      aliases: HashMap::new(),
+    }
+  }
+}
+
+fn resource_type(resource: &Value) -> Result<String, TransactionError> {
+  resource["resourceType"]
+    .as_str()
+    .map(|t| t.to_string())
+    .ok_or(TransactionError::MissingResourceType)
+}
+
+fn search_url(resource_type: &str, criteria: &str) -> String {
+  format!("{}?{}", resource_type, criteria.trim_start_matches('?'))
+}
+
+/// Replaces `reference` strings found in `aliases` anywhere below `value`.
+pub(super) fn rewrite_references(value: &mut Value, aliases: &HashMap<String, String>) {
+  match value {
+    Value::Object(map) => {
+      if let Some(Value::String(reference)) = map.get_mut("reference") {
+        if let Some(full_url) = aliases.get(reference.as_str()) {
+          *reference = full_url.clone();
+        }
+      }
+      for (_, child) in map.iter_mut() {
+        rewrite_references(child, aliases);
+      }
+    }
+    Value::Array(items) => {
+      for item in items {
+        rewrite_references(item, aliases);
+      }
+    }
+    _ => {}
+  }
+}
+
+impl TransactionBuilder {
+  /// A builder for a transaction, processed all-or-nothing.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// A builder for a batch, whose entries are processed independently.
+  pub fn batch() -> Self {
+    TransactionBuilder {
+      bundle_type: "batch",
+      ..Self::default()
+    }
+  }
+
+  /// The server's base URL. Updates of resources with a known id then get
+  /// `[base]/[type]/[id]` as their fullUrl instead of a `urn:uuid:`.
+  pub fn base(&mut self, base: &str) -> &mut Self {
+    self.base = Some(base.trim_end_matches('/').to_string());
+    self
+  }
+
+  fn push(&mut self, full_url: Option<&str>, resource: Option<Value>, request: Value) {
+    let mut entry = json!({});
+    if let Some(full_url) = full_url {
+      entry["fullUrl"] = json!(full_url);
+    }
+    if let Some(resource) = resource {
+      entry["resource"] = resource;
+    }
+    entry["request"] = request;
+    self.entries.push(entry);
+  }
+
+  fn add_create(
+    &mut self,
+    resource: &ResourceList,
+    if_none_exist: Option<&str>,
+  ) -> Result<EntryHandle, TransactionError> {
+    let mut resource = resource.to_json();
+    let resource_type = resource_type(&resource)?;
+    let full_url = new_urn_uuid();
+    if let Some(id) = resource.as_object_mut().and_then(|r| r.remove("id")) {
+      if let Some(id) = id.as_str() {
+        self
+          .aliases
+          .insert(format!("{}/{}", resource_type, id), full_url.clone());
+      }
+    }
+    let mut request = json!({"method": "POST", "url": resource_type});
+    if let Some(criteria) = if_none_exist {
+      request["ifNoneExist"] = json!(criteria.trim_start_matches('?'));
+    }
+    self.push(Some(&full_url), Some(resource), request);
+    Ok(EntryHandle {
+      full_url,
+      resource_type,
+    })
+  }
+
+  /// Creates a resource. The server assigns the id; an id on the resource is
+  /// only used to match local references (see the module documentation).
+  pub fn create(&mut self, resource: &ResourceList) -> Result<EntryHandle, TransactionError> {
+    self.add_create(resource, None)
+  }
+
+  /// Creates a resource unless one matching the search `criteria` (e.g.
+  /// `identifier=http://example.org/ids|123`) already exists.
+  pub fn conditional_create(
+    &mut self,
+    resource: &ResourceList,
+    criteria: &str,
+  ) -> Result<EntryHandle, TransactionError> {
+    self.add_create(resource, Some(criteria))
+  }
+
+  fn add_update(
+    &mut self,
+    resource: &ResourceList,
+    if_match: Option<&str>,
+  ) -> Result<EntryHandle, TransactionError> {
+    let resource = resource.to_json();
+    let resource_type = resource_type(&resource)?;
+    let id = resource["id"]
+      .as_str()
+      .ok_or_else(|| TransactionError::MissingId(resource_type.clone()))?;
+    let url = format!("{}/{}", resource_type, id);
+    let full_url = match &self.base {
+      Some(base) => format!("{}/{}", base, url),
+      None => new_urn_uuid(),
+    };
+    let mut request = json!({"method": "PUT", "url": url});
+    if let Some(version) = if_match {
+      request["ifMatch"] = json!(format!("W/\"{}\"", version));
+    }
+    self.push(Some(&full_url), Some(resource), request);
+    Ok(EntryHandle {
+      full_url,
+      resource_type,
+    })
+  }
+
+  /// Creates or replaces the resource with the resource's `id`.
+  pub fn update(&mut self, resource: &ResourceList) -> Result<EntryHandle, TransactionError> {
+    self.add_update(resource, None)
+  }
+
+  /// Replaces the resource only if its current version is `version_id`.
+  pub fn update_if_match(
+    &mut self,
+    resource: &ResourceList,
+    version_id: &str,
+  ) -> Result<EntryHandle, TransactionError> {
+    self.add_update(resource, Some(version_id))
+  }
+
+  /// Updates the single resource matching the search `criteria`, or creates
+  /// it if there is none.
+  pub fn conditional_update(
+    &mut self,
+    resource: &ResourceList,
+    criteria: &str,
+  ) -> Result<EntryHandle, TransactionError> {
+    let resource = resource.to_json();
+    let resource_type = resource_type(&resource)?;
+    let full_url = new_urn_uuid();
+    let request = json!({"method": "PUT", "url": search_url(&resource_type, criteria)});
+    self.push(Some(&full_url), Some(resource), request);
+    Ok(EntryHandle {
+      full_url,
+      resource_type,
+    })
+  }
+
+  pub fn delete(&mut self, resource_type: &str, id: &str) -> &mut Self {
+    let request = json!({"method": "DELETE", "url": format!("{}/{}", resource_type, id)});
+    self.push(None, None, request);
+    self
+  }
+
+  /// Deletes the resources matching the search `criteria`.
+  pub fn conditional_delete(&mut self, resource_type: &str, criteria: &str) -> &mut Self {
+    let request = json!({"method": "DELETE", "url": search_url(resource_type, criteria)});
+    self.push(None, None, request);
+    self
+  }
+
+  /// Patches a resource with a `Binary` holding a JSON Patch document or a
+  /// `Parameters` holding a FHIRPath Patch.
+  pub fn patch(&mut self, resource_type: &str, id: &str, patch: &ResourceList) -> &mut Self {
+    let request = json!({"method": "PATCH", "url": format!("{}/{}", resource_type, id)});
+    self.push(Some(&new_urn_uuid()), Some(patch.to_json()), request);
+    self
+  }
+
+  /// Reads or searches, e.g. `Patient/123` or `Patient?name=peter`.
+  pub fn read(&mut self, url: &str) -> &mut Self {
+    self.push(None, None, json!({"method": "GET", "url": url}));
+    self
+  }
+
+  /// The Bundle, with local references rewritten, checked against the
+  /// Bundle invariants.
+  pub fn build(&self) -> Result<Bundle<'static>, TransactionError> {
+    let mut entries = self.entries.clone();
+    for entry in &mut entries {
+      if let Some(resource) = entry.get_mut("resource") {
+        rewrite_references(resource, &self.aliases);
+      }
+    }
+    let bundle = Bundle {
+      value: Cow::Owned(json!({
+        "resourceType": "Bundle",
+        "type": self.bundle_type,
+        "entry": entries,
+      })),
+    };
+    let issues = validate_bundle(&bundle);
+    if !issues.is_empty() {
+      return Err(TransactionError::Invalid(issues));
+    }
+    Ok(bundle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_build_transaction() {
+    let patient =
+      json!({"resourceType": "Patient", "id": "local-1", "name": [{"family": "Chalmers"}]});
+    let observation = json!({
+      "resourceType": "Observation",
+      "status": "final",
+      "code": {"text": "weight"},
+      "subject": {"reference": "Patient/local-1"},
+      "performer": [{"reference": "Practitioner/123"}]
+    });
+    let existing = json!({"resourceType": "Patient", "id": "123", "active": true});
+    let patch = json!({"resourceType": "Parameters", "parameter": []});
+
+    let mut builder = TransactionBuilder::new();
+    builder.base("http://example.org/fhir/");
+    let created = builder.create(&ResourceList::new(&patient)).unwrap();
+    builder
+      .conditional_create(
+        &ResourceList::new(&observation),
+        "identifier=http://example.org/ids|1",
+      )
+      .unwrap();
+    let updated = builder
+      .update_if_match(&ResourceList::new(&existing), "2")
+      .unwrap();
+    builder
+      .conditional_update(&ResourceList::new(&existing), "?identifier=456")
+      .unwrap();
+    builder
+      .delete("Patient", "234")
+      .conditional_delete("Patient", "identifier=123456")
+      .patch("Patient", "123", &ResourceList::new(&patch))
+      .read("Patient?name=peter");
+    assert_eq!(
+      builder.update(&ResourceList::new(&json!({"resourceType": "Patient"}))),
+      Err(TransactionError::MissingId("Patient".to_string()))
+    );
+
+    let bundle = builder.build().unwrap();
+    let value = bundle.to_json();
+    assert_eq!(value["type"], "transaction");
+    let entries = value["entry"].as_array().unwrap();
+    assert_eq!(entries.len(), 8);
+    assert!(created.full_url().starts_with("urn:uuid:"));
+    assert_eq!(entries[0]["fullUrl"], created.full_url());
+    assert!(entries[0]["resource"].get("id").is_none());
+    assert_eq!(
+      entries[1]["resource"]["subject"]["reference"],
+      created.full_url()
+    );
+    assert_eq!(
+      entries[1]["resource"]["performer"][0]["reference"],
+      "Practitioner/123"
+    );
+    assert_eq!(
+      entries[1]["request"],
+      json!({"method": "POST", "url": "Observation", "ifNoneExist": "identifier=http://example.org/ids|1"})
+    );
+    assert_eq!(updated.full_url(), "http://example.org/fhir/Patient/123");
+    assert_eq!(entries[2]["request"]["ifMatch"], "W/\"2\"");
+    assert_eq!(entries[3]["request"]["url"], "Patient?identifier=456");
+    assert_eq!(entries[5]["request"]["method"], "DELETE");
+    assert_eq!(entries[6]["request"]["method"], "PATCH");
+    assert_eq!(created.reference().reference(), Some(created.full_url()));
+    assert_ne!(created.full_url(), crate::bundle::new_urn_uuid());
+  }
+}
@@ -0,0 +1,190 @@
+//! The Bundle invariants (`bdl-1` to `bdl-12`).
+
+use crate::model::Bundle::Bundle;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A broken Bundle invariant, on the Bundle or on one entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundleIssue {
+  /// The invariant or rule, e.g. `bdl-3`.
+  pub key: &'static str,
+  pub entry: Option<usize>,
+  pub message: String,
+}
+
+impl fmt::Display for BundleIssue {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.entry {
+      Some(entry) => write!(f, "{} (entry[{}]): {}", self.key, entry, self.message),
+      None => write!(f, "{}: {}", self.key, self.message),
+    }
+  }
+}
+
+fn issue(key: &'static str, entry: Option<usize>, message: &str) -> BundleIssue {
+  BundleIssue {
+    key,
+    entry,
+    message: message.to_string(),
+  }
+}
+
+fn has_value(value: &Value) -> bool {
+  match value {
+    Value::Null => false,
+    Value::String(s) => !s.is_empty(),
+    Value::Array(a) => !a.is_empty(),
+    Value::Object(o) => !o.is_empty(),
+    _ => true,
+  }
+}
+
+/// Checks a Bundle against the Bundle invariants.
+pub fn validate_bundle(bundle: &Bundle) -> Vec<BundleIssue> {
+  let value = &bundle.value;
+  let bundle_type = value["type"].as_str().unwrap_or_default();
+  let entries = value["entry"]
+    .as_array()
+    .map(|e| e.as_slice())
+    .unwrap_or_default();
+  let mut issues = vec![];
+
+  if has_value(&value["total"]) && !matches!(bundle_type, "searchset" | "history") {
+    issues.push(issue("bdl-1", None, "total only when a search or history"));
+  }
+  if bundle_type == "document" {
+    let identifier = &value["identifier"];
+    if !has_value(&identifier["system"]) || !has_value(&identifier["value"]) {
+      issues.push(issue(
+        "bdl-9",
+        None,
+        "A document must have an identifier with a system and a value",
+      ));
+    }
+    if !has_value(&value["timestamp"]) {
+      issues.push(issue("bdl-10", None, "A document must have a date"));
+    }
+    if entries.first().map(|e| &e["resource"]["resourceType"]) != Some(&Value::from("Composition"))
+    {
+      issues.push(issue(
+        "bdl-11",
+        None,
+        "A document must have a Composition as the first resource",
+      ));
+    }
+  }
+  if bundle_type == "message"
+    && entries.first().map(|e| &e["resource"]["resourceType"])
+      != Some(&Value::from("MessageHeader"))
+  {
+    issues.push(issue(
+      "bdl-12",
+      None,
+      "A message must have a MessageHeader as the first resource",
+    ));
+  }
+
+  let needs_request = matches!(bundle_type, "batch" | "transaction" | "history");
+  let needs_response = matches!(
+    bundle_type,
+    "batch-response" | "transaction-response" | "history"
+  );
+  let mut full_urls: HashMap<&str, Vec<Option<&str>>> = HashMap::new();
+  for (i, entry) in entries.iter().enumerate() {
+    let entry_issue = |key, message| issue(key, Some(i), message);
+    if has_value(&entry["search"]) && bundle_type != "searchset" {
+      issues.push(entry_issue("bdl-2", "entry.search only when a search"));
+    }
+    if has_value(&entry["request"]) != needs_request {
+      issues.push(entry_issue(
+        "bdl-3",
+        "entry.request mandatory for batch/transaction/history, otherwise prohibited",
+      ));
+    }
+    if has_value(&entry["response"]) != needs_response {
+      issues.push(entry_issue(
+        "bdl-4",
+        "entry.response mandatory for batch-response/transaction-response/history, otherwise prohibited",
+      ));
+    }
+    if !has_value(&entry["resource"])
+      && !has_value(&entry["request"])
+      && !has_value(&entry["response"])
+    {
+      issues.push(entry_issue(
+        "bdl-5",
+        "must be a resource unless there's a request or response",
+      ));
+    }
+    if let Some(full_url) = entry["fullUrl"].as_str() {
+      if full_url.contains("/_history/") {
+        issues.push(entry_issue(
+          "bdl-8",
+          "fullUrl cannot be a version specific reference",
+        ));
+      }
+      let version = entry["resource"]["meta"]["versionId"].as_str();
+      let versions = full_urls.entry(full_url).or_default();
+      if bundle_type != "history" && versions.contains(&version) {
+        issues.push(entry_issue(
+          "bdl-7",
+          "FullUrl must be unique in a bundle, or else entries with the same fullUrl must have different meta.versionId",
+        ));
+      }
+      versions.push(version);
+    }
+  }
+  issues
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use std::fs;
+
+  #[test]
+  fn test_validate_bundles() {
+    for file in &[
+      "bundle-transaction.json",
+      "bundle-response.json",
+      "bundle-references.json",
+      "document-example-dischargesummary.json",
+    ] {
+      let contents = fs::read_to_string(format!("examples-json/{}", file)).unwrap();
+      let value: Value = serde_json::from_str(&contents).unwrap();
+      assert_eq!(validate_bundle(&Bundle::new(&value)), vec![], "{}", file);
+    }
+
+    let value = json!({
+      "resourceType": "Bundle",
+      "type": "document",
+      "total": 2,
+      "entry": [
+        {"fullUrl": "urn:uuid:1", "resource": {"resourceType": "Patient"}},
+        {"fullUrl": "urn:uuid:1", "resource": {"resourceType": "Patient"},
+          "request": {"method": "POST", "url": "Patient"}},
+        {"fullUrl": "http://example.org/Patient/1/_history/1"}
+      ]
+    });
+    let keys: Vec<(&str, Option<usize>)> = validate_bundle(&Bundle::new(&value))
+      .iter()
+      .map(|i| (i.key, i.entry))
+      .collect();
+    assert_eq!(
+      keys,
+      vec![
+        ("bdl-1", None),
+        ("bdl-9", None),
+        ("bdl-10", None),
+        ("bdl-11", None),
+        ("bdl-3", Some(1)),
+        ("bdl-7", Some(1)),
+        ("bdl-5", Some(2)),
+        ("bdl-8", Some(2)),
+      ]
+    );
+  }
+}
@@ -0,0 +1,449 @@
+//! Semantic comparison of resources, for test assertions and for finding
+//! duplicates.
+//!
+//! A `Comparator` compares the JSON of two resources element by element.
+//! Elements can be ignored (`meta`, `text` and `id` usually differ between
+//! copies of the same resource), decimals are compared by value so that
+//! `1.0` equals `1`, and designated arrays are compared as sets. The first
+//! difference found is reported with its path, e.g.
+//! `Patient.name[1].given[0]`.
+//!
+//! Paths in the configuration are element paths without indexes, such as
+//! `meta` or `name.given`, optionally starting with the resource type. A
+//! path also covers the `_element` of a primitive. Paths start again at
+//! every nested resource, so that `meta` also covers the `meta` of
+//! contained resources and of Bundle entries.
+
+use crate::rest::Resource;
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Where two resources differ. `None` is a missing element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+  pub path: String,
+  pub left: Option<Value>,
+  pub right: Option<Value>,
+}
+
+impl fmt::Display for Difference {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let show = |value: &Option<Value>| match value {
+      Some(value) => value.to_string(),
+      None => "nothing".to_string(),
+    };
+    write!(
+      f,
+      "{} differs: {} != {}",
+      self.path,
+      show(&self.left),
+      show(&self.right)
+    )
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Comparator {
+  ignored: Vec<String>,
+  sets: Vec<String>,
+  numeric: bool,
+}
+
+impl Default for Comparator {
+  fn default() -> Self {
+    Comparator {
+      ignored: vec![],
+      sets: vec![],
+      numeric: true,
+    }
+  }
+}
+
+/// An element path without the resource type, e.g. `name.given`.
+fn normalise(path: &str) -> String {
+  match path.split_once('.') {
+    Some((first, rest)) if first.starts_with(char::is_uppercase) => rest.to_string(),
+    _ => path.to_string(),
+  }
+}
+
+/// The element path of a child object: a nested resource starts a new one.
+fn element_of<'e>(object: &Map<String, Value>, element: &'e str) -> &'e str {
+  if object.contains_key("resourceType") {
+    ""
+  } else {
+    element
+  }
+}
+
+/// Integers are compared exactly, since above 2^53 distinct integers share
+/// an `f64`, and decimals by value.
+fn same_number(left: &serde_json::Number, right: &serde_json::Number) -> bool {
+  if left.is_f64() || right.is_f64() {
+    left.as_f64() == right.as_f64()
+  } else {
+    left == right
+  }
+}
+
+fn join(parent: &str, key: &str) -> String {
+  let key = key.strip_prefix('_').unwrap_or(key);
+  if parent.is_empty() {
+    key.to_string()
+  } else {
+    format!("{}.{}", parent, key)
+  }
+}
+
+impl Comparator {
+  /// Compares every element, decimals by value and arrays in order.
+  pub fn new() -> Comparator {
+    Comparator::default()
+  }
+
+  /// Ignores `meta`, `text` and `id`, which copies of a resource from
+  /// different servers or versions do not share.
+  pub fn semantic() -> Comparator {
+    let mut comparator = Comparator::new();
+    comparator.ignore("meta").ignore("text").ignore("id");
+    comparator
+  }
+
+  pub fn ignore(&mut self, path: &str) -> &mut Self {
+    self.ignored.push(normalise(path));
+    self
+  }
+
+  /// Compares the array at `path`, e.g. `identifier`, as a set: items may
+  /// be in any order.
+  pub fn set(&mut self, path: &str) -> &mut Self {
+    self.sets.push(normalise(path));
+    self
+  }
+
+  /// Whether numbers are compared by value (the default) or as written.
+  pub fn numeric(&mut self, numeric: bool) -> &mut Self {
+    self.numeric = numeric;
+    self
+  }
+
+  /// The first difference between two resources, if any.
+  pub fn difference<R: Resource>(&self, left: &R, right: &R) -> Option<Difference> {
+    self.first_difference(left.json(), right.json())
+  }
+
+  pub fn equivalent<R: Resource>(&self, left: &R, right: &R) -> bool {
+    self.difference(left, right).is_none()
+  }
+
+  /// The first difference between two JSON resources or elements.
+  pub fn first_difference(&self, left: &Value, right: &Value) -> Option<Difference> {
+    let root = left["resourceType"]
+      .as_str()
+      .or_else(|| right["resourceType"].as_str())
+      .unwrap_or_default();
+    self.compare(left, right, root, "")
+  }
+
+  /// The value with ignored elements removed, set arrays sorted and
+  /// whole decimals as integers: equivalent values have equal canonical forms, so
+  /// their strings can key a set of resources when removing duplicates.
+  pub fn canonical(&self, value: &Value) -> Value {
+    self.canonical_at(value, "")
+  }
+
+  fn canonical_at(&self, value: &Value, element: &str) -> Value {
+    match value {
+      Value::Object(object) => {
+        let element = element_of(object, element);
+        Value::Object(
+          object
+            .iter()
+            .filter(|(key, _)| !self.ignored.contains(&join(element, key)))
+            .map(|(key, child)| (key.clone(), self.canonical_at(child, &join(element, key))))
+            .collect::<Map<String, Value>>(),
+        )
+      }
+      Value::Array(items) => {
+        let mut items: Vec<Value> = items
+          .iter()
+          .map(|item| self.canonical_at(item, element))
+          .collect();
+        if self.sets.iter().any(|set| set == element) {
+          items.sort_by_cached_key(|item| item.to_string());
+        }
+        Value::Array(items)
+      }
+      Value::Number(number) if self.numeric && number.is_f64() => match number.as_f64() {
+        Some(decimal) if decimal.fract() == 0.0 && decimal.abs() < 9007199254740992.0 => {
+          Value::from(decimal as i64)
+        }
+        _ => value.clone(),
+      },
+      _ => value.clone(),
+    }
+  }
+
+  /// Compares values at a reported `path` and configured `element` path.
+  fn compare(&self, left: &Value, right: &Value, path: &str, element: &str) -> Option<Difference> {
+    let differ = || {
+      Some(Difference {
+        path: path.to_string(),
+        left: Some(left.clone()),
+        right: Some(right.clone()),
+      })
+    };
+    match (left, right) {
+      (Value::Object(left), Value::Object(right)) => {
+        self.compare_objects(left, right, path, element)
+      }
+      (Value::Array(left_items), Value::Array(right_items)) => {
+        if self.sets.iter().any(|set| set == element) {
+          self.compare_sets(left_items, right_items, path, element)
+        } else {
+          self.compare_arrays(left_items, right_items, path, element)
+        }
+      }
+      (Value::Number(left), Value::Number(right)) if self.numeric => {
+        if same_number(left, right) {
+          None
+        } else {
+          differ()
+        }
+      }
+      _ if left == right => None,
+      _ => differ(),
+    }
+  }
+
+  fn compare_objects(
+    &self,
+    left: &Map<String, Value>,
+    right: &Map<String, Value>,
+    path: &str,
+    element: &str,
+  ) -> Option<Difference> {
+    let element = element_of(left, element_of(right, element));
+    let keys = left
+      .keys()
+      .chain(right.keys().filter(|key| !left.contains_key(*key)));
+    for key in keys {
+      let child_element = join(element, key);
+      if self.ignored.contains(&child_element) {
+        continue;
+      }
+      let child_path = if path.is_empty() {
+        key.to_string()
+      } else {
+        format!("{}.{}", path, key)
+      };
+      let difference = match (left.get(key), right.get(key)) {
+        (Some(left), Some(right)) => self.compare(left, right, &child_path, &child_element),
+        (left, right) => Some(Difference {
+          path: child_path,
+          left: left.cloned(),
+          right: right.cloned(),
+        }),
+      };
+      if difference.is_some() {
+        return difference;
+      }
+    }
+    None
+  }
+
+  fn compare_arrays(
+    &self,
+    left: &[Value],
+    right: &[Value],
+    path: &str,
+    element: &str,
+  ) -> Option<Difference> {
+    for i in 0..left.len().max(right.len()) {
+      let item_path = format!("{}[{}]", path, i);
+      let difference = match (left.get(i), right.get(i)) {
+        (Some(left), Some(right)) => self.compare(left, right, &item_path, element),
+        (left, right) => Some(Difference {
+          path: item_path,
+          left: left.cloned(),
+          right: right.cloned(),
+        }),
+      };
+      if difference.is_some() {
+        return difference;
+      }
+    }
+    None
+  }
+
+  /// Matches every item with an equivalent item of the other array.
+  fn compare_sets(
+    &self,
+    left: &[Value],
+    right: &[Value],
+    path: &str,
+    element: &str,
+  ) -> Option<Difference> {
+    let mut matched = vec![false; right.len()];
+    for (i, item) in left.iter().enumerate() {
+      let found = (0..right.len())
+        .find(|&j| !matched[j] && self.compare(item, &right[j], path, element).is_none());
+      match found {
+        Some(j) => matched[j] = true,
+        None => {
+          return Some(Difference {
+            path: format!("{}[{}]", path, i),
+            left: Some(item.clone()),
+            right: None,
+          })
+        }
+      }
+    }
+    matched
+      .iter()
+      .position(|matched| !matched)
+      .map(|j| Difference {
+        path: format!("{}[{}]", path, j),
+        left: None,
+        right: Some(right[j].clone()),
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use serde_json::json;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_compare() {
+    let json = load("patient-example.json");
+    let patient = Patient::from_json(json.clone());
+    let mut copy = json.clone();
+    copy["id"] = json!("copy");
+    copy["meta"] = json!({"versionId": "7", "lastUpdated": "2020-01-01T00:00:00Z"});
+    copy.as_object_mut().unwrap().remove("text");
+    let copy = Patient::from_json(copy);
+
+    assert_eq!(
+      Comparator::new().difference(&patient, &copy).unwrap().path,
+      "Patient.id"
+    );
+    assert_eq!(Comparator::semantic().difference(&patient, &copy), None);
+    assert!(Comparator::semantic().equivalent(&patient, &copy));
+
+    let mut renamed = json.clone();
+    renamed["name"][1]["given"][0] = json!("Jimmy");
+    renamed["_birthDate"]["extension"][0]["valueDateTime"] = json!("1974-12-25");
+    let difference = Comparator::semantic()
+      .first_difference(&json, &renamed)
+      .unwrap();
+    assert_eq!(
+      difference.path,
+      "Patient._birthDate.extension[0].valueDateTime"
+    );
+    let mut comparator = Comparator::semantic();
+    comparator.ignore("Patient.birthDate");
+    let difference = comparator.first_difference(&json, &renamed).unwrap();
+    assert_eq!(
+      difference,
+      Difference {
+        path: "Patient.name[1].given[0]".to_string(),
+        left: Some(json!("Jim")),
+        right: Some(json!("Jimmy")),
+      }
+    );
+    assert_eq!(
+      difference.to_string(),
+      "Patient.name[1].given[0] differs: \"Jim\" != \"Jimmy\""
+    );
+  }
+
+  #[test]
+  fn test_numbers_and_sets() {
+    let left = json!({
+      "resourceType": "Observation",
+      "valueQuantity": {"value": 1.0},
+      "category": [{"text": "a"}, {"text": "b"}, {"text": "b"}]
+    });
+    let right = json!({
+      "resourceType": "Observation",
+      "valueQuantity": {"value": 1},
+      "category": [{"text": "b"}, {"text": "a"}, {"text": "a"}]
+    });
+    let mut comparator = Comparator::new();
+    assert_eq!(
+      comparator.first_difference(&left, &right).unwrap().path,
+      "Observation.category[0].text"
+    );
+    comparator.set("category");
+    assert_eq!(
+      comparator.first_difference(&left, &right),
+      Some(Difference {
+        path: "Observation.category[2]".to_string(),
+        left: Some(json!({"text": "b"})),
+        right: None,
+      })
+    );
+    let reordered = json!({
+      "resourceType": "Observation",
+      "valueQuantity": {"value": 1},
+      "category": [{"text": "b"}, {"text": "a"}, {"text": "b"}]
+    });
+    assert_eq!(comparator.first_difference(&left, &reordered), None);
+    assert_eq!(
+      comparator.canonical(&json!({"category": [{"text": "b"}, {"text": "a"}], "value": 1})),
+      comparator.canonical(&json!({"category": [{"text": "a"}, {"text": "b"}], "value": 1.0}))
+    );
+    comparator.numeric(false);
+    assert_eq!(
+      comparator.first_difference(&left, &reordered).unwrap().path,
+      "Observation.valueQuantity.value"
+    );
+
+    let big = json!({"valueInteger": 9007199254740993u64});
+    let near = json!({"valueInteger": 9007199254740992u64});
+    assert!(Comparator::new().first_difference(&big, &near).is_some());
+    assert_ne!(
+      Comparator::new().canonical(&big),
+      Comparator::new().canonical(&near)
+    );
+  }
+
+  #[test]
+  fn test_nested_resources() {
+    let bundle = |id: &str, version: &str| {
+      json!({
+        "resourceType": "Bundle",
+        "type": "collection",
+        "entry": [{"resource": {
+          "resourceType": "Observation",
+          "id": id,
+          "meta": {"versionId": version},
+          "contained": [{"resourceType": "Patient", "id": "p", "meta": {"versionId": version}}]
+        }}]
+      })
+    };
+    let left = bundle("a", "1");
+    let right = bundle("b", "2");
+    assert_eq!(
+      Comparator::new()
+        .first_difference(&left, &right)
+        .unwrap()
+        .path,
+      "Bundle.entry[0].resource.contained[0].meta.versionId"
+    );
+    assert_eq!(Comparator::semantic().first_difference(&left, &right), None);
+    assert_eq!(
+      Comparator::semantic().canonical(&left),
+      Comparator::semantic().canonical(&right)
+    );
+  }
+}
@@ -0,0 +1,607 @@
+//! An implementation of the ConceptMap `$translate` operation over the
+//! generated `ConceptMap` models.
+//!
+//! A `Translator` holds one or more maps. Requests are matched against each
+//! group by source system (or target system when translating in reverse),
+//! `dependsOn` conditions are checked against the supplied dependencies, and
+//! `unmapped` rules are applied when an element has no usable target.
+
+use crate::model::Coding::CodingBuilder;
+use crate::model::ConceptMap::ConceptMap;
+use crate::model::ConceptMap_DependsOn::ConceptMap_DependsOn;
+use crate::model::ConceptMap_Group::ConceptMap_Group;
+use crate::model::ConceptMap_Target::ConceptMap_TargetEquivalence;
+use crate::model::ConceptMap_Unmapped::ConceptMap_UnmappedMode;
+use crate::model::Parameters::{Parameters, ParametersBuilder};
+use crate::model::Parameters_Parameter::{Parameters_Parameter, Parameters_ParameterBuilder};
+
+/// Guards against `other-map` chains that loop back on themselves.
+const MAX_MAP_DEPTH: usize = 8;
+
+/// A dependency supplied with a translation request. `element` is matched
+/// against `ConceptMap.group.element.target.dependsOn.property`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslateDependency {
+  pub element: String,
+  pub system: Option<String>,
+  pub code: String,
+}
+
+/// The inputs of `$translate`. Only `code` is mandatory; everything else
+/// narrows the set of groups that are considered.
+#[derive(Debug, Clone, Default)]
+pub struct TranslateRequest {
+  pub code: String,
+  pub system: Option<String>,
+  pub version: Option<String>,
+  pub source: Option<String>,
+  pub target: Option<String>,
+  pub target_system: Option<String>,
+  pub dependencies: Vec<TranslateDependency>,
+  pub reverse: bool,
+}
+
+impl TranslateRequest {
+  pub fn new(system: &str, code: &str) -> TranslateRequest {
+    TranslateRequest {
+      code: code.to_string(),
+      system: Some(system.to_string()),
+      ..Default::default()
+    }
+  }
+
+  pub fn target_system(mut self, system: &str) -> TranslateRequest {
+    self.target_system = Some(system.to_string());
+    self
+  }
+
+  pub fn dependency(mut self, element: &str, system: Option<&str>, code: &str) -> TranslateRequest {
+    self.dependencies.push(TranslateDependency {
+      element: element.to_string(),
+      system: system.map(|s| s.to_string()),
+      code: code.to_string(),
+    });
+    self
+  }
+
+  pub fn reverse(mut self) -> TranslateRequest {
+    self.reverse = true;
+    self
+  }
+}
+
+/// A concept produced by a translation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslatedConcept {
+  pub system: Option<String>,
+  pub version: Option<String>,
+  pub code: Option<String>,
+  pub display: Option<String>,
+}
+
+/// An additional concept that is produced alongside a match, taken from
+/// `product` (or from `dependsOn` when translating in reverse).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslatedProduct {
+  pub element: String,
+  pub concept: TranslatedConcept,
+}
+
+#[derive(Debug)]
+pub struct TranslateMatch {
+  pub equivalence: ConceptMap_TargetEquivalence,
+  pub concept: Option<TranslatedConcept>,
+  pub products: Vec<TranslatedProduct>,
+  /// The canonical URL of the map that produced this match.
+  pub source: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct TranslateResult {
+  pub result: bool,
+  pub message: Option<String>,
+  pub matches: Vec<TranslateMatch>,
+}
+
+impl TranslateResult {
+  /// Renders the result as the `Parameters` resource returned by `$translate`.
+  pub fn to_parameters(&self) -> Parameters<'static> {
+    let mut parameters = vec![];
+
+    let mut result = Parameters_ParameterBuilder::new();
+    result.name("result").value_boolean(self.result);
+    parameters.push(result.build().to_json());
+
+    if let Some(message) = &self.message {
+      let mut param = Parameters_ParameterBuilder::new();
+      param.name("message").value_string(message);
+      parameters.push(param.build().to_json());
+    }
+
+    for m in &self.matches {
+      let mut parts = vec![];
+      let mut equivalence = Parameters_ParameterBuilder::new();
+      equivalence
+        .name("equivalence")
+        .value_code(&m.equivalence.to_string());
+      parts.push(equivalence.build().to_json());
+      if let Some(concept) = &m.concept {
+        parts.push(coding_parameter("concept", concept));
+      }
+      for product in &m.products {
+        let mut element = Parameters_ParameterBuilder::new();
+        element.name("element").value_uri(&product.element);
+        let mut product_param = Parameters_ParameterBuilder::new();
+        product_param.name("product").part(vec![
+          Parameters_Parameter::new(&element.build().to_json()),
+          Parameters_Parameter::new(&coding_parameter("concept", &product.concept)),
+        ]);
+        parts.push(product_param.build().to_json());
+      }
+      if let Some(source) = &m.source {
+        let mut param = Parameters_ParameterBuilder::new();
+        param.name("source").value_uri(source);
+        parts.push(param.build().to_json());
+      }
+      let mut match_param = Parameters_ParameterBuilder::new();
+      match_param
+        .name("match")
+        .part(parts.iter().map(Parameters_Parameter::new).collect());
+      parameters.push(match_param.build().to_json());
+    }
+
+    let mut builder = ParametersBuilder::new();
+    builder.parameter(parameters.iter().map(Parameters_Parameter::new).collect());
+    Parameters {
+      value: std::borrow::Cow::Owned(builder.build().to_json()),
+    }
+  }
+}
+
+fn coding_parameter(name: &str, concept: &TranslatedConcept) -> serde_json::Value {
+  let mut coding = CodingBuilder::new();
+  if let Some(system) = &concept.system {
+    coding.system(system);
+  }
+  if let Some(version) = &concept.version {
+    coding.version(version);
+  }
+  if let Some(code) = &concept.code {
+    coding.code(code);
+  }
+  if let Some(display) = &concept.display {
+    coding.display(display);
+  }
+  let mut param = Parameters_ParameterBuilder::new();
+  param.name(name).value_coding(coding.build());
+  param.build().to_json()
+}
+
+/// Translates codes using a set of concept maps. Maps referenced by
+/// `unmapped.mode = other-map` are looked up among the registered maps by
+/// their canonical `url`.
+#[derive(Debug, Default)]
+pub struct Translator<'a> {
+  maps: Vec<ConceptMap<'a>>,
+}
+
+impl<'a> Translator<'a> {
+  pub fn new() -> Translator<'a> {
+    Translator { maps: vec![] }
+  }
+
+  pub fn add_map(&mut self, map: ConceptMap<'a>) -> &mut Translator<'a> {
+    self.maps.push(map);
+    self
+  }
+
+  /// Translates against every registered map whose source/target scope is
+  /// compatible with the request.
+  pub fn translate(&self, request: &TranslateRequest) -> TranslateResult {
+    let mut matches = vec![];
+    for map in &self.maps {
+      if !map_in_scope(map, request) {
+        continue;
+      }
+      self.translate_map(map, request, 0, &mut matches);
+    }
+    finish(matches)
+  }
+
+  /// Translates against a single map identified by its canonical url.
+  pub fn translate_with(&self, url: &str, request: &TranslateRequest) -> TranslateResult {
+    let mut matches = vec![];
+    if let Some(map) = self.find_map(url) {
+      self.translate_map(map, request, 0, &mut matches);
+    }
+    finish(matches)
+  }
+
+  fn find_map(&self, url: &str) -> Option<&ConceptMap<'a>> {
+    self.maps.iter().find(|m| m.url() == Some(url))
+  }
+
+  fn translate_map(
+    &self,
+    map: &ConceptMap,
+    request: &TranslateRequest,
+    depth: usize,
+    matches: &mut Vec<TranslateMatch>,
+  ) {
+    if depth > MAX_MAP_DEPTH {
+      return;
+    }
+    let groups = match map.group() {
+      Some(groups) => groups,
+      None => return,
+    };
+    for group in groups {
+      if request.reverse {
+        translate_group_reverse(map, &group, request, matches);
+        continue;
+      }
+      if !group_in_scope(&group, request) {
+        continue;
+      }
+      let found = translate_group(map, &group, request, matches);
+      if !found {
+        self.apply_unmapped(map, &group, request, depth, matches);
+      }
+    }
+  }
+
+  fn apply_unmapped(
+    &self,
+    map: &ConceptMap,
+    group: &ConceptMap_Group,
+    request: &TranslateRequest,
+    depth: usize,
+    matches: &mut Vec<TranslateMatch>,
+  ) {
+    let unmapped = match group.unmapped() {
+      Some(unmapped) => unmapped,
+      None => return,
+    };
+    match unmapped.mode() {
+      Some(ConceptMap_UnmappedMode::Provided) => matches.push(TranslateMatch {
+        equivalence: ConceptMap_TargetEquivalence::Equal,
+        concept: Some(TranslatedConcept {
+          system: group.target().map(|s| s.to_string()),
+          version: group.target_version().map(|s| s.to_string()),
+          code: Some(request.code.clone()),
+          display: None,
+        }),
+        products: vec![],
+        source: map.url().map(|s| s.to_string()),
+      }),
+      Some(ConceptMap_UnmappedMode::Fixed) => {
+        if let Some(code) = unmapped.code() {
+          matches.push(TranslateMatch {
+            equivalence: ConceptMap_TargetEquivalence::Inexact,
+            concept: Some(TranslatedConcept {
+              system: group.target().map(|s| s.to_string()),
+              version: group.target_version().map(|s| s.to_string()),
+              code: Some(code.to_string()),
+              display: unmapped.display().map(|s| s.to_string()),
+            }),
+            products: vec![],
+            source: map.url().map(|s| s.to_string()),
+          });
+        }
+      }
+      Some(ConceptMap_UnmappedMode::OtherMap) => {
+        if let Some(other) = unmapped.url().and_then(|url| self.find_map(url)) {
+          self.translate_map(other, request, depth + 1, matches);
+        }
+      }
+      None => {}
+    }
+  }
+}
+
+/// Translates a code against a single map.
+pub fn translate(map: &ConceptMap, request: &TranslateRequest) -> TranslateResult {
+  let translator = Translator {
+    maps: vec![ConceptMap {
+      value: std::borrow::Cow::Borrowed(&*map.value),
+    }],
+  };
+  translator.translate(request)
+}
+
+fn finish(matches: Vec<TranslateMatch>) -> TranslateResult {
+  let result = matches.iter().any(|m| is_positive(&m.equivalence));
+  let message = if result {
+    None
+  } else if matches.is_empty() {
+    Some("No mappings could be found for the supplied code".to_string())
+  } else {
+    Some("Only negative (unmatched or disjoint) mappings were found".to_string())
+  };
+  TranslateResult {
+    result,
+    message,
+    matches,
+  }
+}
+
+fn is_positive(equivalence: &ConceptMap_TargetEquivalence) -> bool {
+  !matches!(
+    equivalence,
+    ConceptMap_TargetEquivalence::Unmatched | ConceptMap_TargetEquivalence::Disjoint
+  )
+}
+
+fn map_in_scope(map: &ConceptMap, request: &TranslateRequest) -> bool {
+  let (source, target) = if request.reverse {
+    (&request.target, &request.source)
+  } else {
+    (&request.source, &request.target)
+  };
+  if let Some(source) = source {
+    let map_source = map.source_uri().or_else(|| map.source_canonical());
+    if map_source.is_some() && map_source != Some(source.as_str()) {
+      return false;
+    }
+  }
+  if let Some(target) = target {
+    let map_target = map.target_uri().or_else(|| map.target_canonical());
+    if map_target.is_some() && map_target != Some(target.as_str()) {
+      return false;
+    }
+  }
+  true
+}
+
+fn group_in_scope(group: &ConceptMap_Group, request: &TranslateRequest) -> bool {
+  if let (Some(system), Some(source)) = (&request.system, group.source()) {
+    if system != source {
+      return false;
+    }
+  }
+  if let (Some(version), Some(source_version)) = (&request.version, group.source_version()) {
+    if version != source_version {
+      return false;
+    }
+  }
+  if let (Some(target_system), Some(target)) = (&request.target_system, group.target()) {
+    if target_system != target {
+      return false;
+    }
+  }
+  true
+}
+
+/// Returns true if every `dependsOn` condition is satisfied by one of the
+/// request's dependencies.
+fn dependencies_satisfied(conditions: &[ConceptMap_DependsOn], request: &TranslateRequest) -> bool {
+  conditions.iter().all(|condition| {
+    request.dependencies.iter().any(|dependency| {
+      Some(dependency.element.as_str()) == condition.property()
+        && Some(dependency.code.as_str()) == condition.value()
+        && match (&dependency.system, condition.system()) {
+          (Some(a), Some(b)) => a == b,
+          _ => true,
+        }
+    })
+  })
+}
+
+fn products_from(items: &[ConceptMap_DependsOn]) -> Vec<TranslatedProduct> {
+  items
+    .iter()
+    .map(|item| TranslatedProduct {
+      element: item.property().unwrap_or_default().to_string(),
+      concept: TranslatedConcept {
+        system: item.system().map(|s| s.to_string()),
+        version: None,
+        code: item.value().map(|s| s.to_string()),
+        display: item.display().map(|s| s.to_string()),
+      },
+    })
+    .collect()
+}
+
+/// Forward translation of a single group. Returns whether the element was
+/// found with at least one applicable target, in which case `unmapped` is not
+/// consulted.
+fn translate_group(
+  map: &ConceptMap,
+  group: &ConceptMap_Group,
+  request: &TranslateRequest,
+  matches: &mut Vec<TranslateMatch>,
+) -> bool {
+  let mut found = false;
+  for element in group.element() {
+    if element.code() != Some(request.code.as_str()) {
+      continue;
+    }
+    for target in element.target().unwrap_or_default() {
+      let depends_on = target.depends_on().unwrap_or_default();
+      if !dependencies_satisfied(&depends_on, request) {
+        continue;
+      }
+      found = true;
+      let equivalence = target
+        .equivalence()
+        .unwrap_or(ConceptMap_TargetEquivalence::Equivalent);
+      let concept = target.code().map(|code| TranslatedConcept {
+        system: group.target().map(|s| s.to_string()),
+        version: group.target_version().map(|s| s.to_string()),
+        code: Some(code.to_string()),
+        display: target.display().map(|s| s.to_string()),
+      });
+      matches.push(TranslateMatch {
+        equivalence,
+        concept,
+        products: products_from(&target.product().unwrap_or_default()),
+        source: map.url().map(|s| s.to_string()),
+      });
+    }
+  }
+  found
+}
+
+/// Reverse translation: the request code is looked up among the targets and
+/// the owning element is returned. Equivalence is inverted so it still reads
+/// from the returned concept to the requested one, and the roles of
+/// `dependsOn` and `product` are swapped.
+fn translate_group_reverse(
+  map: &ConceptMap,
+  group: &ConceptMap_Group,
+  request: &TranslateRequest,
+  matches: &mut Vec<TranslateMatch>,
+) {
+  if let (Some(system), Some(target)) = (&request.system, group.target()) {
+    if system != target {
+      return;
+    }
+  }
+  if let (Some(target_system), Some(source)) = (&request.target_system, group.source()) {
+    if target_system != source {
+      return;
+    }
+  }
+  for element in group.element() {
+    for target in element.target().unwrap_or_default() {
+      if target.code() != Some(request.code.as_str()) {
+        continue;
+      }
+      let product = target.product().unwrap_or_default();
+      if !dependencies_satisfied(&product, request) {
+        continue;
+      }
+      let equivalence = invert(
+        target
+          .equivalence()
+          .unwrap_or(ConceptMap_TargetEquivalence::Equivalent),
+      );
+      matches.push(TranslateMatch {
+        equivalence,
+        concept: element.code().map(|code| TranslatedConcept {
+          system: group.source().map(|s| s.to_string()),
+          version: group.source_version().map(|s| s.to_string()),
+          code: Some(code.to_string()),
+          display: element.display().map(|s| s.to_string()),
+        }),
+        products: products_from(&target.depends_on().unwrap_or_default()),
+        source: map.url().map(|s| s.to_string()),
+      });
+    }
+  }
+}
+
+fn invert(equivalence: ConceptMap_TargetEquivalence) -> ConceptMap_TargetEquivalence {
+  match equivalence {
+    ConceptMap_TargetEquivalence::Wider => ConceptMap_TargetEquivalence::Narrower,
+    ConceptMap_TargetEquivalence::Narrower => ConceptMap_TargetEquivalence::Wider,
+    ConceptMap_TargetEquivalence::Subsumes => ConceptMap_TargetEquivalence::Specializes,
+    ConceptMap_TargetEquivalence::Specializes => ConceptMap_TargetEquivalence::Subsumes,
+    other => other,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::ResourceList::ResourceListEnum;
+  use serde_json::Value;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_translate_forward_and_reverse() {
+    let value = load("cm-administrative-gender-v2.json");
+    let map = ConceptMap::new(&value);
+
+    let result = translate(
+      &map,
+      &TranslateRequest::new("http://hl7.org/fhir/administrative-gender", "other"),
+    );
+    assert!(result.result);
+    let codes: Vec<_> = result
+      .matches
+      .iter()
+      .map(|m| m.concept.as_ref().unwrap().code.clone().unwrap())
+      .collect();
+    assert_eq!(codes, vec!["A", "O"]);
+
+    let reverse = translate(
+      &map,
+      &TranslateRequest::new("http://terminology.hl7.org/CodeSystem/v2-0001", "O").reverse(),
+    );
+    assert!(reverse.result);
+    assert_eq!(reverse.matches.len(), 1);
+    assert_eq!(reverse.matches[0].equivalence.to_string(), "narrower");
+    assert_eq!(
+      reverse.matches[0].concept.as_ref().unwrap().code.as_deref(),
+      Some("other")
+    );
+
+    let parameters = reverse.to_parameters();
+    assert!(parameters.validate());
+    let json = parameters.to_json();
+    assert!(matches!(
+      crate::model::ResourceList::ResourceList::new(&json).resource(),
+      Some(ResourceListEnum::ResourceParameters(_))
+    ));
+  }
+
+  #[test]
+  fn test_translate_depends_on_and_other_map() {
+    let first = load("conceptmap-example-2.json");
+    let second = serde_json::json!({
+      "resourceType": "ConceptMap",
+      "url": "http://example.org/fhir/ConceptMap/map2",
+      "group": [{
+        "source": "http://example.org/fhir/example1",
+        "target": "http://example.org/fhir/example4",
+        "element": [{"code": "code", "target": [{"code": "disjoint-code", "equivalence": "disjoint"}]}],
+        "unmapped": {"mode": "fixed", "code": "UNK"}
+      }]
+    });
+    let mut translator = Translator::new();
+    translator
+      .add_map(ConceptMap::new(&first))
+      .add_map(ConceptMap::new(&second));
+
+    let request = TranslateRequest::new("http://example.org/fhir/example1", "code")
+      .target_system("http://example.org/fhir/example2");
+    let result = translator.translate(&request);
+    assert!(!result.result);
+    assert!(result.matches.is_empty());
+
+    let request = request.dependency(
+      "http://example.org/fhir/property-value/example",
+      Some("http://example.org/fhir/example3"),
+      "some-code",
+    );
+    let result = translator.translate(&request);
+    assert!(result.result);
+    assert_eq!(
+      result.matches[0].concept.as_ref().unwrap().code.as_deref(),
+      Some("code2")
+    );
+
+    let result = translator.translate_with(
+      "http://example.org/fhir/ConceptMap/map2",
+      &TranslateRequest::new("http://example.org/fhir/example1", "other"),
+    );
+    assert!(result.result);
+    assert_eq!(
+      result.matches[0].concept.as_ref().unwrap().code.as_deref(),
+      Some("UNK")
+    );
+
+    let result = translator.translate_with(
+      "http://example.org/fhir/ConceptMap/map2",
+      &TranslateRequest::new("http://example.org/fhir/example1", "code"),
+    );
+    assert!(!result.result);
+    assert_eq!(result.matches[0].equivalence.to_string(), "disjoint");
+  }
+}
@@ -0,0 +1,238 @@
+//! Formatting and selection of `Address`.
+
+use super::in_period;
+use crate::model::Address::{Address, AddressType, AddressUse};
+
+/// How the locality lines of an address are laid out in a country. Street
+/// lines always come first and the country last.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AddressLayout {
+  /// "Metropolis, IL 44130", as in the United States, Canada and Australia.
+  CityStatePostal,
+  /// The city, then the postal code on its own line, as in the United
+  /// Kingdom.
+  CityThenPostal,
+  /// "1024 RJ Amsterdam", as in most of continental Europe.
+  PostalCity,
+  /// The postal code, then prefecture and city, then the street lines, as
+  /// in Japan and China.
+  PostalFirst,
+  /// City, district, state and postal code on one line.
+  Generic,
+}
+
+/// The ISO 3166 alpha-2 code for common ways of writing a country.
+fn country_code(country: &str) -> String {
+  let upper = country.trim().to_uppercase();
+  let code = match upper.as_str() {
+    "USA" | "UNITED STATES" | "UNITED STATES OF AMERICA" => "US",
+    "CAN" | "CANADA" => "CA",
+    "AUS" | "AUSTRALIA" => "AU",
+    "GBR" | "UK" | "UNITED KINGDOM" | "ENGLAND" | "SCOTLAND" | "WALES" => "GB",
+    "NLD" | "NETHERLANDS" | "THE NETHERLANDS" | "NEDERLAND" => "NL",
+    "DEU" | "GERMANY" | "DEUTSCHLAND" => "DE",
+    "FRA" | "FRANCE" => "FR",
+    "BEL" | "BELGIUM" => "BE",
+    "CHE" | "SWITZERLAND" => "CH",
+    "AUT" | "AUSTRIA" => "AT",
+    "ITA" | "ITALY" => "IT",
+    "ESP" | "SPAIN" => "ES",
+    "DNK" | "DENMARK" => "DK",
+    "NOR" | "NORWAY" => "NO",
+    "SWE" | "SWEDEN" => "SE",
+    "FIN" | "FINLAND" => "FI",
+    "JPN" | "JAPAN" => "JP",
+    "CHN" | "CHINA" => "CN",
+    other => other,
+  };
+  code.to_string()
+}
+
+impl AddressLayout {
+  /// The layout for a country given as an ISO 3166 code or a common name.
+  pub fn for_country(country: &str) -> AddressLayout {
+    match country_code(country).as_str() {
+      "US" | "CA" | "AU" => AddressLayout::CityStatePostal,
+      "GB" | "IE" => AddressLayout::CityThenPostal,
+      "NL" | "DE" | "FR" | "BE" | "CH" | "AT" | "IT" | "ES" | "DK" | "NO" | "SE" | "FI" | "PL"
+      | "PT" => AddressLayout::PostalCity,
+      "JP" | "CN" => AddressLayout::PostalFirst,
+      _ => AddressLayout::Generic,
+    }
+  }
+}
+
+fn join(parts: &[Option<&str>], separator: &str) -> String {
+  parts
+    .iter()
+    .flatten()
+    .filter(|p| !p.is_empty())
+    .cloned()
+    .collect::<Vec<_>>()
+    .join(separator)
+}
+
+impl Address<'_> {
+  /// The address as lines, laid out for its own `country`. Ignores `text`.
+  pub fn format_lines(&self) -> Vec<String> {
+    let layout = self
+      .country()
+      .map(AddressLayout::for_country)
+      .unwrap_or(AddressLayout::Generic);
+    self.format_lines_with(layout)
+  }
+
+  /// The address as lines in the given layout. Ignores `text`.
+  pub fn format_lines_with(&self, layout: AddressLayout) -> Vec<String> {
+    let street: Vec<String> = self
+      .line()
+      .unwrap_or_default()
+      .iter()
+      .map(|l| l.to_string())
+      .collect();
+    let (city, district, state, postal) = (
+      self.city(),
+      self.district(),
+      self.state(),
+      self.postal_code(),
+    );
+    let mut lines = vec![];
+    match layout {
+      AddressLayout::CityStatePostal => {
+        lines.extend(street);
+        lines.push(join(&[district], ""));
+        let region = join(&[state, postal], " ");
+        lines.push(join(&[city, Some(&region)], ", "));
+      }
+      AddressLayout::CityThenPostal => {
+        lines.extend(street);
+        lines.push(join(&[district], ""));
+        lines.push(join(&[city], ""));
+        lines.push(join(&[state], ""));
+        lines.push(join(&[postal], ""));
+      }
+      AddressLayout::PostalCity => {
+        lines.extend(street);
+        lines.push(join(&[district], ""));
+        lines.push(join(&[postal, city], " "));
+        lines.push(join(&[state], ""));
+      }
+      AddressLayout::PostalFirst => {
+        lines.push(join(&[postal], ""));
+        lines.push(join(&[state, city, district], " "));
+        lines.extend(street);
+      }
+      AddressLayout::Generic => {
+        lines.extend(street);
+        let locality = join(&[city, district], ", ");
+        let region = join(&[state, postal], " ");
+        lines.push(join(&[Some(&locality), Some(&region)], ", "));
+      }
+    }
+    lines.push(join(&[self.country()], ""));
+    lines.retain(|l| !l.is_empty());
+    lines
+  }
+
+  /// `text` if present, otherwise the lines of `format_lines` separated by
+  /// newlines.
+  pub fn format(&self) -> String {
+    match self.text() {
+      Some(text) => text.to_string(),
+      None => self.format_lines().join("\n"),
+    }
+  }
+
+  /// Whether the address is in use at `at`, judging by its `period` and
+  /// `use`.
+  pub fn is_current_at(&self, at: &str) -> bool {
+    !matches!(self.fhir_use(), Some(AddressUse::Old)) && in_period(self.period(), at)
+  }
+}
+
+/// Lower is preferred.
+fn use_rank(address: &Address) -> u8 {
+  let use_rank = match address.fhir_use() {
+    Some(AddressUse::Home) => 0,
+    None => 1,
+    Some(AddressUse::Work) => 2,
+    Some(AddressUse::Temp) => 3,
+    Some(AddressUse::Billing) => 4,
+    Some(AddressUse::Old) => 5,
+  };
+  let type_rank = match address.fhir_type() {
+    Some(AddressType::Postal) => 1,
+    _ => 0,
+  };
+  use_rank * 2 + type_rank
+}
+
+/// The address to use for a person at `at`: a current address before one
+/// whose period has ended, then `home`, unspecified, `work`, `temp`,
+/// `billing` and `old`, physical before postal-only, then the first listed.
+pub fn best_address<'a>(addresses: Vec<Address<'a>>, at: &str) -> Option<Address<'a>> {
+  addresses
+    .into_iter()
+    .enumerate()
+    .min_by_key(|(i, address)| (!address.is_current_at(at), use_rank(address), *i))
+    .map(|(_, address)| address)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use serde_json::{json, Value};
+  use std::fs;
+
+  fn read(file: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", file)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_address_layouts() {
+    let pieter = read("patient-example-f001-pieter.json");
+    let patient = Patient::new(&pieter);
+    let address = patient.current_address_at("2020-01-01").unwrap();
+    assert_eq!(
+      address.format_lines(),
+      vec!["Van Egmondkade 23", "1024 RJ Amsterdam", "NLD"]
+    );
+
+    let xds = read("patient-example-xds.json");
+    let patient = Patient::new(&xds);
+    let address = patient.current_address_at("2020-01-01").unwrap();
+    assert_eq!(address.format(), "100 Main St\nMetropolis, Il 44130\nUSA");
+    assert_eq!(
+      address.format_lines_with(AddressLayout::CityThenPostal),
+      vec!["100 Main St", "Metropolis", "Il", "44130", "USA"]
+    );
+
+    let moved = json!({
+      "address": [
+        {"use": "home", "city": "Leiden", "period": {"end": "2015"}},
+        {"use": "work", "city": "Utrecht"},
+        {"use": "home", "type": "postal", "city": "Delft"},
+        {"use": "home", "type": "physical", "city": "Amsterdam"}
+      ]
+    });
+    let patient = Patient::new(&moved);
+    let city = |at| {
+      patient
+        .current_address_at(at)
+        .unwrap()
+        .city()
+        .map(String::from)
+    };
+    assert_eq!(city("2020"), Some("Amsterdam".to_string()));
+    assert_eq!(city("2010-05-01"), Some("Leiden".to_string()));
+    let old = json!({"city": "Delft", "type": "postal", "use": "old"});
+    assert_eq!(
+      best_address(vec![Address::new(&old)], "2020")
+        .and_then(|a| a.fhir_use())
+        .map(|u| u.to_string()),
+      Some("old".to_string())
+    );
+  }
+}
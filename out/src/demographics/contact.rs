@@ -0,0 +1,109 @@
+//! Formatting and ranking of `ContactPoint`.
+
+use super::in_period;
+use crate::model::ContactPoint::{ContactPoint, ContactPointSystem, ContactPointUse};
+
+impl ContactPoint<'_> {
+  /// The value followed by its use, e.g. "(03) 5555 6473 (work)".
+  pub fn format(&self) -> String {
+    let value = self.value().unwrap_or_default();
+    match self.fhir_use() {
+      Some(contact_use) if !value.is_empty() => format!("{} ({})", value, contact_use.to_string()),
+      _ => value.to_string(),
+    }
+  }
+
+  /// Whether the contact point is in use at `at`, judging by its `period`
+  /// and `use`.
+  pub fn is_current_at(&self, at: &str) -> bool {
+    !matches!(self.fhir_use(), Some(ContactPointUse::Old)) && in_period(self.period(), at)
+  }
+}
+
+fn use_rank(contact: &ContactPoint) -> u8 {
+  match contact.fhir_use() {
+    Some(ContactPointUse::Mobile) | Some(ContactPointUse::Home) => 0,
+    None | Some(ContactPointUse::Work) => 1,
+    Some(ContactPointUse::Temp) => 2,
+    Some(ContactPointUse::Old) => 3,
+  }
+}
+
+fn same_system(a: &ContactPointSystem, b: &ContactPointSystem) -> bool {
+  std::mem::discriminant(a) == std::mem::discriminant(b)
+}
+
+/// The contact points in use at `at` that have a value, most preferred
+/// first: by `rank` (1 is highest; unranked last), then mobile and home
+/// before work and unspecified before temp, then as listed.
+pub fn ranked_contacts<'a>(contacts: Vec<ContactPoint<'a>>, at: &str) -> Vec<ContactPoint<'a>> {
+  let mut current: Vec<(usize, ContactPoint<'a>)> = contacts
+    .into_iter()
+    .filter(|c| c.value().is_some() && c.is_current_at(at))
+    .enumerate()
+    .collect();
+  current.sort_by_key(|(i, c)| {
+    (
+      c.rank().filter(|rank| *rank > 0).unwrap_or(i64::MAX),
+      use_rank(c),
+      *i,
+    )
+  });
+  current.into_iter().map(|(_, c)| c).collect()
+}
+
+/// The most preferred contact point in use at `at`, optionally restricted to
+/// one system; see `ranked_contacts`.
+pub fn best_contact<'a>(
+  contacts: Vec<ContactPoint<'a>>,
+  system: Option<ContactPointSystem>,
+  at: &str,
+) -> Option<ContactPoint<'a>> {
+  ranked_contacts(contacts, at)
+    .into_iter()
+    .find(|c| match (&system, c.system()) {
+      (None, _) => true,
+      (Some(wanted), Some(actual)) => same_system(wanted, &actual),
+      (Some(_), None) => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use crate::model::Practitioner::Practitioner;
+  use serde_json::Value;
+  use std::fs;
+
+  fn read(file: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", file)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_rank_contacts() {
+    let value = read("patient-example.json");
+    let patient = Patient::new(&value);
+    let ranked: Vec<String> = ranked_contacts(patient.telecom().unwrap(), "2020")
+      .iter()
+      .map(|c| c.format())
+      .collect();
+    assert_eq!(
+      ranked,
+      vec!["(03) 5555 6473 (work)", "(03) 3410 5613 (mobile)"]
+    );
+    let phone = patient.preferred_telecom_at(Some(ContactPointSystem::Phone), "2020-01-01");
+    assert_eq!(phone.unwrap().value(), Some("(03) 5555 6473"));
+    assert!(patient
+      .preferred_telecom_at(Some(ContactPointSystem::Email), "2020-01-01")
+      .is_none());
+
+    let value = read("practitioner-example-f001-evdb.json");
+    let practitioner = Practitioner::new(&value);
+    let fax = practitioner.preferred_telecom_at(Some(ContactPointSystem::Fax), "2020-01-01");
+    assert_eq!(fax.unwrap().value(), Some("0205664440"));
+    let name = practitioner.current_name_at("2020-01-01").unwrap();
+    assert_eq!(name.format(), "Eric van den broek MD");
+  }
+}
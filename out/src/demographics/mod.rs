@@ -0,0 +1,81 @@
+//! Formatting of `HumanName`, `Address` and `ContactPoint`, and selection of
+//! the name, address and contact to show for a person.
+//!
+//! Selection takes the point in time to select for as a FHIR `dateTime`;
+//! the `current_*` methods and `preferred_telecom` use the present.
+
+pub mod address;
+pub mod contact;
+pub mod name;
+
+pub use address::{best_address, AddressLayout};
+pub use contact::{best_contact, ranked_contacts};
+pub use name::{best_name, NameOrder};
+
+use crate::model::Address::Address;
+use crate::model::ContactPoint::{ContactPoint, ContactPointSystem};
+use crate::model::HumanName::HumanName;
+use crate::model::Patient::Patient;
+use crate::model::Period::Period;
+use crate::model::Practitioner::Practitioner;
+use crate::model::RelatedPerson::RelatedPerson;
+use crate::temporal::{DateTime, Precision};
+
+/// The present as a UTC `dateTime`.
+pub fn now() -> String {
+  DateTime::now(Precision::Second).to_string()
+}
+
+/// Whether `at` falls within `period`. Values that are missing, invalid or
+/// straddle a bound count as current.
+fn in_period(period: Option<Period>, at: &str) -> bool {
+  match period {
+    Some(period) => period.contains(at) != Some(false),
+    None => true,
+  }
+}
+
+macro_rules! person {
+  ($($name:ident),*) => {
+    $(
+      impl $name<'_> {
+        /// The name to show at `at`; see `best_name`.
+        pub fn current_name_at(&self, at: &str) -> Option<HumanName<'_>> {
+          best_name(self.name()?, at)
+        }
+
+        pub fn current_name(&self) -> Option<HumanName<'_>> {
+          self.current_name_at(&now())
+        }
+
+        /// The address to use at `at`; see `best_address`.
+        pub fn current_address_at(&self, at: &str) -> Option<Address<'_>> {
+          best_address(self.address()?, at)
+        }
+
+        pub fn current_address(&self) -> Option<Address<'_>> {
+          self.current_address_at(&now())
+        }
+
+        /// The preferred contact at `at`, optionally of one system such as
+        /// `Phone`; see `best_contact`.
+        pub fn preferred_telecom_at(
+          &self,
+          system: Option<ContactPointSystem>,
+          at: &str,
+        ) -> Option<ContactPoint<'_>> {
+          best_contact(self.telecom()?, system, at)
+        }
+
+        pub fn preferred_telecom(
+          &self,
+          system: Option<ContactPointSystem>,
+        ) -> Option<ContactPoint<'_>> {
+          self.preferred_telecom_at(system, &now())
+        }
+      }
+    )*
+  };
+}
+
+person!(Patient, Practitioner, RelatedPerson);
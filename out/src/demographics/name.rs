@@ -0,0 +1,160 @@
+//! Formatting and selection of `HumanName`.
+
+use super::in_period;
+use crate::model::HumanName::{HumanName, HumanNameUse};
+
+/// Where the family name goes relative to the given names.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameOrder {
+  /// "Peter James Chalmers".
+  GivenFirst,
+  /// "Chalmers Peter James", as in Chinese, Japanese, Korean, Hungarian and
+  /// Vietnamese.
+  FamilyFirst,
+}
+
+impl NameOrder {
+  /// The conventional order for a BCP 47 language tag such as `ja-JP`.
+  pub fn for_locale(locale: &str) -> NameOrder {
+    let language = locale
+      .split(&['-', '_'][..])
+      .next()
+      .unwrap_or_default()
+      .to_lowercase();
+    match language.as_str() {
+      "zh" | "ja" | "ko" | "hu" | "vi" | "mn" => NameOrder::FamilyFirst,
+      _ => NameOrder::GivenFirst,
+    }
+  }
+}
+
+/// Chinese, Japanese and Korean names are written without spaces.
+fn is_cjk(text: &str) -> bool {
+  text.chars().all(|c| {
+    matches!(c as u32,
+      0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF)
+  })
+}
+
+impl HumanName<'_> {
+  /// `text` if present, otherwise the parts with the given names first.
+  pub fn format(&self) -> String {
+    self.format_with(NameOrder::GivenFirst)
+  }
+
+  /// `text` if present, otherwise prefix, names in the given order and
+  /// suffix.
+  pub fn format_with(&self, order: NameOrder) -> String {
+    if let Some(text) = self.text() {
+      return text.to_string();
+    }
+    let given = self.given().unwrap_or_default();
+    let family: Vec<&str> = self.family().into_iter().collect();
+    let mut parts = self.prefix().unwrap_or_default();
+    match order {
+      NameOrder::GivenFirst => {
+        parts.extend(given);
+        parts.extend(family);
+      }
+      NameOrder::FamilyFirst => {
+        parts.extend(family);
+        parts.extend(given);
+      }
+    }
+    parts.extend(self.suffix().unwrap_or_default());
+    parts.retain(|p| !p.is_empty());
+    let separator = if order == NameOrder::FamilyFirst && parts.iter().all(|p| is_cjk(p)) {
+      ""
+    } else {
+      " "
+    };
+    parts.join(separator)
+  }
+
+  /// Formats the name in the order conventional for `locale`.
+  pub fn format_for(&self, locale: &str) -> String {
+    self.format_with(NameOrder::for_locale(locale))
+  }
+
+  /// "Family, Given Middle", for sorted lists. Ignores `text`, prefixes and
+  /// suffixes.
+  pub fn sort_name(&self) -> String {
+    let given = self.given().unwrap_or_default().join(" ");
+    match self.family() {
+      Some(family) if !given.is_empty() => format!("{}, {}", family, given),
+      Some(family) => family.to_string(),
+      None => given,
+    }
+  }
+
+  /// Whether the name is in use at `at`, judging by its `period` and `use`.
+  pub fn is_current_at(&self, at: &str) -> bool {
+    !matches!(self.fhir_use(), Some(HumanNameUse::Old)) && in_period(self.period(), at)
+  }
+}
+
+/// Lower is preferred. Old and maiden names come last.
+fn use_rank(name: &HumanName) -> u8 {
+  match name.fhir_use() {
+    Some(HumanNameUse::Usual) => 0,
+    Some(HumanNameUse::Official) => 1,
+    None => 2,
+    Some(HumanNameUse::Temp) => 3,
+    Some(HumanNameUse::Nickname) => 4,
+    Some(HumanNameUse::Anonymous) => 5,
+    Some(HumanNameUse::Maiden) => 6,
+    Some(HumanNameUse::Old) => 7,
+  }
+}
+
+/// The name to show for a person at `at`: a current name before one whose
+/// period has ended, then `usual`, `official`, unspecified, `temp`,
+/// `nickname`, `anonymous`, `maiden` and `old`, then the first listed.
+pub fn best_name<'a>(names: Vec<HumanName<'a>>, at: &str) -> Option<HumanName<'a>> {
+  names
+    .into_iter()
+    .enumerate()
+    .min_by_key(|(i, name)| (!name.is_current_at(at), use_rank(name), *i))
+    .map(|(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use serde_json::{json, Value};
+  use std::fs;
+
+  #[test]
+  fn test_format_names() {
+    let contents = fs::read_to_string("examples-json/patient-example.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let patient = Patient::new(&value);
+    let names = patient.name().unwrap();
+    assert_eq!(names[0].format(), "Peter James Chalmers");
+    assert_eq!(names[0].format_for("hu-HU"), "Chalmers Peter James");
+    assert_eq!(names[0].sort_name(), "Chalmers, Peter James");
+    assert!(names[2].is_current_at("2001-06-01"));
+    assert!(!names[2].is_current_at("2010"));
+
+    assert_eq!(
+      patient.current_name_at("2020-01-01").unwrap().format(),
+      "Jim"
+    );
+    let official = json!({
+      "name": [
+        {"use": "official", "family": "Chalmers", "given": ["Peter"]},
+        {"use": "usual", "given": ["Jim"], "period": {"end": "2010"}}
+      ]
+    });
+    let patient = Patient::new(&official);
+    assert_eq!(
+      patient.current_name_at("2020-01-01").unwrap().format(),
+      "Peter Chalmers"
+    );
+    assert_eq!(patient.current_name_at("2005").unwrap().format(), "Jim");
+
+    let japanese = json!({"family": "山田", "given": ["太郎"], "suffix": []});
+    assert_eq!(HumanName::new(&japanese).format_for("ja"), "山田太郎");
+  }
+}
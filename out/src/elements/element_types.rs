@@ -0,0 +1,39917 @@
+// Generated from src/fhir.schema.json by fhir-rs-parser. Do not edit.
+
+/// The names of the FHIR datatypes and resource types.
+pub(crate) const TYPE_NAMES: &[&str] = &[
+    "Account",
+    "ActivityDefinition",
+    "Address",
+    "AdverseEvent",
+    "Age",
+    "AllergyIntolerance",
+    "Annotation",
+    "Appointment",
+    "AppointmentResponse",
+    "Attachment",
+    "AuditEvent",
+    "Basic",
+    "Binary",
+    "BiologicallyDerivedProduct",
+    "BodyStructure",
+    "Bundle",
+    "CapabilityStatement",
+    "CarePlan",
+    "CareTeam",
+    "CatalogEntry",
+    "ChargeItem",
+    "ChargeItemDefinition",
+    "Claim",
+    "ClaimResponse",
+    "ClinicalImpression",
+    "CodeSystem",
+    "CodeableConcept",
+    "Coding",
+    "Communication",
+    "CommunicationRequest",
+    "CompartmentDefinition",
+    "Composition",
+    "ConceptMap",
+    "Condition",
+    "Consent",
+    "ContactDetail",
+    "ContactPoint",
+    "Contract",
+    "Contributor",
+    "Count",
+    "Coverage",
+    "CoverageEligibilityRequest",
+    "CoverageEligibilityResponse",
+    "DataRequirement",
+    "DetectedIssue",
+    "Device",
+    "DeviceDefinition",
+    "DeviceMetric",
+    "DeviceRequest",
+    "DeviceUseStatement",
+    "DiagnosticReport",
+    "Distance",
+    "DocumentManifest",
+    "DocumentReference",
+    "Dosage",
+    "Duration",
+    "EffectEvidenceSynthesis",
+    "Element",
+    "ElementDefinition",
+    "Encounter",
+    "Endpoint",
+    "EnrollmentRequest",
+    "EnrollmentResponse",
+    "EpisodeOfCare",
+    "EventDefinition",
+    "Evidence",
+    "EvidenceVariable",
+    "ExampleScenario",
+    "ExplanationOfBenefit",
+    "Expression",
+    "Extension",
+    "FamilyMemberHistory",
+    "Flag",
+    "Goal",
+    "GraphDefinition",
+    "Group",
+    "GuidanceResponse",
+    "HealthcareService",
+    "HumanName",
+    "Identifier",
+    "ImagingStudy",
+    "Immunization",
+    "ImmunizationEvaluation",
+    "ImmunizationRecommendation",
+    "ImplementationGuide",
+    "InsurancePlan",
+    "Invoice",
+    "Library",
+    "Linkage",
+    "List",
+    "Location",
+    "MarketingStatus",
+    "Measure",
+    "MeasureReport",
+    "Media",
+    "Medication",
+    "MedicationAdministration",
+    "MedicationDispense",
+    "MedicationKnowledge",
+    "MedicationRequest",
+    "MedicationStatement",
+    "MedicinalProduct",
+    "MedicinalProductAuthorization",
+    "MedicinalProductContraindication",
+    "MedicinalProductIndication",
+    "MedicinalProductIngredient",
+    "MedicinalProductInteraction",
+    "MedicinalProductManufactured",
+    "MedicinalProductPackaged",
+    "MedicinalProductPharmaceutical",
+    "MedicinalProductUndesirableEffect",
+    "MessageDefinition",
+    "MessageHeader",
+    "Meta",
+    "MolecularSequence",
+    "Money",
+    "NamingSystem",
+    "Narrative",
+    "NutritionOrder",
+    "Observation",
+    "ObservationDefinition",
+    "OperationDefinition",
+    "OperationOutcome",
+    "Organization",
+    "OrganizationAffiliation",
+    "ParameterDefinition",
+    "Parameters",
+    "Patient",
+    "PaymentNotice",
+    "PaymentReconciliation",
+    "Period",
+    "Person",
+    "PlanDefinition",
+    "Population",
+    "Practitioner",
+    "PractitionerRole",
+    "Procedure",
+    "ProdCharacteristic",
+    "ProductShelfLife",
+    "Provenance",
+    "Quantity",
+    "Questionnaire",
+    "QuestionnaireResponse",
+    "Range",
+    "Ratio",
+    "Reference",
+    "RelatedArtifact",
+    "RelatedPerson",
+    "RequestGroup",
+    "ResearchDefinition",
+    "ResearchElementDefinition",
+    "ResearchStudy",
+    "ResearchSubject",
+    "RiskAssessment",
+    "RiskEvidenceSynthesis",
+    "SampledData",
+    "Schedule",
+    "SearchParameter",
+    "ServiceRequest",
+    "Signature",
+    "Slot",
+    "Specimen",
+    "SpecimenDefinition",
+    "StructureDefinition",
+    "StructureMap",
+    "Subscription",
+    "Substance",
+    "SubstanceAmount",
+    "SubstanceNucleicAcid",
+    "SubstancePolymer",
+    "SubstanceProtein",
+    "SubstanceReferenceInformation",
+    "SubstanceSourceMaterial",
+    "SubstanceSpecification",
+    "SupplyDelivery",
+    "SupplyRequest",
+    "Task",
+    "TerminologyCapabilities",
+    "TestReport",
+    "TestScript",
+    "Timing",
+    "TriggerDefinition",
+    "UsageContext",
+    "ValueSet",
+    "VerificationResult",
+    "VisionPrescription",
+    "base64Binary",
+    "boolean",
+    "canonical",
+    "code",
+    "date",
+    "dateTime",
+    "decimal",
+    "id",
+    "instant",
+    "integer",
+    "markdown",
+    "oid",
+    "positiveInt",
+    "string",
+    "time",
+    "unsignedInt",
+    "uri",
+    "url",
+    "uuid",
+    "xhtml",
+];
+
+/// `(type, JSON key, element, element type, repeats)` for the elements of
+/// every type, sorted by type and key. The key of a choice element names
+/// its type, as `deceasedBoolean` does for the element `deceased`.
+pub(crate) const ELEMENTS: &[(&str, &str, &str, &str, bool)] = &[
+    ("Account", "contained", "contained", "ResourceList", true),
+    ("Account", "coverage", "coverage", "Account_Coverage", true),
+    ("Account", "description", "description", "string", false),
+    ("Account", "extension", "extension", "Extension", true),
+    (
+        "Account",
+        "guarantor",
+        "guarantor",
+        "Account_Guarantor",
+        true,
+    ),
+    ("Account", "id", "id", "id", false),
+    ("Account", "identifier", "identifier", "Identifier", true),
+    ("Account", "implicitRules", "implicitRules", "uri", false),
+    ("Account", "language", "language", "code", false),
+    ("Account", "meta", "meta", "Meta", false),
+    (
+        "Account",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Account", "name", "name", "string", false),
+    ("Account", "owner", "owner", "Reference", false),
+    ("Account", "partOf", "partOf", "Reference", false),
+    ("Account", "servicePeriod", "servicePeriod", "Period", false),
+    ("Account", "status", "status", "code", false),
+    ("Account", "subject", "subject", "Reference", true),
+    ("Account", "text", "text", "Narrative", false),
+    ("Account", "type", "type", "CodeableConcept", false),
+    (
+        "Account_Coverage",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    (
+        "Account_Coverage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Account_Coverage", "id", "id", "string", false),
+    (
+        "Account_Coverage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Account_Coverage",
+        "priority",
+        "priority",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Account_Guarantor",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Account_Guarantor", "id", "id", "string", false),
+    (
+        "Account_Guarantor",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Account_Guarantor", "onHold", "onHold", "boolean", false),
+    ("Account_Guarantor", "party", "party", "Reference", false),
+    ("Account_Guarantor", "period", "period", "Period", false),
+    (
+        "ActivityDefinition",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "author",
+        "author",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("ActivityDefinition", "date", "date", "dateTime", false),
+    (
+        "ActivityDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "doNotPerform",
+        "doNotPerform",
+        "boolean",
+        false,
+    ),
+    ("ActivityDefinition", "dosage", "dosage", "Dosage", true),
+    (
+        "ActivityDefinition",
+        "dynamicValue",
+        "dynamicValue",
+        "ActivityDefinition_DynamicValue",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "editor",
+        "editor",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ActivityDefinition", "id", "id", "id", false),
+    (
+        "ActivityDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("ActivityDefinition", "intent", "intent", "code", false),
+    (
+        "ActivityDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("ActivityDefinition", "kind", "kind", "code", false),
+    ("ActivityDefinition", "language", "language", "code", false),
+    (
+        "ActivityDefinition",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "library",
+        "library",
+        "canonical",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "location",
+        "location",
+        "Reference",
+        false,
+    ),
+    ("ActivityDefinition", "meta", "meta", "Meta", false),
+    (
+        "ActivityDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ActivityDefinition", "name", "name", "string", false),
+    (
+        "ActivityDefinition",
+        "observationRequirement",
+        "observationRequirement",
+        "Reference",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "observationResultRequirement",
+        "observationResultRequirement",
+        "Reference",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "participant",
+        "participant",
+        "ActivityDefinition_Participant",
+        true,
+    ),
+    ("ActivityDefinition", "priority", "priority", "code", false),
+    (
+        "ActivityDefinition",
+        "productCodeableConcept",
+        "product",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "productReference",
+        "product",
+        "Reference",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "specimenRequirement",
+        "specimenRequirement",
+        "Reference",
+        true,
+    ),
+    ("ActivityDefinition", "status", "status", "code", false),
+    (
+        "ActivityDefinition",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "subtitle",
+        "subtitle",
+        "string",
+        false,
+    ),
+    ("ActivityDefinition", "text", "text", "Narrative", false),
+    ("ActivityDefinition", "timingAge", "timing", "Age", false),
+    (
+        "ActivityDefinition",
+        "timingDateTime",
+        "timing",
+        "dateTime",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "timingDuration",
+        "timing",
+        "Duration",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "timingPeriod",
+        "timing",
+        "Period",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "timingRange",
+        "timing",
+        "Range",
+        false,
+    ),
+    (
+        "ActivityDefinition",
+        "timingTiming",
+        "timing",
+        "Timing",
+        false,
+    ),
+    ("ActivityDefinition", "title", "title", "string", false),
+    (
+        "ActivityDefinition",
+        "topic",
+        "topic",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ActivityDefinition",
+        "transform",
+        "transform",
+        "canonical",
+        false,
+    ),
+    ("ActivityDefinition", "url", "url", "uri", false),
+    ("ActivityDefinition", "usage", "usage", "string", false),
+    (
+        "ActivityDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("ActivityDefinition", "version", "version", "string", false),
+    (
+        "ActivityDefinition_DynamicValue",
+        "expression",
+        "expression",
+        "Expression",
+        false,
+    ),
+    (
+        "ActivityDefinition_DynamicValue",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ActivityDefinition_DynamicValue",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ActivityDefinition_DynamicValue",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ActivityDefinition_DynamicValue",
+        "path",
+        "path",
+        "string",
+        false,
+    ),
+    (
+        "ActivityDefinition_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ActivityDefinition_Participant",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ActivityDefinition_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ActivityDefinition_Participant",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ActivityDefinition_Participant",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    ("Address", "city", "city", "string", false),
+    ("Address", "country", "country", "string", false),
+    ("Address", "district", "district", "string", false),
+    ("Address", "extension", "extension", "Extension", true),
+    ("Address", "id", "id", "string", false),
+    ("Address", "line", "line", "string", true),
+    ("Address", "period", "period", "Period", false),
+    ("Address", "postalCode", "postalCode", "string", false),
+    ("Address", "state", "state", "string", false),
+    ("Address", "text", "text", "string", false),
+    ("Address", "type", "type", "code", false),
+    ("Address", "use", "use", "code", false),
+    ("AdverseEvent", "actuality", "actuality", "code", false),
+    (
+        "AdverseEvent",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "AdverseEvent",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "AdverseEvent",
+        "contributor",
+        "contributor",
+        "Reference",
+        true,
+    ),
+    ("AdverseEvent", "date", "date", "dateTime", false),
+    ("AdverseEvent", "detected", "detected", "dateTime", false),
+    ("AdverseEvent", "encounter", "encounter", "Reference", false),
+    ("AdverseEvent", "event", "event", "CodeableConcept", false),
+    ("AdverseEvent", "extension", "extension", "Extension", true),
+    ("AdverseEvent", "id", "id", "id", false),
+    (
+        "AdverseEvent",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "AdverseEvent",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("AdverseEvent", "language", "language", "code", false),
+    ("AdverseEvent", "location", "location", "Reference", false),
+    ("AdverseEvent", "meta", "meta", "Meta", false),
+    (
+        "AdverseEvent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "AdverseEvent",
+        "outcome",
+        "outcome",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AdverseEvent",
+        "recordedDate",
+        "recordedDate",
+        "dateTime",
+        false,
+    ),
+    ("AdverseEvent", "recorder", "recorder", "Reference", false),
+    (
+        "AdverseEvent",
+        "referenceDocument",
+        "referenceDocument",
+        "Reference",
+        true,
+    ),
+    (
+        "AdverseEvent",
+        "resultingCondition",
+        "resultingCondition",
+        "Reference",
+        true,
+    ),
+    (
+        "AdverseEvent",
+        "seriousness",
+        "seriousness",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AdverseEvent",
+        "severity",
+        "severity",
+        "CodeableConcept",
+        false,
+    ),
+    ("AdverseEvent", "study", "study", "Reference", true),
+    ("AdverseEvent", "subject", "subject", "Reference", false),
+    (
+        "AdverseEvent",
+        "subjectMedicalHistory",
+        "subjectMedicalHistory",
+        "Reference",
+        true,
+    ),
+    (
+        "AdverseEvent",
+        "suspectEntity",
+        "suspectEntity",
+        "AdverseEvent_SuspectEntity",
+        true,
+    ),
+    ("AdverseEvent", "text", "text", "Narrative", false),
+    (
+        "AdverseEvent_Causality",
+        "assessment",
+        "assessment",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AdverseEvent_Causality",
+        "author",
+        "author",
+        "Reference",
+        false,
+    ),
+    (
+        "AdverseEvent_Causality",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AdverseEvent_Causality", "id", "id", "string", false),
+    (
+        "AdverseEvent_Causality",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AdverseEvent_Causality",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "AdverseEvent_Causality",
+        "productRelatedness",
+        "productRelatedness",
+        "string",
+        false,
+    ),
+    (
+        "AdverseEvent_SuspectEntity",
+        "causality",
+        "causality",
+        "AdverseEvent_Causality",
+        true,
+    ),
+    (
+        "AdverseEvent_SuspectEntity",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AdverseEvent_SuspectEntity", "id", "id", "string", false),
+    (
+        "AdverseEvent_SuspectEntity",
+        "instance",
+        "instance",
+        "Reference",
+        false,
+    ),
+    (
+        "AdverseEvent_SuspectEntity",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Age", "code", "code", "code", false),
+    ("Age", "comparator", "comparator", "code", false),
+    ("Age", "extension", "extension", "Extension", true),
+    ("Age", "id", "id", "string", false),
+    ("Age", "system", "system", "uri", false),
+    ("Age", "unit", "unit", "string", false),
+    ("Age", "value", "value", "decimal", false),
+    (
+        "AllergyIntolerance",
+        "asserter",
+        "asserter",
+        "Reference",
+        false,
+    ),
+    ("AllergyIntolerance", "category", "category", "code", true),
+    (
+        "AllergyIntolerance",
+        "clinicalStatus",
+        "clinicalStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "AllergyIntolerance",
+        "criticality",
+        "criticality",
+        "code",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AllergyIntolerance", "id", "id", "id", false),
+    (
+        "AllergyIntolerance",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "AllergyIntolerance",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("AllergyIntolerance", "language", "language", "code", false),
+    (
+        "AllergyIntolerance",
+        "lastOccurrence",
+        "lastOccurrence",
+        "dateTime",
+        false,
+    ),
+    ("AllergyIntolerance", "meta", "meta", "Meta", false),
+    (
+        "AllergyIntolerance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("AllergyIntolerance", "note", "note", "Annotation", true),
+    ("AllergyIntolerance", "onsetAge", "onset", "Age", false),
+    (
+        "AllergyIntolerance",
+        "onsetDateTime",
+        "onset",
+        "dateTime",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "onsetPeriod",
+        "onset",
+        "Period",
+        false,
+    ),
+    ("AllergyIntolerance", "onsetRange", "onset", "Range", false),
+    (
+        "AllergyIntolerance",
+        "onsetString",
+        "onset",
+        "string",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "reaction",
+        "reaction",
+        "AllergyIntolerance_Reaction",
+        true,
+    ),
+    (
+        "AllergyIntolerance",
+        "recordedDate",
+        "recordedDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "AllergyIntolerance",
+        "recorder",
+        "recorder",
+        "Reference",
+        false,
+    ),
+    ("AllergyIntolerance", "text", "text", "Narrative", false),
+    ("AllergyIntolerance", "type", "type", "code", false),
+    (
+        "AllergyIntolerance",
+        "verificationStatus",
+        "verificationStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "exposureRoute",
+        "exposureRoute",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AllergyIntolerance_Reaction", "id", "id", "string", false),
+    (
+        "AllergyIntolerance_Reaction",
+        "manifestation",
+        "manifestation",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "onset",
+        "onset",
+        "dateTime",
+        false,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "severity",
+        "severity",
+        "code",
+        false,
+    ),
+    (
+        "AllergyIntolerance_Reaction",
+        "substance",
+        "substance",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Annotation",
+        "authorReference",
+        "author",
+        "Reference",
+        false,
+    ),
+    ("Annotation", "authorString", "author", "string", false),
+    ("Annotation", "extension", "extension", "Extension", true),
+    ("Annotation", "id", "id", "string", false),
+    ("Annotation", "text", "text", "markdown", false),
+    ("Annotation", "time", "time", "dateTime", false),
+    (
+        "Appointment",
+        "appointmentType",
+        "appointmentType",
+        "CodeableConcept",
+        false,
+    ),
+    ("Appointment", "basedOn", "basedOn", "Reference", true),
+    (
+        "Appointment",
+        "cancelationReason",
+        "cancelationReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("Appointment", "comment", "comment", "string", false),
+    (
+        "Appointment",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("Appointment", "created", "created", "dateTime", false),
+    ("Appointment", "description", "description", "string", false),
+    ("Appointment", "end", "end", "instant", false),
+    ("Appointment", "extension", "extension", "Extension", true),
+    ("Appointment", "id", "id", "id", false),
+    (
+        "Appointment",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Appointment",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("Appointment", "language", "language", "code", false),
+    ("Appointment", "meta", "meta", "Meta", false),
+    (
+        "Appointment",
+        "minutesDuration",
+        "minutesDuration",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Appointment",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Appointment",
+        "participant",
+        "participant",
+        "Appointment_Participant",
+        true,
+    ),
+    (
+        "Appointment",
+        "patientInstruction",
+        "patientInstruction",
+        "string",
+        false,
+    ),
+    ("Appointment", "priority", "priority", "unsignedInt", false),
+    (
+        "Appointment",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Appointment",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "Appointment",
+        "requestedPeriod",
+        "requestedPeriod",
+        "Period",
+        true,
+    ),
+    (
+        "Appointment",
+        "serviceCategory",
+        "serviceCategory",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Appointment",
+        "serviceType",
+        "serviceType",
+        "CodeableConcept",
+        true,
+    ),
+    ("Appointment", "slot", "slot", "Reference", true),
+    (
+        "Appointment",
+        "specialty",
+        "specialty",
+        "CodeableConcept",
+        true,
+    ),
+    ("Appointment", "start", "start", "instant", false),
+    ("Appointment", "status", "status", "code", false),
+    (
+        "Appointment",
+        "supportingInformation",
+        "supportingInformation",
+        "Reference",
+        true,
+    ),
+    ("Appointment", "text", "text", "Narrative", false),
+    ("AppointmentResponse", "actor", "actor", "Reference", false),
+    (
+        "AppointmentResponse",
+        "appointment",
+        "appointment",
+        "Reference",
+        false,
+    ),
+    ("AppointmentResponse", "comment", "comment", "string", false),
+    (
+        "AppointmentResponse",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("AppointmentResponse", "end", "end", "instant", false),
+    (
+        "AppointmentResponse",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AppointmentResponse", "id", "id", "id", false),
+    (
+        "AppointmentResponse",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "AppointmentResponse",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("AppointmentResponse", "language", "language", "code", false),
+    ("AppointmentResponse", "meta", "meta", "Meta", false),
+    (
+        "AppointmentResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "AppointmentResponse",
+        "participantStatus",
+        "participantStatus",
+        "code",
+        false,
+    ),
+    (
+        "AppointmentResponse",
+        "participantType",
+        "participantType",
+        "CodeableConcept",
+        true,
+    ),
+    ("AppointmentResponse", "start", "start", "instant", false),
+    ("AppointmentResponse", "text", "text", "Narrative", false),
+    (
+        "Appointment_Participant",
+        "actor",
+        "actor",
+        "Reference",
+        false,
+    ),
+    (
+        "Appointment_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Appointment_Participant", "id", "id", "string", false),
+    (
+        "Appointment_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Appointment_Participant",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    (
+        "Appointment_Participant",
+        "required",
+        "required",
+        "code",
+        false,
+    ),
+    ("Appointment_Participant", "status", "status", "code", false),
+    (
+        "Appointment_Participant",
+        "type",
+        "type",
+        "CodeableConcept",
+        true,
+    ),
+    ("Attachment", "contentType", "contentType", "code", false),
+    ("Attachment", "creation", "creation", "dateTime", false),
+    ("Attachment", "data", "data", "base64Binary", false),
+    ("Attachment", "extension", "extension", "Extension", true),
+    ("Attachment", "hash", "hash", "base64Binary", false),
+    ("Attachment", "id", "id", "string", false),
+    ("Attachment", "language", "language", "code", false),
+    ("Attachment", "size", "size", "unsignedInt", false),
+    ("Attachment", "title", "title", "string", false),
+    ("Attachment", "url", "url", "url", false),
+    ("AuditEvent", "action", "action", "code", false),
+    ("AuditEvent", "agent", "agent", "AuditEvent_Agent", true),
+    ("AuditEvent", "contained", "contained", "ResourceList", true),
+    ("AuditEvent", "entity", "entity", "AuditEvent_Entity", true),
+    ("AuditEvent", "extension", "extension", "Extension", true),
+    ("AuditEvent", "id", "id", "id", false),
+    ("AuditEvent", "implicitRules", "implicitRules", "uri", false),
+    ("AuditEvent", "language", "language", "code", false),
+    ("AuditEvent", "meta", "meta", "Meta", false),
+    (
+        "AuditEvent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent", "outcome", "outcome", "code", false),
+    ("AuditEvent", "outcomeDesc", "outcomeDesc", "string", false),
+    ("AuditEvent", "period", "period", "Period", false),
+    (
+        "AuditEvent",
+        "purposeOfEvent",
+        "purposeOfEvent",
+        "CodeableConcept",
+        true,
+    ),
+    ("AuditEvent", "recorded", "recorded", "instant", false),
+    ("AuditEvent", "source", "source", "AuditEvent_Source", false),
+    ("AuditEvent", "subtype", "subtype", "Coding", true),
+    ("AuditEvent", "text", "text", "Narrative", false),
+    ("AuditEvent", "type", "type", "Coding", false),
+    ("AuditEvent_Agent", "altId", "altId", "string", false),
+    (
+        "AuditEvent_Agent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Agent", "id", "id", "string", false),
+    (
+        "AuditEvent_Agent",
+        "location",
+        "location",
+        "Reference",
+        false,
+    ),
+    ("AuditEvent_Agent", "media", "media", "Coding", false),
+    (
+        "AuditEvent_Agent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Agent", "name", "name", "string", false),
+    (
+        "AuditEvent_Agent",
+        "network",
+        "network",
+        "AuditEvent_Network",
+        false,
+    ),
+    ("AuditEvent_Agent", "policy", "policy", "uri", true),
+    (
+        "AuditEvent_Agent",
+        "purposeOfUse",
+        "purposeOfUse",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "AuditEvent_Agent",
+        "requestor",
+        "requestor",
+        "boolean",
+        false,
+    ),
+    ("AuditEvent_Agent", "role", "role", "CodeableConcept", true),
+    ("AuditEvent_Agent", "type", "type", "CodeableConcept", false),
+    ("AuditEvent_Agent", "who", "who", "Reference", false),
+    (
+        "AuditEvent_Detail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Detail", "id", "id", "string", false),
+    (
+        "AuditEvent_Detail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Detail", "type", "type", "string", false),
+    (
+        "AuditEvent_Detail",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    ("AuditEvent_Detail", "valueString", "value", "string", false),
+    (
+        "AuditEvent_Entity",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "AuditEvent_Entity",
+        "detail",
+        "detail",
+        "AuditEvent_Detail",
+        true,
+    ),
+    (
+        "AuditEvent_Entity",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Entity", "id", "id", "string", false),
+    (
+        "AuditEvent_Entity",
+        "lifecycle",
+        "lifecycle",
+        "Coding",
+        false,
+    ),
+    (
+        "AuditEvent_Entity",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Entity", "name", "name", "string", false),
+    ("AuditEvent_Entity", "query", "query", "base64Binary", false),
+    ("AuditEvent_Entity", "role", "role", "Coding", false),
+    (
+        "AuditEvent_Entity",
+        "securityLabel",
+        "securityLabel",
+        "Coding",
+        true,
+    ),
+    ("AuditEvent_Entity", "type", "type", "Coding", false),
+    ("AuditEvent_Entity", "what", "what", "Reference", false),
+    ("AuditEvent_Network", "address", "address", "string", false),
+    (
+        "AuditEvent_Network",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Network", "id", "id", "string", false),
+    (
+        "AuditEvent_Network",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Network", "type", "type", "code", false),
+    (
+        "AuditEvent_Source",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("AuditEvent_Source", "id", "id", "string", false),
+    (
+        "AuditEvent_Source",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "AuditEvent_Source",
+        "observer",
+        "observer",
+        "Reference",
+        false,
+    ),
+    ("AuditEvent_Source", "site", "site", "string", false),
+    ("AuditEvent_Source", "type", "type", "Coding", true),
+    ("Basic", "author", "author", "Reference", false),
+    ("Basic", "code", "code", "CodeableConcept", false),
+    ("Basic", "contained", "contained", "ResourceList", true),
+    ("Basic", "created", "created", "date", false),
+    ("Basic", "extension", "extension", "Extension", true),
+    ("Basic", "id", "id", "id", false),
+    ("Basic", "identifier", "identifier", "Identifier", true),
+    ("Basic", "implicitRules", "implicitRules", "uri", false),
+    ("Basic", "language", "language", "code", false),
+    ("Basic", "meta", "meta", "Meta", false),
+    (
+        "Basic",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Basic", "subject", "subject", "Reference", false),
+    ("Basic", "text", "text", "Narrative", false),
+    ("Binary", "contentType", "contentType", "code", false),
+    ("Binary", "data", "data", "base64Binary", false),
+    ("Binary", "id", "id", "id", false),
+    ("Binary", "implicitRules", "implicitRules", "uri", false),
+    ("Binary", "language", "language", "code", false),
+    ("Binary", "meta", "meta", "Meta", false),
+    (
+        "Binary",
+        "securityContext",
+        "securityContext",
+        "Reference",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "collection",
+        "collection",
+        "BiologicallyDerivedProduct_Collection",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("BiologicallyDerivedProduct", "id", "id", "id", false),
+    (
+        "BiologicallyDerivedProduct",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "manipulation",
+        "manipulation",
+        "BiologicallyDerivedProduct_Manipulation",
+        false,
+    ),
+    ("BiologicallyDerivedProduct", "meta", "meta", "Meta", false),
+    (
+        "BiologicallyDerivedProduct",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "parent",
+        "parent",
+        "Reference",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "processing",
+        "processing",
+        "BiologicallyDerivedProduct_Processing",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "productCategory",
+        "productCategory",
+        "code",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "productCode",
+        "productCode",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "quantity",
+        "quantity",
+        "integer",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "request",
+        "request",
+        "Reference",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "status",
+        "status",
+        "code",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "storage",
+        "storage",
+        "BiologicallyDerivedProduct_Storage",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "collectedDateTime",
+        "collected",
+        "dateTime",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "collectedPeriod",
+        "collected",
+        "Period",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "collector",
+        "collector",
+        "Reference",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Collection",
+        "source",
+        "source",
+        "Reference",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Manipulation",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Manipulation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Manipulation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Manipulation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Manipulation",
+        "timeDateTime",
+        "time",
+        "dateTime",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Manipulation",
+        "timePeriod",
+        "time",
+        "Period",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "additive",
+        "additive",
+        "Reference",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "procedure",
+        "procedure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "timeDateTime",
+        "time",
+        "dateTime",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Processing",
+        "timePeriod",
+        "time",
+        "Period",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "duration",
+        "duration",
+        "Period",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "scale",
+        "scale",
+        "code",
+        false,
+    ),
+    (
+        "BiologicallyDerivedProduct_Storage",
+        "temperature",
+        "temperature",
+        "decimal",
+        false,
+    ),
+    ("BodyStructure", "active", "active", "boolean", false),
+    (
+        "BodyStructure",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "BodyStructure",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    ("BodyStructure", "extension", "extension", "Extension", true),
+    ("BodyStructure", "id", "id", "id", false),
+    (
+        "BodyStructure",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    ("BodyStructure", "image", "image", "Attachment", true),
+    (
+        "BodyStructure",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("BodyStructure", "language", "language", "code", false),
+    (
+        "BodyStructure",
+        "location",
+        "location",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "BodyStructure",
+        "locationQualifier",
+        "locationQualifier",
+        "CodeableConcept",
+        true,
+    ),
+    ("BodyStructure", "meta", "meta", "Meta", false),
+    (
+        "BodyStructure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "BodyStructure",
+        "morphology",
+        "morphology",
+        "CodeableConcept",
+        false,
+    ),
+    ("BodyStructure", "patient", "patient", "Reference", false),
+    ("BodyStructure", "text", "text", "Narrative", false),
+    ("Bundle", "entry", "entry", "Bundle_Entry", true),
+    ("Bundle", "id", "id", "id", false),
+    ("Bundle", "identifier", "identifier", "Identifier", false),
+    ("Bundle", "implicitRules", "implicitRules", "uri", false),
+    ("Bundle", "language", "language", "code", false),
+    ("Bundle", "link", "link", "Bundle_Link", true),
+    ("Bundle", "meta", "meta", "Meta", false),
+    ("Bundle", "signature", "signature", "Signature", false),
+    ("Bundle", "timestamp", "timestamp", "instant", false),
+    ("Bundle", "total", "total", "unsignedInt", false),
+    ("Bundle", "type", "type", "code", false),
+    ("Bundle_Entry", "extension", "extension", "Extension", true),
+    ("Bundle_Entry", "fullUrl", "fullUrl", "uri", false),
+    ("Bundle_Entry", "id", "id", "string", false),
+    ("Bundle_Entry", "link", "link", "Bundle_Link", true),
+    (
+        "Bundle_Entry",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Bundle_Entry",
+        "request",
+        "request",
+        "Bundle_Request",
+        false,
+    ),
+    (
+        "Bundle_Entry",
+        "resource",
+        "resource",
+        "ResourceList",
+        false,
+    ),
+    (
+        "Bundle_Entry",
+        "response",
+        "response",
+        "Bundle_Response",
+        false,
+    ),
+    ("Bundle_Entry", "search", "search", "Bundle_Search", false),
+    ("Bundle_Link", "extension", "extension", "Extension", true),
+    ("Bundle_Link", "id", "id", "string", false),
+    (
+        "Bundle_Link",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Bundle_Link", "relation", "relation", "string", false),
+    ("Bundle_Link", "url", "url", "uri", false),
+    (
+        "Bundle_Request",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Bundle_Request", "id", "id", "string", false),
+    ("Bundle_Request", "ifMatch", "ifMatch", "string", false),
+    (
+        "Bundle_Request",
+        "ifModifiedSince",
+        "ifModifiedSince",
+        "instant",
+        false,
+    ),
+    (
+        "Bundle_Request",
+        "ifNoneExist",
+        "ifNoneExist",
+        "string",
+        false,
+    ),
+    (
+        "Bundle_Request",
+        "ifNoneMatch",
+        "ifNoneMatch",
+        "string",
+        false,
+    ),
+    ("Bundle_Request", "method", "method", "code", false),
+    (
+        "Bundle_Request",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Bundle_Request", "url", "url", "uri", false),
+    ("Bundle_Response", "etag", "etag", "string", false),
+    (
+        "Bundle_Response",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Bundle_Response", "id", "id", "string", false),
+    (
+        "Bundle_Response",
+        "lastModified",
+        "lastModified",
+        "instant",
+        false,
+    ),
+    ("Bundle_Response", "location", "location", "uri", false),
+    (
+        "Bundle_Response",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Bundle_Response",
+        "outcome",
+        "outcome",
+        "ResourceList",
+        false,
+    ),
+    ("Bundle_Response", "status", "status", "string", false),
+    ("Bundle_Search", "extension", "extension", "Extension", true),
+    ("Bundle_Search", "id", "id", "string", false),
+    ("Bundle_Search", "mode", "mode", "code", false),
+    (
+        "Bundle_Search",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Bundle_Search", "score", "score", "decimal", false),
+    (
+        "CapabilityStatement",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("CapabilityStatement", "date", "date", "dateTime", false),
+    (
+        "CapabilityStatement",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement",
+        "document",
+        "document",
+        "CapabilityStatement_Document",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "CapabilityStatement",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "fhirVersion",
+        "fhirVersion",
+        "code",
+        false,
+    ),
+    ("CapabilityStatement", "format", "format", "code", true),
+    ("CapabilityStatement", "id", "id", "id", false),
+    (
+        "CapabilityStatement",
+        "implementation",
+        "implementation",
+        "CapabilityStatement_Implementation",
+        false,
+    ),
+    (
+        "CapabilityStatement",
+        "implementationGuide",
+        "implementationGuide",
+        "canonical",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "CapabilityStatement",
+        "imports",
+        "imports",
+        "canonical",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "instantiates",
+        "instantiates",
+        "canonical",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("CapabilityStatement", "kind", "kind", "code", false),
+    ("CapabilityStatement", "language", "language", "code", false),
+    (
+        "CapabilityStatement",
+        "messaging",
+        "messaging",
+        "CapabilityStatement_Messaging",
+        true,
+    ),
+    ("CapabilityStatement", "meta", "meta", "Meta", false),
+    (
+        "CapabilityStatement",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement", "name", "name", "string", false),
+    (
+        "CapabilityStatement",
+        "patchFormat",
+        "patchFormat",
+        "code",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement",
+        "rest",
+        "rest",
+        "CapabilityStatement_Rest",
+        true,
+    ),
+    (
+        "CapabilityStatement",
+        "software",
+        "software",
+        "CapabilityStatement_Software",
+        false,
+    ),
+    ("CapabilityStatement", "status", "status", "code", false),
+    ("CapabilityStatement", "text", "text", "Narrative", false),
+    ("CapabilityStatement", "title", "title", "string", false),
+    ("CapabilityStatement", "url", "url", "uri", false),
+    (
+        "CapabilityStatement",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("CapabilityStatement", "version", "version", "string", false),
+    (
+        "CapabilityStatement_Document",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Document",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Document", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Document",
+        "mode",
+        "mode",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Document",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Document",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    (
+        "CapabilityStatement_Endpoint",
+        "address",
+        "address",
+        "url",
+        false,
+    ),
+    (
+        "CapabilityStatement_Endpoint",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Endpoint", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Endpoint",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Endpoint",
+        "protocol",
+        "protocol",
+        "Coding",
+        false,
+    ),
+    (
+        "CapabilityStatement_Implementation",
+        "custodian",
+        "custodian",
+        "Reference",
+        false,
+    ),
+    (
+        "CapabilityStatement_Implementation",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_Implementation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Implementation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_Implementation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Implementation",
+        "url",
+        "url",
+        "url",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction",
+        "code",
+        "code",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Interaction",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Interaction1",
+        "code",
+        "code",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction1",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Interaction1",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_Interaction1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Messaging",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Messaging",
+        "endpoint",
+        "endpoint",
+        "CapabilityStatement_Endpoint",
+        true,
+    ),
+    (
+        "CapabilityStatement_Messaging",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Messaging", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Messaging",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Messaging",
+        "reliableCache",
+        "reliableCache",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "CapabilityStatement_Messaging",
+        "supportedMessage",
+        "supportedMessage",
+        "CapabilityStatement_SupportedMessage",
+        true,
+    ),
+    (
+        "CapabilityStatement_Operation",
+        "definition",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "CapabilityStatement_Operation",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Operation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Operation", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Operation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Operation",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "conditionalCreate",
+        "conditionalCreate",
+        "boolean",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "conditionalDelete",
+        "conditionalDelete",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "conditionalRead",
+        "conditionalRead",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "conditionalUpdate",
+        "conditionalUpdate",
+        "boolean",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Resource", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Resource",
+        "interaction",
+        "interaction",
+        "CapabilityStatement_Interaction",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "operation",
+        "operation",
+        "CapabilityStatement_Operation",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "readHistory",
+        "readHistory",
+        "boolean",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "referencePolicy",
+        "referencePolicy",
+        "code",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "searchInclude",
+        "searchInclude",
+        "string",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "searchParam",
+        "searchParam",
+        "CapabilityStatement_SearchParam",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "searchRevInclude",
+        "searchRevInclude",
+        "string",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "supportedProfile",
+        "supportedProfile",
+        "canonical",
+        true,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "updateCreate",
+        "updateCreate",
+        "boolean",
+        false,
+    ),
+    (
+        "CapabilityStatement_Resource",
+        "versioning",
+        "versioning",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "compartment",
+        "compartment",
+        "canonical",
+        true,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Rest", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Rest",
+        "interaction",
+        "interaction",
+        "CapabilityStatement_Interaction1",
+        true,
+    ),
+    ("CapabilityStatement_Rest", "mode", "mode", "code", false),
+    (
+        "CapabilityStatement_Rest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "operation",
+        "operation",
+        "CapabilityStatement_Operation",
+        true,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "resource",
+        "resource",
+        "CapabilityStatement_Resource",
+        true,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "searchParam",
+        "searchParam",
+        "CapabilityStatement_SearchParam",
+        true,
+    ),
+    (
+        "CapabilityStatement_Rest",
+        "security",
+        "security",
+        "CapabilityStatement_Security",
+        false,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "definition",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "documentation",
+        "documentation",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_SearchParam",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_Security",
+        "cors",
+        "cors",
+        "boolean",
+        false,
+    ),
+    (
+        "CapabilityStatement_Security",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "CapabilityStatement_Security",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Security", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Security",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Security",
+        "service",
+        "service",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CapabilityStatement_Software",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CapabilityStatement_Software", "id", "id", "string", false),
+    (
+        "CapabilityStatement_Software",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_Software",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_Software",
+        "releaseDate",
+        "releaseDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "CapabilityStatement_Software",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_SupportedMessage",
+        "definition",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "CapabilityStatement_SupportedMessage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CapabilityStatement_SupportedMessage",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CapabilityStatement_SupportedMessage",
+        "mode",
+        "mode",
+        "code",
+        false,
+    ),
+    (
+        "CapabilityStatement_SupportedMessage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CarePlan",
+        "activity",
+        "activity",
+        "CarePlan_Activity",
+        true,
+    ),
+    ("CarePlan", "addresses", "addresses", "Reference", true),
+    ("CarePlan", "author", "author", "Reference", false),
+    ("CarePlan", "basedOn", "basedOn", "Reference", true),
+    ("CarePlan", "careTeam", "careTeam", "Reference", true),
+    ("CarePlan", "category", "category", "CodeableConcept", true),
+    ("CarePlan", "contained", "contained", "ResourceList", true),
+    ("CarePlan", "contributor", "contributor", "Reference", true),
+    ("CarePlan", "created", "created", "dateTime", false),
+    ("CarePlan", "description", "description", "string", false),
+    ("CarePlan", "encounter", "encounter", "Reference", false),
+    ("CarePlan", "extension", "extension", "Extension", true),
+    ("CarePlan", "goal", "goal", "Reference", true),
+    ("CarePlan", "id", "id", "id", false),
+    ("CarePlan", "identifier", "identifier", "Identifier", true),
+    ("CarePlan", "implicitRules", "implicitRules", "uri", false),
+    (
+        "CarePlan",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "CarePlan",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("CarePlan", "intent", "intent", "code", false),
+    ("CarePlan", "language", "language", "code", false),
+    ("CarePlan", "meta", "meta", "Meta", false),
+    (
+        "CarePlan",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CarePlan", "note", "note", "Annotation", true),
+    ("CarePlan", "partOf", "partOf", "Reference", true),
+    ("CarePlan", "period", "period", "Period", false),
+    ("CarePlan", "replaces", "replaces", "Reference", true),
+    ("CarePlan", "status", "status", "code", false),
+    ("CarePlan", "subject", "subject", "Reference", false),
+    (
+        "CarePlan",
+        "supportingInfo",
+        "supportingInfo",
+        "Reference",
+        true,
+    ),
+    ("CarePlan", "text", "text", "Narrative", false),
+    ("CarePlan", "title", "title", "string", false),
+    (
+        "CarePlan_Activity",
+        "detail",
+        "detail",
+        "CarePlan_Detail",
+        false,
+    ),
+    (
+        "CarePlan_Activity",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CarePlan_Activity", "id", "id", "string", false),
+    (
+        "CarePlan_Activity",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CarePlan_Activity",
+        "outcomeCodeableConcept",
+        "outcomeCodeableConcept",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CarePlan_Activity",
+        "outcomeReference",
+        "outcomeReference",
+        "Reference",
+        true,
+    ),
+    (
+        "CarePlan_Activity",
+        "progress",
+        "progress",
+        "Annotation",
+        true,
+    ),
+    (
+        "CarePlan_Activity",
+        "reference",
+        "reference",
+        "Reference",
+        false,
+    ),
+    ("CarePlan_Detail", "code", "code", "CodeableConcept", false),
+    (
+        "CarePlan_Detail",
+        "dailyAmount",
+        "dailyAmount",
+        "Quantity",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "doNotPerform",
+        "doNotPerform",
+        "boolean",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CarePlan_Detail", "goal", "goal", "Reference", true),
+    ("CarePlan_Detail", "id", "id", "string", false),
+    (
+        "CarePlan_Detail",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "CarePlan_Detail",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("CarePlan_Detail", "kind", "kind", "code", false),
+    (
+        "CarePlan_Detail",
+        "location",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CarePlan_Detail",
+        "performer",
+        "performer",
+        "Reference",
+        true,
+    ),
+    (
+        "CarePlan_Detail",
+        "productCodeableConcept",
+        "product",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "productReference",
+        "product",
+        "Reference",
+        false,
+    ),
+    ("CarePlan_Detail", "quantity", "quantity", "Quantity", false),
+    (
+        "CarePlan_Detail",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CarePlan_Detail",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "CarePlan_Detail",
+        "scheduledPeriod",
+        "scheduled",
+        "Period",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "scheduledString",
+        "scheduled",
+        "string",
+        false,
+    ),
+    (
+        "CarePlan_Detail",
+        "scheduledTiming",
+        "scheduled",
+        "Timing",
+        false,
+    ),
+    ("CarePlan_Detail", "status", "status", "code", false),
+    (
+        "CarePlan_Detail",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("CareTeam", "category", "category", "CodeableConcept", true),
+    ("CareTeam", "contained", "contained", "ResourceList", true),
+    ("CareTeam", "encounter", "encounter", "Reference", false),
+    ("CareTeam", "extension", "extension", "Extension", true),
+    ("CareTeam", "id", "id", "id", false),
+    ("CareTeam", "identifier", "identifier", "Identifier", true),
+    ("CareTeam", "implicitRules", "implicitRules", "uri", false),
+    ("CareTeam", "language", "language", "code", false),
+    (
+        "CareTeam",
+        "managingOrganization",
+        "managingOrganization",
+        "Reference",
+        true,
+    ),
+    ("CareTeam", "meta", "meta", "Meta", false),
+    (
+        "CareTeam",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CareTeam", "name", "name", "string", false),
+    ("CareTeam", "note", "note", "Annotation", true),
+    (
+        "CareTeam",
+        "participant",
+        "participant",
+        "CareTeam_Participant",
+        true,
+    ),
+    ("CareTeam", "period", "period", "Period", false),
+    (
+        "CareTeam",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CareTeam",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("CareTeam", "status", "status", "code", false),
+    ("CareTeam", "subject", "subject", "Reference", false),
+    ("CareTeam", "telecom", "telecom", "ContactPoint", true),
+    ("CareTeam", "text", "text", "Narrative", false),
+    (
+        "CareTeam_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CareTeam_Participant", "id", "id", "string", false),
+    (
+        "CareTeam_Participant",
+        "member",
+        "member",
+        "Reference",
+        false,
+    ),
+    (
+        "CareTeam_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CareTeam_Participant",
+        "onBehalfOf",
+        "onBehalfOf",
+        "Reference",
+        false,
+    ),
+    ("CareTeam_Participant", "period", "period", "Period", false),
+    (
+        "CareTeam_Participant",
+        "role",
+        "role",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CatalogEntry",
+        "additionalCharacteristic",
+        "additionalCharacteristic",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CatalogEntry",
+        "additionalClassification",
+        "additionalClassification",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CatalogEntry",
+        "additionalIdentifier",
+        "additionalIdentifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "CatalogEntry",
+        "classification",
+        "classification",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CatalogEntry",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("CatalogEntry", "extension", "extension", "Extension", true),
+    ("CatalogEntry", "id", "id", "id", false),
+    (
+        "CatalogEntry",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "CatalogEntry",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("CatalogEntry", "language", "language", "code", false),
+    (
+        "CatalogEntry",
+        "lastUpdated",
+        "lastUpdated",
+        "dateTime",
+        false,
+    ),
+    ("CatalogEntry", "meta", "meta", "Meta", false),
+    (
+        "CatalogEntry",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CatalogEntry", "orderable", "orderable", "boolean", false),
+    (
+        "CatalogEntry",
+        "referencedItem",
+        "referencedItem",
+        "Reference",
+        false,
+    ),
+    (
+        "CatalogEntry",
+        "relatedEntry",
+        "relatedEntry",
+        "CatalogEntry_RelatedEntry",
+        true,
+    ),
+    ("CatalogEntry", "status", "status", "code", false),
+    ("CatalogEntry", "text", "text", "Narrative", false),
+    ("CatalogEntry", "type", "type", "CodeableConcept", false),
+    ("CatalogEntry", "validTo", "validTo", "dateTime", false),
+    (
+        "CatalogEntry",
+        "validityPeriod",
+        "validityPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "CatalogEntry_RelatedEntry",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CatalogEntry_RelatedEntry", "id", "id", "string", false),
+    (
+        "CatalogEntry_RelatedEntry",
+        "item",
+        "item",
+        "Reference",
+        false,
+    ),
+    (
+        "CatalogEntry_RelatedEntry",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CatalogEntry_RelatedEntry",
+        "relationtype",
+        "relationtype",
+        "code",
+        false,
+    ),
+    ("ChargeItem", "account", "account", "Reference", true),
+    (
+        "ChargeItem",
+        "bodysite",
+        "bodysite",
+        "CodeableConcept",
+        true,
+    ),
+    ("ChargeItem", "code", "code", "CodeableConcept", false),
+    ("ChargeItem", "contained", "contained", "ResourceList", true),
+    ("ChargeItem", "context", "context", "Reference", false),
+    ("ChargeItem", "costCenter", "costCenter", "Reference", false),
+    (
+        "ChargeItem",
+        "definitionCanonical",
+        "definitionCanonical",
+        "canonical",
+        true,
+    ),
+    ("ChargeItem", "definitionUri", "definitionUri", "uri", true),
+    (
+        "ChargeItem",
+        "enteredDate",
+        "enteredDate",
+        "dateTime",
+        false,
+    ),
+    ("ChargeItem", "enterer", "enterer", "Reference", false),
+    ("ChargeItem", "extension", "extension", "Extension", true),
+    (
+        "ChargeItem",
+        "factorOverride",
+        "factorOverride",
+        "decimal",
+        false,
+    ),
+    ("ChargeItem", "id", "id", "id", false),
+    ("ChargeItem", "identifier", "identifier", "Identifier", true),
+    ("ChargeItem", "implicitRules", "implicitRules", "uri", false),
+    ("ChargeItem", "language", "language", "code", false),
+    ("ChargeItem", "meta", "meta", "Meta", false),
+    (
+        "ChargeItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ChargeItem", "note", "note", "Annotation", true),
+    (
+        "ChargeItem",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "ChargeItem",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "ChargeItem",
+        "occurrenceTiming",
+        "occurrence",
+        "Timing",
+        false,
+    ),
+    (
+        "ChargeItem",
+        "overrideReason",
+        "overrideReason",
+        "string",
+        false,
+    ),
+    ("ChargeItem", "partOf", "partOf", "Reference", true),
+    (
+        "ChargeItem",
+        "performer",
+        "performer",
+        "ChargeItem_Performer",
+        true,
+    ),
+    (
+        "ChargeItem",
+        "performingOrganization",
+        "performingOrganization",
+        "Reference",
+        false,
+    ),
+    (
+        "ChargeItem",
+        "priceOverride",
+        "priceOverride",
+        "Money",
+        false,
+    ),
+    (
+        "ChargeItem",
+        "productCodeableConcept",
+        "product",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ChargeItem",
+        "productReference",
+        "product",
+        "Reference",
+        false,
+    ),
+    ("ChargeItem", "quantity", "quantity", "Quantity", false),
+    ("ChargeItem", "reason", "reason", "CodeableConcept", true),
+    (
+        "ChargeItem",
+        "requestingOrganization",
+        "requestingOrganization",
+        "Reference",
+        false,
+    ),
+    ("ChargeItem", "service", "service", "Reference", true),
+    ("ChargeItem", "status", "status", "code", false),
+    ("ChargeItem", "subject", "subject", "Reference", false),
+    (
+        "ChargeItem",
+        "supportingInformation",
+        "supportingInformation",
+        "Reference",
+        true,
+    ),
+    ("ChargeItem", "text", "text", "Narrative", false),
+    (
+        "ChargeItemDefinition",
+        "applicability",
+        "applicability",
+        "ChargeItemDefinition_Applicability",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("ChargeItemDefinition", "date", "date", "dateTime", false),
+    (
+        "ChargeItemDefinition",
+        "derivedFromUri",
+        "derivedFromUri",
+        "uri",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ChargeItemDefinition", "id", "id", "id", false),
+    (
+        "ChargeItemDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "instance",
+        "instance",
+        "Reference",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("ChargeItemDefinition", "meta", "meta", "Meta", false),
+    (
+        "ChargeItemDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "partOf",
+        "partOf",
+        "canonical",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "propertyGroup",
+        "propertyGroup",
+        "ChargeItemDefinition_PropertyGroup",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition",
+        "replaces",
+        "replaces",
+        "canonical",
+        true,
+    ),
+    ("ChargeItemDefinition", "status", "status", "code", false),
+    ("ChargeItemDefinition", "text", "text", "Narrative", false),
+    ("ChargeItemDefinition", "title", "title", "string", false),
+    ("ChargeItemDefinition", "url", "url", "uri", false),
+    (
+        "ChargeItemDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "ChargeItemDefinition",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_Applicability",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_Applicability",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_Applicability",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_Applicability",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_Applicability",
+        "language",
+        "language",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_Applicability",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "amount",
+        "amount",
+        "Money",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_PriceComponent",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_PropertyGroup",
+        "applicability",
+        "applicability",
+        "ChargeItemDefinition_Applicability",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_PropertyGroup",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_PropertyGroup",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ChargeItemDefinition_PropertyGroup",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItemDefinition_PropertyGroup",
+        "priceComponent",
+        "priceComponent",
+        "ChargeItemDefinition_PriceComponent",
+        true,
+    ),
+    ("ChargeItem_Performer", "actor", "actor", "Reference", false),
+    (
+        "ChargeItem_Performer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ChargeItem_Performer",
+        "function",
+        "function",
+        "CodeableConcept",
+        false,
+    ),
+    ("ChargeItem_Performer", "id", "id", "string", false),
+    (
+        "ChargeItem_Performer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim", "accident", "accident", "Claim_Accident", false),
+    ("Claim", "billablePeriod", "billablePeriod", "Period", false),
+    ("Claim", "careTeam", "careTeam", "Claim_CareTeam", true),
+    ("Claim", "contained", "contained", "ResourceList", true),
+    ("Claim", "created", "created", "dateTime", false),
+    ("Claim", "diagnosis", "diagnosis", "Claim_Diagnosis", true),
+    ("Claim", "enterer", "enterer", "Reference", false),
+    ("Claim", "extension", "extension", "Extension", true),
+    ("Claim", "facility", "facility", "Reference", false),
+    (
+        "Claim",
+        "fundsReserve",
+        "fundsReserve",
+        "CodeableConcept",
+        false,
+    ),
+    ("Claim", "id", "id", "id", false),
+    ("Claim", "identifier", "identifier", "Identifier", true),
+    ("Claim", "implicitRules", "implicitRules", "uri", false),
+    ("Claim", "insurance", "insurance", "Claim_Insurance", true),
+    ("Claim", "insurer", "insurer", "Reference", false),
+    ("Claim", "item", "item", "Claim_Item", true),
+    ("Claim", "language", "language", "code", false),
+    ("Claim", "meta", "meta", "Meta", false),
+    (
+        "Claim",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Claim",
+        "originalPrescription",
+        "originalPrescription",
+        "Reference",
+        false,
+    ),
+    ("Claim", "patient", "patient", "Reference", false),
+    ("Claim", "payee", "payee", "Claim_Payee", false),
+    ("Claim", "prescription", "prescription", "Reference", false),
+    ("Claim", "priority", "priority", "CodeableConcept", false),
+    ("Claim", "procedure", "procedure", "Claim_Procedure", true),
+    ("Claim", "provider", "provider", "Reference", false),
+    ("Claim", "referral", "referral", "Reference", false),
+    ("Claim", "related", "related", "Claim_Related", true),
+    ("Claim", "status", "status", "code", false),
+    ("Claim", "subType", "subType", "CodeableConcept", false),
+    (
+        "Claim",
+        "supportingInfo",
+        "supportingInfo",
+        "Claim_SupportingInfo",
+        true,
+    ),
+    ("Claim", "text", "text", "Narrative", false),
+    ("Claim", "total", "total", "Money", false),
+    ("Claim", "type", "type", "CodeableConcept", false),
+    ("Claim", "use", "use", "code", false),
+    (
+        "ClaimResponse",
+        "addItem",
+        "addItem",
+        "ClaimResponse_AddItem",
+        true,
+    ),
+    (
+        "ClaimResponse",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse",
+        "communicationRequest",
+        "communicationRequest",
+        "Reference",
+        true,
+    ),
+    (
+        "ClaimResponse",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("ClaimResponse", "created", "created", "dateTime", false),
+    (
+        "ClaimResponse",
+        "disposition",
+        "disposition",
+        "string",
+        false,
+    ),
+    (
+        "ClaimResponse",
+        "error",
+        "error",
+        "ClaimResponse_Error",
+        true,
+    ),
+    ("ClaimResponse", "extension", "extension", "Extension", true),
+    ("ClaimResponse", "form", "form", "Attachment", false),
+    (
+        "ClaimResponse",
+        "formCode",
+        "formCode",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse",
+        "fundsReserve",
+        "fundsReserve",
+        "CodeableConcept",
+        false,
+    ),
+    ("ClaimResponse", "id", "id", "id", false),
+    (
+        "ClaimResponse",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ClaimResponse",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ClaimResponse",
+        "insurance",
+        "insurance",
+        "ClaimResponse_Insurance",
+        true,
+    ),
+    ("ClaimResponse", "insurer", "insurer", "Reference", false),
+    ("ClaimResponse", "item", "item", "ClaimResponse_Item", true),
+    ("ClaimResponse", "language", "language", "code", false),
+    ("ClaimResponse", "meta", "meta", "Meta", false),
+    (
+        "ClaimResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse", "outcome", "outcome", "code", false),
+    ("ClaimResponse", "patient", "patient", "Reference", false),
+    (
+        "ClaimResponse",
+        "payeeType",
+        "payeeType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse",
+        "payment",
+        "payment",
+        "ClaimResponse_Payment",
+        false,
+    ),
+    (
+        "ClaimResponse",
+        "preAuthPeriod",
+        "preAuthPeriod",
+        "Period",
+        false,
+    ),
+    ("ClaimResponse", "preAuthRef", "preAuthRef", "string", false),
+    (
+        "ClaimResponse",
+        "processNote",
+        "processNote",
+        "ClaimResponse_ProcessNote",
+        true,
+    ),
+    ("ClaimResponse", "request", "request", "Reference", false),
+    (
+        "ClaimResponse",
+        "requestor",
+        "requestor",
+        "Reference",
+        false,
+    ),
+    ("ClaimResponse", "status", "status", "code", false),
+    (
+        "ClaimResponse",
+        "subType",
+        "subType",
+        "CodeableConcept",
+        false,
+    ),
+    ("ClaimResponse", "text", "text", "Narrative", false),
+    (
+        "ClaimResponse",
+        "total",
+        "total",
+        "ClaimResponse_Total",
+        true,
+    ),
+    ("ClaimResponse", "type", "type", "CodeableConcept", false),
+    ("ClaimResponse", "use", "use", "code", false),
+    (
+        "ClaimResponse_AddItem",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "detail",
+        "detail",
+        "ClaimResponse_Detail1",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "detailSequence",
+        "detailSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ClaimResponse_AddItem", "id", "id", "string", false),
+    (
+        "ClaimResponse_AddItem",
+        "itemSequence",
+        "itemSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "locationAddress",
+        "location",
+        "Address",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "locationCodeableConcept",
+        "location",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "locationReference",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_AddItem", "net", "net", "Money", false),
+    (
+        "ClaimResponse_AddItem",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "provider",
+        "provider",
+        "Reference",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "servicedDate",
+        "serviced",
+        "date",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "servicedPeriod",
+        "serviced",
+        "Period",
+        false,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "subSite",
+        "subSite",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "subdetailSequence",
+        "subdetailSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_AddItem",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ClaimResponse_Adjudication",
+        "amount",
+        "amount",
+        "Money",
+        false,
+    ),
+    (
+        "ClaimResponse_Adjudication",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_Adjudication",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Adjudication", "id", "id", "string", false),
+    (
+        "ClaimResponse_Adjudication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Adjudication",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_Adjudication",
+        "value",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "ClaimResponse_Detail",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail",
+        "detailSequence",
+        "detailSequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_Detail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Detail", "id", "id", "string", false),
+    (
+        "ClaimResponse_Detail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail",
+        "subDetail",
+        "subDetail",
+        "ClaimResponse_SubDetail",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ClaimResponse_Detail1", "id", "id", "string", false),
+    (
+        "ClaimResponse_Detail1",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Detail1", "net", "net", "Money", false),
+    (
+        "ClaimResponse_Detail1",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "subDetail",
+        "subDetail",
+        "ClaimResponse_SubDetail1",
+        true,
+    ),
+    (
+        "ClaimResponse_Detail1",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ClaimResponse_Error",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_Error",
+        "detailSequence",
+        "detailSequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_Error",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Error", "id", "id", "string", false),
+    (
+        "ClaimResponse_Error",
+        "itemSequence",
+        "itemSequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_Error",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Error",
+        "subDetailSequence",
+        "subDetailSequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_Insurance",
+        "businessArrangement",
+        "businessArrangement",
+        "string",
+        false,
+    ),
+    (
+        "ClaimResponse_Insurance",
+        "claimResponse",
+        "claimResponse",
+        "Reference",
+        false,
+    ),
+    (
+        "ClaimResponse_Insurance",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    (
+        "ClaimResponse_Insurance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Insurance",
+        "focal",
+        "focal",
+        "boolean",
+        false,
+    ),
+    ("ClaimResponse_Insurance", "id", "id", "string", false),
+    (
+        "ClaimResponse_Insurance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Insurance",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_Item",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse_Item",
+        "detail",
+        "detail",
+        "ClaimResponse_Detail",
+        true,
+    ),
+    (
+        "ClaimResponse_Item",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Item", "id", "id", "string", false),
+    (
+        "ClaimResponse_Item",
+        "itemSequence",
+        "itemSequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Item",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_Payment",
+        "adjustment",
+        "adjustment",
+        "Money",
+        false,
+    ),
+    (
+        "ClaimResponse_Payment",
+        "adjustmentReason",
+        "adjustmentReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("ClaimResponse_Payment", "amount", "amount", "Money", false),
+    ("ClaimResponse_Payment", "date", "date", "date", false),
+    (
+        "ClaimResponse_Payment",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Payment", "id", "id", "string", false),
+    (
+        "ClaimResponse_Payment",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "ClaimResponse_Payment",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_Payment",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_ProcessNote",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_ProcessNote", "id", "id", "string", false),
+    (
+        "ClaimResponse_ProcessNote",
+        "language",
+        "language",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_ProcessNote",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_ProcessNote",
+        "number",
+        "number",
+        "positiveInt",
+        false,
+    ),
+    ("ClaimResponse_ProcessNote", "text", "text", "string", false),
+    ("ClaimResponse_ProcessNote", "type", "type", "code", false),
+    (
+        "ClaimResponse_SubDetail",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_SubDetail", "id", "id", "string", false),
+    (
+        "ClaimResponse_SubDetail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail",
+        "subDetailSequence",
+        "subDetailSequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "adjudication",
+        "adjudication",
+        "ClaimResponse_Adjudication",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ClaimResponse_SubDetail1", "id", "id", "string", false),
+    (
+        "ClaimResponse_SubDetail1",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_SubDetail1", "net", "net", "Money", false),
+    (
+        "ClaimResponse_SubDetail1",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ClaimResponse_SubDetail1",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    ("ClaimResponse_Total", "amount", "amount", "Money", false),
+    (
+        "ClaimResponse_Total",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClaimResponse_Total",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClaimResponse_Total", "id", "id", "string", false),
+    (
+        "ClaimResponse_Total",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Accident", "date", "date", "date", false),
+    (
+        "Claim_Accident",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Accident", "id", "id", "string", false),
+    (
+        "Claim_Accident",
+        "locationAddress",
+        "location",
+        "Address",
+        false,
+    ),
+    (
+        "Claim_Accident",
+        "locationReference",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_Accident",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Accident", "type", "type", "CodeableConcept", false),
+    (
+        "Claim_CareTeam",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_CareTeam", "id", "id", "string", false),
+    (
+        "Claim_CareTeam",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_CareTeam", "provider", "provider", "Reference", false),
+    (
+        "Claim_CareTeam",
+        "qualification",
+        "qualification",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_CareTeam",
+        "responsible",
+        "responsible",
+        "boolean",
+        false,
+    ),
+    ("Claim_CareTeam", "role", "role", "CodeableConcept", false),
+    (
+        "Claim_CareTeam",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Claim_Detail",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    ("Claim_Detail", "extension", "extension", "Extension", true),
+    ("Claim_Detail", "factor", "factor", "decimal", false),
+    ("Claim_Detail", "id", "id", "string", false),
+    (
+        "Claim_Detail",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Claim_Detail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Detail", "net", "net", "Money", false),
+    (
+        "Claim_Detail",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Detail",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    ("Claim_Detail", "quantity", "quantity", "Quantity", false),
+    (
+        "Claim_Detail",
+        "revenue",
+        "revenue",
+        "CodeableConcept",
+        false,
+    ),
+    ("Claim_Detail", "sequence", "sequence", "positiveInt", false),
+    (
+        "Claim_Detail",
+        "subDetail",
+        "subDetail",
+        "Claim_SubDetail",
+        true,
+    ),
+    ("Claim_Detail", "udi", "udi", "Reference", true),
+    ("Claim_Detail", "unitPrice", "unitPrice", "Money", false),
+    (
+        "Claim_Diagnosis",
+        "diagnosisCodeableConcept",
+        "diagnosis",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Diagnosis",
+        "diagnosisReference",
+        "diagnosis",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_Diagnosis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Diagnosis", "id", "id", "string", false),
+    (
+        "Claim_Diagnosis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Claim_Diagnosis",
+        "onAdmission",
+        "onAdmission",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Diagnosis",
+        "packageCode",
+        "packageCode",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Diagnosis",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    ("Claim_Diagnosis", "type", "type", "CodeableConcept", true),
+    (
+        "Claim_Insurance",
+        "businessArrangement",
+        "businessArrangement",
+        "string",
+        false,
+    ),
+    (
+        "Claim_Insurance",
+        "claimResponse",
+        "claimResponse",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_Insurance",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_Insurance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Insurance", "focal", "focal", "boolean", false),
+    ("Claim_Insurance", "id", "id", "string", false),
+    (
+        "Claim_Insurance",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "Claim_Insurance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Claim_Insurance",
+        "preAuthRef",
+        "preAuthRef",
+        "string",
+        true,
+    ),
+    (
+        "Claim_Insurance",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Claim_Item",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Item",
+        "careTeamSequence",
+        "careTeamSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "Claim_Item",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    ("Claim_Item", "detail", "detail", "Claim_Detail", true),
+    (
+        "Claim_Item",
+        "diagnosisSequence",
+        "diagnosisSequence",
+        "positiveInt",
+        true,
+    ),
+    ("Claim_Item", "encounter", "encounter", "Reference", true),
+    ("Claim_Item", "extension", "extension", "Extension", true),
+    ("Claim_Item", "factor", "factor", "decimal", false),
+    ("Claim_Item", "id", "id", "string", false),
+    (
+        "Claim_Item",
+        "informationSequence",
+        "informationSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "Claim_Item",
+        "locationAddress",
+        "location",
+        "Address",
+        false,
+    ),
+    (
+        "Claim_Item",
+        "locationCodeableConcept",
+        "location",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Item",
+        "locationReference",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_Item",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Claim_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Item", "net", "net", "Money", false),
+    (
+        "Claim_Item",
+        "procedureSequence",
+        "procedureSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "Claim_Item",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Item",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    ("Claim_Item", "quantity", "quantity", "Quantity", false),
+    ("Claim_Item", "revenue", "revenue", "CodeableConcept", false),
+    ("Claim_Item", "sequence", "sequence", "positiveInt", false),
+    ("Claim_Item", "servicedDate", "serviced", "date", false),
+    ("Claim_Item", "servicedPeriod", "serviced", "Period", false),
+    ("Claim_Item", "subSite", "subSite", "CodeableConcept", true),
+    ("Claim_Item", "udi", "udi", "Reference", true),
+    ("Claim_Item", "unitPrice", "unitPrice", "Money", false),
+    ("Claim_Payee", "extension", "extension", "Extension", true),
+    ("Claim_Payee", "id", "id", "string", false),
+    (
+        "Claim_Payee",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Payee", "party", "party", "Reference", false),
+    ("Claim_Payee", "type", "type", "CodeableConcept", false),
+    ("Claim_Procedure", "date", "date", "dateTime", false),
+    (
+        "Claim_Procedure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_Procedure", "id", "id", "string", false),
+    (
+        "Claim_Procedure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Claim_Procedure",
+        "procedureCodeableConcept",
+        "procedure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_Procedure",
+        "procedureReference",
+        "procedure",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_Procedure",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    ("Claim_Procedure", "type", "type", "CodeableConcept", true),
+    ("Claim_Procedure", "udi", "udi", "Reference", true),
+    ("Claim_Related", "claim", "claim", "Reference", false),
+    ("Claim_Related", "extension", "extension", "Extension", true),
+    ("Claim_Related", "id", "id", "string", false),
+    (
+        "Claim_Related",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Claim_Related",
+        "reference",
+        "reference",
+        "Identifier",
+        false,
+    ),
+    (
+        "Claim_Related",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SubDetail",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SubDetail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_SubDetail", "factor", "factor", "decimal", false),
+    ("Claim_SubDetail", "id", "id", "string", false),
+    (
+        "Claim_SubDetail",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Claim_SubDetail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Claim_SubDetail", "net", "net", "Money", false),
+    (
+        "Claim_SubDetail",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SubDetail",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    ("Claim_SubDetail", "quantity", "quantity", "Quantity", false),
+    (
+        "Claim_SubDetail",
+        "revenue",
+        "revenue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SubDetail",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    ("Claim_SubDetail", "udi", "udi", "Reference", true),
+    ("Claim_SubDetail", "unitPrice", "unitPrice", "Money", false),
+    (
+        "Claim_SupportingInfo",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Claim_SupportingInfo", "id", "id", "string", false),
+    (
+        "Claim_SupportingInfo",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "timingDate",
+        "timing",
+        "date",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "timingPeriod",
+        "timing",
+        "Period",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "Claim_SupportingInfo",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "assessor",
+        "assessor",
+        "Reference",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("ClinicalImpression", "date", "date", "dateTime", false),
+    (
+        "ClinicalImpression",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "effectiveDateTime",
+        "effective",
+        "dateTime",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "effectivePeriod",
+        "effective",
+        "Period",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClinicalImpression",
+        "finding",
+        "finding",
+        "ClinicalImpression_Finding",
+        true,
+    ),
+    ("ClinicalImpression", "id", "id", "id", false),
+    (
+        "ClinicalImpression",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ClinicalImpression",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "investigation",
+        "investigation",
+        "ClinicalImpression_Investigation",
+        true,
+    ),
+    ("ClinicalImpression", "language", "language", "code", false),
+    ("ClinicalImpression", "meta", "meta", "Meta", false),
+    (
+        "ClinicalImpression",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ClinicalImpression", "note", "note", "Annotation", true),
+    (
+        "ClinicalImpression",
+        "previous",
+        "previous",
+        "Reference",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "problem",
+        "problem",
+        "Reference",
+        true,
+    ),
+    (
+        "ClinicalImpression",
+        "prognosisCodeableConcept",
+        "prognosisCodeableConcept",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ClinicalImpression",
+        "prognosisReference",
+        "prognosisReference",
+        "Reference",
+        true,
+    ),
+    ("ClinicalImpression", "protocol", "protocol", "uri", true),
+    ("ClinicalImpression", "status", "status", "code", false),
+    (
+        "ClinicalImpression",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClinicalImpression",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("ClinicalImpression", "summary", "summary", "string", false),
+    (
+        "ClinicalImpression",
+        "supportingInfo",
+        "supportingInfo",
+        "Reference",
+        true,
+    ),
+    ("ClinicalImpression", "text", "text", "Narrative", false),
+    (
+        "ClinicalImpression_Finding",
+        "basis",
+        "basis",
+        "string",
+        false,
+    ),
+    (
+        "ClinicalImpression_Finding",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ClinicalImpression_Finding", "id", "id", "string", false),
+    (
+        "ClinicalImpression_Finding",
+        "itemCodeableConcept",
+        "item",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClinicalImpression_Finding",
+        "itemReference",
+        "item",
+        "Reference",
+        false,
+    ),
+    (
+        "ClinicalImpression_Finding",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClinicalImpression_Investigation",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ClinicalImpression_Investigation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ClinicalImpression_Investigation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ClinicalImpression_Investigation",
+        "item",
+        "item",
+        "Reference",
+        true,
+    ),
+    (
+        "ClinicalImpression_Investigation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CodeSystem",
+        "caseSensitive",
+        "caseSensitive",
+        "boolean",
+        false,
+    ),
+    (
+        "CodeSystem",
+        "compositional",
+        "compositional",
+        "boolean",
+        false,
+    ),
+    (
+        "CodeSystem",
+        "concept",
+        "concept",
+        "CodeSystem_Concept",
+        true,
+    ),
+    ("CodeSystem", "contact", "contact", "ContactDetail", true),
+    ("CodeSystem", "contained", "contained", "ResourceList", true),
+    ("CodeSystem", "content", "content", "code", false),
+    ("CodeSystem", "copyright", "copyright", "markdown", false),
+    ("CodeSystem", "count", "count", "unsignedInt", false),
+    ("CodeSystem", "date", "date", "dateTime", false),
+    (
+        "CodeSystem",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "CodeSystem",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    ("CodeSystem", "extension", "extension", "Extension", true),
+    ("CodeSystem", "filter", "filter", "CodeSystem_Filter", true),
+    (
+        "CodeSystem",
+        "hierarchyMeaning",
+        "hierarchyMeaning",
+        "code",
+        false,
+    ),
+    ("CodeSystem", "id", "id", "id", false),
+    ("CodeSystem", "identifier", "identifier", "Identifier", true),
+    ("CodeSystem", "implicitRules", "implicitRules", "uri", false),
+    (
+        "CodeSystem",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("CodeSystem", "language", "language", "code", false),
+    ("CodeSystem", "meta", "meta", "Meta", false),
+    (
+        "CodeSystem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem", "name", "name", "string", false),
+    (
+        "CodeSystem",
+        "property",
+        "property",
+        "CodeSystem_Property",
+        true,
+    ),
+    ("CodeSystem", "publisher", "publisher", "string", false),
+    ("CodeSystem", "purpose", "purpose", "markdown", false),
+    ("CodeSystem", "status", "status", "code", false),
+    (
+        "CodeSystem",
+        "supplements",
+        "supplements",
+        "canonical",
+        false,
+    ),
+    ("CodeSystem", "text", "text", "Narrative", false),
+    ("CodeSystem", "title", "title", "string", false),
+    ("CodeSystem", "url", "url", "uri", false),
+    (
+        "CodeSystem",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("CodeSystem", "valueSet", "valueSet", "canonical", false),
+    ("CodeSystem", "version", "version", "string", false),
+    (
+        "CodeSystem",
+        "versionNeeded",
+        "versionNeeded",
+        "boolean",
+        false,
+    ),
+    ("CodeSystem_Concept", "code", "code", "code", false),
+    (
+        "CodeSystem_Concept",
+        "concept",
+        "concept",
+        "CodeSystem_Concept",
+        true,
+    ),
+    (
+        "CodeSystem_Concept",
+        "definition",
+        "definition",
+        "string",
+        false,
+    ),
+    (
+        "CodeSystem_Concept",
+        "designation",
+        "designation",
+        "CodeSystem_Designation",
+        true,
+    ),
+    ("CodeSystem_Concept", "display", "display", "string", false),
+    (
+        "CodeSystem_Concept",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Concept", "id", "id", "string", false),
+    (
+        "CodeSystem_Concept",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CodeSystem_Concept",
+        "property",
+        "property",
+        "CodeSystem_Property1",
+        true,
+    ),
+    (
+        "CodeSystem_Designation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Designation", "id", "id", "string", false),
+    (
+        "CodeSystem_Designation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "CodeSystem_Designation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Designation", "use", "use", "Coding", false),
+    ("CodeSystem_Designation", "value", "value", "string", false),
+    ("CodeSystem_Filter", "code", "code", "code", false),
+    (
+        "CodeSystem_Filter",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "CodeSystem_Filter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Filter", "id", "id", "string", false),
+    (
+        "CodeSystem_Filter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Filter", "operator", "operator", "code", true),
+    ("CodeSystem_Filter", "value", "value", "string", false),
+    ("CodeSystem_Property", "code", "code", "code", false),
+    (
+        "CodeSystem_Property",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "CodeSystem_Property",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Property", "id", "id", "string", false),
+    (
+        "CodeSystem_Property",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Property", "type", "type", "code", false),
+    ("CodeSystem_Property", "uri", "uri", "uri", false),
+    ("CodeSystem_Property1", "code", "code", "code", false),
+    (
+        "CodeSystem_Property1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CodeSystem_Property1", "id", "id", "string", false),
+    (
+        "CodeSystem_Property1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CodeSystem_Property1",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    ("CodeSystem_Property1", "valueCode", "value", "code", false),
+    (
+        "CodeSystem_Property1",
+        "valueCoding",
+        "value",
+        "Coding",
+        false,
+    ),
+    (
+        "CodeSystem_Property1",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "CodeSystem_Property1",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "CodeSystem_Property1",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "CodeSystem_Property1",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    ("CodeableConcept", "coding", "coding", "Coding", true),
+    (
+        "CodeableConcept",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CodeableConcept", "id", "id", "string", false),
+    ("CodeableConcept", "text", "text", "string", false),
+    ("Coding", "code", "code", "code", false),
+    ("Coding", "display", "display", "string", false),
+    ("Coding", "extension", "extension", "Extension", true),
+    ("Coding", "id", "id", "string", false),
+    ("Coding", "system", "system", "uri", false),
+    ("Coding", "userSelected", "userSelected", "boolean", false),
+    ("Coding", "version", "version", "string", false),
+    ("Communication", "about", "about", "Reference", true),
+    ("Communication", "basedOn", "basedOn", "Reference", true),
+    (
+        "Communication",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Communication",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "Communication",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    ("Communication", "extension", "extension", "Extension", true),
+    ("Communication", "id", "id", "id", false),
+    (
+        "Communication",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Communication",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "Communication",
+        "inResponseTo",
+        "inResponseTo",
+        "Reference",
+        true,
+    ),
+    (
+        "Communication",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "Communication",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("Communication", "language", "language", "code", false),
+    ("Communication", "medium", "medium", "CodeableConcept", true),
+    ("Communication", "meta", "meta", "Meta", false),
+    (
+        "Communication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Communication", "note", "note", "Annotation", true),
+    ("Communication", "partOf", "partOf", "Reference", true),
+    (
+        "Communication",
+        "payload",
+        "payload",
+        "Communication_Payload",
+        true,
+    ),
+    ("Communication", "priority", "priority", "code", false),
+    (
+        "Communication",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Communication",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("Communication", "received", "received", "dateTime", false),
+    ("Communication", "recipient", "recipient", "Reference", true),
+    ("Communication", "sender", "sender", "Reference", false),
+    ("Communication", "sent", "sent", "dateTime", false),
+    ("Communication", "status", "status", "code", false),
+    (
+        "Communication",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("Communication", "subject", "subject", "Reference", false),
+    ("Communication", "text", "text", "Narrative", false),
+    ("Communication", "topic", "topic", "CodeableConcept", false),
+    ("CommunicationRequest", "about", "about", "Reference", true),
+    (
+        "CommunicationRequest",
+        "authoredOn",
+        "authoredOn",
+        "dateTime",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "basedOn",
+        "basedOn",
+        "Reference",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "doNotPerform",
+        "doNotPerform",
+        "boolean",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "groupIdentifier",
+        "groupIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("CommunicationRequest", "id", "id", "id", false),
+    (
+        "CommunicationRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "medium",
+        "medium",
+        "CodeableConcept",
+        true,
+    ),
+    ("CommunicationRequest", "meta", "meta", "Meta", false),
+    (
+        "CommunicationRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CommunicationRequest", "note", "note", "Annotation", true),
+    (
+        "CommunicationRequest",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "payload",
+        "payload",
+        "CommunicationRequest_Payload",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "priority",
+        "priority",
+        "code",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "recipient",
+        "recipient",
+        "Reference",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "replaces",
+        "replaces",
+        "Reference",
+        true,
+    ),
+    (
+        "CommunicationRequest",
+        "requester",
+        "requester",
+        "Reference",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "sender",
+        "sender",
+        "Reference",
+        false,
+    ),
+    ("CommunicationRequest", "status", "status", "code", false),
+    (
+        "CommunicationRequest",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CommunicationRequest",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("CommunicationRequest", "text", "text", "Narrative", false),
+    (
+        "CommunicationRequest_Payload",
+        "contentAttachment",
+        "content",
+        "Attachment",
+        false,
+    ),
+    (
+        "CommunicationRequest_Payload",
+        "contentReference",
+        "content",
+        "Reference",
+        false,
+    ),
+    (
+        "CommunicationRequest_Payload",
+        "contentString",
+        "content",
+        "string",
+        false,
+    ),
+    (
+        "CommunicationRequest_Payload",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CommunicationRequest_Payload", "id", "id", "string", false),
+    (
+        "CommunicationRequest_Payload",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Communication_Payload",
+        "contentAttachment",
+        "content",
+        "Attachment",
+        false,
+    ),
+    (
+        "Communication_Payload",
+        "contentReference",
+        "content",
+        "Reference",
+        false,
+    ),
+    (
+        "Communication_Payload",
+        "contentString",
+        "content",
+        "string",
+        false,
+    ),
+    (
+        "Communication_Payload",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Communication_Payload", "id", "id", "string", false),
+    (
+        "Communication_Payload",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CompartmentDefinition", "code", "code", "code", false),
+    (
+        "CompartmentDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "CompartmentDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("CompartmentDefinition", "date", "date", "dateTime", false),
+    (
+        "CompartmentDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "CompartmentDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "CompartmentDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("CompartmentDefinition", "id", "id", "id", false),
+    (
+        "CompartmentDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "CompartmentDefinition",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("CompartmentDefinition", "meta", "meta", "Meta", false),
+    (
+        "CompartmentDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("CompartmentDefinition", "name", "name", "string", false),
+    (
+        "CompartmentDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "CompartmentDefinition",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "CompartmentDefinition",
+        "resource",
+        "resource",
+        "CompartmentDefinition_Resource",
+        true,
+    ),
+    (
+        "CompartmentDefinition",
+        "search",
+        "search",
+        "boolean",
+        false,
+    ),
+    ("CompartmentDefinition", "status", "status", "code", false),
+    ("CompartmentDefinition", "text", "text", "Narrative", false),
+    ("CompartmentDefinition", "url", "url", "uri", false),
+    (
+        "CompartmentDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "CompartmentDefinition",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "CompartmentDefinition_Resource",
+        "code",
+        "code",
+        "code",
+        false,
+    ),
+    (
+        "CompartmentDefinition_Resource",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "CompartmentDefinition_Resource",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CompartmentDefinition_Resource",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CompartmentDefinition_Resource",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CompartmentDefinition_Resource",
+        "param",
+        "param",
+        "string",
+        true,
+    ),
+    (
+        "Composition",
+        "attester",
+        "attester",
+        "Composition_Attester",
+        true,
+    ),
+    ("Composition", "author", "author", "Reference", true),
+    (
+        "Composition",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Composition",
+        "confidentiality",
+        "confidentiality",
+        "code",
+        false,
+    ),
+    (
+        "Composition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("Composition", "custodian", "custodian", "Reference", false),
+    ("Composition", "date", "date", "dateTime", false),
+    ("Composition", "encounter", "encounter", "Reference", false),
+    ("Composition", "event", "event", "Composition_Event", true),
+    ("Composition", "extension", "extension", "Extension", true),
+    ("Composition", "id", "id", "id", false),
+    (
+        "Composition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "Composition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("Composition", "language", "language", "code", false),
+    ("Composition", "meta", "meta", "Meta", false),
+    (
+        "Composition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Composition",
+        "relatesTo",
+        "relatesTo",
+        "Composition_RelatesTo",
+        true,
+    ),
+    (
+        "Composition",
+        "section",
+        "section",
+        "Composition_Section",
+        true,
+    ),
+    ("Composition", "status", "status", "code", false),
+    ("Composition", "subject", "subject", "Reference", false),
+    ("Composition", "text", "text", "Narrative", false),
+    ("Composition", "title", "title", "string", false),
+    ("Composition", "type", "type", "CodeableConcept", false),
+    (
+        "Composition_Attester",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Composition_Attester", "id", "id", "string", false),
+    ("Composition_Attester", "mode", "mode", "code", false),
+    (
+        "Composition_Attester",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Composition_Attester", "party", "party", "Reference", false),
+    ("Composition_Attester", "time", "time", "dateTime", false),
+    ("Composition_Event", "code", "code", "CodeableConcept", true),
+    ("Composition_Event", "detail", "detail", "Reference", true),
+    (
+        "Composition_Event",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Composition_Event", "id", "id", "string", false),
+    (
+        "Composition_Event",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Composition_Event", "period", "period", "Period", false),
+    ("Composition_RelatesTo", "code", "code", "code", false),
+    (
+        "Composition_RelatesTo",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Composition_RelatesTo", "id", "id", "string", false),
+    (
+        "Composition_RelatesTo",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Composition_RelatesTo",
+        "targetIdentifier",
+        "target",
+        "Identifier",
+        false,
+    ),
+    (
+        "Composition_RelatesTo",
+        "targetReference",
+        "target",
+        "Reference",
+        false,
+    ),
+    ("Composition_Section", "author", "author", "Reference", true),
+    (
+        "Composition_Section",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Composition_Section",
+        "emptyReason",
+        "emptyReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("Composition_Section", "entry", "entry", "Reference", true),
+    (
+        "Composition_Section",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Composition_Section", "focus", "focus", "Reference", false),
+    ("Composition_Section", "id", "id", "string", false),
+    ("Composition_Section", "mode", "mode", "code", false),
+    (
+        "Composition_Section",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Composition_Section",
+        "orderedBy",
+        "orderedBy",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Composition_Section",
+        "section",
+        "section",
+        "Composition_Section",
+        true,
+    ),
+    ("Composition_Section", "text", "text", "Narrative", false),
+    ("Composition_Section", "title", "title", "string", false),
+    ("ConceptMap", "contact", "contact", "ContactDetail", true),
+    ("ConceptMap", "contained", "contained", "ResourceList", true),
+    ("ConceptMap", "copyright", "copyright", "markdown", false),
+    ("ConceptMap", "date", "date", "dateTime", false),
+    (
+        "ConceptMap",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ConceptMap",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    ("ConceptMap", "extension", "extension", "Extension", true),
+    ("ConceptMap", "group", "group", "ConceptMap_Group", true),
+    ("ConceptMap", "id", "id", "id", false),
+    (
+        "ConceptMap",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    ("ConceptMap", "implicitRules", "implicitRules", "uri", false),
+    (
+        "ConceptMap",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("ConceptMap", "language", "language", "code", false),
+    ("ConceptMap", "meta", "meta", "Meta", false),
+    (
+        "ConceptMap",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap", "name", "name", "string", false),
+    ("ConceptMap", "publisher", "publisher", "string", false),
+    ("ConceptMap", "purpose", "purpose", "markdown", false),
+    (
+        "ConceptMap",
+        "sourceCanonical",
+        "source",
+        "canonical",
+        false,
+    ),
+    ("ConceptMap", "sourceUri", "source", "uri", false),
+    ("ConceptMap", "status", "status", "code", false),
+    (
+        "ConceptMap",
+        "targetCanonical",
+        "target",
+        "canonical",
+        false,
+    ),
+    ("ConceptMap", "targetUri", "target", "uri", false),
+    ("ConceptMap", "text", "text", "Narrative", false),
+    ("ConceptMap", "title", "title", "string", false),
+    ("ConceptMap", "url", "url", "uri", false),
+    (
+        "ConceptMap",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("ConceptMap", "version", "version", "string", false),
+    (
+        "ConceptMap_DependsOn",
+        "display",
+        "display",
+        "string",
+        false,
+    ),
+    (
+        "ConceptMap_DependsOn",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_DependsOn", "id", "id", "string", false),
+    (
+        "ConceptMap_DependsOn",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_DependsOn", "property", "property", "uri", false),
+    (
+        "ConceptMap_DependsOn",
+        "system",
+        "system",
+        "canonical",
+        false,
+    ),
+    ("ConceptMap_DependsOn", "value", "value", "string", false),
+    ("ConceptMap_Element", "code", "code", "code", false),
+    ("ConceptMap_Element", "display", "display", "string", false),
+    (
+        "ConceptMap_Element",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_Element", "id", "id", "string", false),
+    (
+        "ConceptMap_Element",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ConceptMap_Element",
+        "target",
+        "target",
+        "ConceptMap_Target",
+        true,
+    ),
+    (
+        "ConceptMap_Group",
+        "element",
+        "element",
+        "ConceptMap_Element",
+        true,
+    ),
+    (
+        "ConceptMap_Group",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_Group", "id", "id", "string", false),
+    (
+        "ConceptMap_Group",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_Group", "source", "source", "uri", false),
+    (
+        "ConceptMap_Group",
+        "sourceVersion",
+        "sourceVersion",
+        "string",
+        false,
+    ),
+    ("ConceptMap_Group", "target", "target", "uri", false),
+    (
+        "ConceptMap_Group",
+        "targetVersion",
+        "targetVersion",
+        "string",
+        false,
+    ),
+    (
+        "ConceptMap_Group",
+        "unmapped",
+        "unmapped",
+        "ConceptMap_Unmapped",
+        false,
+    ),
+    ("ConceptMap_Target", "code", "code", "code", false),
+    ("ConceptMap_Target", "comment", "comment", "string", false),
+    (
+        "ConceptMap_Target",
+        "dependsOn",
+        "dependsOn",
+        "ConceptMap_DependsOn",
+        true,
+    ),
+    ("ConceptMap_Target", "display", "display", "string", false),
+    (
+        "ConceptMap_Target",
+        "equivalence",
+        "equivalence",
+        "code",
+        false,
+    ),
+    (
+        "ConceptMap_Target",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_Target", "id", "id", "string", false),
+    (
+        "ConceptMap_Target",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ConceptMap_Target",
+        "product",
+        "product",
+        "ConceptMap_DependsOn",
+        true,
+    ),
+    ("ConceptMap_Unmapped", "code", "code", "code", false),
+    ("ConceptMap_Unmapped", "display", "display", "string", false),
+    (
+        "ConceptMap_Unmapped",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_Unmapped", "id", "id", "string", false),
+    ("ConceptMap_Unmapped", "mode", "mode", "code", false),
+    (
+        "ConceptMap_Unmapped",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ConceptMap_Unmapped", "url", "url", "canonical", false),
+    ("Condition", "abatementAge", "abatement", "Age", false),
+    (
+        "Condition",
+        "abatementDateTime",
+        "abatement",
+        "dateTime",
+        false,
+    ),
+    ("Condition", "abatementPeriod", "abatement", "Period", false),
+    ("Condition", "abatementRange", "abatement", "Range", false),
+    ("Condition", "abatementString", "abatement", "string", false),
+    ("Condition", "asserter", "asserter", "Reference", false),
+    ("Condition", "bodySite", "bodySite", "CodeableConcept", true),
+    ("Condition", "category", "category", "CodeableConcept", true),
+    (
+        "Condition",
+        "clinicalStatus",
+        "clinicalStatus",
+        "CodeableConcept",
+        false,
+    ),
+    ("Condition", "code", "code", "CodeableConcept", false),
+    ("Condition", "contained", "contained", "ResourceList", true),
+    ("Condition", "encounter", "encounter", "Reference", false),
+    (
+        "Condition",
+        "evidence",
+        "evidence",
+        "Condition_Evidence",
+        true,
+    ),
+    ("Condition", "extension", "extension", "Extension", true),
+    ("Condition", "id", "id", "id", false),
+    ("Condition", "identifier", "identifier", "Identifier", true),
+    ("Condition", "implicitRules", "implicitRules", "uri", false),
+    ("Condition", "language", "language", "code", false),
+    ("Condition", "meta", "meta", "Meta", false),
+    (
+        "Condition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Condition", "note", "note", "Annotation", true),
+    ("Condition", "onsetAge", "onset", "Age", false),
+    ("Condition", "onsetDateTime", "onset", "dateTime", false),
+    ("Condition", "onsetPeriod", "onset", "Period", false),
+    ("Condition", "onsetRange", "onset", "Range", false),
+    ("Condition", "onsetString", "onset", "string", false),
+    (
+        "Condition",
+        "recordedDate",
+        "recordedDate",
+        "dateTime",
+        false,
+    ),
+    ("Condition", "recorder", "recorder", "Reference", false),
+    (
+        "Condition",
+        "severity",
+        "severity",
+        "CodeableConcept",
+        false,
+    ),
+    ("Condition", "stage", "stage", "Condition_Stage", true),
+    ("Condition", "subject", "subject", "Reference", false),
+    ("Condition", "text", "text", "Narrative", false),
+    (
+        "Condition",
+        "verificationStatus",
+        "verificationStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Condition_Evidence",
+        "code",
+        "code",
+        "CodeableConcept",
+        true,
+    ),
+    ("Condition_Evidence", "detail", "detail", "Reference", true),
+    (
+        "Condition_Evidence",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Condition_Evidence", "id", "id", "string", false),
+    (
+        "Condition_Evidence",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Condition_Stage",
+        "assessment",
+        "assessment",
+        "Reference",
+        true,
+    ),
+    (
+        "Condition_Stage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Condition_Stage", "id", "id", "string", false),
+    (
+        "Condition_Stage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Condition_Stage",
+        "summary",
+        "summary",
+        "CodeableConcept",
+        false,
+    ),
+    ("Condition_Stage", "type", "type", "CodeableConcept", false),
+    ("Consent", "category", "category", "CodeableConcept", true),
+    ("Consent", "contained", "contained", "ResourceList", true),
+    ("Consent", "dateTime", "dateTime", "dateTime", false),
+    ("Consent", "extension", "extension", "Extension", true),
+    ("Consent", "id", "id", "id", false),
+    ("Consent", "identifier", "identifier", "Identifier", true),
+    ("Consent", "implicitRules", "implicitRules", "uri", false),
+    ("Consent", "language", "language", "code", false),
+    ("Consent", "meta", "meta", "Meta", false),
+    (
+        "Consent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Consent", "organization", "organization", "Reference", true),
+    ("Consent", "patient", "patient", "Reference", false),
+    ("Consent", "performer", "performer", "Reference", true),
+    ("Consent", "policy", "policy", "Consent_Policy", true),
+    (
+        "Consent",
+        "policyRule",
+        "policyRule",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Consent",
+        "provision",
+        "provision",
+        "Consent_Provision",
+        false,
+    ),
+    ("Consent", "scope", "scope", "CodeableConcept", false),
+    ("Consent", "sourceAttachment", "source", "Attachment", false),
+    ("Consent", "sourceReference", "source", "Reference", false),
+    ("Consent", "status", "status", "code", false),
+    ("Consent", "text", "text", "Narrative", false),
+    (
+        "Consent",
+        "verification",
+        "verification",
+        "Consent_Verification",
+        true,
+    ),
+    ("Consent_Actor", "extension", "extension", "Extension", true),
+    ("Consent_Actor", "id", "id", "string", false),
+    (
+        "Consent_Actor",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Consent_Actor",
+        "reference",
+        "reference",
+        "Reference",
+        false,
+    ),
+    ("Consent_Actor", "role", "role", "CodeableConcept", false),
+    ("Consent_Data", "extension", "extension", "Extension", true),
+    ("Consent_Data", "id", "id", "string", false),
+    ("Consent_Data", "meaning", "meaning", "code", false),
+    (
+        "Consent_Data",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Consent_Data", "reference", "reference", "Reference", false),
+    ("Consent_Policy", "authority", "authority", "uri", false),
+    (
+        "Consent_Policy",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Consent_Policy", "id", "id", "string", false),
+    (
+        "Consent_Policy",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Consent_Policy", "uri", "uri", "uri", false),
+    (
+        "Consent_Provision",
+        "action",
+        "action",
+        "CodeableConcept",
+        true,
+    ),
+    ("Consent_Provision", "actor", "actor", "Consent_Actor", true),
+    ("Consent_Provision", "class", "class", "Coding", true),
+    ("Consent_Provision", "code", "code", "CodeableConcept", true),
+    ("Consent_Provision", "data", "data", "Consent_Data", true),
+    (
+        "Consent_Provision",
+        "dataPeriod",
+        "dataPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "Consent_Provision",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Consent_Provision", "id", "id", "string", false),
+    (
+        "Consent_Provision",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Consent_Provision", "period", "period", "Period", false),
+    (
+        "Consent_Provision",
+        "provision",
+        "provision",
+        "Consent_Provision",
+        true,
+    ),
+    ("Consent_Provision", "purpose", "purpose", "Coding", true),
+    (
+        "Consent_Provision",
+        "securityLabel",
+        "securityLabel",
+        "Coding",
+        true,
+    ),
+    ("Consent_Provision", "type", "type", "code", false),
+    (
+        "Consent_Verification",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Consent_Verification", "id", "id", "string", false),
+    (
+        "Consent_Verification",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Consent_Verification",
+        "verificationDate",
+        "verificationDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "Consent_Verification",
+        "verified",
+        "verified",
+        "boolean",
+        false,
+    ),
+    (
+        "Consent_Verification",
+        "verifiedWith",
+        "verifiedWith",
+        "Reference",
+        false,
+    ),
+    ("ContactDetail", "extension", "extension", "Extension", true),
+    ("ContactDetail", "id", "id", "string", false),
+    ("ContactDetail", "name", "name", "string", false),
+    ("ContactDetail", "telecom", "telecom", "ContactPoint", true),
+    ("ContactPoint", "extension", "extension", "Extension", true),
+    ("ContactPoint", "id", "id", "string", false),
+    ("ContactPoint", "period", "period", "Period", false),
+    ("ContactPoint", "rank", "rank", "positiveInt", false),
+    ("ContactPoint", "system", "system", "code", false),
+    ("ContactPoint", "use", "use", "code", false),
+    ("ContactPoint", "value", "value", "string", false),
+    ("Contract", "alias", "alias", "string", true),
+    ("Contract", "applies", "applies", "Period", false),
+    ("Contract", "author", "author", "Reference", false),
+    ("Contract", "authority", "authority", "Reference", true),
+    ("Contract", "contained", "contained", "ResourceList", true),
+    (
+        "Contract",
+        "contentDefinition",
+        "contentDefinition",
+        "Contract_ContentDefinition",
+        false,
+    ),
+    (
+        "Contract",
+        "contentDerivative",
+        "contentDerivative",
+        "CodeableConcept",
+        false,
+    ),
+    ("Contract", "domain", "domain", "Reference", true),
+    (
+        "Contract",
+        "expirationType",
+        "expirationType",
+        "CodeableConcept",
+        false,
+    ),
+    ("Contract", "extension", "extension", "Extension", true),
+    (
+        "Contract",
+        "friendly",
+        "friendly",
+        "Contract_Friendly",
+        true,
+    ),
+    ("Contract", "id", "id", "id", false),
+    ("Contract", "identifier", "identifier", "Identifier", true),
+    ("Contract", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Contract",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        false,
+    ),
+    ("Contract", "issued", "issued", "dateTime", false),
+    ("Contract", "language", "language", "code", false),
+    ("Contract", "legal", "legal", "Contract_Legal", true),
+    (
+        "Contract",
+        "legalState",
+        "legalState",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract",
+        "legallyBindingAttachment",
+        "legallyBinding",
+        "Attachment",
+        false,
+    ),
+    (
+        "Contract",
+        "legallyBindingReference",
+        "legallyBinding",
+        "Reference",
+        false,
+    ),
+    ("Contract", "meta", "meta", "Meta", false),
+    (
+        "Contract",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract", "name", "name", "string", false),
+    (
+        "Contract",
+        "relevantHistory",
+        "relevantHistory",
+        "Reference",
+        true,
+    ),
+    ("Contract", "rule", "rule", "Contract_Rule", true),
+    ("Contract", "scope", "scope", "CodeableConcept", false),
+    ("Contract", "signer", "signer", "Contract_Signer", true),
+    ("Contract", "site", "site", "Reference", true),
+    ("Contract", "status", "status", "code", false),
+    ("Contract", "subType", "subType", "CodeableConcept", true),
+    ("Contract", "subject", "subject", "Reference", true),
+    ("Contract", "subtitle", "subtitle", "string", false),
+    (
+        "Contract",
+        "supportingInfo",
+        "supportingInfo",
+        "Reference",
+        true,
+    ),
+    ("Contract", "term", "term", "Contract_Term", true),
+    ("Contract", "text", "text", "Narrative", false),
+    ("Contract", "title", "title", "string", false),
+    (
+        "Contract",
+        "topicCodeableConcept",
+        "topic",
+        "CodeableConcept",
+        false,
+    ),
+    ("Contract", "topicReference", "topic", "Reference", false),
+    ("Contract", "type", "type", "CodeableConcept", false),
+    ("Contract", "url", "url", "uri", false),
+    ("Contract", "version", "version", "string", false),
+    ("Contract_Action", "context", "context", "Reference", false),
+    (
+        "Contract_Action",
+        "contextLinkId",
+        "contextLinkId",
+        "string",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "doNotPerform",
+        "doNotPerform",
+        "boolean",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Action", "id", "id", "string", false),
+    (
+        "Contract_Action",
+        "intent",
+        "intent",
+        "CodeableConcept",
+        false,
+    ),
+    ("Contract_Action", "linkId", "linkId", "string", true),
+    (
+        "Contract_Action",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Action", "note", "note", "Annotation", true),
+    (
+        "Contract_Action",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "occurrenceTiming",
+        "occurrence",
+        "Timing",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "performerLinkId",
+        "performerLinkId",
+        "string",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "performerRole",
+        "performerRole",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "performerType",
+        "performerType",
+        "CodeableConcept",
+        true,
+    ),
+    ("Contract_Action", "reason", "reason", "string", true),
+    (
+        "Contract_Action",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "reasonLinkId",
+        "reasonLinkId",
+        "string",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "requester",
+        "requester",
+        "Reference",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "requesterLinkId",
+        "requesterLinkId",
+        "string",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "securityLabelNumber",
+        "securityLabelNumber",
+        "unsignedInt",
+        true,
+    ),
+    (
+        "Contract_Action",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract_Action",
+        "subject",
+        "subject",
+        "Contract_Subject",
+        true,
+    ),
+    ("Contract_Action", "type", "type", "CodeableConcept", false),
+    (
+        "Contract_Answer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Answer", "id", "id", "string", false),
+    (
+        "Contract_Answer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_Answer",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    ("Contract_Answer", "valueBoolean", "value", "boolean", false),
+    ("Contract_Answer", "valueCoding", "value", "Coding", false),
+    ("Contract_Answer", "valueDate", "value", "date", false),
+    (
+        "Contract_Answer",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    ("Contract_Answer", "valueDecimal", "value", "decimal", false),
+    ("Contract_Answer", "valueInteger", "value", "integer", false),
+    (
+        "Contract_Answer",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Contract_Answer",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    ("Contract_Answer", "valueString", "value", "string", false),
+    ("Contract_Answer", "valueTime", "value", "time", false),
+    ("Contract_Answer", "valueUri", "value", "uri", false),
+    (
+        "Contract_Asset",
+        "answer",
+        "answer",
+        "Contract_Answer",
+        true,
+    ),
+    ("Contract_Asset", "condition", "condition", "string", false),
+    (
+        "Contract_Asset",
+        "context",
+        "context",
+        "Contract_Context",
+        true,
+    ),
+    (
+        "Contract_Asset",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Asset", "id", "id", "string", false),
+    ("Contract_Asset", "linkId", "linkId", "string", true),
+    (
+        "Contract_Asset",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Asset", "period", "period", "Period", true),
+    (
+        "Contract_Asset",
+        "periodType",
+        "periodType",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Contract_Asset",
+        "relationship",
+        "relationship",
+        "Coding",
+        false,
+    ),
+    ("Contract_Asset", "scope", "scope", "CodeableConcept", false),
+    (
+        "Contract_Asset",
+        "securityLabelNumber",
+        "securityLabelNumber",
+        "unsignedInt",
+        true,
+    ),
+    (
+        "Contract_Asset",
+        "subtype",
+        "subtype",
+        "CodeableConcept",
+        true,
+    ),
+    ("Contract_Asset", "text", "text", "string", false),
+    ("Contract_Asset", "type", "type", "CodeableConcept", true),
+    (
+        "Contract_Asset",
+        "typeReference",
+        "typeReference",
+        "Reference",
+        true,
+    ),
+    ("Contract_Asset", "usePeriod", "usePeriod", "Period", true),
+    (
+        "Contract_Asset",
+        "valuedItem",
+        "valuedItem",
+        "Contract_ValuedItem",
+        true,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_ContentDefinition", "id", "id", "string", false),
+    (
+        "Contract_ContentDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "publicationDate",
+        "publicationDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "publicationStatus",
+        "publicationStatus",
+        "code",
+        false,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "publisher",
+        "publisher",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "subType",
+        "subType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract_ContentDefinition",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    ("Contract_Context", "code", "code", "CodeableConcept", true),
+    (
+        "Contract_Context",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Context", "id", "id", "string", false),
+    (
+        "Contract_Context",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_Context",
+        "reference",
+        "reference",
+        "Reference",
+        false,
+    ),
+    ("Contract_Context", "text", "text", "string", false),
+    (
+        "Contract_Friendly",
+        "contentAttachment",
+        "content",
+        "Attachment",
+        false,
+    ),
+    (
+        "Contract_Friendly",
+        "contentReference",
+        "content",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_Friendly",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Friendly", "id", "id", "string", false),
+    (
+        "Contract_Friendly",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_Legal",
+        "contentAttachment",
+        "content",
+        "Attachment",
+        false,
+    ),
+    (
+        "Contract_Legal",
+        "contentReference",
+        "content",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_Legal",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Legal", "id", "id", "string", false),
+    (
+        "Contract_Legal",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_Offer",
+        "answer",
+        "answer",
+        "Contract_Answer",
+        true,
+    ),
+    (
+        "Contract_Offer",
+        "decision",
+        "decision",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract_Offer",
+        "decisionMode",
+        "decisionMode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Contract_Offer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Offer", "id", "id", "string", false),
+    (
+        "Contract_Offer",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    ("Contract_Offer", "linkId", "linkId", "string", true),
+    (
+        "Contract_Offer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Offer", "party", "party", "Contract_Party", true),
+    (
+        "Contract_Offer",
+        "securityLabelNumber",
+        "securityLabelNumber",
+        "unsignedInt",
+        true,
+    ),
+    ("Contract_Offer", "text", "text", "string", false),
+    ("Contract_Offer", "topic", "topic", "Reference", false),
+    ("Contract_Offer", "type", "type", "CodeableConcept", false),
+    (
+        "Contract_Party",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Party", "id", "id", "string", false),
+    (
+        "Contract_Party",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_Party",
+        "reference",
+        "reference",
+        "Reference",
+        true,
+    ),
+    ("Contract_Party", "role", "role", "CodeableConcept", false),
+    (
+        "Contract_Rule",
+        "contentAttachment",
+        "content",
+        "Attachment",
+        false,
+    ),
+    (
+        "Contract_Rule",
+        "contentReference",
+        "content",
+        "Reference",
+        false,
+    ),
+    ("Contract_Rule", "extension", "extension", "Extension", true),
+    ("Contract_Rule", "id", "id", "string", false),
+    (
+        "Contract_Rule",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_SecurityLabel",
+        "category",
+        "category",
+        "Coding",
+        true,
+    ),
+    (
+        "Contract_SecurityLabel",
+        "classification",
+        "classification",
+        "Coding",
+        false,
+    ),
+    (
+        "Contract_SecurityLabel",
+        "control",
+        "control",
+        "Coding",
+        true,
+    ),
+    (
+        "Contract_SecurityLabel",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_SecurityLabel", "id", "id", "string", false),
+    (
+        "Contract_SecurityLabel",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_SecurityLabel",
+        "number",
+        "number",
+        "unsignedInt",
+        true,
+    ),
+    (
+        "Contract_Signer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Signer", "id", "id", "string", false),
+    (
+        "Contract_Signer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Signer", "party", "party", "Reference", false),
+    (
+        "Contract_Signer",
+        "signature",
+        "signature",
+        "Signature",
+        true,
+    ),
+    ("Contract_Signer", "type", "type", "Coding", false),
+    (
+        "Contract_Subject",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Subject", "id", "id", "string", false),
+    (
+        "Contract_Subject",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Contract_Subject",
+        "reference",
+        "reference",
+        "Reference",
+        true,
+    ),
+    ("Contract_Subject", "role", "role", "CodeableConcept", false),
+    ("Contract_Term", "action", "action", "Contract_Action", true),
+    ("Contract_Term", "applies", "applies", "Period", false),
+    ("Contract_Term", "asset", "asset", "Contract_Asset", true),
+    ("Contract_Term", "extension", "extension", "Extension", true),
+    ("Contract_Term", "group", "group", "Contract_Term", true),
+    ("Contract_Term", "id", "id", "string", false),
+    (
+        "Contract_Term",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    ("Contract_Term", "issued", "issued", "dateTime", false),
+    (
+        "Contract_Term",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract_Term", "offer", "offer", "Contract_Offer", false),
+    (
+        "Contract_Term",
+        "securityLabel",
+        "securityLabel",
+        "Contract_SecurityLabel",
+        true,
+    ),
+    (
+        "Contract_Term",
+        "subType",
+        "subType",
+        "CodeableConcept",
+        false,
+    ),
+    ("Contract_Term", "text", "text", "string", false),
+    (
+        "Contract_Term",
+        "topicCodeableConcept",
+        "topic",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract_Term",
+        "topicReference",
+        "topic",
+        "Reference",
+        false,
+    ),
+    ("Contract_Term", "type", "type", "CodeableConcept", false),
+    (
+        "Contract_ValuedItem",
+        "effectiveTime",
+        "effectiveTime",
+        "dateTime",
+        false,
+    ),
+    (
+        "Contract_ValuedItem",
+        "entityCodeableConcept",
+        "entity",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Contract_ValuedItem",
+        "entityReference",
+        "entity",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_ValuedItem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Contract_ValuedItem", "factor", "factor", "decimal", false),
+    ("Contract_ValuedItem", "id", "id", "string", false),
+    (
+        "Contract_ValuedItem",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    ("Contract_ValuedItem", "linkId", "linkId", "string", true),
+    (
+        "Contract_ValuedItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Contract_ValuedItem", "net", "net", "Money", false),
+    ("Contract_ValuedItem", "payment", "payment", "string", false),
+    (
+        "Contract_ValuedItem",
+        "paymentDate",
+        "paymentDate",
+        "dateTime",
+        false,
+    ),
+    ("Contract_ValuedItem", "points", "points", "decimal", false),
+    (
+        "Contract_ValuedItem",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "Contract_ValuedItem",
+        "recipient",
+        "recipient",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_ValuedItem",
+        "responsible",
+        "responsible",
+        "Reference",
+        false,
+    ),
+    (
+        "Contract_ValuedItem",
+        "securityLabelNumber",
+        "securityLabelNumber",
+        "unsignedInt",
+        true,
+    ),
+    (
+        "Contract_ValuedItem",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    ("Contributor", "contact", "contact", "ContactDetail", true),
+    ("Contributor", "extension", "extension", "Extension", true),
+    ("Contributor", "id", "id", "string", false),
+    ("Contributor", "name", "name", "string", false),
+    ("Contributor", "type", "type", "code", false),
+    ("Count", "code", "code", "code", false),
+    ("Count", "comparator", "comparator", "code", false),
+    ("Count", "extension", "extension", "Extension", true),
+    ("Count", "id", "id", "string", false),
+    ("Count", "system", "system", "uri", false),
+    ("Count", "unit", "unit", "string", false),
+    ("Count", "value", "value", "decimal", false),
+    ("Coverage", "beneficiary", "beneficiary", "Reference", false),
+    ("Coverage", "class", "class", "Coverage_Class", true),
+    ("Coverage", "contained", "contained", "ResourceList", true),
+    ("Coverage", "contract", "contract", "Reference", true),
+    (
+        "Coverage",
+        "costToBeneficiary",
+        "costToBeneficiary",
+        "Coverage_CostToBeneficiary",
+        true,
+    ),
+    ("Coverage", "dependent", "dependent", "string", false),
+    ("Coverage", "extension", "extension", "Extension", true),
+    ("Coverage", "id", "id", "id", false),
+    ("Coverage", "identifier", "identifier", "Identifier", true),
+    ("Coverage", "implicitRules", "implicitRules", "uri", false),
+    ("Coverage", "language", "language", "code", false),
+    ("Coverage", "meta", "meta", "Meta", false),
+    (
+        "Coverage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Coverage", "network", "network", "string", false),
+    ("Coverage", "order", "order", "positiveInt", false),
+    ("Coverage", "payor", "payor", "Reference", true),
+    ("Coverage", "period", "period", "Period", false),
+    (
+        "Coverage",
+        "policyHolder",
+        "policyHolder",
+        "Reference",
+        false,
+    ),
+    (
+        "Coverage",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        false,
+    ),
+    ("Coverage", "status", "status", "code", false),
+    ("Coverage", "subrogation", "subrogation", "boolean", false),
+    ("Coverage", "subscriber", "subscriber", "Reference", false),
+    ("Coverage", "subscriberId", "subscriberId", "string", false),
+    ("Coverage", "text", "text", "Narrative", false),
+    ("Coverage", "type", "type", "CodeableConcept", false),
+    (
+        "CoverageEligibilityRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "created",
+        "created",
+        "dateTime",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "enterer",
+        "enterer",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "facility",
+        "facility",
+        "Reference",
+        false,
+    ),
+    ("CoverageEligibilityRequest", "id", "id", "id", false),
+    (
+        "CoverageEligibilityRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "insurance",
+        "insurance",
+        "CoverageEligibilityRequest_Insurance",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "insurer",
+        "insurer",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "item",
+        "item",
+        "CoverageEligibilityRequest_Item",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("CoverageEligibilityRequest", "meta", "meta", "Meta", false),
+    (
+        "CoverageEligibilityRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "priority",
+        "priority",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "provider",
+        "provider",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "purpose",
+        "purpose",
+        "code",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "servicedDate",
+        "serviced",
+        "date",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "servicedPeriod",
+        "serviced",
+        "Period",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "status",
+        "status",
+        "code",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "supportingInfo",
+        "supportingInfo",
+        "CoverageEligibilityRequest_SupportingInfo",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Diagnosis",
+        "diagnosisCodeableConcept",
+        "diagnosis",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Diagnosis",
+        "diagnosisReference",
+        "diagnosis",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Diagnosis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Diagnosis",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Diagnosis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Insurance",
+        "businessArrangement",
+        "businessArrangement",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Insurance",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Insurance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Insurance",
+        "focal",
+        "focal",
+        "boolean",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Insurance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Insurance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "detail",
+        "detail",
+        "Reference",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "diagnosis",
+        "diagnosis",
+        "CoverageEligibilityRequest_Diagnosis",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "facility",
+        "facility",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "provider",
+        "provider",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "supportingInfoSequence",
+        "supportingInfoSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_Item",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_SupportingInfo",
+        "appliesToAll",
+        "appliesToAll",
+        "boolean",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_SupportingInfo",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_SupportingInfo",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_SupportingInfo",
+        "information",
+        "information",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityRequest_SupportingInfo",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityRequest_SupportingInfo",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "created",
+        "created",
+        "dateTime",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "disposition",
+        "disposition",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "error",
+        "error",
+        "CoverageEligibilityResponse_Error",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "form",
+        "form",
+        "CodeableConcept",
+        false,
+    ),
+    ("CoverageEligibilityResponse", "id", "id", "id", false),
+    (
+        "CoverageEligibilityResponse",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "insurance",
+        "insurance",
+        "CoverageEligibilityResponse_Insurance",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "insurer",
+        "insurer",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("CoverageEligibilityResponse", "meta", "meta", "Meta", false),
+    (
+        "CoverageEligibilityResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "outcome",
+        "outcome",
+        "code",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "preAuthRef",
+        "preAuthRef",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "purpose",
+        "purpose",
+        "code",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "request",
+        "request",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "requestor",
+        "requestor",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "servicedDate",
+        "serviced",
+        "date",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "servicedPeriod",
+        "serviced",
+        "Period",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "status",
+        "status",
+        "code",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "allowedMoney",
+        "allowed",
+        "Money",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "allowedString",
+        "allowed",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "allowedUnsignedInt",
+        "allowed",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "usedMoney",
+        "used",
+        "Money",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "usedString",
+        "used",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Benefit",
+        "usedUnsignedInt",
+        "used",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Error",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Error",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Error",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Error",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "benefitPeriod",
+        "benefitPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "inforce",
+        "inforce",
+        "boolean",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "item",
+        "item",
+        "CoverageEligibilityResponse_Item",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Insurance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "authorizationRequired",
+        "authorizationRequired",
+        "boolean",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "authorizationSupporting",
+        "authorizationSupporting",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "authorizationUrl",
+        "authorizationUrl",
+        "uri",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "benefit",
+        "benefit",
+        "CoverageEligibilityResponse_Benefit",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "excluded",
+        "excluded",
+        "boolean",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "network",
+        "network",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "provider",
+        "provider",
+        "Reference",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "term",
+        "term",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "CoverageEligibilityResponse_Item",
+        "unit",
+        "unit",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Coverage_Class",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Coverage_Class", "id", "id", "string", false),
+    (
+        "Coverage_Class",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Coverage_Class", "name", "name", "string", false),
+    ("Coverage_Class", "type", "type", "CodeableConcept", false),
+    ("Coverage_Class", "value", "value", "string", false),
+    (
+        "Coverage_CostToBeneficiary",
+        "exception",
+        "exception",
+        "Coverage_Exception",
+        true,
+    ),
+    (
+        "Coverage_CostToBeneficiary",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Coverage_CostToBeneficiary", "id", "id", "string", false),
+    (
+        "Coverage_CostToBeneficiary",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Coverage_CostToBeneficiary",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Coverage_CostToBeneficiary",
+        "valueMoney",
+        "value",
+        "Money",
+        false,
+    ),
+    (
+        "Coverage_CostToBeneficiary",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Coverage_Exception",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Coverage_Exception", "id", "id", "string", false),
+    (
+        "Coverage_Exception",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Coverage_Exception", "period", "period", "Period", false),
+    (
+        "Coverage_Exception",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DataRequirement",
+        "codeFilter",
+        "codeFilter",
+        "DataRequirement_CodeFilter",
+        true,
+    ),
+    (
+        "DataRequirement",
+        "dateFilter",
+        "dateFilter",
+        "DataRequirement_DateFilter",
+        true,
+    ),
+    (
+        "DataRequirement",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DataRequirement", "id", "id", "string", false),
+    ("DataRequirement", "limit", "limit", "positiveInt", false),
+    (
+        "DataRequirement",
+        "mustSupport",
+        "mustSupport",
+        "string",
+        true,
+    ),
+    ("DataRequirement", "profile", "profile", "canonical", true),
+    (
+        "DataRequirement",
+        "sort",
+        "sort",
+        "DataRequirement_Sort",
+        true,
+    ),
+    (
+        "DataRequirement",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DataRequirement",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("DataRequirement", "type", "type", "code", false),
+    ("DataRequirement_CodeFilter", "code", "code", "Coding", true),
+    (
+        "DataRequirement_CodeFilter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DataRequirement_CodeFilter", "id", "id", "string", false),
+    (
+        "DataRequirement_CodeFilter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DataRequirement_CodeFilter",
+        "path",
+        "path",
+        "string",
+        false,
+    ),
+    (
+        "DataRequirement_CodeFilter",
+        "searchParam",
+        "searchParam",
+        "string",
+        false,
+    ),
+    (
+        "DataRequirement_CodeFilter",
+        "valueSet",
+        "valueSet",
+        "canonical",
+        false,
+    ),
+    (
+        "DataRequirement_DateFilter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DataRequirement_DateFilter", "id", "id", "string", false),
+    (
+        "DataRequirement_DateFilter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DataRequirement_DateFilter",
+        "path",
+        "path",
+        "string",
+        false,
+    ),
+    (
+        "DataRequirement_DateFilter",
+        "searchParam",
+        "searchParam",
+        "string",
+        false,
+    ),
+    (
+        "DataRequirement_DateFilter",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "DataRequirement_DateFilter",
+        "valueDuration",
+        "value",
+        "Duration",
+        false,
+    ),
+    (
+        "DataRequirement_DateFilter",
+        "valuePeriod",
+        "value",
+        "Period",
+        false,
+    ),
+    (
+        "DataRequirement_Sort",
+        "direction",
+        "direction",
+        "code",
+        false,
+    ),
+    (
+        "DataRequirement_Sort",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DataRequirement_Sort", "id", "id", "string", false),
+    (
+        "DataRequirement_Sort",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DataRequirement_Sort", "path", "path", "string", false),
+    ("DetectedIssue", "author", "author", "Reference", false),
+    ("DetectedIssue", "code", "code", "CodeableConcept", false),
+    (
+        "DetectedIssue",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("DetectedIssue", "detail", "detail", "string", false),
+    (
+        "DetectedIssue",
+        "evidence",
+        "evidence",
+        "DetectedIssue_Evidence",
+        true,
+    ),
+    ("DetectedIssue", "extension", "extension", "Extension", true),
+    ("DetectedIssue", "id", "id", "id", false),
+    (
+        "DetectedIssue",
+        "identifiedDateTime",
+        "identified",
+        "dateTime",
+        false,
+    ),
+    (
+        "DetectedIssue",
+        "identifiedPeriod",
+        "identified",
+        "Period",
+        false,
+    ),
+    (
+        "DetectedIssue",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DetectedIssue",
+        "implicated",
+        "implicated",
+        "Reference",
+        true,
+    ),
+    (
+        "DetectedIssue",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DetectedIssue", "language", "language", "code", false),
+    ("DetectedIssue", "meta", "meta", "Meta", false),
+    (
+        "DetectedIssue",
+        "mitigation",
+        "mitigation",
+        "DetectedIssue_Mitigation",
+        true,
+    ),
+    (
+        "DetectedIssue",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DetectedIssue", "patient", "patient", "Reference", false),
+    ("DetectedIssue", "reference", "reference", "uri", false),
+    ("DetectedIssue", "severity", "severity", "code", false),
+    ("DetectedIssue", "status", "status", "code", false),
+    ("DetectedIssue", "text", "text", "Narrative", false),
+    (
+        "DetectedIssue_Evidence",
+        "code",
+        "code",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DetectedIssue_Evidence",
+        "detail",
+        "detail",
+        "Reference",
+        true,
+    ),
+    (
+        "DetectedIssue_Evidence",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DetectedIssue_Evidence", "id", "id", "string", false),
+    (
+        "DetectedIssue_Evidence",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DetectedIssue_Mitigation",
+        "action",
+        "action",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DetectedIssue_Mitigation",
+        "author",
+        "author",
+        "Reference",
+        false,
+    ),
+    (
+        "DetectedIssue_Mitigation",
+        "date",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "DetectedIssue_Mitigation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DetectedIssue_Mitigation", "id", "id", "string", false),
+    (
+        "DetectedIssue_Mitigation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Device", "contact", "contact", "ContactPoint", true),
+    ("Device", "contained", "contained", "ResourceList", true),
+    ("Device", "definition", "definition", "Reference", false),
+    (
+        "Device",
+        "deviceName",
+        "deviceName",
+        "Device_DeviceName",
+        true,
+    ),
+    (
+        "Device",
+        "distinctIdentifier",
+        "distinctIdentifier",
+        "string",
+        false,
+    ),
+    (
+        "Device",
+        "expirationDate",
+        "expirationDate",
+        "dateTime",
+        false,
+    ),
+    ("Device", "extension", "extension", "Extension", true),
+    ("Device", "id", "id", "id", false),
+    ("Device", "identifier", "identifier", "Identifier", true),
+    ("Device", "implicitRules", "implicitRules", "uri", false),
+    ("Device", "language", "language", "code", false),
+    ("Device", "location", "location", "Reference", false),
+    ("Device", "lotNumber", "lotNumber", "string", false),
+    (
+        "Device",
+        "manufactureDate",
+        "manufactureDate",
+        "dateTime",
+        false,
+    ),
+    ("Device", "manufacturer", "manufacturer", "string", false),
+    ("Device", "meta", "meta", "Meta", false),
+    ("Device", "modelNumber", "modelNumber", "string", false),
+    (
+        "Device",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Device", "note", "note", "Annotation", true),
+    ("Device", "owner", "owner", "Reference", false),
+    ("Device", "parent", "parent", "Reference", false),
+    ("Device", "partNumber", "partNumber", "string", false),
+    ("Device", "patient", "patient", "Reference", false),
+    ("Device", "property", "property", "Device_Property", true),
+    ("Device", "safety", "safety", "CodeableConcept", true),
+    ("Device", "serialNumber", "serialNumber", "string", false),
+    (
+        "Device",
+        "specialization",
+        "specialization",
+        "Device_Specialization",
+        true,
+    ),
+    ("Device", "status", "status", "code", false),
+    (
+        "Device",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        true,
+    ),
+    ("Device", "text", "text", "Narrative", false),
+    ("Device", "type", "type", "CodeableConcept", false),
+    (
+        "Device",
+        "udiCarrier",
+        "udiCarrier",
+        "Device_UdiCarrier",
+        true,
+    ),
+    ("Device", "url", "url", "uri", false),
+    ("Device", "version", "version", "Device_Version", true),
+    (
+        "DeviceDefinition",
+        "capability",
+        "capability",
+        "DeviceDefinition_Capability",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "contact",
+        "contact",
+        "ContactPoint",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "deviceName",
+        "deviceName",
+        "DeviceDefinition_DeviceName",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceDefinition", "id", "id", "id", false),
+    (
+        "DeviceDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DeviceDefinition", "language", "language", "code", false),
+    (
+        "DeviceDefinition",
+        "languageCode",
+        "languageCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "manufacturerReference",
+        "manufacturer",
+        "Reference",
+        false,
+    ),
+    (
+        "DeviceDefinition",
+        "manufacturerString",
+        "manufacturer",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition",
+        "material",
+        "material",
+        "DeviceDefinition_Material",
+        true,
+    ),
+    ("DeviceDefinition", "meta", "meta", "Meta", false),
+    (
+        "DeviceDefinition",
+        "modelNumber",
+        "modelNumber",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DeviceDefinition", "note", "note", "Annotation", true),
+    (
+        "DeviceDefinition",
+        "onlineInformation",
+        "onlineInformation",
+        "uri",
+        false,
+    ),
+    ("DeviceDefinition", "owner", "owner", "Reference", false),
+    (
+        "DeviceDefinition",
+        "parentDevice",
+        "parentDevice",
+        "Reference",
+        false,
+    ),
+    (
+        "DeviceDefinition",
+        "physicalCharacteristics",
+        "physicalCharacteristics",
+        "ProdCharacteristic",
+        false,
+    ),
+    (
+        "DeviceDefinition",
+        "property",
+        "property",
+        "DeviceDefinition_Property",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "DeviceDefinition",
+        "safety",
+        "safety",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "shelfLifeStorage",
+        "shelfLifeStorage",
+        "ProductShelfLife",
+        true,
+    ),
+    (
+        "DeviceDefinition",
+        "specialization",
+        "specialization",
+        "DeviceDefinition_Specialization",
+        true,
+    ),
+    ("DeviceDefinition", "text", "text", "Narrative", false),
+    ("DeviceDefinition", "type", "type", "CodeableConcept", false),
+    (
+        "DeviceDefinition",
+        "udiDeviceIdentifier",
+        "udiDeviceIdentifier",
+        "DeviceDefinition_UdiDeviceIdentifier",
+        true,
+    ),
+    ("DeviceDefinition", "url", "url", "uri", false),
+    ("DeviceDefinition", "version", "version", "string", true),
+    (
+        "DeviceDefinition_Capability",
+        "description",
+        "description",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DeviceDefinition_Capability",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceDefinition_Capability", "id", "id", "string", false),
+    (
+        "DeviceDefinition_Capability",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_Capability",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceDefinition_DeviceName",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceDefinition_DeviceName", "id", "id", "string", false),
+    (
+        "DeviceDefinition_DeviceName",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_DeviceName",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    ("DeviceDefinition_DeviceName", "type", "type", "code", false),
+    (
+        "DeviceDefinition_Material",
+        "allergenicIndicator",
+        "allergenicIndicator",
+        "boolean",
+        false,
+    ),
+    (
+        "DeviceDefinition_Material",
+        "alternate",
+        "alternate",
+        "boolean",
+        false,
+    ),
+    (
+        "DeviceDefinition_Material",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceDefinition_Material", "id", "id", "string", false),
+    (
+        "DeviceDefinition_Material",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_Material",
+        "substance",
+        "substance",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceDefinition_Property",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceDefinition_Property", "id", "id", "string", false),
+    (
+        "DeviceDefinition_Property",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_Property",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceDefinition_Property",
+        "valueCode",
+        "valueCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DeviceDefinition_Property",
+        "valueQuantity",
+        "valueQuantity",
+        "Quantity",
+        true,
+    ),
+    (
+        "DeviceDefinition_Specialization",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_Specialization",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition_Specialization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_Specialization",
+        "systemType",
+        "systemType",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition_Specialization",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition_UdiDeviceIdentifier",
+        "deviceIdentifier",
+        "deviceIdentifier",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition_UdiDeviceIdentifier",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceDefinition_UdiDeviceIdentifier",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "DeviceDefinition_UdiDeviceIdentifier",
+        "issuer",
+        "issuer",
+        "uri",
+        false,
+    ),
+    (
+        "DeviceDefinition_UdiDeviceIdentifier",
+        "jurisdiction",
+        "jurisdiction",
+        "uri",
+        false,
+    ),
+    (
+        "DeviceDefinition_UdiDeviceIdentifier",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceMetric",
+        "calibration",
+        "calibration",
+        "DeviceMetric_Calibration",
+        true,
+    ),
+    ("DeviceMetric", "category", "category", "code", false),
+    ("DeviceMetric", "color", "color", "code", false),
+    (
+        "DeviceMetric",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("DeviceMetric", "extension", "extension", "Extension", true),
+    ("DeviceMetric", "id", "id", "id", false),
+    (
+        "DeviceMetric",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DeviceMetric",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DeviceMetric", "language", "language", "code", false),
+    (
+        "DeviceMetric",
+        "measurementPeriod",
+        "measurementPeriod",
+        "Timing",
+        false,
+    ),
+    ("DeviceMetric", "meta", "meta", "Meta", false),
+    (
+        "DeviceMetric",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceMetric",
+        "operationalStatus",
+        "operationalStatus",
+        "code",
+        false,
+    ),
+    ("DeviceMetric", "parent", "parent", "Reference", false),
+    ("DeviceMetric", "source", "source", "Reference", false),
+    ("DeviceMetric", "text", "text", "Narrative", false),
+    ("DeviceMetric", "type", "type", "CodeableConcept", false),
+    ("DeviceMetric", "unit", "unit", "CodeableConcept", false),
+    (
+        "DeviceMetric_Calibration",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceMetric_Calibration", "id", "id", "string", false),
+    (
+        "DeviceMetric_Calibration",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DeviceMetric_Calibration", "state", "state", "code", false),
+    ("DeviceMetric_Calibration", "time", "time", "instant", false),
+    ("DeviceMetric_Calibration", "type", "type", "code", false),
+    (
+        "DeviceRequest",
+        "authoredOn",
+        "authoredOn",
+        "dateTime",
+        false,
+    ),
+    ("DeviceRequest", "basedOn", "basedOn", "Reference", true),
+    (
+        "DeviceRequest",
+        "codeCodeableConcept",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    ("DeviceRequest", "codeReference", "code", "Reference", false),
+    (
+        "DeviceRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    ("DeviceRequest", "extension", "extension", "Extension", true),
+    (
+        "DeviceRequest",
+        "groupIdentifier",
+        "groupIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("DeviceRequest", "id", "id", "id", false),
+    (
+        "DeviceRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "DeviceRequest",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("DeviceRequest", "insurance", "insurance", "Reference", true),
+    ("DeviceRequest", "intent", "intent", "code", false),
+    ("DeviceRequest", "language", "language", "code", false),
+    ("DeviceRequest", "meta", "meta", "Meta", false),
+    (
+        "DeviceRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DeviceRequest", "note", "note", "Annotation", true),
+    (
+        "DeviceRequest",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "DeviceRequest",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "DeviceRequest",
+        "occurrenceTiming",
+        "occurrence",
+        "Timing",
+        false,
+    ),
+    (
+        "DeviceRequest",
+        "parameter",
+        "parameter",
+        "DeviceRequest_Parameter",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    (
+        "DeviceRequest",
+        "performerType",
+        "performerType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceRequest",
+        "priorRequest",
+        "priorRequest",
+        "Reference",
+        true,
+    ),
+    ("DeviceRequest", "priority", "priority", "code", false),
+    (
+        "DeviceRequest",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "relevantHistory",
+        "relevantHistory",
+        "Reference",
+        true,
+    ),
+    (
+        "DeviceRequest",
+        "requester",
+        "requester",
+        "Reference",
+        false,
+    ),
+    ("DeviceRequest", "status", "status", "code", false),
+    ("DeviceRequest", "subject", "subject", "Reference", false),
+    (
+        "DeviceRequest",
+        "supportingInfo",
+        "supportingInfo",
+        "Reference",
+        true,
+    ),
+    ("DeviceRequest", "text", "text", "Narrative", false),
+    (
+        "DeviceRequest_Parameter",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceRequest_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceRequest_Parameter", "id", "id", "string", false),
+    (
+        "DeviceRequest_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DeviceRequest_Parameter",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "DeviceRequest_Parameter",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceRequest_Parameter",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "DeviceRequest_Parameter",
+        "valueRange",
+        "value",
+        "Range",
+        false,
+    ),
+    (
+        "DeviceUseStatement",
+        "basedOn",
+        "basedOn",
+        "Reference",
+        true,
+    ),
+    (
+        "DeviceUseStatement",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DeviceUseStatement",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "DeviceUseStatement",
+        "derivedFrom",
+        "derivedFrom",
+        "Reference",
+        true,
+    ),
+    ("DeviceUseStatement", "device", "device", "Reference", false),
+    (
+        "DeviceUseStatement",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DeviceUseStatement", "id", "id", "id", false),
+    (
+        "DeviceUseStatement",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DeviceUseStatement",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DeviceUseStatement", "language", "language", "code", false),
+    ("DeviceUseStatement", "meta", "meta", "Meta", false),
+    (
+        "DeviceUseStatement",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DeviceUseStatement", "note", "note", "Annotation", true),
+    (
+        "DeviceUseStatement",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DeviceUseStatement",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "DeviceUseStatement",
+        "recordedOn",
+        "recordedOn",
+        "dateTime",
+        false,
+    ),
+    ("DeviceUseStatement", "source", "source", "Reference", false),
+    ("DeviceUseStatement", "status", "status", "code", false),
+    (
+        "DeviceUseStatement",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("DeviceUseStatement", "text", "text", "Narrative", false),
+    (
+        "DeviceUseStatement",
+        "timingDateTime",
+        "timing",
+        "dateTime",
+        false,
+    ),
+    (
+        "DeviceUseStatement",
+        "timingPeriod",
+        "timing",
+        "Period",
+        false,
+    ),
+    (
+        "DeviceUseStatement",
+        "timingTiming",
+        "timing",
+        "Timing",
+        false,
+    ),
+    (
+        "Device_DeviceName",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Device_DeviceName", "id", "id", "string", false),
+    (
+        "Device_DeviceName",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Device_DeviceName", "name", "name", "string", false),
+    ("Device_DeviceName", "type", "type", "code", false),
+    (
+        "Device_Property",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Device_Property", "id", "id", "string", false),
+    (
+        "Device_Property",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Device_Property", "type", "type", "CodeableConcept", false),
+    (
+        "Device_Property",
+        "valueCode",
+        "valueCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Device_Property",
+        "valueQuantity",
+        "valueQuantity",
+        "Quantity",
+        true,
+    ),
+    (
+        "Device_Specialization",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Device_Specialization", "id", "id", "string", false),
+    (
+        "Device_Specialization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Device_Specialization",
+        "systemType",
+        "systemType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Device_Specialization",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "Device_UdiCarrier",
+        "carrierAIDC",
+        "carrierAIDC",
+        "base64Binary",
+        false,
+    ),
+    (
+        "Device_UdiCarrier",
+        "carrierHRF",
+        "carrierHRF",
+        "string",
+        false,
+    ),
+    (
+        "Device_UdiCarrier",
+        "deviceIdentifier",
+        "deviceIdentifier",
+        "string",
+        false,
+    ),
+    ("Device_UdiCarrier", "entryType", "entryType", "code", false),
+    (
+        "Device_UdiCarrier",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Device_UdiCarrier", "id", "id", "string", false),
+    ("Device_UdiCarrier", "issuer", "issuer", "uri", false),
+    (
+        "Device_UdiCarrier",
+        "jurisdiction",
+        "jurisdiction",
+        "uri",
+        false,
+    ),
+    (
+        "Device_UdiCarrier",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Device_Version",
+        "component",
+        "component",
+        "Identifier",
+        false,
+    ),
+    (
+        "Device_Version",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Device_Version", "id", "id", "string", false),
+    (
+        "Device_Version",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Device_Version", "type", "type", "CodeableConcept", false),
+    ("Device_Version", "value", "value", "string", false),
+    ("DiagnosticReport", "basedOn", "basedOn", "Reference", true),
+    (
+        "DiagnosticReport",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    ("DiagnosticReport", "code", "code", "CodeableConcept", false),
+    (
+        "DiagnosticReport",
+        "conclusion",
+        "conclusion",
+        "string",
+        false,
+    ),
+    (
+        "DiagnosticReport",
+        "conclusionCode",
+        "conclusionCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "effectiveDateTime",
+        "effective",
+        "dateTime",
+        false,
+    ),
+    (
+        "DiagnosticReport",
+        "effectivePeriod",
+        "effective",
+        "Period",
+        false,
+    ),
+    (
+        "DiagnosticReport",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "DiagnosticReport",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DiagnosticReport", "id", "id", "id", false),
+    (
+        "DiagnosticReport",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "imagingStudy",
+        "imagingStudy",
+        "Reference",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DiagnosticReport", "issued", "issued", "instant", false),
+    ("DiagnosticReport", "language", "language", "code", false),
+    (
+        "DiagnosticReport",
+        "media",
+        "media",
+        "DiagnosticReport_Media",
+        true,
+    ),
+    ("DiagnosticReport", "meta", "meta", "Meta", false),
+    (
+        "DiagnosticReport",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "performer",
+        "performer",
+        "Reference",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "presentedForm",
+        "presentedForm",
+        "Attachment",
+        true,
+    ),
+    ("DiagnosticReport", "result", "result", "Reference", true),
+    (
+        "DiagnosticReport",
+        "resultsInterpreter",
+        "resultsInterpreter",
+        "Reference",
+        true,
+    ),
+    (
+        "DiagnosticReport",
+        "specimen",
+        "specimen",
+        "Reference",
+        true,
+    ),
+    ("DiagnosticReport", "status", "status", "code", false),
+    ("DiagnosticReport", "subject", "subject", "Reference", false),
+    ("DiagnosticReport", "text", "text", "Narrative", false),
+    (
+        "DiagnosticReport_Media",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "DiagnosticReport_Media",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DiagnosticReport_Media", "id", "id", "string", false),
+    ("DiagnosticReport_Media", "link", "link", "Reference", false),
+    (
+        "DiagnosticReport_Media",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Distance", "code", "code", "code", false),
+    ("Distance", "comparator", "comparator", "code", false),
+    ("Distance", "extension", "extension", "Extension", true),
+    ("Distance", "id", "id", "string", false),
+    ("Distance", "system", "system", "uri", false),
+    ("Distance", "unit", "unit", "string", false),
+    ("Distance", "value", "value", "decimal", false),
+    ("DocumentManifest", "author", "author", "Reference", true),
+    (
+        "DocumentManifest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("DocumentManifest", "content", "content", "Reference", true),
+    ("DocumentManifest", "created", "created", "dateTime", false),
+    (
+        "DocumentManifest",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "DocumentManifest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DocumentManifest", "id", "id", "id", false),
+    (
+        "DocumentManifest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DocumentManifest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DocumentManifest", "language", "language", "code", false),
+    (
+        "DocumentManifest",
+        "masterIdentifier",
+        "masterIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("DocumentManifest", "meta", "meta", "Meta", false),
+    (
+        "DocumentManifest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentManifest",
+        "recipient",
+        "recipient",
+        "Reference",
+        true,
+    ),
+    (
+        "DocumentManifest",
+        "related",
+        "related",
+        "DocumentManifest_Related",
+        true,
+    ),
+    ("DocumentManifest", "source", "source", "uri", false),
+    ("DocumentManifest", "status", "status", "code", false),
+    ("DocumentManifest", "subject", "subject", "Reference", false),
+    ("DocumentManifest", "text", "text", "Narrative", false),
+    ("DocumentManifest", "type", "type", "CodeableConcept", false),
+    (
+        "DocumentManifest_Related",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DocumentManifest_Related", "id", "id", "string", false),
+    (
+        "DocumentManifest_Related",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "DocumentManifest_Related",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("DocumentManifest_Related", "ref", "ref", "Reference", false),
+    (
+        "DocumentReference",
+        "authenticator",
+        "authenticator",
+        "Reference",
+        false,
+    ),
+    ("DocumentReference", "author", "author", "Reference", true),
+    (
+        "DocumentReference",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DocumentReference",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "DocumentReference",
+        "content",
+        "content",
+        "DocumentReference_Content",
+        true,
+    ),
+    (
+        "DocumentReference",
+        "context",
+        "context",
+        "DocumentReference_Context",
+        false,
+    ),
+    (
+        "DocumentReference",
+        "custodian",
+        "custodian",
+        "Reference",
+        false,
+    ),
+    ("DocumentReference", "date", "date", "instant", false),
+    (
+        "DocumentReference",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    ("DocumentReference", "docStatus", "docStatus", "code", false),
+    (
+        "DocumentReference",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DocumentReference", "id", "id", "id", false),
+    (
+        "DocumentReference",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "DocumentReference",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("DocumentReference", "language", "language", "code", false),
+    (
+        "DocumentReference",
+        "masterIdentifier",
+        "masterIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("DocumentReference", "meta", "meta", "Meta", false),
+    (
+        "DocumentReference",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentReference",
+        "relatesTo",
+        "relatesTo",
+        "DocumentReference_RelatesTo",
+        true,
+    ),
+    (
+        "DocumentReference",
+        "securityLabel",
+        "securityLabel",
+        "CodeableConcept",
+        true,
+    ),
+    ("DocumentReference", "status", "status", "code", false),
+    (
+        "DocumentReference",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("DocumentReference", "text", "text", "Narrative", false),
+    (
+        "DocumentReference",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DocumentReference_Content",
+        "attachment",
+        "attachment",
+        "Attachment",
+        false,
+    ),
+    (
+        "DocumentReference_Content",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentReference_Content",
+        "format",
+        "format",
+        "Coding",
+        false,
+    ),
+    ("DocumentReference_Content", "id", "id", "string", false),
+    (
+        "DocumentReference_Content",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentReference_Context",
+        "encounter",
+        "encounter",
+        "Reference",
+        true,
+    ),
+    (
+        "DocumentReference_Context",
+        "event",
+        "event",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "DocumentReference_Context",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentReference_Context",
+        "facilityType",
+        "facilityType",
+        "CodeableConcept",
+        false,
+    ),
+    ("DocumentReference_Context", "id", "id", "string", false),
+    (
+        "DocumentReference_Context",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentReference_Context",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    (
+        "DocumentReference_Context",
+        "practiceSetting",
+        "practiceSetting",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "DocumentReference_Context",
+        "related",
+        "related",
+        "Reference",
+        true,
+    ),
+    (
+        "DocumentReference_Context",
+        "sourcePatientInfo",
+        "sourcePatientInfo",
+        "Reference",
+        false,
+    ),
+    ("DocumentReference_RelatesTo", "code", "code", "code", false),
+    (
+        "DocumentReference_RelatesTo",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("DocumentReference_RelatesTo", "id", "id", "string", false),
+    (
+        "DocumentReference_RelatesTo",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "DocumentReference_RelatesTo",
+        "target",
+        "target",
+        "Reference",
+        false,
+    ),
+    (
+        "Dosage",
+        "additionalInstruction",
+        "additionalInstruction",
+        "CodeableConcept",
+        true,
+    ),
+    ("Dosage", "asNeededBoolean", "asNeeded", "boolean", false),
+    (
+        "Dosage",
+        "asNeededCodeableConcept",
+        "asNeeded",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Dosage",
+        "doseAndRate",
+        "doseAndRate",
+        "Dosage_DoseAndRate",
+        true,
+    ),
+    ("Dosage", "extension", "extension", "Extension", true),
+    ("Dosage", "id", "id", "string", false),
+    (
+        "Dosage",
+        "maxDosePerAdministration",
+        "maxDosePerAdministration",
+        "Quantity",
+        false,
+    ),
+    (
+        "Dosage",
+        "maxDosePerLifetime",
+        "maxDosePerLifetime",
+        "Quantity",
+        false,
+    ),
+    (
+        "Dosage",
+        "maxDosePerPeriod",
+        "maxDosePerPeriod",
+        "Ratio",
+        false,
+    ),
+    ("Dosage", "method", "method", "CodeableConcept", false),
+    (
+        "Dosage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Dosage",
+        "patientInstruction",
+        "patientInstruction",
+        "string",
+        false,
+    ),
+    ("Dosage", "route", "route", "CodeableConcept", false),
+    ("Dosage", "sequence", "sequence", "integer", false),
+    ("Dosage", "site", "site", "CodeableConcept", false),
+    ("Dosage", "text", "text", "string", false),
+    ("Dosage", "timing", "timing", "Timing", false),
+    (
+        "Dosage_DoseAndRate",
+        "doseQuantity",
+        "dose",
+        "Quantity",
+        false,
+    ),
+    ("Dosage_DoseAndRate", "doseRange", "dose", "Range", false),
+    (
+        "Dosage_DoseAndRate",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Dosage_DoseAndRate", "id", "id", "string", false),
+    (
+        "Dosage_DoseAndRate",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Dosage_DoseAndRate",
+        "rateQuantity",
+        "rate",
+        "Quantity",
+        false,
+    ),
+    ("Dosage_DoseAndRate", "rateRange", "rate", "Range", false),
+    ("Dosage_DoseAndRate", "rateRatio", "rate", "Ratio", false),
+    (
+        "Dosage_DoseAndRate",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    ("Duration", "code", "code", "code", false),
+    ("Duration", "comparator", "comparator", "code", false),
+    ("Duration", "extension", "extension", "Extension", true),
+    ("Duration", "id", "id", "string", false),
+    ("Duration", "system", "system", "uri", false),
+    ("Duration", "unit", "unit", "string", false),
+    ("Duration", "value", "value", "decimal", false),
+    (
+        "EffectEvidenceSynthesis",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "author",
+        "author",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "certainty",
+        "certainty",
+        "EffectEvidenceSynthesis_Certainty",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("EffectEvidenceSynthesis", "date", "date", "dateTime", false),
+    (
+        "EffectEvidenceSynthesis",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "editor",
+        "editor",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "effectEstimate",
+        "effectEstimate",
+        "EffectEvidenceSynthesis_EffectEstimate",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "exposure",
+        "exposure",
+        "Reference",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "exposureAlternative",
+        "exposureAlternative",
+        "Reference",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EffectEvidenceSynthesis", "id", "id", "id", false),
+    (
+        "EffectEvidenceSynthesis",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("EffectEvidenceSynthesis", "meta", "meta", "Meta", false),
+    (
+        "EffectEvidenceSynthesis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("EffectEvidenceSynthesis", "name", "name", "string", false),
+    (
+        "EffectEvidenceSynthesis",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "outcome",
+        "outcome",
+        "Reference",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "population",
+        "population",
+        "Reference",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "resultsByExposure",
+        "resultsByExposure",
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "sampleSize",
+        "sampleSize",
+        "EffectEvidenceSynthesis_SampleSize",
+        false,
+    ),
+    ("EffectEvidenceSynthesis", "status", "status", "code", false),
+    (
+        "EffectEvidenceSynthesis",
+        "studyType",
+        "studyType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "synthesisType",
+        "synthesisType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    ("EffectEvidenceSynthesis", "title", "title", "string", false),
+    (
+        "EffectEvidenceSynthesis",
+        "topic",
+        "topic",
+        "CodeableConcept",
+        true,
+    ),
+    ("EffectEvidenceSynthesis", "url", "url", "uri", false),
+    (
+        "EffectEvidenceSynthesis",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_Certainty",
+        "certaintySubcomponent",
+        "certaintySubcomponent",
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_Certainty",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_Certainty",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_Certainty",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_Certainty",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_Certainty",
+        "rating",
+        "rating",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        "rating",
+        "rating",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_CertaintySubcomponent",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "precisionEstimate",
+        "precisionEstimate",
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "unitOfMeasure",
+        "unitOfMeasure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "value",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_EffectEstimate",
+        "variantState",
+        "variantState",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "from",
+        "from",
+        "decimal",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "level",
+        "level",
+        "decimal",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "to",
+        "to",
+        "decimal",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_PrecisionEstimate",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "exposureState",
+        "exposureState",
+        "code",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "riskEvidenceSynthesis",
+        "riskEvidenceSynthesis",
+        "Reference",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_ResultsByExposure",
+        "variantState",
+        "variantState",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_SampleSize",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_SampleSize",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_SampleSize",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_SampleSize",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EffectEvidenceSynthesis_SampleSize",
+        "numberOfParticipants",
+        "numberOfParticipants",
+        "integer",
+        false,
+    ),
+    (
+        "EffectEvidenceSynthesis_SampleSize",
+        "numberOfStudies",
+        "numberOfStudies",
+        "integer",
+        false,
+    ),
+    ("Element", "extension", "extension", "Extension", true),
+    ("Element", "id", "id", "string", false),
+    ("ElementDefinition", "alias", "alias", "string", true),
+    (
+        "ElementDefinition",
+        "base",
+        "base",
+        "ElementDefinition_Base",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "binding",
+        "binding",
+        "ElementDefinition_Binding",
+        false,
+    ),
+    ("ElementDefinition", "code", "code", "Coding", true),
+    ("ElementDefinition", "comment", "comment", "markdown", false),
+    ("ElementDefinition", "condition", "condition", "id", true),
+    (
+        "ElementDefinition",
+        "constraint",
+        "constraint",
+        "ElementDefinition_Constraint",
+        true,
+    ),
+    (
+        "ElementDefinition",
+        "contentReference",
+        "contentReference",
+        "uri",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueAddress",
+        "defaultValue",
+        "Address",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueAge",
+        "defaultValue",
+        "Age",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueAnnotation",
+        "defaultValue",
+        "Annotation",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueAttachment",
+        "defaultValue",
+        "Attachment",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueBase64Binary",
+        "defaultValue",
+        "base64Binary",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueBoolean",
+        "defaultValue",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueCanonical",
+        "defaultValue",
+        "canonical",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueCode",
+        "defaultValue",
+        "code",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueCodeableConcept",
+        "defaultValue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueCoding",
+        "defaultValue",
+        "Coding",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueContactDetail",
+        "defaultValue",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueContactPoint",
+        "defaultValue",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueContributor",
+        "defaultValue",
+        "Contributor",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueCount",
+        "defaultValue",
+        "Count",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDataRequirement",
+        "defaultValue",
+        "DataRequirement",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDate",
+        "defaultValue",
+        "date",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDateTime",
+        "defaultValue",
+        "dateTime",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDecimal",
+        "defaultValue",
+        "decimal",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDistance",
+        "defaultValue",
+        "Distance",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDosage",
+        "defaultValue",
+        "Dosage",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueDuration",
+        "defaultValue",
+        "Duration",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueExpression",
+        "defaultValue",
+        "Expression",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueHumanName",
+        "defaultValue",
+        "HumanName",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueId",
+        "defaultValue",
+        "id",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueIdentifier",
+        "defaultValue",
+        "Identifier",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueInstant",
+        "defaultValue",
+        "instant",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueInteger",
+        "defaultValue",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueMarkdown",
+        "defaultValue",
+        "markdown",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueMeta",
+        "defaultValue",
+        "Meta",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueMoney",
+        "defaultValue",
+        "Money",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueOid",
+        "defaultValue",
+        "oid",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueParameterDefinition",
+        "defaultValue",
+        "ParameterDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValuePeriod",
+        "defaultValue",
+        "Period",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValuePositiveInt",
+        "defaultValue",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueQuantity",
+        "defaultValue",
+        "Quantity",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueRange",
+        "defaultValue",
+        "Range",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueRatio",
+        "defaultValue",
+        "Ratio",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueReference",
+        "defaultValue",
+        "Reference",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueRelatedArtifact",
+        "defaultValue",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueSampledData",
+        "defaultValue",
+        "SampledData",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueSignature",
+        "defaultValue",
+        "Signature",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueString",
+        "defaultValue",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueTime",
+        "defaultValue",
+        "time",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueTiming",
+        "defaultValue",
+        "Timing",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueTriggerDefinition",
+        "defaultValue",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueUnsignedInt",
+        "defaultValue",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueUri",
+        "defaultValue",
+        "uri",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueUrl",
+        "defaultValue",
+        "url",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueUsageContext",
+        "defaultValue",
+        "UsageContext",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "defaultValueUuid",
+        "defaultValue",
+        "uuid",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "definition",
+        "definition",
+        "markdown",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "example",
+        "example",
+        "ElementDefinition_Example",
+        true,
+    ),
+    (
+        "ElementDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition",
+        "fixedAddress",
+        "fixed",
+        "Address",
+        false,
+    ),
+    ("ElementDefinition", "fixedAge", "fixed", "Age", false),
+    (
+        "ElementDefinition",
+        "fixedAnnotation",
+        "fixed",
+        "Annotation",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedAttachment",
+        "fixed",
+        "Attachment",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedBase64Binary",
+        "fixed",
+        "base64Binary",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedBoolean",
+        "fixed",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedCanonical",
+        "fixed",
+        "canonical",
+        false,
+    ),
+    ("ElementDefinition", "fixedCode", "fixed", "code", false),
+    (
+        "ElementDefinition",
+        "fixedCodeableConcept",
+        "fixed",
+        "CodeableConcept",
+        false,
+    ),
+    ("ElementDefinition", "fixedCoding", "fixed", "Coding", false),
+    (
+        "ElementDefinition",
+        "fixedContactDetail",
+        "fixed",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedContactPoint",
+        "fixed",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedContributor",
+        "fixed",
+        "Contributor",
+        false,
+    ),
+    ("ElementDefinition", "fixedCount", "fixed", "Count", false),
+    (
+        "ElementDefinition",
+        "fixedDataRequirement",
+        "fixed",
+        "DataRequirement",
+        false,
+    ),
+    ("ElementDefinition", "fixedDate", "fixed", "date", false),
+    (
+        "ElementDefinition",
+        "fixedDateTime",
+        "fixed",
+        "dateTime",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedDecimal",
+        "fixed",
+        "decimal",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedDistance",
+        "fixed",
+        "Distance",
+        false,
+    ),
+    ("ElementDefinition", "fixedDosage", "fixed", "Dosage", false),
+    (
+        "ElementDefinition",
+        "fixedDuration",
+        "fixed",
+        "Duration",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedExpression",
+        "fixed",
+        "Expression",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedHumanName",
+        "fixed",
+        "HumanName",
+        false,
+    ),
+    ("ElementDefinition", "fixedId", "fixed", "id", false),
+    (
+        "ElementDefinition",
+        "fixedIdentifier",
+        "fixed",
+        "Identifier",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedInstant",
+        "fixed",
+        "instant",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedInteger",
+        "fixed",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedMarkdown",
+        "fixed",
+        "markdown",
+        false,
+    ),
+    ("ElementDefinition", "fixedMeta", "fixed", "Meta", false),
+    ("ElementDefinition", "fixedMoney", "fixed", "Money", false),
+    ("ElementDefinition", "fixedOid", "fixed", "oid", false),
+    (
+        "ElementDefinition",
+        "fixedParameterDefinition",
+        "fixed",
+        "ParameterDefinition",
+        false,
+    ),
+    ("ElementDefinition", "fixedPeriod", "fixed", "Period", false),
+    (
+        "ElementDefinition",
+        "fixedPositiveInt",
+        "fixed",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedQuantity",
+        "fixed",
+        "Quantity",
+        false,
+    ),
+    ("ElementDefinition", "fixedRange", "fixed", "Range", false),
+    ("ElementDefinition", "fixedRatio", "fixed", "Ratio", false),
+    (
+        "ElementDefinition",
+        "fixedReference",
+        "fixed",
+        "Reference",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedRelatedArtifact",
+        "fixed",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedSampledData",
+        "fixed",
+        "SampledData",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedSignature",
+        "fixed",
+        "Signature",
+        false,
+    ),
+    ("ElementDefinition", "fixedString", "fixed", "string", false),
+    ("ElementDefinition", "fixedTime", "fixed", "time", false),
+    ("ElementDefinition", "fixedTiming", "fixed", "Timing", false),
+    (
+        "ElementDefinition",
+        "fixedTriggerDefinition",
+        "fixed",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "fixedUnsignedInt",
+        "fixed",
+        "unsignedInt",
+        false,
+    ),
+    ("ElementDefinition", "fixedUri", "fixed", "uri", false),
+    ("ElementDefinition", "fixedUrl", "fixed", "url", false),
+    (
+        "ElementDefinition",
+        "fixedUsageContext",
+        "fixed",
+        "UsageContext",
+        false,
+    ),
+    ("ElementDefinition", "fixedUuid", "fixed", "uuid", false),
+    ("ElementDefinition", "id", "id", "string", false),
+    (
+        "ElementDefinition",
+        "isModifier",
+        "isModifier",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "isModifierReason",
+        "isModifierReason",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "isSummary",
+        "isSummary",
+        "boolean",
+        false,
+    ),
+    ("ElementDefinition", "label", "label", "string", false),
+    (
+        "ElementDefinition",
+        "mapping",
+        "mapping",
+        "ElementDefinition_Mapping",
+        true,
+    ),
+    ("ElementDefinition", "max", "max", "string", false),
+    (
+        "ElementDefinition",
+        "maxLength",
+        "maxLength",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueDate",
+        "maxValue",
+        "date",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueDateTime",
+        "maxValue",
+        "dateTime",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueDecimal",
+        "maxValue",
+        "decimal",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueInstant",
+        "maxValue",
+        "instant",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueInteger",
+        "maxValue",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValuePositiveInt",
+        "maxValue",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueQuantity",
+        "maxValue",
+        "Quantity",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueTime",
+        "maxValue",
+        "time",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "maxValueUnsignedInt",
+        "maxValue",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "meaningWhenMissing",
+        "meaningWhenMissing",
+        "markdown",
+        false,
+    ),
+    ("ElementDefinition", "min", "min", "unsignedInt", false),
+    (
+        "ElementDefinition",
+        "minValueDate",
+        "minValue",
+        "date",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueDateTime",
+        "minValue",
+        "dateTime",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueDecimal",
+        "minValue",
+        "decimal",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueInstant",
+        "minValue",
+        "instant",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueInteger",
+        "minValue",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValuePositiveInt",
+        "minValue",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueQuantity",
+        "minValue",
+        "Quantity",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueTime",
+        "minValue",
+        "time",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "minValueUnsignedInt",
+        "minValue",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition",
+        "mustSupport",
+        "mustSupport",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "orderMeaning",
+        "orderMeaning",
+        "string",
+        false,
+    ),
+    ("ElementDefinition", "path", "path", "string", false),
+    (
+        "ElementDefinition",
+        "patternAddress",
+        "pattern",
+        "Address",
+        false,
+    ),
+    ("ElementDefinition", "patternAge", "pattern", "Age", false),
+    (
+        "ElementDefinition",
+        "patternAnnotation",
+        "pattern",
+        "Annotation",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternAttachment",
+        "pattern",
+        "Attachment",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternBase64Binary",
+        "pattern",
+        "base64Binary",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternBoolean",
+        "pattern",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternCanonical",
+        "pattern",
+        "canonical",
+        false,
+    ),
+    ("ElementDefinition", "patternCode", "pattern", "code", false),
+    (
+        "ElementDefinition",
+        "patternCodeableConcept",
+        "pattern",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternCoding",
+        "pattern",
+        "Coding",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternContactDetail",
+        "pattern",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternContactPoint",
+        "pattern",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternContributor",
+        "pattern",
+        "Contributor",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternCount",
+        "pattern",
+        "Count",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternDataRequirement",
+        "pattern",
+        "DataRequirement",
+        false,
+    ),
+    ("ElementDefinition", "patternDate", "pattern", "date", false),
+    (
+        "ElementDefinition",
+        "patternDateTime",
+        "pattern",
+        "dateTime",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternDecimal",
+        "pattern",
+        "decimal",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternDistance",
+        "pattern",
+        "Distance",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternDosage",
+        "pattern",
+        "Dosage",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternDuration",
+        "pattern",
+        "Duration",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternExpression",
+        "pattern",
+        "Expression",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternHumanName",
+        "pattern",
+        "HumanName",
+        false,
+    ),
+    ("ElementDefinition", "patternId", "pattern", "id", false),
+    (
+        "ElementDefinition",
+        "patternIdentifier",
+        "pattern",
+        "Identifier",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternInstant",
+        "pattern",
+        "instant",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternInteger",
+        "pattern",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternMarkdown",
+        "pattern",
+        "markdown",
+        false,
+    ),
+    ("ElementDefinition", "patternMeta", "pattern", "Meta", false),
+    (
+        "ElementDefinition",
+        "patternMoney",
+        "pattern",
+        "Money",
+        false,
+    ),
+    ("ElementDefinition", "patternOid", "pattern", "oid", false),
+    (
+        "ElementDefinition",
+        "patternParameterDefinition",
+        "pattern",
+        "ParameterDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternPeriod",
+        "pattern",
+        "Period",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternPositiveInt",
+        "pattern",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternQuantity",
+        "pattern",
+        "Quantity",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternRange",
+        "pattern",
+        "Range",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternRatio",
+        "pattern",
+        "Ratio",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternReference",
+        "pattern",
+        "Reference",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternRelatedArtifact",
+        "pattern",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternSampledData",
+        "pattern",
+        "SampledData",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternSignature",
+        "pattern",
+        "Signature",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternString",
+        "pattern",
+        "string",
+        false,
+    ),
+    ("ElementDefinition", "patternTime", "pattern", "time", false),
+    (
+        "ElementDefinition",
+        "patternTiming",
+        "pattern",
+        "Timing",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternTriggerDefinition",
+        "pattern",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "patternUnsignedInt",
+        "pattern",
+        "unsignedInt",
+        false,
+    ),
+    ("ElementDefinition", "patternUri", "pattern", "uri", false),
+    ("ElementDefinition", "patternUrl", "pattern", "url", false),
+    (
+        "ElementDefinition",
+        "patternUsageContext",
+        "pattern",
+        "UsageContext",
+        false,
+    ),
+    ("ElementDefinition", "patternUuid", "pattern", "uuid", false),
+    (
+        "ElementDefinition",
+        "representation",
+        "representation",
+        "code",
+        true,
+    ),
+    (
+        "ElementDefinition",
+        "requirements",
+        "requirements",
+        "markdown",
+        false,
+    ),
+    ("ElementDefinition", "short", "short", "string", false),
+    (
+        "ElementDefinition",
+        "sliceIsConstraining",
+        "sliceIsConstraining",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "sliceName",
+        "sliceName",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "slicing",
+        "slicing",
+        "ElementDefinition_Slicing",
+        false,
+    ),
+    (
+        "ElementDefinition",
+        "type",
+        "type",
+        "ElementDefinition_Type",
+        true,
+    ),
+    (
+        "ElementDefinition_Base",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Base", "id", "id", "string", false),
+    ("ElementDefinition_Base", "max", "max", "string", false),
+    ("ElementDefinition_Base", "min", "min", "unsignedInt", false),
+    (
+        "ElementDefinition_Base",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Base", "path", "path", "string", false),
+    (
+        "ElementDefinition_Binding",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Binding",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Binding", "id", "id", "string", false),
+    (
+        "ElementDefinition_Binding",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Binding",
+        "strength",
+        "strength",
+        "code",
+        false,
+    ),
+    (
+        "ElementDefinition_Binding",
+        "valueSet",
+        "valueSet",
+        "canonical",
+        false,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "human",
+        "human",
+        "string",
+        false,
+    ),
+    ("ElementDefinition_Constraint", "id", "id", "string", false),
+    ("ElementDefinition_Constraint", "key", "key", "id", false),
+    (
+        "ElementDefinition_Constraint",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "requirements",
+        "requirements",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "severity",
+        "severity",
+        "code",
+        false,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "source",
+        "source",
+        "canonical",
+        false,
+    ),
+    (
+        "ElementDefinition_Constraint",
+        "xpath",
+        "xpath",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Discriminator",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Discriminator",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Discriminator",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Discriminator",
+        "path",
+        "path",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Discriminator",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Example", "id", "id", "string", false),
+    (
+        "ElementDefinition_Example",
+        "label",
+        "label",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueAddress",
+        "value",
+        "Address",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueAge",
+        "value",
+        "Age",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueAnnotation",
+        "value",
+        "Annotation",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueCanonical",
+        "value",
+        "canonical",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueCode",
+        "value",
+        "code",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueCoding",
+        "value",
+        "Coding",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueContactDetail",
+        "value",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueContactPoint",
+        "value",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueContributor",
+        "value",
+        "Contributor",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueCount",
+        "value",
+        "Count",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDataRequirement",
+        "value",
+        "DataRequirement",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDate",
+        "value",
+        "date",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDistance",
+        "value",
+        "Distance",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDosage",
+        "value",
+        "Dosage",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueDuration",
+        "value",
+        "Duration",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueExpression",
+        "value",
+        "Expression",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueHumanName",
+        "value",
+        "HumanName",
+        false,
+    ),
+    ("ElementDefinition_Example", "valueId", "value", "id", false),
+    (
+        "ElementDefinition_Example",
+        "valueIdentifier",
+        "value",
+        "Identifier",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueInstant",
+        "value",
+        "instant",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueMarkdown",
+        "value",
+        "markdown",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueMeta",
+        "value",
+        "Meta",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueMoney",
+        "value",
+        "Money",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueOid",
+        "value",
+        "oid",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueParameterDefinition",
+        "value",
+        "ParameterDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valuePeriod",
+        "value",
+        "Period",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valuePositiveInt",
+        "value",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueRange",
+        "value",
+        "Range",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueRatio",
+        "value",
+        "Ratio",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueRelatedArtifact",
+        "value",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueSignature",
+        "value",
+        "Signature",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueTime",
+        "value",
+        "time",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueTiming",
+        "value",
+        "Timing",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueTriggerDefinition",
+        "value",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueUnsignedInt",
+        "value",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueUri",
+        "value",
+        "uri",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueUrl",
+        "value",
+        "url",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueUsageContext",
+        "value",
+        "UsageContext",
+        false,
+    ),
+    (
+        "ElementDefinition_Example",
+        "valueUuid",
+        "value",
+        "uuid",
+        false,
+    ),
+    (
+        "ElementDefinition_Mapping",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Mapping",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Mapping", "id", "id", "string", false),
+    (
+        "ElementDefinition_Mapping",
+        "identity",
+        "identity",
+        "id",
+        false,
+    ),
+    (
+        "ElementDefinition_Mapping",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("ElementDefinition_Mapping", "map", "map", "string", false),
+    (
+        "ElementDefinition_Mapping",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Slicing",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ElementDefinition_Slicing",
+        "discriminator",
+        "discriminator",
+        "ElementDefinition_Discriminator",
+        true,
+    ),
+    (
+        "ElementDefinition_Slicing",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Slicing", "id", "id", "string", false),
+    (
+        "ElementDefinition_Slicing",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Slicing",
+        "ordered",
+        "ordered",
+        "boolean",
+        false,
+    ),
+    ("ElementDefinition_Slicing", "rules", "rules", "code", false),
+    (
+        "ElementDefinition_Type",
+        "aggregation",
+        "aggregation",
+        "code",
+        true,
+    ),
+    ("ElementDefinition_Type", "code", "code", "uri", false),
+    (
+        "ElementDefinition_Type",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ElementDefinition_Type", "id", "id", "string", false),
+    (
+        "ElementDefinition_Type",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ElementDefinition_Type",
+        "profile",
+        "profile",
+        "canonical",
+        true,
+    ),
+    (
+        "ElementDefinition_Type",
+        "targetProfile",
+        "targetProfile",
+        "canonical",
+        true,
+    ),
+    (
+        "ElementDefinition_Type",
+        "versioning",
+        "versioning",
+        "code",
+        false,
+    ),
+    ("Encounter", "account", "account", "Reference", true),
+    ("Encounter", "appointment", "appointment", "Reference", true),
+    ("Encounter", "basedOn", "basedOn", "Reference", true),
+    ("Encounter", "class", "class", "Coding", false),
+    (
+        "Encounter",
+        "classHistory",
+        "classHistory",
+        "Encounter_ClassHistory",
+        true,
+    ),
+    ("Encounter", "contained", "contained", "ResourceList", true),
+    (
+        "Encounter",
+        "diagnosis",
+        "diagnosis",
+        "Encounter_Diagnosis",
+        true,
+    ),
+    (
+        "Encounter",
+        "episodeOfCare",
+        "episodeOfCare",
+        "Reference",
+        true,
+    ),
+    ("Encounter", "extension", "extension", "Extension", true),
+    (
+        "Encounter",
+        "hospitalization",
+        "hospitalization",
+        "Encounter_Hospitalization",
+        false,
+    ),
+    ("Encounter", "id", "id", "id", false),
+    ("Encounter", "identifier", "identifier", "Identifier", true),
+    ("Encounter", "implicitRules", "implicitRules", "uri", false),
+    ("Encounter", "language", "language", "code", false),
+    ("Encounter", "length", "length", "Duration", false),
+    (
+        "Encounter",
+        "location",
+        "location",
+        "Encounter_Location",
+        true,
+    ),
+    ("Encounter", "meta", "meta", "Meta", false),
+    (
+        "Encounter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Encounter", "partOf", "partOf", "Reference", false),
+    (
+        "Encounter",
+        "participant",
+        "participant",
+        "Encounter_Participant",
+        true,
+    ),
+    ("Encounter", "period", "period", "Period", false),
+    (
+        "Encounter",
+        "priority",
+        "priority",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Encounter",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Encounter",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "Encounter",
+        "serviceProvider",
+        "serviceProvider",
+        "Reference",
+        false,
+    ),
+    (
+        "Encounter",
+        "serviceType",
+        "serviceType",
+        "CodeableConcept",
+        false,
+    ),
+    ("Encounter", "status", "status", "code", false),
+    (
+        "Encounter",
+        "statusHistory",
+        "statusHistory",
+        "Encounter_StatusHistory",
+        true,
+    ),
+    ("Encounter", "subject", "subject", "Reference", false),
+    ("Encounter", "text", "text", "Narrative", false),
+    ("Encounter", "type", "type", "CodeableConcept", true),
+    ("Encounter_ClassHistory", "class", "class", "Coding", false),
+    (
+        "Encounter_ClassHistory",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_ClassHistory", "id", "id", "string", false),
+    (
+        "Encounter_ClassHistory",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Encounter_ClassHistory",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    (
+        "Encounter_Diagnosis",
+        "condition",
+        "condition",
+        "Reference",
+        false,
+    ),
+    (
+        "Encounter_Diagnosis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Diagnosis", "id", "id", "string", false),
+    (
+        "Encounter_Diagnosis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Diagnosis", "rank", "rank", "positiveInt", false),
+    (
+        "Encounter_Diagnosis",
+        "use",
+        "use",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "admitSource",
+        "admitSource",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "destination",
+        "destination",
+        "Reference",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "dietPreference",
+        "dietPreference",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "dischargeDisposition",
+        "dischargeDisposition",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Hospitalization", "id", "id", "string", false),
+    (
+        "Encounter_Hospitalization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "origin",
+        "origin",
+        "Reference",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "preAdmissionIdentifier",
+        "preAdmissionIdentifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "reAdmission",
+        "reAdmission",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "specialArrangement",
+        "specialArrangement",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Encounter_Hospitalization",
+        "specialCourtesy",
+        "specialCourtesy",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Encounter_Location",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Location", "id", "id", "string", false),
+    (
+        "Encounter_Location",
+        "location",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "Encounter_Location",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Location", "period", "period", "Period", false),
+    (
+        "Encounter_Location",
+        "physicalType",
+        "physicalType",
+        "CodeableConcept",
+        false,
+    ),
+    ("Encounter_Location", "status", "status", "code", false),
+    (
+        "Encounter_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Participant", "id", "id", "string", false),
+    (
+        "Encounter_Participant",
+        "individual",
+        "individual",
+        "Reference",
+        false,
+    ),
+    (
+        "Encounter_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_Participant", "period", "period", "Period", false),
+    (
+        "Encounter_Participant",
+        "type",
+        "type",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Encounter_StatusHistory",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Encounter_StatusHistory", "id", "id", "string", false),
+    (
+        "Encounter_StatusHistory",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Encounter_StatusHistory",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    ("Encounter_StatusHistory", "status", "status", "code", false),
+    ("Endpoint", "address", "address", "url", false),
+    (
+        "Endpoint",
+        "connectionType",
+        "connectionType",
+        "Coding",
+        false,
+    ),
+    ("Endpoint", "contact", "contact", "ContactPoint", true),
+    ("Endpoint", "contained", "contained", "ResourceList", true),
+    ("Endpoint", "extension", "extension", "Extension", true),
+    ("Endpoint", "header", "header", "string", true),
+    ("Endpoint", "id", "id", "id", false),
+    ("Endpoint", "identifier", "identifier", "Identifier", true),
+    ("Endpoint", "implicitRules", "implicitRules", "uri", false),
+    ("Endpoint", "language", "language", "code", false),
+    (
+        "Endpoint",
+        "managingOrganization",
+        "managingOrganization",
+        "Reference",
+        false,
+    ),
+    ("Endpoint", "meta", "meta", "Meta", false),
+    (
+        "Endpoint",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Endpoint", "name", "name", "string", false),
+    (
+        "Endpoint",
+        "payloadMimeType",
+        "payloadMimeType",
+        "code",
+        true,
+    ),
+    (
+        "Endpoint",
+        "payloadType",
+        "payloadType",
+        "CodeableConcept",
+        true,
+    ),
+    ("Endpoint", "period", "period", "Period", false),
+    ("Endpoint", "status", "status", "code", false),
+    ("Endpoint", "text", "text", "Narrative", false),
+    (
+        "EnrollmentRequest",
+        "candidate",
+        "candidate",
+        "Reference",
+        false,
+    ),
+    (
+        "EnrollmentRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "EnrollmentRequest",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    ("EnrollmentRequest", "created", "created", "dateTime", false),
+    (
+        "EnrollmentRequest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EnrollmentRequest", "id", "id", "id", false),
+    (
+        "EnrollmentRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "EnrollmentRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "EnrollmentRequest",
+        "insurer",
+        "insurer",
+        "Reference",
+        false,
+    ),
+    ("EnrollmentRequest", "language", "language", "code", false),
+    ("EnrollmentRequest", "meta", "meta", "Meta", false),
+    (
+        "EnrollmentRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EnrollmentRequest",
+        "provider",
+        "provider",
+        "Reference",
+        false,
+    ),
+    ("EnrollmentRequest", "status", "status", "code", false),
+    ("EnrollmentRequest", "text", "text", "Narrative", false),
+    (
+        "EnrollmentResponse",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "EnrollmentResponse",
+        "created",
+        "created",
+        "dateTime",
+        false,
+    ),
+    (
+        "EnrollmentResponse",
+        "disposition",
+        "disposition",
+        "string",
+        false,
+    ),
+    (
+        "EnrollmentResponse",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EnrollmentResponse", "id", "id", "id", false),
+    (
+        "EnrollmentResponse",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "EnrollmentResponse",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("EnrollmentResponse", "language", "language", "code", false),
+    ("EnrollmentResponse", "meta", "meta", "Meta", false),
+    (
+        "EnrollmentResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EnrollmentResponse",
+        "organization",
+        "organization",
+        "Reference",
+        false,
+    ),
+    ("EnrollmentResponse", "outcome", "outcome", "code", false),
+    (
+        "EnrollmentResponse",
+        "request",
+        "request",
+        "Reference",
+        false,
+    ),
+    (
+        "EnrollmentResponse",
+        "requestProvider",
+        "requestProvider",
+        "Reference",
+        false,
+    ),
+    ("EnrollmentResponse", "status", "status", "code", false),
+    ("EnrollmentResponse", "text", "text", "Narrative", false),
+    ("EpisodeOfCare", "account", "account", "Reference", true),
+    (
+        "EpisodeOfCare",
+        "careManager",
+        "careManager",
+        "Reference",
+        false,
+    ),
+    (
+        "EpisodeOfCare",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "EpisodeOfCare",
+        "diagnosis",
+        "diagnosis",
+        "EpisodeOfCare_Diagnosis",
+        true,
+    ),
+    ("EpisodeOfCare", "extension", "extension", "Extension", true),
+    ("EpisodeOfCare", "id", "id", "id", false),
+    (
+        "EpisodeOfCare",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "EpisodeOfCare",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("EpisodeOfCare", "language", "language", "code", false),
+    (
+        "EpisodeOfCare",
+        "managingOrganization",
+        "managingOrganization",
+        "Reference",
+        false,
+    ),
+    ("EpisodeOfCare", "meta", "meta", "Meta", false),
+    (
+        "EpisodeOfCare",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("EpisodeOfCare", "patient", "patient", "Reference", false),
+    ("EpisodeOfCare", "period", "period", "Period", false),
+    (
+        "EpisodeOfCare",
+        "referralRequest",
+        "referralRequest",
+        "Reference",
+        true,
+    ),
+    ("EpisodeOfCare", "status", "status", "code", false),
+    (
+        "EpisodeOfCare",
+        "statusHistory",
+        "statusHistory",
+        "EpisodeOfCare_StatusHistory",
+        true,
+    ),
+    ("EpisodeOfCare", "team", "team", "Reference", true),
+    ("EpisodeOfCare", "text", "text", "Narrative", false),
+    ("EpisodeOfCare", "type", "type", "CodeableConcept", true),
+    (
+        "EpisodeOfCare_Diagnosis",
+        "condition",
+        "condition",
+        "Reference",
+        false,
+    ),
+    (
+        "EpisodeOfCare_Diagnosis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EpisodeOfCare_Diagnosis", "id", "id", "string", false),
+    (
+        "EpisodeOfCare_Diagnosis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EpisodeOfCare_Diagnosis",
+        "rank",
+        "rank",
+        "positiveInt",
+        false,
+    ),
+    (
+        "EpisodeOfCare_Diagnosis",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EpisodeOfCare_StatusHistory",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EpisodeOfCare_StatusHistory", "id", "id", "string", false),
+    (
+        "EpisodeOfCare_StatusHistory",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EpisodeOfCare_StatusHistory",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    (
+        "EpisodeOfCare_StatusHistory",
+        "status",
+        "status",
+        "code",
+        false,
+    ),
+    (
+        "EventDefinition",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    ("EventDefinition", "author", "author", "ContactDetail", true),
+    (
+        "EventDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EventDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "EventDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("EventDefinition", "date", "date", "dateTime", false),
+    (
+        "EventDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    ("EventDefinition", "editor", "editor", "ContactDetail", true),
+    (
+        "EventDefinition",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "EventDefinition",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EventDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "EventDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EventDefinition", "id", "id", "id", false),
+    (
+        "EventDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "EventDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "EventDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("EventDefinition", "language", "language", "code", false),
+    (
+        "EventDefinition",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("EventDefinition", "meta", "meta", "Meta", false),
+    (
+        "EventDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("EventDefinition", "name", "name", "string", false),
+    ("EventDefinition", "publisher", "publisher", "string", false),
+    ("EventDefinition", "purpose", "purpose", "markdown", false),
+    (
+        "EventDefinition",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "EventDefinition",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    ("EventDefinition", "status", "status", "code", false),
+    (
+        "EventDefinition",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EventDefinition",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("EventDefinition", "subtitle", "subtitle", "string", false),
+    ("EventDefinition", "text", "text", "Narrative", false),
+    ("EventDefinition", "title", "title", "string", false),
+    ("EventDefinition", "topic", "topic", "CodeableConcept", true),
+    (
+        "EventDefinition",
+        "trigger",
+        "trigger",
+        "TriggerDefinition",
+        true,
+    ),
+    ("EventDefinition", "url", "url", "uri", false),
+    ("EventDefinition", "usage", "usage", "string", false),
+    (
+        "EventDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("EventDefinition", "version", "version", "string", false),
+    ("Evidence", "approvalDate", "approvalDate", "date", false),
+    ("Evidence", "author", "author", "ContactDetail", true),
+    ("Evidence", "contact", "contact", "ContactDetail", true),
+    ("Evidence", "contained", "contained", "ResourceList", true),
+    ("Evidence", "copyright", "copyright", "markdown", false),
+    ("Evidence", "date", "date", "dateTime", false),
+    ("Evidence", "description", "description", "markdown", false),
+    ("Evidence", "editor", "editor", "ContactDetail", true),
+    (
+        "Evidence",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    ("Evidence", "endorser", "endorser", "ContactDetail", true),
+    (
+        "Evidence",
+        "exposureBackground",
+        "exposureBackground",
+        "Reference",
+        false,
+    ),
+    (
+        "Evidence",
+        "exposureVariant",
+        "exposureVariant",
+        "Reference",
+        true,
+    ),
+    ("Evidence", "extension", "extension", "Extension", true),
+    ("Evidence", "id", "id", "id", false),
+    ("Evidence", "identifier", "identifier", "Identifier", true),
+    ("Evidence", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Evidence",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("Evidence", "language", "language", "code", false),
+    (
+        "Evidence",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("Evidence", "meta", "meta", "Meta", false),
+    (
+        "Evidence",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Evidence", "name", "name", "string", false),
+    ("Evidence", "note", "note", "Annotation", true),
+    ("Evidence", "outcome", "outcome", "Reference", true),
+    ("Evidence", "publisher", "publisher", "string", false),
+    (
+        "Evidence",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    ("Evidence", "reviewer", "reviewer", "ContactDetail", true),
+    ("Evidence", "shortTitle", "shortTitle", "string", false),
+    ("Evidence", "status", "status", "code", false),
+    ("Evidence", "subtitle", "subtitle", "string", false),
+    ("Evidence", "text", "text", "Narrative", false),
+    ("Evidence", "title", "title", "string", false),
+    ("Evidence", "topic", "topic", "CodeableConcept", true),
+    ("Evidence", "url", "url", "uri", false),
+    ("Evidence", "useContext", "useContext", "UsageContext", true),
+    ("Evidence", "version", "version", "string", false),
+    (
+        "EvidenceVariable",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "EvidenceVariable",
+        "author",
+        "author",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "characteristic",
+        "characteristic",
+        "EvidenceVariable_Characteristic",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("EvidenceVariable", "date", "date", "dateTime", false),
+    (
+        "EvidenceVariable",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "EvidenceVariable",
+        "editor",
+        "editor",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "EvidenceVariable",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("EvidenceVariable", "id", "id", "id", false),
+    (
+        "EvidenceVariable",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "EvidenceVariable",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("EvidenceVariable", "language", "language", "code", false),
+    (
+        "EvidenceVariable",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("EvidenceVariable", "meta", "meta", "Meta", false),
+    (
+        "EvidenceVariable",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("EvidenceVariable", "name", "name", "string", false),
+    ("EvidenceVariable", "note", "note", "Annotation", true),
+    (
+        "EvidenceVariable",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "EvidenceVariable",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "EvidenceVariable",
+        "shortTitle",
+        "shortTitle",
+        "string",
+        false,
+    ),
+    ("EvidenceVariable", "status", "status", "code", false),
+    ("EvidenceVariable", "subtitle", "subtitle", "string", false),
+    ("EvidenceVariable", "text", "text", "Narrative", false),
+    ("EvidenceVariable", "title", "title", "string", false),
+    (
+        "EvidenceVariable",
+        "topic",
+        "topic",
+        "CodeableConcept",
+        true,
+    ),
+    ("EvidenceVariable", "type", "type", "code", false),
+    ("EvidenceVariable", "url", "url", "uri", false),
+    (
+        "EvidenceVariable",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("EvidenceVariable", "version", "version", "string", false),
+    (
+        "EvidenceVariable_Characteristic",
+        "definitionCanonical",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "definitionCodeableConcept",
+        "definition",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "definitionDataRequirement",
+        "definition",
+        "DataRequirement",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "definitionExpression",
+        "definition",
+        "Expression",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "definitionReference",
+        "definition",
+        "Reference",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "definitionTriggerDefinition",
+        "definition",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "exclude",
+        "exclude",
+        "boolean",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "groupMeasure",
+        "groupMeasure",
+        "code",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "participantEffectiveDateTime",
+        "participantEffective",
+        "dateTime",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "participantEffectiveDuration",
+        "participantEffective",
+        "Duration",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "participantEffectivePeriod",
+        "participantEffective",
+        "Period",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "participantEffectiveTiming",
+        "participantEffective",
+        "Timing",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "timeFromStart",
+        "timeFromStart",
+        "Duration",
+        false,
+    ),
+    (
+        "EvidenceVariable_Characteristic",
+        "usageContext",
+        "usageContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "ExampleScenario",
+        "actor",
+        "actor",
+        "ExampleScenario_Actor",
+        true,
+    ),
+    (
+        "ExampleScenario",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ExampleScenario",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ExampleScenario",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("ExampleScenario", "date", "date", "dateTime", false),
+    (
+        "ExampleScenario",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "ExampleScenario",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario", "id", "id", "id", false),
+    (
+        "ExampleScenario",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ExampleScenario",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ExampleScenario",
+        "instance",
+        "instance",
+        "ExampleScenario_Instance",
+        true,
+    ),
+    (
+        "ExampleScenario",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("ExampleScenario", "language", "language", "code", false),
+    ("ExampleScenario", "meta", "meta", "Meta", false),
+    (
+        "ExampleScenario",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario", "name", "name", "string", false),
+    (
+        "ExampleScenario",
+        "process",
+        "process",
+        "ExampleScenario_Process",
+        true,
+    ),
+    ("ExampleScenario", "publisher", "publisher", "string", false),
+    ("ExampleScenario", "purpose", "purpose", "markdown", false),
+    ("ExampleScenario", "status", "status", "code", false),
+    ("ExampleScenario", "text", "text", "Narrative", false),
+    ("ExampleScenario", "url", "url", "uri", false),
+    (
+        "ExampleScenario",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("ExampleScenario", "version", "version", "string", false),
+    ("ExampleScenario", "workflow", "workflow", "canonical", true),
+    (
+        "ExampleScenario_Actor",
+        "actorId",
+        "actorId",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_Actor",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Actor",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Actor", "id", "id", "string", false),
+    (
+        "ExampleScenario_Actor",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Actor", "name", "name", "string", false),
+    ("ExampleScenario_Actor", "type", "type", "code", false),
+    (
+        "ExampleScenario_Alternative",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Alternative",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Alternative", "id", "id", "string", false),
+    (
+        "ExampleScenario_Alternative",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExampleScenario_Alternative",
+        "step",
+        "step",
+        "ExampleScenario_Step",
+        true,
+    ),
+    (
+        "ExampleScenario_Alternative",
+        "title",
+        "title",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_ContainedInstance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExampleScenario_ContainedInstance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_ContainedInstance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExampleScenario_ContainedInstance",
+        "resourceId",
+        "resourceId",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_ContainedInstance",
+        "versionId",
+        "versionId",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_Instance",
+        "containedInstance",
+        "containedInstance",
+        "ExampleScenario_ContainedInstance",
+        true,
+    ),
+    (
+        "ExampleScenario_Instance",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Instance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Instance", "id", "id", "string", false),
+    (
+        "ExampleScenario_Instance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Instance", "name", "name", "string", false),
+    (
+        "ExampleScenario_Instance",
+        "resourceId",
+        "resourceId",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_Instance",
+        "resourceType",
+        "resourceType",
+        "code",
+        false,
+    ),
+    (
+        "ExampleScenario_Instance",
+        "version",
+        "version",
+        "ExampleScenario_Version",
+        true,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Operation", "id", "id", "string", false),
+    (
+        "ExampleScenario_Operation",
+        "initiator",
+        "initiator",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "initiatorActive",
+        "initiatorActive",
+        "boolean",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Operation", "name", "name", "string", false),
+    (
+        "ExampleScenario_Operation",
+        "number",
+        "number",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "receiver",
+        "receiver",
+        "string",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "receiverActive",
+        "receiverActive",
+        "boolean",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "request",
+        "request",
+        "ExampleScenario_ContainedInstance",
+        false,
+    ),
+    (
+        "ExampleScenario_Operation",
+        "response",
+        "response",
+        "ExampleScenario_ContainedInstance",
+        false,
+    ),
+    ("ExampleScenario_Operation", "type", "type", "string", false),
+    (
+        "ExampleScenario_Process",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Process",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Process", "id", "id", "string", false),
+    (
+        "ExampleScenario_Process",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExampleScenario_Process",
+        "postConditions",
+        "postConditions",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Process",
+        "preConditions",
+        "preConditions",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Process",
+        "step",
+        "step",
+        "ExampleScenario_Step",
+        true,
+    ),
+    ("ExampleScenario_Process", "title", "title", "string", false),
+    (
+        "ExampleScenario_Step",
+        "alternative",
+        "alternative",
+        "ExampleScenario_Alternative",
+        true,
+    ),
+    (
+        "ExampleScenario_Step",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Step", "id", "id", "string", false),
+    (
+        "ExampleScenario_Step",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExampleScenario_Step",
+        "operation",
+        "operation",
+        "ExampleScenario_Operation",
+        false,
+    ),
+    ("ExampleScenario_Step", "pause", "pause", "boolean", false),
+    (
+        "ExampleScenario_Step",
+        "process",
+        "process",
+        "ExampleScenario_Process",
+        true,
+    ),
+    (
+        "ExampleScenario_Version",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ExampleScenario_Version",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExampleScenario_Version", "id", "id", "string", false),
+    (
+        "ExampleScenario_Version",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExampleScenario_Version",
+        "versionId",
+        "versionId",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "accident",
+        "accident",
+        "ExplanationOfBenefit_Accident",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "addItem",
+        "addItem",
+        "ExplanationOfBenefit_AddItem",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "benefitBalance",
+        "benefitBalance",
+        "ExplanationOfBenefit_BenefitBalance",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "benefitPeriod",
+        "benefitPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "billablePeriod",
+        "billablePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "careTeam",
+        "careTeam",
+        "ExplanationOfBenefit_CareTeam",
+        true,
+    ),
+    ("ExplanationOfBenefit", "claim", "claim", "Reference", false),
+    (
+        "ExplanationOfBenefit",
+        "claimResponse",
+        "claimResponse",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "created",
+        "created",
+        "dateTime",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "diagnosis",
+        "diagnosis",
+        "ExplanationOfBenefit_Diagnosis",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "disposition",
+        "disposition",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "enterer",
+        "enterer",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "facility",
+        "facility",
+        "Reference",
+        false,
+    ),
+    ("ExplanationOfBenefit", "form", "form", "Attachment", false),
+    (
+        "ExplanationOfBenefit",
+        "formCode",
+        "formCode",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "fundsReserve",
+        "fundsReserve",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "fundsReserveRequested",
+        "fundsReserveRequested",
+        "CodeableConcept",
+        false,
+    ),
+    ("ExplanationOfBenefit", "id", "id", "id", false),
+    (
+        "ExplanationOfBenefit",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "insurance",
+        "insurance",
+        "ExplanationOfBenefit_Insurance",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "insurer",
+        "insurer",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "item",
+        "item",
+        "ExplanationOfBenefit_Item",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("ExplanationOfBenefit", "meta", "meta", "Meta", false),
+    (
+        "ExplanationOfBenefit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "originalPrescription",
+        "originalPrescription",
+        "Reference",
+        false,
+    ),
+    ("ExplanationOfBenefit", "outcome", "outcome", "code", false),
+    (
+        "ExplanationOfBenefit",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "payee",
+        "payee",
+        "ExplanationOfBenefit_Payee",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "payment",
+        "payment",
+        "ExplanationOfBenefit_Payment",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "preAuthRef",
+        "preAuthRef",
+        "string",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "preAuthRefPeriod",
+        "preAuthRefPeriod",
+        "Period",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "precedence",
+        "precedence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "prescription",
+        "prescription",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "priority",
+        "priority",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "procedure",
+        "procedure",
+        "ExplanationOfBenefit_Procedure",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "processNote",
+        "processNote",
+        "ExplanationOfBenefit_ProcessNote",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "provider",
+        "provider",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "referral",
+        "referral",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "related",
+        "related",
+        "ExplanationOfBenefit_Related",
+        true,
+    ),
+    ("ExplanationOfBenefit", "status", "status", "code", false),
+    (
+        "ExplanationOfBenefit",
+        "subType",
+        "subType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "supportingInfo",
+        "supportingInfo",
+        "ExplanationOfBenefit_SupportingInfo",
+        true,
+    ),
+    ("ExplanationOfBenefit", "text", "text", "Narrative", false),
+    (
+        "ExplanationOfBenefit",
+        "total",
+        "total",
+        "ExplanationOfBenefit_Total",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    ("ExplanationOfBenefit", "use", "use", "code", false),
+    (
+        "ExplanationOfBenefit_Accident",
+        "date",
+        "date",
+        "date",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Accident",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Accident", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Accident",
+        "locationAddress",
+        "location",
+        "Address",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Accident",
+        "locationReference",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Accident",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Accident",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "detail",
+        "detail",
+        "ExplanationOfBenefit_Detail1",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "detailSequence",
+        "detailSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ExplanationOfBenefit_AddItem", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "itemSequence",
+        "itemSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "locationAddress",
+        "location",
+        "Address",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "locationCodeableConcept",
+        "location",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "locationReference",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_AddItem", "net", "net", "Money", false),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "provider",
+        "provider",
+        "Reference",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "servicedDate",
+        "serviced",
+        "date",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "servicedPeriod",
+        "serviced",
+        "Period",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "subDetailSequence",
+        "subDetailSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "subSite",
+        "subSite",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_AddItem",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "amount",
+        "amount",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Adjudication",
+        "value",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "excluded",
+        "excluded",
+        "boolean",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "financial",
+        "financial",
+        "ExplanationOfBenefit_Financial",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "network",
+        "network",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "term",
+        "term",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_BenefitBalance",
+        "unit",
+        "unit",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_CareTeam", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "provider",
+        "provider",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "qualification",
+        "qualification",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "responsible",
+        "responsible",
+        "boolean",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_CareTeam",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ExplanationOfBenefit_Detail", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Detail",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Detail", "net", "net", "Money", false),
+    (
+        "ExplanationOfBenefit_Detail",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "revenue",
+        "revenue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "subDetail",
+        "subDetail",
+        "ExplanationOfBenefit_SubDetail",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "udi",
+        "udi",
+        "Reference",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ExplanationOfBenefit_Detail1", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Detail1", "net", "net", "Money", false),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "subDetail",
+        "subDetail",
+        "ExplanationOfBenefit_SubDetail1",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Detail1",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "diagnosisCodeableConcept",
+        "diagnosis",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "diagnosisReference",
+        "diagnosis",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "onAdmission",
+        "onAdmission",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "packageCode",
+        "packageCode",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Diagnosis",
+        "type",
+        "type",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "allowedMoney",
+        "allowed",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "allowedString",
+        "allowed",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "allowedUnsignedInt",
+        "allowed",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "usedMoney",
+        "used",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Financial",
+        "usedUnsignedInt",
+        "used",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Insurance",
+        "coverage",
+        "coverage",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Insurance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Insurance",
+        "focal",
+        "focal",
+        "boolean",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Insurance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Insurance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Insurance",
+        "preAuthRef",
+        "preAuthRef",
+        "string",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "careTeamSequence",
+        "careTeamSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "detail",
+        "detail",
+        "ExplanationOfBenefit_Detail",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "diagnosisSequence",
+        "diagnosisSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "encounter",
+        "encounter",
+        "Reference",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("ExplanationOfBenefit_Item", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Item",
+        "informationSequence",
+        "informationSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "locationAddress",
+        "location",
+        "Address",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "locationCodeableConcept",
+        "location",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "locationReference",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Item", "net", "net", "Money", false),
+    (
+        "ExplanationOfBenefit_Item",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "procedureSequence",
+        "procedureSequence",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "revenue",
+        "revenue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "servicedDate",
+        "serviced",
+        "date",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "servicedPeriod",
+        "serviced",
+        "Period",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Item",
+        "subSite",
+        "subSite",
+        "CodeableConcept",
+        true,
+    ),
+    ("ExplanationOfBenefit_Item", "udi", "udi", "Reference", true),
+    (
+        "ExplanationOfBenefit_Item",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payee",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Payee", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Payee",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Payee",
+        "party",
+        "party",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payee",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "adjustment",
+        "adjustment",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "adjustmentReason",
+        "adjustmentReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "amount",
+        "amount",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "date",
+        "date",
+        "date",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Payment", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Payment",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Payment",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "date",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "procedureCodeableConcept",
+        "procedure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "procedureReference",
+        "procedure",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "type",
+        "type",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Procedure",
+        "udi",
+        "udi",
+        "Reference",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "language",
+        "language",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "number",
+        "number",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "text",
+        "text",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_ProcessNote",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Related",
+        "claim",
+        "claim",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Related",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Related", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Related",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_Related",
+        "reference",
+        "reference",
+        "Identifier",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Related",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "net",
+        "net",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "programCode",
+        "programCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "revenue",
+        "revenue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "udi",
+        "udi",
+        "Reference",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "adjudication",
+        "adjudication",
+        "ExplanationOfBenefit_Adjudication",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "net",
+        "net",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "noteNumber",
+        "noteNumber",
+        "positiveInt",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "productOrService",
+        "productOrService",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SubDetail1",
+        "unitPrice",
+        "unitPrice",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "reason",
+        "reason",
+        "Coding",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "timingDate",
+        "timing",
+        "date",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "timingPeriod",
+        "timing",
+        "Period",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_SupportingInfo",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Total",
+        "amount",
+        "amount",
+        "Money",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Total",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ExplanationOfBenefit_Total",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ExplanationOfBenefit_Total", "id", "id", "string", false),
+    (
+        "ExplanationOfBenefit_Total",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Expression", "description", "description", "string", false),
+    ("Expression", "expression", "expression", "string", false),
+    ("Expression", "extension", "extension", "Extension", true),
+    ("Expression", "id", "id", "string", false),
+    ("Expression", "language", "language", "code", false),
+    ("Expression", "name", "name", "id", false),
+    ("Expression", "reference", "reference", "uri", false),
+    ("Extension", "extension", "extension", "Extension", true),
+    ("Extension", "id", "id", "string", false),
+    ("Extension", "url", "url", "uri", false),
+    ("Extension", "valueAddress", "value", "Address", false),
+    ("Extension", "valueAge", "value", "Age", false),
+    ("Extension", "valueAnnotation", "value", "Annotation", false),
+    ("Extension", "valueAttachment", "value", "Attachment", false),
+    (
+        "Extension",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    ("Extension", "valueBoolean", "value", "boolean", false),
+    ("Extension", "valueCanonical", "value", "canonical", false),
+    ("Extension", "valueCode", "value", "code", false),
+    (
+        "Extension",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    ("Extension", "valueCoding", "value", "Coding", false),
+    (
+        "Extension",
+        "valueContactDetail",
+        "value",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "Extension",
+        "valueContactPoint",
+        "value",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "Extension",
+        "valueContributor",
+        "value",
+        "Contributor",
+        false,
+    ),
+    ("Extension", "valueCount", "value", "Count", false),
+    (
+        "Extension",
+        "valueDataRequirement",
+        "value",
+        "DataRequirement",
+        false,
+    ),
+    ("Extension", "valueDate", "value", "date", false),
+    ("Extension", "valueDateTime", "value", "dateTime", false),
+    ("Extension", "valueDecimal", "value", "decimal", false),
+    ("Extension", "valueDistance", "value", "Distance", false),
+    ("Extension", "valueDosage", "value", "Dosage", false),
+    ("Extension", "valueDuration", "value", "Duration", false),
+    ("Extension", "valueExpression", "value", "Expression", false),
+    ("Extension", "valueHumanName", "value", "HumanName", false),
+    ("Extension", "valueId", "value", "id", false),
+    ("Extension", "valueIdentifier", "value", "Identifier", false),
+    ("Extension", "valueInstant", "value", "instant", false),
+    ("Extension", "valueInteger", "value", "integer", false),
+    ("Extension", "valueMarkdown", "value", "markdown", false),
+    ("Extension", "valueMeta", "value", "Meta", false),
+    ("Extension", "valueMoney", "value", "Money", false),
+    ("Extension", "valueOid", "value", "oid", false),
+    (
+        "Extension",
+        "valueParameterDefinition",
+        "value",
+        "ParameterDefinition",
+        false,
+    ),
+    ("Extension", "valuePeriod", "value", "Period", false),
+    (
+        "Extension",
+        "valuePositiveInt",
+        "value",
+        "positiveInt",
+        false,
+    ),
+    ("Extension", "valueQuantity", "value", "Quantity", false),
+    ("Extension", "valueRange", "value", "Range", false),
+    ("Extension", "valueRatio", "value", "Ratio", false),
+    ("Extension", "valueReference", "value", "Reference", false),
+    (
+        "Extension",
+        "valueRelatedArtifact",
+        "value",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "Extension",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    ("Extension", "valueSignature", "value", "Signature", false),
+    ("Extension", "valueString", "value", "string", false),
+    ("Extension", "valueTime", "value", "time", false),
+    ("Extension", "valueTiming", "value", "Timing", false),
+    (
+        "Extension",
+        "valueTriggerDefinition",
+        "value",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "Extension",
+        "valueUnsignedInt",
+        "value",
+        "unsignedInt",
+        false,
+    ),
+    ("Extension", "valueUri", "value", "uri", false),
+    ("Extension", "valueUrl", "value", "url", false),
+    (
+        "Extension",
+        "valueUsageContext",
+        "value",
+        "UsageContext",
+        false,
+    ),
+    ("Extension", "valueUuid", "value", "uuid", false),
+    ("FamilyMemberHistory", "ageAge", "age", "Age", false),
+    ("FamilyMemberHistory", "ageRange", "age", "Range", false),
+    ("FamilyMemberHistory", "ageString", "age", "string", false),
+    ("FamilyMemberHistory", "bornDate", "born", "date", false),
+    ("FamilyMemberHistory", "bornPeriod", "born", "Period", false),
+    ("FamilyMemberHistory", "bornString", "born", "string", false),
+    (
+        "FamilyMemberHistory",
+        "condition",
+        "condition",
+        "FamilyMemberHistory_Condition",
+        true,
+    ),
+    (
+        "FamilyMemberHistory",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "FamilyMemberHistory",
+        "dataAbsentReason",
+        "dataAbsentReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("FamilyMemberHistory", "date", "date", "dateTime", false),
+    (
+        "FamilyMemberHistory",
+        "deceasedAge",
+        "deceased",
+        "Age",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "deceasedBoolean",
+        "deceased",
+        "boolean",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "deceasedDate",
+        "deceased",
+        "date",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "deceasedRange",
+        "deceased",
+        "Range",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "deceasedString",
+        "deceased",
+        "string",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "estimatedAge",
+        "estimatedAge",
+        "boolean",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("FamilyMemberHistory", "id", "id", "id", false),
+    (
+        "FamilyMemberHistory",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "FamilyMemberHistory",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "FamilyMemberHistory",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("FamilyMemberHistory", "language", "language", "code", false),
+    ("FamilyMemberHistory", "meta", "meta", "Meta", false),
+    (
+        "FamilyMemberHistory",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("FamilyMemberHistory", "name", "name", "string", false),
+    ("FamilyMemberHistory", "note", "note", "Annotation", true),
+    (
+        "FamilyMemberHistory",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "FamilyMemberHistory",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "FamilyMemberHistory",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "FamilyMemberHistory",
+        "sex",
+        "sex",
+        "CodeableConcept",
+        false,
+    ),
+    ("FamilyMemberHistory", "status", "status", "code", false),
+    ("FamilyMemberHistory", "text", "text", "Narrative", false),
+    (
+        "FamilyMemberHistory_Condition",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "contributedToDeath",
+        "contributedToDeath",
+        "boolean",
+        false,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("FamilyMemberHistory_Condition", "id", "id", "string", false),
+    (
+        "FamilyMemberHistory_Condition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "onsetAge",
+        "onset",
+        "Age",
+        false,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "onsetPeriod",
+        "onset",
+        "Period",
+        false,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "onsetRange",
+        "onset",
+        "Range",
+        false,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "onsetString",
+        "onset",
+        "string",
+        false,
+    ),
+    (
+        "FamilyMemberHistory_Condition",
+        "outcome",
+        "outcome",
+        "CodeableConcept",
+        false,
+    ),
+    ("Flag", "author", "author", "Reference", false),
+    ("Flag", "category", "category", "CodeableConcept", true),
+    ("Flag", "code", "code", "CodeableConcept", false),
+    ("Flag", "contained", "contained", "ResourceList", true),
+    ("Flag", "encounter", "encounter", "Reference", false),
+    ("Flag", "extension", "extension", "Extension", true),
+    ("Flag", "id", "id", "id", false),
+    ("Flag", "identifier", "identifier", "Identifier", true),
+    ("Flag", "implicitRules", "implicitRules", "uri", false),
+    ("Flag", "language", "language", "code", false),
+    ("Flag", "meta", "meta", "Meta", false),
+    (
+        "Flag",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Flag", "period", "period", "Period", false),
+    ("Flag", "status", "status", "code", false),
+    ("Flag", "subject", "subject", "Reference", false),
+    ("Flag", "text", "text", "Narrative", false),
+    (
+        "Goal",
+        "achievementStatus",
+        "achievementStatus",
+        "CodeableConcept",
+        false,
+    ),
+    ("Goal", "addresses", "addresses", "Reference", true),
+    ("Goal", "category", "category", "CodeableConcept", true),
+    ("Goal", "contained", "contained", "ResourceList", true),
+    (
+        "Goal",
+        "description",
+        "description",
+        "CodeableConcept",
+        false,
+    ),
+    ("Goal", "expressedBy", "expressedBy", "Reference", false),
+    ("Goal", "extension", "extension", "Extension", true),
+    ("Goal", "id", "id", "id", false),
+    ("Goal", "identifier", "identifier", "Identifier", true),
+    ("Goal", "implicitRules", "implicitRules", "uri", false),
+    ("Goal", "language", "language", "code", false),
+    ("Goal", "lifecycleStatus", "lifecycleStatus", "code", false),
+    ("Goal", "meta", "meta", "Meta", false),
+    (
+        "Goal",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Goal", "note", "note", "Annotation", true),
+    (
+        "Goal",
+        "outcomeCode",
+        "outcomeCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Goal",
+        "outcomeReference",
+        "outcomeReference",
+        "Reference",
+        true,
+    ),
+    ("Goal", "priority", "priority", "CodeableConcept", false),
+    (
+        "Goal",
+        "startCodeableConcept",
+        "start",
+        "CodeableConcept",
+        false,
+    ),
+    ("Goal", "startDate", "start", "date", false),
+    ("Goal", "statusDate", "statusDate", "date", false),
+    ("Goal", "statusReason", "statusReason", "string", false),
+    ("Goal", "subject", "subject", "Reference", false),
+    ("Goal", "target", "target", "Goal_Target", true),
+    ("Goal", "text", "text", "Narrative", false),
+    ("Goal_Target", "detailBoolean", "detail", "boolean", false),
+    (
+        "Goal_Target",
+        "detailCodeableConcept",
+        "detail",
+        "CodeableConcept",
+        false,
+    ),
+    ("Goal_Target", "detailInteger", "detail", "integer", false),
+    ("Goal_Target", "detailQuantity", "detail", "Quantity", false),
+    ("Goal_Target", "detailRange", "detail", "Range", false),
+    ("Goal_Target", "detailRatio", "detail", "Ratio", false),
+    ("Goal_Target", "detailString", "detail", "string", false),
+    ("Goal_Target", "dueDate", "due", "date", false),
+    ("Goal_Target", "dueDuration", "due", "Duration", false),
+    ("Goal_Target", "extension", "extension", "Extension", true),
+    ("Goal_Target", "id", "id", "string", false),
+    (
+        "Goal_Target",
+        "measure",
+        "measure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Goal_Target",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "GraphDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "GraphDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("GraphDefinition", "date", "date", "dateTime", false),
+    (
+        "GraphDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "GraphDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "GraphDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition", "id", "id", "id", false),
+    (
+        "GraphDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "GraphDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("GraphDefinition", "language", "language", "code", false),
+    (
+        "GraphDefinition",
+        "link",
+        "link",
+        "GraphDefinition_Link",
+        true,
+    ),
+    ("GraphDefinition", "meta", "meta", "Meta", false),
+    (
+        "GraphDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition", "name", "name", "string", false),
+    ("GraphDefinition", "profile", "profile", "canonical", false),
+    ("GraphDefinition", "publisher", "publisher", "string", false),
+    ("GraphDefinition", "purpose", "purpose", "markdown", false),
+    ("GraphDefinition", "start", "start", "code", false),
+    ("GraphDefinition", "status", "status", "code", false),
+    ("GraphDefinition", "text", "text", "Narrative", false),
+    ("GraphDefinition", "url", "url", "uri", false),
+    (
+        "GraphDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("GraphDefinition", "version", "version", "string", false),
+    ("GraphDefinition_Compartment", "code", "code", "code", false),
+    (
+        "GraphDefinition_Compartment",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "GraphDefinition_Compartment",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "GraphDefinition_Compartment",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition_Compartment", "id", "id", "string", false),
+    (
+        "GraphDefinition_Compartment",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition_Compartment", "rule", "rule", "code", false),
+    ("GraphDefinition_Compartment", "use", "use", "code", false),
+    (
+        "GraphDefinition_Link",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "GraphDefinition_Link",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition_Link", "id", "id", "string", false),
+    ("GraphDefinition_Link", "max", "max", "string", false),
+    ("GraphDefinition_Link", "min", "min", "integer", false),
+    (
+        "GraphDefinition_Link",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition_Link", "path", "path", "string", false),
+    (
+        "GraphDefinition_Link",
+        "sliceName",
+        "sliceName",
+        "string",
+        false,
+    ),
+    (
+        "GraphDefinition_Link",
+        "target",
+        "target",
+        "GraphDefinition_Target",
+        true,
+    ),
+    (
+        "GraphDefinition_Target",
+        "compartment",
+        "compartment",
+        "GraphDefinition_Compartment",
+        true,
+    ),
+    (
+        "GraphDefinition_Target",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("GraphDefinition_Target", "id", "id", "string", false),
+    (
+        "GraphDefinition_Target",
+        "link",
+        "link",
+        "GraphDefinition_Link",
+        true,
+    ),
+    (
+        "GraphDefinition_Target",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "GraphDefinition_Target",
+        "params",
+        "params",
+        "string",
+        false,
+    ),
+    (
+        "GraphDefinition_Target",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    ("GraphDefinition_Target", "type", "type", "code", false),
+    ("Group", "active", "active", "boolean", false),
+    ("Group", "actual", "actual", "boolean", false),
+    (
+        "Group",
+        "characteristic",
+        "characteristic",
+        "Group_Characteristic",
+        true,
+    ),
+    ("Group", "code", "code", "CodeableConcept", false),
+    ("Group", "contained", "contained", "ResourceList", true),
+    ("Group", "extension", "extension", "Extension", true),
+    ("Group", "id", "id", "id", false),
+    ("Group", "identifier", "identifier", "Identifier", true),
+    ("Group", "implicitRules", "implicitRules", "uri", false),
+    ("Group", "language", "language", "code", false),
+    (
+        "Group",
+        "managingEntity",
+        "managingEntity",
+        "Reference",
+        false,
+    ),
+    ("Group", "member", "member", "Group_Member", true),
+    ("Group", "meta", "meta", "Meta", false),
+    (
+        "Group",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Group", "name", "name", "string", false),
+    ("Group", "quantity", "quantity", "unsignedInt", false),
+    ("Group", "text", "text", "Narrative", false),
+    ("Group", "type", "type", "code", false),
+    (
+        "Group_Characteristic",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Group_Characteristic",
+        "exclude",
+        "exclude",
+        "boolean",
+        false,
+    ),
+    (
+        "Group_Characteristic",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Group_Characteristic", "id", "id", "string", false),
+    (
+        "Group_Characteristic",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Group_Characteristic", "period", "period", "Period", false),
+    (
+        "Group_Characteristic",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "Group_Characteristic",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Group_Characteristic",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Group_Characteristic",
+        "valueRange",
+        "value",
+        "Range",
+        false,
+    ),
+    (
+        "Group_Characteristic",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    ("Group_Member", "entity", "entity", "Reference", false),
+    ("Group_Member", "extension", "extension", "Extension", true),
+    ("Group_Member", "id", "id", "string", false),
+    ("Group_Member", "inactive", "inactive", "boolean", false),
+    (
+        "Group_Member",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Group_Member", "period", "period", "Period", false),
+    (
+        "GuidanceResponse",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "dataRequirement",
+        "dataRequirement",
+        "DataRequirement",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "GuidanceResponse",
+        "evaluationMessage",
+        "evaluationMessage",
+        "Reference",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("GuidanceResponse", "id", "id", "id", false),
+    (
+        "GuidanceResponse",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("GuidanceResponse", "language", "language", "code", false),
+    ("GuidanceResponse", "meta", "meta", "Meta", false),
+    (
+        "GuidanceResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "moduleCanonical",
+        "module",
+        "canonical",
+        false,
+    ),
+    (
+        "GuidanceResponse",
+        "moduleCodeableConcept",
+        "module",
+        "CodeableConcept",
+        false,
+    ),
+    ("GuidanceResponse", "moduleUri", "module", "uri", false),
+    ("GuidanceResponse", "note", "note", "Annotation", true),
+    (
+        "GuidanceResponse",
+        "occurrenceDateTime",
+        "occurrenceDateTime",
+        "dateTime",
+        false,
+    ),
+    (
+        "GuidanceResponse",
+        "outputParameters",
+        "outputParameters",
+        "Reference",
+        false,
+    ),
+    (
+        "GuidanceResponse",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    (
+        "GuidanceResponse",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "GuidanceResponse",
+        "requestIdentifier",
+        "requestIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("GuidanceResponse", "result", "result", "Reference", false),
+    ("GuidanceResponse", "status", "status", "code", false),
+    ("GuidanceResponse", "subject", "subject", "Reference", false),
+    ("GuidanceResponse", "text", "text", "Narrative", false),
+    ("HealthcareService", "active", "active", "boolean", false),
+    (
+        "HealthcareService",
+        "appointmentRequired",
+        "appointmentRequired",
+        "boolean",
+        false,
+    ),
+    (
+        "HealthcareService",
+        "availabilityExceptions",
+        "availabilityExceptions",
+        "string",
+        false,
+    ),
+    (
+        "HealthcareService",
+        "availableTime",
+        "availableTime",
+        "HealthcareService_AvailableTime",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "characteristic",
+        "characteristic",
+        "CodeableConcept",
+        true,
+    ),
+    ("HealthcareService", "comment", "comment", "string", false),
+    (
+        "HealthcareService",
+        "communication",
+        "communication",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "coverageArea",
+        "coverageArea",
+        "Reference",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "eligibility",
+        "eligibility",
+        "HealthcareService_Eligibility",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "endpoint",
+        "endpoint",
+        "Reference",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "extraDetails",
+        "extraDetails",
+        "markdown",
+        false,
+    ),
+    ("HealthcareService", "id", "id", "id", false),
+    (
+        "HealthcareService",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("HealthcareService", "language", "language", "code", false),
+    (
+        "HealthcareService",
+        "location",
+        "location",
+        "Reference",
+        true,
+    ),
+    ("HealthcareService", "meta", "meta", "Meta", false),
+    (
+        "HealthcareService",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("HealthcareService", "name", "name", "string", false),
+    (
+        "HealthcareService",
+        "notAvailable",
+        "notAvailable",
+        "HealthcareService_NotAvailable",
+        true,
+    ),
+    ("HealthcareService", "photo", "photo", "Attachment", false),
+    (
+        "HealthcareService",
+        "program",
+        "program",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "providedBy",
+        "providedBy",
+        "Reference",
+        false,
+    ),
+    (
+        "HealthcareService",
+        "referralMethod",
+        "referralMethod",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "serviceProvisionCode",
+        "serviceProvisionCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "specialty",
+        "specialty",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "HealthcareService",
+        "telecom",
+        "telecom",
+        "ContactPoint",
+        true,
+    ),
+    ("HealthcareService", "text", "text", "Narrative", false),
+    ("HealthcareService", "type", "type", "CodeableConcept", true),
+    (
+        "HealthcareService_AvailableTime",
+        "allDay",
+        "allDay",
+        "boolean",
+        false,
+    ),
+    (
+        "HealthcareService_AvailableTime",
+        "availableEndTime",
+        "availableEndTime",
+        "time",
+        false,
+    ),
+    (
+        "HealthcareService_AvailableTime",
+        "availableStartTime",
+        "availableStartTime",
+        "time",
+        false,
+    ),
+    (
+        "HealthcareService_AvailableTime",
+        "daysOfWeek",
+        "daysOfWeek",
+        "code",
+        true,
+    ),
+    (
+        "HealthcareService_AvailableTime",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "HealthcareService_AvailableTime",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "HealthcareService_AvailableTime",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "HealthcareService_Eligibility",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "HealthcareService_Eligibility",
+        "comment",
+        "comment",
+        "markdown",
+        false,
+    ),
+    (
+        "HealthcareService_Eligibility",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("HealthcareService_Eligibility", "id", "id", "string", false),
+    (
+        "HealthcareService_Eligibility",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "HealthcareService_NotAvailable",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "HealthcareService_NotAvailable",
+        "during",
+        "during",
+        "Period",
+        false,
+    ),
+    (
+        "HealthcareService_NotAvailable",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "HealthcareService_NotAvailable",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "HealthcareService_NotAvailable",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("HumanName", "extension", "extension", "Extension", true),
+    ("HumanName", "family", "family", "string", false),
+    ("HumanName", "given", "given", "string", true),
+    ("HumanName", "id", "id", "string", false),
+    ("HumanName", "period", "period", "Period", false),
+    ("HumanName", "prefix", "prefix", "string", true),
+    ("HumanName", "suffix", "suffix", "string", true),
+    ("HumanName", "text", "text", "string", false),
+    ("HumanName", "use", "use", "code", false),
+    ("Identifier", "assigner", "assigner", "Reference", false),
+    ("Identifier", "extension", "extension", "Extension", true),
+    ("Identifier", "id", "id", "string", false),
+    ("Identifier", "period", "period", "Period", false),
+    ("Identifier", "system", "system", "uri", false),
+    ("Identifier", "type", "type", "CodeableConcept", false),
+    ("Identifier", "use", "use", "code", false),
+    ("Identifier", "value", "value", "string", false),
+    ("ImagingStudy", "basedOn", "basedOn", "Reference", true),
+    (
+        "ImagingStudy",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ImagingStudy",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    ("ImagingStudy", "encounter", "encounter", "Reference", false),
+    ("ImagingStudy", "endpoint", "endpoint", "Reference", true),
+    ("ImagingStudy", "extension", "extension", "Extension", true),
+    ("ImagingStudy", "id", "id", "id", false),
+    (
+        "ImagingStudy",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ImagingStudy",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ImagingStudy",
+        "interpreter",
+        "interpreter",
+        "Reference",
+        true,
+    ),
+    ("ImagingStudy", "language", "language", "code", false),
+    ("ImagingStudy", "location", "location", "Reference", false),
+    ("ImagingStudy", "meta", "meta", "Meta", false),
+    ("ImagingStudy", "modality", "modality", "Coding", true),
+    (
+        "ImagingStudy",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ImagingStudy", "note", "note", "Annotation", true),
+    (
+        "ImagingStudy",
+        "numberOfInstances",
+        "numberOfInstances",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ImagingStudy",
+        "numberOfSeries",
+        "numberOfSeries",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ImagingStudy",
+        "procedureCode",
+        "procedureCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ImagingStudy",
+        "procedureReference",
+        "procedureReference",
+        "Reference",
+        false,
+    ),
+    (
+        "ImagingStudy",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ImagingStudy",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("ImagingStudy", "referrer", "referrer", "Reference", false),
+    (
+        "ImagingStudy",
+        "series",
+        "series",
+        "ImagingStudy_Series",
+        true,
+    ),
+    ("ImagingStudy", "started", "started", "dateTime", false),
+    ("ImagingStudy", "status", "status", "code", false),
+    ("ImagingStudy", "subject", "subject", "Reference", false),
+    ("ImagingStudy", "text", "text", "Narrative", false),
+    (
+        "ImagingStudy_Instance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImagingStudy_Instance", "id", "id", "string", false),
+    (
+        "ImagingStudy_Instance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImagingStudy_Instance",
+        "number",
+        "number",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ImagingStudy_Instance",
+        "sopClass",
+        "sopClass",
+        "Coding",
+        false,
+    ),
+    ("ImagingStudy_Instance", "title", "title", "string", false),
+    ("ImagingStudy_Instance", "uid", "uid", "id", false),
+    (
+        "ImagingStudy_Performer",
+        "actor",
+        "actor",
+        "Reference",
+        false,
+    ),
+    (
+        "ImagingStudy_Performer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImagingStudy_Performer",
+        "function",
+        "function",
+        "CodeableConcept",
+        false,
+    ),
+    ("ImagingStudy_Performer", "id", "id", "string", false),
+    (
+        "ImagingStudy_Performer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImagingStudy_Series",
+        "bodySite",
+        "bodySite",
+        "Coding",
+        false,
+    ),
+    (
+        "ImagingStudy_Series",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ImagingStudy_Series",
+        "endpoint",
+        "endpoint",
+        "Reference",
+        true,
+    ),
+    (
+        "ImagingStudy_Series",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImagingStudy_Series", "id", "id", "string", false),
+    (
+        "ImagingStudy_Series",
+        "instance",
+        "instance",
+        "ImagingStudy_Instance",
+        true,
+    ),
+    (
+        "ImagingStudy_Series",
+        "laterality",
+        "laterality",
+        "Coding",
+        false,
+    ),
+    (
+        "ImagingStudy_Series",
+        "modality",
+        "modality",
+        "Coding",
+        false,
+    ),
+    (
+        "ImagingStudy_Series",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImagingStudy_Series",
+        "number",
+        "number",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ImagingStudy_Series",
+        "numberOfInstances",
+        "numberOfInstances",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "ImagingStudy_Series",
+        "performer",
+        "performer",
+        "ImagingStudy_Performer",
+        true,
+    ),
+    (
+        "ImagingStudy_Series",
+        "specimen",
+        "specimen",
+        "Reference",
+        true,
+    ),
+    (
+        "ImagingStudy_Series",
+        "started",
+        "started",
+        "dateTime",
+        false,
+    ),
+    ("ImagingStudy_Series", "uid", "uid", "id", false),
+    (
+        "Immunization",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "Immunization",
+        "doseQuantity",
+        "doseQuantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "Immunization",
+        "education",
+        "education",
+        "Immunization_Education",
+        true,
+    ),
+    ("Immunization", "encounter", "encounter", "Reference", false),
+    (
+        "Immunization",
+        "expirationDate",
+        "expirationDate",
+        "date",
+        false,
+    ),
+    ("Immunization", "extension", "extension", "Extension", true),
+    (
+        "Immunization",
+        "fundingSource",
+        "fundingSource",
+        "CodeableConcept",
+        false,
+    ),
+    ("Immunization", "id", "id", "id", false),
+    (
+        "Immunization",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Immunization",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "Immunization",
+        "isSubpotent",
+        "isSubpotent",
+        "boolean",
+        false,
+    ),
+    ("Immunization", "language", "language", "code", false),
+    ("Immunization", "location", "location", "Reference", false),
+    ("Immunization", "lotNumber", "lotNumber", "string", false),
+    (
+        "Immunization",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        false,
+    ),
+    ("Immunization", "meta", "meta", "Meta", false),
+    (
+        "Immunization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Immunization", "note", "note", "Annotation", true),
+    (
+        "Immunization",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "Immunization",
+        "occurrenceString",
+        "occurrence",
+        "string",
+        false,
+    ),
+    ("Immunization", "patient", "patient", "Reference", false),
+    (
+        "Immunization",
+        "performer",
+        "performer",
+        "Immunization_Performer",
+        true,
+    ),
+    (
+        "Immunization",
+        "primarySource",
+        "primarySource",
+        "boolean",
+        false,
+    ),
+    (
+        "Immunization",
+        "programEligibility",
+        "programEligibility",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Immunization",
+        "protocolApplied",
+        "protocolApplied",
+        "Immunization_ProtocolApplied",
+        true,
+    ),
+    (
+        "Immunization",
+        "reaction",
+        "reaction",
+        "Immunization_Reaction",
+        true,
+    ),
+    (
+        "Immunization",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Immunization",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("Immunization", "recorded", "recorded", "dateTime", false),
+    (
+        "Immunization",
+        "reportOrigin",
+        "reportOrigin",
+        "CodeableConcept",
+        false,
+    ),
+    ("Immunization", "route", "route", "CodeableConcept", false),
+    ("Immunization", "site", "site", "CodeableConcept", false),
+    ("Immunization", "status", "status", "code", false),
+    (
+        "Immunization",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Immunization",
+        "subpotentReason",
+        "subpotentReason",
+        "CodeableConcept",
+        true,
+    ),
+    ("Immunization", "text", "text", "Narrative", false),
+    (
+        "Immunization",
+        "vaccineCode",
+        "vaccineCode",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "authority",
+        "authority",
+        "Reference",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("ImmunizationEvaluation", "date", "date", "dateTime", false),
+    (
+        "ImmunizationEvaluation",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "doseNumberPositiveInt",
+        "doseNumber",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "doseNumberString",
+        "doseNumber",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "doseStatus",
+        "doseStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "doseStatusReason",
+        "doseStatusReason",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImmunizationEvaluation", "id", "id", "id", false),
+    (
+        "ImmunizationEvaluation",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "immunizationEvent",
+        "immunizationEvent",
+        "Reference",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("ImmunizationEvaluation", "meta", "meta", "Meta", false),
+    (
+        "ImmunizationEvaluation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "series",
+        "series",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "seriesDosesPositiveInt",
+        "seriesDoses",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ImmunizationEvaluation",
+        "seriesDosesString",
+        "seriesDoses",
+        "string",
+        false,
+    ),
+    ("ImmunizationEvaluation", "status", "status", "code", false),
+    (
+        "ImmunizationEvaluation",
+        "targetDisease",
+        "targetDisease",
+        "CodeableConcept",
+        false,
+    ),
+    ("ImmunizationEvaluation", "text", "text", "Narrative", false),
+    (
+        "ImmunizationRecommendation",
+        "authority",
+        "authority",
+        "Reference",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "date",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImmunizationRecommendation", "id", "id", "id", false),
+    (
+        "ImmunizationRecommendation",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("ImmunizationRecommendation", "meta", "meta", "Meta", false),
+    (
+        "ImmunizationRecommendation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "recommendation",
+        "recommendation",
+        "ImmunizationRecommendation_Recommendation",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_DateCriterion",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_DateCriterion",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_DateCriterion",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_DateCriterion",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_DateCriterion",
+        "value",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "contraindicatedVaccineCode",
+        "contraindicatedVaccineCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "dateCriterion",
+        "dateCriterion",
+        "ImmunizationRecommendation_DateCriterion",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "doseNumberPositiveInt",
+        "doseNumber",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "doseNumberString",
+        "doseNumber",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "forecastReason",
+        "forecastReason",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "forecastStatus",
+        "forecastStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "series",
+        "series",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "seriesDosesPositiveInt",
+        "seriesDoses",
+        "positiveInt",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "seriesDosesString",
+        "seriesDoses",
+        "string",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "supportingImmunization",
+        "supportingImmunization",
+        "Reference",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "supportingPatientInformation",
+        "supportingPatientInformation",
+        "Reference",
+        true,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "targetDisease",
+        "targetDisease",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ImmunizationRecommendation_Recommendation",
+        "vaccineCode",
+        "vaccineCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Immunization_Education",
+        "documentType",
+        "documentType",
+        "string",
+        false,
+    ),
+    (
+        "Immunization_Education",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Immunization_Education", "id", "id", "string", false),
+    (
+        "Immunization_Education",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Immunization_Education",
+        "presentationDate",
+        "presentationDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "Immunization_Education",
+        "publicationDate",
+        "publicationDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "Immunization_Education",
+        "reference",
+        "reference",
+        "uri",
+        false,
+    ),
+    (
+        "Immunization_Performer",
+        "actor",
+        "actor",
+        "Reference",
+        false,
+    ),
+    (
+        "Immunization_Performer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "Immunization_Performer",
+        "function",
+        "function",
+        "CodeableConcept",
+        false,
+    ),
+    ("Immunization_Performer", "id", "id", "string", false),
+    (
+        "Immunization_Performer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "authority",
+        "authority",
+        "Reference",
+        false,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "doseNumberPositiveInt",
+        "doseNumber",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "doseNumberString",
+        "doseNumber",
+        "string",
+        false,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Immunization_ProtocolApplied", "id", "id", "string", false),
+    (
+        "Immunization_ProtocolApplied",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "series",
+        "series",
+        "string",
+        false,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "seriesDosesPositiveInt",
+        "seriesDoses",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "seriesDosesString",
+        "seriesDoses",
+        "string",
+        false,
+    ),
+    (
+        "Immunization_ProtocolApplied",
+        "targetDisease",
+        "targetDisease",
+        "CodeableConcept",
+        true,
+    ),
+    ("Immunization_Reaction", "date", "date", "dateTime", false),
+    (
+        "Immunization_Reaction",
+        "detail",
+        "detail",
+        "Reference",
+        false,
+    ),
+    (
+        "Immunization_Reaction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Immunization_Reaction", "id", "id", "string", false),
+    (
+        "Immunization_Reaction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Immunization_Reaction",
+        "reported",
+        "reported",
+        "boolean",
+        false,
+    ),
+    (
+        "ImplementationGuide",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ImplementationGuide",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ImplementationGuide",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("ImplementationGuide", "date", "date", "dateTime", false),
+    (
+        "ImplementationGuide",
+        "definition",
+        "definition",
+        "ImplementationGuide_Definition",
+        false,
+    ),
+    (
+        "ImplementationGuide",
+        "dependsOn",
+        "dependsOn",
+        "ImplementationGuide_DependsOn",
+        true,
+    ),
+    (
+        "ImplementationGuide",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ImplementationGuide",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "ImplementationGuide",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide",
+        "fhirVersion",
+        "fhirVersion",
+        "code",
+        true,
+    ),
+    (
+        "ImplementationGuide",
+        "global",
+        "global",
+        "ImplementationGuide_Global",
+        true,
+    ),
+    ("ImplementationGuide", "id", "id", "id", false),
+    (
+        "ImplementationGuide",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ImplementationGuide",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("ImplementationGuide", "language", "language", "code", false),
+    ("ImplementationGuide", "license", "license", "code", false),
+    (
+        "ImplementationGuide",
+        "manifest",
+        "manifest",
+        "ImplementationGuide_Manifest",
+        false,
+    ),
+    ("ImplementationGuide", "meta", "meta", "Meta", false),
+    (
+        "ImplementationGuide",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide", "name", "name", "string", false),
+    ("ImplementationGuide", "packageId", "packageId", "id", false),
+    (
+        "ImplementationGuide",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    ("ImplementationGuide", "status", "status", "code", false),
+    ("ImplementationGuide", "text", "text", "Narrative", false),
+    ("ImplementationGuide", "title", "title", "string", false),
+    ("ImplementationGuide", "url", "url", "uri", false),
+    (
+        "ImplementationGuide",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("ImplementationGuide", "version", "version", "string", false),
+    (
+        "ImplementationGuide_Definition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "grouping",
+        "grouping",
+        "ImplementationGuide_Grouping",
+        true,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "page",
+        "page",
+        "ImplementationGuide_Page",
+        false,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "parameter",
+        "parameter",
+        "ImplementationGuide_Parameter",
+        true,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "resource",
+        "resource",
+        "ImplementationGuide_Resource",
+        true,
+    ),
+    (
+        "ImplementationGuide_Definition",
+        "template",
+        "template",
+        "ImplementationGuide_Template",
+        true,
+    ),
+    (
+        "ImplementationGuide_DependsOn",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_DependsOn", "id", "id", "string", false),
+    (
+        "ImplementationGuide_DependsOn",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_DependsOn",
+        "packageId",
+        "packageId",
+        "id",
+        false,
+    ),
+    (
+        "ImplementationGuide_DependsOn",
+        "uri",
+        "uri",
+        "canonical",
+        false,
+    ),
+    (
+        "ImplementationGuide_DependsOn",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Global",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Global", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Global",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Global",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    ("ImplementationGuide_Global", "type", "type", "code", false),
+    (
+        "ImplementationGuide_Grouping",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Grouping",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Grouping", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Grouping",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Grouping",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Manifest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Manifest", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Manifest",
+        "image",
+        "image",
+        "string",
+        true,
+    ),
+    (
+        "ImplementationGuide_Manifest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Manifest",
+        "other",
+        "other",
+        "string",
+        true,
+    ),
+    (
+        "ImplementationGuide_Manifest",
+        "page",
+        "page",
+        "ImplementationGuide_Page1",
+        true,
+    ),
+    (
+        "ImplementationGuide_Manifest",
+        "rendering",
+        "rendering",
+        "url",
+        false,
+    ),
+    (
+        "ImplementationGuide_Manifest",
+        "resource",
+        "resource",
+        "ImplementationGuide_Resource1",
+        true,
+    ),
+    (
+        "ImplementationGuide_Page",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Page",
+        "generation",
+        "generation",
+        "code",
+        false,
+    ),
+    ("ImplementationGuide_Page", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Page",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Page",
+        "nameReference",
+        "name",
+        "Reference",
+        false,
+    ),
+    ("ImplementationGuide_Page", "nameUrl", "name", "url", false),
+    (
+        "ImplementationGuide_Page",
+        "page",
+        "page",
+        "ImplementationGuide_Page",
+        true,
+    ),
+    (
+        "ImplementationGuide_Page",
+        "title",
+        "title",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Page1",
+        "anchor",
+        "anchor",
+        "string",
+        true,
+    ),
+    (
+        "ImplementationGuide_Page1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Page1", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Page1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Page1", "name", "name", "string", false),
+    (
+        "ImplementationGuide_Page1",
+        "title",
+        "title",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Parameter",
+        "code",
+        "code",
+        "code",
+        false,
+    ),
+    (
+        "ImplementationGuide_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Parameter", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Parameter",
+        "value",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "exampleBoolean",
+        "example",
+        "boolean",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "exampleCanonical",
+        "example",
+        "canonical",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "fhirVersion",
+        "fhirVersion",
+        "code",
+        true,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "groupingId",
+        "groupingId",
+        "id",
+        false,
+    ),
+    ("ImplementationGuide_Resource", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Resource",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource",
+        "reference",
+        "reference",
+        "Reference",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource1",
+        "exampleBoolean",
+        "example",
+        "boolean",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource1",
+        "exampleCanonical",
+        "example",
+        "canonical",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Resource1", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Resource1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Resource1",
+        "reference",
+        "reference",
+        "Reference",
+        false,
+    ),
+    (
+        "ImplementationGuide_Resource1",
+        "relativePath",
+        "relativePath",
+        "url",
+        false,
+    ),
+    (
+        "ImplementationGuide_Template",
+        "code",
+        "code",
+        "code",
+        false,
+    ),
+    (
+        "ImplementationGuide_Template",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ImplementationGuide_Template", "id", "id", "string", false),
+    (
+        "ImplementationGuide_Template",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ImplementationGuide_Template",
+        "scope",
+        "scope",
+        "string",
+        false,
+    ),
+    (
+        "ImplementationGuide_Template",
+        "source",
+        "source",
+        "string",
+        false,
+    ),
+    (
+        "InsurancePlan",
+        "administeredBy",
+        "administeredBy",
+        "Reference",
+        false,
+    ),
+    ("InsurancePlan", "alias", "alias", "string", true),
+    (
+        "InsurancePlan",
+        "contact",
+        "contact",
+        "InsurancePlan_Contact",
+        true,
+    ),
+    (
+        "InsurancePlan",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "InsurancePlan",
+        "coverage",
+        "coverage",
+        "InsurancePlan_Coverage",
+        true,
+    ),
+    (
+        "InsurancePlan",
+        "coverageArea",
+        "coverageArea",
+        "Reference",
+        true,
+    ),
+    ("InsurancePlan", "endpoint", "endpoint", "Reference", true),
+    ("InsurancePlan", "extension", "extension", "Extension", true),
+    ("InsurancePlan", "id", "id", "id", false),
+    (
+        "InsurancePlan",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "InsurancePlan",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("InsurancePlan", "language", "language", "code", false),
+    ("InsurancePlan", "meta", "meta", "Meta", false),
+    (
+        "InsurancePlan",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan", "name", "name", "string", false),
+    ("InsurancePlan", "network", "network", "Reference", true),
+    ("InsurancePlan", "ownedBy", "ownedBy", "Reference", false),
+    ("InsurancePlan", "period", "period", "Period", false),
+    ("InsurancePlan", "plan", "plan", "InsurancePlan_Plan", true),
+    ("InsurancePlan", "status", "status", "code", false),
+    ("InsurancePlan", "text", "text", "Narrative", false),
+    ("InsurancePlan", "type", "type", "CodeableConcept", true),
+    (
+        "InsurancePlan_Benefit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Benefit", "id", "id", "string", false),
+    (
+        "InsurancePlan_Benefit",
+        "limit",
+        "limit",
+        "InsurancePlan_Limit",
+        true,
+    ),
+    (
+        "InsurancePlan_Benefit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_Benefit",
+        "requirement",
+        "requirement",
+        "string",
+        false,
+    ),
+    (
+        "InsurancePlan_Benefit",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_Benefit1",
+        "cost",
+        "cost",
+        "InsurancePlan_Cost",
+        true,
+    ),
+    (
+        "InsurancePlan_Benefit1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Benefit1", "id", "id", "string", false),
+    (
+        "InsurancePlan_Benefit1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_Benefit1",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_Contact",
+        "address",
+        "address",
+        "Address",
+        false,
+    ),
+    (
+        "InsurancePlan_Contact",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Contact", "id", "id", "string", false),
+    (
+        "InsurancePlan_Contact",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Contact", "name", "name", "HumanName", false),
+    (
+        "InsurancePlan_Contact",
+        "purpose",
+        "purpose",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_Contact",
+        "telecom",
+        "telecom",
+        "ContactPoint",
+        true,
+    ),
+    (
+        "InsurancePlan_Cost",
+        "applicability",
+        "applicability",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_Cost",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Cost", "id", "id", "string", false),
+    (
+        "InsurancePlan_Cost",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_Cost",
+        "qualifiers",
+        "qualifiers",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "InsurancePlan_Cost",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    ("InsurancePlan_Cost", "value", "value", "Quantity", false),
+    (
+        "InsurancePlan_Coverage",
+        "benefit",
+        "benefit",
+        "InsurancePlan_Benefit",
+        true,
+    ),
+    (
+        "InsurancePlan_Coverage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Coverage", "id", "id", "string", false),
+    (
+        "InsurancePlan_Coverage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_Coverage",
+        "network",
+        "network",
+        "Reference",
+        true,
+    ),
+    (
+        "InsurancePlan_Coverage",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_GeneralCost",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    ("InsurancePlan_GeneralCost", "cost", "cost", "Money", false),
+    (
+        "InsurancePlan_GeneralCost",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_GeneralCost",
+        "groupSize",
+        "groupSize",
+        "positiveInt",
+        false,
+    ),
+    ("InsurancePlan_GeneralCost", "id", "id", "string", false),
+    (
+        "InsurancePlan_GeneralCost",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_GeneralCost",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_Limit",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_Limit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Limit", "id", "id", "string", false),
+    (
+        "InsurancePlan_Limit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_Limit", "value", "value", "Quantity", false),
+    (
+        "InsurancePlan_Plan",
+        "coverageArea",
+        "coverageArea",
+        "Reference",
+        true,
+    ),
+    (
+        "InsurancePlan_Plan",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_Plan",
+        "generalCost",
+        "generalCost",
+        "InsurancePlan_GeneralCost",
+        true,
+    ),
+    ("InsurancePlan_Plan", "id", "id", "string", false),
+    (
+        "InsurancePlan_Plan",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "InsurancePlan_Plan",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "InsurancePlan_Plan",
+        "network",
+        "network",
+        "Reference",
+        true,
+    ),
+    (
+        "InsurancePlan_Plan",
+        "specificCost",
+        "specificCost",
+        "InsurancePlan_SpecificCost",
+        true,
+    ),
+    (
+        "InsurancePlan_Plan",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_SpecificCost",
+        "benefit",
+        "benefit",
+        "InsurancePlan_Benefit1",
+        true,
+    ),
+    (
+        "InsurancePlan_SpecificCost",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "InsurancePlan_SpecificCost",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("InsurancePlan_SpecificCost", "id", "id", "string", false),
+    (
+        "InsurancePlan_SpecificCost",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Invoice", "account", "account", "Reference", false),
+    (
+        "Invoice",
+        "cancelledReason",
+        "cancelledReason",
+        "string",
+        false,
+    ),
+    ("Invoice", "contained", "contained", "ResourceList", true),
+    ("Invoice", "date", "date", "dateTime", false),
+    ("Invoice", "extension", "extension", "Extension", true),
+    ("Invoice", "id", "id", "id", false),
+    ("Invoice", "identifier", "identifier", "Identifier", true),
+    ("Invoice", "implicitRules", "implicitRules", "uri", false),
+    ("Invoice", "issuer", "issuer", "Reference", false),
+    ("Invoice", "language", "language", "code", false),
+    ("Invoice", "lineItem", "lineItem", "Invoice_LineItem", true),
+    ("Invoice", "meta", "meta", "Meta", false),
+    (
+        "Invoice",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Invoice", "note", "note", "Annotation", true),
+    (
+        "Invoice",
+        "participant",
+        "participant",
+        "Invoice_Participant",
+        true,
+    ),
+    ("Invoice", "paymentTerms", "paymentTerms", "markdown", false),
+    ("Invoice", "recipient", "recipient", "Reference", false),
+    ("Invoice", "status", "status", "code", false),
+    ("Invoice", "subject", "subject", "Reference", false),
+    ("Invoice", "text", "text", "Narrative", false),
+    ("Invoice", "totalGross", "totalGross", "Money", false),
+    ("Invoice", "totalNet", "totalNet", "Money", false),
+    (
+        "Invoice",
+        "totalPriceComponent",
+        "totalPriceComponent",
+        "Invoice_PriceComponent",
+        true,
+    ),
+    ("Invoice", "type", "type", "CodeableConcept", false),
+    (
+        "Invoice_LineItem",
+        "chargeItemCodeableConcept",
+        "chargeItem",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Invoice_LineItem",
+        "chargeItemReference",
+        "chargeItem",
+        "Reference",
+        false,
+    ),
+    (
+        "Invoice_LineItem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Invoice_LineItem", "id", "id", "string", false),
+    (
+        "Invoice_LineItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Invoice_LineItem",
+        "priceComponent",
+        "priceComponent",
+        "Invoice_PriceComponent",
+        true,
+    ),
+    (
+        "Invoice_LineItem",
+        "sequence",
+        "sequence",
+        "positiveInt",
+        false,
+    ),
+    ("Invoice_Participant", "actor", "actor", "Reference", false),
+    (
+        "Invoice_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Invoice_Participant", "id", "id", "string", false),
+    (
+        "Invoice_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Invoice_Participant",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    ("Invoice_PriceComponent", "amount", "amount", "Money", false),
+    (
+        "Invoice_PriceComponent",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Invoice_PriceComponent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "Invoice_PriceComponent",
+        "factor",
+        "factor",
+        "decimal",
+        false,
+    ),
+    ("Invoice_PriceComponent", "id", "id", "string", false),
+    (
+        "Invoice_PriceComponent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Invoice_PriceComponent", "type", "type", "code", false),
+    ("Library", "approvalDate", "approvalDate", "date", false),
+    ("Library", "author", "author", "ContactDetail", true),
+    ("Library", "contact", "contact", "ContactDetail", true),
+    ("Library", "contained", "contained", "ResourceList", true),
+    ("Library", "content", "content", "Attachment", true),
+    ("Library", "copyright", "copyright", "markdown", false),
+    (
+        "Library",
+        "dataRequirement",
+        "dataRequirement",
+        "DataRequirement",
+        true,
+    ),
+    ("Library", "date", "date", "dateTime", false),
+    ("Library", "description", "description", "markdown", false),
+    ("Library", "editor", "editor", "ContactDetail", true),
+    (
+        "Library",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    ("Library", "endorser", "endorser", "ContactDetail", true),
+    ("Library", "experimental", "experimental", "boolean", false),
+    ("Library", "extension", "extension", "Extension", true),
+    ("Library", "id", "id", "id", false),
+    ("Library", "identifier", "identifier", "Identifier", true),
+    ("Library", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Library",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("Library", "language", "language", "code", false),
+    ("Library", "lastReviewDate", "lastReviewDate", "date", false),
+    ("Library", "meta", "meta", "Meta", false),
+    (
+        "Library",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Library", "name", "name", "string", false),
+    (
+        "Library",
+        "parameter",
+        "parameter",
+        "ParameterDefinition",
+        true,
+    ),
+    ("Library", "publisher", "publisher", "string", false),
+    ("Library", "purpose", "purpose", "markdown", false),
+    (
+        "Library",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    ("Library", "reviewer", "reviewer", "ContactDetail", true),
+    ("Library", "status", "status", "code", false),
+    (
+        "Library",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    ("Library", "subjectReference", "subject", "Reference", false),
+    ("Library", "subtitle", "subtitle", "string", false),
+    ("Library", "text", "text", "Narrative", false),
+    ("Library", "title", "title", "string", false),
+    ("Library", "topic", "topic", "CodeableConcept", true),
+    ("Library", "type", "type", "CodeableConcept", false),
+    ("Library", "url", "url", "uri", false),
+    ("Library", "usage", "usage", "string", false),
+    ("Library", "useContext", "useContext", "UsageContext", true),
+    ("Library", "version", "version", "string", false),
+    ("Linkage", "active", "active", "boolean", false),
+    ("Linkage", "author", "author", "Reference", false),
+    ("Linkage", "contained", "contained", "ResourceList", true),
+    ("Linkage", "extension", "extension", "Extension", true),
+    ("Linkage", "id", "id", "id", false),
+    ("Linkage", "implicitRules", "implicitRules", "uri", false),
+    ("Linkage", "item", "item", "Linkage_Item", true),
+    ("Linkage", "language", "language", "code", false),
+    ("Linkage", "meta", "meta", "Meta", false),
+    (
+        "Linkage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Linkage", "text", "text", "Narrative", false),
+    ("Linkage_Item", "extension", "extension", "Extension", true),
+    ("Linkage_Item", "id", "id", "string", false),
+    (
+        "Linkage_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Linkage_Item", "resource", "resource", "Reference", false),
+    ("Linkage_Item", "type", "type", "code", false),
+    ("List", "code", "code", "CodeableConcept", false),
+    ("List", "contained", "contained", "ResourceList", true),
+    ("List", "date", "date", "dateTime", false),
+    (
+        "List",
+        "emptyReason",
+        "emptyReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("List", "encounter", "encounter", "Reference", false),
+    ("List", "entry", "entry", "List_Entry", true),
+    ("List", "extension", "extension", "Extension", true),
+    ("List", "id", "id", "id", false),
+    ("List", "identifier", "identifier", "Identifier", true),
+    ("List", "implicitRules", "implicitRules", "uri", false),
+    ("List", "language", "language", "code", false),
+    ("List", "meta", "meta", "Meta", false),
+    ("List", "mode", "mode", "code", false),
+    (
+        "List",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("List", "note", "note", "Annotation", true),
+    ("List", "orderedBy", "orderedBy", "CodeableConcept", false),
+    ("List", "source", "source", "Reference", false),
+    ("List", "status", "status", "code", false),
+    ("List", "subject", "subject", "Reference", false),
+    ("List", "text", "text", "Narrative", false),
+    ("List", "title", "title", "string", false),
+    ("List_Entry", "date", "date", "dateTime", false),
+    ("List_Entry", "deleted", "deleted", "boolean", false),
+    ("List_Entry", "extension", "extension", "Extension", true),
+    ("List_Entry", "flag", "flag", "CodeableConcept", false),
+    ("List_Entry", "id", "id", "string", false),
+    ("List_Entry", "item", "item", "Reference", false),
+    (
+        "List_Entry",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Location", "address", "address", "Address", false),
+    ("Location", "alias", "alias", "string", true),
+    (
+        "Location",
+        "availabilityExceptions",
+        "availabilityExceptions",
+        "string",
+        false,
+    ),
+    ("Location", "contained", "contained", "ResourceList", true),
+    ("Location", "description", "description", "string", false),
+    ("Location", "endpoint", "endpoint", "Reference", true),
+    ("Location", "extension", "extension", "Extension", true),
+    (
+        "Location",
+        "hoursOfOperation",
+        "hoursOfOperation",
+        "Location_HoursOfOperation",
+        true,
+    ),
+    ("Location", "id", "id", "id", false),
+    ("Location", "identifier", "identifier", "Identifier", true),
+    ("Location", "implicitRules", "implicitRules", "uri", false),
+    ("Location", "language", "language", "code", false),
+    (
+        "Location",
+        "managingOrganization",
+        "managingOrganization",
+        "Reference",
+        false,
+    ),
+    ("Location", "meta", "meta", "Meta", false),
+    ("Location", "mode", "mode", "code", false),
+    (
+        "Location",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Location", "name", "name", "string", false),
+    (
+        "Location",
+        "operationalStatus",
+        "operationalStatus",
+        "Coding",
+        false,
+    ),
+    ("Location", "partOf", "partOf", "Reference", false),
+    (
+        "Location",
+        "physicalType",
+        "physicalType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Location",
+        "position",
+        "position",
+        "Location_Position",
+        false,
+    ),
+    ("Location", "status", "status", "code", false),
+    ("Location", "telecom", "telecom", "ContactPoint", true),
+    ("Location", "text", "text", "Narrative", false),
+    ("Location", "type", "type", "CodeableConcept", true),
+    (
+        "Location_HoursOfOperation",
+        "allDay",
+        "allDay",
+        "boolean",
+        false,
+    ),
+    (
+        "Location_HoursOfOperation",
+        "closingTime",
+        "closingTime",
+        "time",
+        false,
+    ),
+    (
+        "Location_HoursOfOperation",
+        "daysOfWeek",
+        "daysOfWeek",
+        "code",
+        true,
+    ),
+    (
+        "Location_HoursOfOperation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Location_HoursOfOperation", "id", "id", "string", false),
+    (
+        "Location_HoursOfOperation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Location_HoursOfOperation",
+        "openingTime",
+        "openingTime",
+        "time",
+        false,
+    ),
+    (
+        "Location_Position",
+        "altitude",
+        "altitude",
+        "decimal",
+        false,
+    ),
+    (
+        "Location_Position",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Location_Position", "id", "id", "string", false),
+    (
+        "Location_Position",
+        "latitude",
+        "latitude",
+        "decimal",
+        false,
+    ),
+    (
+        "Location_Position",
+        "longitude",
+        "longitude",
+        "decimal",
+        false,
+    ),
+    (
+        "Location_Position",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MarketingStatus",
+        "country",
+        "country",
+        "CodeableConcept",
+        false,
+    ),
+    ("MarketingStatus", "dateRange", "dateRange", "Period", false),
+    (
+        "MarketingStatus",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MarketingStatus", "id", "id", "string", false),
+    (
+        "MarketingStatus",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MarketingStatus",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MarketingStatus",
+        "restoreDate",
+        "restoreDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "MarketingStatus",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    ("Measure", "approvalDate", "approvalDate", "date", false),
+    ("Measure", "author", "author", "ContactDetail", true),
+    (
+        "Measure",
+        "clinicalRecommendationStatement",
+        "clinicalRecommendationStatement",
+        "markdown",
+        false,
+    ),
+    (
+        "Measure",
+        "compositeScoring",
+        "compositeScoring",
+        "CodeableConcept",
+        false,
+    ),
+    ("Measure", "contact", "contact", "ContactDetail", true),
+    ("Measure", "contained", "contained", "ResourceList", true),
+    ("Measure", "copyright", "copyright", "markdown", false),
+    ("Measure", "date", "date", "dateTime", false),
+    ("Measure", "definition", "definition", "markdown", true),
+    ("Measure", "description", "description", "markdown", false),
+    ("Measure", "disclaimer", "disclaimer", "markdown", false),
+    ("Measure", "editor", "editor", "ContactDetail", true),
+    (
+        "Measure",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    ("Measure", "endorser", "endorser", "ContactDetail", true),
+    ("Measure", "experimental", "experimental", "boolean", false),
+    ("Measure", "extension", "extension", "Extension", true),
+    ("Measure", "group", "group", "Measure_Group", true),
+    ("Measure", "guidance", "guidance", "markdown", false),
+    ("Measure", "id", "id", "id", false),
+    ("Measure", "identifier", "identifier", "Identifier", true),
+    ("Measure", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Measure",
+        "improvementNotation",
+        "improvementNotation",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Measure",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("Measure", "language", "language", "code", false),
+    ("Measure", "lastReviewDate", "lastReviewDate", "date", false),
+    ("Measure", "library", "library", "canonical", true),
+    ("Measure", "meta", "meta", "Meta", false),
+    (
+        "Measure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Measure", "name", "name", "string", false),
+    ("Measure", "publisher", "publisher", "string", false),
+    ("Measure", "purpose", "purpose", "markdown", false),
+    (
+        "Measure",
+        "rateAggregation",
+        "rateAggregation",
+        "string",
+        false,
+    ),
+    ("Measure", "rationale", "rationale", "markdown", false),
+    (
+        "Measure",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    ("Measure", "reviewer", "reviewer", "ContactDetail", true),
+    (
+        "Measure",
+        "riskAdjustment",
+        "riskAdjustment",
+        "string",
+        false,
+    ),
+    ("Measure", "scoring", "scoring", "CodeableConcept", false),
+    ("Measure", "status", "status", "code", false),
+    (
+        "Measure",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    ("Measure", "subjectReference", "subject", "Reference", false),
+    ("Measure", "subtitle", "subtitle", "string", false),
+    (
+        "Measure",
+        "supplementalData",
+        "supplementalData",
+        "Measure_SupplementalData",
+        true,
+    ),
+    ("Measure", "text", "text", "Narrative", false),
+    ("Measure", "title", "title", "string", false),
+    ("Measure", "topic", "topic", "CodeableConcept", true),
+    ("Measure", "type", "type", "CodeableConcept", true),
+    ("Measure", "url", "url", "uri", false),
+    ("Measure", "usage", "usage", "string", false),
+    ("Measure", "useContext", "useContext", "UsageContext", true),
+    ("Measure", "version", "version", "string", false),
+    (
+        "MeasureReport",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("MeasureReport", "date", "date", "dateTime", false),
+    (
+        "MeasureReport",
+        "evaluatedResource",
+        "evaluatedResource",
+        "Reference",
+        true,
+    ),
+    ("MeasureReport", "extension", "extension", "Extension", true),
+    (
+        "MeasureReport",
+        "group",
+        "group",
+        "MeasureReport_Group",
+        true,
+    ),
+    ("MeasureReport", "id", "id", "id", false),
+    (
+        "MeasureReport",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MeasureReport",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MeasureReport",
+        "improvementNotation",
+        "improvementNotation",
+        "CodeableConcept",
+        false,
+    ),
+    ("MeasureReport", "language", "language", "code", false),
+    ("MeasureReport", "measure", "measure", "canonical", false),
+    ("MeasureReport", "meta", "meta", "Meta", false),
+    (
+        "MeasureReport",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport", "period", "period", "Period", false),
+    ("MeasureReport", "reporter", "reporter", "Reference", false),
+    ("MeasureReport", "status", "status", "code", false),
+    ("MeasureReport", "subject", "subject", "Reference", false),
+    ("MeasureReport", "text", "text", "Narrative", false),
+    ("MeasureReport", "type", "type", "code", false),
+    (
+        "MeasureReport_Component",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MeasureReport_Component",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport_Component", "id", "id", "string", false),
+    (
+        "MeasureReport_Component",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MeasureReport_Component",
+        "value",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MeasureReport_Group",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MeasureReport_Group",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport_Group", "id", "id", "string", false),
+    (
+        "MeasureReport_Group",
+        "measureScore",
+        "measureScore",
+        "Quantity",
+        false,
+    ),
+    (
+        "MeasureReport_Group",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MeasureReport_Group",
+        "population",
+        "population",
+        "MeasureReport_Population",
+        true,
+    ),
+    (
+        "MeasureReport_Group",
+        "stratifier",
+        "stratifier",
+        "MeasureReport_Stratifier",
+        true,
+    ),
+    (
+        "MeasureReport_Population",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MeasureReport_Population",
+        "count",
+        "count",
+        "integer",
+        false,
+    ),
+    (
+        "MeasureReport_Population",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport_Population", "id", "id", "string", false),
+    (
+        "MeasureReport_Population",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MeasureReport_Population",
+        "subjectResults",
+        "subjectResults",
+        "Reference",
+        false,
+    ),
+    (
+        "MeasureReport_Population1",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MeasureReport_Population1",
+        "count",
+        "count",
+        "integer",
+        false,
+    ),
+    (
+        "MeasureReport_Population1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport_Population1", "id", "id", "string", false),
+    (
+        "MeasureReport_Population1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MeasureReport_Population1",
+        "subjectResults",
+        "subjectResults",
+        "Reference",
+        false,
+    ),
+    (
+        "MeasureReport_Stratifier",
+        "code",
+        "code",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MeasureReport_Stratifier",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport_Stratifier", "id", "id", "string", false),
+    (
+        "MeasureReport_Stratifier",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MeasureReport_Stratifier",
+        "stratum",
+        "stratum",
+        "MeasureReport_Stratum",
+        true,
+    ),
+    (
+        "MeasureReport_Stratum",
+        "component",
+        "component",
+        "MeasureReport_Component",
+        true,
+    ),
+    (
+        "MeasureReport_Stratum",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MeasureReport_Stratum", "id", "id", "string", false),
+    (
+        "MeasureReport_Stratum",
+        "measureScore",
+        "measureScore",
+        "Quantity",
+        false,
+    ),
+    (
+        "MeasureReport_Stratum",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MeasureReport_Stratum",
+        "population",
+        "population",
+        "MeasureReport_Population1",
+        true,
+    ),
+    (
+        "MeasureReport_Stratum",
+        "value",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Measure_Component",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Measure_Component",
+        "criteria",
+        "criteria",
+        "Expression",
+        false,
+    ),
+    (
+        "Measure_Component",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "Measure_Component",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Measure_Component", "id", "id", "string", false),
+    (
+        "Measure_Component",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Measure_Group", "code", "code", "CodeableConcept", false),
+    (
+        "Measure_Group",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    ("Measure_Group", "extension", "extension", "Extension", true),
+    ("Measure_Group", "id", "id", "string", false),
+    (
+        "Measure_Group",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Measure_Group",
+        "population",
+        "population",
+        "Measure_Population",
+        true,
+    ),
+    (
+        "Measure_Group",
+        "stratifier",
+        "stratifier",
+        "Measure_Stratifier",
+        true,
+    ),
+    (
+        "Measure_Population",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Measure_Population",
+        "criteria",
+        "criteria",
+        "Expression",
+        false,
+    ),
+    (
+        "Measure_Population",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "Measure_Population",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Measure_Population", "id", "id", "string", false),
+    (
+        "Measure_Population",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Measure_Stratifier",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Measure_Stratifier",
+        "component",
+        "component",
+        "Measure_Component",
+        true,
+    ),
+    (
+        "Measure_Stratifier",
+        "criteria",
+        "criteria",
+        "Expression",
+        false,
+    ),
+    (
+        "Measure_Stratifier",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "Measure_Stratifier",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Measure_Stratifier", "id", "id", "string", false),
+    (
+        "Measure_Stratifier",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Measure_SupplementalData",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Measure_SupplementalData",
+        "criteria",
+        "criteria",
+        "Expression",
+        false,
+    ),
+    (
+        "Measure_SupplementalData",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "Measure_SupplementalData",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Measure_SupplementalData", "id", "id", "string", false),
+    (
+        "Measure_SupplementalData",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Measure_SupplementalData",
+        "usage",
+        "usage",
+        "CodeableConcept",
+        true,
+    ),
+    ("Media", "basedOn", "basedOn", "Reference", true),
+    ("Media", "bodySite", "bodySite", "CodeableConcept", false),
+    ("Media", "contained", "contained", "ResourceList", true),
+    ("Media", "content", "content", "Attachment", false),
+    ("Media", "createdDateTime", "created", "dateTime", false),
+    ("Media", "createdPeriod", "created", "Period", false),
+    ("Media", "device", "device", "Reference", false),
+    ("Media", "deviceName", "deviceName", "string", false),
+    ("Media", "duration", "duration", "decimal", false),
+    ("Media", "encounter", "encounter", "Reference", false),
+    ("Media", "extension", "extension", "Extension", true),
+    ("Media", "frames", "frames", "positiveInt", false),
+    ("Media", "height", "height", "positiveInt", false),
+    ("Media", "id", "id", "id", false),
+    ("Media", "identifier", "identifier", "Identifier", true),
+    ("Media", "implicitRules", "implicitRules", "uri", false),
+    ("Media", "issued", "issued", "instant", false),
+    ("Media", "language", "language", "code", false),
+    ("Media", "meta", "meta", "Meta", false),
+    ("Media", "modality", "modality", "CodeableConcept", false),
+    (
+        "Media",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Media", "note", "note", "Annotation", true),
+    ("Media", "operator", "operator", "Reference", false),
+    ("Media", "partOf", "partOf", "Reference", true),
+    ("Media", "reasonCode", "reasonCode", "CodeableConcept", true),
+    ("Media", "status", "status", "code", false),
+    ("Media", "subject", "subject", "Reference", false),
+    ("Media", "text", "text", "Narrative", false),
+    ("Media", "type", "type", "CodeableConcept", false),
+    ("Media", "view", "view", "CodeableConcept", false),
+    ("Media", "width", "width", "positiveInt", false),
+    ("Medication", "amount", "amount", "Ratio", false),
+    ("Medication", "batch", "batch", "Medication_Batch", false),
+    ("Medication", "code", "code", "CodeableConcept", false),
+    ("Medication", "contained", "contained", "ResourceList", true),
+    ("Medication", "extension", "extension", "Extension", true),
+    ("Medication", "form", "form", "CodeableConcept", false),
+    ("Medication", "id", "id", "id", false),
+    ("Medication", "identifier", "identifier", "Identifier", true),
+    ("Medication", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Medication",
+        "ingredient",
+        "ingredient",
+        "Medication_Ingredient",
+        true,
+    ),
+    ("Medication", "language", "language", "code", false),
+    (
+        "Medication",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        false,
+    ),
+    ("Medication", "meta", "meta", "Meta", false),
+    (
+        "Medication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Medication", "status", "status", "code", false),
+    ("Medication", "text", "text", "Narrative", false),
+    (
+        "MedicationAdministration",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "context",
+        "context",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "device",
+        "device",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "dosage",
+        "dosage",
+        "MedicationAdministration_Dosage",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "effectiveDateTime",
+        "effective",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "effectivePeriod",
+        "effective",
+        "Period",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "eventHistory",
+        "eventHistory",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationAdministration", "id", "id", "id", false),
+    (
+        "MedicationAdministration",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "instantiates",
+        "instantiates",
+        "uri",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "medicationCodeableConcept",
+        "medication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "medicationReference",
+        "medication",
+        "Reference",
+        false,
+    ),
+    ("MedicationAdministration", "meta", "meta", "Meta", false),
+    (
+        "MedicationAdministration",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "partOf",
+        "partOf",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "performer",
+        "performer",
+        "MedicationAdministration_Performer",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "request",
+        "request",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "status",
+        "status",
+        "code",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationAdministration",
+        "supportingInformation",
+        "supportingInformation",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationAdministration",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "dose",
+        "dose",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "rateQuantity",
+        "rate",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "rateRatio",
+        "rate",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "route",
+        "route",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "site",
+        "site",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationAdministration_Dosage",
+        "text",
+        "text",
+        "string",
+        false,
+    ),
+    (
+        "MedicationAdministration_Performer",
+        "actor",
+        "actor",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationAdministration_Performer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationAdministration_Performer",
+        "function",
+        "function",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationAdministration_Performer",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationAdministration_Performer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "authorizingPrescription",
+        "authorizingPrescription",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "context",
+        "context",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "daysSupply",
+        "daysSupply",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "destination",
+        "destination",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "detectedIssue",
+        "detectedIssue",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "dosageInstruction",
+        "dosageInstruction",
+        "Dosage",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "eventHistory",
+        "eventHistory",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationDispense", "id", "id", "id", false),
+    (
+        "MedicationDispense",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("MedicationDispense", "language", "language", "code", false),
+    (
+        "MedicationDispense",
+        "location",
+        "location",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "medicationCodeableConcept",
+        "medication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "medicationReference",
+        "medication",
+        "Reference",
+        false,
+    ),
+    ("MedicationDispense", "meta", "meta", "Meta", false),
+    (
+        "MedicationDispense",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MedicationDispense", "note", "note", "Annotation", true),
+    ("MedicationDispense", "partOf", "partOf", "Reference", true),
+    (
+        "MedicationDispense",
+        "performer",
+        "performer",
+        "MedicationDispense_Performer",
+        true,
+    ),
+    (
+        "MedicationDispense",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "receiver",
+        "receiver",
+        "Reference",
+        true,
+    ),
+    ("MedicationDispense", "status", "status", "code", false),
+    (
+        "MedicationDispense",
+        "statusReasonCodeableConcept",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "statusReasonReference",
+        "statusReason",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "substitution",
+        "substitution",
+        "MedicationDispense_Substitution",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "supportingInformation",
+        "supportingInformation",
+        "Reference",
+        true,
+    ),
+    ("MedicationDispense", "text", "text", "Narrative", false),
+    (
+        "MedicationDispense",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "whenHandedOver",
+        "whenHandedOver",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicationDispense",
+        "whenPrepared",
+        "whenPrepared",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicationDispense_Performer",
+        "actor",
+        "actor",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationDispense_Performer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationDispense_Performer",
+        "function",
+        "function",
+        "CodeableConcept",
+        false,
+    ),
+    ("MedicationDispense_Performer", "id", "id", "string", false),
+    (
+        "MedicationDispense_Performer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "responsibleParty",
+        "responsibleParty",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationDispense_Substitution",
+        "wasSubstituted",
+        "wasSubstituted",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "administrationGuidelines",
+        "administrationGuidelines",
+        "MedicationKnowledge_AdministrationGuidelines",
+        true,
+    ),
+    ("MedicationKnowledge", "amount", "amount", "Quantity", false),
+    (
+        "MedicationKnowledge",
+        "associatedMedication",
+        "associatedMedication",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "contraindication",
+        "contraindication",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "cost",
+        "cost",
+        "MedicationKnowledge_Cost",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "doseForm",
+        "doseForm",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "drugCharacteristic",
+        "drugCharacteristic",
+        "MedicationKnowledge_DrugCharacteristic",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationKnowledge", "id", "id", "id", false),
+    (
+        "MedicationKnowledge",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "ingredient",
+        "ingredient",
+        "MedicationKnowledge_Ingredient",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "intendedRoute",
+        "intendedRoute",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "kinetics",
+        "kinetics",
+        "MedicationKnowledge_Kinetics",
+        true,
+    ),
+    ("MedicationKnowledge", "language", "language", "code", false),
+    (
+        "MedicationKnowledge",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "medicineClassification",
+        "medicineClassification",
+        "MedicationKnowledge_MedicineClassification",
+        true,
+    ),
+    ("MedicationKnowledge", "meta", "meta", "Meta", false),
+    (
+        "MedicationKnowledge",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "monitoringProgram",
+        "monitoringProgram",
+        "MedicationKnowledge_MonitoringProgram",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "monograph",
+        "monograph",
+        "MedicationKnowledge_Monograph",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "packaging",
+        "packaging",
+        "MedicationKnowledge_Packaging",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "preparationInstruction",
+        "preparationInstruction",
+        "markdown",
+        false,
+    ),
+    (
+        "MedicationKnowledge",
+        "productType",
+        "productType",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "regulatory",
+        "regulatory",
+        "MedicationKnowledge_Regulatory",
+        true,
+    ),
+    (
+        "MedicationKnowledge",
+        "relatedMedicationKnowledge",
+        "relatedMedicationKnowledge",
+        "MedicationKnowledge_RelatedMedicationKnowledge",
+        true,
+    ),
+    ("MedicationKnowledge", "status", "status", "code", false),
+    ("MedicationKnowledge", "synonym", "synonym", "string", true),
+    ("MedicationKnowledge", "text", "text", "Narrative", false),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "dosage",
+        "dosage",
+        "MedicationKnowledge_Dosage",
+        true,
+    ),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "indicationCodeableConcept",
+        "indication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "indicationReference",
+        "indication",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_AdministrationGuidelines",
+        "patientCharacteristics",
+        "patientCharacteristics",
+        "MedicationKnowledge_PatientCharacteristics",
+        true,
+    ),
+    ("MedicationKnowledge_Cost", "cost", "cost", "Money", false),
+    (
+        "MedicationKnowledge_Cost",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationKnowledge_Cost", "id", "id", "string", false),
+    (
+        "MedicationKnowledge_Cost",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Cost",
+        "source",
+        "source",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Cost",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Dosage",
+        "dosage",
+        "dosage",
+        "Dosage",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Dosage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationKnowledge_Dosage", "id", "id", "string", false),
+    (
+        "MedicationKnowledge_Dosage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Dosage",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationKnowledge_DrugCharacteristic",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "isActive",
+        "isActive",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "itemCodeableConcept",
+        "item",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "itemReference",
+        "item",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Ingredient",
+        "strength",
+        "strength",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Kinetics",
+        "areaUnderCurve",
+        "areaUnderCurve",
+        "Quantity",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Kinetics",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Kinetics",
+        "halfLifePeriod",
+        "halfLifePeriod",
+        "Duration",
+        false,
+    ),
+    ("MedicationKnowledge_Kinetics", "id", "id", "string", false),
+    (
+        "MedicationKnowledge_Kinetics",
+        "lethalDose50",
+        "lethalDose50",
+        "Quantity",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Kinetics",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MaxDispense",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MaxDispense",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MaxDispense",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MaxDispense",
+        "period",
+        "period",
+        "Duration",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MaxDispense",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MedicineClassification",
+        "classification",
+        "classification",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MedicineClassification",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MedicineClassification",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MedicineClassification",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MedicineClassification",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MonitoringProgram",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MonitoringProgram",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MonitoringProgram",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_MonitoringProgram",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_MonitoringProgram",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Monograph",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationKnowledge_Monograph", "id", "id", "string", false),
+    (
+        "MedicationKnowledge_Monograph",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Monograph",
+        "source",
+        "source",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Monograph",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Packaging",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationKnowledge_Packaging", "id", "id", "string", false),
+    (
+        "MedicationKnowledge_Packaging",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Packaging",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Packaging",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_PatientCharacteristics",
+        "characteristicCodeableConcept",
+        "characteristic",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_PatientCharacteristics",
+        "characteristicQuantity",
+        "characteristic",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationKnowledge_PatientCharacteristics",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_PatientCharacteristics",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_PatientCharacteristics",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_PatientCharacteristics",
+        "value",
+        "value",
+        "string",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "maxDispense",
+        "maxDispense",
+        "MedicationKnowledge_MaxDispense",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "regulatoryAuthority",
+        "regulatoryAuthority",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "schedule",
+        "schedule",
+        "MedicationKnowledge_Schedule",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Regulatory",
+        "substitution",
+        "substitution",
+        "MedicationKnowledge_Substitution",
+        true,
+    ),
+    (
+        "MedicationKnowledge_RelatedMedicationKnowledge",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_RelatedMedicationKnowledge",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_RelatedMedicationKnowledge",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_RelatedMedicationKnowledge",
+        "reference",
+        "reference",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationKnowledge_RelatedMedicationKnowledge",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Schedule",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationKnowledge_Schedule", "id", "id", "string", false),
+    (
+        "MedicationKnowledge_Schedule",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Schedule",
+        "schedule",
+        "schedule",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Substitution",
+        "allowed",
+        "allowed",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Substitution",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Substitution",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationKnowledge_Substitution",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationKnowledge_Substitution",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "authoredOn",
+        "authoredOn",
+        "dateTime",
+        false,
+    ),
+    ("MedicationRequest", "basedOn", "basedOn", "Reference", true),
+    (
+        "MedicationRequest",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "courseOfTherapyType",
+        "courseOfTherapyType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "detectedIssue",
+        "detectedIssue",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "dispenseRequest",
+        "dispenseRequest",
+        "MedicationRequest_DispenseRequest",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "doNotPerform",
+        "doNotPerform",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "dosageInstruction",
+        "dosageInstruction",
+        "Dosage",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "eventHistory",
+        "eventHistory",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "groupIdentifier",
+        "groupIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("MedicationRequest", "id", "id", "id", false),
+    (
+        "MedicationRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "insurance",
+        "insurance",
+        "Reference",
+        true,
+    ),
+    ("MedicationRequest", "intent", "intent", "code", false),
+    ("MedicationRequest", "language", "language", "code", false),
+    (
+        "MedicationRequest",
+        "medicationCodeableConcept",
+        "medication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "medicationReference",
+        "medication",
+        "Reference",
+        false,
+    ),
+    ("MedicationRequest", "meta", "meta", "Meta", false),
+    (
+        "MedicationRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MedicationRequest", "note", "note", "Annotation", true),
+    (
+        "MedicationRequest",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "performerType",
+        "performerType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "priorPrescription",
+        "priorPrescription",
+        "Reference",
+        false,
+    ),
+    ("MedicationRequest", "priority", "priority", "code", false),
+    (
+        "MedicationRequest",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationRequest",
+        "recorder",
+        "recorder",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "reportedBoolean",
+        "reported",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "reportedReference",
+        "reported",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "requester",
+        "requester",
+        "Reference",
+        false,
+    ),
+    ("MedicationRequest", "status", "status", "code", false),
+    (
+        "MedicationRequest",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "substitution",
+        "substitution",
+        "MedicationRequest_Substitution",
+        false,
+    ),
+    (
+        "MedicationRequest",
+        "supportingInformation",
+        "supportingInformation",
+        "Reference",
+        true,
+    ),
+    ("MedicationRequest", "text", "text", "Narrative", false),
+    (
+        "MedicationRequest_DispenseRequest",
+        "dispenseInterval",
+        "dispenseInterval",
+        "Duration",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "expectedSupplyDuration",
+        "expectedSupplyDuration",
+        "Duration",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "initialFill",
+        "initialFill",
+        "MedicationRequest_InitialFill",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "numberOfRepeatsAllowed",
+        "numberOfRepeatsAllowed",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationRequest_DispenseRequest",
+        "validityPeriod",
+        "validityPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "MedicationRequest_InitialFill",
+        "duration",
+        "duration",
+        "Duration",
+        false,
+    ),
+    (
+        "MedicationRequest_InitialFill",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationRequest_InitialFill", "id", "id", "string", false),
+    (
+        "MedicationRequest_InitialFill",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationRequest_InitialFill",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicationRequest_Substitution",
+        "allowedBoolean",
+        "allowed",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicationRequest_Substitution",
+        "allowedCodeableConcept",
+        "allowed",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationRequest_Substitution",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationRequest_Substitution",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicationRequest_Substitution",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicationRequest_Substitution",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "basedOn",
+        "basedOn",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicationStatement",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicationStatement",
+        "context",
+        "context",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "dateAsserted",
+        "dateAsserted",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "derivedFrom",
+        "derivedFrom",
+        "Reference",
+        true,
+    ),
+    ("MedicationStatement", "dosage", "dosage", "Dosage", true),
+    (
+        "MedicationStatement",
+        "effectiveDateTime",
+        "effective",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "effectivePeriod",
+        "effective",
+        "Period",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicationStatement", "id", "id", "id", false),
+    (
+        "MedicationStatement",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicationStatement",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "informationSource",
+        "informationSource",
+        "Reference",
+        false,
+    ),
+    ("MedicationStatement", "language", "language", "code", false),
+    (
+        "MedicationStatement",
+        "medicationCodeableConcept",
+        "medication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicationStatement",
+        "medicationReference",
+        "medication",
+        "Reference",
+        false,
+    ),
+    ("MedicationStatement", "meta", "meta", "Meta", false),
+    (
+        "MedicationStatement",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MedicationStatement", "note", "note", "Annotation", true),
+    ("MedicationStatement", "partOf", "partOf", "Reference", true),
+    (
+        "MedicationStatement",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationStatement",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("MedicationStatement", "status", "status", "code", false),
+    (
+        "MedicationStatement",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicationStatement",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("MedicationStatement", "text", "text", "Narrative", false),
+    (
+        "Medication_Batch",
+        "expirationDate",
+        "expirationDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "Medication_Batch",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Medication_Batch", "id", "id", "string", false),
+    (
+        "Medication_Batch",
+        "lotNumber",
+        "lotNumber",
+        "string",
+        false,
+    ),
+    (
+        "Medication_Batch",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Medication_Ingredient",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Medication_Ingredient", "id", "id", "string", false),
+    (
+        "Medication_Ingredient",
+        "isActive",
+        "isActive",
+        "boolean",
+        false,
+    ),
+    (
+        "Medication_Ingredient",
+        "itemCodeableConcept",
+        "item",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Medication_Ingredient",
+        "itemReference",
+        "item",
+        "Reference",
+        false,
+    ),
+    (
+        "Medication_Ingredient",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Medication_Ingredient",
+        "strength",
+        "strength",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProduct",
+        "additionalMonitoringIndicator",
+        "additionalMonitoringIndicator",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct",
+        "attachedDocument",
+        "attachedDocument",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "clinicalTrial",
+        "clinicalTrial",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "combinedPharmaceuticalDoseForm",
+        "combinedPharmaceuticalDoseForm",
+        "CodeableConcept",
+        false,
+    ),
+    ("MedicinalProduct", "contact", "contact", "Reference", true),
+    (
+        "MedicinalProduct",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "crossReference",
+        "crossReference",
+        "Identifier",
+        true,
+    ),
+    ("MedicinalProduct", "domain", "domain", "Coding", false),
+    (
+        "MedicinalProduct",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProduct", "id", "id", "id", false),
+    (
+        "MedicinalProduct",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("MedicinalProduct", "language", "language", "code", false),
+    (
+        "MedicinalProduct",
+        "legalStatusOfSupply",
+        "legalStatusOfSupply",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct",
+        "manufacturingBusinessOperation",
+        "manufacturingBusinessOperation",
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "marketingStatus",
+        "marketingStatus",
+        "MarketingStatus",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "masterFile",
+        "masterFile",
+        "Reference",
+        true,
+    ),
+    ("MedicinalProduct", "meta", "meta", "Meta", false),
+    (
+        "MedicinalProduct",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "name",
+        "name",
+        "MedicinalProduct_Name",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "packagedMedicinalProduct",
+        "packagedMedicinalProduct",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "paediatricUseIndicator",
+        "paediatricUseIndicator",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct",
+        "pharmaceuticalProduct",
+        "pharmaceuticalProduct",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "productClassification",
+        "productClassification",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "specialDesignation",
+        "specialDesignation",
+        "MedicinalProduct_SpecialDesignation",
+        true,
+    ),
+    (
+        "MedicinalProduct",
+        "specialMeasures",
+        "specialMeasures",
+        "string",
+        true,
+    ),
+    ("MedicinalProduct", "text", "text", "Narrative", false),
+    ("MedicinalProduct", "type", "type", "CodeableConcept", false),
+    (
+        "MedicinalProductAuthorization",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "country",
+        "country",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "dataExclusivityPeriod",
+        "dataExclusivityPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "dateOfFirstAuthorization",
+        "dateOfFirstAuthorization",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "holder",
+        "holder",
+        "Reference",
+        false,
+    ),
+    ("MedicinalProductAuthorization", "id", "id", "id", false),
+    (
+        "MedicinalProductAuthorization",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "internationalBirthDate",
+        "internationalBirthDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "jurisdictionalAuthorization",
+        "jurisdictionalAuthorization",
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "legalBasis",
+        "legalBasis",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "meta",
+        "meta",
+        "Meta",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "procedure",
+        "procedure",
+        "MedicinalProductAuthorization_Procedure",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "regulator",
+        "regulator",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "restoreDate",
+        "restoreDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "statusDate",
+        "statusDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization",
+        "validityPeriod",
+        "validityPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "country",
+        "country",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "legalStatusOfSupply",
+        "legalStatusOfSupply",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_JurisdictionalAuthorization",
+        "validityPeriod",
+        "validityPeriod",
+        "Period",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "application",
+        "application",
+        "MedicinalProductAuthorization_Procedure",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "dateDateTime",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "datePeriod",
+        "date",
+        "Period",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductAuthorization_Procedure",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "comorbidity",
+        "comorbidity",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "disease",
+        "disease",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "diseaseStatus",
+        "diseaseStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductContraindication", "id", "id", "id", false),
+    (
+        "MedicinalProductContraindication",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "meta",
+        "meta",
+        "Meta",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "otherTherapy",
+        "otherTherapy",
+        "MedicinalProductContraindication_OtherTherapy",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "population",
+        "population",
+        "Population",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "subject",
+        "subject",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication",
+        "therapeuticIndication",
+        "therapeuticIndication",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication_OtherTherapy",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication_OtherTherapy",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication_OtherTherapy",
+        "medicationCodeableConcept",
+        "medication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication_OtherTherapy",
+        "medicationReference",
+        "medication",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProductContraindication_OtherTherapy",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductContraindication_OtherTherapy",
+        "therapyRelationshipType",
+        "therapyRelationshipType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "comorbidity",
+        "comorbidity",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductIndication",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductIndication",
+        "diseaseStatus",
+        "diseaseStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "diseaseSymptomProcedure",
+        "diseaseSymptomProcedure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "duration",
+        "duration",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductIndication", "id", "id", "id", false),
+    (
+        "MedicinalProductIndication",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "intendedEffect",
+        "intendedEffect",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("MedicinalProductIndication", "meta", "meta", "Meta", false),
+    (
+        "MedicinalProductIndication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIndication",
+        "otherTherapy",
+        "otherTherapy",
+        "MedicinalProductIndication_OtherTherapy",
+        true,
+    ),
+    (
+        "MedicinalProductIndication",
+        "population",
+        "population",
+        "Population",
+        true,
+    ),
+    (
+        "MedicinalProductIndication",
+        "subject",
+        "subject",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductIndication",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductIndication",
+        "undesirableEffect",
+        "undesirableEffect",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductIndication_OtherTherapy",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIndication_OtherTherapy",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIndication_OtherTherapy",
+        "medicationCodeableConcept",
+        "medication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIndication_OtherTherapy",
+        "medicationReference",
+        "medication",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProductIndication_OtherTherapy",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIndication_OtherTherapy",
+        "therapyRelationshipType",
+        "therapyRelationshipType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "allergenicIndicator",
+        "allergenicIndicator",
+        "boolean",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductIngredient", "id", "id", "id", false),
+    (
+        "MedicinalProductIngredient",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        true,
+    ),
+    ("MedicinalProductIngredient", "meta", "meta", "Meta", false),
+    (
+        "MedicinalProductIngredient",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "specifiedSubstance",
+        "specifiedSubstance",
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "substance",
+        "substance",
+        "MedicinalProductIngredient_Substance",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "country",
+        "country",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "measurementPoint",
+        "measurementPoint",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "strength",
+        "strength",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "strengthLowLimit",
+        "strengthLowLimit",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_ReferenceStrength",
+        "substance",
+        "substance",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "confidentiality",
+        "confidentiality",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "group",
+        "group",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_SpecifiedSubstance",
+        "strength",
+        "strength",
+        "MedicinalProductIngredient_Strength",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "concentration",
+        "concentration",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "concentrationLowLimit",
+        "concentrationLowLimit",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "country",
+        "country",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "measurementPoint",
+        "measurementPoint",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "presentation",
+        "presentation",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "presentationLowLimit",
+        "presentationLowLimit",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Strength",
+        "referenceStrength",
+        "referenceStrength",
+        "MedicinalProductIngredient_ReferenceStrength",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Substance",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Substance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Substance",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductIngredient_Substance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductIngredient_Substance",
+        "strength",
+        "strength",
+        "MedicinalProductIngredient_Strength",
+        true,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "effect",
+        "effect",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductInteraction", "id", "id", "id", false),
+    (
+        "MedicinalProductInteraction",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "incidence",
+        "incidence",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "interactant",
+        "interactant",
+        "MedicinalProductInteraction_Interactant",
+        true,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "management",
+        "management",
+        "CodeableConcept",
+        false,
+    ),
+    ("MedicinalProductInteraction", "meta", "meta", "Meta", false),
+    (
+        "MedicinalProductInteraction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "subject",
+        "subject",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction_Interactant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductInteraction_Interactant",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction_Interactant",
+        "itemCodeableConcept",
+        "item",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction_Interactant",
+        "itemReference",
+        "item",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProductInteraction_Interactant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductManufactured", "id", "id", "id", false),
+    (
+        "MedicinalProductManufactured",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "ingredient",
+        "ingredient",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "manufacturedDoseForm",
+        "manufacturedDoseForm",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "meta",
+        "meta",
+        "Meta",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "otherCharacteristics",
+        "otherCharacteristics",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "physicalCharacteristics",
+        "physicalCharacteristics",
+        "ProdCharacteristic",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductManufactured",
+        "unitOfPresentation",
+        "unitOfPresentation",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "batchIdentifier",
+        "batchIdentifier",
+        "MedicinalProductPackaged_BatchIdentifier",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductPackaged", "id", "id", "id", false),
+    (
+        "MedicinalProductPackaged",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "legalStatusOfSupply",
+        "legalStatusOfSupply",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "marketingAuthorization",
+        "marketingAuthorization",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "marketingStatus",
+        "marketingStatus",
+        "MarketingStatus",
+        true,
+    ),
+    ("MedicinalProductPackaged", "meta", "meta", "Meta", false),
+    (
+        "MedicinalProductPackaged",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "packageItem",
+        "packageItem",
+        "MedicinalProductPackaged_PackageItem",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "subject",
+        "subject",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_BatchIdentifier",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_BatchIdentifier",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_BatchIdentifier",
+        "immediatePackaging",
+        "immediatePackaging",
+        "Identifier",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_BatchIdentifier",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_BatchIdentifier",
+        "outerPackaging",
+        "outerPackaging",
+        "Identifier",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "alternateMaterial",
+        "alternateMaterial",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "device",
+        "device",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "manufacturedItem",
+        "manufacturedItem",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "material",
+        "material",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "otherCharacteristics",
+        "otherCharacteristics",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "packageItem",
+        "packageItem",
+        "MedicinalProductPackaged_PackageItem",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "physicalCharacteristics",
+        "physicalCharacteristics",
+        "ProdCharacteristic",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "shelfLifeStorage",
+        "shelfLifeStorage",
+        "ProductShelfLife",
+        true,
+    ),
+    (
+        "MedicinalProductPackaged_PackageItem",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "administrableDoseForm",
+        "administrableDoseForm",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "characteristics",
+        "characteristics",
+        "MedicinalProductPharmaceutical_Characteristics",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "device",
+        "device",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProductPharmaceutical", "id", "id", "id", false),
+    (
+        "MedicinalProductPharmaceutical",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "ingredient",
+        "ingredient",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "meta",
+        "meta",
+        "Meta",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "routeOfAdministration",
+        "routeOfAdministration",
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical",
+        "unitOfPresentation",
+        "unitOfPresentation",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_Characteristics",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_Characteristics",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_Characteristics",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_Characteristics",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_Characteristics",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "firstDose",
+        "firstDose",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "maxDosePerDay",
+        "maxDosePerDay",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "maxDosePerTreatmentPeriod",
+        "maxDosePerTreatmentPeriod",
+        "Ratio",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "maxSingleDose",
+        "maxSingleDose",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "maxTreatmentPeriod",
+        "maxTreatmentPeriod",
+        "Duration",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_RouteOfAdministration",
+        "targetSpecies",
+        "targetSpecies",
+        "MedicinalProductPharmaceutical_TargetSpecies",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_TargetSpecies",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_TargetSpecies",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_TargetSpecies",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_TargetSpecies",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_TargetSpecies",
+        "withdrawalPeriod",
+        "withdrawalPeriod",
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        "supportingInformation",
+        "supportingInformation",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        "tissue",
+        "tissue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductPharmaceutical_WithdrawalPeriod",
+        "value",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "classification",
+        "classification",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "frequencyOfOccurrence",
+        "frequencyOfOccurrence",
+        "CodeableConcept",
+        false,
+    ),
+    ("MedicinalProductUndesirableEffect", "id", "id", "id", false),
+    (
+        "MedicinalProductUndesirableEffect",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "meta",
+        "meta",
+        "Meta",
+        false,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "population",
+        "population",
+        "Population",
+        true,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "subject",
+        "subject",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "symptomConditionEffect",
+        "symptomConditionEffect",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProductUndesirableEffect",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "MedicinalProduct_CountryLanguage",
+        "country",
+        "country",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_CountryLanguage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_CountryLanguage",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProduct_CountryLanguage",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_CountryLanguage",
+        "language",
+        "language",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_CountryLanguage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "authorisationReferenceNumber",
+        "authorisationReferenceNumber",
+        "Identifier",
+        false,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "confidentialityIndicator",
+        "confidentialityIndicator",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "effectiveDate",
+        "effectiveDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "manufacturer",
+        "manufacturer",
+        "Reference",
+        true,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "operationType",
+        "operationType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_ManufacturingBusinessOperation",
+        "regulator",
+        "regulator",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProduct_Name",
+        "countryLanguage",
+        "countryLanguage",
+        "MedicinalProduct_CountryLanguage",
+        true,
+    ),
+    (
+        "MedicinalProduct_Name",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProduct_Name", "id", "id", "string", false),
+    (
+        "MedicinalProduct_Name",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_Name",
+        "namePart",
+        "namePart",
+        "MedicinalProduct_NamePart",
+        true,
+    ),
+    (
+        "MedicinalProduct_Name",
+        "productName",
+        "productName",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProduct_NamePart",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProduct_NamePart", "id", "id", "string", false),
+    (
+        "MedicinalProduct_NamePart",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MedicinalProduct_NamePart", "part", "part", "string", false),
+    ("MedicinalProduct_NamePart", "type", "type", "Coding", false),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "date",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "indicationCodeableConcept",
+        "indication",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "indicationReference",
+        "indication",
+        "Reference",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "intendedUse",
+        "intendedUse",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "species",
+        "species",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MedicinalProduct_SpecialDesignation",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MessageDefinition",
+        "allowedResponse",
+        "allowedResponse",
+        "MessageDefinition_AllowedResponse",
+        true,
+    ),
+    ("MessageDefinition", "base", "base", "canonical", false),
+    ("MessageDefinition", "category", "category", "code", false),
+    (
+        "MessageDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "MessageDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MessageDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("MessageDefinition", "date", "date", "dateTime", false),
+    (
+        "MessageDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    ("MessageDefinition", "eventCoding", "event", "Coding", false),
+    ("MessageDefinition", "eventUri", "event", "uri", false),
+    (
+        "MessageDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "MessageDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MessageDefinition",
+        "focus",
+        "focus",
+        "MessageDefinition_Focus",
+        true,
+    ),
+    ("MessageDefinition", "graph", "graph", "canonical", true),
+    ("MessageDefinition", "id", "id", "id", false),
+    (
+        "MessageDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MessageDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "MessageDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("MessageDefinition", "language", "language", "code", false),
+    ("MessageDefinition", "meta", "meta", "Meta", false),
+    (
+        "MessageDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MessageDefinition", "name", "name", "string", false),
+    ("MessageDefinition", "parent", "parent", "canonical", true),
+    (
+        "MessageDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    ("MessageDefinition", "purpose", "purpose", "markdown", false),
+    (
+        "MessageDefinition",
+        "replaces",
+        "replaces",
+        "canonical",
+        true,
+    ),
+    (
+        "MessageDefinition",
+        "responseRequired",
+        "responseRequired",
+        "code",
+        false,
+    ),
+    ("MessageDefinition", "status", "status", "code", false),
+    ("MessageDefinition", "text", "text", "Narrative", false),
+    ("MessageDefinition", "title", "title", "string", false),
+    ("MessageDefinition", "url", "url", "uri", false),
+    (
+        "MessageDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("MessageDefinition", "version", "version", "string", false),
+    (
+        "MessageDefinition_AllowedResponse",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MessageDefinition_AllowedResponse",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MessageDefinition_AllowedResponse",
+        "message",
+        "message",
+        "canonical",
+        false,
+    ),
+    (
+        "MessageDefinition_AllowedResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MessageDefinition_AllowedResponse",
+        "situation",
+        "situation",
+        "markdown",
+        false,
+    ),
+    ("MessageDefinition_Focus", "code", "code", "code", false),
+    (
+        "MessageDefinition_Focus",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MessageDefinition_Focus", "id", "id", "string", false),
+    ("MessageDefinition_Focus", "max", "max", "string", false),
+    (
+        "MessageDefinition_Focus",
+        "min",
+        "min",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "MessageDefinition_Focus",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MessageDefinition_Focus",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    ("MessageHeader", "author", "author", "Reference", false),
+    (
+        "MessageHeader",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MessageHeader",
+        "definition",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "MessageHeader",
+        "destination",
+        "destination",
+        "MessageHeader_Destination",
+        true,
+    ),
+    ("MessageHeader", "enterer", "enterer", "Reference", false),
+    ("MessageHeader", "eventCoding", "event", "Coding", false),
+    ("MessageHeader", "eventUri", "event", "uri", false),
+    ("MessageHeader", "extension", "extension", "Extension", true),
+    ("MessageHeader", "focus", "focus", "Reference", true),
+    ("MessageHeader", "id", "id", "id", false),
+    (
+        "MessageHeader",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("MessageHeader", "language", "language", "code", false),
+    ("MessageHeader", "meta", "meta", "Meta", false),
+    (
+        "MessageHeader",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MessageHeader",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MessageHeader",
+        "response",
+        "response",
+        "MessageHeader_Response",
+        false,
+    ),
+    (
+        "MessageHeader",
+        "responsible",
+        "responsible",
+        "Reference",
+        false,
+    ),
+    ("MessageHeader", "sender", "sender", "Reference", false),
+    (
+        "MessageHeader",
+        "source",
+        "source",
+        "MessageHeader_Source",
+        false,
+    ),
+    ("MessageHeader", "text", "text", "Narrative", false),
+    (
+        "MessageHeader_Destination",
+        "endpoint",
+        "endpoint",
+        "url",
+        false,
+    ),
+    (
+        "MessageHeader_Destination",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MessageHeader_Destination", "id", "id", "string", false),
+    (
+        "MessageHeader_Destination",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MessageHeader_Destination", "name", "name", "string", false),
+    (
+        "MessageHeader_Destination",
+        "receiver",
+        "receiver",
+        "Reference",
+        false,
+    ),
+    (
+        "MessageHeader_Destination",
+        "target",
+        "target",
+        "Reference",
+        false,
+    ),
+    ("MessageHeader_Response", "code", "code", "code", false),
+    (
+        "MessageHeader_Response",
+        "details",
+        "details",
+        "Reference",
+        false,
+    ),
+    (
+        "MessageHeader_Response",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MessageHeader_Response", "id", "id", "string", false),
+    (
+        "MessageHeader_Response",
+        "identifier",
+        "identifier",
+        "id",
+        false,
+    ),
+    (
+        "MessageHeader_Response",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MessageHeader_Source",
+        "contact",
+        "contact",
+        "ContactPoint",
+        false,
+    ),
+    ("MessageHeader_Source", "endpoint", "endpoint", "url", false),
+    (
+        "MessageHeader_Source",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MessageHeader_Source", "id", "id", "string", false),
+    (
+        "MessageHeader_Source",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MessageHeader_Source", "name", "name", "string", false),
+    (
+        "MessageHeader_Source",
+        "software",
+        "software",
+        "string",
+        false,
+    ),
+    (
+        "MessageHeader_Source",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    ("Meta", "extension", "extension", "Extension", true),
+    ("Meta", "id", "id", "string", false),
+    ("Meta", "lastUpdated", "lastUpdated", "instant", false),
+    ("Meta", "profile", "profile", "canonical", true),
+    ("Meta", "security", "security", "Coding", true),
+    ("Meta", "source", "source", "uri", false),
+    ("Meta", "tag", "tag", "Coding", true),
+    ("Meta", "versionId", "versionId", "id", false),
+    (
+        "MolecularSequence",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "MolecularSequence",
+        "coordinateSystem",
+        "coordinateSystem",
+        "integer",
+        false,
+    ),
+    ("MolecularSequence", "device", "device", "Reference", false),
+    (
+        "MolecularSequence",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MolecularSequence", "id", "id", "id", false),
+    (
+        "MolecularSequence",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "MolecularSequence",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("MolecularSequence", "language", "language", "code", false),
+    ("MolecularSequence", "meta", "meta", "Meta", false),
+    (
+        "MolecularSequence",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence",
+        "observedSeq",
+        "observedSeq",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "MolecularSequence",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    ("MolecularSequence", "pointer", "pointer", "Reference", true),
+    (
+        "MolecularSequence",
+        "quality",
+        "quality",
+        "MolecularSequence_Quality",
+        true,
+    ),
+    (
+        "MolecularSequence",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "MolecularSequence",
+        "readCoverage",
+        "readCoverage",
+        "integer",
+        false,
+    ),
+    (
+        "MolecularSequence",
+        "referenceSeq",
+        "referenceSeq",
+        "MolecularSequence_ReferenceSeq",
+        false,
+    ),
+    (
+        "MolecularSequence",
+        "repository",
+        "repository",
+        "MolecularSequence_Repository",
+        true,
+    ),
+    (
+        "MolecularSequence",
+        "specimen",
+        "specimen",
+        "Reference",
+        false,
+    ),
+    (
+        "MolecularSequence",
+        "structureVariant",
+        "structureVariant",
+        "MolecularSequence_StructureVariant",
+        true,
+    ),
+    ("MolecularSequence", "text", "text", "Narrative", false),
+    ("MolecularSequence", "type", "type", "code", false),
+    (
+        "MolecularSequence",
+        "variant",
+        "variant",
+        "MolecularSequence_Variant",
+        true,
+    ),
+    ("MolecularSequence_Inner", "end", "end", "integer", false),
+    (
+        "MolecularSequence_Inner",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MolecularSequence_Inner", "id", "id", "string", false),
+    (
+        "MolecularSequence_Inner",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Inner",
+        "start",
+        "start",
+        "integer",
+        false,
+    ),
+    ("MolecularSequence_Outer", "end", "end", "integer", false),
+    (
+        "MolecularSequence_Outer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MolecularSequence_Outer", "id", "id", "string", false),
+    (
+        "MolecularSequence_Outer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Outer",
+        "start",
+        "start",
+        "integer",
+        false,
+    ),
+    ("MolecularSequence_Quality", "end", "end", "integer", false),
+    (
+        "MolecularSequence_Quality",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "fScore",
+        "fScore",
+        "decimal",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "gtFP",
+        "gtFP",
+        "decimal",
+        false,
+    ),
+    ("MolecularSequence_Quality", "id", "id", "string", false),
+    (
+        "MolecularSequence_Quality",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "precision",
+        "precision",
+        "decimal",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "queryFP",
+        "queryFP",
+        "decimal",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "queryTP",
+        "queryTP",
+        "decimal",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "recall",
+        "recall",
+        "decimal",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "roc",
+        "roc",
+        "MolecularSequence_Roc",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "score",
+        "score",
+        "Quantity",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "standardSequence",
+        "standardSequence",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "start",
+        "start",
+        "integer",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "truthFN",
+        "truthFN",
+        "decimal",
+        false,
+    ),
+    (
+        "MolecularSequence_Quality",
+        "truthTP",
+        "truthTP",
+        "decimal",
+        false,
+    ),
+    ("MolecularSequence_Quality", "type", "type", "code", false),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "chromosome",
+        "chromosome",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "genomeBuild",
+        "genomeBuild",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "orientation",
+        "orientation",
+        "code",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "referenceSeqId",
+        "referenceSeqId",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "referenceSeqPointer",
+        "referenceSeqPointer",
+        "Reference",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "referenceSeqString",
+        "referenceSeqString",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "strand",
+        "strand",
+        "code",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "windowEnd",
+        "windowEnd",
+        "integer",
+        false,
+    ),
+    (
+        "MolecularSequence_ReferenceSeq",
+        "windowStart",
+        "windowStart",
+        "integer",
+        false,
+    ),
+    (
+        "MolecularSequence_Repository",
+        "datasetId",
+        "datasetId",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_Repository",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MolecularSequence_Repository", "id", "id", "string", false),
+    (
+        "MolecularSequence_Repository",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Repository",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_Repository",
+        "readsetId",
+        "readsetId",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_Repository",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    ("MolecularSequence_Repository", "url", "url", "uri", false),
+    (
+        "MolecularSequence_Repository",
+        "variantsetId",
+        "variantsetId",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_Roc",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Roc",
+        "fMeasure",
+        "fMeasure",
+        "decimal",
+        true,
+    ),
+    ("MolecularSequence_Roc", "id", "id", "string", false),
+    (
+        "MolecularSequence_Roc",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("MolecularSequence_Roc", "numFN", "numFN", "integer", true),
+    ("MolecularSequence_Roc", "numFP", "numFP", "integer", true),
+    ("MolecularSequence_Roc", "numTP", "numTP", "integer", true),
+    (
+        "MolecularSequence_Roc",
+        "precision",
+        "precision",
+        "decimal",
+        true,
+    ),
+    ("MolecularSequence_Roc", "score", "score", "integer", true),
+    (
+        "MolecularSequence_Roc",
+        "sensitivity",
+        "sensitivity",
+        "decimal",
+        true,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "exact",
+        "exact",
+        "boolean",
+        false,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "inner",
+        "inner",
+        "MolecularSequence_Inner",
+        false,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "length",
+        "length",
+        "integer",
+        false,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "outer",
+        "outer",
+        "MolecularSequence_Outer",
+        false,
+    ),
+    (
+        "MolecularSequence_StructureVariant",
+        "variantType",
+        "variantType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "MolecularSequence_Variant",
+        "cigar",
+        "cigar",
+        "string",
+        false,
+    ),
+    ("MolecularSequence_Variant", "end", "end", "integer", false),
+    (
+        "MolecularSequence_Variant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("MolecularSequence_Variant", "id", "id", "string", false),
+    (
+        "MolecularSequence_Variant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "MolecularSequence_Variant",
+        "observedAllele",
+        "observedAllele",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_Variant",
+        "referenceAllele",
+        "referenceAllele",
+        "string",
+        false,
+    ),
+    (
+        "MolecularSequence_Variant",
+        "start",
+        "start",
+        "integer",
+        false,
+    ),
+    (
+        "MolecularSequence_Variant",
+        "variantPointer",
+        "variantPointer",
+        "Reference",
+        false,
+    ),
+    ("Money", "currency", "currency", "code", false),
+    ("Money", "extension", "extension", "Extension", true),
+    ("Money", "id", "id", "string", false),
+    ("Money", "value", "value", "decimal", false),
+    ("NamingSystem", "contact", "contact", "ContactDetail", true),
+    (
+        "NamingSystem",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("NamingSystem", "date", "date", "dateTime", false),
+    (
+        "NamingSystem",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    ("NamingSystem", "extension", "extension", "Extension", true),
+    ("NamingSystem", "id", "id", "id", false),
+    (
+        "NamingSystem",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "NamingSystem",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("NamingSystem", "kind", "kind", "code", false),
+    ("NamingSystem", "language", "language", "code", false),
+    ("NamingSystem", "meta", "meta", "Meta", false),
+    (
+        "NamingSystem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("NamingSystem", "name", "name", "string", false),
+    ("NamingSystem", "publisher", "publisher", "string", false),
+    (
+        "NamingSystem",
+        "responsible",
+        "responsible",
+        "string",
+        false,
+    ),
+    ("NamingSystem", "status", "status", "code", false),
+    ("NamingSystem", "text", "text", "Narrative", false),
+    ("NamingSystem", "type", "type", "CodeableConcept", false),
+    (
+        "NamingSystem",
+        "uniqueId",
+        "uniqueId",
+        "NamingSystem_UniqueId",
+        true,
+    ),
+    ("NamingSystem", "usage", "usage", "string", false),
+    (
+        "NamingSystem",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "NamingSystem_UniqueId",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "NamingSystem_UniqueId",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("NamingSystem_UniqueId", "id", "id", "string", false),
+    (
+        "NamingSystem_UniqueId",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("NamingSystem_UniqueId", "period", "period", "Period", false),
+    (
+        "NamingSystem_UniqueId",
+        "preferred",
+        "preferred",
+        "boolean",
+        false,
+    ),
+    ("NamingSystem_UniqueId", "type", "type", "code", false),
+    ("NamingSystem_UniqueId", "value", "value", "string", false),
+    ("Narrative", "div", "div", "xhtml", false),
+    ("Narrative", "extension", "extension", "Extension", true),
+    ("Narrative", "id", "id", "string", false),
+    ("Narrative", "status", "status", "code", false),
+    (
+        "NutritionOrder",
+        "allergyIntolerance",
+        "allergyIntolerance",
+        "Reference",
+        true,
+    ),
+    (
+        "NutritionOrder",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("NutritionOrder", "dateTime", "dateTime", "dateTime", false),
+    (
+        "NutritionOrder",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "NutritionOrder",
+        "enteralFormula",
+        "enteralFormula",
+        "NutritionOrder_EnteralFormula",
+        false,
+    ),
+    (
+        "NutritionOrder",
+        "excludeFoodModifier",
+        "excludeFoodModifier",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "NutritionOrder",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder",
+        "foodPreferenceModifier",
+        "foodPreferenceModifier",
+        "CodeableConcept",
+        true,
+    ),
+    ("NutritionOrder", "id", "id", "id", false),
+    (
+        "NutritionOrder",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "NutritionOrder",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "NutritionOrder",
+        "instantiates",
+        "instantiates",
+        "uri",
+        true,
+    ),
+    (
+        "NutritionOrder",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "NutritionOrder",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("NutritionOrder", "intent", "intent", "code", false),
+    ("NutritionOrder", "language", "language", "code", false),
+    ("NutritionOrder", "meta", "meta", "Meta", false),
+    (
+        "NutritionOrder",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("NutritionOrder", "note", "note", "Annotation", true),
+    (
+        "NutritionOrder",
+        "oralDiet",
+        "oralDiet",
+        "NutritionOrder_OralDiet",
+        false,
+    ),
+    ("NutritionOrder", "orderer", "orderer", "Reference", false),
+    ("NutritionOrder", "patient", "patient", "Reference", false),
+    ("NutritionOrder", "status", "status", "code", false),
+    (
+        "NutritionOrder",
+        "supplement",
+        "supplement",
+        "NutritionOrder_Supplement",
+        true,
+    ),
+    ("NutritionOrder", "text", "text", "Narrative", false),
+    (
+        "NutritionOrder_Administration",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("NutritionOrder_Administration", "id", "id", "string", false),
+    (
+        "NutritionOrder_Administration",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_Administration",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "NutritionOrder_Administration",
+        "rateQuantity",
+        "rate",
+        "Quantity",
+        false,
+    ),
+    (
+        "NutritionOrder_Administration",
+        "rateRatio",
+        "rate",
+        "Ratio",
+        false,
+    ),
+    (
+        "NutritionOrder_Administration",
+        "schedule",
+        "schedule",
+        "Timing",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "additiveProductName",
+        "additiveProductName",
+        "string",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "additiveType",
+        "additiveType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "administration",
+        "administration",
+        "NutritionOrder_Administration",
+        true,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "administrationInstruction",
+        "administrationInstruction",
+        "string",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "baseFormulaProductName",
+        "baseFormulaProductName",
+        "string",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "baseFormulaType",
+        "baseFormulaType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "caloricDensity",
+        "caloricDensity",
+        "Quantity",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("NutritionOrder_EnteralFormula", "id", "id", "string", false),
+    (
+        "NutritionOrder_EnteralFormula",
+        "maxVolumeToDeliver",
+        "maxVolumeToDeliver",
+        "Quantity",
+        false,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_EnteralFormula",
+        "routeofAdministration",
+        "routeofAdministration",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "NutritionOrder_Nutrient",
+        "amount",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    (
+        "NutritionOrder_Nutrient",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("NutritionOrder_Nutrient", "id", "id", "string", false),
+    (
+        "NutritionOrder_Nutrient",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "NutritionOrder_Nutrient",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "fluidConsistencyType",
+        "fluidConsistencyType",
+        "CodeableConcept",
+        true,
+    ),
+    ("NutritionOrder_OralDiet", "id", "id", "string", false),
+    (
+        "NutritionOrder_OralDiet",
+        "instruction",
+        "instruction",
+        "string",
+        false,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "nutrient",
+        "nutrient",
+        "NutritionOrder_Nutrient",
+        true,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "schedule",
+        "schedule",
+        "Timing",
+        true,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "texture",
+        "texture",
+        "NutritionOrder_Texture",
+        true,
+    ),
+    (
+        "NutritionOrder_OralDiet",
+        "type",
+        "type",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "NutritionOrder_Supplement",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("NutritionOrder_Supplement", "id", "id", "string", false),
+    (
+        "NutritionOrder_Supplement",
+        "instruction",
+        "instruction",
+        "string",
+        false,
+    ),
+    (
+        "NutritionOrder_Supplement",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_Supplement",
+        "productName",
+        "productName",
+        "string",
+        false,
+    ),
+    (
+        "NutritionOrder_Supplement",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "NutritionOrder_Supplement",
+        "schedule",
+        "schedule",
+        "Timing",
+        true,
+    ),
+    (
+        "NutritionOrder_Supplement",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "NutritionOrder_Texture",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "NutritionOrder_Texture",
+        "foodType",
+        "foodType",
+        "CodeableConcept",
+        false,
+    ),
+    ("NutritionOrder_Texture", "id", "id", "string", false),
+    (
+        "NutritionOrder_Texture",
+        "modifier",
+        "modifier",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "NutritionOrder_Texture",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Observation", "basedOn", "basedOn", "Reference", true),
+    (
+        "Observation",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Observation",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    ("Observation", "code", "code", "CodeableConcept", false),
+    (
+        "Observation",
+        "component",
+        "component",
+        "Observation_Component",
+        true,
+    ),
+    (
+        "Observation",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "Observation",
+        "dataAbsentReason",
+        "dataAbsentReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Observation",
+        "derivedFrom",
+        "derivedFrom",
+        "Reference",
+        true,
+    ),
+    ("Observation", "device", "device", "Reference", false),
+    (
+        "Observation",
+        "effectiveDateTime",
+        "effective",
+        "dateTime",
+        false,
+    ),
+    (
+        "Observation",
+        "effectiveInstant",
+        "effective",
+        "instant",
+        false,
+    ),
+    (
+        "Observation",
+        "effectivePeriod",
+        "effective",
+        "Period",
+        false,
+    ),
+    (
+        "Observation",
+        "effectiveTiming",
+        "effective",
+        "Timing",
+        false,
+    ),
+    ("Observation", "encounter", "encounter", "Reference", false),
+    ("Observation", "extension", "extension", "Extension", true),
+    ("Observation", "focus", "focus", "Reference", true),
+    ("Observation", "hasMember", "hasMember", "Reference", true),
+    ("Observation", "id", "id", "id", false),
+    (
+        "Observation",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Observation",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "Observation",
+        "interpretation",
+        "interpretation",
+        "CodeableConcept",
+        true,
+    ),
+    ("Observation", "issued", "issued", "instant", false),
+    ("Observation", "language", "language", "code", false),
+    ("Observation", "meta", "meta", "Meta", false),
+    ("Observation", "method", "method", "CodeableConcept", false),
+    (
+        "Observation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Observation", "note", "note", "Annotation", true),
+    ("Observation", "partOf", "partOf", "Reference", true),
+    ("Observation", "performer", "performer", "Reference", true),
+    (
+        "Observation",
+        "referenceRange",
+        "referenceRange",
+        "Observation_ReferenceRange",
+        true,
+    ),
+    ("Observation", "specimen", "specimen", "Reference", false),
+    ("Observation", "status", "status", "code", false),
+    ("Observation", "subject", "subject", "Reference", false),
+    ("Observation", "text", "text", "Narrative", false),
+    ("Observation", "valueBoolean", "value", "boolean", false),
+    (
+        "Observation",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    ("Observation", "valueDateTime", "value", "dateTime", false),
+    ("Observation", "valueInteger", "value", "integer", false),
+    ("Observation", "valuePeriod", "value", "Period", false),
+    ("Observation", "valueQuantity", "value", "Quantity", false),
+    ("Observation", "valueRange", "value", "Range", false),
+    ("Observation", "valueRatio", "value", "Ratio", false),
+    (
+        "Observation",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    ("Observation", "valueString", "value", "string", false),
+    ("Observation", "valueTime", "value", "time", false),
+    (
+        "ObservationDefinition",
+        "abnormalCodedValueSet",
+        "abnormalCodedValueSet",
+        "Reference",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ObservationDefinition",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ObservationDefinition",
+        "criticalCodedValueSet",
+        "criticalCodedValueSet",
+        "Reference",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ObservationDefinition", "id", "id", "id", false),
+    (
+        "ObservationDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ObservationDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("ObservationDefinition", "meta", "meta", "Meta", false),
+    (
+        "ObservationDefinition",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ObservationDefinition",
+        "multipleResultsAllowed",
+        "multipleResultsAllowed",
+        "boolean",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "normalCodedValueSet",
+        "normalCodedValueSet",
+        "Reference",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "permittedDataType",
+        "permittedDataType",
+        "code",
+        true,
+    ),
+    (
+        "ObservationDefinition",
+        "preferredReportName",
+        "preferredReportName",
+        "string",
+        false,
+    ),
+    (
+        "ObservationDefinition",
+        "qualifiedInterval",
+        "qualifiedInterval",
+        "ObservationDefinition_QualifiedInterval",
+        true,
+    ),
+    (
+        "ObservationDefinition",
+        "quantitativeDetails",
+        "quantitativeDetails",
+        "ObservationDefinition_QuantitativeDetails",
+        false,
+    ),
+    ("ObservationDefinition", "text", "text", "Narrative", false),
+    (
+        "ObservationDefinition",
+        "validCodedValueSet",
+        "validCodedValueSet",
+        "Reference",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "age",
+        "age",
+        "Range",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "appliesTo",
+        "appliesTo",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "category",
+        "category",
+        "code",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "condition",
+        "condition",
+        "string",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "context",
+        "context",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "gender",
+        "gender",
+        "code",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "gestationalAge",
+        "gestationalAge",
+        "Range",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ObservationDefinition_QualifiedInterval",
+        "range",
+        "range",
+        "Range",
+        false,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "conversionFactor",
+        "conversionFactor",
+        "decimal",
+        false,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "customaryUnit",
+        "customaryUnit",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "decimalPrecision",
+        "decimalPrecision",
+        "integer",
+        false,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ObservationDefinition_QuantitativeDetails",
+        "unit",
+        "unit",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "dataAbsentReason",
+        "dataAbsentReason",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Observation_Component", "id", "id", "string", false),
+    (
+        "Observation_Component",
+        "interpretation",
+        "interpretation",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Observation_Component",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Observation_Component",
+        "referenceRange",
+        "referenceRange",
+        "Observation_ReferenceRange",
+        true,
+    ),
+    (
+        "Observation_Component",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valuePeriod",
+        "value",
+        "Period",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueRange",
+        "value",
+        "Range",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueRatio",
+        "value",
+        "Ratio",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    (
+        "Observation_Component",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    ("Observation_Component", "valueTime", "value", "time", false),
+    ("Observation_ReferenceRange", "age", "age", "Range", false),
+    (
+        "Observation_ReferenceRange",
+        "appliesTo",
+        "appliesTo",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Observation_ReferenceRange",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "Observation_ReferenceRange",
+        "high",
+        "high",
+        "Quantity",
+        false,
+    ),
+    ("Observation_ReferenceRange", "id", "id", "string", false),
+    (
+        "Observation_ReferenceRange",
+        "low",
+        "low",
+        "Quantity",
+        false,
+    ),
+    (
+        "Observation_ReferenceRange",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Observation_ReferenceRange",
+        "text",
+        "text",
+        "string",
+        false,
+    ),
+    (
+        "Observation_ReferenceRange",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "affectsState",
+        "affectsState",
+        "boolean",
+        false,
+    ),
+    ("OperationDefinition", "base", "base", "canonical", false),
+    ("OperationDefinition", "code", "code", "code", false),
+    (
+        "OperationDefinition",
+        "comment",
+        "comment",
+        "markdown",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "OperationDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("OperationDefinition", "date", "date", "dateTime", false),
+    (
+        "OperationDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("OperationDefinition", "id", "id", "id", false),
+    (
+        "OperationDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "inputProfile",
+        "inputProfile",
+        "canonical",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "instance",
+        "instance",
+        "boolean",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("OperationDefinition", "kind", "kind", "code", false),
+    ("OperationDefinition", "language", "language", "code", false),
+    ("OperationDefinition", "meta", "meta", "Meta", false),
+    (
+        "OperationDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("OperationDefinition", "name", "name", "string", false),
+    (
+        "OperationDefinition",
+        "outputProfile",
+        "outputProfile",
+        "canonical",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "overload",
+        "overload",
+        "OperationDefinition_Overload",
+        true,
+    ),
+    (
+        "OperationDefinition",
+        "parameter",
+        "parameter",
+        "OperationDefinition_Parameter",
+        true,
+    ),
+    (
+        "OperationDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "OperationDefinition",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    ("OperationDefinition", "resource", "resource", "code", true),
+    ("OperationDefinition", "status", "status", "code", false),
+    ("OperationDefinition", "system", "system", "boolean", false),
+    ("OperationDefinition", "text", "text", "Narrative", false),
+    ("OperationDefinition", "title", "title", "string", false),
+    ("OperationDefinition", "type", "type", "boolean", false),
+    ("OperationDefinition", "url", "url", "uri", false),
+    (
+        "OperationDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("OperationDefinition", "version", "version", "string", false),
+    (
+        "OperationDefinition_Binding",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("OperationDefinition_Binding", "id", "id", "string", false),
+    (
+        "OperationDefinition_Binding",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "OperationDefinition_Binding",
+        "strength",
+        "strength",
+        "code",
+        false,
+    ),
+    (
+        "OperationDefinition_Binding",
+        "valueSet",
+        "valueSet",
+        "canonical",
+        false,
+    ),
+    (
+        "OperationDefinition_Overload",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "OperationDefinition_Overload",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("OperationDefinition_Overload", "id", "id", "string", false),
+    (
+        "OperationDefinition_Overload",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "OperationDefinition_Overload",
+        "parameterName",
+        "parameterName",
+        "string",
+        true,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "binding",
+        "binding",
+        "OperationDefinition_Binding",
+        false,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("OperationDefinition_Parameter", "id", "id", "string", false),
+    (
+        "OperationDefinition_Parameter",
+        "max",
+        "max",
+        "string",
+        false,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "min",
+        "min",
+        "integer",
+        false,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "name",
+        "name",
+        "code",
+        false,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "part",
+        "part",
+        "OperationDefinition_Parameter",
+        true,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "referencedFrom",
+        "referencedFrom",
+        "OperationDefinition_ReferencedFrom",
+        true,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "searchType",
+        "searchType",
+        "code",
+        false,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "targetProfile",
+        "targetProfile",
+        "canonical",
+        true,
+    ),
+    (
+        "OperationDefinition_Parameter",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    ("OperationDefinition_Parameter", "use", "use", "code", false),
+    (
+        "OperationDefinition_ReferencedFrom",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "OperationDefinition_ReferencedFrom",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "OperationDefinition_ReferencedFrom",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "OperationDefinition_ReferencedFrom",
+        "source",
+        "source",
+        "string",
+        false,
+    ),
+    (
+        "OperationDefinition_ReferencedFrom",
+        "sourceId",
+        "sourceId",
+        "string",
+        false,
+    ),
+    (
+        "OperationOutcome",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "OperationOutcome",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("OperationOutcome", "id", "id", "id", false),
+    (
+        "OperationOutcome",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "OperationOutcome",
+        "issue",
+        "issue",
+        "OperationOutcome_Issue",
+        true,
+    ),
+    ("OperationOutcome", "language", "language", "code", false),
+    ("OperationOutcome", "meta", "meta", "Meta", false),
+    (
+        "OperationOutcome",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("OperationOutcome", "text", "text", "Narrative", false),
+    ("OperationOutcome_Issue", "code", "code", "code", false),
+    (
+        "OperationOutcome_Issue",
+        "details",
+        "details",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "OperationOutcome_Issue",
+        "diagnostics",
+        "diagnostics",
+        "string",
+        false,
+    ),
+    (
+        "OperationOutcome_Issue",
+        "expression",
+        "expression",
+        "string",
+        true,
+    ),
+    (
+        "OperationOutcome_Issue",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("OperationOutcome_Issue", "id", "id", "string", false),
+    (
+        "OperationOutcome_Issue",
+        "location",
+        "location",
+        "string",
+        true,
+    ),
+    (
+        "OperationOutcome_Issue",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "OperationOutcome_Issue",
+        "severity",
+        "severity",
+        "code",
+        false,
+    ),
+    ("Organization", "active", "active", "boolean", false),
+    ("Organization", "address", "address", "Address", true),
+    ("Organization", "alias", "alias", "string", true),
+    (
+        "Organization",
+        "contact",
+        "contact",
+        "Organization_Contact",
+        true,
+    ),
+    (
+        "Organization",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("Organization", "endpoint", "endpoint", "Reference", true),
+    ("Organization", "extension", "extension", "Extension", true),
+    ("Organization", "id", "id", "id", false),
+    (
+        "Organization",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Organization",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("Organization", "language", "language", "code", false),
+    ("Organization", "meta", "meta", "Meta", false),
+    (
+        "Organization",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Organization", "name", "name", "string", false),
+    ("Organization", "partOf", "partOf", "Reference", false),
+    ("Organization", "telecom", "telecom", "ContactPoint", true),
+    ("Organization", "text", "text", "Narrative", false),
+    ("Organization", "type", "type", "CodeableConcept", true),
+    (
+        "OrganizationAffiliation",
+        "active",
+        "active",
+        "boolean",
+        false,
+    ),
+    (
+        "OrganizationAffiliation",
+        "code",
+        "code",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "endpoint",
+        "endpoint",
+        "Reference",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "healthcareService",
+        "healthcareService",
+        "Reference",
+        true,
+    ),
+    ("OrganizationAffiliation", "id", "id", "id", false),
+    (
+        "OrganizationAffiliation",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "OrganizationAffiliation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "OrganizationAffiliation",
+        "location",
+        "location",
+        "Reference",
+        true,
+    ),
+    ("OrganizationAffiliation", "meta", "meta", "Meta", false),
+    (
+        "OrganizationAffiliation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "network",
+        "network",
+        "Reference",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "organization",
+        "organization",
+        "Reference",
+        false,
+    ),
+    (
+        "OrganizationAffiliation",
+        "participatingOrganization",
+        "participatingOrganization",
+        "Reference",
+        false,
+    ),
+    (
+        "OrganizationAffiliation",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    (
+        "OrganizationAffiliation",
+        "specialty",
+        "specialty",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "telecom",
+        "telecom",
+        "ContactPoint",
+        true,
+    ),
+    (
+        "OrganizationAffiliation",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "Organization_Contact",
+        "address",
+        "address",
+        "Address",
+        false,
+    ),
+    (
+        "Organization_Contact",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Organization_Contact", "id", "id", "string", false),
+    (
+        "Organization_Contact",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Organization_Contact", "name", "name", "HumanName", false),
+    (
+        "Organization_Contact",
+        "purpose",
+        "purpose",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Organization_Contact",
+        "telecom",
+        "telecom",
+        "ContactPoint",
+        true,
+    ),
+    (
+        "ParameterDefinition",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "ParameterDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ParameterDefinition", "id", "id", "string", false),
+    ("ParameterDefinition", "max", "max", "string", false),
+    ("ParameterDefinition", "min", "min", "integer", false),
+    ("ParameterDefinition", "name", "name", "code", false),
+    (
+        "ParameterDefinition",
+        "profile",
+        "profile",
+        "canonical",
+        false,
+    ),
+    ("ParameterDefinition", "type", "type", "code", false),
+    ("ParameterDefinition", "use", "use", "code", false),
+    ("Parameters", "id", "id", "id", false),
+    ("Parameters", "implicitRules", "implicitRules", "uri", false),
+    ("Parameters", "language", "language", "code", false),
+    ("Parameters", "meta", "meta", "Meta", false),
+    (
+        "Parameters",
+        "parameter",
+        "parameter",
+        "Parameters_Parameter",
+        true,
+    ),
+    (
+        "Parameters_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Parameters_Parameter", "id", "id", "string", false),
+    (
+        "Parameters_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Parameters_Parameter", "name", "name", "string", false),
+    (
+        "Parameters_Parameter",
+        "part",
+        "part",
+        "Parameters_Parameter",
+        true,
+    ),
+    (
+        "Parameters_Parameter",
+        "resource",
+        "resource",
+        "ResourceList",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueAddress",
+        "value",
+        "Address",
+        false,
+    ),
+    ("Parameters_Parameter", "valueAge", "value", "Age", false),
+    (
+        "Parameters_Parameter",
+        "valueAnnotation",
+        "value",
+        "Annotation",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueCanonical",
+        "value",
+        "canonical",
+        false,
+    ),
+    ("Parameters_Parameter", "valueCode", "value", "code", false),
+    (
+        "Parameters_Parameter",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueCoding",
+        "value",
+        "Coding",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueContactDetail",
+        "value",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueContactPoint",
+        "value",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueContributor",
+        "value",
+        "Contributor",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueCount",
+        "value",
+        "Count",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueDataRequirement",
+        "value",
+        "DataRequirement",
+        false,
+    ),
+    ("Parameters_Parameter", "valueDate", "value", "date", false),
+    (
+        "Parameters_Parameter",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueDistance",
+        "value",
+        "Distance",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueDosage",
+        "value",
+        "Dosage",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueDuration",
+        "value",
+        "Duration",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueExpression",
+        "value",
+        "Expression",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueHumanName",
+        "value",
+        "HumanName",
+        false,
+    ),
+    ("Parameters_Parameter", "valueId", "value", "id", false),
+    (
+        "Parameters_Parameter",
+        "valueIdentifier",
+        "value",
+        "Identifier",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueInstant",
+        "value",
+        "instant",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueMarkdown",
+        "value",
+        "markdown",
+        false,
+    ),
+    ("Parameters_Parameter", "valueMeta", "value", "Meta", false),
+    (
+        "Parameters_Parameter",
+        "valueMoney",
+        "value",
+        "Money",
+        false,
+    ),
+    ("Parameters_Parameter", "valueOid", "value", "oid", false),
+    (
+        "Parameters_Parameter",
+        "valueParameterDefinition",
+        "value",
+        "ParameterDefinition",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valuePeriod",
+        "value",
+        "Period",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valuePositiveInt",
+        "value",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueRange",
+        "value",
+        "Range",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueRatio",
+        "value",
+        "Ratio",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueRelatedArtifact",
+        "value",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueSignature",
+        "value",
+        "Signature",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    ("Parameters_Parameter", "valueTime", "value", "time", false),
+    (
+        "Parameters_Parameter",
+        "valueTiming",
+        "value",
+        "Timing",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueTriggerDefinition",
+        "value",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "Parameters_Parameter",
+        "valueUnsignedInt",
+        "value",
+        "unsignedInt",
+        false,
+    ),
+    ("Parameters_Parameter", "valueUri", "value", "uri", false),
+    ("Parameters_Parameter", "valueUrl", "value", "url", false),
+    (
+        "Parameters_Parameter",
+        "valueUsageContext",
+        "value",
+        "UsageContext",
+        false,
+    ),
+    ("Parameters_Parameter", "valueUuid", "value", "uuid", false),
+    ("Patient", "active", "active", "boolean", false),
+    ("Patient", "address", "address", "Address", true),
+    ("Patient", "birthDate", "birthDate", "date", false),
+    (
+        "Patient",
+        "communication",
+        "communication",
+        "Patient_Communication",
+        true,
+    ),
+    ("Patient", "contact", "contact", "Patient_Contact", true),
+    ("Patient", "contained", "contained", "ResourceList", true),
+    ("Patient", "deceasedBoolean", "deceased", "boolean", false),
+    ("Patient", "deceasedDateTime", "deceased", "dateTime", false),
+    ("Patient", "extension", "extension", "Extension", true),
+    ("Patient", "gender", "gender", "code", false),
+    (
+        "Patient",
+        "generalPractitioner",
+        "generalPractitioner",
+        "Reference",
+        true,
+    ),
+    ("Patient", "id", "id", "id", false),
+    ("Patient", "identifier", "identifier", "Identifier", true),
+    ("Patient", "implicitRules", "implicitRules", "uri", false),
+    ("Patient", "language", "language", "code", false),
+    ("Patient", "link", "link", "Patient_Link", true),
+    (
+        "Patient",
+        "managingOrganization",
+        "managingOrganization",
+        "Reference",
+        false,
+    ),
+    (
+        "Patient",
+        "maritalStatus",
+        "maritalStatus",
+        "CodeableConcept",
+        false,
+    ),
+    ("Patient", "meta", "meta", "Meta", false),
+    (
+        "Patient",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Patient",
+        "multipleBirthBoolean",
+        "multipleBirth",
+        "boolean",
+        false,
+    ),
+    (
+        "Patient",
+        "multipleBirthInteger",
+        "multipleBirth",
+        "integer",
+        false,
+    ),
+    ("Patient", "name", "name", "HumanName", true),
+    ("Patient", "photo", "photo", "Attachment", true),
+    ("Patient", "telecom", "telecom", "ContactPoint", true),
+    ("Patient", "text", "text", "Narrative", false),
+    (
+        "Patient_Communication",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Patient_Communication", "id", "id", "string", false),
+    (
+        "Patient_Communication",
+        "language",
+        "language",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Patient_Communication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Patient_Communication",
+        "preferred",
+        "preferred",
+        "boolean",
+        false,
+    ),
+    ("Patient_Contact", "address", "address", "Address", false),
+    (
+        "Patient_Contact",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Patient_Contact", "gender", "gender", "code", false),
+    ("Patient_Contact", "id", "id", "string", false),
+    (
+        "Patient_Contact",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Patient_Contact", "name", "name", "HumanName", false),
+    (
+        "Patient_Contact",
+        "organization",
+        "organization",
+        "Reference",
+        false,
+    ),
+    ("Patient_Contact", "period", "period", "Period", false),
+    (
+        "Patient_Contact",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Patient_Contact",
+        "telecom",
+        "telecom",
+        "ContactPoint",
+        true,
+    ),
+    ("Patient_Link", "extension", "extension", "Extension", true),
+    ("Patient_Link", "id", "id", "string", false),
+    (
+        "Patient_Link",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Patient_Link", "other", "other", "Reference", false),
+    ("Patient_Link", "type", "type", "code", false),
+    ("PaymentNotice", "amount", "amount", "Money", false),
+    (
+        "PaymentNotice",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("PaymentNotice", "created", "created", "dateTime", false),
+    ("PaymentNotice", "extension", "extension", "Extension", true),
+    ("PaymentNotice", "id", "id", "id", false),
+    (
+        "PaymentNotice",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "PaymentNotice",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("PaymentNotice", "language", "language", "code", false),
+    ("PaymentNotice", "meta", "meta", "Meta", false),
+    (
+        "PaymentNotice",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("PaymentNotice", "payee", "payee", "Reference", false),
+    ("PaymentNotice", "payment", "payment", "Reference", false),
+    ("PaymentNotice", "paymentDate", "paymentDate", "date", false),
+    (
+        "PaymentNotice",
+        "paymentStatus",
+        "paymentStatus",
+        "CodeableConcept",
+        false,
+    ),
+    ("PaymentNotice", "provider", "provider", "Reference", false),
+    (
+        "PaymentNotice",
+        "recipient",
+        "recipient",
+        "Reference",
+        false,
+    ),
+    ("PaymentNotice", "request", "request", "Reference", false),
+    ("PaymentNotice", "response", "response", "Reference", false),
+    ("PaymentNotice", "status", "status", "code", false),
+    ("PaymentNotice", "text", "text", "Narrative", false),
+    (
+        "PaymentReconciliation",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "PaymentReconciliation",
+        "created",
+        "created",
+        "dateTime",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "detail",
+        "detail",
+        "PaymentReconciliation_Detail",
+        true,
+    ),
+    (
+        "PaymentReconciliation",
+        "disposition",
+        "disposition",
+        "string",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "PaymentReconciliation",
+        "formCode",
+        "formCode",
+        "CodeableConcept",
+        false,
+    ),
+    ("PaymentReconciliation", "id", "id", "id", false),
+    (
+        "PaymentReconciliation",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "PaymentReconciliation",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("PaymentReconciliation", "meta", "meta", "Meta", false),
+    (
+        "PaymentReconciliation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("PaymentReconciliation", "outcome", "outcome", "code", false),
+    (
+        "PaymentReconciliation",
+        "paymentAmount",
+        "paymentAmount",
+        "Money",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "paymentDate",
+        "payment",
+        "date",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "paymentIdentifier",
+        "payment",
+        "Identifier",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "paymentIssuer",
+        "paymentIssuer",
+        "Reference",
+        false,
+    ),
+    ("PaymentReconciliation", "period", "period", "Period", false),
+    (
+        "PaymentReconciliation",
+        "processNote",
+        "processNote",
+        "PaymentReconciliation_ProcessNote",
+        true,
+    ),
+    (
+        "PaymentReconciliation",
+        "request",
+        "request",
+        "Reference",
+        false,
+    ),
+    (
+        "PaymentReconciliation",
+        "requestor",
+        "requestor",
+        "Reference",
+        false,
+    ),
+    ("PaymentReconciliation", "status", "status", "code", false),
+    ("PaymentReconciliation", "text", "text", "Narrative", false),
+    (
+        "PaymentReconciliation_Detail",
+        "amount",
+        "amount",
+        "Money",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "date",
+        "date",
+        "date",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PaymentReconciliation_Detail", "id", "id", "string", false),
+    (
+        "PaymentReconciliation_Detail",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "payee",
+        "payee",
+        "Reference",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "predecessor",
+        "predecessor",
+        "Identifier",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "request",
+        "request",
+        "Reference",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "response",
+        "response",
+        "Reference",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "responsible",
+        "responsible",
+        "Reference",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "submitter",
+        "submitter",
+        "Reference",
+        false,
+    ),
+    (
+        "PaymentReconciliation_Detail",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PaymentReconciliation_ProcessNote",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "PaymentReconciliation_ProcessNote",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "PaymentReconciliation_ProcessNote",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PaymentReconciliation_ProcessNote",
+        "text",
+        "text",
+        "string",
+        false,
+    ),
+    (
+        "PaymentReconciliation_ProcessNote",
+        "type",
+        "type",
+        "code",
+        false,
+    ),
+    ("Period", "end", "end", "dateTime", false),
+    ("Period", "extension", "extension", "Extension", true),
+    ("Period", "id", "id", "string", false),
+    ("Period", "start", "start", "dateTime", false),
+    ("Person", "active", "active", "boolean", false),
+    ("Person", "address", "address", "Address", true),
+    ("Person", "birthDate", "birthDate", "date", false),
+    ("Person", "contained", "contained", "ResourceList", true),
+    ("Person", "extension", "extension", "Extension", true),
+    ("Person", "gender", "gender", "code", false),
+    ("Person", "id", "id", "id", false),
+    ("Person", "identifier", "identifier", "Identifier", true),
+    ("Person", "implicitRules", "implicitRules", "uri", false),
+    ("Person", "language", "language", "code", false),
+    ("Person", "link", "link", "Person_Link", true),
+    (
+        "Person",
+        "managingOrganization",
+        "managingOrganization",
+        "Reference",
+        false,
+    ),
+    ("Person", "meta", "meta", "Meta", false),
+    (
+        "Person",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Person", "name", "name", "HumanName", true),
+    ("Person", "photo", "photo", "Attachment", false),
+    ("Person", "telecom", "telecom", "ContactPoint", true),
+    ("Person", "text", "text", "Narrative", false),
+    ("Person_Link", "assurance", "assurance", "code", false),
+    ("Person_Link", "extension", "extension", "Extension", true),
+    ("Person_Link", "id", "id", "string", false),
+    (
+        "Person_Link",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Person_Link", "target", "target", "Reference", false),
+    (
+        "PlanDefinition",
+        "action",
+        "action",
+        "PlanDefinition_Action",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    ("PlanDefinition", "author", "author", "ContactDetail", true),
+    (
+        "PlanDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("PlanDefinition", "date", "date", "dateTime", false),
+    (
+        "PlanDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    ("PlanDefinition", "editor", "editor", "ContactDetail", true),
+    (
+        "PlanDefinition",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "PlanDefinition",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "PlanDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "goal",
+        "goal",
+        "PlanDefinition_Goal",
+        true,
+    ),
+    ("PlanDefinition", "id", "id", "id", false),
+    (
+        "PlanDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "PlanDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("PlanDefinition", "language", "language", "code", false),
+    (
+        "PlanDefinition",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("PlanDefinition", "library", "library", "canonical", true),
+    ("PlanDefinition", "meta", "meta", "Meta", false),
+    (
+        "PlanDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition", "name", "name", "string", false),
+    ("PlanDefinition", "publisher", "publisher", "string", false),
+    ("PlanDefinition", "purpose", "purpose", "markdown", false),
+    (
+        "PlanDefinition",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "PlanDefinition",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    ("PlanDefinition", "status", "status", "code", false),
+    (
+        "PlanDefinition",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("PlanDefinition", "subtitle", "subtitle", "string", false),
+    ("PlanDefinition", "text", "text", "Narrative", false),
+    ("PlanDefinition", "title", "title", "string", false),
+    ("PlanDefinition", "topic", "topic", "CodeableConcept", true),
+    ("PlanDefinition", "type", "type", "CodeableConcept", false),
+    ("PlanDefinition", "url", "url", "uri", false),
+    ("PlanDefinition", "usage", "usage", "string", false),
+    (
+        "PlanDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("PlanDefinition", "version", "version", "string", false),
+    (
+        "PlanDefinition_Action",
+        "action",
+        "action",
+        "PlanDefinition_Action",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "cardinalityBehavior",
+        "cardinalityBehavior",
+        "code",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "code",
+        "code",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "condition",
+        "condition",
+        "PlanDefinition_Condition",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "definitionCanonical",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "definitionUri",
+        "definition",
+        "uri",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "documentation",
+        "documentation",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "dynamicValue",
+        "dynamicValue",
+        "PlanDefinition_DynamicValue",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_Action", "goalId", "goalId", "id", true),
+    (
+        "PlanDefinition_Action",
+        "groupingBehavior",
+        "groupingBehavior",
+        "code",
+        false,
+    ),
+    ("PlanDefinition_Action", "id", "id", "string", false),
+    (
+        "PlanDefinition_Action",
+        "input",
+        "input",
+        "DataRequirement",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "output",
+        "output",
+        "DataRequirement",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "participant",
+        "participant",
+        "PlanDefinition_Participant",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "precheckBehavior",
+        "precheckBehavior",
+        "code",
+        false,
+    ),
+    ("PlanDefinition_Action", "prefix", "prefix", "string", false),
+    (
+        "PlanDefinition_Action",
+        "priority",
+        "priority",
+        "code",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "reason",
+        "reason",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "relatedAction",
+        "relatedAction",
+        "PlanDefinition_RelatedAction",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "requiredBehavior",
+        "requiredBehavior",
+        "code",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "selectionBehavior",
+        "selectionBehavior",
+        "code",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "textEquivalent",
+        "textEquivalent",
+        "string",
+        false,
+    ),
+    ("PlanDefinition_Action", "timingAge", "timing", "Age", false),
+    (
+        "PlanDefinition_Action",
+        "timingDateTime",
+        "timing",
+        "dateTime",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "timingDuration",
+        "timing",
+        "Duration",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "timingPeriod",
+        "timing",
+        "Period",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "timingRange",
+        "timing",
+        "Range",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "timingTiming",
+        "timing",
+        "Timing",
+        false,
+    ),
+    ("PlanDefinition_Action", "title", "title", "string", false),
+    (
+        "PlanDefinition_Action",
+        "transform",
+        "transform",
+        "canonical",
+        false,
+    ),
+    (
+        "PlanDefinition_Action",
+        "trigger",
+        "trigger",
+        "TriggerDefinition",
+        true,
+    ),
+    (
+        "PlanDefinition_Action",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Condition",
+        "expression",
+        "expression",
+        "Expression",
+        false,
+    ),
+    (
+        "PlanDefinition_Condition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_Condition", "id", "id", "string", false),
+    ("PlanDefinition_Condition", "kind", "kind", "code", false),
+    (
+        "PlanDefinition_Condition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition_DynamicValue",
+        "expression",
+        "expression",
+        "Expression",
+        false,
+    ),
+    (
+        "PlanDefinition_DynamicValue",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_DynamicValue", "id", "id", "string", false),
+    (
+        "PlanDefinition_DynamicValue",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition_DynamicValue",
+        "path",
+        "path",
+        "string",
+        false,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "addresses",
+        "addresses",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "description",
+        "description",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "documentation",
+        "documentation",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_Goal", "id", "id", "string", false),
+    (
+        "PlanDefinition_Goal",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "priority",
+        "priority",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "start",
+        "start",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Goal",
+        "target",
+        "target",
+        "PlanDefinition_Target",
+        true,
+    ),
+    (
+        "PlanDefinition_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_Participant", "id", "id", "string", false),
+    (
+        "PlanDefinition_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition_Participant",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    ("PlanDefinition_Participant", "type", "type", "code", false),
+    (
+        "PlanDefinition_RelatedAction",
+        "actionId",
+        "actionId",
+        "id",
+        false,
+    ),
+    (
+        "PlanDefinition_RelatedAction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_RelatedAction", "id", "id", "string", false),
+    (
+        "PlanDefinition_RelatedAction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PlanDefinition_RelatedAction",
+        "offsetDuration",
+        "offset",
+        "Duration",
+        false,
+    ),
+    (
+        "PlanDefinition_RelatedAction",
+        "offsetRange",
+        "offset",
+        "Range",
+        false,
+    ),
+    (
+        "PlanDefinition_RelatedAction",
+        "relationship",
+        "relationship",
+        "code",
+        false,
+    ),
+    (
+        "PlanDefinition_Target",
+        "detailCodeableConcept",
+        "detail",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Target",
+        "detailQuantity",
+        "detail",
+        "Quantity",
+        false,
+    ),
+    (
+        "PlanDefinition_Target",
+        "detailRange",
+        "detail",
+        "Range",
+        false,
+    ),
+    ("PlanDefinition_Target", "due", "due", "Duration", false),
+    (
+        "PlanDefinition_Target",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PlanDefinition_Target", "id", "id", "string", false),
+    (
+        "PlanDefinition_Target",
+        "measure",
+        "measure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "PlanDefinition_Target",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Population",
+        "ageCodeableConcept",
+        "age",
+        "CodeableConcept",
+        false,
+    ),
+    ("Population", "ageRange", "age", "Range", false),
+    ("Population", "extension", "extension", "Extension", true),
+    ("Population", "gender", "gender", "CodeableConcept", false),
+    ("Population", "id", "id", "string", false),
+    (
+        "Population",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Population",
+        "physiologicalCondition",
+        "physiologicalCondition",
+        "CodeableConcept",
+        false,
+    ),
+    ("Population", "race", "race", "CodeableConcept", false),
+    ("Practitioner", "active", "active", "boolean", false),
+    ("Practitioner", "address", "address", "Address", true),
+    ("Practitioner", "birthDate", "birthDate", "date", false),
+    (
+        "Practitioner",
+        "communication",
+        "communication",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Practitioner",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("Practitioner", "extension", "extension", "Extension", true),
+    ("Practitioner", "gender", "gender", "code", false),
+    ("Practitioner", "id", "id", "id", false),
+    (
+        "Practitioner",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Practitioner",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("Practitioner", "language", "language", "code", false),
+    ("Practitioner", "meta", "meta", "Meta", false),
+    (
+        "Practitioner",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Practitioner", "name", "name", "HumanName", true),
+    ("Practitioner", "photo", "photo", "Attachment", true),
+    (
+        "Practitioner",
+        "qualification",
+        "qualification",
+        "Practitioner_Qualification",
+        true,
+    ),
+    ("Practitioner", "telecom", "telecom", "ContactPoint", true),
+    ("Practitioner", "text", "text", "Narrative", false),
+    ("PractitionerRole", "active", "active", "boolean", false),
+    (
+        "PractitionerRole",
+        "availabilityExceptions",
+        "availabilityExceptions",
+        "string",
+        false,
+    ),
+    (
+        "PractitionerRole",
+        "availableTime",
+        "availableTime",
+        "PractitionerRole_AvailableTime",
+        true,
+    ),
+    ("PractitionerRole", "code", "code", "CodeableConcept", true),
+    (
+        "PractitionerRole",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "endpoint",
+        "endpoint",
+        "Reference",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "healthcareService",
+        "healthcareService",
+        "Reference",
+        true,
+    ),
+    ("PractitionerRole", "id", "id", "id", false),
+    (
+        "PractitionerRole",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("PractitionerRole", "language", "language", "code", false),
+    (
+        "PractitionerRole",
+        "location",
+        "location",
+        "Reference",
+        true,
+    ),
+    ("PractitionerRole", "meta", "meta", "Meta", false),
+    (
+        "PractitionerRole",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "notAvailable",
+        "notAvailable",
+        "PractitionerRole_NotAvailable",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "organization",
+        "organization",
+        "Reference",
+        false,
+    ),
+    ("PractitionerRole", "period", "period", "Period", false),
+    (
+        "PractitionerRole",
+        "practitioner",
+        "practitioner",
+        "Reference",
+        false,
+    ),
+    (
+        "PractitionerRole",
+        "specialty",
+        "specialty",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "PractitionerRole",
+        "telecom",
+        "telecom",
+        "ContactPoint",
+        true,
+    ),
+    ("PractitionerRole", "text", "text", "Narrative", false),
+    (
+        "PractitionerRole_AvailableTime",
+        "allDay",
+        "allDay",
+        "boolean",
+        false,
+    ),
+    (
+        "PractitionerRole_AvailableTime",
+        "availableEndTime",
+        "availableEndTime",
+        "time",
+        false,
+    ),
+    (
+        "PractitionerRole_AvailableTime",
+        "availableStartTime",
+        "availableStartTime",
+        "time",
+        false,
+    ),
+    (
+        "PractitionerRole_AvailableTime",
+        "daysOfWeek",
+        "daysOfWeek",
+        "code",
+        true,
+    ),
+    (
+        "PractitionerRole_AvailableTime",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "PractitionerRole_AvailableTime",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "PractitionerRole_AvailableTime",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "PractitionerRole_NotAvailable",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "PractitionerRole_NotAvailable",
+        "during",
+        "during",
+        "Period",
+        false,
+    ),
+    (
+        "PractitionerRole_NotAvailable",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("PractitionerRole_NotAvailable", "id", "id", "string", false),
+    (
+        "PractitionerRole_NotAvailable",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Practitioner_Qualification",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Practitioner_Qualification",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Practitioner_Qualification", "id", "id", "string", false),
+    (
+        "Practitioner_Qualification",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Practitioner_Qualification",
+        "issuer",
+        "issuer",
+        "Reference",
+        false,
+    ),
+    (
+        "Practitioner_Qualification",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Practitioner_Qualification",
+        "period",
+        "period",
+        "Period",
+        false,
+    ),
+    ("Procedure", "asserter", "asserter", "Reference", false),
+    ("Procedure", "basedOn", "basedOn", "Reference", true),
+    ("Procedure", "bodySite", "bodySite", "CodeableConcept", true),
+    (
+        "Procedure",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    ("Procedure", "code", "code", "CodeableConcept", false),
+    (
+        "Procedure",
+        "complication",
+        "complication",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Procedure",
+        "complicationDetail",
+        "complicationDetail",
+        "Reference",
+        true,
+    ),
+    ("Procedure", "contained", "contained", "ResourceList", true),
+    ("Procedure", "encounter", "encounter", "Reference", false),
+    ("Procedure", "extension", "extension", "Extension", true),
+    (
+        "Procedure",
+        "focalDevice",
+        "focalDevice",
+        "Procedure_FocalDevice",
+        true,
+    ),
+    ("Procedure", "followUp", "followUp", "CodeableConcept", true),
+    ("Procedure", "id", "id", "id", false),
+    ("Procedure", "identifier", "identifier", "Identifier", true),
+    ("Procedure", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Procedure",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "Procedure",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("Procedure", "language", "language", "code", false),
+    ("Procedure", "location", "location", "Reference", false),
+    ("Procedure", "meta", "meta", "Meta", false),
+    (
+        "Procedure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Procedure", "note", "note", "Annotation", true),
+    ("Procedure", "outcome", "outcome", "CodeableConcept", false),
+    ("Procedure", "partOf", "partOf", "Reference", true),
+    ("Procedure", "performedAge", "performed", "Age", false),
+    (
+        "Procedure",
+        "performedDateTime",
+        "performed",
+        "dateTime",
+        false,
+    ),
+    ("Procedure", "performedPeriod", "performed", "Period", false),
+    ("Procedure", "performedRange", "performed", "Range", false),
+    ("Procedure", "performedString", "performed", "string", false),
+    (
+        "Procedure",
+        "performer",
+        "performer",
+        "Procedure_Performer",
+        true,
+    ),
+    (
+        "Procedure",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Procedure",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("Procedure", "recorder", "recorder", "Reference", false),
+    ("Procedure", "report", "report", "Reference", true),
+    ("Procedure", "status", "status", "code", false),
+    (
+        "Procedure",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("Procedure", "subject", "subject", "Reference", false),
+    ("Procedure", "text", "text", "Narrative", false),
+    ("Procedure", "usedCode", "usedCode", "CodeableConcept", true),
+    (
+        "Procedure",
+        "usedReference",
+        "usedReference",
+        "Reference",
+        true,
+    ),
+    (
+        "Procedure_FocalDevice",
+        "action",
+        "action",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Procedure_FocalDevice",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Procedure_FocalDevice", "id", "id", "string", false),
+    (
+        "Procedure_FocalDevice",
+        "manipulated",
+        "manipulated",
+        "Reference",
+        false,
+    ),
+    (
+        "Procedure_FocalDevice",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Procedure_Performer", "actor", "actor", "Reference", false),
+    (
+        "Procedure_Performer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "Procedure_Performer",
+        "function",
+        "function",
+        "CodeableConcept",
+        false,
+    ),
+    ("Procedure_Performer", "id", "id", "string", false),
+    (
+        "Procedure_Performer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Procedure_Performer",
+        "onBehalfOf",
+        "onBehalfOf",
+        "Reference",
+        false,
+    ),
+    ("ProdCharacteristic", "color", "color", "string", true),
+    ("ProdCharacteristic", "depth", "depth", "Quantity", false),
+    (
+        "ProdCharacteristic",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ProdCharacteristic",
+        "externalDiameter",
+        "externalDiameter",
+        "Quantity",
+        false,
+    ),
+    ("ProdCharacteristic", "height", "height", "Quantity", false),
+    ("ProdCharacteristic", "id", "id", "string", false),
+    ("ProdCharacteristic", "image", "image", "Attachment", true),
+    ("ProdCharacteristic", "imprint", "imprint", "string", true),
+    (
+        "ProdCharacteristic",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ProdCharacteristic",
+        "nominalVolume",
+        "nominalVolume",
+        "Quantity",
+        false,
+    ),
+    (
+        "ProdCharacteristic",
+        "scoring",
+        "scoring",
+        "CodeableConcept",
+        false,
+    ),
+    ("ProdCharacteristic", "shape", "shape", "string", false),
+    ("ProdCharacteristic", "weight", "weight", "Quantity", false),
+    ("ProdCharacteristic", "width", "width", "Quantity", false),
+    (
+        "ProductShelfLife",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ProductShelfLife", "id", "id", "string", false),
+    (
+        "ProductShelfLife",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "ProductShelfLife",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ProductShelfLife", "period", "period", "Quantity", false),
+    (
+        "ProductShelfLife",
+        "specialPrecautionsForStorage",
+        "specialPrecautionsForStorage",
+        "CodeableConcept",
+        true,
+    ),
+    ("ProductShelfLife", "type", "type", "CodeableConcept", false),
+    (
+        "Provenance",
+        "activity",
+        "activity",
+        "CodeableConcept",
+        false,
+    ),
+    ("Provenance", "agent", "agent", "Provenance_Agent", true),
+    ("Provenance", "contained", "contained", "ResourceList", true),
+    ("Provenance", "entity", "entity", "Provenance_Entity", true),
+    ("Provenance", "extension", "extension", "Extension", true),
+    ("Provenance", "id", "id", "id", false),
+    ("Provenance", "implicitRules", "implicitRules", "uri", false),
+    ("Provenance", "language", "language", "code", false),
+    ("Provenance", "location", "location", "Reference", false),
+    ("Provenance", "meta", "meta", "Meta", false),
+    (
+        "Provenance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Provenance",
+        "occurredDateTime",
+        "occurred",
+        "dateTime",
+        false,
+    ),
+    ("Provenance", "occurredPeriod", "occurred", "Period", false),
+    ("Provenance", "policy", "policy", "uri", true),
+    ("Provenance", "reason", "reason", "CodeableConcept", true),
+    ("Provenance", "recorded", "recorded", "instant", false),
+    ("Provenance", "signature", "signature", "Signature", true),
+    ("Provenance", "target", "target", "Reference", true),
+    ("Provenance", "text", "text", "Narrative", false),
+    (
+        "Provenance_Agent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Provenance_Agent", "id", "id", "string", false),
+    (
+        "Provenance_Agent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Provenance_Agent",
+        "onBehalfOf",
+        "onBehalfOf",
+        "Reference",
+        false,
+    ),
+    ("Provenance_Agent", "role", "role", "CodeableConcept", true),
+    ("Provenance_Agent", "type", "type", "CodeableConcept", false),
+    ("Provenance_Agent", "who", "who", "Reference", false),
+    (
+        "Provenance_Entity",
+        "agent",
+        "agent",
+        "Provenance_Agent",
+        true,
+    ),
+    (
+        "Provenance_Entity",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Provenance_Entity", "id", "id", "string", false),
+    (
+        "Provenance_Entity",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Provenance_Entity", "role", "role", "code", false),
+    ("Provenance_Entity", "what", "what", "Reference", false),
+    ("Quantity", "code", "code", "code", false),
+    ("Quantity", "comparator", "comparator", "code", false),
+    ("Quantity", "extension", "extension", "Extension", true),
+    ("Quantity", "id", "id", "string", false),
+    ("Quantity", "system", "system", "uri", false),
+    ("Quantity", "unit", "unit", "string", false),
+    ("Quantity", "value", "value", "decimal", false),
+    (
+        "Questionnaire",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    ("Questionnaire", "code", "code", "Coding", true),
+    ("Questionnaire", "contact", "contact", "ContactDetail", true),
+    (
+        "Questionnaire",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("Questionnaire", "copyright", "copyright", "markdown", false),
+    ("Questionnaire", "date", "date", "dateTime", false),
+    (
+        "Questionnaire",
+        "derivedFrom",
+        "derivedFrom",
+        "canonical",
+        true,
+    ),
+    (
+        "Questionnaire",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "Questionnaire",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "Questionnaire",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    ("Questionnaire", "extension", "extension", "Extension", true),
+    ("Questionnaire", "id", "id", "id", false),
+    (
+        "Questionnaire",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Questionnaire",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("Questionnaire", "item", "item", "Questionnaire_Item", true),
+    (
+        "Questionnaire",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("Questionnaire", "language", "language", "code", false),
+    (
+        "Questionnaire",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("Questionnaire", "meta", "meta", "Meta", false),
+    (
+        "Questionnaire",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Questionnaire", "name", "name", "string", false),
+    ("Questionnaire", "publisher", "publisher", "string", false),
+    ("Questionnaire", "purpose", "purpose", "markdown", false),
+    ("Questionnaire", "status", "status", "code", false),
+    ("Questionnaire", "subjectType", "subjectType", "code", true),
+    ("Questionnaire", "text", "text", "Narrative", false),
+    ("Questionnaire", "title", "title", "string", false),
+    ("Questionnaire", "url", "url", "uri", false),
+    (
+        "Questionnaire",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("Questionnaire", "version", "version", "string", false),
+    (
+        "QuestionnaireResponse",
+        "author",
+        "author",
+        "Reference",
+        false,
+    ),
+    (
+        "QuestionnaireResponse",
+        "authored",
+        "authored",
+        "dateTime",
+        false,
+    ),
+    (
+        "QuestionnaireResponse",
+        "basedOn",
+        "basedOn",
+        "Reference",
+        true,
+    ),
+    (
+        "QuestionnaireResponse",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "QuestionnaireResponse",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "QuestionnaireResponse",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("QuestionnaireResponse", "id", "id", "id", false),
+    (
+        "QuestionnaireResponse",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "QuestionnaireResponse",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "QuestionnaireResponse",
+        "item",
+        "item",
+        "QuestionnaireResponse_Item",
+        true,
+    ),
+    (
+        "QuestionnaireResponse",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("QuestionnaireResponse", "meta", "meta", "Meta", false),
+    (
+        "QuestionnaireResponse",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "QuestionnaireResponse",
+        "partOf",
+        "partOf",
+        "Reference",
+        true,
+    ),
+    (
+        "QuestionnaireResponse",
+        "questionnaire",
+        "questionnaire",
+        "canonical",
+        false,
+    ),
+    (
+        "QuestionnaireResponse",
+        "source",
+        "source",
+        "Reference",
+        false,
+    ),
+    ("QuestionnaireResponse", "status", "status", "code", false),
+    (
+        "QuestionnaireResponse",
+        "subject",
+        "subject",
+        "Reference",
+        false,
+    ),
+    ("QuestionnaireResponse", "text", "text", "Narrative", false),
+    (
+        "QuestionnaireResponse_Answer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("QuestionnaireResponse_Answer", "id", "id", "string", false),
+    (
+        "QuestionnaireResponse_Answer",
+        "item",
+        "item",
+        "QuestionnaireResponse_Item",
+        true,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueCoding",
+        "value",
+        "Coding",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueDate",
+        "value",
+        "date",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueTime",
+        "value",
+        "time",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Answer",
+        "valueUri",
+        "value",
+        "uri",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Item",
+        "answer",
+        "answer",
+        "QuestionnaireResponse_Answer",
+        true,
+    ),
+    (
+        "QuestionnaireResponse_Item",
+        "definition",
+        "definition",
+        "uri",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Item",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("QuestionnaireResponse_Item", "id", "id", "string", false),
+    (
+        "QuestionnaireResponse_Item",
+        "item",
+        "item",
+        "QuestionnaireResponse_Item",
+        true,
+    ),
+    (
+        "QuestionnaireResponse_Item",
+        "linkId",
+        "linkId",
+        "string",
+        false,
+    ),
+    (
+        "QuestionnaireResponse_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "QuestionnaireResponse_Item",
+        "text",
+        "text",
+        "string",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Questionnaire_AnswerOption", "id", "id", "string", false),
+    (
+        "Questionnaire_AnswerOption",
+        "initialSelected",
+        "initialSelected",
+        "boolean",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "valueCoding",
+        "value",
+        "Coding",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "valueDate",
+        "value",
+        "date",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "Questionnaire_AnswerOption",
+        "valueTime",
+        "value",
+        "time",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerBoolean",
+        "answer",
+        "boolean",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerCoding",
+        "answer",
+        "Coding",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerDate",
+        "answer",
+        "date",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerDateTime",
+        "answer",
+        "dateTime",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerDecimal",
+        "answer",
+        "decimal",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerInteger",
+        "answer",
+        "integer",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerQuantity",
+        "answer",
+        "Quantity",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerReference",
+        "answer",
+        "Reference",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerString",
+        "answer",
+        "string",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "answerTime",
+        "answer",
+        "time",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Questionnaire_EnableWhen", "id", "id", "string", false),
+    (
+        "Questionnaire_EnableWhen",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "operator",
+        "operator",
+        "code",
+        false,
+    ),
+    (
+        "Questionnaire_EnableWhen",
+        "question",
+        "question",
+        "string",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Questionnaire_Initial", "id", "id", "string", false),
+    (
+        "Questionnaire_Initial",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueCoding",
+        "value",
+        "Coding",
+        false,
+    ),
+    ("Questionnaire_Initial", "valueDate", "value", "date", false),
+    (
+        "Questionnaire_Initial",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    (
+        "Questionnaire_Initial",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    ("Questionnaire_Initial", "valueTime", "value", "time", false),
+    ("Questionnaire_Initial", "valueUri", "value", "uri", false),
+    (
+        "Questionnaire_Item",
+        "answerOption",
+        "answerOption",
+        "Questionnaire_AnswerOption",
+        true,
+    ),
+    (
+        "Questionnaire_Item",
+        "answerValueSet",
+        "answerValueSet",
+        "canonical",
+        false,
+    ),
+    ("Questionnaire_Item", "code", "code", "Coding", true),
+    (
+        "Questionnaire_Item",
+        "definition",
+        "definition",
+        "uri",
+        false,
+    ),
+    (
+        "Questionnaire_Item",
+        "enableBehavior",
+        "enableBehavior",
+        "code",
+        false,
+    ),
+    (
+        "Questionnaire_Item",
+        "enableWhen",
+        "enableWhen",
+        "Questionnaire_EnableWhen",
+        true,
+    ),
+    (
+        "Questionnaire_Item",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Questionnaire_Item", "id", "id", "string", false),
+    (
+        "Questionnaire_Item",
+        "initial",
+        "initial",
+        "Questionnaire_Initial",
+        true,
+    ),
+    (
+        "Questionnaire_Item",
+        "item",
+        "item",
+        "Questionnaire_Item",
+        true,
+    ),
+    ("Questionnaire_Item", "linkId", "linkId", "string", false),
+    (
+        "Questionnaire_Item",
+        "maxLength",
+        "maxLength",
+        "integer",
+        false,
+    ),
+    (
+        "Questionnaire_Item",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Questionnaire_Item", "prefix", "prefix", "string", false),
+    (
+        "Questionnaire_Item",
+        "readOnly",
+        "readOnly",
+        "boolean",
+        false,
+    ),
+    ("Questionnaire_Item", "repeats", "repeats", "boolean", false),
+    (
+        "Questionnaire_Item",
+        "required",
+        "required",
+        "boolean",
+        false,
+    ),
+    ("Questionnaire_Item", "text", "text", "string", false),
+    ("Questionnaire_Item", "type", "type", "code", false),
+    ("Range", "extension", "extension", "Extension", true),
+    ("Range", "high", "high", "Quantity", false),
+    ("Range", "id", "id", "string", false),
+    ("Range", "low", "low", "Quantity", false),
+    ("Ratio", "denominator", "denominator", "Quantity", false),
+    ("Ratio", "extension", "extension", "Extension", true),
+    ("Ratio", "id", "id", "string", false),
+    ("Ratio", "numerator", "numerator", "Quantity", false),
+    ("Reference", "display", "display", "string", false),
+    ("Reference", "extension", "extension", "Extension", true),
+    ("Reference", "id", "id", "string", false),
+    ("Reference", "identifier", "identifier", "Identifier", false),
+    ("Reference", "reference", "reference", "string", false),
+    ("Reference", "type", "type", "uri", false),
+    ("RelatedArtifact", "citation", "citation", "markdown", false),
+    ("RelatedArtifact", "display", "display", "string", false),
+    (
+        "RelatedArtifact",
+        "document",
+        "document",
+        "Attachment",
+        false,
+    ),
+    (
+        "RelatedArtifact",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RelatedArtifact", "id", "id", "string", false),
+    ("RelatedArtifact", "label", "label", "string", false),
+    (
+        "RelatedArtifact",
+        "resource",
+        "resource",
+        "canonical",
+        false,
+    ),
+    ("RelatedArtifact", "type", "type", "code", false),
+    ("RelatedArtifact", "url", "url", "url", false),
+    ("RelatedPerson", "active", "active", "boolean", false),
+    ("RelatedPerson", "address", "address", "Address", true),
+    ("RelatedPerson", "birthDate", "birthDate", "date", false),
+    (
+        "RelatedPerson",
+        "communication",
+        "communication",
+        "RelatedPerson_Communication",
+        true,
+    ),
+    (
+        "RelatedPerson",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("RelatedPerson", "extension", "extension", "Extension", true),
+    ("RelatedPerson", "gender", "gender", "code", false),
+    ("RelatedPerson", "id", "id", "id", false),
+    (
+        "RelatedPerson",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "RelatedPerson",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("RelatedPerson", "language", "language", "code", false),
+    ("RelatedPerson", "meta", "meta", "Meta", false),
+    (
+        "RelatedPerson",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("RelatedPerson", "name", "name", "HumanName", true),
+    ("RelatedPerson", "patient", "patient", "Reference", false),
+    ("RelatedPerson", "period", "period", "Period", false),
+    ("RelatedPerson", "photo", "photo", "Attachment", true),
+    (
+        "RelatedPerson",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        true,
+    ),
+    ("RelatedPerson", "telecom", "telecom", "ContactPoint", true),
+    ("RelatedPerson", "text", "text", "Narrative", false),
+    (
+        "RelatedPerson_Communication",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RelatedPerson_Communication", "id", "id", "string", false),
+    (
+        "RelatedPerson_Communication",
+        "language",
+        "language",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RelatedPerson_Communication",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RelatedPerson_Communication",
+        "preferred",
+        "preferred",
+        "boolean",
+        false,
+    ),
+    (
+        "RequestGroup",
+        "action",
+        "action",
+        "RequestGroup_Action",
+        true,
+    ),
+    ("RequestGroup", "author", "author", "Reference", false),
+    (
+        "RequestGroup",
+        "authoredOn",
+        "authoredOn",
+        "dateTime",
+        false,
+    ),
+    ("RequestGroup", "basedOn", "basedOn", "Reference", true),
+    ("RequestGroup", "code", "code", "CodeableConcept", false),
+    (
+        "RequestGroup",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("RequestGroup", "encounter", "encounter", "Reference", false),
+    ("RequestGroup", "extension", "extension", "Extension", true),
+    (
+        "RequestGroup",
+        "groupIdentifier",
+        "groupIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("RequestGroup", "id", "id", "id", false),
+    (
+        "RequestGroup",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "RequestGroup",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "RequestGroup",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "RequestGroup",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    ("RequestGroup", "intent", "intent", "code", false),
+    ("RequestGroup", "language", "language", "code", false),
+    ("RequestGroup", "meta", "meta", "Meta", false),
+    (
+        "RequestGroup",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("RequestGroup", "note", "note", "Annotation", true),
+    ("RequestGroup", "priority", "priority", "code", false),
+    (
+        "RequestGroup",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "RequestGroup",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("RequestGroup", "replaces", "replaces", "Reference", true),
+    ("RequestGroup", "status", "status", "code", false),
+    ("RequestGroup", "subject", "subject", "Reference", false),
+    ("RequestGroup", "text", "text", "Narrative", false),
+    (
+        "RequestGroup_Action",
+        "action",
+        "action",
+        "RequestGroup_Action",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "cardinalityBehavior",
+        "cardinalityBehavior",
+        "code",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "code",
+        "code",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "condition",
+        "condition",
+        "RequestGroup_Condition",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "documentation",
+        "documentation",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "groupingBehavior",
+        "groupingBehavior",
+        "code",
+        false,
+    ),
+    ("RequestGroup_Action", "id", "id", "string", false),
+    (
+        "RequestGroup_Action",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "participant",
+        "participant",
+        "Reference",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "precheckBehavior",
+        "precheckBehavior",
+        "code",
+        false,
+    ),
+    ("RequestGroup_Action", "prefix", "prefix", "string", false),
+    ("RequestGroup_Action", "priority", "priority", "code", false),
+    (
+        "RequestGroup_Action",
+        "relatedAction",
+        "relatedAction",
+        "RequestGroup_RelatedAction",
+        true,
+    ),
+    (
+        "RequestGroup_Action",
+        "requiredBehavior",
+        "requiredBehavior",
+        "code",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "resource",
+        "resource",
+        "Reference",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "selectionBehavior",
+        "selectionBehavior",
+        "code",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "textEquivalent",
+        "textEquivalent",
+        "string",
+        false,
+    ),
+    ("RequestGroup_Action", "timingAge", "timing", "Age", false),
+    (
+        "RequestGroup_Action",
+        "timingDateTime",
+        "timing",
+        "dateTime",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "timingDuration",
+        "timing",
+        "Duration",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "timingPeriod",
+        "timing",
+        "Period",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "timingRange",
+        "timing",
+        "Range",
+        false,
+    ),
+    (
+        "RequestGroup_Action",
+        "timingTiming",
+        "timing",
+        "Timing",
+        false,
+    ),
+    ("RequestGroup_Action", "title", "title", "string", false),
+    (
+        "RequestGroup_Action",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RequestGroup_Condition",
+        "expression",
+        "expression",
+        "Expression",
+        false,
+    ),
+    (
+        "RequestGroup_Condition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RequestGroup_Condition", "id", "id", "string", false),
+    ("RequestGroup_Condition", "kind", "kind", "code", false),
+    (
+        "RequestGroup_Condition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RequestGroup_RelatedAction",
+        "actionId",
+        "actionId",
+        "id",
+        false,
+    ),
+    (
+        "RequestGroup_RelatedAction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RequestGroup_RelatedAction", "id", "id", "string", false),
+    (
+        "RequestGroup_RelatedAction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RequestGroup_RelatedAction",
+        "offsetDuration",
+        "offset",
+        "Duration",
+        false,
+    ),
+    (
+        "RequestGroup_RelatedAction",
+        "offsetRange",
+        "offset",
+        "Range",
+        false,
+    ),
+    (
+        "RequestGroup_RelatedAction",
+        "relationship",
+        "relationship",
+        "code",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "author",
+        "author",
+        "ContactDetail",
+        true,
+    ),
+    ("ResearchDefinition", "comment", "comment", "string", true),
+    (
+        "ResearchDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("ResearchDefinition", "date", "date", "dateTime", false),
+    (
+        "ResearchDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "editor",
+        "editor",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "exposure",
+        "exposure",
+        "Reference",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "exposureAlternative",
+        "exposureAlternative",
+        "Reference",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ResearchDefinition", "id", "id", "id", false),
+    (
+        "ResearchDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("ResearchDefinition", "language", "language", "code", false),
+    (
+        "ResearchDefinition",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "library",
+        "library",
+        "canonical",
+        true,
+    ),
+    ("ResearchDefinition", "meta", "meta", "Meta", false),
+    (
+        "ResearchDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ResearchDefinition", "name", "name", "string", false),
+    (
+        "ResearchDefinition",
+        "outcome",
+        "outcome",
+        "Reference",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "population",
+        "population",
+        "Reference",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchDefinition",
+        "shortTitle",
+        "shortTitle",
+        "string",
+        false,
+    ),
+    ("ResearchDefinition", "status", "status", "code", false),
+    (
+        "ResearchDefinition",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "ResearchDefinition",
+        "subtitle",
+        "subtitle",
+        "string",
+        false,
+    ),
+    ("ResearchDefinition", "text", "text", "Narrative", false),
+    ("ResearchDefinition", "title", "title", "string", false),
+    (
+        "ResearchDefinition",
+        "topic",
+        "topic",
+        "CodeableConcept",
+        true,
+    ),
+    ("ResearchDefinition", "url", "url", "uri", false),
+    ("ResearchDefinition", "usage", "usage", "string", false),
+    (
+        "ResearchDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("ResearchDefinition", "version", "version", "string", false),
+    (
+        "ResearchElementDefinition",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "author",
+        "author",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "characteristic",
+        "characteristic",
+        "ResearchElementDefinition_Characteristic",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "comment",
+        "comment",
+        "string",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "date",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "editor",
+        "editor",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ResearchElementDefinition", "id", "id", "id", false),
+    (
+        "ResearchElementDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "library",
+        "library",
+        "canonical",
+        true,
+    ),
+    ("ResearchElementDefinition", "meta", "meta", "Meta", false),
+    (
+        "ResearchElementDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ResearchElementDefinition", "name", "name", "string", false),
+    (
+        "ResearchElementDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "shortTitle",
+        "shortTitle",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "status",
+        "status",
+        "code",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "subjectCodeableConcept",
+        "subject",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "subjectReference",
+        "subject",
+        "Reference",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "subtitle",
+        "subtitle",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "title",
+        "title",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "topic",
+        "topic",
+        "CodeableConcept",
+        true,
+    ),
+    ("ResearchElementDefinition", "type", "type", "code", false),
+    ("ResearchElementDefinition", "url", "url", "uri", false),
+    (
+        "ResearchElementDefinition",
+        "usage",
+        "usage",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "ResearchElementDefinition",
+        "variableType",
+        "variableType",
+        "code",
+        false,
+    ),
+    (
+        "ResearchElementDefinition",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "definitionCanonical",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "definitionCodeableConcept",
+        "definition",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "definitionDataRequirement",
+        "definition",
+        "DataRequirement",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "definitionExpression",
+        "definition",
+        "Expression",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "exclude",
+        "exclude",
+        "boolean",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectiveDateTime",
+        "participantEffective",
+        "dateTime",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectiveDescription",
+        "participantEffectiveDescription",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectiveDuration",
+        "participantEffective",
+        "Duration",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectiveGroupMeasure",
+        "participantEffectiveGroupMeasure",
+        "code",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectivePeriod",
+        "participantEffective",
+        "Period",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectiveTimeFromStart",
+        "participantEffectiveTimeFromStart",
+        "Duration",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "participantEffectiveTiming",
+        "participantEffective",
+        "Timing",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectiveDateTime",
+        "studyEffective",
+        "dateTime",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectiveDescription",
+        "studyEffectiveDescription",
+        "string",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectiveDuration",
+        "studyEffective",
+        "Duration",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectiveGroupMeasure",
+        "studyEffectiveGroupMeasure",
+        "code",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectivePeriod",
+        "studyEffective",
+        "Period",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectiveTimeFromStart",
+        "studyEffectiveTimeFromStart",
+        "Duration",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "studyEffectiveTiming",
+        "studyEffective",
+        "Timing",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "unitOfMeasure",
+        "unitOfMeasure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchElementDefinition_Characteristic",
+        "usageContext",
+        "usageContext",
+        "UsageContext",
+        true,
+    ),
+    ("ResearchStudy", "arm", "arm", "ResearchStudy_Arm", true),
+    (
+        "ResearchStudy",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ResearchStudy",
+        "condition",
+        "condition",
+        "CodeableConcept",
+        true,
+    ),
+    ("ResearchStudy", "contact", "contact", "ContactDetail", true),
+    (
+        "ResearchStudy",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ResearchStudy",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "ResearchStudy",
+        "enrollment",
+        "enrollment",
+        "Reference",
+        true,
+    ),
+    ("ResearchStudy", "extension", "extension", "Extension", true),
+    ("ResearchStudy", "focus", "focus", "CodeableConcept", true),
+    ("ResearchStudy", "id", "id", "id", false),
+    (
+        "ResearchStudy",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ResearchStudy",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ResearchStudy",
+        "keyword",
+        "keyword",
+        "CodeableConcept",
+        true,
+    ),
+    ("ResearchStudy", "language", "language", "code", false),
+    (
+        "ResearchStudy",
+        "location",
+        "location",
+        "CodeableConcept",
+        true,
+    ),
+    ("ResearchStudy", "meta", "meta", "Meta", false),
+    (
+        "ResearchStudy",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ResearchStudy", "note", "note", "Annotation", true),
+    (
+        "ResearchStudy",
+        "objective",
+        "objective",
+        "ResearchStudy_Objective",
+        true,
+    ),
+    ("ResearchStudy", "partOf", "partOf", "Reference", true),
+    ("ResearchStudy", "period", "period", "Period", false),
+    ("ResearchStudy", "phase", "phase", "CodeableConcept", false),
+    (
+        "ResearchStudy",
+        "primaryPurposeType",
+        "primaryPurposeType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchStudy",
+        "principalInvestigator",
+        "principalInvestigator",
+        "Reference",
+        false,
+    ),
+    ("ResearchStudy", "protocol", "protocol", "Reference", true),
+    (
+        "ResearchStudy",
+        "reasonStopped",
+        "reasonStopped",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchStudy",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    ("ResearchStudy", "site", "site", "Reference", true),
+    ("ResearchStudy", "sponsor", "sponsor", "Reference", false),
+    ("ResearchStudy", "status", "status", "code", false),
+    ("ResearchStudy", "text", "text", "Narrative", false),
+    ("ResearchStudy", "title", "title", "string", false),
+    (
+        "ResearchStudy_Arm",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "ResearchStudy_Arm",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ResearchStudy_Arm", "id", "id", "string", false),
+    (
+        "ResearchStudy_Arm",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ResearchStudy_Arm", "name", "name", "string", false),
+    (
+        "ResearchStudy_Arm",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ResearchStudy_Objective",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ResearchStudy_Objective", "id", "id", "string", false),
+    (
+        "ResearchStudy_Objective",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ResearchStudy_Objective", "name", "name", "string", false),
+    (
+        "ResearchStudy_Objective",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    ("ResearchSubject", "actualArm", "actualArm", "string", false),
+    (
+        "ResearchSubject",
+        "assignedArm",
+        "assignedArm",
+        "string",
+        false,
+    ),
+    ("ResearchSubject", "consent", "consent", "Reference", false),
+    (
+        "ResearchSubject",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ResearchSubject",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ResearchSubject", "id", "id", "id", false),
+    (
+        "ResearchSubject",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ResearchSubject",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ResearchSubject",
+        "individual",
+        "individual",
+        "Reference",
+        false,
+    ),
+    ("ResearchSubject", "language", "language", "code", false),
+    ("ResearchSubject", "meta", "meta", "Meta", false),
+    (
+        "ResearchSubject",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ResearchSubject", "period", "period", "Period", false),
+    ("ResearchSubject", "status", "status", "code", false),
+    ("ResearchSubject", "study", "study", "Reference", false),
+    ("ResearchSubject", "text", "text", "Narrative", false),
+    ("RiskAssessment", "basedOn", "basedOn", "Reference", false),
+    ("RiskAssessment", "basis", "basis", "Reference", true),
+    ("RiskAssessment", "code", "code", "CodeableConcept", false),
+    (
+        "RiskAssessment",
+        "condition",
+        "condition",
+        "Reference",
+        false,
+    ),
+    (
+        "RiskAssessment",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "RiskAssessment",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "RiskAssessment",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RiskAssessment", "id", "id", "id", false),
+    (
+        "RiskAssessment",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "RiskAssessment",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("RiskAssessment", "language", "language", "code", false),
+    ("RiskAssessment", "meta", "meta", "Meta", false),
+    (
+        "RiskAssessment",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskAssessment",
+        "mitigation",
+        "mitigation",
+        "string",
+        false,
+    ),
+    (
+        "RiskAssessment",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("RiskAssessment", "note", "note", "Annotation", true),
+    (
+        "RiskAssessment",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "RiskAssessment",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    ("RiskAssessment", "parent", "parent", "Reference", false),
+    (
+        "RiskAssessment",
+        "performer",
+        "performer",
+        "Reference",
+        false,
+    ),
+    (
+        "RiskAssessment",
+        "prediction",
+        "prediction",
+        "RiskAssessment_Prediction",
+        true,
+    ),
+    (
+        "RiskAssessment",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "RiskAssessment",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    ("RiskAssessment", "status", "status", "code", false),
+    ("RiskAssessment", "subject", "subject", "Reference", false),
+    ("RiskAssessment", "text", "text", "Narrative", false),
+    (
+        "RiskAssessment_Prediction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RiskAssessment_Prediction", "id", "id", "string", false),
+    (
+        "RiskAssessment_Prediction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "outcome",
+        "outcome",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "probabilityDecimal",
+        "probability",
+        "decimal",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "probabilityRange",
+        "probability",
+        "Range",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "qualitativeRisk",
+        "qualitativeRisk",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "rationale",
+        "rationale",
+        "string",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "relativeRisk",
+        "relativeRisk",
+        "decimal",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "whenPeriod",
+        "when",
+        "Period",
+        false,
+    ),
+    (
+        "RiskAssessment_Prediction",
+        "whenRange",
+        "when",
+        "Range",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "approvalDate",
+        "approvalDate",
+        "date",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "author",
+        "author",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "certainty",
+        "certainty",
+        "RiskEvidenceSynthesis_Certainty",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("RiskEvidenceSynthesis", "date", "date", "dateTime", false),
+    (
+        "RiskEvidenceSynthesis",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "editor",
+        "editor",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "effectivePeriod",
+        "effectivePeriod",
+        "Period",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "endorser",
+        "endorser",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "exposure",
+        "exposure",
+        "Reference",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("RiskEvidenceSynthesis", "id", "id", "id", false),
+    (
+        "RiskEvidenceSynthesis",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "lastReviewDate",
+        "lastReviewDate",
+        "date",
+        false,
+    ),
+    ("RiskEvidenceSynthesis", "meta", "meta", "Meta", false),
+    (
+        "RiskEvidenceSynthesis",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("RiskEvidenceSynthesis", "name", "name", "string", false),
+    ("RiskEvidenceSynthesis", "note", "note", "Annotation", true),
+    (
+        "RiskEvidenceSynthesis",
+        "outcome",
+        "outcome",
+        "Reference",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "population",
+        "population",
+        "Reference",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "relatedArtifact",
+        "relatedArtifact",
+        "RelatedArtifact",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "reviewer",
+        "reviewer",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "riskEstimate",
+        "riskEstimate",
+        "RiskEvidenceSynthesis_RiskEstimate",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "sampleSize",
+        "sampleSize",
+        "RiskEvidenceSynthesis_SampleSize",
+        false,
+    ),
+    ("RiskEvidenceSynthesis", "status", "status", "code", false),
+    (
+        "RiskEvidenceSynthesis",
+        "studyType",
+        "studyType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "synthesisType",
+        "synthesisType",
+        "CodeableConcept",
+        false,
+    ),
+    ("RiskEvidenceSynthesis", "text", "text", "Narrative", false),
+    ("RiskEvidenceSynthesis", "title", "title", "string", false),
+    (
+        "RiskEvidenceSynthesis",
+        "topic",
+        "topic",
+        "CodeableConcept",
+        true,
+    ),
+    ("RiskEvidenceSynthesis", "url", "url", "uri", false),
+    (
+        "RiskEvidenceSynthesis",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_Certainty",
+        "certaintySubcomponent",
+        "certaintySubcomponent",
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_Certainty",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_Certainty",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_Certainty",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_Certainty",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_Certainty",
+        "rating",
+        "rating",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        "rating",
+        "rating",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_CertaintySubcomponent",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "from",
+        "from",
+        "decimal",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "level",
+        "level",
+        "decimal",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "to",
+        "to",
+        "decimal",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "denominatorCount",
+        "denominatorCount",
+        "integer",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "numeratorCount",
+        "numeratorCount",
+        "integer",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "precisionEstimate",
+        "precisionEstimate",
+        "RiskEvidenceSynthesis_PrecisionEstimate",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "unitOfMeasure",
+        "unitOfMeasure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_RiskEstimate",
+        "value",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_SampleSize",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_SampleSize",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_SampleSize",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_SampleSize",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "RiskEvidenceSynthesis_SampleSize",
+        "numberOfParticipants",
+        "numberOfParticipants",
+        "integer",
+        false,
+    ),
+    (
+        "RiskEvidenceSynthesis_SampleSize",
+        "numberOfStudies",
+        "numberOfStudies",
+        "integer",
+        false,
+    ),
+    ("SampledData", "data", "data", "string", false),
+    (
+        "SampledData",
+        "dimensions",
+        "dimensions",
+        "positiveInt",
+        false,
+    ),
+    ("SampledData", "extension", "extension", "Extension", true),
+    ("SampledData", "factor", "factor", "decimal", false),
+    ("SampledData", "id", "id", "string", false),
+    ("SampledData", "lowerLimit", "lowerLimit", "decimal", false),
+    ("SampledData", "origin", "origin", "Quantity", false),
+    ("SampledData", "period", "period", "decimal", false),
+    ("SampledData", "upperLimit", "upperLimit", "decimal", false),
+    ("Schedule", "active", "active", "boolean", false),
+    ("Schedule", "actor", "actor", "Reference", true),
+    ("Schedule", "comment", "comment", "string", false),
+    ("Schedule", "contained", "contained", "ResourceList", true),
+    ("Schedule", "extension", "extension", "Extension", true),
+    ("Schedule", "id", "id", "id", false),
+    ("Schedule", "identifier", "identifier", "Identifier", true),
+    ("Schedule", "implicitRules", "implicitRules", "uri", false),
+    ("Schedule", "language", "language", "code", false),
+    ("Schedule", "meta", "meta", "Meta", false),
+    (
+        "Schedule",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Schedule",
+        "planningHorizon",
+        "planningHorizon",
+        "Period",
+        false,
+    ),
+    (
+        "Schedule",
+        "serviceCategory",
+        "serviceCategory",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Schedule",
+        "serviceType",
+        "serviceType",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Schedule",
+        "specialty",
+        "specialty",
+        "CodeableConcept",
+        true,
+    ),
+    ("Schedule", "text", "text", "Narrative", false),
+    ("SearchParameter", "base", "base", "code", true),
+    ("SearchParameter", "chain", "chain", "string", true),
+    ("SearchParameter", "code", "code", "code", false),
+    ("SearchParameter", "comparator", "comparator", "code", true),
+    (
+        "SearchParameter",
+        "component",
+        "component",
+        "SearchParameter_Component",
+        true,
+    ),
+    (
+        "SearchParameter",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "SearchParameter",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("SearchParameter", "date", "date", "dateTime", false),
+    (
+        "SearchParameter",
+        "derivedFrom",
+        "derivedFrom",
+        "canonical",
+        false,
+    ),
+    (
+        "SearchParameter",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "SearchParameter",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "SearchParameter",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "SearchParameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SearchParameter", "id", "id", "id", false),
+    (
+        "SearchParameter",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "SearchParameter",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("SearchParameter", "language", "language", "code", false),
+    ("SearchParameter", "meta", "meta", "Meta", false),
+    ("SearchParameter", "modifier", "modifier", "code", true),
+    (
+        "SearchParameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SearchParameter",
+        "multipleAnd",
+        "multipleAnd",
+        "boolean",
+        false,
+    ),
+    (
+        "SearchParameter",
+        "multipleOr",
+        "multipleOr",
+        "boolean",
+        false,
+    ),
+    ("SearchParameter", "name", "name", "string", false),
+    ("SearchParameter", "publisher", "publisher", "string", false),
+    ("SearchParameter", "purpose", "purpose", "markdown", false),
+    ("SearchParameter", "status", "status", "code", false),
+    ("SearchParameter", "target", "target", "code", true),
+    ("SearchParameter", "text", "text", "Narrative", false),
+    ("SearchParameter", "type", "type", "code", false),
+    ("SearchParameter", "url", "url", "uri", false),
+    (
+        "SearchParameter",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("SearchParameter", "version", "version", "string", false),
+    ("SearchParameter", "xpath", "xpath", "string", false),
+    ("SearchParameter", "xpathUsage", "xpathUsage", "code", false),
+    (
+        "SearchParameter_Component",
+        "definition",
+        "definition",
+        "canonical",
+        false,
+    ),
+    (
+        "SearchParameter_Component",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "SearchParameter_Component",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SearchParameter_Component", "id", "id", "string", false),
+    (
+        "SearchParameter_Component",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "asNeededBoolean",
+        "asNeeded",
+        "boolean",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "asNeededCodeableConcept",
+        "asNeeded",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "authoredOn",
+        "authoredOn",
+        "dateTime",
+        false,
+    ),
+    ("ServiceRequest", "basedOn", "basedOn", "Reference", true),
+    (
+        "ServiceRequest",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "category",
+        "category",
+        "CodeableConcept",
+        true,
+    ),
+    ("ServiceRequest", "code", "code", "CodeableConcept", false),
+    (
+        "ServiceRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "doNotPerform",
+        "doNotPerform",
+        "boolean",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ServiceRequest", "id", "id", "id", false),
+    (
+        "ServiceRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "instantiatesCanonical",
+        "instantiatesCanonical",
+        "canonical",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "instantiatesUri",
+        "instantiatesUri",
+        "uri",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "insurance",
+        "insurance",
+        "Reference",
+        true,
+    ),
+    ("ServiceRequest", "intent", "intent", "code", false),
+    ("ServiceRequest", "language", "language", "code", false),
+    (
+        "ServiceRequest",
+        "locationCode",
+        "locationCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "locationReference",
+        "locationReference",
+        "Reference",
+        true,
+    ),
+    ("ServiceRequest", "meta", "meta", "Meta", false),
+    (
+        "ServiceRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ServiceRequest", "note", "note", "Annotation", true),
+    (
+        "ServiceRequest",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "occurrenceTiming",
+        "occurrence",
+        "Timing",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "orderDetail",
+        "orderDetail",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "patientInstruction",
+        "patientInstruction",
+        "string",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "performer",
+        "performer",
+        "Reference",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "performerType",
+        "performerType",
+        "CodeableConcept",
+        false,
+    ),
+    ("ServiceRequest", "priority", "priority", "code", false),
+    (
+        "ServiceRequest",
+        "quantityQuantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "quantityRange",
+        "quantity",
+        "Range",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "quantityRatio",
+        "quantity",
+        "Ratio",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "ServiceRequest",
+        "relevantHistory",
+        "relevantHistory",
+        "Reference",
+        true,
+    ),
+    ("ServiceRequest", "replaces", "replaces", "Reference", true),
+    (
+        "ServiceRequest",
+        "requester",
+        "requester",
+        "Reference",
+        false,
+    ),
+    (
+        "ServiceRequest",
+        "requisition",
+        "requisition",
+        "Identifier",
+        false,
+    ),
+    ("ServiceRequest", "specimen", "specimen", "Reference", true),
+    ("ServiceRequest", "status", "status", "code", false),
+    ("ServiceRequest", "subject", "subject", "Reference", false),
+    (
+        "ServiceRequest",
+        "supportingInfo",
+        "supportingInfo",
+        "Reference",
+        true,
+    ),
+    ("ServiceRequest", "text", "text", "Narrative", false),
+    ("Signature", "data", "data", "base64Binary", false),
+    ("Signature", "extension", "extension", "Extension", true),
+    ("Signature", "id", "id", "string", false),
+    ("Signature", "onBehalfOf", "onBehalfOf", "Reference", false),
+    ("Signature", "sigFormat", "sigFormat", "code", false),
+    ("Signature", "targetFormat", "targetFormat", "code", false),
+    ("Signature", "type", "type", "Coding", true),
+    ("Signature", "when", "when", "instant", false),
+    ("Signature", "who", "who", "Reference", false),
+    (
+        "Slot",
+        "appointmentType",
+        "appointmentType",
+        "CodeableConcept",
+        false,
+    ),
+    ("Slot", "comment", "comment", "string", false),
+    ("Slot", "contained", "contained", "ResourceList", true),
+    ("Slot", "end", "end", "instant", false),
+    ("Slot", "extension", "extension", "Extension", true),
+    ("Slot", "id", "id", "id", false),
+    ("Slot", "identifier", "identifier", "Identifier", true),
+    ("Slot", "implicitRules", "implicitRules", "uri", false),
+    ("Slot", "language", "language", "code", false),
+    ("Slot", "meta", "meta", "Meta", false),
+    (
+        "Slot",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Slot", "overbooked", "overbooked", "boolean", false),
+    ("Slot", "schedule", "schedule", "Reference", false),
+    (
+        "Slot",
+        "serviceCategory",
+        "serviceCategory",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "Slot",
+        "serviceType",
+        "serviceType",
+        "CodeableConcept",
+        true,
+    ),
+    ("Slot", "specialty", "specialty", "CodeableConcept", true),
+    ("Slot", "start", "start", "instant", false),
+    ("Slot", "status", "status", "code", false),
+    ("Slot", "text", "text", "Narrative", false),
+    (
+        "Specimen",
+        "accessionIdentifier",
+        "accessionIdentifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "Specimen",
+        "collection",
+        "collection",
+        "Specimen_Collection",
+        false,
+    ),
+    (
+        "Specimen",
+        "condition",
+        "condition",
+        "CodeableConcept",
+        true,
+    ),
+    ("Specimen", "contained", "contained", "ResourceList", true),
+    (
+        "Specimen",
+        "container",
+        "container",
+        "Specimen_Container",
+        true,
+    ),
+    ("Specimen", "extension", "extension", "Extension", true),
+    ("Specimen", "id", "id", "id", false),
+    ("Specimen", "identifier", "identifier", "Identifier", true),
+    ("Specimen", "implicitRules", "implicitRules", "uri", false),
+    ("Specimen", "language", "language", "code", false),
+    ("Specimen", "meta", "meta", "Meta", false),
+    (
+        "Specimen",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Specimen", "note", "note", "Annotation", true),
+    ("Specimen", "parent", "parent", "Reference", true),
+    (
+        "Specimen",
+        "processing",
+        "processing",
+        "Specimen_Processing",
+        true,
+    ),
+    (
+        "Specimen",
+        "receivedTime",
+        "receivedTime",
+        "dateTime",
+        false,
+    ),
+    ("Specimen", "request", "request", "Reference", true),
+    ("Specimen", "status", "status", "code", false),
+    ("Specimen", "subject", "subject", "Reference", false),
+    ("Specimen", "text", "text", "Narrative", false),
+    ("Specimen", "type", "type", "CodeableConcept", false),
+    (
+        "SpecimenDefinition",
+        "collection",
+        "collection",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SpecimenDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SpecimenDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SpecimenDefinition", "id", "id", "id", false),
+    (
+        "SpecimenDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "SpecimenDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("SpecimenDefinition", "language", "language", "code", false),
+    ("SpecimenDefinition", "meta", "meta", "Meta", false),
+    (
+        "SpecimenDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SpecimenDefinition",
+        "patientPreparation",
+        "patientPreparation",
+        "CodeableConcept",
+        true,
+    ),
+    ("SpecimenDefinition", "text", "text", "Narrative", false),
+    (
+        "SpecimenDefinition",
+        "timeAspect",
+        "timeAspect",
+        "string",
+        false,
+    ),
+    (
+        "SpecimenDefinition",
+        "typeCollected",
+        "typeCollected",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SpecimenDefinition",
+        "typeTested",
+        "typeTested",
+        "SpecimenDefinition_TypeTested",
+        true,
+    ),
+    (
+        "SpecimenDefinition_Additive",
+        "additiveCodeableConcept",
+        "additive",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Additive",
+        "additiveReference",
+        "additive",
+        "Reference",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Additive",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SpecimenDefinition_Additive", "id", "id", "string", false),
+    (
+        "SpecimenDefinition_Additive",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "additive",
+        "additive",
+        "SpecimenDefinition_Additive",
+        true,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "cap",
+        "cap",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "capacity",
+        "capacity",
+        "Quantity",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SpecimenDefinition_Container", "id", "id", "string", false),
+    (
+        "SpecimenDefinition_Container",
+        "material",
+        "material",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "minimumVolumeQuantity",
+        "minimumVolume",
+        "Quantity",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "minimumVolumeString",
+        "minimumVolume",
+        "string",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "preparation",
+        "preparation",
+        "string",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Container",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Handling",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SpecimenDefinition_Handling", "id", "id", "string", false),
+    (
+        "SpecimenDefinition_Handling",
+        "instruction",
+        "instruction",
+        "string",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Handling",
+        "maxDuration",
+        "maxDuration",
+        "Duration",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Handling",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SpecimenDefinition_Handling",
+        "temperatureQualifier",
+        "temperatureQualifier",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SpecimenDefinition_Handling",
+        "temperatureRange",
+        "temperatureRange",
+        "Range",
+        false,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "container",
+        "container",
+        "SpecimenDefinition_Container",
+        false,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "handling",
+        "handling",
+        "SpecimenDefinition_Handling",
+        true,
+    ),
+    ("SpecimenDefinition_TypeTested", "id", "id", "string", false),
+    (
+        "SpecimenDefinition_TypeTested",
+        "isDerived",
+        "isDerived",
+        "boolean",
+        false,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "preference",
+        "preference",
+        "code",
+        false,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "rejectionCriterion",
+        "rejectionCriterion",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "requirement",
+        "requirement",
+        "string",
+        false,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "retentionTime",
+        "retentionTime",
+        "Duration",
+        false,
+    ),
+    (
+        "SpecimenDefinition_TypeTested",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "bodySite",
+        "bodySite",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "collectedDateTime",
+        "collected",
+        "dateTime",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "collectedPeriod",
+        "collected",
+        "Period",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "collector",
+        "collector",
+        "Reference",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "duration",
+        "duration",
+        "Duration",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "Specimen_Collection",
+        "fastingStatusCodeableConcept",
+        "fastingStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "fastingStatusDuration",
+        "fastingStatus",
+        "Duration",
+        false,
+    ),
+    ("Specimen_Collection", "id", "id", "string", false),
+    (
+        "Specimen_Collection",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Collection",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Specimen_Collection",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "Specimen_Container",
+        "additiveCodeableConcept",
+        "additive",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Container",
+        "additiveReference",
+        "additive",
+        "Reference",
+        false,
+    ),
+    (
+        "Specimen_Container",
+        "capacity",
+        "capacity",
+        "Quantity",
+        false,
+    ),
+    (
+        "Specimen_Container",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "Specimen_Container",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Specimen_Container", "id", "id", "string", false),
+    (
+        "Specimen_Container",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "Specimen_Container",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Specimen_Container",
+        "specimenQuantity",
+        "specimenQuantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "Specimen_Container",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Processing",
+        "additive",
+        "additive",
+        "Reference",
+        true,
+    ),
+    (
+        "Specimen_Processing",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "Specimen_Processing",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Specimen_Processing", "id", "id", "string", false),
+    (
+        "Specimen_Processing",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Specimen_Processing",
+        "procedure",
+        "procedure",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Specimen_Processing",
+        "timeDateTime",
+        "time",
+        "dateTime",
+        false,
+    ),
+    ("Specimen_Processing", "timePeriod", "time", "Period", false),
+    (
+        "StructureDefinition",
+        "abstract",
+        "abstract",
+        "boolean",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "baseDefinition",
+        "baseDefinition",
+        "canonical",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "StructureDefinition",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "StructureDefinition",
+        "context",
+        "context",
+        "StructureDefinition_Context",
+        true,
+    ),
+    (
+        "StructureDefinition",
+        "contextInvariant",
+        "contextInvariant",
+        "string",
+        true,
+    ),
+    (
+        "StructureDefinition",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("StructureDefinition", "date", "date", "dateTime", false),
+    (
+        "StructureDefinition",
+        "derivation",
+        "derivation",
+        "code",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "differential",
+        "differential",
+        "StructureDefinition_Differential",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "StructureDefinition",
+        "fhirVersion",
+        "fhirVersion",
+        "code",
+        false,
+    ),
+    ("StructureDefinition", "id", "id", "id", false),
+    (
+        "StructureDefinition",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "StructureDefinition",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("StructureDefinition", "keyword", "keyword", "Coding", true),
+    ("StructureDefinition", "kind", "kind", "code", false),
+    ("StructureDefinition", "language", "language", "code", false),
+    (
+        "StructureDefinition",
+        "mapping",
+        "mapping",
+        "StructureDefinition_Mapping",
+        true,
+    ),
+    ("StructureDefinition", "meta", "meta", "Meta", false),
+    (
+        "StructureDefinition",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureDefinition", "name", "name", "string", false),
+    (
+        "StructureDefinition",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "StructureDefinition",
+        "snapshot",
+        "snapshot",
+        "StructureDefinition_Snapshot",
+        false,
+    ),
+    ("StructureDefinition", "status", "status", "code", false),
+    ("StructureDefinition", "text", "text", "Narrative", false),
+    ("StructureDefinition", "title", "title", "string", false),
+    ("StructureDefinition", "type", "type", "uri", false),
+    ("StructureDefinition", "url", "url", "uri", false),
+    (
+        "StructureDefinition",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("StructureDefinition", "version", "version", "string", false),
+    (
+        "StructureDefinition_Context",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "StructureDefinition_Context",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureDefinition_Context", "id", "id", "string", false),
+    (
+        "StructureDefinition_Context",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureDefinition_Context", "type", "type", "code", false),
+    (
+        "StructureDefinition_Differential",
+        "element",
+        "element",
+        "ElementDefinition",
+        true,
+    ),
+    (
+        "StructureDefinition_Differential",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "StructureDefinition_Differential",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "StructureDefinition_Differential",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "StructureDefinition_Mapping",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "StructureDefinition_Mapping",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureDefinition_Mapping", "id", "id", "string", false),
+    (
+        "StructureDefinition_Mapping",
+        "identity",
+        "identity",
+        "id",
+        false,
+    ),
+    (
+        "StructureDefinition_Mapping",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "StructureDefinition_Mapping",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    ("StructureDefinition_Mapping", "uri", "uri", "uri", false),
+    (
+        "StructureDefinition_Snapshot",
+        "element",
+        "element",
+        "ElementDefinition",
+        true,
+    ),
+    (
+        "StructureDefinition_Snapshot",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureDefinition_Snapshot", "id", "id", "string", false),
+    (
+        "StructureDefinition_Snapshot",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap", "contact", "contact", "ContactDetail", true),
+    (
+        "StructureMap",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("StructureMap", "copyright", "copyright", "markdown", false),
+    ("StructureMap", "date", "date", "dateTime", false),
+    (
+        "StructureMap",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "StructureMap",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    ("StructureMap", "extension", "extension", "Extension", true),
+    ("StructureMap", "group", "group", "StructureMap_Group", true),
+    ("StructureMap", "id", "id", "id", false),
+    (
+        "StructureMap",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "StructureMap",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("StructureMap", "import", "import", "canonical", true),
+    (
+        "StructureMap",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("StructureMap", "language", "language", "code", false),
+    ("StructureMap", "meta", "meta", "Meta", false),
+    (
+        "StructureMap",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap", "name", "name", "string", false),
+    ("StructureMap", "publisher", "publisher", "string", false),
+    ("StructureMap", "purpose", "purpose", "markdown", false),
+    ("StructureMap", "status", "status", "code", false),
+    (
+        "StructureMap",
+        "structure",
+        "structure",
+        "StructureMap_Structure",
+        true,
+    ),
+    ("StructureMap", "text", "text", "Narrative", false),
+    ("StructureMap", "title", "title", "string", false),
+    ("StructureMap", "url", "url", "uri", false),
+    (
+        "StructureMap",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    ("StructureMap", "version", "version", "string", false),
+    (
+        "StructureMap_Dependent",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Dependent", "id", "id", "string", false),
+    (
+        "StructureMap_Dependent",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Dependent", "name", "name", "id", false),
+    (
+        "StructureMap_Dependent",
+        "variable",
+        "variable",
+        "string",
+        true,
+    ),
+    (
+        "StructureMap_Group",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    ("StructureMap_Group", "extends", "extends", "id", false),
+    (
+        "StructureMap_Group",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Group", "id", "id", "string", false),
+    (
+        "StructureMap_Group",
+        "input",
+        "input",
+        "StructureMap_Input",
+        true,
+    ),
+    (
+        "StructureMap_Group",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Group", "name", "name", "id", false),
+    (
+        "StructureMap_Group",
+        "rule",
+        "rule",
+        "StructureMap_Rule",
+        true,
+    ),
+    ("StructureMap_Group", "typeMode", "typeMode", "code", false),
+    (
+        "StructureMap_Input",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "StructureMap_Input",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Input", "id", "id", "string", false),
+    ("StructureMap_Input", "mode", "mode", "code", false),
+    (
+        "StructureMap_Input",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Input", "name", "name", "id", false),
+    ("StructureMap_Input", "type", "type", "string", false),
+    (
+        "StructureMap_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Parameter", "id", "id", "string", false),
+    (
+        "StructureMap_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "StructureMap_Parameter",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "StructureMap_Parameter",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    ("StructureMap_Parameter", "valueId", "value", "id", false),
+    (
+        "StructureMap_Parameter",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "StructureMap_Parameter",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "StructureMap_Rule",
+        "dependent",
+        "dependent",
+        "StructureMap_Dependent",
+        true,
+    ),
+    (
+        "StructureMap_Rule",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "StructureMap_Rule",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Rule", "id", "id", "string", false),
+    (
+        "StructureMap_Rule",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Rule", "name", "name", "id", false),
+    (
+        "StructureMap_Rule",
+        "rule",
+        "rule",
+        "StructureMap_Rule",
+        true,
+    ),
+    (
+        "StructureMap_Rule",
+        "source",
+        "source",
+        "StructureMap_Source",
+        true,
+    ),
+    (
+        "StructureMap_Rule",
+        "target",
+        "target",
+        "StructureMap_Target",
+        true,
+    ),
+    ("StructureMap_Source", "check", "check", "string", false),
+    (
+        "StructureMap_Source",
+        "condition",
+        "condition",
+        "string",
+        false,
+    ),
+    ("StructureMap_Source", "context", "context", "id", false),
+    (
+        "StructureMap_Source",
+        "defaultValueAddress",
+        "defaultValue",
+        "Address",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueAge",
+        "defaultValue",
+        "Age",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueAnnotation",
+        "defaultValue",
+        "Annotation",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueAttachment",
+        "defaultValue",
+        "Attachment",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueBase64Binary",
+        "defaultValue",
+        "base64Binary",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueBoolean",
+        "defaultValue",
+        "boolean",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueCanonical",
+        "defaultValue",
+        "canonical",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueCode",
+        "defaultValue",
+        "code",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueCodeableConcept",
+        "defaultValue",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueCoding",
+        "defaultValue",
+        "Coding",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueContactDetail",
+        "defaultValue",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueContactPoint",
+        "defaultValue",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueContributor",
+        "defaultValue",
+        "Contributor",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueCount",
+        "defaultValue",
+        "Count",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDataRequirement",
+        "defaultValue",
+        "DataRequirement",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDate",
+        "defaultValue",
+        "date",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDateTime",
+        "defaultValue",
+        "dateTime",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDecimal",
+        "defaultValue",
+        "decimal",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDistance",
+        "defaultValue",
+        "Distance",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDosage",
+        "defaultValue",
+        "Dosage",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueDuration",
+        "defaultValue",
+        "Duration",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueExpression",
+        "defaultValue",
+        "Expression",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueHumanName",
+        "defaultValue",
+        "HumanName",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueId",
+        "defaultValue",
+        "id",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueIdentifier",
+        "defaultValue",
+        "Identifier",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueInstant",
+        "defaultValue",
+        "instant",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueInteger",
+        "defaultValue",
+        "integer",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueMarkdown",
+        "defaultValue",
+        "markdown",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueMeta",
+        "defaultValue",
+        "Meta",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueMoney",
+        "defaultValue",
+        "Money",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueOid",
+        "defaultValue",
+        "oid",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueParameterDefinition",
+        "defaultValue",
+        "ParameterDefinition",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValuePeriod",
+        "defaultValue",
+        "Period",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValuePositiveInt",
+        "defaultValue",
+        "positiveInt",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueQuantity",
+        "defaultValue",
+        "Quantity",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueRange",
+        "defaultValue",
+        "Range",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueRatio",
+        "defaultValue",
+        "Ratio",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueReference",
+        "defaultValue",
+        "Reference",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueRelatedArtifact",
+        "defaultValue",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueSampledData",
+        "defaultValue",
+        "SampledData",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueSignature",
+        "defaultValue",
+        "Signature",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueString",
+        "defaultValue",
+        "string",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueTime",
+        "defaultValue",
+        "time",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueTiming",
+        "defaultValue",
+        "Timing",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueTriggerDefinition",
+        "defaultValue",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueUnsignedInt",
+        "defaultValue",
+        "unsignedInt",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueUri",
+        "defaultValue",
+        "uri",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueUrl",
+        "defaultValue",
+        "url",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueUsageContext",
+        "defaultValue",
+        "UsageContext",
+        false,
+    ),
+    (
+        "StructureMap_Source",
+        "defaultValueUuid",
+        "defaultValue",
+        "uuid",
+        false,
+    ),
+    ("StructureMap_Source", "element", "element", "string", false),
+    (
+        "StructureMap_Source",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Source", "id", "id", "string", false),
+    ("StructureMap_Source", "listMode", "listMode", "code", false),
+    (
+        "StructureMap_Source",
+        "logMessage",
+        "logMessage",
+        "string",
+        false,
+    ),
+    ("StructureMap_Source", "max", "max", "string", false),
+    ("StructureMap_Source", "min", "min", "integer", false),
+    (
+        "StructureMap_Source",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Source", "type", "type", "string", false),
+    ("StructureMap_Source", "variable", "variable", "id", false),
+    ("StructureMap_Structure", "alias", "alias", "string", false),
+    (
+        "StructureMap_Structure",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "StructureMap_Structure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Structure", "id", "id", "string", false),
+    ("StructureMap_Structure", "mode", "mode", "code", false),
+    (
+        "StructureMap_Structure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Structure", "url", "url", "canonical", false),
+    ("StructureMap_Target", "context", "context", "id", false),
+    (
+        "StructureMap_Target",
+        "contextType",
+        "contextType",
+        "code",
+        false,
+    ),
+    ("StructureMap_Target", "element", "element", "string", false),
+    (
+        "StructureMap_Target",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("StructureMap_Target", "id", "id", "string", false),
+    ("StructureMap_Target", "listMode", "listMode", "code", true),
+    (
+        "StructureMap_Target",
+        "listRuleId",
+        "listRuleId",
+        "id",
+        false,
+    ),
+    (
+        "StructureMap_Target",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "StructureMap_Target",
+        "parameter",
+        "parameter",
+        "StructureMap_Parameter",
+        true,
+    ),
+    (
+        "StructureMap_Target",
+        "transform",
+        "transform",
+        "code",
+        false,
+    ),
+    ("StructureMap_Target", "variable", "variable", "id", false),
+    (
+        "Subscription",
+        "channel",
+        "channel",
+        "Subscription_Channel",
+        false,
+    ),
+    ("Subscription", "contact", "contact", "ContactPoint", true),
+    (
+        "Subscription",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    ("Subscription", "criteria", "criteria", "string", false),
+    ("Subscription", "end", "end", "instant", false),
+    ("Subscription", "error", "error", "string", false),
+    ("Subscription", "extension", "extension", "Extension", true),
+    ("Subscription", "id", "id", "id", false),
+    (
+        "Subscription",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("Subscription", "language", "language", "code", false),
+    ("Subscription", "meta", "meta", "Meta", false),
+    (
+        "Subscription",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Subscription", "reason", "reason", "string", false),
+    ("Subscription", "status", "status", "code", false),
+    ("Subscription", "text", "text", "Narrative", false),
+    ("Subscription_Channel", "endpoint", "endpoint", "url", false),
+    (
+        "Subscription_Channel",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Subscription_Channel", "header", "header", "string", true),
+    ("Subscription_Channel", "id", "id", "string", false),
+    (
+        "Subscription_Channel",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Subscription_Channel", "payload", "payload", "code", false),
+    ("Subscription_Channel", "type", "type", "code", false),
+    ("Substance", "category", "category", "CodeableConcept", true),
+    ("Substance", "code", "code", "CodeableConcept", false),
+    ("Substance", "contained", "contained", "ResourceList", true),
+    ("Substance", "description", "description", "string", false),
+    ("Substance", "extension", "extension", "Extension", true),
+    ("Substance", "id", "id", "id", false),
+    ("Substance", "identifier", "identifier", "Identifier", true),
+    ("Substance", "implicitRules", "implicitRules", "uri", false),
+    (
+        "Substance",
+        "ingredient",
+        "ingredient",
+        "Substance_Ingredient",
+        true,
+    ),
+    (
+        "Substance",
+        "instance",
+        "instance",
+        "Substance_Instance",
+        true,
+    ),
+    ("Substance", "language", "language", "code", false),
+    ("Substance", "meta", "meta", "Meta", false),
+    (
+        "Substance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Substance", "status", "status", "code", false),
+    ("Substance", "text", "text", "Narrative", false),
+    (
+        "SubstanceAmount",
+        "amountQuantity",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    ("SubstanceAmount", "amountRange", "amount", "Range", false),
+    ("SubstanceAmount", "amountString", "amount", "string", false),
+    (
+        "SubstanceAmount",
+        "amountText",
+        "amountText",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceAmount",
+        "amountType",
+        "amountType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceAmount",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceAmount", "id", "id", "string", false),
+    (
+        "SubstanceAmount",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceAmount",
+        "referenceRange",
+        "referenceRange",
+        "SubstanceAmount_ReferenceRange",
+        false,
+    ),
+    (
+        "SubstanceAmount_ReferenceRange",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceAmount_ReferenceRange",
+        "highLimit",
+        "highLimit",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceAmount_ReferenceRange",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceAmount_ReferenceRange",
+        "lowLimit",
+        "lowLimit",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceAmount_ReferenceRange",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "areaOfHybridisation",
+        "areaOfHybridisation",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceNucleicAcid", "id", "id", "id", false),
+    (
+        "SubstanceNucleicAcid",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("SubstanceNucleicAcid", "meta", "meta", "Meta", false),
+    (
+        "SubstanceNucleicAcid",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "numberOfSubunits",
+        "numberOfSubunits",
+        "integer",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "oligoNucleotideType",
+        "oligoNucleotideType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "sequenceType",
+        "sequenceType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid",
+        "subunit",
+        "subunit",
+        "SubstanceNucleicAcid_Subunit",
+        true,
+    ),
+    ("SubstanceNucleicAcid", "text", "text", "Narrative", false),
+    (
+        "SubstanceNucleicAcid_Linkage",
+        "connectivity",
+        "connectivity",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Linkage",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceNucleicAcid_Linkage", "id", "id", "string", false),
+    (
+        "SubstanceNucleicAcid_Linkage",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Linkage",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid_Linkage",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Linkage",
+        "residueSite",
+        "residueSite",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "fivePrime",
+        "fivePrime",
+        "CodeableConcept",
+        false,
+    ),
+    ("SubstanceNucleicAcid_Subunit", "id", "id", "string", false),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "length",
+        "length",
+        "integer",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "linkage",
+        "linkage",
+        "SubstanceNucleicAcid_Linkage",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "sequence",
+        "sequence",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "sequenceAttachment",
+        "sequenceAttachment",
+        "Attachment",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "subunit",
+        "subunit",
+        "integer",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "sugar",
+        "sugar",
+        "SubstanceNucleicAcid_Sugar",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid_Subunit",
+        "threePrime",
+        "threePrime",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Sugar",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceNucleicAcid_Sugar", "id", "id", "string", false),
+    (
+        "SubstanceNucleicAcid_Sugar",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Sugar",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceNucleicAcid_Sugar",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceNucleicAcid_Sugar",
+        "residueSite",
+        "residueSite",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer",
+        "class",
+        "class",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SubstancePolymer",
+        "copolymerConnectivity",
+        "copolymerConnectivity",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SubstancePolymer",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer",
+        "geometry",
+        "geometry",
+        "CodeableConcept",
+        false,
+    ),
+    ("SubstancePolymer", "id", "id", "id", false),
+    (
+        "SubstancePolymer",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("SubstancePolymer", "language", "language", "code", false),
+    ("SubstancePolymer", "meta", "meta", "Meta", false),
+    (
+        "SubstancePolymer",
+        "modification",
+        "modification",
+        "string",
+        true,
+    ),
+    (
+        "SubstancePolymer",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer",
+        "monomerSet",
+        "monomerSet",
+        "SubstancePolymer_MonomerSet",
+        true,
+    ),
+    (
+        "SubstancePolymer",
+        "repeat",
+        "repeat",
+        "SubstancePolymer_Repeat",
+        true,
+    ),
+    ("SubstancePolymer", "text", "text", "Narrative", false),
+    (
+        "SubstancePolymer_DegreeOfPolymerisation",
+        "amount",
+        "amount",
+        "SubstanceAmount",
+        false,
+    ),
+    (
+        "SubstancePolymer_DegreeOfPolymerisation",
+        "degree",
+        "degree",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer_DegreeOfPolymerisation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_DegreeOfPolymerisation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer_DegreeOfPolymerisation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_MonomerSet",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstancePolymer_MonomerSet", "id", "id", "string", false),
+    (
+        "SubstancePolymer_MonomerSet",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_MonomerSet",
+        "ratioType",
+        "ratioType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer_MonomerSet",
+        "startingMaterial",
+        "startingMaterial",
+        "SubstancePolymer_StartingMaterial",
+        true,
+    ),
+    (
+        "SubstancePolymer_Repeat",
+        "averageMolecularFormula",
+        "averageMolecularFormula",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer_Repeat",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstancePolymer_Repeat", "id", "id", "string", false),
+    (
+        "SubstancePolymer_Repeat",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_Repeat",
+        "numberOfUnits",
+        "numberOfUnits",
+        "integer",
+        false,
+    ),
+    (
+        "SubstancePolymer_Repeat",
+        "repeatUnit",
+        "repeatUnit",
+        "SubstancePolymer_RepeatUnit",
+        true,
+    ),
+    (
+        "SubstancePolymer_Repeat",
+        "repeatUnitAmountType",
+        "repeatUnitAmountType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "amount",
+        "amount",
+        "SubstanceAmount",
+        false,
+    ),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "degreeOfPolymerisation",
+        "degreeOfPolymerisation",
+        "SubstancePolymer_DegreeOfPolymerisation",
+        true,
+    ),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstancePolymer_RepeatUnit", "id", "id", "string", false),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "orientationOfPolymerisation",
+        "orientationOfPolymerisation",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "repeatUnit",
+        "repeatUnit",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer_RepeatUnit",
+        "structuralRepresentation",
+        "structuralRepresentation",
+        "SubstancePolymer_StructuralRepresentation",
+        true,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "amount",
+        "amount",
+        "SubstanceAmount",
+        false,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "isDefining",
+        "isDefining",
+        "boolean",
+        false,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "material",
+        "material",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_StartingMaterial",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstancePolymer_StructuralRepresentation",
+        "attachment",
+        "attachment",
+        "Attachment",
+        false,
+    ),
+    (
+        "SubstancePolymer_StructuralRepresentation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_StructuralRepresentation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer_StructuralRepresentation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstancePolymer_StructuralRepresentation",
+        "representation",
+        "representation",
+        "string",
+        false,
+    ),
+    (
+        "SubstancePolymer_StructuralRepresentation",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceProtein",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SubstanceProtein",
+        "disulfideLinkage",
+        "disulfideLinkage",
+        "string",
+        true,
+    ),
+    (
+        "SubstanceProtein",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceProtein", "id", "id", "id", false),
+    (
+        "SubstanceProtein",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("SubstanceProtein", "language", "language", "code", false),
+    ("SubstanceProtein", "meta", "meta", "Meta", false),
+    (
+        "SubstanceProtein",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceProtein",
+        "numberOfSubunits",
+        "numberOfSubunits",
+        "integer",
+        false,
+    ),
+    (
+        "SubstanceProtein",
+        "sequenceType",
+        "sequenceType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceProtein",
+        "subunit",
+        "subunit",
+        "SubstanceProtein_Subunit",
+        true,
+    ),
+    ("SubstanceProtein", "text", "text", "Narrative", false),
+    (
+        "SubstanceProtein_Subunit",
+        "cTerminalModification",
+        "cTerminalModification",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "cTerminalModificationId",
+        "cTerminalModificationId",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceProtein_Subunit", "id", "id", "string", false),
+    (
+        "SubstanceProtein_Subunit",
+        "length",
+        "length",
+        "integer",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "nTerminalModification",
+        "nTerminalModification",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "nTerminalModificationId",
+        "nTerminalModificationId",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "sequence",
+        "sequence",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "sequenceAttachment",
+        "sequenceAttachment",
+        "Attachment",
+        false,
+    ),
+    (
+        "SubstanceProtein_Subunit",
+        "subunit",
+        "subunit",
+        "integer",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "classification",
+        "classification",
+        "SubstanceReferenceInformation_Classification",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "gene",
+        "gene",
+        "SubstanceReferenceInformation_Gene",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "geneElement",
+        "geneElement",
+        "SubstanceReferenceInformation_GeneElement",
+        true,
+    ),
+    ("SubstanceReferenceInformation", "id", "id", "id", false),
+    (
+        "SubstanceReferenceInformation",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "meta",
+        "meta",
+        "Meta",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "target",
+        "target",
+        "SubstanceReferenceInformation_Target",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "classification",
+        "classification",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "domain",
+        "domain",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Classification",
+        "subtype",
+        "subtype",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Gene",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Gene",
+        "gene",
+        "gene",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Gene",
+        "geneSequenceOrigin",
+        "geneSequenceOrigin",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Gene",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Gene",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Gene",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_GeneElement",
+        "element",
+        "element",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_GeneElement",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_GeneElement",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_GeneElement",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_GeneElement",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_GeneElement",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "amountQuantity",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "amountRange",
+        "amount",
+        "Range",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "amountString",
+        "amount",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "amountType",
+        "amountType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "interaction",
+        "interaction",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "organism",
+        "organism",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "organismType",
+        "organismType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "target",
+        "target",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceReferenceInformation_Target",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "countryOfOrigin",
+        "countryOfOrigin",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "developmentStage",
+        "developmentStage",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "fractionDescription",
+        "fractionDescription",
+        "SubstanceSourceMaterial_FractionDescription",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "geographicalLocation",
+        "geographicalLocation",
+        "string",
+        true,
+    ),
+    ("SubstanceSourceMaterial", "id", "id", "id", false),
+    (
+        "SubstanceSourceMaterial",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("SubstanceSourceMaterial", "meta", "meta", "Meta", false),
+    (
+        "SubstanceSourceMaterial",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "organism",
+        "organism",
+        "SubstanceSourceMaterial_Organism",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "organismId",
+        "organismId",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "organismName",
+        "organismName",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "parentSubstanceId",
+        "parentSubstanceId",
+        "Identifier",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "parentSubstanceName",
+        "parentSubstanceName",
+        "string",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "partDescription",
+        "partDescription",
+        "SubstanceSourceMaterial_PartDescription",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "sourceMaterialClass",
+        "sourceMaterialClass",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "sourceMaterialState",
+        "sourceMaterialState",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "sourceMaterialType",
+        "sourceMaterialType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Author",
+        "authorDescription",
+        "authorDescription",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Author",
+        "authorType",
+        "authorType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Author",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Author",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Author",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_FractionDescription",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_FractionDescription",
+        "fraction",
+        "fraction",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_FractionDescription",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_FractionDescription",
+        "materialType",
+        "materialType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_FractionDescription",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "hybridType",
+        "hybridType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "maternalOrganismId",
+        "maternalOrganismId",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "maternalOrganismName",
+        "maternalOrganismName",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "paternalOrganismId",
+        "paternalOrganismId",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Hybrid",
+        "paternalOrganismName",
+        "paternalOrganismName",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "author",
+        "author",
+        "SubstanceSourceMaterial_Author",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "family",
+        "family",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "genus",
+        "genus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "hybrid",
+        "hybrid",
+        "SubstanceSourceMaterial_Hybrid",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "intraspecificDescription",
+        "intraspecificDescription",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "intraspecificType",
+        "intraspecificType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "organismGeneral",
+        "organismGeneral",
+        "SubstanceSourceMaterial_OrganismGeneral",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_Organism",
+        "species",
+        "species",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "class",
+        "class",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "kingdom",
+        "kingdom",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "order",
+        "order",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_OrganismGeneral",
+        "phylum",
+        "phylum",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_PartDescription",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_PartDescription",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_PartDescription",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSourceMaterial_PartDescription",
+        "part",
+        "part",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSourceMaterial_PartDescription",
+        "partLocation",
+        "partLocation",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "code",
+        "code",
+        "SubstanceSpecification_Code",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "domain",
+        "domain",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceSpecification", "id", "id", "id", false),
+    (
+        "SubstanceSpecification",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    ("SubstanceSpecification", "meta", "meta", "Meta", false),
+    (
+        "SubstanceSpecification",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "moiety",
+        "moiety",
+        "SubstanceSpecification_Moiety",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "molecularWeight",
+        "molecularWeight",
+        "SubstanceSpecification_MolecularWeight",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "name",
+        "name",
+        "SubstanceSpecification_Name",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "nucleicAcid",
+        "nucleicAcid",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "polymer",
+        "polymer",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "property",
+        "property",
+        "SubstanceSpecification_Property",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "protein",
+        "protein",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "referenceInformation",
+        "referenceInformation",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "relationship",
+        "relationship",
+        "SubstanceSpecification_Relationship",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceSpecification",
+        "sourceMaterial",
+        "sourceMaterial",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification",
+        "structure",
+        "structure",
+        "SubstanceSpecification_Structure",
+        false,
+    ),
+    ("SubstanceSpecification", "text", "text", "Narrative", false),
+    (
+        "SubstanceSpecification",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Code",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Code",
+        "comment",
+        "comment",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Code",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceSpecification_Code", "id", "id", "string", false),
+    (
+        "SubstanceSpecification_Code",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Code",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Code",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Code",
+        "statusDate",
+        "statusDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "halfLife",
+        "halfLife",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "molecularWeight",
+        "molecularWeight",
+        "SubstanceSpecification_MolecularWeight",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "name",
+        "name",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Isotope",
+        "substitution",
+        "substitution",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "amountQuantity",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "amountString",
+        "amount",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceSpecification_Moiety", "id", "id", "string", false),
+    (
+        "SubstanceSpecification_Moiety",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "molecularFormula",
+        "molecularFormula",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "opticalActivity",
+        "opticalActivity",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "role",
+        "role",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Moiety",
+        "stereochemistry",
+        "stereochemistry",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_MolecularWeight",
+        "amount",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceSpecification_MolecularWeight",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_MolecularWeight",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_MolecularWeight",
+        "method",
+        "method",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_MolecularWeight",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_MolecularWeight",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "domain",
+        "domain",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SubstanceSpecification_Name", "id", "id", "string", false),
+    (
+        "SubstanceSpecification_Name",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "language",
+        "language",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "official",
+        "official",
+        "SubstanceSpecification_Official",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "preferred",
+        "preferred",
+        "boolean",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "synonym",
+        "synonym",
+        "SubstanceSpecification_Name",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "translation",
+        "translation",
+        "SubstanceSpecification_Name",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Name",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Official",
+        "authority",
+        "authority",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Official",
+        "date",
+        "date",
+        "dateTime",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Official",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Official",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Official",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Official",
+        "status",
+        "status",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "amountQuantity",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "amountString",
+        "amount",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "definingSubstanceCodeableConcept",
+        "definingSubstance",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "definingSubstanceReference",
+        "definingSubstance",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Property",
+        "parameters",
+        "parameters",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "amountQuantity",
+        "amount",
+        "Quantity",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "amountRange",
+        "amount",
+        "Range",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "amountRatio",
+        "amount",
+        "Ratio",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "amountRatioLowLimit",
+        "amountRatioLowLimit",
+        "Ratio",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "amountString",
+        "amount",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "amountType",
+        "amountType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "isDefining",
+        "isDefining",
+        "boolean",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "relationship",
+        "relationship",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "substanceCodeableConcept",
+        "substance",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Relationship",
+        "substanceReference",
+        "substance",
+        "Reference",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Representation",
+        "attachment",
+        "attachment",
+        "Attachment",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Representation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Representation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Representation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Representation",
+        "representation",
+        "representation",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Representation",
+        "type",
+        "type",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "isotope",
+        "isotope",
+        "SubstanceSpecification_Isotope",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "molecularFormula",
+        "molecularFormula",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "molecularFormulaByMoiety",
+        "molecularFormulaByMoiety",
+        "string",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "molecularWeight",
+        "molecularWeight",
+        "SubstanceSpecification_MolecularWeight",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "opticalActivity",
+        "opticalActivity",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "representation",
+        "representation",
+        "SubstanceSpecification_Representation",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "source",
+        "source",
+        "Reference",
+        true,
+    ),
+    (
+        "SubstanceSpecification_Structure",
+        "stereochemistry",
+        "stereochemistry",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Substance_Ingredient",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Substance_Ingredient", "id", "id", "string", false),
+    (
+        "Substance_Ingredient",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Substance_Ingredient",
+        "quantity",
+        "quantity",
+        "Ratio",
+        false,
+    ),
+    (
+        "Substance_Ingredient",
+        "substanceCodeableConcept",
+        "substance",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "Substance_Ingredient",
+        "substanceReference",
+        "substance",
+        "Reference",
+        false,
+    ),
+    ("Substance_Instance", "expiry", "expiry", "dateTime", false),
+    (
+        "Substance_Instance",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Substance_Instance", "id", "id", "string", false),
+    (
+        "Substance_Instance",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    (
+        "Substance_Instance",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "Substance_Instance",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    ("SupplyDelivery", "basedOn", "basedOn", "Reference", true),
+    (
+        "SupplyDelivery",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SupplyDelivery",
+        "destination",
+        "destination",
+        "Reference",
+        false,
+    ),
+    (
+        "SupplyDelivery",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SupplyDelivery", "id", "id", "id", false),
+    (
+        "SupplyDelivery",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "SupplyDelivery",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("SupplyDelivery", "language", "language", "code", false),
+    ("SupplyDelivery", "meta", "meta", "Meta", false),
+    (
+        "SupplyDelivery",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SupplyDelivery",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "SupplyDelivery",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "SupplyDelivery",
+        "occurrenceTiming",
+        "occurrence",
+        "Timing",
+        false,
+    ),
+    ("SupplyDelivery", "partOf", "partOf", "Reference", true),
+    ("SupplyDelivery", "patient", "patient", "Reference", false),
+    ("SupplyDelivery", "receiver", "receiver", "Reference", true),
+    ("SupplyDelivery", "status", "status", "code", false),
+    (
+        "SupplyDelivery",
+        "suppliedItem",
+        "suppliedItem",
+        "SupplyDelivery_SuppliedItem",
+        false,
+    ),
+    ("SupplyDelivery", "supplier", "supplier", "Reference", false),
+    ("SupplyDelivery", "text", "text", "Narrative", false),
+    ("SupplyDelivery", "type", "type", "CodeableConcept", false),
+    (
+        "SupplyDelivery_SuppliedItem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SupplyDelivery_SuppliedItem", "id", "id", "string", false),
+    (
+        "SupplyDelivery_SuppliedItem",
+        "itemCodeableConcept",
+        "item",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SupplyDelivery_SuppliedItem",
+        "itemReference",
+        "item",
+        "Reference",
+        false,
+    ),
+    (
+        "SupplyDelivery_SuppliedItem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SupplyDelivery_SuppliedItem",
+        "quantity",
+        "quantity",
+        "Quantity",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "authoredOn",
+        "authoredOn",
+        "dateTime",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "category",
+        "category",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "SupplyRequest",
+        "deliverFrom",
+        "deliverFrom",
+        "Reference",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "deliverTo",
+        "deliverTo",
+        "Reference",
+        false,
+    ),
+    ("SupplyRequest", "extension", "extension", "Extension", true),
+    ("SupplyRequest", "id", "id", "id", false),
+    (
+        "SupplyRequest",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "SupplyRequest",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "itemCodeableConcept",
+        "item",
+        "CodeableConcept",
+        false,
+    ),
+    ("SupplyRequest", "itemReference", "item", "Reference", false),
+    ("SupplyRequest", "language", "language", "code", false),
+    ("SupplyRequest", "meta", "meta", "Meta", false),
+    (
+        "SupplyRequest",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SupplyRequest",
+        "occurrenceDateTime",
+        "occurrence",
+        "dateTime",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "occurrencePeriod",
+        "occurrence",
+        "Period",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "occurrenceTiming",
+        "occurrence",
+        "Timing",
+        false,
+    ),
+    (
+        "SupplyRequest",
+        "parameter",
+        "parameter",
+        "SupplyRequest_Parameter",
+        true,
+    ),
+    ("SupplyRequest", "priority", "priority", "code", false),
+    ("SupplyRequest", "quantity", "quantity", "Quantity", false),
+    (
+        "SupplyRequest",
+        "reasonCode",
+        "reasonCode",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "SupplyRequest",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        true,
+    ),
+    (
+        "SupplyRequest",
+        "requester",
+        "requester",
+        "Reference",
+        false,
+    ),
+    ("SupplyRequest", "status", "status", "code", false),
+    ("SupplyRequest", "supplier", "supplier", "Reference", true),
+    ("SupplyRequest", "text", "text", "Narrative", false),
+    (
+        "SupplyRequest_Parameter",
+        "code",
+        "code",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SupplyRequest_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("SupplyRequest_Parameter", "id", "id", "string", false),
+    (
+        "SupplyRequest_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "SupplyRequest_Parameter",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    (
+        "SupplyRequest_Parameter",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "SupplyRequest_Parameter",
+        "valueQuantity",
+        "value",
+        "Quantity",
+        false,
+    ),
+    (
+        "SupplyRequest_Parameter",
+        "valueRange",
+        "value",
+        "Range",
+        false,
+    ),
+    ("Task", "authoredOn", "authoredOn", "dateTime", false),
+    ("Task", "basedOn", "basedOn", "Reference", true),
+    (
+        "Task",
+        "businessStatus",
+        "businessStatus",
+        "CodeableConcept",
+        false,
+    ),
+    ("Task", "code", "code", "CodeableConcept", false),
+    ("Task", "contained", "contained", "ResourceList", true),
+    ("Task", "description", "description", "string", false),
+    ("Task", "encounter", "encounter", "Reference", false),
+    (
+        "Task",
+        "executionPeriod",
+        "executionPeriod",
+        "Period",
+        false,
+    ),
+    ("Task", "extension", "extension", "Extension", true),
+    ("Task", "focus", "focus", "Reference", false),
+    ("Task", "for", "for", "Reference", false),
+    (
+        "Task",
+        "groupIdentifier",
+        "groupIdentifier",
+        "Identifier",
+        false,
+    ),
+    ("Task", "id", "id", "id", false),
+    ("Task", "identifier", "identifier", "Identifier", true),
+    ("Task", "implicitRules", "implicitRules", "uri", false),
+    ("Task", "input", "input", "Task_Input", true),
+    (
+        "Task",
+        "instantiatesCanonical",
+        "instantiates",
+        "canonical",
+        false,
+    ),
+    ("Task", "instantiatesUri", "instantiates", "uri", false),
+    ("Task", "insurance", "insurance", "Reference", true),
+    ("Task", "intent", "intent", "code", false),
+    ("Task", "language", "language", "code", false),
+    ("Task", "lastModified", "lastModified", "dateTime", false),
+    ("Task", "location", "location", "Reference", false),
+    ("Task", "meta", "meta", "Meta", false),
+    (
+        "Task",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Task", "note", "note", "Annotation", true),
+    ("Task", "output", "output", "Task_Output", true),
+    ("Task", "owner", "owner", "Reference", false),
+    ("Task", "partOf", "partOf", "Reference", true),
+    (
+        "Task",
+        "performerType",
+        "performerType",
+        "CodeableConcept",
+        true,
+    ),
+    ("Task", "priority", "priority", "code", false),
+    ("Task", "reasonCode", "reasonCode", "CodeableConcept", false),
+    (
+        "Task",
+        "reasonReference",
+        "reasonReference",
+        "Reference",
+        false,
+    ),
+    (
+        "Task",
+        "relevantHistory",
+        "relevantHistory",
+        "Reference",
+        true,
+    ),
+    ("Task", "requester", "requester", "Reference", false),
+    (
+        "Task",
+        "restriction",
+        "restriction",
+        "Task_Restriction",
+        false,
+    ),
+    ("Task", "status", "status", "code", false),
+    (
+        "Task",
+        "statusReason",
+        "statusReason",
+        "CodeableConcept",
+        false,
+    ),
+    ("Task", "text", "text", "Narrative", false),
+    ("Task_Input", "extension", "extension", "Extension", true),
+    ("Task_Input", "id", "id", "string", false),
+    (
+        "Task_Input",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Task_Input", "type", "type", "CodeableConcept", false),
+    ("Task_Input", "valueAddress", "value", "Address", false),
+    ("Task_Input", "valueAge", "value", "Age", false),
+    (
+        "Task_Input",
+        "valueAnnotation",
+        "value",
+        "Annotation",
+        false,
+    ),
+    (
+        "Task_Input",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "Task_Input",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    ("Task_Input", "valueBoolean", "value", "boolean", false),
+    ("Task_Input", "valueCanonical", "value", "canonical", false),
+    ("Task_Input", "valueCode", "value", "code", false),
+    (
+        "Task_Input",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    ("Task_Input", "valueCoding", "value", "Coding", false),
+    (
+        "Task_Input",
+        "valueContactDetail",
+        "value",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "Task_Input",
+        "valueContactPoint",
+        "value",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "Task_Input",
+        "valueContributor",
+        "value",
+        "Contributor",
+        false,
+    ),
+    ("Task_Input", "valueCount", "value", "Count", false),
+    (
+        "Task_Input",
+        "valueDataRequirement",
+        "value",
+        "DataRequirement",
+        false,
+    ),
+    ("Task_Input", "valueDate", "value", "date", false),
+    ("Task_Input", "valueDateTime", "value", "dateTime", false),
+    ("Task_Input", "valueDecimal", "value", "decimal", false),
+    ("Task_Input", "valueDistance", "value", "Distance", false),
+    ("Task_Input", "valueDosage", "value", "Dosage", false),
+    ("Task_Input", "valueDuration", "value", "Duration", false),
+    (
+        "Task_Input",
+        "valueExpression",
+        "value",
+        "Expression",
+        false,
+    ),
+    ("Task_Input", "valueHumanName", "value", "HumanName", false),
+    ("Task_Input", "valueId", "value", "id", false),
+    (
+        "Task_Input",
+        "valueIdentifier",
+        "value",
+        "Identifier",
+        false,
+    ),
+    ("Task_Input", "valueInstant", "value", "instant", false),
+    ("Task_Input", "valueInteger", "value", "integer", false),
+    ("Task_Input", "valueMarkdown", "value", "markdown", false),
+    ("Task_Input", "valueMeta", "value", "Meta", false),
+    ("Task_Input", "valueMoney", "value", "Money", false),
+    ("Task_Input", "valueOid", "value", "oid", false),
+    (
+        "Task_Input",
+        "valueParameterDefinition",
+        "value",
+        "ParameterDefinition",
+        false,
+    ),
+    ("Task_Input", "valuePeriod", "value", "Period", false),
+    (
+        "Task_Input",
+        "valuePositiveInt",
+        "value",
+        "positiveInt",
+        false,
+    ),
+    ("Task_Input", "valueQuantity", "value", "Quantity", false),
+    ("Task_Input", "valueRange", "value", "Range", false),
+    ("Task_Input", "valueRatio", "value", "Ratio", false),
+    ("Task_Input", "valueReference", "value", "Reference", false),
+    (
+        "Task_Input",
+        "valueRelatedArtifact",
+        "value",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "Task_Input",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    ("Task_Input", "valueSignature", "value", "Signature", false),
+    ("Task_Input", "valueString", "value", "string", false),
+    ("Task_Input", "valueTime", "value", "time", false),
+    ("Task_Input", "valueTiming", "value", "Timing", false),
+    (
+        "Task_Input",
+        "valueTriggerDefinition",
+        "value",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "Task_Input",
+        "valueUnsignedInt",
+        "value",
+        "unsignedInt",
+        false,
+    ),
+    ("Task_Input", "valueUri", "value", "uri", false),
+    ("Task_Input", "valueUrl", "value", "url", false),
+    (
+        "Task_Input",
+        "valueUsageContext",
+        "value",
+        "UsageContext",
+        false,
+    ),
+    ("Task_Input", "valueUuid", "value", "uuid", false),
+    ("Task_Output", "extension", "extension", "Extension", true),
+    ("Task_Output", "id", "id", "string", false),
+    (
+        "Task_Output",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Task_Output", "type", "type", "CodeableConcept", false),
+    ("Task_Output", "valueAddress", "value", "Address", false),
+    ("Task_Output", "valueAge", "value", "Age", false),
+    (
+        "Task_Output",
+        "valueAnnotation",
+        "value",
+        "Annotation",
+        false,
+    ),
+    (
+        "Task_Output",
+        "valueAttachment",
+        "value",
+        "Attachment",
+        false,
+    ),
+    (
+        "Task_Output",
+        "valueBase64Binary",
+        "value",
+        "base64Binary",
+        false,
+    ),
+    ("Task_Output", "valueBoolean", "value", "boolean", false),
+    ("Task_Output", "valueCanonical", "value", "canonical", false),
+    ("Task_Output", "valueCode", "value", "code", false),
+    (
+        "Task_Output",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    ("Task_Output", "valueCoding", "value", "Coding", false),
+    (
+        "Task_Output",
+        "valueContactDetail",
+        "value",
+        "ContactDetail",
+        false,
+    ),
+    (
+        "Task_Output",
+        "valueContactPoint",
+        "value",
+        "ContactPoint",
+        false,
+    ),
+    (
+        "Task_Output",
+        "valueContributor",
+        "value",
+        "Contributor",
+        false,
+    ),
+    ("Task_Output", "valueCount", "value", "Count", false),
+    (
+        "Task_Output",
+        "valueDataRequirement",
+        "value",
+        "DataRequirement",
+        false,
+    ),
+    ("Task_Output", "valueDate", "value", "date", false),
+    ("Task_Output", "valueDateTime", "value", "dateTime", false),
+    ("Task_Output", "valueDecimal", "value", "decimal", false),
+    ("Task_Output", "valueDistance", "value", "Distance", false),
+    ("Task_Output", "valueDosage", "value", "Dosage", false),
+    ("Task_Output", "valueDuration", "value", "Duration", false),
+    (
+        "Task_Output",
+        "valueExpression",
+        "value",
+        "Expression",
+        false,
+    ),
+    ("Task_Output", "valueHumanName", "value", "HumanName", false),
+    ("Task_Output", "valueId", "value", "id", false),
+    (
+        "Task_Output",
+        "valueIdentifier",
+        "value",
+        "Identifier",
+        false,
+    ),
+    ("Task_Output", "valueInstant", "value", "instant", false),
+    ("Task_Output", "valueInteger", "value", "integer", false),
+    ("Task_Output", "valueMarkdown", "value", "markdown", false),
+    ("Task_Output", "valueMeta", "value", "Meta", false),
+    ("Task_Output", "valueMoney", "value", "Money", false),
+    ("Task_Output", "valueOid", "value", "oid", false),
+    (
+        "Task_Output",
+        "valueParameterDefinition",
+        "value",
+        "ParameterDefinition",
+        false,
+    ),
+    ("Task_Output", "valuePeriod", "value", "Period", false),
+    (
+        "Task_Output",
+        "valuePositiveInt",
+        "value",
+        "positiveInt",
+        false,
+    ),
+    ("Task_Output", "valueQuantity", "value", "Quantity", false),
+    ("Task_Output", "valueRange", "value", "Range", false),
+    ("Task_Output", "valueRatio", "value", "Ratio", false),
+    ("Task_Output", "valueReference", "value", "Reference", false),
+    (
+        "Task_Output",
+        "valueRelatedArtifact",
+        "value",
+        "RelatedArtifact",
+        false,
+    ),
+    (
+        "Task_Output",
+        "valueSampledData",
+        "value",
+        "SampledData",
+        false,
+    ),
+    ("Task_Output", "valueSignature", "value", "Signature", false),
+    ("Task_Output", "valueString", "value", "string", false),
+    ("Task_Output", "valueTime", "value", "time", false),
+    ("Task_Output", "valueTiming", "value", "Timing", false),
+    (
+        "Task_Output",
+        "valueTriggerDefinition",
+        "value",
+        "TriggerDefinition",
+        false,
+    ),
+    (
+        "Task_Output",
+        "valueUnsignedInt",
+        "value",
+        "unsignedInt",
+        false,
+    ),
+    ("Task_Output", "valueUri", "value", "uri", false),
+    ("Task_Output", "valueUrl", "value", "url", false),
+    (
+        "Task_Output",
+        "valueUsageContext",
+        "value",
+        "UsageContext",
+        false,
+    ),
+    ("Task_Output", "valueUuid", "value", "uuid", false),
+    (
+        "Task_Restriction",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("Task_Restriction", "id", "id", "string", false),
+    (
+        "Task_Restriction",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Task_Restriction", "period", "period", "Period", false),
+    (
+        "Task_Restriction",
+        "recipient",
+        "recipient",
+        "Reference",
+        true,
+    ),
+    (
+        "Task_Restriction",
+        "repetitions",
+        "repetitions",
+        "positiveInt",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "closure",
+        "closure",
+        "TerminologyCapabilities_Closure",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "codeSearch",
+        "codeSearch",
+        "code",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "codeSystem",
+        "codeSystem",
+        "TerminologyCapabilities_CodeSystem",
+        true,
+    ),
+    (
+        "TerminologyCapabilities",
+        "contact",
+        "contact",
+        "ContactDetail",
+        true,
+    ),
+    (
+        "TerminologyCapabilities",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "TerminologyCapabilities",
+        "copyright",
+        "copyright",
+        "markdown",
+        false,
+    ),
+    ("TerminologyCapabilities", "date", "date", "dateTime", false),
+    (
+        "TerminologyCapabilities",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "expansion",
+        "expansion",
+        "TerminologyCapabilities_Expansion",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TerminologyCapabilities", "id", "id", "id", false),
+    (
+        "TerminologyCapabilities",
+        "implementation",
+        "implementation",
+        "TerminologyCapabilities_Implementation",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("TerminologyCapabilities", "kind", "kind", "code", false),
+    (
+        "TerminologyCapabilities",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "lockedDate",
+        "lockedDate",
+        "boolean",
+        false,
+    ),
+    ("TerminologyCapabilities", "meta", "meta", "Meta", false),
+    (
+        "TerminologyCapabilities",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TerminologyCapabilities", "name", "name", "string", false),
+    (
+        "TerminologyCapabilities",
+        "publisher",
+        "publisher",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "purpose",
+        "purpose",
+        "markdown",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "software",
+        "software",
+        "TerminologyCapabilities_Software",
+        false,
+    ),
+    ("TerminologyCapabilities", "status", "status", "code", false),
+    (
+        "TerminologyCapabilities",
+        "text",
+        "text",
+        "Narrative",
+        false,
+    ),
+    ("TerminologyCapabilities", "title", "title", "string", false),
+    (
+        "TerminologyCapabilities",
+        "translation",
+        "translation",
+        "TerminologyCapabilities_Translation",
+        false,
+    ),
+    ("TerminologyCapabilities", "url", "url", "uri", false),
+    (
+        "TerminologyCapabilities",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "TerminologyCapabilities",
+        "validateCode",
+        "validateCode",
+        "TerminologyCapabilities_ValidateCode",
+        false,
+    ),
+    (
+        "TerminologyCapabilities",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Closure",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Closure",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Closure",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Closure",
+        "translation",
+        "translation",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_CodeSystem",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_CodeSystem",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_CodeSystem",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_CodeSystem",
+        "subsumption",
+        "subsumption",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_CodeSystem",
+        "uri",
+        "uri",
+        "canonical",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_CodeSystem",
+        "version",
+        "version",
+        "TerminologyCapabilities_Version",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "hierarchical",
+        "hierarchical",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "incomplete",
+        "incomplete",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "paging",
+        "paging",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "parameter",
+        "parameter",
+        "TerminologyCapabilities_Parameter",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Expansion",
+        "textFilter",
+        "textFilter",
+        "markdown",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Filter",
+        "code",
+        "code",
+        "code",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Filter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Filter",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Filter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TerminologyCapabilities_Filter", "op", "op", "code", true),
+    (
+        "TerminologyCapabilities_Implementation",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Implementation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Implementation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Implementation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Implementation",
+        "url",
+        "url",
+        "url",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Parameter",
+        "documentation",
+        "documentation",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Parameter",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Parameter",
+        "name",
+        "name",
+        "code",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Software",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Software",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Software",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Software",
+        "name",
+        "name",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Software",
+        "version",
+        "version",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Translation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Translation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Translation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Translation",
+        "needsMap",
+        "needsMap",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_ValidateCode",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_ValidateCode",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_ValidateCode",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_ValidateCode",
+        "translations",
+        "translations",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "code",
+        "code",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "compositional",
+        "compositional",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "filter",
+        "filter",
+        "TerminologyCapabilities_Filter",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "isDefault",
+        "isDefault",
+        "boolean",
+        false,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "language",
+        "language",
+        "code",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TerminologyCapabilities_Version",
+        "property",
+        "property",
+        "code",
+        true,
+    ),
+    ("TestReport", "contained", "contained", "ResourceList", true),
+    ("TestReport", "extension", "extension", "Extension", true),
+    ("TestReport", "id", "id", "id", false),
+    (
+        "TestReport",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    ("TestReport", "implicitRules", "implicitRules", "uri", false),
+    ("TestReport", "issued", "issued", "dateTime", false),
+    ("TestReport", "language", "language", "code", false),
+    ("TestReport", "meta", "meta", "Meta", false),
+    (
+        "TestReport",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestReport", "name", "name", "string", false),
+    (
+        "TestReport",
+        "participant",
+        "participant",
+        "TestReport_Participant",
+        true,
+    ),
+    ("TestReport", "result", "result", "code", false),
+    ("TestReport", "score", "score", "decimal", false),
+    ("TestReport", "setup", "setup", "TestReport_Setup", false),
+    ("TestReport", "status", "status", "code", false),
+    (
+        "TestReport",
+        "teardown",
+        "teardown",
+        "TestReport_Teardown",
+        false,
+    ),
+    ("TestReport", "test", "test", "TestReport_Test", true),
+    ("TestReport", "testScript", "testScript", "Reference", false),
+    ("TestReport", "tester", "tester", "string", false),
+    ("TestReport", "text", "text", "Narrative", false),
+    (
+        "TestReport_Action",
+        "assert",
+        "assert",
+        "TestReport_Assert",
+        false,
+    ),
+    (
+        "TestReport_Action",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Action", "id", "id", "string", false),
+    (
+        "TestReport_Action",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestReport_Action",
+        "operation",
+        "operation",
+        "TestReport_Operation",
+        false,
+    ),
+    (
+        "TestReport_Action1",
+        "assert",
+        "assert",
+        "TestReport_Assert",
+        false,
+    ),
+    (
+        "TestReport_Action1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Action1", "id", "id", "string", false),
+    (
+        "TestReport_Action1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestReport_Action1",
+        "operation",
+        "operation",
+        "TestReport_Operation",
+        false,
+    ),
+    (
+        "TestReport_Action2",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Action2", "id", "id", "string", false),
+    (
+        "TestReport_Action2",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestReport_Action2",
+        "operation",
+        "operation",
+        "TestReport_Operation",
+        false,
+    ),
+    ("TestReport_Assert", "detail", "detail", "string", false),
+    (
+        "TestReport_Assert",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Assert", "id", "id", "string", false),
+    ("TestReport_Assert", "message", "message", "markdown", false),
+    (
+        "TestReport_Assert",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Assert", "result", "result", "code", false),
+    ("TestReport_Operation", "detail", "detail", "uri", false),
+    (
+        "TestReport_Operation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Operation", "id", "id", "string", false),
+    (
+        "TestReport_Operation",
+        "message",
+        "message",
+        "markdown",
+        false,
+    ),
+    (
+        "TestReport_Operation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Operation", "result", "result", "code", false),
+    (
+        "TestReport_Participant",
+        "display",
+        "display",
+        "string",
+        false,
+    ),
+    (
+        "TestReport_Participant",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Participant", "id", "id", "string", false),
+    (
+        "TestReport_Participant",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Participant", "type", "type", "code", false),
+    ("TestReport_Participant", "uri", "uri", "uri", false),
+    (
+        "TestReport_Setup",
+        "action",
+        "action",
+        "TestReport_Action",
+        true,
+    ),
+    (
+        "TestReport_Setup",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Setup", "id", "id", "string", false),
+    (
+        "TestReport_Setup",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestReport_Teardown",
+        "action",
+        "action",
+        "TestReport_Action2",
+        true,
+    ),
+    (
+        "TestReport_Teardown",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Teardown", "id", "id", "string", false),
+    (
+        "TestReport_Teardown",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestReport_Test",
+        "action",
+        "action",
+        "TestReport_Action1",
+        true,
+    ),
+    (
+        "TestReport_Test",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TestReport_Test",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Test", "id", "id", "string", false),
+    (
+        "TestReport_Test",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestReport_Test", "name", "name", "string", false),
+    ("TestScript", "contact", "contact", "ContactDetail", true),
+    ("TestScript", "contained", "contained", "ResourceList", true),
+    ("TestScript", "copyright", "copyright", "markdown", false),
+    ("TestScript", "date", "date", "dateTime", false),
+    (
+        "TestScript",
+        "description",
+        "description",
+        "markdown",
+        false,
+    ),
+    (
+        "TestScript",
+        "destination",
+        "destination",
+        "TestScript_Destination",
+        true,
+    ),
+    (
+        "TestScript",
+        "experimental",
+        "experimental",
+        "boolean",
+        false,
+    ),
+    ("TestScript", "extension", "extension", "Extension", true),
+    (
+        "TestScript",
+        "fixture",
+        "fixture",
+        "TestScript_Fixture",
+        true,
+    ),
+    ("TestScript", "id", "id", "id", false),
+    (
+        "TestScript",
+        "identifier",
+        "identifier",
+        "Identifier",
+        false,
+    ),
+    ("TestScript", "implicitRules", "implicitRules", "uri", false),
+    (
+        "TestScript",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("TestScript", "language", "language", "code", false),
+    ("TestScript", "meta", "meta", "Meta", false),
+    (
+        "TestScript",
+        "metadata",
+        "metadata",
+        "TestScript_Metadata",
+        false,
+    ),
+    (
+        "TestScript",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript", "name", "name", "string", false),
+    ("TestScript", "origin", "origin", "TestScript_Origin", true),
+    ("TestScript", "profile", "profile", "Reference", true),
+    ("TestScript", "publisher", "publisher", "string", false),
+    ("TestScript", "purpose", "purpose", "markdown", false),
+    ("TestScript", "setup", "setup", "TestScript_Setup", false),
+    ("TestScript", "status", "status", "code", false),
+    (
+        "TestScript",
+        "teardown",
+        "teardown",
+        "TestScript_Teardown",
+        false,
+    ),
+    ("TestScript", "test", "test", "TestScript_Test", true),
+    ("TestScript", "text", "text", "Narrative", false),
+    ("TestScript", "title", "title", "string", false),
+    ("TestScript", "url", "url", "uri", false),
+    (
+        "TestScript",
+        "useContext",
+        "useContext",
+        "UsageContext",
+        true,
+    ),
+    (
+        "TestScript",
+        "variable",
+        "variable",
+        "TestScript_Variable",
+        true,
+    ),
+    ("TestScript", "version", "version", "string", false),
+    (
+        "TestScript_Action",
+        "assert",
+        "assert",
+        "TestScript_Assert",
+        false,
+    ),
+    (
+        "TestScript_Action",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Action", "id", "id", "string", false),
+    (
+        "TestScript_Action",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Action",
+        "operation",
+        "operation",
+        "TestScript_Operation",
+        false,
+    ),
+    (
+        "TestScript_Action1",
+        "assert",
+        "assert",
+        "TestScript_Assert",
+        false,
+    ),
+    (
+        "TestScript_Action1",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Action1", "id", "id", "string", false),
+    (
+        "TestScript_Action1",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Action1",
+        "operation",
+        "operation",
+        "TestScript_Operation",
+        false,
+    ),
+    (
+        "TestScript_Action2",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Action2", "id", "id", "string", false),
+    (
+        "TestScript_Action2",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Action2",
+        "operation",
+        "operation",
+        "TestScript_Operation",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "compareToSourceExpression",
+        "compareToSourceExpression",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "compareToSourceId",
+        "compareToSourceId",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "compareToSourcePath",
+        "compareToSourcePath",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "contentType",
+        "contentType",
+        "code",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    ("TestScript_Assert", "direction", "direction", "code", false),
+    (
+        "TestScript_Assert",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Assert",
+        "headerField",
+        "headerField",
+        "string",
+        false,
+    ),
+    ("TestScript_Assert", "id", "id", "string", false),
+    ("TestScript_Assert", "label", "label", "string", false),
+    (
+        "TestScript_Assert",
+        "minimumId",
+        "minimumId",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Assert",
+        "navigationLinks",
+        "navigationLinks",
+        "boolean",
+        false,
+    ),
+    ("TestScript_Assert", "operator", "operator", "code", false),
+    ("TestScript_Assert", "path", "path", "string", false),
+    (
+        "TestScript_Assert",
+        "requestMethod",
+        "requestMethod",
+        "code",
+        false,
+    ),
+    (
+        "TestScript_Assert",
+        "requestURL",
+        "requestURL",
+        "string",
+        false,
+    ),
+    ("TestScript_Assert", "resource", "resource", "code", false),
+    ("TestScript_Assert", "response", "response", "code", false),
+    (
+        "TestScript_Assert",
+        "responseCode",
+        "responseCode",
+        "string",
+        false,
+    ),
+    ("TestScript_Assert", "sourceId", "sourceId", "id", false),
+    (
+        "TestScript_Assert",
+        "validateProfileId",
+        "validateProfileId",
+        "id",
+        false,
+    ),
+    ("TestScript_Assert", "value", "value", "string", false),
+    (
+        "TestScript_Assert",
+        "warningOnly",
+        "warningOnly",
+        "boolean",
+        false,
+    ),
+    (
+        "TestScript_Capability",
+        "capabilities",
+        "capabilities",
+        "canonical",
+        false,
+    ),
+    (
+        "TestScript_Capability",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Capability",
+        "destination",
+        "destination",
+        "integer",
+        false,
+    ),
+    (
+        "TestScript_Capability",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Capability", "id", "id", "string", false),
+    ("TestScript_Capability", "link", "link", "uri", true),
+    (
+        "TestScript_Capability",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Capability", "origin", "origin", "integer", true),
+    (
+        "TestScript_Capability",
+        "required",
+        "required",
+        "boolean",
+        false,
+    ),
+    (
+        "TestScript_Capability",
+        "validated",
+        "validated",
+        "boolean",
+        false,
+    ),
+    (
+        "TestScript_Destination",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Destination", "id", "id", "string", false),
+    ("TestScript_Destination", "index", "index", "integer", false),
+    (
+        "TestScript_Destination",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Destination",
+        "profile",
+        "profile",
+        "Coding",
+        false,
+    ),
+    (
+        "TestScript_Fixture",
+        "autocreate",
+        "autocreate",
+        "boolean",
+        false,
+    ),
+    (
+        "TestScript_Fixture",
+        "autodelete",
+        "autodelete",
+        "boolean",
+        false,
+    ),
+    (
+        "TestScript_Fixture",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Fixture", "id", "id", "string", false),
+    (
+        "TestScript_Fixture",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Fixture",
+        "resource",
+        "resource",
+        "Reference",
+        false,
+    ),
+    (
+        "TestScript_Link",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Link",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Link", "id", "id", "string", false),
+    (
+        "TestScript_Link",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Link", "url", "url", "uri", false),
+    (
+        "TestScript_Metadata",
+        "capability",
+        "capability",
+        "TestScript_Capability",
+        true,
+    ),
+    (
+        "TestScript_Metadata",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Metadata", "id", "id", "string", false),
+    (
+        "TestScript_Metadata",
+        "link",
+        "link",
+        "TestScript_Link",
+        true,
+    ),
+    (
+        "TestScript_Metadata",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Operation", "accept", "accept", "code", false),
+    (
+        "TestScript_Operation",
+        "contentType",
+        "contentType",
+        "code",
+        false,
+    ),
+    (
+        "TestScript_Operation",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Operation",
+        "destination",
+        "destination",
+        "integer",
+        false,
+    ),
+    (
+        "TestScript_Operation",
+        "encodeRequestUrl",
+        "encodeRequestUrl",
+        "boolean",
+        false,
+    ),
+    (
+        "TestScript_Operation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Operation", "id", "id", "string", false),
+    ("TestScript_Operation", "label", "label", "string", false),
+    ("TestScript_Operation", "method", "method", "code", false),
+    (
+        "TestScript_Operation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Operation", "origin", "origin", "integer", false),
+    ("TestScript_Operation", "params", "params", "string", false),
+    (
+        "TestScript_Operation",
+        "requestHeader",
+        "requestHeader",
+        "TestScript_RequestHeader",
+        true,
+    ),
+    (
+        "TestScript_Operation",
+        "requestId",
+        "requestId",
+        "id",
+        false,
+    ),
+    (
+        "TestScript_Operation",
+        "resource",
+        "resource",
+        "code",
+        false,
+    ),
+    (
+        "TestScript_Operation",
+        "responseId",
+        "responseId",
+        "id",
+        false,
+    ),
+    ("TestScript_Operation", "sourceId", "sourceId", "id", false),
+    ("TestScript_Operation", "targetId", "targetId", "id", false),
+    ("TestScript_Operation", "type", "type", "Coding", false),
+    ("TestScript_Operation", "url", "url", "string", false),
+    (
+        "TestScript_Origin",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Origin", "id", "id", "string", false),
+    ("TestScript_Origin", "index", "index", "integer", false),
+    (
+        "TestScript_Origin",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Origin", "profile", "profile", "Coding", false),
+    (
+        "TestScript_RequestHeader",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_RequestHeader",
+        "field",
+        "field",
+        "string",
+        false,
+    ),
+    ("TestScript_RequestHeader", "id", "id", "string", false),
+    (
+        "TestScript_RequestHeader",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_RequestHeader",
+        "value",
+        "value",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Setup",
+        "action",
+        "action",
+        "TestScript_Action",
+        true,
+    ),
+    (
+        "TestScript_Setup",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Setup", "id", "id", "string", false),
+    (
+        "TestScript_Setup",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Teardown",
+        "action",
+        "action",
+        "TestScript_Action2",
+        true,
+    ),
+    (
+        "TestScript_Teardown",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Teardown", "id", "id", "string", false),
+    (
+        "TestScript_Teardown",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Test",
+        "action",
+        "action",
+        "TestScript_Action1",
+        true,
+    ),
+    (
+        "TestScript_Test",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Test",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Test", "id", "id", "string", false),
+    (
+        "TestScript_Test",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Test", "name", "name", "string", false),
+    (
+        "TestScript_Variable",
+        "defaultValue",
+        "defaultValue",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Variable",
+        "description",
+        "description",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Variable",
+        "expression",
+        "expression",
+        "string",
+        false,
+    ),
+    (
+        "TestScript_Variable",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "TestScript_Variable",
+        "headerField",
+        "headerField",
+        "string",
+        false,
+    ),
+    ("TestScript_Variable", "hint", "hint", "string", false),
+    ("TestScript_Variable", "id", "id", "string", false),
+    (
+        "TestScript_Variable",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("TestScript_Variable", "name", "name", "string", false),
+    ("TestScript_Variable", "path", "path", "string", false),
+    ("TestScript_Variable", "sourceId", "sourceId", "id", false),
+    ("Timing", "code", "code", "CodeableConcept", false),
+    ("Timing", "event", "event", "dateTime", true),
+    ("Timing", "extension", "extension", "Extension", true),
+    ("Timing", "id", "id", "string", false),
+    (
+        "Timing",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Timing", "repeat", "repeat", "Timing_Repeat", false),
+    (
+        "Timing_Repeat",
+        "boundsDuration",
+        "bounds",
+        "Duration",
+        false,
+    ),
+    ("Timing_Repeat", "boundsPeriod", "bounds", "Period", false),
+    ("Timing_Repeat", "boundsRange", "bounds", "Range", false),
+    ("Timing_Repeat", "count", "count", "positiveInt", false),
+    (
+        "Timing_Repeat",
+        "countMax",
+        "countMax",
+        "positiveInt",
+        false,
+    ),
+    ("Timing_Repeat", "dayOfWeek", "dayOfWeek", "code", true),
+    ("Timing_Repeat", "duration", "duration", "decimal", false),
+    (
+        "Timing_Repeat",
+        "durationMax",
+        "durationMax",
+        "decimal",
+        false,
+    ),
+    (
+        "Timing_Repeat",
+        "durationUnit",
+        "durationUnit",
+        "code",
+        false,
+    ),
+    ("Timing_Repeat", "extension", "extension", "Extension", true),
+    (
+        "Timing_Repeat",
+        "frequency",
+        "frequency",
+        "positiveInt",
+        false,
+    ),
+    (
+        "Timing_Repeat",
+        "frequencyMax",
+        "frequencyMax",
+        "positiveInt",
+        false,
+    ),
+    ("Timing_Repeat", "id", "id", "string", false),
+    (
+        "Timing_Repeat",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("Timing_Repeat", "offset", "offset", "unsignedInt", false),
+    ("Timing_Repeat", "period", "period", "decimal", false),
+    ("Timing_Repeat", "periodMax", "periodMax", "decimal", false),
+    ("Timing_Repeat", "periodUnit", "periodUnit", "code", false),
+    ("Timing_Repeat", "timeOfDay", "timeOfDay", "time", true),
+    ("Timing_Repeat", "when", "when", "code", true),
+    (
+        "TriggerDefinition",
+        "condition",
+        "condition",
+        "Expression",
+        false,
+    ),
+    ("TriggerDefinition", "data", "data", "DataRequirement", true),
+    (
+        "TriggerDefinition",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("TriggerDefinition", "id", "id", "string", false),
+    ("TriggerDefinition", "name", "name", "string", false),
+    ("TriggerDefinition", "timingDate", "timing", "date", false),
+    (
+        "TriggerDefinition",
+        "timingDateTime",
+        "timing",
+        "dateTime",
+        false,
+    ),
+    (
+        "TriggerDefinition",
+        "timingReference",
+        "timing",
+        "Reference",
+        false,
+    ),
+    (
+        "TriggerDefinition",
+        "timingTiming",
+        "timing",
+        "Timing",
+        false,
+    ),
+    ("TriggerDefinition", "type", "type", "code", false),
+    ("UsageContext", "code", "code", "Coding", false),
+    ("UsageContext", "extension", "extension", "Extension", true),
+    ("UsageContext", "id", "id", "string", false),
+    (
+        "UsageContext",
+        "valueCodeableConcept",
+        "value",
+        "CodeableConcept",
+        false,
+    ),
+    ("UsageContext", "valueQuantity", "value", "Quantity", false),
+    ("UsageContext", "valueRange", "value", "Range", false),
+    (
+        "UsageContext",
+        "valueReference",
+        "value",
+        "Reference",
+        false,
+    ),
+    ("ValueSet", "compose", "compose", "ValueSet_Compose", false),
+    ("ValueSet", "contact", "contact", "ContactDetail", true),
+    ("ValueSet", "contained", "contained", "ResourceList", true),
+    ("ValueSet", "copyright", "copyright", "markdown", false),
+    ("ValueSet", "date", "date", "dateTime", false),
+    ("ValueSet", "description", "description", "markdown", false),
+    (
+        "ValueSet",
+        "expansion",
+        "expansion",
+        "ValueSet_Expansion",
+        false,
+    ),
+    ("ValueSet", "experimental", "experimental", "boolean", false),
+    ("ValueSet", "extension", "extension", "Extension", true),
+    ("ValueSet", "id", "id", "id", false),
+    ("ValueSet", "identifier", "identifier", "Identifier", true),
+    ("ValueSet", "immutable", "immutable", "boolean", false),
+    ("ValueSet", "implicitRules", "implicitRules", "uri", false),
+    (
+        "ValueSet",
+        "jurisdiction",
+        "jurisdiction",
+        "CodeableConcept",
+        true,
+    ),
+    ("ValueSet", "language", "language", "code", false),
+    ("ValueSet", "meta", "meta", "Meta", false),
+    (
+        "ValueSet",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet", "name", "name", "string", false),
+    ("ValueSet", "publisher", "publisher", "string", false),
+    ("ValueSet", "purpose", "purpose", "markdown", false),
+    ("ValueSet", "status", "status", "code", false),
+    ("ValueSet", "text", "text", "Narrative", false),
+    ("ValueSet", "title", "title", "string", false),
+    ("ValueSet", "url", "url", "uri", false),
+    ("ValueSet", "useContext", "useContext", "UsageContext", true),
+    ("ValueSet", "version", "version", "string", false),
+    (
+        "ValueSet_Compose",
+        "exclude",
+        "exclude",
+        "ValueSet_Include",
+        true,
+    ),
+    (
+        "ValueSet_Compose",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Compose", "id", "id", "string", false),
+    ("ValueSet_Compose", "inactive", "inactive", "boolean", false),
+    (
+        "ValueSet_Compose",
+        "include",
+        "include",
+        "ValueSet_Include",
+        true,
+    ),
+    (
+        "ValueSet_Compose",
+        "lockedDate",
+        "lockedDate",
+        "date",
+        false,
+    ),
+    (
+        "ValueSet_Compose",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Concept", "code", "code", "code", false),
+    (
+        "ValueSet_Concept",
+        "designation",
+        "designation",
+        "ValueSet_Designation",
+        true,
+    ),
+    ("ValueSet_Concept", "display", "display", "string", false),
+    (
+        "ValueSet_Concept",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Concept", "id", "id", "string", false),
+    (
+        "ValueSet_Concept",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "ValueSet_Contains",
+        "abstract",
+        "abstract",
+        "boolean",
+        false,
+    ),
+    ("ValueSet_Contains", "code", "code", "code", false),
+    (
+        "ValueSet_Contains",
+        "contains",
+        "contains",
+        "ValueSet_Contains",
+        true,
+    ),
+    (
+        "ValueSet_Contains",
+        "designation",
+        "designation",
+        "ValueSet_Designation",
+        true,
+    ),
+    ("ValueSet_Contains", "display", "display", "string", false),
+    (
+        "ValueSet_Contains",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Contains", "id", "id", "string", false),
+    (
+        "ValueSet_Contains",
+        "inactive",
+        "inactive",
+        "boolean",
+        false,
+    ),
+    (
+        "ValueSet_Contains",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Contains", "system", "system", "uri", false),
+    ("ValueSet_Contains", "version", "version", "string", false),
+    (
+        "ValueSet_Designation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Designation", "id", "id", "string", false),
+    (
+        "ValueSet_Designation",
+        "language",
+        "language",
+        "code",
+        false,
+    ),
+    (
+        "ValueSet_Designation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Designation", "use", "use", "Coding", false),
+    ("ValueSet_Designation", "value", "value", "string", false),
+    (
+        "ValueSet_Expansion",
+        "contains",
+        "contains",
+        "ValueSet_Contains",
+        true,
+    ),
+    (
+        "ValueSet_Expansion",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Expansion", "id", "id", "string", false),
+    (
+        "ValueSet_Expansion",
+        "identifier",
+        "identifier",
+        "uri",
+        false,
+    ),
+    (
+        "ValueSet_Expansion",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Expansion", "offset", "offset", "integer", false),
+    (
+        "ValueSet_Expansion",
+        "parameter",
+        "parameter",
+        "ValueSet_Parameter",
+        true,
+    ),
+    (
+        "ValueSet_Expansion",
+        "timestamp",
+        "timestamp",
+        "dateTime",
+        false,
+    ),
+    ("ValueSet_Expansion", "total", "total", "integer", false),
+    (
+        "ValueSet_Filter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Filter", "id", "id", "string", false),
+    (
+        "ValueSet_Filter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Filter", "op", "op", "code", false),
+    ("ValueSet_Filter", "property", "property", "code", false),
+    ("ValueSet_Filter", "value", "value", "string", false),
+    (
+        "ValueSet_Include",
+        "concept",
+        "concept",
+        "ValueSet_Concept",
+        true,
+    ),
+    (
+        "ValueSet_Include",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "ValueSet_Include",
+        "filter",
+        "filter",
+        "ValueSet_Filter",
+        true,
+    ),
+    ("ValueSet_Include", "id", "id", "string", false),
+    (
+        "ValueSet_Include",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Include", "system", "system", "uri", false),
+    (
+        "ValueSet_Include",
+        "valueSet",
+        "valueSet",
+        "canonical",
+        true,
+    ),
+    ("ValueSet_Include", "version", "version", "string", false),
+    (
+        "ValueSet_Parameter",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Parameter", "id", "id", "string", false),
+    (
+        "ValueSet_Parameter",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    ("ValueSet_Parameter", "name", "name", "string", false),
+    (
+        "ValueSet_Parameter",
+        "valueBoolean",
+        "value",
+        "boolean",
+        false,
+    ),
+    ("ValueSet_Parameter", "valueCode", "value", "code", false),
+    (
+        "ValueSet_Parameter",
+        "valueDateTime",
+        "value",
+        "dateTime",
+        false,
+    ),
+    (
+        "ValueSet_Parameter",
+        "valueDecimal",
+        "value",
+        "decimal",
+        false,
+    ),
+    (
+        "ValueSet_Parameter",
+        "valueInteger",
+        "value",
+        "integer",
+        false,
+    ),
+    (
+        "ValueSet_Parameter",
+        "valueString",
+        "value",
+        "string",
+        false,
+    ),
+    ("ValueSet_Parameter", "valueUri", "value", "uri", false),
+    (
+        "VerificationResult",
+        "attestation",
+        "attestation",
+        "VerificationResult_Attestation",
+        false,
+    ),
+    (
+        "VerificationResult",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "VerificationResult",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult",
+        "failureAction",
+        "failureAction",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VerificationResult",
+        "frequency",
+        "frequency",
+        "Timing",
+        false,
+    ),
+    ("VerificationResult", "id", "id", "id", false),
+    (
+        "VerificationResult",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("VerificationResult", "language", "language", "code", false),
+    (
+        "VerificationResult",
+        "lastPerformed",
+        "lastPerformed",
+        "dateTime",
+        false,
+    ),
+    ("VerificationResult", "meta", "meta", "Meta", false),
+    (
+        "VerificationResult",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult",
+        "need",
+        "need",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VerificationResult",
+        "nextScheduled",
+        "nextScheduled",
+        "date",
+        false,
+    ),
+    (
+        "VerificationResult",
+        "primarySource",
+        "primarySource",
+        "VerificationResult_PrimarySource",
+        true,
+    ),
+    ("VerificationResult", "status", "status", "code", false),
+    (
+        "VerificationResult",
+        "statusDate",
+        "statusDate",
+        "dateTime",
+        false,
+    ),
+    ("VerificationResult", "target", "target", "Reference", true),
+    (
+        "VerificationResult",
+        "targetLocation",
+        "targetLocation",
+        "string",
+        true,
+    ),
+    ("VerificationResult", "text", "text", "Narrative", false),
+    (
+        "VerificationResult",
+        "validationProcess",
+        "validationProcess",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "VerificationResult",
+        "validationType",
+        "validationType",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VerificationResult",
+        "validator",
+        "validator",
+        "VerificationResult_Validator",
+        true,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "communicationMethod",
+        "communicationMethod",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "date",
+        "date",
+        "date",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "onBehalfOf",
+        "onBehalfOf",
+        "Reference",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "proxyIdentityCertificate",
+        "proxyIdentityCertificate",
+        "string",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "proxySignature",
+        "proxySignature",
+        "Signature",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "sourceIdentityCertificate",
+        "sourceIdentityCertificate",
+        "string",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "sourceSignature",
+        "sourceSignature",
+        "Signature",
+        false,
+    ),
+    (
+        "VerificationResult_Attestation",
+        "who",
+        "who",
+        "Reference",
+        false,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "canPushUpdates",
+        "canPushUpdates",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "communicationMethod",
+        "communicationMethod",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "pushTypeAvailable",
+        "pushTypeAvailable",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "type",
+        "type",
+        "CodeableConcept",
+        true,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "validationDate",
+        "validationDate",
+        "dateTime",
+        false,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "validationStatus",
+        "validationStatus",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VerificationResult_PrimarySource",
+        "who",
+        "who",
+        "Reference",
+        false,
+    ),
+    (
+        "VerificationResult_Validator",
+        "attestationSignature",
+        "attestationSignature",
+        "Signature",
+        false,
+    ),
+    (
+        "VerificationResult_Validator",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("VerificationResult_Validator", "id", "id", "string", false),
+    (
+        "VerificationResult_Validator",
+        "identityCertificate",
+        "identityCertificate",
+        "string",
+        false,
+    ),
+    (
+        "VerificationResult_Validator",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "VerificationResult_Validator",
+        "organization",
+        "organization",
+        "Reference",
+        false,
+    ),
+    (
+        "VisionPrescription",
+        "contained",
+        "contained",
+        "ResourceList",
+        true,
+    ),
+    (
+        "VisionPrescription",
+        "created",
+        "created",
+        "dateTime",
+        false,
+    ),
+    (
+        "VisionPrescription",
+        "dateWritten",
+        "dateWritten",
+        "dateTime",
+        false,
+    ),
+    (
+        "VisionPrescription",
+        "encounter",
+        "encounter",
+        "Reference",
+        false,
+    ),
+    (
+        "VisionPrescription",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("VisionPrescription", "id", "id", "id", false),
+    (
+        "VisionPrescription",
+        "identifier",
+        "identifier",
+        "Identifier",
+        true,
+    ),
+    (
+        "VisionPrescription",
+        "implicitRules",
+        "implicitRules",
+        "uri",
+        false,
+    ),
+    ("VisionPrescription", "language", "language", "code", false),
+    (
+        "VisionPrescription",
+        "lensSpecification",
+        "lensSpecification",
+        "VisionPrescription_LensSpecification",
+        true,
+    ),
+    ("VisionPrescription", "meta", "meta", "Meta", false),
+    (
+        "VisionPrescription",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "VisionPrescription",
+        "patient",
+        "patient",
+        "Reference",
+        false,
+    ),
+    (
+        "VisionPrescription",
+        "prescriber",
+        "prescriber",
+        "Reference",
+        false,
+    ),
+    ("VisionPrescription", "status", "status", "code", false),
+    ("VisionPrescription", "text", "text", "Narrative", false),
+    (
+        "VisionPrescription_LensSpecification",
+        "add",
+        "add",
+        "decimal",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "axis",
+        "axis",
+        "integer",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "backCurve",
+        "backCurve",
+        "decimal",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "brand",
+        "brand",
+        "string",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "color",
+        "color",
+        "string",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "cylinder",
+        "cylinder",
+        "decimal",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "diameter",
+        "diameter",
+        "decimal",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "duration",
+        "duration",
+        "Quantity",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "eye",
+        "eye",
+        "code",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "id",
+        "id",
+        "string",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "note",
+        "note",
+        "Annotation",
+        true,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "power",
+        "power",
+        "decimal",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "prism",
+        "prism",
+        "VisionPrescription_Prism",
+        true,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "product",
+        "product",
+        "CodeableConcept",
+        false,
+    ),
+    (
+        "VisionPrescription_LensSpecification",
+        "sphere",
+        "sphere",
+        "decimal",
+        false,
+    ),
+    (
+        "VisionPrescription_Prism",
+        "amount",
+        "amount",
+        "decimal",
+        false,
+    ),
+    ("VisionPrescription_Prism", "base", "base", "code", false),
+    (
+        "VisionPrescription_Prism",
+        "extension",
+        "extension",
+        "Extension",
+        true,
+    ),
+    ("VisionPrescription_Prism", "id", "id", "string", false),
+    (
+        "VisionPrescription_Prism",
+        "modifierExtension",
+        "modifierExtension",
+        "Extension",
+        true,
+    ),
+];
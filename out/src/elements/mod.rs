@@ -0,0 +1,96 @@
+//! What the generated model knows about the elements of each type: the
+//! type of their values, whether they repeat and which are choices.
+//!
+//! The table in `element_types.rs` is generated from the same schema as the
+//! model, so it names the types the model's getters return.
+
+include!("element_types.rs");
+
+/// An element of a type, as one JSON key holds it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElementType {
+  pub key: &'static str,
+  /// The element name: `deceased` for the key `deceasedBoolean`.
+  pub name: &'static str,
+  /// The type of the values, e.g. `HumanName`, `date`, or `Patient_Contact`
+  /// for a backbone element. Resources have the type `ResourceList`.
+  pub type_name: &'static str,
+  pub repeats: bool,
+}
+
+impl ElementType {
+  /// Whether the key names the type of a choice element.
+  pub fn is_choice(&self) -> bool {
+    self.key != self.name
+  }
+
+  /// Whether the values have elements of their own: complex datatypes and
+  /// backbone elements, but not primitives or resources.
+  pub fn is_complex(&self) -> bool {
+    self.type_name.starts_with(char::is_uppercase) && self.type_name != "ResourceList"
+  }
+}
+
+fn element_type(
+  entry: &(&'static str, &'static str, &'static str, &'static str, bool),
+) -> ElementType {
+  let (_, key, name, type_name, repeats) = *entry;
+  ElementType {
+    key,
+    name,
+    type_name,
+    repeats,
+  }
+}
+
+/// The elements of a type, such as `Patient` or `Patient_Contact`, ordered
+/// by key.
+pub fn elements(parent: &str) -> impl Iterator<Item = ElementType> {
+  let start = ELEMENTS.partition_point(|(type_name, ..)| *type_name < parent);
+  let end = ELEMENTS.partition_point(|(type_name, ..)| *type_name <= parent);
+  ELEMENTS[start..end].iter().map(element_type)
+}
+
+/// The element a JSON key of a type holds, e.g. `telecom` of `Patient`.
+pub fn element(parent: &str, key: &str) -> Option<ElementType> {
+  ELEMENTS
+    .binary_search_by(|(type_name, element_key, ..)| (*type_name, *element_key).cmp(&(parent, key)))
+    .ok()
+    .map(|index| element_type(&ELEMENTS[index]))
+}
+
+/// Whether `name` is a FHIR datatype or resource type, e.g. `Quantity`,
+/// `dateTime` or `Patient`.
+pub fn is_type_name(name: &str) -> bool {
+  TYPE_NAMES.binary_search(&name).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_element_types() {
+    let telecom = element("Patient", "telecom").unwrap();
+    assert_eq!(telecom.type_name, "ContactPoint");
+    assert!(telecom.repeats && telecom.is_complex() && !telecom.is_choice());
+    let deceased = element("Patient", "deceasedDateTime").unwrap();
+    assert_eq!(
+      (deceased.name, deceased.type_name),
+      ("deceased", "dateTime")
+    );
+    assert!(deceased.is_choice() && !deceased.is_complex());
+    assert_eq!(element("Patient", "deceased"), None);
+    assert_eq!(
+      element("Patient_Contact", "name").map(|e| e.type_name),
+      Some("HumanName")
+    );
+    assert!(!element("Bundle_Entry", "resource").unwrap().is_complex());
+    assert_eq!(
+      elements("Encounter").filter(|e| e.name == "class").count(),
+      1
+    );
+    assert!(is_type_name("CodeableConcept") && is_type_name("dateTime"));
+    assert!(!is_type_name("Patient_Contact") && !is_type_name("History"));
+  }
+}
@@ -0,0 +1,288 @@
+//! Evaluating an `Expr` against a resource.
+
+use super::parser::{Expr, Operator};
+use crate::elements;
+use crate::reference::ReferenceTarget;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+/// An item of a FHIRPath collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<'a> {
+  pub value: Cow<'a, Value>,
+  /// The FHIR type, where the data shows it: the `resourceType` of a
+  /// resource, the type of a choice element (`Quantity` for
+  /// `valueQuantity`), or the target type after `resolve()`.
+  pub type_name: Option<String>,
+  /// Where the item is in the resource, as a JSON pointer; `None` for
+  /// computed values.
+  pub pointer: Option<String>,
+}
+
+impl<'a> Node<'a> {
+  /// The resource the evaluation starts from.
+  pub fn root(resource: &'a Value) -> Node<'a> {
+    Node {
+      value: Cow::Borrowed(resource),
+      type_name: resource["resourceType"].as_str().map(|t| t.to_string()),
+      pointer: Some(String::new()),
+    }
+  }
+
+  fn computed(value: Value) -> Node<'static> {
+    Node {
+      value: Cow::Owned(value),
+      type_name: None,
+      pointer: None,
+    }
+  }
+
+  fn child(
+    &self,
+    value: &'a Value,
+    key: &str,
+    index: Option<usize>,
+    type_name: Option<String>,
+  ) -> Node<'a> {
+    let pointer = self.pointer.as_ref().map(|pointer| match index {
+      Some(index) => format!("{}/{}/{}", pointer, key, index),
+      None => format!("{}/{}", pointer, key),
+    });
+    Node {
+      value: Cow::Borrowed(value),
+      type_name: value["resourceType"]
+        .as_str()
+        .map(|t| t.to_string())
+        .or(type_name),
+      pointer,
+    }
+  }
+}
+
+fn boolean(value: bool) -> Vec<Node<'static>> {
+  vec![Node::computed(json!(value))]
+}
+
+/// A collection as a single boolean: `None` when empty.
+pub(crate) fn truth(nodes: &[Node]) -> Option<bool> {
+  match nodes {
+    [] => None,
+    [node] => Some(node.value.as_bool().unwrap_or(true)),
+    _ => Some(true),
+  }
+}
+
+fn equal(left: &Value, right: &Value) -> bool {
+  match (left.as_f64(), right.as_f64()) {
+    (Some(left), Some(right)) => left == right,
+    _ => left == right,
+  }
+}
+
+/// Whether the node has the type, or `None` if its type is not known.
+fn type_matches(node: &Node, name: &str) -> Option<bool> {
+  let matches = |type_name: &str| type_name.eq_ignore_ascii_case(name);
+  match &node.type_name {
+    Some(type_name) => Some(
+      matches(type_name)
+        || (matches!(name, "Resource" | "DomainResource")
+          && node.value.get("resourceType").is_some()),
+    ),
+    None => match &*node.value {
+      Value::Bool(_) => Some(matches("boolean")),
+      Value::Number(_) => Some(matches("decimal") || matches("integer")),
+      _ => None,
+    },
+  }
+}
+
+/// Whether a member name selects the node itself, as `Patient` does at the
+/// start of `Patient.name`.
+fn is_type_name(node: &Node, name: &str) -> bool {
+  name.starts_with(char::is_uppercase) && type_matches(node, name) == Some(true)
+}
+
+/// Whether the end of a choice element's key names a FHIR type, as
+/// `Quantity` in `valueQuantity` and `DateTime` in `onsetDateTime` do.
+fn is_choice_type(suffix: &str) -> bool {
+  let mut chars = suffix.chars();
+  match chars.next() {
+    Some(first) if first.is_uppercase() => {
+      let lower: String = first.to_lowercase().chain(chars).collect();
+      elements::is_type_name(suffix) || elements::is_type_name(&lower)
+    }
+    _ => false,
+  }
+}
+
+fn member<'a>(node: &Node<'a>, name: &str, found: &mut Vec<Node<'a>>) {
+  if is_type_name(node, name) {
+    found.push(node.clone());
+    return;
+  }
+  let object = match &node.value {
+    Cow::Borrowed(Value::Object(object)) => object,
+    _ => return,
+  };
+  let (key, value, type_name) = match object.get_key_value(name) {
+    Some((key, value)) => (key, value, None),
+    None => {
+      let choice = object
+        .iter()
+        .find(|(key, _)| key.starts_with(name) && is_choice_type(&key[name.len()..]));
+      match choice {
+        Some((key, value)) => (key, value, Some(key[name.len()..].to_string())),
+        None => return,
+      }
+    }
+  };
+  match value {
+    Value::Array(items) => {
+      for (i, item) in items.iter().enumerate() {
+        found.push(node.child(item, key, Some(i), type_name.clone()));
+      }
+    }
+    _ => found.push(node.child(value, key, None, type_name)),
+  }
+}
+
+fn resolve<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+  let reference = node.value["reference"].as_str()?;
+  let resource_type = ReferenceTarget::parse(reference)
+    .and_then(|target| target.resource_type)
+    .or_else(|| node.value["type"].as_str().map(|t| t.to_string()))?;
+  Some(Node {
+    type_name: Some(resource_type),
+    ..node.clone()
+  })
+}
+
+fn type_argument(arguments: &[Expr]) -> &str {
+  match arguments.first() {
+    Some(Expr::Literal(value)) => value.as_str().unwrap_or_default(),
+    _ => "",
+  }
+}
+
+fn call<'a>(focus: Vec<Node<'a>>, name: &str, arguments: &[Expr]) -> Vec<Node<'a>> {
+  let criteria = |node: &Node<'a>| match arguments.first() {
+    Some(criteria) => truth(&evaluate(criteria, node)) == Some(true),
+    None => true,
+  };
+  match name {
+    "where" => focus.into_iter().filter(|node| criteria(node)).collect(),
+    "exists" => boolean(focus.iter().any(criteria)),
+    "empty" => boolean(focus.is_empty()),
+    "not" => match truth(&focus) {
+      Some(value) => boolean(!value),
+      None => vec![],
+    },
+    "first" => focus.into_iter().take(1).collect(),
+    "last" => focus.into_iter().last().into_iter().collect(),
+    "count" => vec![Node::computed(json!(focus.len()))],
+    "resolve" => focus.iter().filter_map(resolve).collect(),
+    "as" | "ofType" => {
+      let type_name = type_argument(arguments);
+      focus
+        .into_iter()
+        .filter(|node| type_matches(node, type_name) != Some(false))
+        .collect()
+    }
+    "is" => {
+      let type_name = type_argument(arguments);
+      match focus.as_slice() {
+        [node] => boolean(type_matches(node, type_name) == Some(true)),
+        _ => vec![],
+      }
+    }
+    "extension" => {
+      let url = arguments
+        .first()
+        .map(|url| evaluate(url, &Node::computed(Value::Null)));
+      let url = url
+        .as_ref()
+        .and_then(|url| url.first())
+        .map(|url| url.value.clone().into_owned());
+      let mut found = vec![];
+      for node in &focus {
+        member(node, "extension", &mut found);
+      }
+      found
+        .into_iter()
+        .filter(|extension| {
+          url
+            .as_ref()
+            .is_none_or(|url| extension.value["url"] == *url)
+        })
+        .collect()
+    }
+    _ => vec![],
+  }
+}
+
+/// Evaluates `expr` with `focus` as `$this`.
+pub fn evaluate<'a>(expr: &Expr, focus: &Node<'a>) -> Vec<Node<'a>> {
+  match expr {
+    Expr::This => vec![focus.clone()],
+    Expr::Literal(value) => vec![Node::computed(value.clone())],
+    Expr::Member(inner, name) => {
+      let mut found = vec![];
+      for node in evaluate(inner, focus) {
+        member(&node, name, &mut found);
+      }
+      found
+    }
+    Expr::Call(inner, name, arguments) => call(evaluate(inner, focus), name, arguments),
+    Expr::Index(inner, index) => evaluate(inner, focus)
+      .into_iter()
+      .skip(*index)
+      .take(1)
+      .collect(),
+    Expr::As(inner, type_name) => call(
+      evaluate(inner, focus),
+      "as",
+      &[Expr::Literal(json!(type_name))],
+    ),
+    Expr::Is(inner, type_name) => call(
+      evaluate(inner, focus),
+      "is",
+      &[Expr::Literal(json!(type_name))],
+    ),
+    Expr::Binary(left, operator, right) => {
+      let left = evaluate(left, focus);
+      let right = evaluate(right, focus);
+      match operator {
+        Operator::Union => {
+          let mut union: Vec<Node<'a>> = vec![];
+          for node in left.into_iter().chain(right) {
+            if !union.iter().any(|n| equal(&n.value, &node.value)) {
+              union.push(node);
+            }
+          }
+          union
+        }
+        Operator::Equal | Operator::NotEqual => {
+          if left.is_empty() || right.is_empty() {
+            return vec![];
+          }
+          let same = left.len() == right.len()
+            && left
+              .iter()
+              .zip(&right)
+              .all(|(l, r)| equal(&l.value, &r.value));
+          boolean(same == (*operator == Operator::Equal))
+        }
+        Operator::And => match (truth(&left), truth(&right)) {
+          (Some(false), _) | (_, Some(false)) => boolean(false),
+          (Some(true), Some(true)) => boolean(true),
+          _ => vec![],
+        },
+        Operator::Or => match (truth(&left), truth(&right)) {
+          (Some(true), _) | (_, Some(true)) => boolean(true),
+          (Some(false), Some(false)) => boolean(false),
+          _ => vec![],
+        },
+      }
+    }
+  }
+}
@@ -0,0 +1,114 @@
+//! A subset of FHIRPath: enough for the expressions of the SearchParameter
+//! definitions and for addressing elements in patches.
+//!
+//! Supported are paths with choice elements (`Observation.value` finds
+//! `valueQuantity`), indexers, `|`, `=`, `!=`, `and`, `or`, `as` and `is`,
+//! and the functions in `parser::FUNCTIONS`. `resolve()` does not fetch
+//! anything: it yields the reference itself, typed with the target type
+//! from the reference, so that `where(resolve() is Patient)` works.
+
+pub mod eval;
+pub mod parser;
+
+pub use eval::Node;
+pub use parser::{Expr, FhirPathError};
+
+use serde_json::Value;
+
+/// A parsed FHIRPath expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+  text: String,
+  expr: Expr,
+}
+
+impl Expression {
+  pub fn parse(text: &str) -> Result<Expression, FhirPathError> {
+    Ok(Expression {
+      text: text.to_string(),
+      expr: parser::parse(text)?,
+    })
+  }
+
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+
+  pub fn expr(&self) -> &Expr {
+    &self.expr
+  }
+
+  /// The items the expression selects in a resource.
+  pub fn evaluate<'a>(&self, resource: &'a Value) -> Vec<Node<'a>> {
+    eval::evaluate(&self.expr, &Node::root(resource))
+  }
+
+  /// The expression as a boolean: `None` if it selects nothing.
+  pub fn is_true(&self, resource: &Value) -> Option<bool> {
+    eval::truth(&self.evaluate(resource))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_evaluate() {
+    let patient = load("patient-example.json");
+    let values = |text: &str, resource: &Value| -> Vec<Value> {
+      Expression::parse(text)
+        .unwrap()
+        .evaluate(resource)
+        .into_iter()
+        .map(|node| node.value.into_owned())
+        .collect()
+    };
+    assert_eq!(
+      values("Patient.name.where(use='official').given", &patient),
+      vec![Value::from("Peter"), Value::from("James")]
+    );
+    assert_eq!(
+      values("Patient.telecom.where(system='phone')[1].value", &patient),
+      vec![Value::from("(03) 3410 5613")]
+    );
+    assert!(values("Observation.code", &patient).is_empty());
+    let expression =
+      Expression::parse("Patient.deceased.exists() and Patient.deceased != false").unwrap();
+    assert_eq!(expression.is_true(&patient), Some(false));
+    let nodes = Expression::parse("Patient.managingOrganization.where(resolve() is Organization)")
+      .unwrap()
+      .evaluate(&patient)
+      .into_iter()
+      .map(|node| node.pointer)
+      .collect::<Vec<_>>();
+    assert_eq!(nodes, vec![Some("/managingOrganization".to_string())]);
+
+    let observation = load("observation-example.json");
+    let quantity =
+      Expression::parse("(Observation.value as Quantity) | (Observation.value as CodeableConcept)")
+        .unwrap();
+    let nodes = quantity.evaluate(&observation);
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].type_name.as_deref(), Some("Quantity"));
+    assert_eq!(nodes[0].pointer.as_deref(), Some("/valueQuantity"));
+
+    let encounter = json!({
+      "resourceType": "Encounter",
+      "status": "finished",
+      "classHistory": [{"class": {"code": "IMP"}, "period": {"start": "2020"}}]
+    });
+    assert!(values("Encounter.class", &encounter).is_empty());
+    assert_eq!(
+      values("Encounter.classHistory.class.code", &encounter),
+      vec![Value::from("IMP")]
+    );
+  }
+}
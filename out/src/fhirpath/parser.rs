@@ -0,0 +1,418 @@
+//! Parsing FHIRPath expressions into an `Expr` tree.
+
+use serde_json::{json, Value};
+use std::fmt;
+
+/// The functions `evaluate` implements.
+pub const FUNCTIONS: &[&str] = &[
+  "where",
+  "exists",
+  "empty",
+  "not",
+  "first",
+  "last",
+  "count",
+  "resolve",
+  "as",
+  "is",
+  "ofType",
+  "extension",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FhirPathError {
+  /// The expression does not parse; `position` is a byte offset.
+  Syntax {
+    position: usize,
+    message: String,
+  },
+  UnknownFunction(String),
+}
+
+impl fmt::Display for FhirPathError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      FhirPathError::Syntax { position, message } => {
+        write!(f, "Syntax error at {}: {}", position, message)
+      }
+      FhirPathError::UnknownFunction(name) => write!(f, "Unknown function {}()", name),
+    }
+  }
+}
+
+impl std::error::Error for FhirPathError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+  Union,
+  Equal,
+  NotEqual,
+  And,
+  Or,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+  /// The focus: the resource at the start, or the item inside `where()`.
+  This,
+  Literal(Value),
+  /// `expr.name`; a leading type name such as `Patient` selects the focus
+  /// if it has that type.
+  Member(Box<Expr>, String),
+  /// `expr.name(arguments)`.
+  Call(Box<Expr>, String, Vec<Expr>),
+  Index(Box<Expr>, usize),
+  /// `expr as Type`.
+  As(Box<Expr>, String),
+  /// `expr is Type`.
+  Is(Box<Expr>, String),
+  Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Identifier(String),
+  Text(String),
+  Number(String),
+  Symbol(&'static str),
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, FhirPathError> {
+  let error = |position: usize, message: &str| FhirPathError::Syntax {
+    position,
+    message: message.to_string(),
+  };
+  let chars: Vec<(usize, char)> = text.char_indices().collect();
+  let mut tokens = vec![];
+  let mut i = 0;
+  while i < chars.len() {
+    let (position, c) = chars[i];
+    if c.is_whitespace() {
+      i += 1;
+    } else if c.is_alphabetic() || c == '_' || c == '$' {
+      let start = i;
+      while i < chars.len() && (chars[i].1.is_alphanumeric() || matches!(chars[i].1, '_' | '$')) {
+        i += 1;
+      }
+      let name: String = chars[start..i].iter().map(|(_, c)| c).collect();
+      tokens.push((position, Token::Identifier(name)));
+    } else if c == '`' {
+      let end = chars[i + 1..]
+        .iter()
+        .position(|(_, c)| *c == '`')
+        .ok_or_else(|| error(position, "Unterminated identifier"))?;
+      let name: String = chars[i + 1..i + 1 + end].iter().map(|(_, c)| c).collect();
+      tokens.push((position, Token::Identifier(name)));
+      i += end + 2;
+    } else if c == '\'' {
+      let mut string = String::new();
+      i += 1;
+      loop {
+        match chars.get(i) {
+          None => return Err(error(position, "Unterminated string")),
+          Some((_, '\'')) => break,
+          Some((_, '\\')) => {
+            let escaped = chars.get(i + 1).map(|(_, c)| *c).unwrap_or('\\');
+            string.push(match escaped {
+              'n' => '\n',
+              'r' => '\r',
+              't' => '\t',
+              other => other,
+            });
+            i += 2;
+          }
+          Some((_, c)) => {
+            string.push(*c);
+            i += 1;
+          }
+        }
+      }
+      tokens.push((position, Token::Text(string)));
+      i += 1;
+    } else if c.is_ascii_digit() {
+      let start = i;
+      while i < chars.len() && (chars[i].1.is_ascii_digit() || chars[i].1 == '.') {
+        i += 1;
+      }
+      let number: String = chars[start..i].iter().map(|(_, c)| c).collect();
+      tokens.push((position, Token::Number(number)));
+    } else {
+      let next = chars.get(i + 1).map(|(_, c)| *c);
+      let symbol = match (c, next) {
+        ('!', Some('=')) => "!=",
+        ('.', _) => ".",
+        ('(', _) => "(",
+        (')', _) => ")",
+        ('[', _) => "[",
+        (']', _) => "]",
+        ('|', _) => "|",
+        ('=', _) => "=",
+        (',', _) => ",",
+        _ => return Err(error(position, &format!("Unexpected {}", c))),
+      };
+      i += symbol.len();
+      tokens.push((position, Token::Symbol(symbol)));
+    }
+  }
+  Ok(tokens)
+}
+
+struct Parser {
+  tokens: Vec<(usize, Token)>,
+  next: usize,
+  end: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.next).map(|(_, token)| token)
+  }
+
+  fn position(&self) -> usize {
+    self
+      .tokens
+      .get(self.next)
+      .map_or(self.end, |(position, _)| *position)
+  }
+
+  fn error<T>(&self, message: &str) -> Result<T, FhirPathError> {
+    Err(FhirPathError::Syntax {
+      position: self.position(),
+      message: message.to_string(),
+    })
+  }
+
+  fn is_symbol(&self, symbol: &str) -> bool {
+    matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+  }
+
+  fn is_keyword(&self, keyword: &str) -> bool {
+    matches!(self.peek(), Some(Token::Identifier(name)) if name == keyword)
+  }
+
+  fn expect(&mut self, symbol: &str) -> Result<(), FhirPathError> {
+    if self.is_symbol(symbol) {
+      self.next += 1;
+      Ok(())
+    } else {
+      self.error(&format!("Expected {}", symbol))
+    }
+  }
+
+  fn identifier(&mut self) -> Result<String, FhirPathError> {
+    match self.peek() {
+      Some(Token::Identifier(name)) => {
+        let name = name.clone();
+        self.next += 1;
+        Ok(name)
+      }
+      _ => self.error("Expected a name"),
+    }
+  }
+
+  /// A type name, possibly qualified as in `FHIR.Patient`.
+  fn type_name(&mut self) -> Result<String, FhirPathError> {
+    let mut name = self.identifier()?;
+    while self.is_symbol(".") {
+      self.next += 1;
+      name = self.identifier()?;
+    }
+    Ok(name)
+  }
+
+  fn binary(
+    &mut self,
+    operators: &[(&str, Operator)],
+    operand: fn(&mut Parser) -> Result<Expr, FhirPathError>,
+  ) -> Result<Expr, FhirPathError> {
+    let mut left = operand(self)?;
+    loop {
+      let operator = operators.iter().find(|(text, _)| {
+        if text.starts_with(char::is_alphabetic) {
+          self.is_keyword(text)
+        } else {
+          self.is_symbol(text)
+        }
+      });
+      match operator {
+        Some((_, operator)) => {
+          let operator = *operator;
+          self.next += 1;
+          let right = operand(self)?;
+          left = Expr::Binary(Box::new(left), operator, Box::new(right));
+        }
+        None => return Ok(left),
+      }
+    }
+  }
+
+  fn expression(&mut self) -> Result<Expr, FhirPathError> {
+    self.binary(&[("or", Operator::Or)], Parser::and)
+  }
+
+  fn and(&mut self) -> Result<Expr, FhirPathError> {
+    self.binary(&[("and", Operator::And)], Parser::equality)
+  }
+
+  fn equality(&mut self) -> Result<Expr, FhirPathError> {
+    self.binary(
+      &[("=", Operator::Equal), ("!=", Operator::NotEqual)],
+      Parser::union,
+    )
+  }
+
+  fn union(&mut self) -> Result<Expr, FhirPathError> {
+    self.binary(&[("|", Operator::Union)], Parser::type_expression)
+  }
+
+  fn type_expression(&mut self) -> Result<Expr, FhirPathError> {
+    let mut expr = self.postfix()?;
+    loop {
+      if self.is_keyword("as") {
+        self.next += 1;
+        expr = Expr::As(Box::new(expr), self.type_name()?);
+      } else if self.is_keyword("is") {
+        self.next += 1;
+        expr = Expr::Is(Box::new(expr), self.type_name()?);
+      } else {
+        return Ok(expr);
+      }
+    }
+  }
+
+  fn postfix(&mut self) -> Result<Expr, FhirPathError> {
+    let mut expr = self.primary()?;
+    loop {
+      if self.is_symbol(".") {
+        self.next += 1;
+        expr = self.invocation(expr)?;
+      } else if self.is_symbol("[") {
+        self.next += 1;
+        let index = match self.peek() {
+          Some(Token::Number(number)) => number.parse::<usize>().ok(),
+          _ => None,
+        };
+        let index = match index {
+          Some(index) => index,
+          None => return self.error("Expected an index"),
+        };
+        self.next += 1;
+        self.expect("]")?;
+        expr = Expr::Index(Box::new(expr), index);
+      } else {
+        return Ok(expr);
+      }
+    }
+  }
+
+  fn invocation(&mut self, focus: Expr) -> Result<Expr, FhirPathError> {
+    let name = self.identifier()?;
+    if !self.is_symbol("(") {
+      return Ok(Expr::Member(Box::new(focus), name));
+    }
+    self.next += 1;
+    if !FUNCTIONS.contains(&name.as_str()) {
+      return Err(FhirPathError::UnknownFunction(name));
+    }
+    let mut arguments = vec![];
+    if !self.is_symbol(")") {
+      loop {
+        arguments.push(if matches!(name.as_str(), "as" | "is" | "ofType") {
+          Expr::Literal(json!(self.type_name()?))
+        } else {
+          self.expression()?
+        });
+        if !self.is_symbol(",") {
+          break;
+        }
+        self.next += 1;
+      }
+    }
+    self.expect(")")?;
+    Ok(Expr::Call(Box::new(focus), name, arguments))
+  }
+
+  fn primary(&mut self) -> Result<Expr, FhirPathError> {
+    let token = match self.peek() {
+      Some(token) => token.clone(),
+      None => return self.error("Unexpected end"),
+    };
+    match token {
+      Token::Symbol("(") => {
+        self.next += 1;
+        let expr = self.expression()?;
+        self.expect(")")?;
+        Ok(expr)
+      }
+      Token::Text(text) => {
+        self.next += 1;
+        Ok(Expr::Literal(json!(text)))
+      }
+      Token::Number(number) => match serde_json::from_str::<Value>(&number) {
+        Ok(value) => {
+          self.next += 1;
+          Ok(Expr::Literal(value))
+        }
+        Err(_) => self.error("Invalid number"),
+      },
+      Token::Identifier(name) if name == "true" || name == "false" => {
+        self.next += 1;
+        Ok(Expr::Literal(json!(name == "true")))
+      }
+      Token::Identifier(name) if name == "$this" => {
+        self.next += 1;
+        Ok(Expr::This)
+      }
+      Token::Identifier(_) => self.invocation(Expr::This),
+      Token::Symbol(symbol) => self.error(&format!("Unexpected {}", symbol)),
+    }
+  }
+}
+
+/// Parses a FHIRPath expression.
+pub fn parse(text: &str) -> Result<Expr, FhirPathError> {
+  let mut parser = Parser {
+    tokens: tokenize(text)?,
+    next: 0,
+    end: text.len(),
+  };
+  let expr = parser.expression()?;
+  if parser.peek().is_some() {
+    return parser.error("Unexpected input");
+  }
+  Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse() {
+    let member = |focus: Expr, name: &str| Expr::Member(Box::new(focus), name.to_string());
+    assert_eq!(
+      parse("(Observation.value as Quantity) | Observation.component[0]").unwrap(),
+      Expr::Binary(
+        Box::new(Expr::As(
+          Box::new(member(member(Expr::This, "Observation"), "value")),
+          "Quantity".to_string()
+        )),
+        Operator::Union,
+        Box::new(Expr::Index(
+          Box::new(member(member(Expr::This, "Observation"), "component")),
+          0
+        )),
+      )
+    );
+    assert!(parse("Patient.telecom.where(system='phone')").is_ok());
+    assert!(parse("Patient.deceased.exists() and Patient.deceased != false").is_ok());
+    assert_eq!(
+      parse("Patient.name.reverse()"),
+      Err(FhirPathError::UnknownFunction("reverse".to_string()))
+    );
+    assert!(matches!(
+      parse("Patient.name.where(use = 'official'"),
+      Err(FhirPathError::Syntax { position: 35, .. })
+    ));
+  }
+}
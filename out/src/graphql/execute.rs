@@ -0,0 +1,552 @@
+//! Executing queries against a `ResourceStore`.
+
+use super::schema::{FieldDefinition, Schema, TypeDefinition};
+use super::syntax::{
+  parse_document, Directive, Document, Field, InputValue, Operation, OperationKind, Selection,
+  TypeRef,
+};
+use super::GraphQlError;
+use crate::model::ResourceList::ResourceList;
+use crate::reference::{ContainedResolver, ReferenceKind, ReferenceResolver, ReferenceTarget};
+use crate::rest::is_resource_type;
+use crate::store::ResourceStore;
+use serde_json::{json, Map, Value};
+
+/// The value of an argument or variable default, with variables replaced.
+fn input(value: &InputValue, variables: &Map<String, Value>) -> Value {
+  match value {
+    InputValue::Variable(name) => variables.get(name).cloned().unwrap_or(Value::Null),
+    InputValue::Null => Value::Null,
+    InputValue::Int(number) => json!(number),
+    InputValue::Float(number) => json!(number),
+    InputValue::String(text) | InputValue::Enum(text) => json!(text),
+    InputValue::Boolean(value) => json!(value),
+    InputValue::List(items) => items.iter().map(|item| input(item, variables)).collect(),
+    InputValue::Object(fields) => Value::Object(
+      fields
+        .iter()
+        .map(|(name, value)| (name.clone(), input(value, variables)))
+        .collect(),
+    ),
+  }
+}
+
+/// Whether `@skip` and `@include` keep a selection.
+fn included(directives: &[Directive], variables: &Map<String, Value>) -> bool {
+  directives.iter().all(|directive| {
+    let condition = directive
+      .arguments
+      .iter()
+      .find(|(name, _)| name == "if")
+      .map(|(_, value)| input(value, variables));
+    match directive.name.as_str() {
+      "skip" => condition != Some(json!(true)),
+      "include" => condition != Some(json!(false)),
+      _ => true,
+    }
+  })
+}
+
+/// The search parameter an argument stands for: `value_quantity` is
+/// `value-quantity`, as names cannot contain `-`.
+fn parameter_name(argument: &str) -> String {
+  match argument.strip_prefix('_') {
+    Some(rest) => format!("_{}", rest.replace('_', "-")),
+    None => argument.replace('_', "-"),
+  }
+}
+
+fn parameter_value(value: &Value) -> Option<String> {
+  match value {
+    Value::Null => None,
+    Value::String(text) => Some(text.clone()),
+    Value::Array(items) => Some(
+      items
+        .iter()
+        .filter_map(parameter_value)
+        .collect::<Vec<_>>()
+        .join(","),
+    ),
+    other => Some(other.to_string()),
+  }
+}
+
+fn error(message: &str, path: &[Value]) -> Value {
+  json!({ "message": message, "path": path })
+}
+
+/// The state of one request.
+struct Context<'d> {
+  document: &'d Document,
+  variables: Map<String, Value>,
+  errors: Vec<Value>,
+  /// Where the field being completed is in the response.
+  path: Vec<Value>,
+}
+
+impl Context<'_> {
+  fn error(&mut self, message: &str) -> Value {
+    let error = error(message, &self.path);
+    self.errors.push(error);
+    Value::Null
+  }
+}
+
+/// Runs GraphQL queries against a store.
+///
+/// Each resource type has two entry points on `Query`: `Patient(id: ...)`
+/// reads one resource, and `PatientList(...)` searches, with a search
+/// parameter per argument and paging by `_count` and `_offset`. Choice
+/// elements are unions of their types, and the `resource` of a
+/// `Reference` resolves against the referring resource's `contained`, then
+/// with the resolver if one is set and with the store if not. References
+/// that do not resolve are `null`.
+pub struct Executor<'s, S: ResourceStore> {
+  store: &'s S,
+  schema: &'s Schema,
+  resolver: Option<&'s dyn ReferenceResolver>,
+}
+
+impl<'s, S: ResourceStore> Executor<'s, S> {
+  /// An executor of the FHIR schema over the store.
+  pub fn new(store: &'s S) -> Self {
+    Executor {
+      store,
+      schema: Schema::fhir(),
+      resolver: None,
+    }
+  }
+
+  /// Uses another schema, such as a subset of the FHIR schema.
+  pub fn schema(&mut self, schema: &'s Schema) -> &mut Self {
+    self.schema = schema;
+    self
+  }
+
+  /// Resolves references with `resolver` rather than the store.
+  pub fn resolver(&mut self, resolver: &'s dyn ReferenceResolver) -> &mut Self {
+    self.resolver = Some(resolver);
+    self
+  }
+
+  /// Runs a request in its JSON form, with `query` and optionally
+  /// `operationName` and `variables`.
+  pub fn execute_request(&self, request: &Value) -> Value {
+    match request["query"].as_str() {
+      Some(query) => self.execute(
+        query,
+        request["operationName"].as_str(),
+        &request["variables"],
+      ),
+      None => json!({ "errors": [{ "message": "The request has no query" }] }),
+    }
+  }
+
+  /// Runs the operation of a document, or the one named if it has more
+  /// than one, and returns the response with its `data` and `errors`.
+  pub fn execute(&self, query: &str, operation_name: Option<&str>, variables: &Value) -> Value {
+    let document = match parse_document(query) {
+      Ok(document) => document,
+      Err(error) => return json!({ "errors": [{ "message": error.to_string() }] }),
+    };
+    let (operation, mut context) = match self.prepare(&document, operation_name, variables) {
+      Ok(prepared) => prepared,
+      Err(error) => return json!({ "errors": [{ "message": error.to_string() }] }),
+    };
+    let data = self.object(
+      &mut context,
+      self.schema.query_type(),
+      &Value::Null,
+      &Value::Null,
+      &[&operation.selections],
+    );
+    let mut response = json!({ "data": data });
+    if !context.errors.is_empty() {
+      response["errors"] = Value::Array(context.errors);
+    }
+    response
+  }
+
+  /// Picks the operation and coerces the variables it declares.
+  fn prepare<'d>(
+    &self,
+    document: &'d Document,
+    operation_name: Option<&str>,
+    variables: &Value,
+  ) -> Result<(&'d Operation, Context<'d>), GraphQlError> {
+    let operation = match operation_name {
+      Some(name) => document
+        .operations
+        .iter()
+        .find(|operation| operation.name.as_deref() == Some(name))
+        .ok_or_else(|| GraphQlError::Request(format!("There is no operation {}", name)))?,
+      None if document.operations.len() == 1 => &document.operations[0],
+      None => {
+        return Err(GraphQlError::Request(
+          "The document has several operations, so one must be named".to_string(),
+        ))
+      }
+    };
+    if operation.kind != OperationKind::Query {
+      return Err(GraphQlError::Request(
+        "Only queries are supported".to_string(),
+      ));
+    }
+    let mut coerced = Map::new();
+    for definition in &operation.variables {
+      let value = match (variables.get(&definition.name), &definition.default) {
+        (Some(value), _) => value.clone(),
+        (None, Some(default)) => input(default, &Map::new()),
+        (None, None) => Value::Null,
+      };
+      if value.is_null() && matches!(definition.var_type, TypeRef::NonNull(_)) {
+        return Err(GraphQlError::Request(format!(
+          "Variable ${} is required",
+          definition.name
+        )));
+      }
+      coerced.insert(definition.name.clone(), value);
+    }
+    let context = Context {
+      document,
+      variables: coerced,
+      errors: vec![],
+      path: vec![],
+    };
+    Ok((operation, context))
+  }
+
+  /// The fields selected on an object of a type, grouped by response key,
+  /// with the fragments that apply to the type expanded.
+  fn collect<'d>(
+    &self,
+    context: &Context<'d>,
+    type_name: &str,
+    selections: &'d [Selection],
+    fields: &mut Vec<(&'d str, Vec<&'d Field>)>,
+    spread: &mut Vec<&'d str>,
+  ) -> Result<(), String> {
+    for selection in selections {
+      match selection {
+        Selection::Field(field) => {
+          if !included(&field.directives, &context.variables) {
+            continue;
+          }
+          match fields.iter_mut().find(|(key, _)| *key == field.key()) {
+            Some((_, same)) => same.push(field),
+            None => fields.push((field.key(), vec![field])),
+          }
+        }
+        Selection::FragmentSpread { name, directives } => {
+          // A fragment spread inside itself adds nothing.
+          if !included(directives, &context.variables) || spread.contains(&name.as_str()) {
+            continue;
+          }
+          let fragment = context
+            .document
+            .fragment(name)
+            .ok_or_else(|| format!("There is no fragment {}", name))?;
+          if self
+            .schema
+            .is_possible_type(&fragment.type_condition, type_name)
+          {
+            spread.push(&fragment.name);
+            self.collect(context, type_name, &fragment.selections, fields, spread)?;
+            spread.pop();
+          }
+        }
+        Selection::InlineFragment {
+          type_condition,
+          directives,
+          selections,
+        } => {
+          let applies = type_condition
+            .as_ref()
+            .is_none_or(|condition| self.schema.is_possible_type(condition, type_name));
+          if applies && included(directives, &context.variables) {
+            self.collect(context, type_name, selections, fields, spread)?;
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Executes selection sets on an object. `root` is the resource the
+  /// object is in, for resolving references to contained resources.
+  fn object<'d>(
+    &self,
+    context: &mut Context<'d>,
+    type_name: &str,
+    source: &Value,
+    root: &Value,
+    selection_sets: &[&'d [Selection]],
+  ) -> Value {
+    let mut fields = vec![];
+    for selections in selection_sets {
+      if let Err(message) = self.collect(context, type_name, selections, &mut fields, &mut vec![]) {
+        return context.error(&message);
+      }
+    }
+    let mut object = Map::new();
+    for (key, same) in fields {
+      context.path.push(json!(key));
+      let value = self.field(context, type_name, source, root, &same);
+      context.path.pop();
+      object.insert(key.to_string(), value);
+    }
+    Value::Object(object)
+  }
+
+  fn field<'d>(
+    &self,
+    context: &mut Context<'d>,
+    type_name: &str,
+    source: &Value,
+    root: &Value,
+    fields: &[&'d Field],
+  ) -> Value {
+    let field = fields[0];
+    if field.name == "__typename" {
+      return json!(type_name);
+    }
+    let definition = match self.schema.field(type_name, &field.name) {
+      Some(definition) => definition,
+      None => {
+        return context.error(&format!(
+          "Cannot query field {} on type {}",
+          field.name, type_name
+        ))
+      }
+    };
+    let arguments = match self.arguments(context, field, definition) {
+      Ok(arguments) => arguments,
+      Err(message) => return context.error(&message),
+    };
+    let resolved = if type_name == self.schema.query_type() {
+      self.entry_point(&field.name, &arguments)
+    } else if type_name == "Reference" && field.name == "resource" {
+      Ok((self.resolve(source, root), None))
+    } else {
+      Ok(self.member(source, definition))
+    };
+    match resolved {
+      Ok((value, object_type)) => self.complete(
+        context,
+        &definition.field_type,
+        value,
+        object_type.as_deref(),
+        root,
+        fields,
+      ),
+      Err(message) => context.error(&message),
+    }
+  }
+
+  fn arguments(
+    &self,
+    context: &Context,
+    field: &Field,
+    definition: &FieldDefinition,
+  ) -> Result<Map<String, Value>, String> {
+    let mut arguments = Map::new();
+    for (name, value) in &field.arguments {
+      if !definition
+        .arguments
+        .iter()
+        .any(|argument| &argument.name == name)
+      {
+        return Err(format!("Unknown argument {} of {}", name, field.name));
+      }
+      arguments.insert(name.clone(), input(value, &context.variables));
+    }
+    for argument in &definition.arguments {
+      if arguments.get(&argument.name).is_none_or(Value::is_null) {
+        if let Some(default) = &argument.default {
+          arguments.insert(argument.name.clone(), input(default, &Map::new()));
+        } else if matches!(argument.arg_type, TypeRef::NonNull(_)) {
+          return Err(format!(
+            "Argument {} of {} is required",
+            argument.name, field.name
+          ));
+        }
+      }
+    }
+    Ok(arguments)
+  }
+
+  /// Reads a resource for `[type](id)`, or searches for `[type]List`.
+  fn entry_point(
+    &self,
+    name: &str,
+    arguments: &Map<String, Value>,
+  ) -> Result<(Value, Option<String>), String> {
+    if is_resource_type(name) {
+      let id = arguments
+        .get("id")
+        .and_then(parameter_value)
+        .unwrap_or_default();
+      return match self.store.read(name, &id) {
+        Ok(resource) => Ok((resource, None)),
+        Err(error) => Err(error.to_string()),
+      };
+    }
+    let resource_type = match name.strip_suffix("List") {
+      Some(resource_type) if is_resource_type(resource_type) => resource_type,
+      _ => return Err(format!("{} has no resolver", name)),
+    };
+    let mut count = None;
+    let mut offset = 0;
+    let mut params = vec![];
+    for (argument, value) in arguments {
+      match argument.as_str() {
+        "_count" => count = value.as_u64().map(|count| count as usize),
+        "_offset" => offset = value.as_u64().unwrap_or_default() as usize,
+        _ => {
+          if let Some(value) = parameter_value(value) {
+            params.push((parameter_name(argument), value));
+          }
+        }
+      }
+    }
+    let matches = self
+      .store
+      .search(resource_type, &params)
+      .map_err(|error| error.to_string())?;
+    let page = matches
+      .into_iter()
+      .skip(offset)
+      .take(count.unwrap_or(usize::MAX))
+      .collect();
+    Ok((Value::Array(page), None))
+  }
+
+  /// The value of a field of an element, and its object type if the field
+  /// is a choice: `value` finds `valueQuantity` as a `Quantity`, and
+  /// `valueString` as a `StringValue` object holding the string.
+  fn member(&self, source: &Value, definition: &FieldDefinition) -> (Value, Option<String>) {
+    if let Some(value) = source.get(&definition.name) {
+      return (value.clone(), None);
+    }
+    let members = match self.schema.get(definition.field_type.name()) {
+      Some(TypeDefinition::Union(members)) => members,
+      _ => return (Value::Null, None),
+    };
+    for (key, value) in source.as_object().into_iter().flatten() {
+      let suffix = match key.strip_prefix(definition.name.as_str()) {
+        Some(suffix) if suffix.starts_with(char::is_uppercase) => suffix,
+        _ => continue,
+      };
+      if members.iter().any(|member| member == suffix) {
+        return (value.clone(), Some(suffix.to_string()));
+      }
+      let wrapper = format!("{}Value", suffix);
+      if members.contains(&wrapper) {
+        return (json!({ "value": value }), Some(wrapper));
+      }
+    }
+    (Value::Null, None)
+  }
+
+  /// The resource a Reference points to.
+  fn resolve(&self, reference: &Value, root: &Value) -> Value {
+    let target = match reference["reference"]
+      .as_str()
+      .and_then(ReferenceTarget::parse)
+    {
+      Some(target) => target,
+      None => return Value::Null,
+    };
+    if target.kind == ReferenceKind::Contained {
+      let container = ResourceList::new(root);
+      return ContainedResolver::new(&container)
+        .resolve(&target)
+        .map_or(Value::Null, |resource| resource.to_json());
+    }
+    if let Some(resolver) = self.resolver {
+      return resolver
+        .resolve(&target)
+        .map_or(Value::Null, |resource| resource.to_json());
+    }
+    let found = match (&target.kind, &target.resource_type, &target.id) {
+      (ReferenceKind::Relative, Some(resource_type), Some(id)) => match &target.version {
+        Some(version) => self.store.vread(resource_type, id, version),
+        None => self.store.read(resource_type, id),
+      },
+      _ => return Value::Null,
+    };
+    found.unwrap_or(Value::Null)
+  }
+
+  /// Completes a resolved value to the field's type: lists item by item,
+  /// scalars as they are, and objects by executing the fields' selections.
+  fn complete<'d>(
+    &self,
+    context: &mut Context<'d>,
+    field_type: &TypeRef,
+    value: Value,
+    object_type: Option<&str>,
+    root: &Value,
+    fields: &[&'d Field],
+  ) -> Value {
+    let name = match field_type {
+      TypeRef::NonNull(inner) => {
+        return self.complete(context, inner, value, object_type, root, fields)
+      }
+      TypeRef::List(inner) => {
+        let items = match value {
+          Value::Null => return Value::Null,
+          Value::Array(items) => items,
+          item => vec![item],
+        };
+        let mut completed = vec![];
+        for (index, item) in items.into_iter().enumerate() {
+          context.path.push(json!(index));
+          completed.push(self.complete(context, inner, item, object_type, root, fields));
+          context.path.pop();
+        }
+        return Value::Array(completed);
+      }
+      TypeRef::Named(name) => name,
+    };
+    if value.is_null() {
+      return Value::Null;
+    }
+    let selected = fields.iter().any(|field| !field.selections.is_empty());
+    let object_type = match self.schema.get(name) {
+      Some(TypeDefinition::Scalar) | Some(TypeDefinition::Enum(_)) => {
+        if selected {
+          return context.error(&format!(
+            "{} of type {} has no fields",
+            fields[0].name, name
+          ));
+        }
+        return value;
+      }
+      Some(TypeDefinition::Object(_)) => name.to_string(),
+      Some(TypeDefinition::Union(_)) => {
+        match object_type.or_else(|| value["resourceType"].as_str()) {
+          Some(object_type) if self.schema.is_possible_type(name, object_type) => {
+            object_type.to_string()
+          }
+          _ => return context.error(&format!("The type of {} is not known", fields[0].name)),
+        }
+      }
+      None => return context.error(&format!("Unknown type {}", name)),
+    };
+    if !selected {
+      return context.error(&format!(
+        "{} of type {} needs a selection of fields",
+        fields[0].name, name
+      ));
+    }
+    let selection_sets: Vec<&'d [Selection]> =
+      fields.iter().map(|field| &field.selections[..]).collect();
+    let root = if value.get("resourceType").is_some() {
+      &value
+    } else {
+      root
+    };
+    self.object(context, &object_type, &value, root, &selection_sets)
+  }
+}
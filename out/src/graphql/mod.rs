@@ -0,0 +1,153 @@
+//! GraphQL over a `ResourceStore`.
+//!
+//! The generator writes the schema SDL to `schema.graphql`: an object type
+//! per FHIR type, choice elements as unions (`Observation.value` is an
+//! `ObservationValue`, a union of `Quantity`, `StringValue`, ...),
+//! `ResourceList` as the union of all resources, and on `Query` a read
+//! and a search entry point per resource type. `Executor` runs queries
+//! against that schema in process, resolving every field from the JSON of
+//! the resources; `Reference.resource` resolves the reference.
+//!
+//! Only queries are supported, not mutations or subscriptions, and the
+//! schema is not introspectable beyond `__typename`.
+
+pub mod execute;
+pub mod schema;
+pub mod syntax;
+
+pub use execute::Executor;
+pub use schema::{Schema, FHIR_SDL};
+pub use syntax::{parse_document, Document};
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphQlError {
+  /// The text does not parse; `position` is a byte offset.
+  Syntax { position: usize, message: String },
+  /// The request cannot be run, such as when a required variable is
+  /// missing.
+  Request(String),
+}
+
+impl fmt::Display for GraphQlError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      GraphQlError::Syntax { position, message } => {
+        write!(f, "Syntax error at {}: {}", position, message)
+      }
+      GraphQlError::Request(message) => write!(f, "{}", message),
+    }
+  }
+}
+
+impl std::error::Error for GraphQlError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::store::{MemoryStore, ResourceStore};
+  use serde_json::{json, Value};
+  use std::collections::HashMap;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  fn store() -> MemoryStore {
+    let mut store = MemoryStore::new();
+    store.update(load("patient-example.json"), None).unwrap();
+    store
+      .update(load("observation-example.json"), None)
+      .unwrap();
+    store
+  }
+
+  #[test]
+  fn test_execute() {
+    let store = store();
+    let executor = Executor::new(&store);
+    let response = executor.execute(
+      r#"
+      query Weight($patient: String) {
+        ObservationList(subject: $patient, _count: 1) {
+          id
+          status
+          value {
+            __typename
+            ... on Quantity { value unit }
+            ... on StringValue { value }
+          }
+          subject {
+            reference
+            resource { ... on Patient { gender name { family } } }
+          }
+        }
+        missing: Patient(id: "unknown") { id }
+      }
+      "#,
+      None,
+      &json!({ "patient": "Patient/example" }),
+    );
+    assert_eq!(
+      response["data"]["ObservationList"],
+      json!([{
+        "id": "example",
+        "status": "final",
+        "value": { "__typename": "Quantity", "value": 185, "unit": "lbs" },
+        "subject": {
+          "reference": "Patient/example",
+          "resource": {
+            "gender": "male",
+            "name": [{ "family": "Chalmers" }, { "family": null }, { "family": "Windsor" }]
+          }
+        }
+      }])
+    );
+    assert_eq!(response["data"]["missing"], Value::Null);
+    assert_eq!(response["errors"][0]["path"], json!(["missing"]));
+
+    let deceased = executor.execute(
+      "{ Patient(id: \"example\") { deceased { ... on BooleanValue { value } } } }",
+      None,
+      &Value::Null,
+    );
+    assert_eq!(
+      deceased,
+      json!({ "data": { "Patient": { "deceased": { "value": false } } } })
+    );
+
+    let invalid = executor.execute("{ Patient(id: \"example\") { age } }", None, &Value::Null);
+    assert_eq!(invalid["data"]["Patient"], json!({ "age": null }));
+    assert_eq!(
+      invalid["errors"][0]["message"],
+      "Cannot query field age on type Patient"
+    );
+    assert_eq!(
+      executor.execute("mutation { Patient(id: \"x\") { id } }", None, &Value::Null)["errors"][0]
+        ["message"],
+      "Only queries are supported"
+    );
+  }
+
+  #[test]
+  fn test_resolver() {
+    let store = store();
+    let mut organizations = HashMap::new();
+    organizations.insert(
+      "Organization/1".to_string(),
+      json!({ "resourceType": "Organization", "id": "1", "name": "Gastroenterology" }),
+    );
+    let mut executor = Executor::new(&store);
+    executor.resolver(&organizations);
+    let response = executor.execute_request(&json!({
+      "query": "query Organization { Patient(id: \"example\") { managingOrganization { resource { ...named } } } } fragment named on Organization { name }"
+    }));
+    assert_eq!(
+      response["data"]["Patient"]["managingOrganization"]["resource"],
+      json!({ "name": "Gastroenterology" })
+    );
+  }
+}
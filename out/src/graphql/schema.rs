@@ -0,0 +1,246 @@
+//! GraphQL schemas, as parsed from SDL.
+//!
+//! `Schema::fhir` is the schema the generator writes to `schema.graphql`.
+
+use super::syntax::{InputValue, Parser, TypeRef};
+use super::GraphQlError;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// The SDL of the FHIR schema, generated from `fhir.schema.json`.
+pub const FHIR_SDL: &str = include_str!("../../schema.graphql");
+
+const BUILT_IN_SCALARS: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArgumentDefinition {
+  pub name: String,
+  pub arg_type: TypeRef,
+  pub default: Option<InputValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDefinition {
+  pub name: String,
+  pub arguments: Vec<ArgumentDefinition>,
+  pub field_type: TypeRef,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeDefinition {
+  Scalar,
+  Enum(Vec<String>),
+  Object(Vec<FieldDefinition>),
+  /// The names of the member object types.
+  Union(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+  query: String,
+  types: HashMap<String, TypeDefinition>,
+}
+
+impl Schema {
+  /// Parses schema SDL: `schema`, `scalar`, `type`, `union` and `enum`
+  /// definitions. Descriptions and directives are ignored.
+  pub fn parse(sdl: &str) -> Result<Schema, GraphQlError> {
+    let mut parser = Parser::new(sdl)?;
+    let mut schema = Schema {
+      query: "Query".to_string(),
+      types: BUILT_IN_SCALARS
+        .iter()
+        .map(|name| (name.to_string(), TypeDefinition::Scalar))
+        .collect(),
+    };
+    while parser.peek().is_some() {
+      parser.description();
+      let keyword = parser.name()?;
+      if keyword == "schema" {
+        parser.directives(true)?;
+        parser.expect("{")?;
+        while !parser.eat("}") {
+          let operation = parser.name()?;
+          parser.expect(":")?;
+          let name = parser.name()?;
+          if operation == "query" {
+            schema.query = name;
+          }
+        }
+        continue;
+      }
+      let name = parser.name()?;
+      let definition = match keyword.as_str() {
+        "scalar" => {
+          parser.directives(true)?;
+          TypeDefinition::Scalar
+        }
+        "type" => {
+          parser.directives(true)?;
+          TypeDefinition::Object(Schema::fields(&mut parser)?)
+        }
+        "union" => {
+          parser.directives(true)?;
+          parser.expect("=")?;
+          parser.eat("|");
+          let mut members = vec![parser.name()?];
+          while parser.eat("|") {
+            members.push(parser.name()?);
+          }
+          TypeDefinition::Union(members)
+        }
+        "enum" => {
+          parser.directives(true)?;
+          parser.expect("{")?;
+          let mut values = vec![];
+          while !parser.eat("}") {
+            parser.description();
+            values.push(parser.name()?);
+            parser.directives(true)?;
+          }
+          TypeDefinition::Enum(values)
+        }
+        _ => return parser.error(&format!("{} definitions are not supported", keyword)),
+      };
+      schema.types.insert(name, definition);
+    }
+    Ok(schema)
+  }
+
+  fn fields(parser: &mut Parser) -> Result<Vec<FieldDefinition>, GraphQlError> {
+    parser.expect("{")?;
+    let mut fields = vec![];
+    while !parser.eat("}") {
+      parser.description();
+      let name = parser.name()?;
+      let mut arguments = vec![];
+      if parser.eat("(") {
+        while !parser.eat(")") {
+          parser.description();
+          let name = parser.name()?;
+          parser.expect(":")?;
+          let arg_type = parser.type_ref()?;
+          let default = if parser.eat("=") {
+            Some(parser.value(true)?)
+          } else {
+            None
+          };
+          parser.directives(true)?;
+          arguments.push(ArgumentDefinition {
+            name,
+            arg_type,
+            default,
+          });
+        }
+      }
+      parser.expect(":")?;
+      let field_type = parser.type_ref()?;
+      parser.directives(true)?;
+      fields.push(FieldDefinition {
+        name,
+        arguments,
+        field_type,
+      });
+    }
+    Ok(fields)
+  }
+
+  /// The schema generated from the FHIR definitions, parsed once.
+  pub fn fhir() -> &'static Schema {
+    static SCHEMA: OnceLock<Schema> = OnceLock::new();
+    SCHEMA.get_or_init(|| Schema::parse(FHIR_SDL).expect("schema.graphql does not parse"))
+  }
+
+  /// The name of the root query type.
+  pub fn query_type(&self) -> &str {
+    &self.query
+  }
+
+  pub fn get(&self, name: &str) -> Option<&TypeDefinition> {
+    self.types.get(name)
+  }
+
+  pub fn type_names(&self) -> impl Iterator<Item = &str> {
+    self.types.keys().map(|name| name.as_str())
+  }
+
+  pub fn field(&self, type_name: &str, name: &str) -> Option<&FieldDefinition> {
+    match self.types.get(type_name) {
+      Some(TypeDefinition::Object(fields)) => fields.iter().find(|field| field.name == name),
+      _ => None,
+    }
+  }
+
+  /// Whether a value of the object type `object` is also of `type_name`,
+  /// as it is of a union it is a member of.
+  pub fn is_possible_type(&self, type_name: &str, object: &str) -> bool {
+    match self.types.get(type_name) {
+      Some(TypeDefinition::Union(members)) => members.iter().any(|member| member == object),
+      _ => type_name == object,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fhir_schema() {
+    let schema = Schema::fhir();
+    assert_eq!(schema.query_type(), "Query");
+    let read = schema.field("Query", "Patient").unwrap();
+    assert_eq!(read.field_type, TypeRef::Named("Patient".to_string()));
+    assert_eq!(read.arguments[0].name, "id");
+    let list = schema.field("Query", "ObservationList").unwrap();
+    assert!(list
+      .arguments
+      .iter()
+      .any(|argument| argument.name == "value_quantity"));
+    assert_eq!(list.field_type.name(), "Observation");
+
+    let value = schema.field("Observation", "value").unwrap();
+    assert_eq!(value.field_type.name(), "ObservationValue");
+    assert!(schema.is_possible_type("ObservationValue", "Quantity"));
+    assert!(schema.is_possible_type("ObservationValue", "StringValue"));
+    assert!(schema.field("Observation", "valueQuantity").is_none());
+    assert!(schema.is_possible_type("ResourceList", "Patient"));
+    assert_eq!(
+      schema
+        .field("Reference", "resource")
+        .unwrap()
+        .field_type
+        .name(),
+      "ResourceList"
+    );
+
+    // Every type a field, argument or union refers to is defined.
+    for name in schema.type_names() {
+      match schema.get(name).unwrap() {
+        TypeDefinition::Object(fields) => {
+          for field in fields {
+            assert!(
+              schema.get(field.field_type.name()).is_some(),
+              "{}.{}",
+              name,
+              field.name
+            );
+            for argument in &field.arguments {
+              assert!(schema.get(argument.arg_type.name()).is_some());
+            }
+          }
+        }
+        TypeDefinition::Union(members) => {
+          for member in members {
+            assert!(
+              matches!(schema.get(member), Some(TypeDefinition::Object(_))),
+              "{}",
+              member
+            );
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+}
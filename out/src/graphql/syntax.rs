@@ -0,0 +1,588 @@
+//! Tokenizing GraphQL text, and parsing executable documents (queries and
+//! fragments).
+
+use super::GraphQlError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+  Name(String),
+  Int(String),
+  Float(String),
+  Text(String),
+  Punctuator(&'static str),
+}
+
+const PUNCTUATORS: &[&str] = &[
+  "...", "!", "$", "&", "(", ")", ":", "=", "@", "[", "]", "{", "|", "}",
+];
+
+fn syntax(position: usize, message: &str) -> GraphQlError {
+  GraphQlError::Syntax {
+    position,
+    message: message.to_string(),
+  }
+}
+
+/// Reads a `"` string starting after the quote, returning its contents
+/// and the offset after the closing quote.
+fn string(text: &str, start: usize) -> Result<(String, usize), GraphQlError> {
+  let mut string = String::new();
+  let mut chars = text[start..].char_indices();
+  while let Some((offset, c)) = chars.next() {
+    match c {
+      '"' => return Ok((string, start + offset + 1)),
+      '\n' | '\r' => break,
+      '\\' => {
+        let escaped = match chars.next() {
+          Some((_, 'n')) => '\n',
+          Some((_, 'r')) => '\r',
+          Some((_, 't')) => '\t',
+          Some((_, 'b')) => '\u{8}',
+          Some((_, 'f')) => '\u{c}',
+          Some((_, 'u')) => {
+            let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+            u32::from_str_radix(&hex, 16)
+              .ok()
+              .and_then(char::from_u32)
+              .ok_or_else(|| syntax(start + offset, "Invalid unicode escape"))?
+          }
+          Some((_, c)) => c,
+          None => break,
+        };
+        string.push(escaped);
+      }
+      c => string.push(c),
+    }
+  }
+  Err(syntax(start - 1, "Unterminated string"))
+}
+
+pub(crate) fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, GraphQlError> {
+  let mut tokens = vec![];
+  let mut i = 0;
+  while let Some(c) = text[i..].chars().next() {
+    let rest = &text[i..];
+    if c.is_whitespace() || c == ',' || c == '\u{feff}' {
+      i += c.len_utf8();
+    } else if c == '#' {
+      i += rest.find('\n').unwrap_or(rest.len());
+    } else if c.is_ascii_alphabetic() || c == '_' {
+      let length = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+      tokens.push((i, Token::Name(rest[..length].to_string())));
+      i += length;
+    } else if c.is_ascii_digit() || c == '-' {
+      let length = rest[1..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')))
+        .map_or(rest.len(), |length| length + 1);
+      let number = &rest[..length];
+      let token = if number.parse::<i64>().is_ok() {
+        Token::Int(number.to_string())
+      } else if number.parse::<f64>().is_ok() && number.ends_with(|c: char| c.is_ascii_digit()) {
+        Token::Float(number.to_string())
+      } else {
+        return Err(syntax(i, &format!("Invalid number {}", number)));
+      };
+      tokens.push((i, token));
+      i += length;
+    } else if let Some(inner) = rest.strip_prefix("\"\"\"") {
+      // Block strings are kept as written, without removing indentation.
+      let end = inner
+        .find("\"\"\"")
+        .ok_or_else(|| syntax(i, "Unterminated string"))?;
+      let block = inner[..end].replace("\\\"\"\"", "\"\"\"");
+      tokens.push((i, Token::Text(block)));
+      i += end + 6;
+    } else if c == '"' {
+      let (string, end) = string(text, i + 1)?;
+      tokens.push((i, Token::Text(string)));
+      i = end;
+    } else {
+      let punctuator = PUNCTUATORS
+        .iter()
+        .find(|punctuator| rest.starts_with(*punctuator))
+        .ok_or_else(|| syntax(i, &format!("Unexpected {}", c)))?;
+      tokens.push((i, Token::Punctuator(punctuator)));
+      i += punctuator.len();
+    }
+  }
+  Ok(tokens)
+}
+
+/// A type in a variable, argument or field definition.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeRef {
+  Named(String),
+  List(Box<TypeRef>),
+  NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+  /// The name of the type, inside any list and non-null wrappers.
+  pub fn name(&self) -> &str {
+    match self {
+      TypeRef::Named(name) => name,
+      TypeRef::List(inner) | TypeRef::NonNull(inner) => inner.name(),
+    }
+  }
+}
+
+/// An argument or variable value as written in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputValue {
+  Variable(String),
+  Null,
+  Int(i64),
+  Float(f64),
+  String(String),
+  Boolean(bool),
+  Enum(String),
+  List(Vec<InputValue>),
+  Object(Vec<(String, InputValue)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+  pub name: String,
+  pub arguments: Vec<(String, InputValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+  pub alias: Option<String>,
+  pub name: String,
+  pub arguments: Vec<(String, InputValue)>,
+  pub directives: Vec<Directive>,
+  pub selections: Vec<Selection>,
+}
+
+impl Field {
+  /// The key of the field in the response: its alias or its name.
+  pub fn key(&self) -> &str {
+    self.alias.as_deref().unwrap_or(&self.name)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+  Field(Field),
+  FragmentSpread {
+    name: String,
+    directives: Vec<Directive>,
+  },
+  InlineFragment {
+    type_condition: Option<String>,
+    directives: Vec<Directive>,
+    selections: Vec<Selection>,
+  },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OperationKind {
+  Query,
+  Mutation,
+  Subscription,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableDefinition {
+  pub name: String,
+  pub var_type: TypeRef,
+  pub default: Option<InputValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+  pub kind: OperationKind,
+  pub name: Option<String>,
+  pub variables: Vec<VariableDefinition>,
+  pub selections: Vec<Selection>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fragment {
+  pub name: String,
+  pub type_condition: String,
+  pub selections: Vec<Selection>,
+}
+
+/// A parsed query document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+  pub operations: Vec<Operation>,
+  pub fragments: Vec<Fragment>,
+}
+
+impl Document {
+  pub fn fragment(&self, name: &str) -> Option<&Fragment> {
+    self.fragments.iter().find(|fragment| fragment.name == name)
+  }
+}
+
+pub(crate) struct Parser {
+  tokens: Vec<(usize, Token)>,
+  next: usize,
+  end: usize,
+}
+
+impl Parser {
+  pub(crate) fn new(text: &str) -> Result<Parser, GraphQlError> {
+    Ok(Parser {
+      tokens: tokenize(text)?,
+      next: 0,
+      end: text.len(),
+    })
+  }
+
+  pub(crate) fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.next).map(|(_, token)| token)
+  }
+
+  pub(crate) fn error<T>(&self, message: &str) -> Result<T, GraphQlError> {
+    let position = self
+      .tokens
+      .get(self.next)
+      .map_or(self.end, |(position, _)| *position);
+    Err(syntax(position, message))
+  }
+
+  pub(crate) fn is(&self, punctuator: &str) -> bool {
+    matches!(self.peek(), Some(Token::Punctuator(p)) if *p == punctuator)
+  }
+
+  pub(crate) fn is_keyword(&self, keyword: &str) -> bool {
+    matches!(self.peek(), Some(Token::Name(name)) if name == keyword)
+  }
+
+  /// Skips the punctuator if it is next.
+  pub(crate) fn eat(&mut self, punctuator: &str) -> bool {
+    let found = self.is(punctuator);
+    if found {
+      self.next += 1;
+    }
+    found
+  }
+
+  pub(crate) fn expect(&mut self, punctuator: &str) -> Result<(), GraphQlError> {
+    if self.eat(punctuator) {
+      Ok(())
+    } else {
+      self.error(&format!("Expected {}", punctuator))
+    }
+  }
+
+  pub(crate) fn name(&mut self) -> Result<String, GraphQlError> {
+    match self.peek() {
+      Some(Token::Name(name)) => {
+        let name = name.clone();
+        self.next += 1;
+        Ok(name)
+      }
+      _ => self.error("Expected a name"),
+    }
+  }
+
+  pub(crate) fn keyword(&mut self, keyword: &str) -> Result<(), GraphQlError> {
+    if self.is_keyword(keyword) {
+      self.next += 1;
+      Ok(())
+    } else {
+      self.error(&format!("Expected {}", keyword))
+    }
+  }
+
+  /// Skips a description, as written before definitions in a schema.
+  pub(crate) fn description(&mut self) {
+    if let Some(Token::Text(_)) = self.peek() {
+      self.next += 1;
+    }
+  }
+
+  pub(crate) fn type_ref(&mut self) -> Result<TypeRef, GraphQlError> {
+    let type_ref = if self.eat("[") {
+      let inner = self.type_ref()?;
+      self.expect("]")?;
+      TypeRef::List(Box::new(inner))
+    } else {
+      TypeRef::Named(self.name()?)
+    };
+    if self.eat("!") {
+      Ok(TypeRef::NonNull(Box::new(type_ref)))
+    } else {
+      Ok(type_ref)
+    }
+  }
+
+  pub(crate) fn value(&mut self, constant: bool) -> Result<InputValue, GraphQlError> {
+    let token = match self.peek() {
+      Some(token) => token.clone(),
+      None => return self.error("Expected a value"),
+    };
+    if let Token::Punctuator(punctuator) = token {
+      return match punctuator {
+        "$" if !constant => {
+          self.next += 1;
+          Ok(InputValue::Variable(self.name()?))
+        }
+        "[" => {
+          self.next += 1;
+          let mut items = vec![];
+          while !self.eat("]") {
+            items.push(self.value(constant)?);
+          }
+          Ok(InputValue::List(items))
+        }
+        "{" => {
+          self.next += 1;
+          let mut fields = vec![];
+          while !self.eat("}") {
+            let name = self.name()?;
+            self.expect(":")?;
+            fields.push((name, self.value(constant)?));
+          }
+          Ok(InputValue::Object(fields))
+        }
+        _ => self.error("Expected a value"),
+      };
+    }
+    self.next += 1;
+    Ok(match token {
+      Token::Int(number) => InputValue::Int(number.parse().unwrap_or_default()),
+      Token::Float(number) => InputValue::Float(number.parse().unwrap_or_default()),
+      Token::Text(text) => InputValue::String(text),
+      Token::Name(name) => match name.as_str() {
+        "true" => InputValue::Boolean(true),
+        "false" => InputValue::Boolean(false),
+        "null" => InputValue::Null,
+        _ => InputValue::Enum(name),
+      },
+      Token::Punctuator(_) => unreachable!(),
+    })
+  }
+
+  pub(crate) fn arguments(
+    &mut self,
+    constant: bool,
+  ) -> Result<Vec<(String, InputValue)>, GraphQlError> {
+    let mut arguments = vec![];
+    if self.eat("(") {
+      while !self.eat(")") {
+        let name = self.name()?;
+        self.expect(":")?;
+        arguments.push((name, self.value(constant)?));
+      }
+    }
+    Ok(arguments)
+  }
+
+  pub(crate) fn directives(&mut self, constant: bool) -> Result<Vec<Directive>, GraphQlError> {
+    let mut directives = vec![];
+    while self.eat("@") {
+      directives.push(Directive {
+        name: self.name()?,
+        arguments: self.arguments(constant)?,
+      });
+    }
+    Ok(directives)
+  }
+
+  fn selections(&mut self) -> Result<Vec<Selection>, GraphQlError> {
+    self.expect("{")?;
+    let mut selections = vec![];
+    while !self.eat("}") {
+      if self.peek().is_none() {
+        return self.error("Expected }");
+      }
+      selections.push(self.selection()?);
+    }
+    if selections.is_empty() {
+      return self.error("Expected a selection");
+    }
+    Ok(selections)
+  }
+
+  fn selection(&mut self) -> Result<Selection, GraphQlError> {
+    if self.eat("...") {
+      if self.is_keyword("on") || self.is("@") || self.is("{") {
+        let type_condition = if self.is_keyword("on") {
+          self.next += 1;
+          Some(self.name()?)
+        } else {
+          None
+        };
+        return Ok(Selection::InlineFragment {
+          type_condition,
+          directives: self.directives(false)?,
+          selections: self.selections()?,
+        });
+      }
+      return Ok(Selection::FragmentSpread {
+        name: self.name()?,
+        directives: self.directives(false)?,
+      });
+    }
+    let mut name = self.name()?;
+    let mut alias = None;
+    if self.eat(":") {
+      alias = Some(name);
+      name = self.name()?;
+    }
+    let arguments = self.arguments(false)?;
+    let directives = self.directives(false)?;
+    let selections = if self.is("{") {
+      self.selections()?
+    } else {
+      vec![]
+    };
+    Ok(Selection::Field(Field {
+      alias,
+      name,
+      arguments,
+      directives,
+      selections,
+    }))
+  }
+
+  fn operation(&mut self) -> Result<Operation, GraphQlError> {
+    if self.is("{") {
+      return Ok(Operation {
+        kind: OperationKind::Query,
+        name: None,
+        variables: vec![],
+        selections: self.selections()?,
+      });
+    }
+    let kind = match self.name()?.as_str() {
+      "query" => OperationKind::Query,
+      "mutation" => OperationKind::Mutation,
+      "subscription" => OperationKind::Subscription,
+      _ => {
+        self.next -= 1;
+        return self.error("Expected an operation or fragment");
+      }
+    };
+    let name = match self.peek() {
+      Some(Token::Name(_)) => Some(self.name()?),
+      _ => None,
+    };
+    let mut variables = vec![];
+    if self.eat("(") {
+      while !self.eat(")") {
+        self.expect("$")?;
+        let name = self.name()?;
+        self.expect(":")?;
+        let var_type = self.type_ref()?;
+        let default = if self.eat("=") {
+          Some(self.value(true)?)
+        } else {
+          None
+        };
+        self.directives(true)?;
+        variables.push(VariableDefinition {
+          name,
+          var_type,
+          default,
+        });
+      }
+    }
+    self.directives(false)?;
+    Ok(Operation {
+      kind,
+      name,
+      variables,
+      selections: self.selections()?,
+    })
+  }
+
+  fn fragment(&mut self) -> Result<Fragment, GraphQlError> {
+    self.keyword("fragment")?;
+    let name = self.name()?;
+    self.keyword("on")?;
+    let type_condition = self.name()?;
+    self.directives(false)?;
+    Ok(Fragment {
+      name,
+      type_condition,
+      selections: self.selections()?,
+    })
+  }
+}
+
+/// Parses a query document.
+pub fn parse_document(text: &str) -> Result<Document, GraphQlError> {
+  let mut parser = Parser::new(text)?;
+  let mut document = Document {
+    operations: vec![],
+    fragments: vec![],
+  };
+  while parser.peek().is_some() {
+    if parser.is_keyword("fragment") {
+      document.fragments.push(parser.fragment()?);
+    } else {
+      document.operations.push(parser.operation()?);
+    }
+  }
+  if document.operations.is_empty() {
+    return parser.error("Expected an operation");
+  }
+  Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_document() {
+    let document = parse_document(
+      r#"
+      query Vitals($patient: String!, $count: Int = 10) {
+        ObservationList(subject: $patient, _count: $count) {
+          id
+          ... on Observation { status }
+          value { ...quantity }
+          code { text @include(if: true) }
+        }
+      }
+      # The value of an Observation.
+      fragment quantity on Quantity { amount: value, unit }
+      "#,
+    )
+    .unwrap();
+    let operation = &document.operations[0];
+    assert_eq!(operation.name.as_deref(), Some("Vitals"));
+    assert_eq!(
+      operation.variables[0].var_type,
+      TypeRef::NonNull(Box::new(TypeRef::Named("String".to_string())))
+    );
+    assert_eq!(operation.variables[1].default, Some(InputValue::Int(10)));
+    let list = match &operation.selections[0] {
+      Selection::Field(field) => field,
+      other => panic!("unexpected {:?}", other),
+    };
+    assert_eq!(
+      list.arguments[0],
+      (
+        "subject".to_string(),
+        InputValue::Variable("patient".to_string())
+      )
+    );
+    assert_eq!(list.selections.len(), 4);
+    let fragment = document.fragment("quantity").unwrap();
+    match &fragment.selections[0] {
+      Selection::Field(field) => {
+        assert_eq!((field.key(), field.name.as_str()), ("amount", "value"))
+      }
+      other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+      parse_document("{ Patient(id: \"example\") { name { given } }"),
+      Err(GraphQlError::Syntax {
+        position: 43,
+        message: "Expected }".to_string()
+      })
+    );
+    assert!(parse_document("{ Patient(id: -) { id } }").is_err());
+  }
+}
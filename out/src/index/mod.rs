@@ -0,0 +1,289 @@
+//! Search indexing driven by SearchParameter definitions.
+//!
+//! Each parameter's FHIRPath `expression` is evaluated against a resource
+//! and the elements it selects become typed `IndexValue`s, so that every
+//! store can answer "what are this Observation's `code`, `date` and
+//! `subject` values?" the same way, and `search` matches queries against
+//! those values. Composite and special parameters are not indexed.
+
+pub mod search;
+pub mod value;
+
+pub use search::Query;
+pub use value::{extract, normalise, IndexValue};
+
+use crate::fhirpath::{Expression, FhirPathError};
+use crate::model::Bundle::Bundle;
+use crate::model::SearchParameter::{SearchParameter, SearchParameterType};
+use serde_json::Value;
+use std::fmt;
+use std::sync::OnceLock;
+
+include!("search_parameters.rs");
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexError {
+  /// The SearchParameter has no `code`, `base` or `type`.
+  Incomplete(String),
+  /// The expression of the parameter with this code does not parse.
+  Expression { code: String, error: FhirPathError },
+}
+
+impl fmt::Display for IndexError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      IndexError::Incomplete(name) => {
+        write!(f, "SearchParameter {} needs a code, base and type", name)
+      }
+      IndexError::Expression { code, error } => write!(f, "{}: {}", code, error),
+    }
+  }
+}
+
+impl std::error::Error for IndexError {}
+
+/// A search parameter ready to be evaluated.
+#[derive(Debug)]
+pub struct IndexedParameter {
+  pub code: String,
+  pub base: Vec<String>,
+  pub param_type: SearchParameterType,
+  pub expression: Expression,
+}
+
+impl IndexedParameter {
+  /// Whether the parameter applies to resources of the type.
+  pub fn applies_to(&self, resource_type: &str) -> bool {
+    self
+      .base
+      .iter()
+      .any(|base| base == resource_type || base == "Resource" || base == "DomainResource")
+  }
+}
+
+/// One search value of a resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexEntry {
+  /// The parameter's `code`, e.g. `subject`.
+  pub code: String,
+  pub value: IndexValue,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchIndexer {
+  parameters: Vec<IndexedParameter>,
+}
+
+impl SearchIndexer {
+  pub fn new() -> SearchIndexer {
+    SearchIndexer::default()
+  }
+
+  /// The parameters of the FHIR specification, indexed once from the table
+  /// generated from `search-parameters.json`. Stores search with these
+  /// unless they implement `search` themselves.
+  pub fn fhir() -> &'static SearchIndexer {
+    static INDEXER: OnceLock<SearchIndexer> = OnceLock::new();
+    INDEXER.get_or_init(|| {
+      let mut indexer = SearchIndexer::new();
+      for (code, base, param_type, text) in SEARCH_PARAMETERS {
+        if let Some(param_type) = SearchParameterType::from_string(param_type) {
+          let base = base.iter().map(|b| b.to_string()).collect();
+          // The table is generated from definitions that parse.
+          let _ = indexer.insert(code, base, param_type, text);
+        }
+      }
+      indexer
+    })
+  }
+
+  /// Adds a parameter. Returns whether it is indexed: composite and special
+  /// parameters and those without an expression are not.
+  pub fn add(&mut self, parameter: &SearchParameter) -> Result<bool, IndexError> {
+    let name = || {
+      parameter
+        .url()
+        .or_else(|| parameter.id())
+        .unwrap_or_default()
+        .to_string()
+    };
+    let (code, base, param_type) = match (parameter.code(), parameter.base(), parameter.fhir_type())
+    {
+      (Some(code), Some(base), Some(param_type)) => (code, base, param_type),
+      _ => return Err(IndexError::Incomplete(name())),
+    };
+    let text = match (&param_type, parameter.expression()) {
+      (SearchParameterType::Composite, _) | (SearchParameterType::Special, _) | (_, None) => {
+        return Ok(false)
+      }
+      (_, Some(text)) => text,
+    };
+    let base = base.iter().map(|b| b.to_string()).collect();
+    self.insert(code, base, param_type, text)?;
+    Ok(true)
+  }
+
+  fn insert(
+    &mut self,
+    code: &str,
+    base: Vec<String>,
+    param_type: SearchParameterType,
+    text: &str,
+  ) -> Result<(), IndexError> {
+    let expression = Expression::parse(text).map_err(|error| IndexError::Expression {
+      code: code.to_string(),
+      error,
+    })?;
+    self.parameters.push(IndexedParameter {
+      code: code.to_string(),
+      base,
+      param_type,
+      expression,
+    });
+    Ok(())
+  }
+
+  /// Adds the SearchParameters in a Bundle, such as the definitions in
+  /// `search-parameters.json`, and returns the errors of those that could
+  /// not be added.
+  pub fn add_bundle(&mut self, bundle: &Bundle) -> Vec<IndexError> {
+    let mut errors = vec![];
+    for entry in bundle.value["entry"].as_array().into_iter().flatten() {
+      let resource = &entry["resource"];
+      if resource["resourceType"] == "SearchParameter" {
+        if let Err(error) = self.add(&SearchParameter::new(resource)) {
+          errors.push(error);
+        }
+      }
+    }
+    errors
+  }
+
+  /// The parameters that apply to resources of the type.
+  pub fn parameters(&self, resource_type: &str) -> Vec<&IndexedParameter> {
+    self
+      .parameters
+      .iter()
+      .filter(|parameter| parameter.applies_to(resource_type))
+      .collect()
+  }
+
+  pub fn parameter(&self, resource_type: &str, code: &str) -> Option<&IndexedParameter> {
+    self
+      .parameters
+      .iter()
+      .find(|parameter| parameter.code == code && parameter.applies_to(resource_type))
+  }
+
+  fn evaluate(parameter: &IndexedParameter, resource: &Value) -> Vec<IndexValue> {
+    let mut values: Vec<IndexValue> = vec![];
+    for node in parameter.expression.evaluate(resource) {
+      for value in extract(&parameter.param_type, &node) {
+        if !values.contains(&value) {
+          values.push(value);
+        }
+      }
+    }
+    values
+  }
+
+  /// The search values of a resource for every parameter that applies.
+  pub fn index(&self, resource: &Value) -> Vec<IndexEntry> {
+    let resource_type = resource["resourceType"].as_str().unwrap_or_default();
+    self
+      .parameters(resource_type)
+      .into_iter()
+      .flat_map(|parameter| {
+        Self::evaluate(parameter, resource)
+          .into_iter()
+          .map(move |value| IndexEntry {
+            code: parameter.code.clone(),
+            value,
+          })
+      })
+      .collect()
+  }
+
+  /// The search values of a resource for one parameter.
+  pub fn values(&self, resource: &Value, code: &str) -> Vec<IndexValue> {
+    let resource_type = resource["resourceType"].as_str().unwrap_or_default();
+    self
+      .parameter(resource_type, code)
+      .map(|parameter| Self::evaluate(parameter, resource))
+      .unwrap_or_default()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::temporal::{DateTime, Interval};
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_index_observation() {
+    let mut indexer = SearchIndexer::new();
+    let definitions = load("search-parameters.json");
+    assert_eq!(indexer.add_bundle(&Bundle::new(&definitions)), vec![]);
+    assert_eq!(
+      SearchIndexer::fhir().parameters.len(),
+      indexer.parameters.len()
+    );
+
+    let observation = load("observation-example.json");
+    let codes = indexer.values(&observation, "code");
+    assert_eq!(codes.len(), 4);
+    assert_eq!(
+      codes[0],
+      IndexValue::Token {
+        system: Some("http://loinc.org".to_string()),
+        code: Some("29463-7".to_string())
+      }
+    );
+    let day = DateTime::parse("2016-03-28").unwrap();
+    assert_eq!(
+      indexer.values(&observation, "date"),
+      vec![IndexValue::Date(Interval::of(&day))]
+    );
+    match &indexer.values(&observation, "subject")[..] {
+      [IndexValue::Reference { reference, target }] => {
+        assert_eq!(reference, "Patient/example");
+        assert_eq!(target.as_ref().unwrap().id.as_deref(), Some("example"));
+      }
+      other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(indexer.values(&observation, "patient").len(), 1);
+    match &indexer.values(&observation, "value-quantity")[..] {
+      [IndexValue::Quantity {
+        value,
+        canonical: Some((grams, unit)),
+        ..
+      }] => {
+        assert_eq!(*value, 185.0);
+        assert_eq!(unit, "g");
+        assert!((grams - 83914.6).abs() < 0.1);
+      }
+      other => panic!("unexpected {:?}", other),
+    }
+
+    let patient = load("patient-example.json");
+    let names = indexer.values(&patient, "name");
+    assert!(names.contains(&IndexValue::String("chalmers".to_string())));
+    assert!(names.contains(&IndexValue::String("jim".to_string())));
+    assert!(indexer
+      .values(&patient, "phone")
+      .contains(&IndexValue::Token {
+        system: None,
+        code: Some("(03) 5555 6473".to_string())
+      }));
+    let entries = indexer.index(&patient);
+    assert!(entries.iter().any(|entry| entry.code == "gender"));
+    assert!(entries.iter().any(|entry| entry.code == "_id"));
+    assert!(entries.iter().all(|entry| entry.code != "code"));
+  }
+}
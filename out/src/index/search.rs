@@ -0,0 +1,394 @@
+//! Searching with the values a `SearchIndexer` indexes.
+//!
+//! A search is compiled against the SearchParameters of one resource type
+//! first, so an unknown parameter, an unsupported modifier or a malformed
+//! value is an error before any resource is looked at. Each value is then
+//! compared with the `IndexValue`s of a resource the way its parameter
+//! type asks for: strings by normalised prefix, tokens by system and code,
+//! numbers, dates and quantities with prefixes such as `ge`, references by
+//! type and id, and URIs exactly.
+
+use super::value::{texts, IndexValue};
+use super::{normalise, IndexedParameter, SearchIndexer};
+use crate::model::SearchParameter::SearchParameterType;
+use crate::reference::ReferenceTarget;
+use crate::rest::is_resource_type;
+use crate::store::criteria::{criteria, number_range, split_escaped, unescape, Criterion, Prefix};
+use crate::store::StoreError;
+use crate::temporal::{DateTime, Interval};
+use crate::ucum::{Unit, UCUM_SYSTEM};
+use serde_json::Value;
+
+/// One searched value, parsed for its parameter's type.
+#[derive(Debug, Clone, PartialEq)]
+enum Wanted {
+  Number(Prefix, f64, f64),
+  Date(Prefix, Interval),
+  /// A normalised string, matched as a prefix or, with `:contains`,
+  /// anywhere.
+  String(String, bool),
+  /// A string as written, for `:exact`.
+  Exact(String),
+  /// `None` matches any system or code; an empty system matches none.
+  Token {
+    system: Option<String>,
+    code: Option<String>,
+  },
+  Quantity {
+    prefix: Prefix,
+    value: f64,
+    half: f64,
+    system: Option<String>,
+    code: Option<String>,
+  },
+  Reference {
+    resource_type: Option<String>,
+    id: Option<String>,
+    reference: String,
+  },
+  /// A URI, and the modifier `below` or `above` if any.
+  Uri(String, Option<String>),
+}
+
+fn text_or_any(text: &str) -> Option<String> {
+  Some(unescape(text)).filter(|text| !text.is_empty())
+}
+
+impl Wanted {
+  fn parse(param_type: &SearchParameterType, criterion: &Criterion, value: &str) -> Option<Wanted> {
+    let modifier = criterion.modifier.as_deref();
+    Some(match param_type {
+      SearchParameterType::Number => {
+        let (prefix, rest) = Prefix::split(value);
+        let (value, half) = number_range(rest)?;
+        Wanted::Number(prefix, value, half)
+      }
+      SearchParameterType::Date => {
+        let (prefix, rest) = Prefix::split(value);
+        Wanted::Date(prefix, Interval::of(&DateTime::parse(rest)?))
+      }
+      SearchParameterType::String => match modifier {
+        Some("exact") => Wanted::Exact(unescape(value)),
+        _ => Wanted::String(normalise(&unescape(value)), modifier == Some("contains")),
+      },
+      SearchParameterType::Token => match split_escaped(value, '|').as_slice() {
+        [code] => Wanted::Token {
+          system: None,
+          code: text_or_any(code),
+        },
+        [system, code] => Wanted::Token {
+          system: Some(unescape(system)),
+          code: text_or_any(code),
+        },
+        _ => return None,
+      },
+      SearchParameterType::Quantity => {
+        let parts = split_escaped(value, '|');
+        if parts.len() > 3 {
+          return None;
+        }
+        let (prefix, rest) = Prefix::split(parts[0]);
+        let (value, half) = number_range(rest)?;
+        Wanted::Quantity {
+          prefix,
+          value,
+          half,
+          system: parts.get(1).and_then(|system| text_or_any(system)),
+          code: parts.get(2).and_then(|code| text_or_any(code)),
+        }
+      }
+      SearchParameterType::Reference => {
+        let reference = unescape(value);
+        match modifier.filter(|modifier| is_resource_type(modifier)) {
+          Some(resource_type) => Wanted::Reference {
+            resource_type: Some(resource_type.to_string()),
+            id: Some(reference.clone()),
+            reference: format!("{}/{}", resource_type, reference),
+          },
+          None if !reference.contains('/') => Wanted::Reference {
+            resource_type: None,
+            id: Some(reference.clone()),
+            reference,
+          },
+          None => {
+            let target = ReferenceTarget::parse(&reference);
+            Wanted::Reference {
+              resource_type: target.as_ref().and_then(|t| t.resource_type.clone()),
+              id: target.and_then(|t| t.id),
+              reference,
+            }
+          }
+        }
+      }
+      SearchParameterType::Uri => Wanted::Uri(unescape(value), modifier.map(|m| m.to_string())),
+      SearchParameterType::Composite | SearchParameterType::Special => return None,
+    })
+  }
+
+  fn matches(&self, indexed: &IndexValue) -> bool {
+    match (self, indexed) {
+      (Wanted::Number(prefix, value, half), IndexValue::Number(target)) => {
+        prefix.numbers(*target, *value, *half)
+      }
+      (Wanted::Date(prefix, search), IndexValue::Date(target)) => prefix.dates(target, search),
+      (Wanted::String(text, contains), IndexValue::String(target)) => {
+        if *contains {
+          target.contains(text.as_str())
+        } else {
+          target.starts_with(text.as_str())
+        }
+      }
+      (
+        Wanted::Token { system, code },
+        IndexValue::Token {
+          system: target_system,
+          code: target_code,
+        },
+      ) => {
+        let system_matches = match system.as_deref() {
+          None => true,
+          Some("") => target_system.is_none(),
+          system => target_system.as_deref() == system,
+        };
+        system_matches && (code.is_none() || target_code == code)
+      }
+      (
+        Wanted::Quantity {
+          prefix,
+          value,
+          half,
+          system,
+          code,
+        },
+        IndexValue::Quantity {
+          value: target,
+          system: target_system,
+          code: target_code,
+          canonical,
+        },
+      ) => {
+        // UCUM quantities compare in canonical units, so `1|..|kg` finds
+        // `1000 g`.
+        if let (Some(UCUM_SYSTEM), Some(code), Some((target, unit))) =
+          (system.as_deref(), code, canonical)
+        {
+          if let Ok(searched) = Unit::parse(code) {
+            if searched.canonical_code() == *unit {
+              let canonical = searched.to_canonical(*value);
+              let half = (searched.to_canonical(value + half) - canonical).abs();
+              return prefix.numbers(*target, canonical, half);
+            }
+          }
+        }
+        prefix.numbers(*target, *value, *half)
+          && (system.is_none() || target_system == system)
+          && (code.is_none() || target_code == code)
+      }
+      (
+        Wanted::Reference {
+          resource_type,
+          id,
+          reference,
+        },
+        IndexValue::Reference {
+          reference: target_reference,
+          target,
+        },
+      ) => {
+        target_reference == reference
+          || target.as_ref().is_some_and(|target| {
+            id.is_some()
+              && target.id == *id
+              && (resource_type.is_none() || target.resource_type == *resource_type)
+          })
+      }
+      (Wanted::Uri(uri, modifier), IndexValue::Uri(target)) => match modifier.as_deref() {
+        Some("below") => target.starts_with(uri.as_str()),
+        Some("above") => uri.starts_with(target.as_str()),
+        _ => target == uri,
+      },
+      _ => false,
+    }
+  }
+}
+
+/// Whether a parameter of the type can take the modifier.
+fn supports(param_type: &SearchParameterType, modifier: &str) -> bool {
+  match (param_type, modifier) {
+    (_, "missing") | (_, "not") => true,
+    (SearchParameterType::String, "exact") | (SearchParameterType::String, "contains") => true,
+    (SearchParameterType::Uri, "below") | (SearchParameterType::Uri, "above") => true,
+    (SearchParameterType::Reference, modifier) => is_resource_type(modifier),
+    _ => false,
+  }
+}
+
+#[derive(Debug)]
+struct Clause<'i> {
+  parameter: &'i IndexedParameter,
+  /// For `:missing`, whether the parameter must have no values.
+  missing: Option<bool>,
+  negated: bool,
+  values: Vec<Wanted>,
+}
+
+impl Clause<'_> {
+  fn matches(&self, resource: &Value) -> bool {
+    let indexed = SearchIndexer::evaluate(self.parameter, resource);
+    if let Some(missing) = self.missing {
+      return indexed.is_empty() == missing;
+    }
+    let mut written = vec![];
+    if self
+      .values
+      .iter()
+      .any(|wanted| matches!(wanted, Wanted::Exact(_)))
+    {
+      for node in self.parameter.expression.evaluate(resource) {
+        texts(&node.value, &mut written);
+      }
+    }
+    let found = self.values.iter().any(|wanted| match wanted {
+      Wanted::Exact(text) => written.contains(text),
+      _ => indexed.iter().any(|value| wanted.matches(value)),
+    });
+    found != self.negated
+  }
+}
+
+/// A search of one resource type, ready to be matched against resources.
+#[derive(Debug)]
+pub struct Query<'i> {
+  clauses: Vec<Clause<'i>>,
+}
+
+impl Query<'_> {
+  /// Whether a resource matches every criterion of the search.
+  pub fn matches(&self, resource: &Value) -> bool {
+    self.clauses.iter().all(|clause| clause.matches(resource))
+  }
+}
+
+impl SearchIndexer {
+  /// Compiles search parameters for resources of the type. Result
+  /// parameters such as `_count` are left out; parameters without an
+  /// indexed definition, such as `_has`, are an error.
+  pub fn query(
+    &self,
+    resource_type: &str,
+    params: &[(String, String)],
+  ) -> Result<Query<'_>, StoreError> {
+    let mut clauses = vec![];
+    for criterion in criteria(params) {
+      let parameter = self
+        .parameter(resource_type, &criterion.name)
+        .ok_or_else(|| {
+          StoreError::Search(format!(
+            "{} is not a search parameter of {}",
+            criterion.name, resource_type
+          ))
+        })?;
+      let modifier = criterion.modifier.as_deref();
+      if modifier.is_some_and(|modifier| !supports(&parameter.param_type, modifier)) {
+        return Err(criterion.unsupported());
+      }
+      let missing = match modifier {
+        Some("missing") => Some(criterion.missing()?),
+        _ => None,
+      };
+      let mut values = vec![];
+      if missing.is_none() {
+        for value in &criterion.values {
+          let wanted =
+            Wanted::parse(&parameter.param_type, &criterion, value).ok_or_else(|| {
+              StoreError::Search(format!(
+                "{} is not a valid value of {}",
+                unescape(value),
+                criterion.name
+              ))
+            })?;
+          values.push(wanted);
+        }
+      }
+      clauses.push(Clause {
+        parameter,
+        missing,
+        negated: modifier == Some("not"),
+        values,
+      });
+    }
+    Ok(Query { clauses })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::store::criteria::parse_query;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_search_query() {
+    let indexer = SearchIndexer::fhir();
+    let patient = load("patient-example.json");
+    let query = |q: &str| {
+      indexer
+        .query("Patient", &parse_query(q))
+        .unwrap()
+        .matches(&patient)
+    };
+    assert!(query("identifier=urn:oid:1.2.36.146.595.217.0.1|12345"));
+    assert!(query("identifier=12345"));
+    assert!(!query("identifier=|12345"));
+    assert!(!query("identifier=http://example.org|12345"));
+    assert!(query("family=chal&given=Jim"));
+    assert!(query("birthdate=1974-12-25&gender=male"));
+    assert!(query("birthdate=ge1974-01-01&birthdate=le1974-12-25"));
+    assert!(!query("birthdate=gt1974-12-25") && query("birthdate=1974"));
+    assert!(query("gender=female,male") && query("gender:not=female"));
+    assert!(!query("family:exact=chalmers") && query("family:exact=Chalmers"));
+    assert!(!query("family=alm") && query("family:contains=alm"));
+    assert!(query("family=nobody,wïndsor"));
+    assert!(query("family=windsor,x") && !query("family=windsor\\,x"));
+    assert!(query("organization=Organization/1") && query("organization:Organization=1"));
+    assert!(!query("organization:Group=1"));
+    assert!(query("_id=example&_count=10"));
+    assert!(query("deceased:missing=false"));
+
+    let observation = load("observation-example.json");
+    let query = |q: &str| {
+      indexer
+        .query("Observation", &parse_query(q))
+        .unwrap()
+        .matches(&observation)
+    };
+    assert!(query(
+      "value-quantity=185|http://unitsofmeasure.org|[lb_av]"
+    ));
+    assert!(query("value-quantity=gt83|http://unitsofmeasure.org|kg"));
+    assert!(!query("value-quantity=lt80|http://unitsofmeasure.org|kg"));
+    assert!(query(
+      "subject=Patient/example&code=http://loinc.org|29463-7"
+    ));
+
+    let error = |q: &str| indexer.query("Patient", &parse_query(q)).unwrap_err();
+    assert_eq!(
+      error("_has:Observation:patient:code=1"),
+      StoreError::Search("_has is not a search parameter of Patient".to_string())
+    );
+    assert_eq!(error("gender:below=male").status(), 400);
+    assert_eq!(
+      error("birthdate=ge1974-13"),
+      StoreError::Search("ge1974-13 is not a valid value of birthdate".to_string())
+    );
+    assert!(matches!(
+      error("deceased:missing=maybe"),
+      StoreError::Search(_)
+    ));
+  }
+}
@@ -0,0 +1,1331 @@
+// Generated from examples-json/search-parameters.json by fhir-rs-parser. Do not edit.
+
+/// `(code, base, type, expression)` of the SearchParameters of the FHIR
+/// specification that can be indexed.
+pub(crate) const SEARCH_PARAMETERS: &[(&str, &[&str], &str, &str)] = &[
+    ("_id", &["Resource"], "token", "Resource.id"),
+    ("_lastUpdated", &["Resource"], "date", "Resource.meta.lastUpdated"),
+    ("_profile", &["Resource"], "uri", "Resource.meta.profile"),
+    ("_security", &["Resource"], "token", "Resource.meta.security"),
+    ("_source", &["Resource"], "uri", "Resource.meta.source"),
+    ("_tag", &["Resource"], "token", "Resource.meta.tag"),
+    ("identifier", &["Account"], "token", "Account.identifier"),
+    ("name", &["Account"], "string", "Account.name"),
+    ("owner", &["Account"], "reference", "Account.owner"),
+    ("patient", &["Account"], "reference", "Account.subject.where(resolve() is Patient)"),
+    ("period", &["Account"], "date", "Account.servicePeriod"),
+    ("status", &["Account"], "token", "Account.status"),
+    ("subject", &["Account"], "reference", "Account.subject"),
+    ("type", &["Account"], "token", "Account.type"),
+    ("composed-of", &["ActivityDefinition"], "reference", "ActivityDefinition.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["ActivityDefinition"], "token", "(ActivityDefinition.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["ActivityDefinition"], "quantity", "(ActivityDefinition.useContext.value as Quantity) | (ActivityDefinition.useContext.value as Range)"),
+    ("context-type", &["ActivityDefinition"], "token", "ActivityDefinition.useContext.code"),
+    ("date", &["ActivityDefinition"], "date", "ActivityDefinition.date"),
+    ("depends-on", &["ActivityDefinition"], "reference", "ActivityDefinition.relatedArtifact.where(type='depends-on').resource | ActivityDefinition.library"),
+    ("derived-from", &["ActivityDefinition"], "reference", "ActivityDefinition.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["ActivityDefinition"], "string", "ActivityDefinition.description"),
+    ("effective", &["ActivityDefinition"], "date", "ActivityDefinition.effectivePeriod"),
+    ("identifier", &["ActivityDefinition"], "token", "ActivityDefinition.identifier"),
+    ("jurisdiction", &["ActivityDefinition"], "token", "ActivityDefinition.jurisdiction"),
+    ("name", &["ActivityDefinition"], "string", "ActivityDefinition.name"),
+    ("predecessor", &["ActivityDefinition"], "reference", "ActivityDefinition.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["ActivityDefinition"], "string", "ActivityDefinition.publisher"),
+    ("status", &["ActivityDefinition"], "token", "ActivityDefinition.status"),
+    ("successor", &["ActivityDefinition"], "reference", "ActivityDefinition.relatedArtifact.where(type='successor').resource"),
+    ("title", &["ActivityDefinition"], "string", "ActivityDefinition.title"),
+    ("topic", &["ActivityDefinition"], "token", "ActivityDefinition.topic"),
+    ("url", &["ActivityDefinition"], "uri", "ActivityDefinition.url"),
+    ("version", &["ActivityDefinition"], "token", "ActivityDefinition.version"),
+    ("actuality", &["AdverseEvent"], "token", "AdverseEvent.actuality"),
+    ("category", &["AdverseEvent"], "token", "AdverseEvent.category"),
+    ("date", &["AdverseEvent"], "date", "AdverseEvent.date"),
+    ("event", &["AdverseEvent"], "token", "AdverseEvent.event"),
+    ("location", &["AdverseEvent"], "reference", "AdverseEvent.location"),
+    ("recorder", &["AdverseEvent"], "reference", "AdverseEvent.recorder"),
+    ("resultingcondition", &["AdverseEvent"], "reference", "AdverseEvent.resultingCondition"),
+    ("seriousness", &["AdverseEvent"], "token", "AdverseEvent.seriousness"),
+    ("severity", &["AdverseEvent"], "token", "AdverseEvent.severity"),
+    ("study", &["AdverseEvent"], "reference", "AdverseEvent.study"),
+    ("subject", &["AdverseEvent"], "reference", "AdverseEvent.subject"),
+    ("substance", &["AdverseEvent"], "reference", "AdverseEvent.suspectEntity.instance"),
+    ("asserter", &["AllergyIntolerance"], "reference", "AllergyIntolerance.asserter"),
+    ("category", &["AllergyIntolerance"], "token", "AllergyIntolerance.category"),
+    ("clinical-status", &["AllergyIntolerance"], "token", "AllergyIntolerance.clinicalStatus"),
+    ("code", &["AllergyIntolerance", "Condition", "DeviceRequest", "DiagnosticReport", "FamilyMemberHistory", "List", "Medication", "MedicationAdministration", "MedicationDispense", "MedicationRequest", "MedicationStatement", "Observation", "Procedure", "ServiceRequest"], "token", "AllergyIntolerance.code | AllergyIntolerance.reaction.substance | Condition.code | (DeviceRequest.code as CodeableConcept) | DiagnosticReport.code | FamilyMemberHistory.condition.code | List.code | Medication.code | (MedicationAdministration.medication as CodeableConcept) | (MedicationDispense.medication as CodeableConcept) | (MedicationRequest.medication as CodeableConcept) | (MedicationStatement.medication as CodeableConcept) | Observation.code | Procedure.code | ServiceRequest.code"),
+    ("criticality", &["AllergyIntolerance"], "token", "AllergyIntolerance.criticality"),
+    ("date", &["AllergyIntolerance", "CarePlan", "CareTeam", "ClinicalImpression", "Composition", "Consent", "DiagnosticReport", "Encounter", "EpisodeOfCare", "FamilyMemberHistory", "Flag", "Immunization", "List", "Observation", "Procedure", "RiskAssessment", "SupplyRequest"], "date", "AllergyIntolerance.recordedDate | CarePlan.period | CareTeam.period | ClinicalImpression.date | Composition.date | Consent.dateTime | DiagnosticReport.effective | Encounter.period | EpisodeOfCare.period | FamilyMemberHistory.date | Flag.period | Immunization.occurrence | List.date | Observation.effective | Procedure.performed | (RiskAssessment.occurrence as dateTime) | SupplyRequest.authoredOn"),
+    ("identifier", &["AllergyIntolerance", "CarePlan", "CareTeam", "Composition", "Condition", "Consent", "DetectedIssue", "DeviceRequest", "DiagnosticReport", "DocumentManifest", "DocumentReference", "Encounter", "EpisodeOfCare", "FamilyMemberHistory", "Goal", "ImagingStudy", "Immunization", "List", "MedicationAdministration", "MedicationDispense", "MedicationRequest", "MedicationStatement", "NutritionOrder", "Observation", "Procedure", "RiskAssessment", "ServiceRequest", "SupplyDelivery", "SupplyRequest", "VisionPrescription"], "token", "AllergyIntolerance.identifier | CarePlan.identifier | CareTeam.identifier | Composition.identifier | Condition.identifier | Consent.identifier | DetectedIssue.identifier | DeviceRequest.identifier | DiagnosticReport.identifier | DocumentManifest.masterIdentifier | DocumentManifest.identifier | DocumentReference.masterIdentifier | DocumentReference.identifier | Encounter.identifier | EpisodeOfCare.identifier | FamilyMemberHistory.identifier | Goal.identifier | ImagingStudy.identifier | Immunization.identifier | List.identifier | MedicationAdministration.identifier | MedicationDispense.identifier | MedicationRequest.identifier | MedicationStatement.identifier | NutritionOrder.identifier | Observation.identifier | Procedure.identifier | RiskAssessment.identifier | ServiceRequest.identifier | SupplyDelivery.identifier | SupplyRequest.identifier | VisionPrescription.identifier"),
+    ("last-date", &["AllergyIntolerance"], "date", "AllergyIntolerance.lastOccurrence"),
+    ("manifestation", &["AllergyIntolerance"], "token", "AllergyIntolerance.reaction.manifestation"),
+    ("onset", &["AllergyIntolerance"], "date", "AllergyIntolerance.reaction.onset"),
+    ("patient", &["AllergyIntolerance", "CarePlan", "CareTeam", "ClinicalImpression", "Composition", "Condition", "Consent", "DetectedIssue", "DeviceRequest", "DeviceUseStatement", "DiagnosticReport", "DocumentManifest", "DocumentReference", "Encounter", "EpisodeOfCare", "FamilyMemberHistory", "Flag", "Goal", "ImagingStudy", "Immunization", "List", "MedicationAdministration", "MedicationDispense", "MedicationRequest", "MedicationStatement", "NutritionOrder", "Observation", "Procedure", "RiskAssessment", "ServiceRequest", "SupplyDelivery", "VisionPrescription"], "reference", "AllergyIntolerance.patient | CarePlan.subject.where(resolve() is Patient) | CareTeam.subject.where(resolve() is Patient) | ClinicalImpression.subject.where(resolve() is Patient) | Composition.subject.where(resolve() is Patient) | Condition.subject.where(resolve() is Patient) | Consent.patient | DetectedIssue.patient | DeviceRequest.subject.where(resolve() is Patient) | DeviceUseStatement.subject | DiagnosticReport.subject.where(resolve() is Patient) | DocumentManifest.subject.where(resolve() is Patient) | DocumentReference.subject.where(resolve() is Patient) | Encounter.subject.where(resolve() is Patient) | EpisodeOfCare.patient | FamilyMemberHistory.patient | Flag.subject.where(resolve() is Patient) | Goal.subject.where(resolve() is Patient) | ImagingStudy.subject.where(resolve() is Patient) | Immunization.patient | List.subject.where(resolve() is Patient) | MedicationAdministration.subject.where(resolve() is Patient) | MedicationDispense.subject.where(resolve() is Patient) | MedicationRequest.subject.where(resolve() is Patient) | MedicationStatement.subject.where(resolve() is Patient) | NutritionOrder.patient | Observation.subject.where(resolve() is Patient) | Procedure.subject.where(resolve() is Patient) | RiskAssessment.subject.where(resolve() is Patient) | ServiceRequest.subject.where(resolve() is Patient) | SupplyDelivery.patient | VisionPrescription.patient"),
+    ("recorder", &["AllergyIntolerance"], "reference", "AllergyIntolerance.recorder"),
+    ("route", &["AllergyIntolerance"], "token", "AllergyIntolerance.reaction.exposureRoute"),
+    ("severity", &["AllergyIntolerance"], "token", "AllergyIntolerance.reaction.severity"),
+    ("type", &["AllergyIntolerance", "Composition", "DocumentManifest", "DocumentReference", "Encounter", "EpisodeOfCare"], "token", "AllergyIntolerance.type | Composition.type | DocumentManifest.type | DocumentReference.type | Encounter.type | EpisodeOfCare.type"),
+    ("verification-status", &["AllergyIntolerance"], "token", "AllergyIntolerance.verificationStatus"),
+    ("actor", &["Appointment"], "reference", "Appointment.participant.actor"),
+    ("appointment-type", &["Appointment"], "token", "Appointment.appointmentType"),
+    ("based-on", &["Appointment"], "reference", "Appointment.basedOn"),
+    ("date", &["Appointment"], "date", "Appointment.start"),
+    ("identifier", &["Appointment"], "token", "Appointment.identifier"),
+    ("location", &["Appointment"], "reference", "Appointment.participant.actor.where(resolve() is Location)"),
+    ("part-status", &["Appointment"], "token", "Appointment.participant.status"),
+    ("patient", &["Appointment"], "reference", "Appointment.participant.actor.where(resolve() is Patient)"),
+    ("practitioner", &["Appointment"], "reference", "Appointment.participant.actor.where(resolve() is Practitioner)"),
+    ("reason-code", &["Appointment"], "token", "Appointment.reasonCode"),
+    ("reason-reference", &["Appointment"], "reference", "Appointment.reasonReference"),
+    ("service-category", &["Appointment"], "token", "Appointment.serviceCategory"),
+    ("service-type", &["Appointment"], "token", "Appointment.serviceType"),
+    ("slot", &["Appointment"], "reference", "Appointment.slot"),
+    ("specialty", &["Appointment"], "token", "Appointment.specialty"),
+    ("status", &["Appointment"], "token", "Appointment.status"),
+    ("supporting-info", &["Appointment"], "reference", "Appointment.supportingInformation"),
+    ("actor", &["AppointmentResponse"], "reference", "AppointmentResponse.actor"),
+    ("appointment", &["AppointmentResponse"], "reference", "AppointmentResponse.appointment"),
+    ("identifier", &["AppointmentResponse"], "token", "AppointmentResponse.identifier"),
+    ("location", &["AppointmentResponse"], "reference", "AppointmentResponse.actor.where(resolve() is Location)"),
+    ("part-status", &["AppointmentResponse"], "token", "AppointmentResponse.participantStatus"),
+    ("patient", &["AppointmentResponse"], "reference", "AppointmentResponse.actor.where(resolve() is Patient)"),
+    ("practitioner", &["AppointmentResponse"], "reference", "AppointmentResponse.actor.where(resolve() is Practitioner)"),
+    ("action", &["AuditEvent"], "token", "AuditEvent.action"),
+    ("address", &["AuditEvent"], "string", "AuditEvent.agent.network.address"),
+    ("agent", &["AuditEvent"], "reference", "AuditEvent.agent.who"),
+    ("agent-name", &["AuditEvent"], "string", "AuditEvent.agent.name"),
+    ("agent-role", &["AuditEvent"], "token", "AuditEvent.agent.role"),
+    ("altid", &["AuditEvent"], "token", "AuditEvent.agent.altId"),
+    ("date", &["AuditEvent"], "date", "AuditEvent.recorded"),
+    ("entity", &["AuditEvent"], "reference", "AuditEvent.entity.what"),
+    ("entity-name", &["AuditEvent"], "string", "AuditEvent.entity.name"),
+    ("entity-role", &["AuditEvent"], "token", "AuditEvent.entity.role"),
+    ("entity-type", &["AuditEvent"], "token", "AuditEvent.entity.type"),
+    ("outcome", &["AuditEvent"], "token", "AuditEvent.outcome"),
+    ("patient", &["AuditEvent"], "reference", "AuditEvent.agent.who.where(resolve() is Patient) | AuditEvent.entity.what.where(resolve() is Patient)"),
+    ("policy", &["AuditEvent"], "uri", "AuditEvent.agent.policy"),
+    ("site", &["AuditEvent"], "token", "AuditEvent.source.site"),
+    ("source", &["AuditEvent"], "reference", "AuditEvent.source.observer"),
+    ("subtype", &["AuditEvent"], "token", "AuditEvent.subtype"),
+    ("type", &["AuditEvent"], "token", "AuditEvent.type"),
+    ("author", &["Basic"], "reference", "Basic.author"),
+    ("code", &["Basic"], "token", "Basic.code"),
+    ("created", &["Basic"], "date", "Basic.created"),
+    ("identifier", &["Basic"], "token", "Basic.identifier"),
+    ("patient", &["Basic"], "reference", "Basic.subject.where(resolve() is Patient)"),
+    ("subject", &["Basic"], "reference", "Basic.subject"),
+    ("identifier", &["BodyStructure"], "token", "BodyStructure.identifier"),
+    ("location", &["BodyStructure"], "token", "BodyStructure.location"),
+    ("morphology", &["BodyStructure"], "token", "BodyStructure.morphology"),
+    ("patient", &["BodyStructure"], "reference", "BodyStructure.patient"),
+    ("composition", &["Bundle"], "reference", "Bundle.entry[0].resource"),
+    ("identifier", &["Bundle"], "token", "Bundle.identifier"),
+    ("message", &["Bundle"], "reference", "Bundle.entry[0].resource"),
+    ("timestamp", &["Bundle"], "date", "Bundle.timestamp"),
+    ("type", &["Bundle"], "token", "Bundle.type"),
+    ("context", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "token", "(CapabilityStatement.useContext.value as CodeableConcept) | (CodeSystem.useContext.value as CodeableConcept) | (CompartmentDefinition.useContext.value as CodeableConcept) | (ConceptMap.useContext.value as CodeableConcept) | (GraphDefinition.useContext.value as CodeableConcept) | (ImplementationGuide.useContext.value as CodeableConcept) | (MessageDefinition.useContext.value as CodeableConcept) | (NamingSystem.useContext.value as CodeableConcept) | (OperationDefinition.useContext.value as CodeableConcept) | (SearchParameter.useContext.value as CodeableConcept) | (StructureDefinition.useContext.value as CodeableConcept) | (StructureMap.useContext.value as CodeableConcept) | (TerminologyCapabilities.useContext.value as CodeableConcept) | (ValueSet.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "quantity", "(CapabilityStatement.useContext.value as Quantity) | (CapabilityStatement.useContext.value as Range) | (CodeSystem.useContext.value as Quantity) | (CodeSystem.useContext.value as Range) | (CompartmentDefinition.useContext.value as Quantity) | (CompartmentDefinition.useContext.value as Range) | (ConceptMap.useContext.value as Quantity) | (ConceptMap.useContext.value as Range) | (GraphDefinition.useContext.value as Quantity) | (GraphDefinition.useContext.value as Range) | (ImplementationGuide.useContext.value as Quantity) | (ImplementationGuide.useContext.value as Range) | (MessageDefinition.useContext.value as Quantity) | (MessageDefinition.useContext.value as Range) | (NamingSystem.useContext.value as Quantity) | (NamingSystem.useContext.value as Range) | (OperationDefinition.useContext.value as Quantity) | (OperationDefinition.useContext.value as Range) | (SearchParameter.useContext.value as Quantity) | (SearchParameter.useContext.value as Range) | (StructureDefinition.useContext.value as Quantity) | (StructureDefinition.useContext.value as Range) | (StructureMap.useContext.value as Quantity) | (StructureMap.useContext.value as Range) | (TerminologyCapabilities.useContext.value as Quantity) | (TerminologyCapabilities.useContext.value as Range) | (ValueSet.useContext.value as Quantity) | (ValueSet.useContext.value as Range)"),
+    ("context-type", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "token", "CapabilityStatement.useContext.code | CodeSystem.useContext.code | CompartmentDefinition.useContext.code | ConceptMap.useContext.code | GraphDefinition.useContext.code | ImplementationGuide.useContext.code | MessageDefinition.useContext.code | NamingSystem.useContext.code | OperationDefinition.useContext.code | SearchParameter.useContext.code | StructureDefinition.useContext.code | StructureMap.useContext.code | TerminologyCapabilities.useContext.code | ValueSet.useContext.code"),
+    ("date", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "date", "CapabilityStatement.date | CodeSystem.date | CompartmentDefinition.date | ConceptMap.date | GraphDefinition.date | ImplementationGuide.date | MessageDefinition.date | NamingSystem.date | OperationDefinition.date | SearchParameter.date | StructureDefinition.date | StructureMap.date | TerminologyCapabilities.date | ValueSet.date"),
+    ("description", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "string", "CapabilityStatement.description | CodeSystem.description | CompartmentDefinition.description | ConceptMap.description | GraphDefinition.description | ImplementationGuide.description | MessageDefinition.description | NamingSystem.description | OperationDefinition.description | SearchParameter.description | StructureDefinition.description | StructureMap.description | TerminologyCapabilities.description | ValueSet.description"),
+    ("fhirversion", &["CapabilityStatement"], "token", "CapabilityStatement.version"),
+    ("format", &["CapabilityStatement"], "token", "CapabilityStatement.format"),
+    ("guide", &["CapabilityStatement"], "reference", "CapabilityStatement.implementationGuide"),
+    ("jurisdiction", &["CapabilityStatement", "CodeSystem", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "token", "CapabilityStatement.jurisdiction | CodeSystem.jurisdiction | ConceptMap.jurisdiction | GraphDefinition.jurisdiction | ImplementationGuide.jurisdiction | MessageDefinition.jurisdiction | NamingSystem.jurisdiction | OperationDefinition.jurisdiction | SearchParameter.jurisdiction | StructureDefinition.jurisdiction | StructureMap.jurisdiction | TerminologyCapabilities.jurisdiction | ValueSet.jurisdiction"),
+    ("mode", &["CapabilityStatement"], "token", "CapabilityStatement.rest.mode"),
+    ("name", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "string", "CapabilityStatement.name | CodeSystem.name | CompartmentDefinition.name | ConceptMap.name | GraphDefinition.name | ImplementationGuide.name | MessageDefinition.name | NamingSystem.name | OperationDefinition.name | SearchParameter.name | StructureDefinition.name | StructureMap.name | TerminologyCapabilities.name | ValueSet.name"),
+    ("publisher", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "string", "CapabilityStatement.publisher | CodeSystem.publisher | CompartmentDefinition.publisher | ConceptMap.publisher | GraphDefinition.publisher | ImplementationGuide.publisher | MessageDefinition.publisher | NamingSystem.publisher | OperationDefinition.publisher | SearchParameter.publisher | StructureDefinition.publisher | StructureMap.publisher | TerminologyCapabilities.publisher | ValueSet.publisher"),
+    ("resource", &["CapabilityStatement"], "token", "CapabilityStatement.rest.resource.type"),
+    ("resource-profile", &["CapabilityStatement"], "reference", "CapabilityStatement.rest.resource.profile"),
+    ("security-service", &["CapabilityStatement"], "token", "CapabilityStatement.rest.security.service"),
+    ("software", &["CapabilityStatement"], "string", "CapabilityStatement.software.name"),
+    ("status", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "NamingSystem", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "token", "CapabilityStatement.status | CodeSystem.status | CompartmentDefinition.status | ConceptMap.status | GraphDefinition.status | ImplementationGuide.status | MessageDefinition.status | NamingSystem.status | OperationDefinition.status | SearchParameter.status | StructureDefinition.status | StructureMap.status | TerminologyCapabilities.status | ValueSet.status"),
+    ("supported-profile", &["CapabilityStatement"], "reference", "CapabilityStatement.rest.resource.supportedProfile"),
+    ("title", &["CapabilityStatement", "CodeSystem", "ConceptMap", "ImplementationGuide", "MessageDefinition", "OperationDefinition", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "string", "CapabilityStatement.title | CodeSystem.title | ConceptMap.title | ImplementationGuide.title | MessageDefinition.title | OperationDefinition.title | StructureDefinition.title | StructureMap.title | TerminologyCapabilities.title | ValueSet.title"),
+    ("url", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "uri", "CapabilityStatement.url | CodeSystem.url | CompartmentDefinition.url | ConceptMap.url | GraphDefinition.url | ImplementationGuide.url | MessageDefinition.url | OperationDefinition.url | SearchParameter.url | StructureDefinition.url | StructureMap.url | TerminologyCapabilities.url | ValueSet.url"),
+    ("version", &["CapabilityStatement", "CodeSystem", "CompartmentDefinition", "ConceptMap", "GraphDefinition", "ImplementationGuide", "MessageDefinition", "OperationDefinition", "SearchParameter", "StructureDefinition", "StructureMap", "TerminologyCapabilities", "ValueSet"], "token", "CapabilityStatement.version | CodeSystem.version | CompartmentDefinition.version | ConceptMap.version | GraphDefinition.version | ImplementationGuide.version | MessageDefinition.version | OperationDefinition.version | SearchParameter.version | StructureDefinition.version | StructureMap.version | TerminologyCapabilities.version | ValueSet.version"),
+    ("activity-code", &["CarePlan"], "token", "CarePlan.activity.detail.code"),
+    ("activity-date", &["CarePlan"], "date", "CarePlan.activity.detail.scheduled"),
+    ("activity-reference", &["CarePlan"], "reference", "CarePlan.activity.reference"),
+    ("based-on", &["CarePlan"], "reference", "CarePlan.basedOn"),
+    ("care-team", &["CarePlan"], "reference", "CarePlan.careTeam"),
+    ("category", &["CarePlan"], "token", "CarePlan.category"),
+    ("condition", &["CarePlan"], "reference", "CarePlan.addresses"),
+    ("encounter", &["CarePlan"], "reference", "CarePlan.encounter"),
+    ("goal", &["CarePlan"], "reference", "CarePlan.goal"),
+    ("instantiates-canonical", &["CarePlan"], "reference", "CarePlan.instantiatesCanonical"),
+    ("instantiates-uri", &["CarePlan"], "uri", "CarePlan.instantiatesUri"),
+    ("intent", &["CarePlan"], "token", "CarePlan.intent"),
+    ("part-of", &["CarePlan"], "reference", "CarePlan.partOf"),
+    ("performer", &["CarePlan"], "reference", "CarePlan.activity.detail.performer"),
+    ("replaces", &["CarePlan"], "reference", "CarePlan.replaces"),
+    ("status", &["CarePlan"], "token", "CarePlan.status"),
+    ("subject", &["CarePlan"], "reference", "CarePlan.subject"),
+    ("category", &["CareTeam"], "token", "CareTeam.category"),
+    ("encounter", &["CareTeam"], "reference", "CareTeam.encounter"),
+    ("participant", &["CareTeam"], "reference", "CareTeam.participant.member"),
+    ("status", &["CareTeam"], "token", "CareTeam.status"),
+    ("subject", &["CareTeam"], "reference", "CareTeam.subject"),
+    ("account", &["ChargeItem"], "reference", "ChargeItem.account"),
+    ("code", &["ChargeItem"], "token", "ChargeItem.code"),
+    ("context", &["ChargeItem"], "reference", "ChargeItem.context"),
+    ("entered-date", &["ChargeItem"], "date", "ChargeItem.enteredDate"),
+    ("enterer", &["ChargeItem"], "reference", "ChargeItem.enterer"),
+    ("factor-override", &["ChargeItem"], "number", "ChargeItem.factorOverride"),
+    ("identifier", &["ChargeItem"], "token", "ChargeItem.identifier"),
+    ("occurrence", &["ChargeItem"], "date", "ChargeItem.occurrence"),
+    ("patient", &["ChargeItem"], "reference", "ChargeItem.subject.where(resolve() is Patient)"),
+    ("performer-actor", &["ChargeItem"], "reference", "ChargeItem.performer.actor"),
+    ("performer-function", &["ChargeItem"], "token", "ChargeItem.performer.function"),
+    ("performing-organization", &["ChargeItem"], "reference", "ChargeItem.performingOrganization"),
+    ("price-override", &["ChargeItem"], "quantity", "ChargeItem.priceOverride"),
+    ("quantity", &["ChargeItem"], "quantity", "ChargeItem.quantity"),
+    ("requesting-organization", &["ChargeItem"], "reference", "ChargeItem.requestingOrganization"),
+    ("service", &["ChargeItem"], "reference", "ChargeItem.service"),
+    ("subject", &["ChargeItem"], "reference", "ChargeItem.subject"),
+    ("context", &["ChargeItemDefinition"], "token", "(ChargeItemDefinition.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["ChargeItemDefinition"], "quantity", "(ChargeItemDefinition.useContext.value as Quantity) | (ChargeItemDefinition.useContext.value as Range)"),
+    ("context-type", &["ChargeItemDefinition"], "token", "ChargeItemDefinition.useContext.code"),
+    ("date", &["ChargeItemDefinition"], "date", "ChargeItemDefinition.date"),
+    ("description", &["ChargeItemDefinition"], "string", "ChargeItemDefinition.description"),
+    ("effective", &["ChargeItemDefinition"], "date", "ChargeItemDefinition.effectivePeriod"),
+    ("identifier", &["ChargeItemDefinition"], "token", "ChargeItemDefinition.identifier"),
+    ("jurisdiction", &["ChargeItemDefinition"], "token", "ChargeItemDefinition.jurisdiction"),
+    ("publisher", &["ChargeItemDefinition"], "string", "ChargeItemDefinition.publisher"),
+    ("status", &["ChargeItemDefinition"], "token", "ChargeItemDefinition.status"),
+    ("title", &["ChargeItemDefinition"], "string", "ChargeItemDefinition.title"),
+    ("url", &["ChargeItemDefinition"], "uri", "ChargeItemDefinition.url"),
+    ("version", &["ChargeItemDefinition"], "token", "ChargeItemDefinition.version"),
+    ("care-team", &["Claim"], "reference", "Claim.careTeam.provider"),
+    ("created", &["Claim"], "date", "Claim.created"),
+    ("detail-udi", &["Claim"], "reference", "Claim.item.detail.udi"),
+    ("encounter", &["Claim"], "reference", "Claim.item.encounter"),
+    ("enterer", &["Claim"], "reference", "Claim.enterer"),
+    ("facility", &["Claim"], "reference", "Claim.facility"),
+    ("identifier", &["Claim"], "token", "Claim.identifier"),
+    ("insurer", &["Claim"], "reference", "Claim.insurer"),
+    ("item-udi", &["Claim"], "reference", "Claim.item.udi"),
+    ("patient", &["Claim"], "reference", "Claim.patient"),
+    ("payee", &["Claim"], "reference", "Claim.payee.party"),
+    ("priority", &["Claim"], "token", "Claim.priority"),
+    ("procedure-udi", &["Claim"], "reference", "Claim.procedure.udi"),
+    ("provider", &["Claim"], "reference", "Claim.provider"),
+    ("status", &["Claim"], "token", "Claim.status"),
+    ("subdetail-udi", &["Claim"], "reference", "Claim.item.detail.subDetail.udi"),
+    ("use", &["Claim"], "token", "Claim.use"),
+    ("created", &["ClaimResponse"], "date", "ClaimResponse.created"),
+    ("disposition", &["ClaimResponse"], "string", "ClaimResponse.disposition"),
+    ("identifier", &["ClaimResponse"], "token", "ClaimResponse.identifier"),
+    ("insurer", &["ClaimResponse"], "reference", "ClaimResponse.insurer"),
+    ("outcome", &["ClaimResponse"], "token", "ClaimResponse.outcome"),
+    ("patient", &["ClaimResponse"], "reference", "ClaimResponse.patient"),
+    ("payment-date", &["ClaimResponse"], "date", "ClaimResponse.payment.date"),
+    ("request", &["ClaimResponse"], "reference", "ClaimResponse.request"),
+    ("requestor", &["ClaimResponse"], "reference", "ClaimResponse.requestor"),
+    ("status", &["ClaimResponse"], "token", "ClaimResponse.status"),
+    ("use", &["ClaimResponse"], "token", "ClaimResponse.use"),
+    ("assessor", &["ClinicalImpression"], "reference", "ClinicalImpression.assessor"),
+    ("encounter", &["ClinicalImpression"], "reference", "ClinicalImpression.encounter"),
+    ("finding-code", &["ClinicalImpression"], "token", "ClinicalImpression.finding.itemCodeableConcept"),
+    ("finding-ref", &["ClinicalImpression"], "reference", "ClinicalImpression.finding.itemReference"),
+    ("identifier", &["ClinicalImpression"], "token", "ClinicalImpression.identifier"),
+    ("investigation", &["ClinicalImpression"], "reference", "ClinicalImpression.investigation.item"),
+    ("previous", &["ClinicalImpression"], "reference", "ClinicalImpression.previous"),
+    ("problem", &["ClinicalImpression"], "reference", "ClinicalImpression.problem"),
+    ("status", &["ClinicalImpression"], "token", "ClinicalImpression.status"),
+    ("subject", &["ClinicalImpression"], "reference", "ClinicalImpression.subject"),
+    ("supporting-info", &["ClinicalImpression"], "reference", "ClinicalImpression.supportingInfo"),
+    ("code", &["CodeSystem"], "token", "CodeSystem.concept.code"),
+    ("content-mode", &["CodeSystem"], "token", "CodeSystem.content"),
+    ("identifier", &["CodeSystem", "ConceptMap", "MessageDefinition", "StructureDefinition", "StructureMap", "ValueSet"], "token", "CodeSystem.identifier | ConceptMap.identifier | MessageDefinition.identifier | StructureDefinition.identifier | StructureMap.identifier | ValueSet.identifier"),
+    ("language", &["CodeSystem"], "token", "CodeSystem.concept.designation.language"),
+    ("supplements", &["CodeSystem"], "reference", "CodeSystem.supplements"),
+    ("system", &["CodeSystem"], "uri", "CodeSystem.url"),
+    ("based-on", &["Communication"], "reference", "Communication.basedOn"),
+    ("category", &["Communication"], "token", "Communication.category"),
+    ("encounter", &["Communication"], "reference", "Communication.encounter"),
+    ("identifier", &["Communication"], "token", "Communication.identifier"),
+    ("instantiates-canonical", &["Communication"], "reference", "Communication.instantiatesCanonical"),
+    ("instantiates-uri", &["Communication"], "uri", "Communication.instantiatesUri"),
+    ("medium", &["Communication"], "token", "Communication.medium"),
+    ("part-of", &["Communication"], "reference", "Communication.partOf"),
+    ("patient", &["Communication"], "reference", "Communication.subject.where(resolve() is Patient)"),
+    ("received", &["Communication"], "date", "Communication.received"),
+    ("recipient", &["Communication"], "reference", "Communication.recipient"),
+    ("sender", &["Communication"], "reference", "Communication.sender"),
+    ("sent", &["Communication"], "date", "Communication.sent"),
+    ("status", &["Communication"], "token", "Communication.status"),
+    ("subject", &["Communication"], "reference", "Communication.subject"),
+    ("authored", &["CommunicationRequest"], "date", "CommunicationRequest.authoredOn"),
+    ("based-on", &["CommunicationRequest"], "reference", "CommunicationRequest.basedOn"),
+    ("category", &["CommunicationRequest"], "token", "CommunicationRequest.category"),
+    ("encounter", &["CommunicationRequest"], "reference", "CommunicationRequest.encounter"),
+    ("group-identifier", &["CommunicationRequest"], "token", "CommunicationRequest.groupIdentifier"),
+    ("identifier", &["CommunicationRequest"], "token", "CommunicationRequest.identifier"),
+    ("medium", &["CommunicationRequest"], "token", "CommunicationRequest.medium"),
+    ("occurrence", &["CommunicationRequest"], "date", "(CommunicationRequest.occurrence as dateTime)"),
+    ("patient", &["CommunicationRequest"], "reference", "CommunicationRequest.subject.where(resolve() is Patient)"),
+    ("priority", &["CommunicationRequest"], "token", "CommunicationRequest.priority"),
+    ("recipient", &["CommunicationRequest"], "reference", "CommunicationRequest.recipient"),
+    ("replaces", &["CommunicationRequest"], "reference", "CommunicationRequest.replaces"),
+    ("requester", &["CommunicationRequest"], "reference", "CommunicationRequest.requester"),
+    ("sender", &["CommunicationRequest"], "reference", "CommunicationRequest.sender"),
+    ("status", &["CommunicationRequest"], "token", "CommunicationRequest.status"),
+    ("subject", &["CommunicationRequest"], "reference", "CommunicationRequest.subject"),
+    ("code", &["CompartmentDefinition"], "token", "CompartmentDefinition.code"),
+    ("resource", &["CompartmentDefinition"], "token", "CompartmentDefinition.resource.code"),
+    ("attester", &["Composition"], "reference", "Composition.attester.party"),
+    ("author", &["Composition"], "reference", "Composition.author"),
+    ("category", &["Composition"], "token", "Composition.category"),
+    ("confidentiality", &["Composition"], "token", "Composition.confidentiality"),
+    ("context", &["Composition"], "token", "Composition.event.code"),
+    ("encounter", &["Composition", "DeviceRequest", "DiagnosticReport", "DocumentReference", "Flag", "List", "NutritionOrder", "Observation", "Procedure", "RiskAssessment", "ServiceRequest", "VisionPrescription"], "reference", "Composition.encounter | DeviceRequest.encounter | DiagnosticReport.encounter | DocumentReference.context.encounter | Flag.encounter | List.encounter | NutritionOrder.encounter | Observation.encounter | Procedure.encounter | RiskAssessment.encounter | ServiceRequest.encounter | VisionPrescription.encounter"),
+    ("entry", &["Composition"], "reference", "Composition.section.entry"),
+    ("period", &["Composition"], "date", "Composition.event.period"),
+    ("related-id", &["Composition"], "token", "(Composition.relatesTo.target as Identifier)"),
+    ("related-ref", &["Composition"], "reference", "(Composition.relatesTo.target as Reference)"),
+    ("section", &["Composition"], "token", "Composition.section.code"),
+    ("status", &["Composition"], "token", "Composition.status"),
+    ("subject", &["Composition"], "reference", "Composition.subject"),
+    ("title", &["Composition"], "string", "Composition.title"),
+    ("dependson", &["ConceptMap"], "uri", "ConceptMap.group.element.target.dependsOn.property"),
+    ("other", &["ConceptMap"], "reference", "ConceptMap.group.unmapped.url"),
+    ("product", &["ConceptMap"], "uri", "ConceptMap.group.element.target.product.property"),
+    ("source", &["ConceptMap"], "reference", "(ConceptMap.source as canonical)"),
+    ("source-code", &["ConceptMap"], "token", "ConceptMap.group.element.code"),
+    ("source-system", &["ConceptMap"], "uri", "ConceptMap.group.source"),
+    ("source-uri", &["ConceptMap"], "reference", "(ConceptMap.source as uri)"),
+    ("target", &["ConceptMap"], "reference", "(ConceptMap.target as canonical)"),
+    ("target-code", &["ConceptMap"], "token", "ConceptMap.group.element.target.code"),
+    ("target-system", &["ConceptMap"], "uri", "ConceptMap.group.target"),
+    ("target-uri", &["ConceptMap"], "reference", "(ConceptMap.target as uri)"),
+    ("abatement-age", &["Condition"], "quantity", "Condition.abatement.as(Age) | Condition.abatement.as(Range)"),
+    ("abatement-date", &["Condition"], "date", "Condition.abatement.as(dateTime) | Condition.abatement.as(Period)"),
+    ("abatement-string", &["Condition"], "string", "Condition.abatement.as(string)"),
+    ("asserter", &["Condition"], "reference", "Condition.asserter"),
+    ("body-site", &["Condition"], "token", "Condition.bodySite"),
+    ("category", &["Condition"], "token", "Condition.category"),
+    ("clinical-status", &["Condition"], "token", "Condition.clinicalStatus"),
+    ("encounter", &["Condition"], "reference", "Condition.encounter"),
+    ("evidence", &["Condition"], "token", "Condition.evidence.code"),
+    ("evidence-detail", &["Condition"], "reference", "Condition.evidence.detail"),
+    ("onset-age", &["Condition"], "quantity", "Condition.onset.as(Age) | Condition.onset.as(Range)"),
+    ("onset-date", &["Condition"], "date", "Condition.onset.as(dateTime) | Condition.onset.as(Period)"),
+    ("onset-info", &["Condition"], "string", "Condition.onset.as(string)"),
+    ("recorded-date", &["Condition"], "date", "Condition.recordedDate"),
+    ("severity", &["Condition"], "token", "Condition.severity"),
+    ("stage", &["Condition"], "token", "Condition.stage.summary"),
+    ("subject", &["Condition"], "reference", "Condition.subject"),
+    ("verification-status", &["Condition"], "token", "Condition.verificationStatus"),
+    ("action", &["Consent"], "token", "Consent.provision.action"),
+    ("actor", &["Consent"], "reference", "Consent.provision.actor.reference"),
+    ("category", &["Consent"], "token", "Consent.category"),
+    ("consentor", &["Consent"], "reference", "Consent.performer"),
+    ("data", &["Consent"], "reference", "Consent.provision.data.reference"),
+    ("organization", &["Consent"], "reference", "Consent.organization"),
+    ("period", &["Consent"], "date", "Consent.provision.period"),
+    ("purpose", &["Consent"], "token", "Consent.provision.purpose"),
+    ("scope", &["Consent"], "token", "Consent.scope"),
+    ("security-label", &["Consent"], "token", "Consent.provision.securityLabel"),
+    ("source-reference", &["Consent"], "reference", "Consent.source"),
+    ("status", &["Consent"], "token", "Consent.status"),
+    ("authority", &["Contract"], "reference", "Contract.authority"),
+    ("domain", &["Contract"], "reference", "Contract.domain"),
+    ("identifier", &["Contract"], "token", "Contract.identifier"),
+    ("instantiates", &["Contract"], "uri", "Contract.instantiatesUri"),
+    ("issued", &["Contract"], "date", "Contract.issued"),
+    ("patient", &["Contract"], "reference", "Contract.subject.where(resolve() is Patient)"),
+    ("signer", &["Contract"], "reference", "Contract.signer.party"),
+    ("status", &["Contract"], "token", "Contract.status"),
+    ("subject", &["Contract"], "reference", "Contract.subject"),
+    ("url", &["Contract"], "uri", "Contract.url"),
+    ("beneficiary", &["Coverage"], "reference", "Coverage.beneficiary"),
+    ("class-type", &["Coverage"], "token", "Coverage.class.type"),
+    ("class-value", &["Coverage"], "string", "Coverage.class.value"),
+    ("dependent", &["Coverage"], "string", "Coverage.dependent"),
+    ("identifier", &["Coverage"], "token", "Coverage.identifier"),
+    ("patient", &["Coverage"], "reference", "Coverage.beneficiary"),
+    ("payor", &["Coverage"], "reference", "Coverage.payor"),
+    ("policy-holder", &["Coverage"], "reference", "Coverage.policyHolder"),
+    ("status", &["Coverage"], "token", "Coverage.status"),
+    ("subscriber", &["Coverage"], "reference", "Coverage.subscriber"),
+    ("type", &["Coverage"], "token", "Coverage.type"),
+    ("created", &["CoverageEligibilityRequest"], "date", "CoverageEligibilityRequest.created"),
+    ("enterer", &["CoverageEligibilityRequest"], "reference", "CoverageEligibilityRequest.enterer"),
+    ("facility", &["CoverageEligibilityRequest"], "reference", "CoverageEligibilityRequest.facility"),
+    ("identifier", &["CoverageEligibilityRequest"], "token", "CoverageEligibilityRequest.identifier"),
+    ("patient", &["CoverageEligibilityRequest"], "reference", "CoverageEligibilityRequest.patient"),
+    ("provider", &["CoverageEligibilityRequest"], "reference", "CoverageEligibilityRequest.provider"),
+    ("status", &["CoverageEligibilityRequest"], "token", "CoverageEligibilityRequest.status"),
+    ("created", &["CoverageEligibilityResponse"], "date", "CoverageEligibilityResponse.created"),
+    ("disposition", &["CoverageEligibilityResponse"], "string", "CoverageEligibilityResponse.disposition"),
+    ("identifier", &["CoverageEligibilityResponse"], "token", "CoverageEligibilityResponse.identifier"),
+    ("insurer", &["CoverageEligibilityResponse"], "reference", "CoverageEligibilityResponse.insurer"),
+    ("outcome", &["CoverageEligibilityResponse"], "token", "CoverageEligibilityResponse.outcome"),
+    ("patient", &["CoverageEligibilityResponse"], "reference", "CoverageEligibilityResponse.patient"),
+    ("request", &["CoverageEligibilityResponse"], "reference", "CoverageEligibilityResponse.request"),
+    ("requestor", &["CoverageEligibilityResponse"], "reference", "CoverageEligibilityResponse.requestor"),
+    ("status", &["CoverageEligibilityResponse"], "token", "CoverageEligibilityResponse.status"),
+    ("author", &["DetectedIssue"], "reference", "DetectedIssue.author"),
+    ("code", &["DetectedIssue"], "token", "DetectedIssue.code"),
+    ("identified", &["DetectedIssue"], "date", "DetectedIssue.identified"),
+    ("implicated", &["DetectedIssue"], "reference", "DetectedIssue.implicated"),
+    ("device-name", &["Device"], "string", "Device.deviceName.name | Device.type.coding.display | Device.type.text"),
+    ("identifier", &["Device"], "token", "Device.identifier"),
+    ("location", &["Device"], "reference", "Device.location"),
+    ("manufacturer", &["Device"], "string", "Device.manufacturer"),
+    ("model", &["Device"], "string", "Device.modelNumber"),
+    ("organization", &["Device"], "reference", "Device.owner"),
+    ("patient", &["Device"], "reference", "Device.patient"),
+    ("status", &["Device"], "token", "Device.status"),
+    ("type", &["Device"], "token", "Device.type"),
+    ("udi-carrier", &["Device"], "string", "Device.udiCarrier.carrierHRF"),
+    ("udi-di", &["Device"], "string", "Device.udiCarrier.deviceIdentifier"),
+    ("url", &["Device"], "uri", "Device.url"),
+    ("identifier", &["DeviceDefinition"], "token", "DeviceDefinition.identifier"),
+    ("parent", &["DeviceDefinition"], "reference", "DeviceDefinition.parentDevice"),
+    ("type", &["DeviceDefinition"], "token", "DeviceDefinition.type"),
+    ("category", &["DeviceMetric"], "token", "DeviceMetric.category"),
+    ("identifier", &["DeviceMetric"], "token", "DeviceMetric.identifier"),
+    ("parent", &["DeviceMetric"], "reference", "DeviceMetric.parent"),
+    ("source", &["DeviceMetric"], "reference", "DeviceMetric.source"),
+    ("type", &["DeviceMetric"], "token", "DeviceMetric.type"),
+    ("authored-on", &["DeviceRequest"], "date", "DeviceRequest.authoredOn"),
+    ("based-on", &["DeviceRequest"], "reference", "DeviceRequest.basedOn"),
+    ("device", &["DeviceRequest"], "reference", "(DeviceRequest.code as Reference)"),
+    ("event-date", &["DeviceRequest"], "date", "(DeviceRequest.occurrence as dateTime) | (DeviceRequest.occurrence as Period)"),
+    ("group-identifier", &["DeviceRequest"], "token", "DeviceRequest.groupIdentifier"),
+    ("instantiates-canonical", &["DeviceRequest"], "reference", "DeviceRequest.instantiatesCanonical"),
+    ("instantiates-uri", &["DeviceRequest"], "uri", "DeviceRequest.instantiatesUri"),
+    ("insurance", &["DeviceRequest"], "reference", "DeviceRequest.insurance"),
+    ("intent", &["DeviceRequest"], "token", "DeviceRequest.intent"),
+    ("performer", &["DeviceRequest"], "reference", "DeviceRequest.performer"),
+    ("prior-request", &["DeviceRequest"], "reference", "DeviceRequest.priorRequest"),
+    ("requester", &["DeviceRequest"], "reference", "DeviceRequest.requester"),
+    ("status", &["DeviceRequest"], "token", "DeviceRequest.status"),
+    ("subject", &["DeviceRequest"], "reference", "DeviceRequest.subject"),
+    ("device", &["DeviceUseStatement"], "reference", "DeviceUseStatement.device"),
+    ("identifier", &["DeviceUseStatement"], "token", "DeviceUseStatement.identifier"),
+    ("subject", &["DeviceUseStatement"], "reference", "DeviceUseStatement.subject"),
+    ("based-on", &["DiagnosticReport"], "reference", "DiagnosticReport.basedOn"),
+    ("category", &["DiagnosticReport"], "token", "DiagnosticReport.category"),
+    ("conclusion", &["DiagnosticReport"], "token", "DiagnosticReport.conclusionCode"),
+    ("issued", &["DiagnosticReport"], "date", "DiagnosticReport.issued"),
+    ("media", &["DiagnosticReport"], "reference", "DiagnosticReport.media.link"),
+    ("performer", &["DiagnosticReport"], "reference", "DiagnosticReport.performer"),
+    ("result", &["DiagnosticReport"], "reference", "DiagnosticReport.result"),
+    ("results-interpreter", &["DiagnosticReport"], "reference", "DiagnosticReport.resultsInterpreter"),
+    ("specimen", &["DiagnosticReport"], "reference", "DiagnosticReport.specimen"),
+    ("status", &["DiagnosticReport"], "token", "DiagnosticReport.status"),
+    ("subject", &["DiagnosticReport"], "reference", "DiagnosticReport.subject"),
+    ("author", &["DocumentManifest"], "reference", "DocumentManifest.author"),
+    ("created", &["DocumentManifest"], "date", "DocumentManifest.created"),
+    ("description", &["DocumentManifest"], "string", "DocumentManifest.description"),
+    ("item", &["DocumentManifest"], "reference", "DocumentManifest.content"),
+    ("recipient", &["DocumentManifest"], "reference", "DocumentManifest.recipient"),
+    ("related-id", &["DocumentManifest"], "token", "DocumentManifest.related.identifier"),
+    ("related-ref", &["DocumentManifest"], "reference", "DocumentManifest.related.ref"),
+    ("source", &["DocumentManifest"], "uri", "DocumentManifest.source"),
+    ("status", &["DocumentManifest"], "token", "DocumentManifest.status"),
+    ("subject", &["DocumentManifest"], "reference", "DocumentManifest.subject"),
+    ("authenticator", &["DocumentReference"], "reference", "DocumentReference.authenticator"),
+    ("author", &["DocumentReference"], "reference", "DocumentReference.author"),
+    ("category", &["DocumentReference"], "token", "DocumentReference.category"),
+    ("contenttype", &["DocumentReference"], "token", "DocumentReference.content.attachment.contentType"),
+    ("custodian", &["DocumentReference"], "reference", "DocumentReference.custodian"),
+    ("date", &["DocumentReference"], "date", "DocumentReference.date"),
+    ("description", &["DocumentReference"], "string", "DocumentReference.description"),
+    ("event", &["DocumentReference"], "token", "DocumentReference.context.event"),
+    ("facility", &["DocumentReference"], "token", "DocumentReference.context.facilityType"),
+    ("format", &["DocumentReference"], "token", "DocumentReference.content.format"),
+    ("language", &["DocumentReference"], "token", "DocumentReference.content.attachment.language"),
+    ("location", &["DocumentReference"], "uri", "DocumentReference.content.attachment.url"),
+    ("period", &["DocumentReference"], "date", "DocumentReference.context.period"),
+    ("related", &["DocumentReference"], "reference", "DocumentReference.context.related"),
+    ("relatesto", &["DocumentReference"], "reference", "DocumentReference.relatesTo.target"),
+    ("relation", &["DocumentReference"], "token", "DocumentReference.relatesTo.code"),
+    ("security-label", &["DocumentReference"], "token", "DocumentReference.securityLabel"),
+    ("setting", &["DocumentReference"], "token", "DocumentReference.context.practiceSetting"),
+    ("status", &["DocumentReference"], "token", "DocumentReference.status"),
+    ("subject", &["DocumentReference"], "reference", "DocumentReference.subject"),
+    ("context", &["EffectEvidenceSynthesis"], "token", "(EffectEvidenceSynthesis.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["EffectEvidenceSynthesis"], "quantity", "(EffectEvidenceSynthesis.useContext.value as Quantity) | (EffectEvidenceSynthesis.useContext.value as Range)"),
+    ("context-type", &["EffectEvidenceSynthesis"], "token", "EffectEvidenceSynthesis.useContext.code"),
+    ("date", &["EffectEvidenceSynthesis"], "date", "EffectEvidenceSynthesis.date"),
+    ("description", &["EffectEvidenceSynthesis"], "string", "EffectEvidenceSynthesis.description"),
+    ("effective", &["EffectEvidenceSynthesis"], "date", "EffectEvidenceSynthesis.effectivePeriod"),
+    ("identifier", &["EffectEvidenceSynthesis"], "token", "EffectEvidenceSynthesis.identifier"),
+    ("jurisdiction", &["EffectEvidenceSynthesis"], "token", "EffectEvidenceSynthesis.jurisdiction"),
+    ("name", &["EffectEvidenceSynthesis"], "string", "EffectEvidenceSynthesis.name"),
+    ("publisher", &["EffectEvidenceSynthesis"], "string", "EffectEvidenceSynthesis.publisher"),
+    ("status", &["EffectEvidenceSynthesis"], "token", "EffectEvidenceSynthesis.status"),
+    ("title", &["EffectEvidenceSynthesis"], "string", "EffectEvidenceSynthesis.title"),
+    ("url", &["EffectEvidenceSynthesis"], "uri", "EffectEvidenceSynthesis.url"),
+    ("version", &["EffectEvidenceSynthesis"], "token", "EffectEvidenceSynthesis.version"),
+    ("account", &["Encounter"], "reference", "Encounter.account"),
+    ("appointment", &["Encounter"], "reference", "Encounter.appointment"),
+    ("based-on", &["Encounter"], "reference", "Encounter.basedOn"),
+    ("class", &["Encounter"], "token", "Encounter.class"),
+    ("diagnosis", &["Encounter"], "reference", "Encounter.diagnosis.condition"),
+    ("episode-of-care", &["Encounter"], "reference", "Encounter.episodeOfCare"),
+    ("length", &["Encounter"], "quantity", "Encounter.length"),
+    ("location", &["Encounter"], "reference", "Encounter.location.location"),
+    ("location-period", &["Encounter"], "date", "Encounter.location.period"),
+    ("part-of", &["Encounter"], "reference", "Encounter.partOf"),
+    ("participant", &["Encounter"], "reference", "Encounter.participant.individual"),
+    ("participant-type", &["Encounter"], "token", "Encounter.participant.type"),
+    ("practitioner", &["Encounter"], "reference", "Encounter.participant.individual.where(resolve() is Practitioner)"),
+    ("reason-code", &["Encounter"], "token", "Encounter.reasonCode"),
+    ("reason-reference", &["Encounter"], "reference", "Encounter.reasonReference"),
+    ("service-provider", &["Encounter"], "reference", "Encounter.serviceProvider"),
+    ("special-arrangement", &["Encounter"], "token", "Encounter.hospitalization.specialArrangement"),
+    ("status", &["Encounter"], "token", "Encounter.status"),
+    ("subject", &["Encounter"], "reference", "Encounter.subject"),
+    ("connection-type", &["Endpoint"], "token", "Endpoint.connectionType"),
+    ("identifier", &["Endpoint"], "token", "Endpoint.identifier"),
+    ("name", &["Endpoint"], "string", "Endpoint.name"),
+    ("organization", &["Endpoint"], "reference", "Endpoint.managingOrganization"),
+    ("payload-type", &["Endpoint"], "token", "Endpoint.payloadType"),
+    ("status", &["Endpoint"], "token", "Endpoint.status"),
+    ("identifier", &["EnrollmentRequest"], "token", "EnrollmentRequest.identifier"),
+    ("patient", &["EnrollmentRequest"], "reference", "EnrollmentRequest.candidate"),
+    ("status", &["EnrollmentRequest"], "token", "EnrollmentRequest.status"),
+    ("subject", &["EnrollmentRequest"], "reference", "EnrollmentRequest.candidate"),
+    ("identifier", &["EnrollmentResponse"], "token", "EnrollmentResponse.identifier"),
+    ("request", &["EnrollmentResponse"], "reference", "EnrollmentResponse.request"),
+    ("status", &["EnrollmentResponse"], "token", "EnrollmentResponse.status"),
+    ("care-manager", &["EpisodeOfCare"], "reference", "EpisodeOfCare.careManager.where(resolve() is Practitioner)"),
+    ("condition", &["EpisodeOfCare"], "reference", "EpisodeOfCare.diagnosis.condition"),
+    ("incoming-referral", &["EpisodeOfCare"], "reference", "EpisodeOfCare.referralRequest"),
+    ("organization", &["EpisodeOfCare"], "reference", "EpisodeOfCare.managingOrganization"),
+    ("status", &["EpisodeOfCare"], "token", "EpisodeOfCare.status"),
+    ("composed-of", &["EventDefinition"], "reference", "EventDefinition.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["EventDefinition"], "token", "(EventDefinition.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["EventDefinition"], "quantity", "(EventDefinition.useContext.value as Quantity) | (EventDefinition.useContext.value as Range)"),
+    ("context-type", &["EventDefinition"], "token", "EventDefinition.useContext.code"),
+    ("date", &["EventDefinition"], "date", "EventDefinition.date"),
+    ("depends-on", &["EventDefinition"], "reference", "EventDefinition.relatedArtifact.where(type='depends-on').resource"),
+    ("derived-from", &["EventDefinition"], "reference", "EventDefinition.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["EventDefinition"], "string", "EventDefinition.description"),
+    ("effective", &["EventDefinition"], "date", "EventDefinition.effectivePeriod"),
+    ("identifier", &["EventDefinition"], "token", "EventDefinition.identifier"),
+    ("jurisdiction", &["EventDefinition"], "token", "EventDefinition.jurisdiction"),
+    ("name", &["EventDefinition"], "string", "EventDefinition.name"),
+    ("predecessor", &["EventDefinition"], "reference", "EventDefinition.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["EventDefinition"], "string", "EventDefinition.publisher"),
+    ("status", &["EventDefinition"], "token", "EventDefinition.status"),
+    ("successor", &["EventDefinition"], "reference", "EventDefinition.relatedArtifact.where(type='successor').resource"),
+    ("title", &["EventDefinition"], "string", "EventDefinition.title"),
+    ("topic", &["EventDefinition"], "token", "EventDefinition.topic"),
+    ("url", &["EventDefinition"], "uri", "EventDefinition.url"),
+    ("version", &["EventDefinition"], "token", "EventDefinition.version"),
+    ("composed-of", &["Evidence"], "reference", "Evidence.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["Evidence"], "token", "(Evidence.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["Evidence"], "quantity", "(Evidence.useContext.value as Quantity) | (Evidence.useContext.value as Range)"),
+    ("context-type", &["Evidence"], "token", "Evidence.useContext.code"),
+    ("date", &["Evidence"], "date", "Evidence.date"),
+    ("depends-on", &["Evidence"], "reference", "Evidence.relatedArtifact.where(type='depends-on').resource"),
+    ("derived-from", &["Evidence"], "reference", "Evidence.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["Evidence"], "string", "Evidence.description"),
+    ("effective", &["Evidence"], "date", "Evidence.effectivePeriod"),
+    ("identifier", &["Evidence"], "token", "Evidence.identifier"),
+    ("jurisdiction", &["Evidence"], "token", "Evidence.jurisdiction"),
+    ("name", &["Evidence"], "string", "Evidence.name"),
+    ("predecessor", &["Evidence"], "reference", "Evidence.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["Evidence"], "string", "Evidence.publisher"),
+    ("status", &["Evidence"], "token", "Evidence.status"),
+    ("successor", &["Evidence"], "reference", "Evidence.relatedArtifact.where(type='successor').resource"),
+    ("title", &["Evidence"], "string", "Evidence.title"),
+    ("topic", &["Evidence"], "token", "Evidence.topic"),
+    ("url", &["Evidence"], "uri", "Evidence.url"),
+    ("version", &["Evidence"], "token", "Evidence.version"),
+    ("composed-of", &["EvidenceVariable"], "reference", "EvidenceVariable.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["EvidenceVariable"], "token", "(EvidenceVariable.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["EvidenceVariable"], "quantity", "(EvidenceVariable.useContext.value as Quantity) | (EvidenceVariable.useContext.value as Range)"),
+    ("context-type", &["EvidenceVariable"], "token", "EvidenceVariable.useContext.code"),
+    ("date", &["EvidenceVariable"], "date", "EvidenceVariable.date"),
+    ("depends-on", &["EvidenceVariable"], "reference", "EvidenceVariable.relatedArtifact.where(type='depends-on').resource"),
+    ("derived-from", &["EvidenceVariable"], "reference", "EvidenceVariable.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["EvidenceVariable"], "string", "EvidenceVariable.description"),
+    ("effective", &["EvidenceVariable"], "date", "EvidenceVariable.effectivePeriod"),
+    ("identifier", &["EvidenceVariable"], "token", "EvidenceVariable.identifier"),
+    ("jurisdiction", &["EvidenceVariable"], "token", "EvidenceVariable.jurisdiction"),
+    ("name", &["EvidenceVariable"], "string", "EvidenceVariable.name"),
+    ("predecessor", &["EvidenceVariable"], "reference", "EvidenceVariable.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["EvidenceVariable"], "string", "EvidenceVariable.publisher"),
+    ("status", &["EvidenceVariable"], "token", "EvidenceVariable.status"),
+    ("successor", &["EvidenceVariable"], "reference", "EvidenceVariable.relatedArtifact.where(type='successor').resource"),
+    ("title", &["EvidenceVariable"], "string", "EvidenceVariable.title"),
+    ("topic", &["EvidenceVariable"], "token", "EvidenceVariable.topic"),
+    ("url", &["EvidenceVariable"], "uri", "EvidenceVariable.url"),
+    ("version", &["EvidenceVariable"], "token", "EvidenceVariable.version"),
+    ("context", &["ExampleScenario"], "token", "(ExampleScenario.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["ExampleScenario"], "quantity", "(ExampleScenario.useContext.value as Quantity) | (ExampleScenario.useContext.value as Range)"),
+    ("context-type", &["ExampleScenario"], "token", "ExampleScenario.useContext.code"),
+    ("date", &["ExampleScenario"], "date", "ExampleScenario.date"),
+    ("identifier", &["ExampleScenario"], "token", "ExampleScenario.identifier"),
+    ("jurisdiction", &["ExampleScenario"], "token", "ExampleScenario.jurisdiction"),
+    ("name", &["ExampleScenario"], "string", "ExampleScenario.name"),
+    ("publisher", &["ExampleScenario"], "string", "ExampleScenario.publisher"),
+    ("status", &["ExampleScenario"], "token", "ExampleScenario.status"),
+    ("url", &["ExampleScenario"], "uri", "ExampleScenario.url"),
+    ("version", &["ExampleScenario"], "token", "ExampleScenario.version"),
+    ("care-team", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.careTeam.provider"),
+    ("claim", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.claim"),
+    ("coverage", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.insurance.coverage"),
+    ("created", &["ExplanationOfBenefit"], "date", "ExplanationOfBenefit.created"),
+    ("detail-udi", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.item.detail.udi"),
+    ("disposition", &["ExplanationOfBenefit"], "string", "ExplanationOfBenefit.disposition"),
+    ("encounter", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.item.encounter"),
+    ("enterer", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.enterer"),
+    ("facility", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.facility"),
+    ("identifier", &["ExplanationOfBenefit"], "token", "ExplanationOfBenefit.identifier"),
+    ("item-udi", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.item.udi"),
+    ("patient", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.patient"),
+    ("payee", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.payee.party"),
+    ("procedure-udi", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.procedure.udi"),
+    ("provider", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.provider"),
+    ("status", &["ExplanationOfBenefit"], "token", "ExplanationOfBenefit.status"),
+    ("subdetail-udi", &["ExplanationOfBenefit"], "reference", "ExplanationOfBenefit.item.detail.subDetail.udi"),
+    ("instantiates-canonical", &["FamilyMemberHistory"], "reference", "FamilyMemberHistory.instantiatesCanonical"),
+    ("instantiates-uri", &["FamilyMemberHistory"], "uri", "FamilyMemberHistory.instantiatesUri"),
+    ("relationship", &["FamilyMemberHistory"], "token", "FamilyMemberHistory.relationship"),
+    ("sex", &["FamilyMemberHistory"], "token", "FamilyMemberHistory.sex"),
+    ("status", &["FamilyMemberHistory"], "token", "FamilyMemberHistory.status"),
+    ("author", &["Flag"], "reference", "Flag.author"),
+    ("identifier", &["Flag"], "token", "Flag.identifier"),
+    ("subject", &["Flag"], "reference", "Flag.subject"),
+    ("achievement-status", &["Goal"], "token", "Goal.achievementStatus"),
+    ("category", &["Goal"], "token", "Goal.category"),
+    ("lifecycle-status", &["Goal"], "token", "Goal.lifecycleStatus"),
+    ("start-date", &["Goal"], "date", "(Goal.start as date)"),
+    ("subject", &["Goal"], "reference", "Goal.subject"),
+    ("target-date", &["Goal"], "date", "(Goal.target.due as date)"),
+    ("start", &["GraphDefinition"], "token", "GraphDefinition.start"),
+    ("actual", &["Group"], "token", "Group.actual"),
+    ("characteristic", &["Group"], "token", "Group.characteristic.code"),
+    ("code", &["Group"], "token", "Group.code"),
+    ("exclude", &["Group"], "token", "Group.characteristic.exclude"),
+    ("identifier", &["Group"], "token", "Group.identifier"),
+    ("managing-entity", &["Group"], "reference", "Group.managingEntity"),
+    ("member", &["Group"], "reference", "Group.member.entity"),
+    ("type", &["Group"], "token", "Group.type"),
+    ("value", &["Group"], "token", "(Group.characteristic.value as CodeableConcept) | (Group.characteristic.value as boolean)"),
+    ("identifier", &["GuidanceResponse"], "token", "GuidanceResponse.identifier"),
+    ("patient", &["GuidanceResponse"], "reference", "GuidanceResponse.subject.where(resolve() is Patient)"),
+    ("request", &["GuidanceResponse"], "token", "GuidanceResponse.requestIdentifier"),
+    ("subject", &["GuidanceResponse"], "reference", "GuidanceResponse.subject"),
+    ("active", &["HealthcareService"], "token", "HealthcareService.active"),
+    ("characteristic", &["HealthcareService"], "token", "HealthcareService.characteristic"),
+    ("coverage-area", &["HealthcareService"], "reference", "HealthcareService.coverageArea"),
+    ("endpoint", &["HealthcareService"], "reference", "HealthcareService.endpoint"),
+    ("identifier", &["HealthcareService"], "token", "HealthcareService.identifier"),
+    ("location", &["HealthcareService"], "reference", "HealthcareService.location"),
+    ("name", &["HealthcareService"], "string", "HealthcareService.name"),
+    ("organization", &["HealthcareService"], "reference", "HealthcareService.providedBy"),
+    ("program", &["HealthcareService"], "token", "HealthcareService.program"),
+    ("service-category", &["HealthcareService"], "token", "HealthcareService.category"),
+    ("service-type", &["HealthcareService"], "token", "HealthcareService.type"),
+    ("specialty", &["HealthcareService"], "token", "HealthcareService.specialty"),
+    ("basedon", &["ImagingStudy"], "reference", "ImagingStudy.basedOn"),
+    ("bodysite", &["ImagingStudy"], "token", "ImagingStudy.series.bodySite"),
+    ("dicom-class", &["ImagingStudy"], "token", "ImagingStudy.series.instance.sopClass"),
+    ("encounter", &["ImagingStudy"], "reference", "ImagingStudy.encounter"),
+    ("endpoint", &["ImagingStudy"], "reference", "ImagingStudy.endpoint | ImagingStudy.series.endpoint"),
+    ("instance", &["ImagingStudy"], "token", "ImagingStudy.series.instance.uid"),
+    ("interpreter", &["ImagingStudy"], "reference", "ImagingStudy.interpreter"),
+    ("modality", &["ImagingStudy"], "token", "ImagingStudy.series.modality"),
+    ("performer", &["ImagingStudy"], "reference", "ImagingStudy.series.performer.actor"),
+    ("reason", &["ImagingStudy"], "token", "ImagingStudy.reasonCode"),
+    ("referrer", &["ImagingStudy"], "reference", "ImagingStudy.referrer"),
+    ("series", &["ImagingStudy"], "token", "ImagingStudy.series.uid"),
+    ("started", &["ImagingStudy"], "date", "ImagingStudy.started"),
+    ("status", &["ImagingStudy"], "token", "ImagingStudy.status"),
+    ("subject", &["ImagingStudy"], "reference", "ImagingStudy.subject"),
+    ("location", &["Immunization"], "reference", "Immunization.location"),
+    ("lot-number", &["Immunization"], "string", "Immunization.lotNumber"),
+    ("manufacturer", &["Immunization"], "reference", "Immunization.manufacturer"),
+    ("performer", &["Immunization"], "reference", "Immunization.performer.actor"),
+    ("reaction", &["Immunization"], "reference", "Immunization.reaction.detail"),
+    ("reaction-date", &["Immunization"], "date", "Immunization.reaction.date"),
+    ("reason-code", &["Immunization"], "token", "Immunization.reasonCode"),
+    ("reason-reference", &["Immunization"], "reference", "Immunization.reasonReference"),
+    ("series", &["Immunization"], "string", "Immunization.protocolApplied.series"),
+    ("status", &["Immunization"], "token", "Immunization.status"),
+    ("status-reason", &["Immunization"], "token", "Immunization.statusReason"),
+    ("target-disease", &["Immunization"], "token", "Immunization.protocolApplied.targetDisease"),
+    ("vaccine-code", &["Immunization"], "token", "Immunization.vaccineCode"),
+    ("date", &["ImmunizationEvaluation"], "date", "ImmunizationEvaluation.date"),
+    ("dose-status", &["ImmunizationEvaluation"], "token", "ImmunizationEvaluation.doseStatus"),
+    ("identifier", &["ImmunizationEvaluation"], "token", "ImmunizationEvaluation.identifier"),
+    ("immunization-event", &["ImmunizationEvaluation"], "reference", "ImmunizationEvaluation.immunizationEvent"),
+    ("patient", &["ImmunizationEvaluation"], "reference", "ImmunizationEvaluation.patient"),
+    ("status", &["ImmunizationEvaluation"], "token", "ImmunizationEvaluation.status"),
+    ("target-disease", &["ImmunizationEvaluation"], "token", "ImmunizationEvaluation.targetDisease"),
+    ("date", &["ImmunizationRecommendation"], "date", "ImmunizationRecommendation.date"),
+    ("identifier", &["ImmunizationRecommendation"], "token", "ImmunizationRecommendation.identifier"),
+    ("information", &["ImmunizationRecommendation"], "reference", "ImmunizationRecommendation.recommendation.supportingPatientInformation"),
+    ("patient", &["ImmunizationRecommendation"], "reference", "ImmunizationRecommendation.patient"),
+    ("status", &["ImmunizationRecommendation"], "token", "ImmunizationRecommendation.recommendation.forecastStatus"),
+    ("support", &["ImmunizationRecommendation"], "reference", "ImmunizationRecommendation.recommendation.supportingImmunization"),
+    ("target-disease", &["ImmunizationRecommendation"], "token", "ImmunizationRecommendation.recommendation.targetDisease"),
+    ("vaccine-type", &["ImmunizationRecommendation"], "token", "ImmunizationRecommendation.recommendation.vaccineCode"),
+    ("depends-on", &["ImplementationGuide"], "reference", "ImplementationGuide.dependsOn.uri"),
+    ("experimental", &["ImplementationGuide"], "token", "ImplementationGuide.experimental"),
+    ("global", &["ImplementationGuide"], "reference", "ImplementationGuide.global.profile"),
+    ("resource", &["ImplementationGuide"], "reference", "ImplementationGuide.definition.resource.reference"),
+    ("address", &["InsurancePlan"], "string", "InsurancePlan.contact.address"),
+    ("address-city", &["InsurancePlan"], "string", "InsurancePlan.contact.address.city"),
+    ("address-country", &["InsurancePlan"], "string", "InsurancePlan.contact.address.country"),
+    ("address-postalcode", &["InsurancePlan"], "string", "InsurancePlan.contact.address.postalCode"),
+    ("address-state", &["InsurancePlan"], "string", "InsurancePlan.contact.address.state"),
+    ("address-use", &["InsurancePlan"], "token", "InsurancePlan.contact.address.use"),
+    ("administered-by", &["InsurancePlan"], "reference", "InsurancePlan.administeredBy"),
+    ("endpoint", &["InsurancePlan"], "reference", "InsurancePlan.endpoint"),
+    ("identifier", &["InsurancePlan"], "token", "InsurancePlan.identifier"),
+    ("name", &["InsurancePlan"], "string", "name | alias"),
+    ("owned-by", &["InsurancePlan"], "reference", "InsurancePlan.ownedBy"),
+    ("phonetic", &["InsurancePlan"], "string", "InsurancePlan.name"),
+    ("status", &["InsurancePlan"], "token", "InsurancePlan.status"),
+    ("type", &["InsurancePlan"], "token", "InsurancePlan.type"),
+    ("account", &["Invoice"], "reference", "Invoice.account"),
+    ("date", &["Invoice"], "date", "Invoice.date"),
+    ("identifier", &["Invoice"], "token", "Invoice.identifier"),
+    ("issuer", &["Invoice"], "reference", "Invoice.issuer"),
+    ("participant", &["Invoice"], "reference", "Invoice.participant.actor"),
+    ("participant-role", &["Invoice"], "token", "Invoice.participant.role"),
+    ("patient", &["Invoice"], "reference", "Invoice.subject.where(resolve() is Patient)"),
+    ("recipient", &["Invoice"], "reference", "Invoice.recipient"),
+    ("status", &["Invoice"], "token", "Invoice.status"),
+    ("subject", &["Invoice"], "reference", "Invoice.subject"),
+    ("totalgross", &["Invoice"], "quantity", "Invoice.totalGross"),
+    ("totalnet", &["Invoice"], "quantity", "Invoice.totalNet"),
+    ("type", &["Invoice"], "token", "Invoice.type"),
+    ("composed-of", &["Library"], "reference", "Library.relatedArtifact.where(type='composed-of').resource"),
+    ("content-type", &["Library"], "token", "Library.content.contentType"),
+    ("context", &["Library"], "token", "(Library.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["Library"], "quantity", "(Library.useContext.value as Quantity) | (Library.useContext.value as Range)"),
+    ("context-type", &["Library"], "token", "Library.useContext.code"),
+    ("date", &["Library"], "date", "Library.date"),
+    ("depends-on", &["Library"], "reference", "Library.relatedArtifact.where(type='depends-on').resource"),
+    ("derived-from", &["Library"], "reference", "Library.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["Library"], "string", "Library.description"),
+    ("effective", &["Library"], "date", "Library.effectivePeriod"),
+    ("identifier", &["Library"], "token", "Library.identifier"),
+    ("jurisdiction", &["Library"], "token", "Library.jurisdiction"),
+    ("name", &["Library"], "string", "Library.name"),
+    ("predecessor", &["Library"], "reference", "Library.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["Library"], "string", "Library.publisher"),
+    ("status", &["Library"], "token", "Library.status"),
+    ("successor", &["Library"], "reference", "Library.relatedArtifact.where(type='successor').resource"),
+    ("title", &["Library"], "string", "Library.title"),
+    ("topic", &["Library"], "token", "Library.topic"),
+    ("type", &["Library"], "token", "Library.type"),
+    ("url", &["Library"], "uri", "Library.url"),
+    ("version", &["Library"], "token", "Library.version"),
+    ("author", &["Linkage"], "reference", "Linkage.author"),
+    ("item", &["Linkage"], "reference", "Linkage.item.resource"),
+    ("source", &["Linkage"], "reference", "Linkage.item.resource"),
+    ("empty-reason", &["List"], "token", "List.emptyReason"),
+    ("item", &["List"], "reference", "List.entry.item"),
+    ("notes", &["List"], "string", "List.note.text"),
+    ("source", &["List"], "reference", "List.source"),
+    ("status", &["List"], "token", "List.status"),
+    ("subject", &["List"], "reference", "List.subject"),
+    ("title", &["List"], "string", "List.title"),
+    ("address", &["Location"], "string", "Location.address"),
+    ("address-city", &["Location"], "string", "Location.address.city"),
+    ("address-country", &["Location"], "string", "Location.address.country"),
+    ("address-postalcode", &["Location"], "string", "Location.address.postalCode"),
+    ("address-state", &["Location"], "string", "Location.address.state"),
+    ("address-use", &["Location"], "token", "Location.address.use"),
+    ("endpoint", &["Location"], "reference", "Location.endpoint"),
+    ("identifier", &["Location"], "token", "Location.identifier"),
+    ("name", &["Location"], "string", "Location.name | Location.alias"),
+    ("operational-status", &["Location"], "token", "Location.operationalStatus"),
+    ("organization", &["Location"], "reference", "Location.managingOrganization"),
+    ("partof", &["Location"], "reference", "Location.partOf"),
+    ("status", &["Location"], "token", "Location.status"),
+    ("type", &["Location"], "token", "Location.type"),
+    ("composed-of", &["Measure"], "reference", "Measure.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["Measure"], "token", "(Measure.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["Measure"], "quantity", "(Measure.useContext.value as Quantity) | (Measure.useContext.value as Range)"),
+    ("context-type", &["Measure"], "token", "Measure.useContext.code"),
+    ("date", &["Measure"], "date", "Measure.date"),
+    ("depends-on", &["Measure"], "reference", "Measure.relatedArtifact.where(type='depends-on').resource | Measure.library"),
+    ("derived-from", &["Measure"], "reference", "Measure.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["Measure"], "string", "Measure.description"),
+    ("effective", &["Measure"], "date", "Measure.effectivePeriod"),
+    ("identifier", &["Measure"], "token", "Measure.identifier"),
+    ("jurisdiction", &["Measure"], "token", "Measure.jurisdiction"),
+    ("name", &["Measure"], "string", "Measure.name"),
+    ("predecessor", &["Measure"], "reference", "Measure.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["Measure"], "string", "Measure.publisher"),
+    ("status", &["Measure"], "token", "Measure.status"),
+    ("successor", &["Measure"], "reference", "Measure.relatedArtifact.where(type='successor').resource"),
+    ("title", &["Measure"], "string", "Measure.title"),
+    ("topic", &["Measure"], "token", "Measure.topic"),
+    ("url", &["Measure"], "uri", "Measure.url"),
+    ("version", &["Measure"], "token", "Measure.version"),
+    ("date", &["MeasureReport"], "date", "MeasureReport.date"),
+    ("evaluated-resource", &["MeasureReport"], "reference", "MeasureReport.evaluatedResource"),
+    ("identifier", &["MeasureReport"], "token", "MeasureReport.identifier"),
+    ("measure", &["MeasureReport"], "reference", "MeasureReport.measure"),
+    ("patient", &["MeasureReport"], "reference", "MeasureReport.subject.where(resolve() is Patient)"),
+    ("period", &["MeasureReport"], "date", "MeasureReport.period"),
+    ("reporter", &["MeasureReport"], "reference", "MeasureReport.reporter"),
+    ("status", &["MeasureReport"], "token", "MeasureReport.status"),
+    ("subject", &["MeasureReport"], "reference", "MeasureReport.subject"),
+    ("based-on", &["Media"], "reference", "Media.basedOn"),
+    ("created", &["Media"], "date", "Media.created"),
+    ("device", &["Media"], "reference", "Media.device"),
+    ("encounter", &["Media"], "reference", "Media.encounter"),
+    ("identifier", &["Media"], "token", "Media.identifier"),
+    ("modality", &["Media"], "token", "Media.modality"),
+    ("operator", &["Media"], "reference", "Media.operator"),
+    ("patient", &["Media"], "reference", "Media.subject.where(resolve() is Patient)"),
+    ("site", &["Media"], "token", "Media.bodySite"),
+    ("status", &["Media"], "token", "Media.status"),
+    ("subject", &["Media"], "reference", "Media.subject"),
+    ("type", &["Media"], "token", "Media.type"),
+    ("view", &["Media"], "token", "Media.view"),
+    ("expiration-date", &["Medication"], "date", "Medication.batch.expirationDate"),
+    ("form", &["Medication"], "token", "Medication.form"),
+    ("identifier", &["Medication"], "token", "Medication.identifier"),
+    ("ingredient", &["Medication"], "reference", "(Medication.ingredient.item as Reference)"),
+    ("ingredient-code", &["Medication"], "token", "(Medication.ingredient.item as CodeableConcept)"),
+    ("lot-number", &["Medication"], "token", "Medication.batch.lotNumber"),
+    ("manufacturer", &["Medication"], "reference", "Medication.manufacturer"),
+    ("status", &["Medication"], "token", "Medication.status"),
+    ("context", &["MedicationAdministration"], "reference", "MedicationAdministration.context"),
+    ("device", &["MedicationAdministration"], "reference", "MedicationAdministration.device"),
+    ("effective-time", &["MedicationAdministration"], "date", "MedicationAdministration.effective"),
+    ("medication", &["MedicationAdministration", "MedicationDispense", "MedicationRequest", "MedicationStatement"], "reference", "(MedicationAdministration.medication as Reference) | (MedicationDispense.medication as Reference) | (MedicationRequest.medication as Reference) | (MedicationStatement.medication as Reference)"),
+    ("performer", &["MedicationAdministration"], "reference", "MedicationAdministration.performer.actor"),
+    ("reason-given", &["MedicationAdministration"], "token", "MedicationAdministration.reasonCode"),
+    ("reason-not-given", &["MedicationAdministration"], "token", "MedicationAdministration.statusReason"),
+    ("request", &["MedicationAdministration"], "reference", "MedicationAdministration.request"),
+    ("status", &["MedicationAdministration", "MedicationDispense", "MedicationRequest", "MedicationStatement"], "token", "MedicationAdministration.status | MedicationDispense.status | MedicationRequest.status | MedicationStatement.status"),
+    ("subject", &["MedicationAdministration"], "reference", "MedicationAdministration.subject"),
+    ("context", &["MedicationDispense"], "reference", "MedicationDispense.context"),
+    ("destination", &["MedicationDispense"], "reference", "MedicationDispense.destination"),
+    ("performer", &["MedicationDispense"], "reference", "MedicationDispense.performer.actor"),
+    ("prescription", &["MedicationDispense"], "reference", "MedicationDispense.authorizingPrescription"),
+    ("receiver", &["MedicationDispense"], "reference", "MedicationDispense.receiver"),
+    ("responsibleparty", &["MedicationDispense"], "reference", "MedicationDispense.substitution.responsibleParty"),
+    ("subject", &["MedicationDispense"], "reference", "MedicationDispense.subject"),
+    ("type", &["MedicationDispense"], "token", "MedicationDispense.type"),
+    ("whenhandedover", &["MedicationDispense"], "date", "MedicationDispense.whenHandedOver"),
+    ("whenprepared", &["MedicationDispense"], "date", "MedicationDispense.whenPrepared"),
+    ("classification", &["MedicationKnowledge"], "token", "MedicationKnowledge.medicineClassification.classification"),
+    ("classification-type", &["MedicationKnowledge"], "token", "MedicationKnowledge.medicineClassification.type"),
+    ("code", &["MedicationKnowledge"], "token", "MedicationKnowledge.code"),
+    ("doseform", &["MedicationKnowledge"], "token", "MedicationKnowledge.doseForm"),
+    ("ingredient", &["MedicationKnowledge"], "reference", "(MedicationKnowledge.ingredient.item as Reference)"),
+    ("ingredient-code", &["MedicationKnowledge"], "token", "(MedicationKnowledge.ingredient.item as CodeableConcept)"),
+    ("manufacturer", &["MedicationKnowledge"], "reference", "MedicationKnowledge.manufacturer"),
+    ("monitoring-program-name", &["MedicationKnowledge"], "token", "MedicationKnowledge.monitoringProgram.name"),
+    ("monitoring-program-type", &["MedicationKnowledge"], "token", "MedicationKnowledge.monitoringProgram.type"),
+    ("monograph", &["MedicationKnowledge"], "reference", "MedicationKnowledge.monograph.source"),
+    ("monograph-type", &["MedicationKnowledge"], "token", "MedicationKnowledge.monograph.type"),
+    ("source-cost", &["MedicationKnowledge"], "token", "MedicationKnowledge.cost.source"),
+    ("status", &["MedicationKnowledge"], "token", "MedicationKnowledge.status"),
+    ("authoredon", &["MedicationRequest"], "date", "MedicationRequest.authoredOn"),
+    ("category", &["MedicationRequest"], "token", "MedicationRequest.category"),
+    ("date", &["MedicationRequest"], "date", "MedicationRequest.dosageInstruction.timing.event"),
+    ("encounter", &["MedicationRequest"], "reference", "MedicationRequest.encounter"),
+    ("intended-dispenser", &["MedicationRequest"], "reference", "MedicationRequest.dispenseRequest.performer"),
+    ("intended-performer", &["MedicationRequest"], "reference", "MedicationRequest.performer"),
+    ("intended-performertype", &["MedicationRequest"], "token", "MedicationRequest.performerType"),
+    ("intent", &["MedicationRequest"], "token", "MedicationRequest.intent"),
+    ("priority", &["MedicationRequest"], "token", "MedicationRequest.priority"),
+    ("requester", &["MedicationRequest"], "reference", "MedicationRequest.requester"),
+    ("subject", &["MedicationRequest"], "reference", "MedicationRequest.subject"),
+    ("category", &["MedicationStatement"], "token", "MedicationStatement.category"),
+    ("context", &["MedicationStatement"], "reference", "MedicationStatement.context"),
+    ("effective", &["MedicationStatement"], "date", "MedicationStatement.effective"),
+    ("part-of", &["MedicationStatement"], "reference", "MedicationStatement.partOf"),
+    ("source", &["MedicationStatement"], "reference", "MedicationStatement.informationSource"),
+    ("subject", &["MedicationStatement"], "reference", "MedicationStatement.subject"),
+    ("identifier", &["MedicinalProduct"], "token", "MedicinalProduct.identifier"),
+    ("name", &["MedicinalProduct"], "string", "MedicinalProduct.name.productName"),
+    ("name-language", &["MedicinalProduct"], "token", "MedicinalProduct.name.countryLanguage.language"),
+    ("country", &["MedicinalProductAuthorization"], "token", "MedicinalProductAuthorization.country"),
+    ("holder", &["MedicinalProductAuthorization"], "reference", "MedicinalProductAuthorization.holder"),
+    ("identifier", &["MedicinalProductAuthorization"], "token", "MedicinalProductAuthorization.identifier"),
+    ("status", &["MedicinalProductAuthorization"], "token", "MedicinalProductAuthorization.status"),
+    ("subject", &["MedicinalProductAuthorization"], "reference", "MedicinalProductAuthorization.subject"),
+    ("subject", &["MedicinalProductContraindication"], "reference", "MedicinalProductContraindication.subject"),
+    ("subject", &["MedicinalProductIndication"], "reference", "MedicinalProductIndication.subject"),
+    ("subject", &["MedicinalProductInteraction"], "reference", "MedicinalProductInteraction.subject"),
+    ("identifier", &["MedicinalProductPackaged"], "token", "MedicinalProductPackaged.identifier"),
+    ("subject", &["MedicinalProductPackaged"], "reference", "MedicinalProductPackaged.subject"),
+    ("identifier", &["MedicinalProductPharmaceutical"], "token", "MedicinalProductPharmaceutical.identifier"),
+    ("route", &["MedicinalProductPharmaceutical"], "token", "MedicinalProductPharmaceutical.routeOfAdministration.code"),
+    ("target-species", &["MedicinalProductPharmaceutical"], "token", "MedicinalProductPharmaceutical.routeOfAdministration.targetSpecies.code"),
+    ("subject", &["MedicinalProductUndesirableEffect"], "reference", "MedicinalProductUndesirableEffect.subject"),
+    ("category", &["MessageDefinition"], "token", "MessageDefinition.category"),
+    ("event", &["MessageDefinition"], "token", "MessageDefinition.event"),
+    ("focus", &["MessageDefinition"], "token", "MessageDefinition.focus.code"),
+    ("parent", &["MessageDefinition"], "reference", "MessageDefinition.parent"),
+    ("author", &["MessageHeader"], "reference", "MessageHeader.author"),
+    ("code", &["MessageHeader"], "token", "MessageHeader.response.code"),
+    ("destination", &["MessageHeader"], "string", "MessageHeader.destination.name"),
+    ("destination-uri", &["MessageHeader"], "uri", "MessageHeader.destination.endpoint"),
+    ("enterer", &["MessageHeader"], "reference", "MessageHeader.enterer"),
+    ("event", &["MessageHeader"], "token", "MessageHeader.event"),
+    ("focus", &["MessageHeader"], "reference", "MessageHeader.focus"),
+    ("receiver", &["MessageHeader"], "reference", "MessageHeader.destination.receiver"),
+    ("response-id", &["MessageHeader"], "token", "MessageHeader.response.identifier"),
+    ("responsible", &["MessageHeader"], "reference", "MessageHeader.responsible"),
+    ("sender", &["MessageHeader"], "reference", "MessageHeader.sender"),
+    ("source", &["MessageHeader"], "string", "MessageHeader.source.name"),
+    ("source-uri", &["MessageHeader"], "uri", "MessageHeader.source.endpoint"),
+    ("target", &["MessageHeader"], "reference", "MessageHeader.destination.target"),
+    ("chromosome", &["MolecularSequence"], "token", "MolecularSequence.referenceSeq.chromosome"),
+    ("identifier", &["MolecularSequence"], "token", "MolecularSequence.identifier"),
+    ("patient", &["MolecularSequence"], "reference", "MolecularSequence.patient"),
+    ("referenceseqid", &["MolecularSequence"], "token", "MolecularSequence.referenceSeq.referenceSeqId"),
+    ("type", &["MolecularSequence"], "token", "MolecularSequence.type"),
+    ("variant-end", &["MolecularSequence"], "number", "MolecularSequence.variant.end"),
+    ("variant-start", &["MolecularSequence"], "number", "MolecularSequence.variant.start"),
+    ("window-end", &["MolecularSequence"], "number", "MolecularSequence.referenceSeq.windowEnd"),
+    ("window-start", &["MolecularSequence"], "number", "MolecularSequence.referenceSeq.windowStart"),
+    ("contact", &["NamingSystem"], "string", "NamingSystem.contact.name"),
+    ("id-type", &["NamingSystem"], "token", "NamingSystem.uniqueId.type"),
+    ("kind", &["NamingSystem"], "token", "NamingSystem.kind"),
+    ("period", &["NamingSystem"], "date", "NamingSystem.uniqueId.period"),
+    ("responsible", &["NamingSystem"], "string", "NamingSystem.responsible"),
+    ("telecom", &["NamingSystem"], "token", "NamingSystem.contact.telecom"),
+    ("type", &["NamingSystem"], "token", "NamingSystem.type"),
+    ("value", &["NamingSystem"], "string", "NamingSystem.uniqueId.value"),
+    ("additive", &["NutritionOrder"], "token", "NutritionOrder.enteralFormula.additiveType"),
+    ("datetime", &["NutritionOrder"], "date", "NutritionOrder.dateTime"),
+    ("formula", &["NutritionOrder"], "token", "NutritionOrder.enteralFormula.baseFormulaType"),
+    ("instantiates-canonical", &["NutritionOrder"], "reference", "NutritionOrder.instantiatesCanonical"),
+    ("instantiates-uri", &["NutritionOrder"], "uri", "NutritionOrder.instantiatesUri"),
+    ("oraldiet", &["NutritionOrder"], "token", "NutritionOrder.oralDiet.type"),
+    ("provider", &["NutritionOrder"], "reference", "NutritionOrder.orderer"),
+    ("status", &["NutritionOrder"], "token", "NutritionOrder.status"),
+    ("supplement", &["NutritionOrder"], "token", "NutritionOrder.supplement.type"),
+    ("based-on", &["Observation"], "reference", "Observation.basedOn"),
+    ("category", &["Observation"], "token", "Observation.category"),
+    ("combo-code", &["Observation"], "token", "Observation.code | Observation.component.code"),
+    ("combo-data-absent-reason", &["Observation"], "token", "Observation.dataAbsentReason | Observation.component.dataAbsentReason"),
+    ("combo-value-concept", &["Observation"], "token", "(Observation.value as CodeableConcept) | (Observation.component.value as CodeableConcept)"),
+    ("combo-value-quantity", &["Observation"], "quantity", "(Observation.value as Quantity) | (Observation.value as SampledData) | (Observation.component.value as Quantity) | (Observation.component.value as SampledData)"),
+    ("component-code", &["Observation"], "token", "Observation.component.code"),
+    ("component-data-absent-reason", &["Observation"], "token", "Observation.component.dataAbsentReason"),
+    ("component-value-concept", &["Observation"], "token", "(Observation.component.value as CodeableConcept)"),
+    ("component-value-quantity", &["Observation"], "quantity", "(Observation.component.value as Quantity) | (Observation.component.value as SampledData)"),
+    ("data-absent-reason", &["Observation"], "token", "Observation.dataAbsentReason"),
+    ("derived-from", &["Observation"], "reference", "Observation.derivedFrom"),
+    ("device", &["Observation"], "reference", "Observation.device"),
+    ("focus", &["Observation"], "reference", "Observation.focus"),
+    ("has-member", &["Observation"], "reference", "Observation.hasMember"),
+    ("method", &["Observation"], "token", "Observation.method"),
+    ("part-of", &["Observation"], "reference", "Observation.partOf"),
+    ("performer", &["Observation"], "reference", "Observation.performer"),
+    ("specimen", &["Observation"], "reference", "Observation.specimen"),
+    ("status", &["Observation"], "token", "Observation.status"),
+    ("subject", &["Observation"], "reference", "Observation.subject"),
+    ("value-concept", &["Observation"], "token", "(Observation.value as CodeableConcept)"),
+    ("value-date", &["Observation"], "date", "(Observation.value as dateTime) | (Observation.value as Period)"),
+    ("value-quantity", &["Observation"], "quantity", "(Observation.value as Quantity) | (Observation.value as SampledData)"),
+    ("value-string", &["Observation"], "string", "(Observation.value as string) | (Observation.value as CodeableConcept).text"),
+    ("base", &["OperationDefinition"], "reference", "OperationDefinition.base"),
+    ("code", &["OperationDefinition"], "token", "OperationDefinition.code"),
+    ("input-profile", &["OperationDefinition"], "reference", "OperationDefinition.inputProfile"),
+    ("instance", &["OperationDefinition"], "token", "OperationDefinition.instance"),
+    ("kind", &["OperationDefinition"], "token", "OperationDefinition.kind"),
+    ("output-profile", &["OperationDefinition"], "reference", "OperationDefinition.outputProfile"),
+    ("system", &["OperationDefinition"], "token", "OperationDefinition.system"),
+    ("type", &["OperationDefinition"], "token", "OperationDefinition.type"),
+    ("active", &["Organization"], "token", "Organization.active"),
+    ("address", &["Organization"], "string", "Organization.address"),
+    ("address-city", &["Organization"], "string", "Organization.address.city"),
+    ("address-country", &["Organization"], "string", "Organization.address.country"),
+    ("address-postalcode", &["Organization"], "string", "Organization.address.postalCode"),
+    ("address-state", &["Organization"], "string", "Organization.address.state"),
+    ("address-use", &["Organization"], "token", "Organization.address.use"),
+    ("endpoint", &["Organization"], "reference", "Organization.endpoint"),
+    ("identifier", &["Organization"], "token", "Organization.identifier"),
+    ("name", &["Organization"], "string", "Organization.name | Organization.alias"),
+    ("partof", &["Organization"], "reference", "Organization.partOf"),
+    ("phonetic", &["Organization"], "string", "Organization.name"),
+    ("type", &["Organization"], "token", "Organization.type"),
+    ("active", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.active"),
+    ("date", &["OrganizationAffiliation"], "date", "OrganizationAffiliation.period"),
+    ("email", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.telecom.where(system='email')"),
+    ("endpoint", &["OrganizationAffiliation"], "reference", "OrganizationAffiliation.endpoint"),
+    ("identifier", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.identifier"),
+    ("location", &["OrganizationAffiliation"], "reference", "OrganizationAffiliation.location"),
+    ("network", &["OrganizationAffiliation"], "reference", "OrganizationAffiliation.network"),
+    ("participating-organization", &["OrganizationAffiliation"], "reference", "OrganizationAffiliation.participatingOrganization"),
+    ("phone", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.telecom.where(system='phone')"),
+    ("primary-organization", &["OrganizationAffiliation"], "reference", "OrganizationAffiliation.organization"),
+    ("role", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.code"),
+    ("service", &["OrganizationAffiliation"], "reference", "OrganizationAffiliation.healthcareService"),
+    ("specialty", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.specialty"),
+    ("telecom", &["OrganizationAffiliation"], "token", "OrganizationAffiliation.telecom"),
+    ("active", &["Patient"], "token", "Patient.active"),
+    ("address", &["Patient", "Person", "Practitioner", "RelatedPerson"], "string", "Patient.address | Person.address | Practitioner.address | RelatedPerson.address"),
+    ("address-city", &["Patient", "Person", "Practitioner", "RelatedPerson"], "string", "Patient.address.city | Person.address.city | Practitioner.address.city | RelatedPerson.address.city"),
+    ("address-country", &["Patient", "Person", "Practitioner", "RelatedPerson"], "string", "Patient.address.country | Person.address.country | Practitioner.address.country | RelatedPerson.address.country"),
+    ("address-postalcode", &["Patient", "Person", "Practitioner", "RelatedPerson"], "string", "Patient.address.postalCode | Person.address.postalCode | Practitioner.address.postalCode | RelatedPerson.address.postalCode"),
+    ("address-state", &["Patient", "Person", "Practitioner", "RelatedPerson"], "string", "Patient.address.state | Person.address.state | Practitioner.address.state | RelatedPerson.address.state"),
+    ("address-use", &["Patient", "Person", "Practitioner", "RelatedPerson"], "token", "Patient.address.use | Person.address.use | Practitioner.address.use | RelatedPerson.address.use"),
+    ("birthdate", &["Patient", "Person", "RelatedPerson"], "date", "Patient.birthDate | Person.birthDate | RelatedPerson.birthDate"),
+    ("death-date", &["Patient"], "date", "(Patient.deceased as dateTime)"),
+    ("deceased", &["Patient"], "token", "Patient.deceased.exists() and Patient.deceased != false"),
+    ("email", &["Patient", "Person", "Practitioner", "PractitionerRole", "RelatedPerson"], "token", "Patient.telecom.where(system='email') | Person.telecom.where(system='email') | Practitioner.telecom.where(system='email') | PractitionerRole.telecom.where(system='email') | RelatedPerson.telecom.where(system='email')"),
+    ("family", &["Patient", "Practitioner"], "string", "Patient.name.family | Practitioner.name.family"),
+    ("gender", &["Patient", "Person", "Practitioner", "RelatedPerson"], "token", "Patient.gender | Person.gender | Practitioner.gender | RelatedPerson.gender"),
+    ("general-practitioner", &["Patient"], "reference", "Patient.generalPractitioner"),
+    ("given", &["Patient", "Practitioner"], "string", "Patient.name.given | Practitioner.name.given"),
+    ("identifier", &["Patient"], "token", "Patient.identifier"),
+    ("language", &["Patient"], "token", "Patient.communication.language"),
+    ("link", &["Patient"], "reference", "Patient.link.other"),
+    ("name", &["Patient"], "string", "Patient.name"),
+    ("organization", &["Patient"], "reference", "Patient.managingOrganization"),
+    ("phone", &["Patient", "Person", "Practitioner", "PractitionerRole", "RelatedPerson"], "token", "Patient.telecom.where(system='phone') | Person.telecom.where(system='phone') | Practitioner.telecom.where(system='phone') | PractitionerRole.telecom.where(system='phone') | RelatedPerson.telecom.where(system='phone')"),
+    ("phonetic", &["Patient", "Person", "Practitioner", "RelatedPerson"], "string", "Patient.name | Person.name | Practitioner.name | RelatedPerson.name"),
+    ("telecom", &["Patient", "Person", "Practitioner", "PractitionerRole", "RelatedPerson"], "token", "Patient.telecom | Person.telecom | Practitioner.telecom | PractitionerRole.telecom | RelatedPerson.telecom"),
+    ("created", &["PaymentNotice"], "date", "PaymentNotice.created"),
+    ("identifier", &["PaymentNotice"], "token", "PaymentNotice.identifier"),
+    ("payment-status", &["PaymentNotice"], "token", "PaymentNotice.paymentStatus"),
+    ("provider", &["PaymentNotice"], "reference", "PaymentNotice.provider"),
+    ("request", &["PaymentNotice"], "reference", "PaymentNotice.request"),
+    ("response", &["PaymentNotice"], "reference", "PaymentNotice.response"),
+    ("status", &["PaymentNotice"], "token", "PaymentNotice.status"),
+    ("created", &["PaymentReconciliation"], "date", "PaymentReconciliation.created"),
+    ("disposition", &["PaymentReconciliation"], "string", "PaymentReconciliation.disposition"),
+    ("identifier", &["PaymentReconciliation"], "token", "PaymentReconciliation.identifier"),
+    ("outcome", &["PaymentReconciliation"], "token", "PaymentReconciliation.outcome"),
+    ("payment-issuer", &["PaymentReconciliation"], "reference", "PaymentReconciliation.paymentIssuer"),
+    ("request", &["PaymentReconciliation"], "reference", "PaymentReconciliation.request"),
+    ("requestor", &["PaymentReconciliation"], "reference", "PaymentReconciliation.requestor"),
+    ("status", &["PaymentReconciliation"], "token", "PaymentReconciliation.status"),
+    ("identifier", &["Person"], "token", "Person.identifier"),
+    ("link", &["Person"], "reference", "Person.link.target"),
+    ("name", &["Person"], "string", "Person.name"),
+    ("organization", &["Person"], "reference", "Person.managingOrganization"),
+    ("patient", &["Person"], "reference", "Person.link.target.where(resolve() is Patient)"),
+    ("practitioner", &["Person"], "reference", "Person.link.target.where(resolve() is Practitioner)"),
+    ("relatedperson", &["Person"], "reference", "Person.link.target.where(resolve() is RelatedPerson)"),
+    ("composed-of", &["PlanDefinition"], "reference", "PlanDefinition.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["PlanDefinition"], "token", "(PlanDefinition.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["PlanDefinition"], "quantity", "(PlanDefinition.useContext.value as Quantity) | (PlanDefinition.useContext.value as Range)"),
+    ("context-type", &["PlanDefinition"], "token", "PlanDefinition.useContext.code"),
+    ("date", &["PlanDefinition"], "date", "PlanDefinition.date"),
+    ("definition", &["PlanDefinition"], "reference", "PlanDefinition.action.definition"),
+    ("depends-on", &["PlanDefinition"], "reference", "PlanDefinition.relatedArtifact.where(type='depends-on').resource | PlanDefinition.library"),
+    ("derived-from", &["PlanDefinition"], "reference", "PlanDefinition.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["PlanDefinition"], "string", "PlanDefinition.description"),
+    ("effective", &["PlanDefinition"], "date", "PlanDefinition.effectivePeriod"),
+    ("identifier", &["PlanDefinition"], "token", "PlanDefinition.identifier"),
+    ("jurisdiction", &["PlanDefinition"], "token", "PlanDefinition.jurisdiction"),
+    ("name", &["PlanDefinition"], "string", "PlanDefinition.name"),
+    ("predecessor", &["PlanDefinition"], "reference", "PlanDefinition.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["PlanDefinition"], "string", "PlanDefinition.publisher"),
+    ("status", &["PlanDefinition"], "token", "PlanDefinition.status"),
+    ("successor", &["PlanDefinition"], "reference", "PlanDefinition.relatedArtifact.where(type='successor').resource"),
+    ("title", &["PlanDefinition"], "string", "PlanDefinition.title"),
+    ("topic", &["PlanDefinition"], "token", "PlanDefinition.topic"),
+    ("type", &["PlanDefinition"], "token", "PlanDefinition.type"),
+    ("url", &["PlanDefinition"], "uri", "PlanDefinition.url"),
+    ("version", &["PlanDefinition"], "token", "PlanDefinition.version"),
+    ("active", &["Practitioner"], "token", "Practitioner.active"),
+    ("communication", &["Practitioner"], "token", "Practitioner.communication"),
+    ("identifier", &["Practitioner"], "token", "Practitioner.identifier"),
+    ("name", &["Practitioner"], "string", "Practitioner.name"),
+    ("active", &["PractitionerRole"], "token", "PractitionerRole.active"),
+    ("date", &["PractitionerRole"], "date", "PractitionerRole.period"),
+    ("endpoint", &["PractitionerRole"], "reference", "PractitionerRole.endpoint"),
+    ("identifier", &["PractitionerRole"], "token", "PractitionerRole.identifier"),
+    ("location", &["PractitionerRole"], "reference", "PractitionerRole.location"),
+    ("organization", &["PractitionerRole"], "reference", "PractitionerRole.organization"),
+    ("practitioner", &["PractitionerRole"], "reference", "PractitionerRole.practitioner"),
+    ("role", &["PractitionerRole"], "token", "PractitionerRole.code"),
+    ("service", &["PractitionerRole"], "reference", "PractitionerRole.healthcareService"),
+    ("specialty", &["PractitionerRole"], "token", "PractitionerRole.specialty"),
+    ("based-on", &["Procedure"], "reference", "Procedure.basedOn"),
+    ("category", &["Procedure"], "token", "Procedure.category"),
+    ("instantiates-canonical", &["Procedure"], "reference", "Procedure.instantiatesCanonical"),
+    ("instantiates-uri", &["Procedure"], "uri", "Procedure.instantiatesUri"),
+    ("location", &["Procedure"], "reference", "Procedure.location"),
+    ("part-of", &["Procedure"], "reference", "Procedure.partOf"),
+    ("performer", &["Procedure"], "reference", "Procedure.performer.actor"),
+    ("reason-code", &["Procedure"], "token", "Procedure.reasonCode"),
+    ("reason-reference", &["Procedure"], "reference", "Procedure.reasonReference"),
+    ("status", &["Procedure"], "token", "Procedure.status"),
+    ("subject", &["Procedure"], "reference", "Procedure.subject"),
+    ("agent", &["Provenance"], "reference", "Provenance.agent.who"),
+    ("agent-role", &["Provenance"], "token", "Provenance.agent.role"),
+    ("agent-type", &["Provenance"], "token", "Provenance.agent.type"),
+    ("entity", &["Provenance"], "reference", "Provenance.entity.what"),
+    ("location", &["Provenance"], "reference", "Provenance.location"),
+    ("patient", &["Provenance"], "reference", "Provenance.target.where(resolve() is Patient)"),
+    ("recorded", &["Provenance"], "date", "Provenance.recorded"),
+    ("signature-type", &["Provenance"], "token", "Provenance.signature.type"),
+    ("target", &["Provenance"], "reference", "Provenance.target"),
+    ("when", &["Provenance"], "date", "(Provenance.occurred as dateTime)"),
+    ("code", &["Questionnaire"], "token", "Questionnaire.item.code"),
+    ("context", &["Questionnaire"], "token", "(Questionnaire.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["Questionnaire"], "quantity", "(Questionnaire.useContext.value as Quantity) | (Questionnaire.useContext.value as Range)"),
+    ("context-type", &["Questionnaire"], "token", "Questionnaire.useContext.code"),
+    ("date", &["Questionnaire"], "date", "Questionnaire.date"),
+    ("definition", &["Questionnaire"], "uri", "Questionnaire.item.definition"),
+    ("description", &["Questionnaire"], "string", "Questionnaire.description"),
+    ("effective", &["Questionnaire"], "date", "Questionnaire.effectivePeriod"),
+    ("identifier", &["Questionnaire"], "token", "Questionnaire.identifier"),
+    ("jurisdiction", &["Questionnaire"], "token", "Questionnaire.jurisdiction"),
+    ("name", &["Questionnaire"], "string", "Questionnaire.name"),
+    ("publisher", &["Questionnaire"], "string", "Questionnaire.publisher"),
+    ("status", &["Questionnaire"], "token", "Questionnaire.status"),
+    ("subject-type", &["Questionnaire"], "token", "Questionnaire.subjectType"),
+    ("title", &["Questionnaire"], "string", "Questionnaire.title"),
+    ("url", &["Questionnaire"], "uri", "Questionnaire.url"),
+    ("version", &["Questionnaire"], "token", "Questionnaire.version"),
+    ("author", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.author"),
+    ("authored", &["QuestionnaireResponse"], "date", "QuestionnaireResponse.authored"),
+    ("based-on", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.basedOn"),
+    ("encounter", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.encounter"),
+    ("identifier", &["QuestionnaireResponse"], "token", "QuestionnaireResponse.identifier"),
+    ("part-of", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.partOf"),
+    ("patient", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.subject.where(resolve() is Patient)"),
+    ("questionnaire", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.questionnaire"),
+    ("source", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.source"),
+    ("status", &["QuestionnaireResponse"], "token", "QuestionnaireResponse.status"),
+    ("subject", &["QuestionnaireResponse"], "reference", "QuestionnaireResponse.subject"),
+    ("active", &["RelatedPerson"], "token", "RelatedPerson.active"),
+    ("identifier", &["RelatedPerson"], "token", "RelatedPerson.identifier"),
+    ("name", &["RelatedPerson"], "string", "RelatedPerson.name"),
+    ("patient", &["RelatedPerson"], "reference", "RelatedPerson.patient"),
+    ("relationship", &["RelatedPerson"], "token", "RelatedPerson.relationship"),
+    ("author", &["RequestGroup"], "reference", "RequestGroup.author"),
+    ("authored", &["RequestGroup"], "date", "RequestGroup.authoredOn"),
+    ("code", &["RequestGroup"], "token", "RequestGroup.code"),
+    ("encounter", &["RequestGroup"], "reference", "RequestGroup.encounter"),
+    ("group-identifier", &["RequestGroup"], "token", "RequestGroup.groupIdentifier"),
+    ("identifier", &["RequestGroup"], "token", "RequestGroup.identifier"),
+    ("instantiates-canonical", &["RequestGroup"], "reference", "RequestGroup.instantiatesCanonical"),
+    ("instantiates-uri", &["RequestGroup"], "uri", "RequestGroup.instantiatesUri"),
+    ("intent", &["RequestGroup"], "token", "RequestGroup.intent"),
+    ("participant", &["RequestGroup"], "reference", "RequestGroup.action.participant"),
+    ("patient", &["RequestGroup"], "reference", "RequestGroup.subject.where(resolve() is Patient)"),
+    ("priority", &["RequestGroup"], "token", "RequestGroup.priority"),
+    ("status", &["RequestGroup"], "token", "RequestGroup.status"),
+    ("subject", &["RequestGroup"], "reference", "RequestGroup.subject"),
+    ("composed-of", &["ResearchDefinition"], "reference", "ResearchDefinition.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["ResearchDefinition"], "token", "(ResearchDefinition.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["ResearchDefinition"], "quantity", "(ResearchDefinition.useContext.value as Quantity) | (ResearchDefinition.useContext.value as Range)"),
+    ("context-type", &["ResearchDefinition"], "token", "ResearchDefinition.useContext.code"),
+    ("date", &["ResearchDefinition"], "date", "ResearchDefinition.date"),
+    ("depends-on", &["ResearchDefinition"], "reference", "ResearchDefinition.relatedArtifact.where(type='depends-on').resource | ResearchDefinition.library"),
+    ("derived-from", &["ResearchDefinition"], "reference", "ResearchDefinition.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["ResearchDefinition"], "string", "ResearchDefinition.description"),
+    ("effective", &["ResearchDefinition"], "date", "ResearchDefinition.effectivePeriod"),
+    ("identifier", &["ResearchDefinition"], "token", "ResearchDefinition.identifier"),
+    ("jurisdiction", &["ResearchDefinition"], "token", "ResearchDefinition.jurisdiction"),
+    ("name", &["ResearchDefinition"], "string", "ResearchDefinition.name"),
+    ("predecessor", &["ResearchDefinition"], "reference", "ResearchDefinition.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["ResearchDefinition"], "string", "ResearchDefinition.publisher"),
+    ("status", &["ResearchDefinition"], "token", "ResearchDefinition.status"),
+    ("successor", &["ResearchDefinition"], "reference", "ResearchDefinition.relatedArtifact.where(type='successor').resource"),
+    ("title", &["ResearchDefinition"], "string", "ResearchDefinition.title"),
+    ("topic", &["ResearchDefinition"], "token", "ResearchDefinition.topic"),
+    ("url", &["ResearchDefinition"], "uri", "ResearchDefinition.url"),
+    ("version", &["ResearchDefinition"], "token", "ResearchDefinition.version"),
+    ("composed-of", &["ResearchElementDefinition"], "reference", "ResearchElementDefinition.relatedArtifact.where(type='composed-of').resource"),
+    ("context", &["ResearchElementDefinition"], "token", "(ResearchElementDefinition.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["ResearchElementDefinition"], "quantity", "(ResearchElementDefinition.useContext.value as Quantity) | (ResearchElementDefinition.useContext.value as Range)"),
+    ("context-type", &["ResearchElementDefinition"], "token", "ResearchElementDefinition.useContext.code"),
+    ("date", &["ResearchElementDefinition"], "date", "ResearchElementDefinition.date"),
+    ("depends-on", &["ResearchElementDefinition"], "reference", "ResearchElementDefinition.relatedArtifact.where(type='depends-on').resource | ResearchElementDefinition.library"),
+    ("derived-from", &["ResearchElementDefinition"], "reference", "ResearchElementDefinition.relatedArtifact.where(type='derived-from').resource"),
+    ("description", &["ResearchElementDefinition"], "string", "ResearchElementDefinition.description"),
+    ("effective", &["ResearchElementDefinition"], "date", "ResearchElementDefinition.effectivePeriod"),
+    ("identifier", &["ResearchElementDefinition"], "token", "ResearchElementDefinition.identifier"),
+    ("jurisdiction", &["ResearchElementDefinition"], "token", "ResearchElementDefinition.jurisdiction"),
+    ("name", &["ResearchElementDefinition"], "string", "ResearchElementDefinition.name"),
+    ("predecessor", &["ResearchElementDefinition"], "reference", "ResearchElementDefinition.relatedArtifact.where(type='predecessor').resource"),
+    ("publisher", &["ResearchElementDefinition"], "string", "ResearchElementDefinition.publisher"),
+    ("status", &["ResearchElementDefinition"], "token", "ResearchElementDefinition.status"),
+    ("successor", &["ResearchElementDefinition"], "reference", "ResearchElementDefinition.relatedArtifact.where(type='successor').resource"),
+    ("title", &["ResearchElementDefinition"], "string", "ResearchElementDefinition.title"),
+    ("topic", &["ResearchElementDefinition"], "token", "ResearchElementDefinition.topic"),
+    ("url", &["ResearchElementDefinition"], "uri", "ResearchElementDefinition.url"),
+    ("version", &["ResearchElementDefinition"], "token", "ResearchElementDefinition.version"),
+    ("category", &["ResearchStudy"], "token", "ResearchStudy.category"),
+    ("date", &["ResearchStudy"], "date", "ResearchStudy.period"),
+    ("focus", &["ResearchStudy"], "token", "ResearchStudy.focus"),
+    ("identifier", &["ResearchStudy"], "token", "ResearchStudy.identifier"),
+    ("keyword", &["ResearchStudy"], "token", "ResearchStudy.keyword"),
+    ("location", &["ResearchStudy"], "token", "ResearchStudy.location"),
+    ("partof", &["ResearchStudy"], "reference", "ResearchStudy.partOf"),
+    ("principalinvestigator", &["ResearchStudy"], "reference", "ResearchStudy.principalInvestigator"),
+    ("protocol", &["ResearchStudy"], "reference", "ResearchStudy.protocol"),
+    ("site", &["ResearchStudy"], "reference", "ResearchStudy.site"),
+    ("sponsor", &["ResearchStudy"], "reference", "ResearchStudy.sponsor"),
+    ("status", &["ResearchStudy"], "token", "ResearchStudy.status"),
+    ("title", &["ResearchStudy"], "string", "ResearchStudy.title"),
+    ("date", &["ResearchSubject"], "date", "ResearchSubject.period"),
+    ("identifier", &["ResearchSubject"], "token", "ResearchSubject.identifier"),
+    ("individual", &["ResearchSubject"], "reference", "ResearchSubject.individual"),
+    ("patient", &["ResearchSubject"], "reference", "ResearchSubject.individual"),
+    ("status", &["ResearchSubject"], "token", "ResearchSubject.status"),
+    ("study", &["ResearchSubject"], "reference", "ResearchSubject.study"),
+    ("condition", &["RiskAssessment"], "reference", "RiskAssessment.condition"),
+    ("method", &["RiskAssessment"], "token", "RiskAssessment.method"),
+    ("performer", &["RiskAssessment"], "reference", "RiskAssessment.performer"),
+    ("probability", &["RiskAssessment"], "number", "RiskAssessment.prediction.probability"),
+    ("risk", &["RiskAssessment"], "token", "RiskAssessment.prediction.qualitativeRisk"),
+    ("subject", &["RiskAssessment"], "reference", "RiskAssessment.subject"),
+    ("context", &["RiskEvidenceSynthesis"], "token", "(RiskEvidenceSynthesis.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["RiskEvidenceSynthesis"], "quantity", "(RiskEvidenceSynthesis.useContext.value as Quantity) | (RiskEvidenceSynthesis.useContext.value as Range)"),
+    ("context-type", &["RiskEvidenceSynthesis"], "token", "RiskEvidenceSynthesis.useContext.code"),
+    ("date", &["RiskEvidenceSynthesis"], "date", "RiskEvidenceSynthesis.date"),
+    ("description", &["RiskEvidenceSynthesis"], "string", "RiskEvidenceSynthesis.description"),
+    ("effective", &["RiskEvidenceSynthesis"], "date", "RiskEvidenceSynthesis.effectivePeriod"),
+    ("identifier", &["RiskEvidenceSynthesis"], "token", "RiskEvidenceSynthesis.identifier"),
+    ("jurisdiction", &["RiskEvidenceSynthesis"], "token", "RiskEvidenceSynthesis.jurisdiction"),
+    ("name", &["RiskEvidenceSynthesis"], "string", "RiskEvidenceSynthesis.name"),
+    ("publisher", &["RiskEvidenceSynthesis"], "string", "RiskEvidenceSynthesis.publisher"),
+    ("status", &["RiskEvidenceSynthesis"], "token", "RiskEvidenceSynthesis.status"),
+    ("title", &["RiskEvidenceSynthesis"], "string", "RiskEvidenceSynthesis.title"),
+    ("url", &["RiskEvidenceSynthesis"], "uri", "RiskEvidenceSynthesis.url"),
+    ("version", &["RiskEvidenceSynthesis"], "token", "RiskEvidenceSynthesis.version"),
+    ("active", &["Schedule"], "token", "Schedule.active"),
+    ("actor", &["Schedule"], "reference", "Schedule.actor"),
+    ("date", &["Schedule"], "date", "Schedule.planningHorizon"),
+    ("identifier", &["Schedule"], "token", "Schedule.identifier"),
+    ("service-category", &["Schedule"], "token", "Schedule.serviceCategory"),
+    ("service-type", &["Schedule"], "token", "Schedule.serviceType"),
+    ("specialty", &["Schedule"], "token", "Schedule.specialty"),
+    ("base", &["SearchParameter"], "token", "SearchParameter.base"),
+    ("code", &["SearchParameter"], "token", "SearchParameter.code"),
+    ("component", &["SearchParameter"], "reference", "SearchParameter.component.definition"),
+    ("derived-from", &["SearchParameter"], "reference", "SearchParameter.derivedFrom"),
+    ("target", &["SearchParameter"], "token", "SearchParameter.target"),
+    ("type", &["SearchParameter"], "token", "SearchParameter.type"),
+    ("authored", &["ServiceRequest"], "date", "ServiceRequest.authoredOn"),
+    ("based-on", &["ServiceRequest"], "reference", "ServiceRequest.basedOn"),
+    ("body-site", &["ServiceRequest"], "token", "ServiceRequest.bodySite"),
+    ("category", &["ServiceRequest"], "token", "ServiceRequest.category"),
+    ("instantiates-canonical", &["ServiceRequest"], "reference", "ServiceRequest.instantiatesCanonical"),
+    ("instantiates-uri", &["ServiceRequest"], "uri", "ServiceRequest.instantiatesUri"),
+    ("intent", &["ServiceRequest"], "token", "ServiceRequest.intent"),
+    ("occurrence", &["ServiceRequest"], "date", "ServiceRequest.occurrence"),
+    ("performer", &["ServiceRequest"], "reference", "ServiceRequest.performer"),
+    ("performer-type", &["ServiceRequest"], "token", "ServiceRequest.performerType"),
+    ("priority", &["ServiceRequest"], "token", "ServiceRequest.priority"),
+    ("replaces", &["ServiceRequest"], "reference", "ServiceRequest.replaces"),
+    ("requester", &["ServiceRequest"], "reference", "ServiceRequest.requester"),
+    ("requisition", &["ServiceRequest"], "token", "ServiceRequest.requisition"),
+    ("specimen", &["ServiceRequest"], "reference", "ServiceRequest.specimen"),
+    ("status", &["ServiceRequest"], "token", "ServiceRequest.status"),
+    ("subject", &["ServiceRequest"], "reference", "ServiceRequest.subject"),
+    ("appointment-type", &["Slot"], "token", "Slot.appointmentType"),
+    ("identifier", &["Slot"], "token", "Slot.identifier"),
+    ("schedule", &["Slot"], "reference", "Slot.schedule"),
+    ("service-category", &["Slot"], "token", "Slot.serviceCategory"),
+    ("service-type", &["Slot"], "token", "Slot.serviceType"),
+    ("specialty", &["Slot"], "token", "Slot.specialty"),
+    ("start", &["Slot"], "date", "Slot.start"),
+    ("status", &["Slot"], "token", "Slot.status"),
+    ("accession", &["Specimen"], "token", "Specimen.accessionIdentifier"),
+    ("bodysite", &["Specimen"], "token", "Specimen.collection.bodySite"),
+    ("collected", &["Specimen"], "date", "Specimen.collection.collected"),
+    ("collector", &["Specimen"], "reference", "Specimen.collection.collector"),
+    ("container", &["Specimen"], "token", "Specimen.container.type"),
+    ("container-id", &["Specimen"], "token", "Specimen.container.identifier"),
+    ("identifier", &["Specimen"], "token", "Specimen.identifier"),
+    ("parent", &["Specimen"], "reference", "Specimen.parent"),
+    ("patient", &["Specimen"], "reference", "Specimen.subject.where(resolve() is Patient)"),
+    ("status", &["Specimen"], "token", "Specimen.status"),
+    ("subject", &["Specimen"], "reference", "Specimen.subject"),
+    ("type", &["Specimen"], "token", "Specimen.type"),
+    ("container", &["SpecimenDefinition"], "token", "SpecimenDefinition.typeTested.container.type"),
+    ("identifier", &["SpecimenDefinition"], "token", "SpecimenDefinition.identifier"),
+    ("type", &["SpecimenDefinition"], "token", "SpecimenDefinition.typeCollected"),
+    ("abstract", &["StructureDefinition"], "token", "StructureDefinition.abstract"),
+    ("base", &["StructureDefinition"], "reference", "StructureDefinition.baseDefinition"),
+    ("base-path", &["StructureDefinition"], "token", "StructureDefinition.snapshot.element.base.path | StructureDefinition.differential.element.base.path"),
+    ("derivation", &["StructureDefinition"], "token", "StructureDefinition.derivation"),
+    ("experimental", &["StructureDefinition"], "token", "StructureDefinition.experimental"),
+    ("ext-context", &["StructureDefinition"], "token", "StructureDefinition.context.type"),
+    ("keyword", &["StructureDefinition"], "token", "StructureDefinition.keyword"),
+    ("kind", &["StructureDefinition"], "token", "StructureDefinition.kind"),
+    ("path", &["StructureDefinition"], "token", "StructureDefinition.snapshot.element.path | StructureDefinition.differential.element.path"),
+    ("type", &["StructureDefinition"], "uri", "StructureDefinition.type"),
+    ("valueset", &["StructureDefinition"], "reference", "StructureDefinition.snapshot.element.binding.valueSet"),
+    ("contact", &["Subscription"], "token", "Subscription.contact"),
+    ("criteria", &["Subscription"], "string", "Subscription.criteria"),
+    ("payload", &["Subscription"], "token", "Subscription.channel.payload"),
+    ("status", &["Subscription"], "token", "Subscription.status"),
+    ("type", &["Subscription"], "token", "Subscription.channel.type"),
+    ("url", &["Subscription"], "uri", "Subscription.channel.endpoint"),
+    ("category", &["Substance"], "token", "Substance.category"),
+    ("code", &["Substance"], "token", "Substance.code | (Substance.ingredient.substance as CodeableConcept)"),
+    ("container-identifier", &["Substance"], "token", "Substance.instance.identifier"),
+    ("expiry", &["Substance"], "date", "Substance.instance.expiry"),
+    ("identifier", &["Substance"], "token", "Substance.identifier"),
+    ("quantity", &["Substance"], "quantity", "Substance.instance.quantity"),
+    ("status", &["Substance"], "token", "Substance.status"),
+    ("substance-reference", &["Substance"], "reference", "(Substance.ingredient.substance as Reference)"),
+    ("code", &["SubstanceSpecification"], "token", "SubstanceSpecification.code.code"),
+    ("receiver", &["SupplyDelivery"], "reference", "SupplyDelivery.receiver"),
+    ("status", &["SupplyDelivery"], "token", "SupplyDelivery.status"),
+    ("supplier", &["SupplyDelivery"], "reference", "SupplyDelivery.supplier"),
+    ("category", &["SupplyRequest"], "token", "SupplyRequest.category"),
+    ("requester", &["SupplyRequest"], "reference", "SupplyRequest.requester"),
+    ("status", &["SupplyRequest"], "token", "SupplyRequest.status"),
+    ("subject", &["SupplyRequest"], "reference", "SupplyRequest.deliverTo"),
+    ("supplier", &["SupplyRequest"], "reference", "SupplyRequest.supplier"),
+    ("authored-on", &["Task"], "date", "Task.authoredOn"),
+    ("based-on", &["Task"], "reference", "Task.basedOn"),
+    ("business-status", &["Task"], "token", "Task.businessStatus"),
+    ("code", &["Task"], "token", "Task.code"),
+    ("encounter", &["Task"], "reference", "Task.encounter"),
+    ("focus", &["Task"], "reference", "Task.focus"),
+    ("group-identifier", &["Task"], "token", "Task.groupIdentifier"),
+    ("identifier", &["Task"], "token", "Task.identifier"),
+    ("intent", &["Task"], "token", "Task.intent"),
+    ("modified", &["Task"], "date", "Task.lastModified"),
+    ("owner", &["Task"], "reference", "Task.owner"),
+    ("part-of", &["Task"], "reference", "Task.partOf"),
+    ("patient", &["Task"], "reference", "Task.for.where(resolve() is Patient)"),
+    ("performer", &["Task"], "token", "Task.performerType"),
+    ("period", &["Task"], "date", "Task.executionPeriod"),
+    ("priority", &["Task"], "token", "Task.priority"),
+    ("requester", &["Task"], "reference", "Task.requester"),
+    ("status", &["Task"], "token", "Task.status"),
+    ("subject", &["Task"], "reference", "Task.for"),
+    ("identifier", &["TestReport"], "token", "TestReport.identifier"),
+    ("issued", &["TestReport"], "date", "TestReport.issued"),
+    ("participant", &["TestReport"], "uri", "TestReport.participant.uri"),
+    ("result", &["TestReport"], "token", "TestReport.result"),
+    ("tester", &["TestReport"], "string", "TestReport.tester"),
+    ("testscript", &["TestReport"], "reference", "TestReport.testScript"),
+    ("context", &["TestScript"], "token", "(TestScript.useContext.value as CodeableConcept)"),
+    ("context-quantity", &["TestScript"], "quantity", "(TestScript.useContext.value as Quantity) | (TestScript.useContext.value as Range)"),
+    ("context-type", &["TestScript"], "token", "TestScript.useContext.code"),
+    ("date", &["TestScript"], "date", "TestScript.date"),
+    ("description", &["TestScript"], "string", "TestScript.description"),
+    ("identifier", &["TestScript"], "token", "TestScript.identifier"),
+    ("jurisdiction", &["TestScript"], "token", "TestScript.jurisdiction"),
+    ("name", &["TestScript"], "string", "TestScript.name"),
+    ("publisher", &["TestScript"], "string", "TestScript.publisher"),
+    ("status", &["TestScript"], "token", "TestScript.status"),
+    ("testscript-capability", &["TestScript"], "string", "TestScript.metadata.capability.description"),
+    ("title", &["TestScript"], "string", "TestScript.title"),
+    ("url", &["TestScript"], "uri", "TestScript.url"),
+    ("version", &["TestScript"], "token", "TestScript.version"),
+    ("code", &["ValueSet"], "token", "ValueSet.expansion.contains.code | ValueSet.compose.include.concept.code"),
+    ("expansion", &["ValueSet"], "uri", "ValueSet.expansion.identifier"),
+    ("reference", &["ValueSet"], "uri", "ValueSet.compose.include.system"),
+    ("target", &["VerificationResult"], "reference", "VerificationResult.target"),
+    ("datewritten", &["VisionPrescription"], "date", "VisionPrescription.dateWritten"),
+    ("prescriber", &["VisionPrescription"], "reference", "VisionPrescription.prescriber"),
+    ("status", &["VisionPrescription"], "token", "VisionPrescription.status"),
+];
@@ -0,0 +1,221 @@
+//! The typed values a search parameter indexes, extracted from the elements
+//! its expression selects.
+
+use crate::fhirpath::Node;
+use crate::model::SearchParameter::SearchParameterType;
+use crate::reference::ReferenceTarget;
+use crate::temporal::{DateTime, Interval};
+use crate::ucum::{Unit, UCUM_SYSTEM};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexValue {
+  Number(f64),
+  /// The instants a date, dateTime, instant, Period or Timing event covers,
+  /// so that `2020-03` indexes all of March.
+  Date(Interval),
+  /// A string normalised with `normalise`.
+  String(String),
+  /// A code, coding, identifier or contact point value.
+  Token {
+    system: Option<String>,
+    code: Option<String>,
+  },
+  /// A quantity, with its value in canonical UCUM units when it has a UCUM
+  /// code.
+  Quantity {
+    value: f64,
+    system: Option<String>,
+    code: Option<String>,
+    canonical: Option<(f64, String)>,
+  },
+  Reference {
+    reference: String,
+    target: Option<ReferenceTarget>,
+  },
+  Uri(String),
+}
+
+fn fold(c: char) -> char {
+  match c {
+    'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+    'ç' => 'c',
+    'è' | 'é' | 'ê' | 'ë' => 'e',
+    'ì' | 'í' | 'î' | 'ï' => 'i',
+    'ñ' => 'n',
+    'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+    'ù' | 'ú' | 'û' | 'ü' => 'u',
+    'ý' | 'ÿ' => 'y',
+    _ => c,
+  }
+}
+
+/// Lower case, without common accents and with runs of whitespace and
+/// punctuation collapsed to one space, so that `Zoë  O'Brien` and
+/// `zoe o brien` index alike.
+pub fn normalise(text: &str) -> String {
+  text
+    .to_lowercase()
+    .chars()
+    .map(fold)
+    .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+    .collect::<String>()
+    .split_whitespace()
+    .collect::<Vec<&str>>()
+    .join(" ")
+}
+
+const STRING_PARTS: &[&str] = &[
+  "text",
+  "family",
+  "given",
+  "prefix",
+  "suffix",
+  "line",
+  "city",
+  "district",
+  "state",
+  "postalCode",
+  "country",
+];
+
+fn text(value: &Value, key: &str) -> Option<String> {
+  value[key].as_str().map(|s| s.to_string())
+}
+
+/// The strings of an element as written: the element itself, or the parts
+/// of a HumanName or Address.
+pub(crate) fn texts(value: &Value, found: &mut Vec<String>) {
+  match value {
+    Value::String(text) => found.push(text.clone()),
+    Value::Array(items) => items.iter().for_each(|item| texts(item, found)),
+    Value::Object(object) => STRING_PARTS
+      .iter()
+      .filter_map(|key| object.get(*key))
+      .for_each(|part| texts(part, found)),
+    _ => {}
+  }
+}
+
+fn strings(value: &Value, found: &mut Vec<IndexValue>) {
+  let mut written = vec![];
+  texts(value, &mut written);
+  found.extend(
+    written
+      .iter()
+      .map(|text| IndexValue::String(normalise(text))),
+  );
+}
+
+fn tokens(value: &Value, found: &mut Vec<IndexValue>) {
+  let token = |system: Option<String>, code: Option<String>| IndexValue::Token { system, code };
+  match value {
+    Value::String(code) => found.push(token(None, Some(code.clone()))),
+    Value::Bool(code) => found.push(token(None, Some(code.to_string()))),
+    Value::Object(object) if object.contains_key("coding") => {
+      for coding in value["coding"].as_array().into_iter().flatten() {
+        tokens(coding, found);
+      }
+    }
+    Value::Object(object) if object.contains_key("value") => {
+      // An Identifier has a URI for its system, a ContactPoint a code such
+      // as `phone`, which is not a token system.
+      let system = text(value, "system").filter(|system| system.contains(':'));
+      found.push(token(
+        system,
+        value["value"].as_str().map(|v| v.to_string()),
+      ));
+    }
+    Value::Object(object) if object.contains_key("code") || object.contains_key("system") => {
+      found.push(token(text(value, "system"), text(value, "code")));
+    }
+    _ => {}
+  }
+}
+
+fn dates(value: &Value, found: &mut Vec<IndexValue>) {
+  match value {
+    Value::String(text) => {
+      if let Some(date_time) = DateTime::parse(text) {
+        found.push(IndexValue::Date(Interval::of(&date_time)));
+      }
+    }
+    Value::Object(object) if object.contains_key("event") || object.contains_key("repeat") => {
+      for event in value["event"].as_array().into_iter().flatten() {
+        dates(event, found);
+      }
+    }
+    Value::Object(_) => {
+      let start = value["start"].as_str().and_then(DateTime::parse);
+      let end = value["end"].as_str().and_then(DateTime::parse);
+      if start.is_some() || end.is_some() {
+        found.push(IndexValue::Date(Interval {
+          low: start.map(|start| start.low()),
+          high: end.map(|end| end.high()),
+        }));
+      }
+    }
+    _ => {}
+  }
+}
+
+fn quantities(value: &Value, found: &mut Vec<IndexValue>) {
+  let number = match value["value"].as_f64() {
+    Some(number) => number,
+    None => return,
+  };
+  let system = text(value, "system");
+  let code = text(value, "code");
+  let canonical = match (system.as_deref(), code.as_deref()) {
+    (Some(UCUM_SYSTEM), Some(code)) => Unit::parse(code)
+      .ok()
+      .map(|unit| (unit.to_canonical(number), unit.canonical_code())),
+    _ => None,
+  };
+  found.push(IndexValue::Quantity {
+    value: number,
+    system,
+    code,
+    canonical,
+  });
+}
+
+fn references(value: &Value, found: &mut Vec<IndexValue>) {
+  let reference = match value {
+    Value::String(reference) => reference.as_str(),
+    _ => match value["reference"].as_str() {
+      Some(reference) => reference,
+      None => return,
+    },
+  };
+  found.push(IndexValue::Reference {
+    reference: reference.to_string(),
+    target: ReferenceTarget::parse(reference),
+  });
+}
+
+/// The values of one element for a parameter of type `param_type`.
+/// Composite and special parameters have none.
+pub fn extract(param_type: &SearchParameterType, node: &Node) -> Vec<IndexValue> {
+  let value = &*node.value;
+  let mut found = vec![];
+  match param_type {
+    SearchParameterType::Number => {
+      if let Some(number) = value.as_f64().or_else(|| value["value"].as_f64()) {
+        found.push(IndexValue::Number(number));
+      }
+    }
+    SearchParameterType::Date => dates(value, &mut found),
+    SearchParameterType::String => strings(value, &mut found),
+    SearchParameterType::Token => tokens(value, &mut found),
+    SearchParameterType::Reference => references(value, &mut found),
+    SearchParameterType::Quantity => quantities(value, &mut found),
+    SearchParameterType::Uri => {
+      if let Some(uri) = value.as_str() {
+        found.push(IndexValue::Uri(uri.to_string()));
+      }
+    }
+    SearchParameterType::Composite | SearchParameterType::Special => {}
+  }
+  found
+}
@@ -1,5 +1,24 @@
+pub mod base64;
+pub mod bundle;
+pub mod compare;
+pub mod concept_map;
+pub mod demographics;
+pub mod elements;
+pub mod fhirpath;
+pub mod graphql;
+pub mod index;
 pub mod model;
+pub mod narrative;
 pub mod parser;
+pub mod patch;
+pub mod projection;
+pub mod questionnaire;
+pub mod reference;
+pub mod rest;
+pub mod server;
+pub mod store;
+pub mod temporal;
+pub mod ucum;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,917 @@
+//! Human-readable sentences for `Dosage` instructions, e.g. "Take 2 tablets
+//! by mouth twice daily for 7 days, as needed for pain".
+//!
+//! Every word the renderer produces comes from a phrase table keyed by the
+//! concept it expresses (`route.26643006`, `times.2`, `per.d`, ...), so the
+//! sentences can be localised by replacing phrases. Templates use `{0}`,
+//! `{1}`, ... for their arguments.
+
+use crate::model::CodeableConcept::CodeableConcept;
+use crate::model::Dosage::Dosage;
+use crate::model::Quantity::Quantity;
+use crate::model::Range::Range;
+use crate::model::Timing::Timing;
+use crate::model::Timing_Repeat::Timing_Repeat;
+use crate::temporal::timing::abbreviation;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The English phrase table.
+const ENGLISH: &[(&str, &str)] = &[
+  ("verb", "Take"),
+  ("method.421521009", "Swallow"),
+  ("method.421538008", "Instill"),
+  ("method.417924000", "Apply"),
+  ("method.421257003", "Insert"),
+  ("method.422145002", "Inject"),
+  ("method.420620005", "Push"),
+  ("dose.range", "{0} to {1}"),
+  ("form.TAB.one", "tablet"),
+  ("form.TAB.other", "tablets"),
+  ("form.CAP.one", "capsule"),
+  ("form.CAP.other", "capsules"),
+  ("form.SUPP.one", "suppository"),
+  ("form.SUPP.other", "suppositories"),
+  ("form.[drp].one", "drop"),
+  ("form.[drp].other", "drops"),
+  ("form.PATCH.one", "patch"),
+  ("form.PATCH.other", "patches"),
+  ("form.{puff}.one", "puff"),
+  ("form.{puff}.other", "puffs"),
+  ("form.U.one", "unit"),
+  ("form.U.other", "units"),
+  ("route", "via {0}"),
+  ("route.26643006", "by mouth"),
+  ("route.47625008", "intravenously"),
+  ("route.255560000", "intravenously"),
+  ("route.263887005", "subcutaneously"),
+  ("route.34206005", "subcutaneously"),
+  ("route.255559005", "intramuscularly"),
+  ("route.78421000", "intramuscularly"),
+  ("route.6064005", "topically"),
+  ("route.359540000", "topically"),
+  ("route.16857009", "vaginally"),
+  ("route.37161004", "rectally"),
+  ("route.54485002", "in the eye"),
+  ("route.46713006", "nasally"),
+  ("route.45890007", "transdermally"),
+  ("route.447694001", "by inhalation"),
+  ("site", "to {0}"),
+  ("times.1", "once"),
+  ("times.2", "twice"),
+  ("times.3", "three times"),
+  ("times.4", "four times"),
+  ("times.n", "{0} times"),
+  ("times.range", "{0} to {1} times"),
+  ("per.s", "every second"),
+  ("per.min", "every minute"),
+  ("per.h", "hourly"),
+  ("per.d", "daily"),
+  ("per.wk", "weekly"),
+  ("per.mo", "monthly"),
+  ("per.a", "yearly"),
+  ("once.per.d", "once daily"),
+  ("once.per.wk", "once weekly"),
+  ("once.per.mo", "once monthly"),
+  ("once.per.a", "once yearly"),
+  ("frequency.per", "{0} {1}"),
+  ("every", "every {0} {1}"),
+  ("every.one", "every {0}"),
+  ("frequency.every", "{0} every {1} {2}"),
+  ("range", "{0} to {1}"),
+  ("unit.s.one", "second"),
+  ("unit.s.other", "seconds"),
+  ("unit.min.one", "minute"),
+  ("unit.min.other", "minutes"),
+  ("unit.h.one", "hour"),
+  ("unit.h.other", "hours"),
+  ("unit.d.one", "day"),
+  ("unit.d.other", "days"),
+  ("unit.wk.one", "week"),
+  ("unit.wk.other", "weeks"),
+  ("unit.mo.one", "month"),
+  ("unit.mo.other", "months"),
+  ("unit.a.one", "year"),
+  ("unit.a.other", "years"),
+  ("at", "at {0}"),
+  ("on", "on {0}"),
+  ("day.mon", "Monday"),
+  ("day.tue", "Tuesday"),
+  ("day.wed", "Wednesday"),
+  ("day.thu", "Thursday"),
+  ("day.fri", "Friday"),
+  ("day.sat", "Saturday"),
+  ("day.sun", "Sunday"),
+  ("when.MORN", "in the morning"),
+  ("when.MORN.early", "early in the morning"),
+  ("when.MORN.late", "late in the morning"),
+  ("when.NOON", "at noon"),
+  ("when.AFT", "in the afternoon"),
+  ("when.AFT.early", "early in the afternoon"),
+  ("when.AFT.late", "late in the afternoon"),
+  ("when.EVE", "in the evening"),
+  ("when.EVE.early", "early in the evening"),
+  ("when.EVE.late", "late in the evening"),
+  ("when.NIGHT", "at night"),
+  ("when.PHS", "after sleep"),
+  ("when.HS", "at bedtime"),
+  ("when.WAKE", "on waking"),
+  ("when.C", "with a meal"),
+  ("when.CM", "with breakfast"),
+  ("when.CD", "with lunch"),
+  ("when.CV", "with dinner"),
+  ("when.AC", "before a meal"),
+  ("when.ACM", "before breakfast"),
+  ("when.ACD", "before lunch"),
+  ("when.ACV", "before dinner"),
+  ("when.PC", "after a meal"),
+  ("when.PCM", "after breakfast"),
+  ("when.PCD", "after lunch"),
+  ("when.PCV", "after dinner"),
+  ("offset", "{0} minutes {1}"),
+  ("list", "{0}, {1}"),
+  ("list.last", "{0} and {1}"),
+  ("over", "over {0} {1}"),
+  ("for", "for {0} {1}"),
+  ("count.1", "as a single dose"),
+  ("count.n", "for {0} doses"),
+  ("from_to", "from {0} to {1}"),
+  ("from", "from {0}"),
+  ("until", "until {0}"),
+  ("rate", "at {0}"),
+  ("ratio", "{0} per {1}"),
+  ("max", "up to {0}"),
+  ("as_needed", "as needed"),
+  ("as_needed_for", "as needed for {0}"),
+  ("clause", "{0}, {1}"),
+  ("sentence", "{0}. {1}"),
+];
+
+/// Renders `Dosage` instructions as sentences from a phrase table. The
+/// default table is English; replace entries with `phrase` to localise.
+#[derive(Debug, Clone)]
+pub struct DosageRenderer {
+  phrases: HashMap<String, String>,
+}
+
+impl Default for DosageRenderer {
+  fn default() -> Self {
+    DosageRenderer {
+      phrases: ENGLISH
+        .iter()
+        .map(|(key, template)| (key.to_string(), template.to_string()))
+        .collect(),
+    }
+  }
+}
+
+fn fill(template: &str, args: &[&str]) -> String {
+  let mut filled = template.to_string();
+  for (i, arg) in args.iter().enumerate() {
+    filled = filled.replace(&format!("{{{}}}", i), arg);
+  }
+  filled
+}
+
+fn number(value: f64) -> String {
+  if value.fract() == 0.0 && value.abs() < 1e15 {
+    (value as i64).to_string()
+  } else {
+    value.to_string()
+  }
+}
+
+/// A display without its SNOMED CT semantic tag and the qualifier after
+/// " - ", e.g. "Rib Pain" for "Rib Pain (finding)".
+fn clean(display: &str) -> String {
+  let mut display = display.trim();
+  if display.ends_with(')') {
+    if let Some(open) = display.rfind(" (") {
+      display = &display[..open];
+    }
+  }
+  if let Some(dash) = display.find(" - ") {
+    display = &display[..dash];
+  }
+  display.to_string()
+}
+
+/// Lowercases words for use mid-sentence, leaving acronyms alone.
+fn lowercase(text: &str) -> String {
+  text
+    .split(' ')
+    .map(|word| {
+      if word.len() > 1 && word.chars().all(|c| !c.is_lowercase()) {
+        word.to_string()
+      } else {
+        word.to_lowercase()
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn capitalise(text: &str) -> String {
+  let mut chars = text.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+fn concept_text(concept: &CodeableConcept) -> String {
+  clean(&super::render::codeable_concept(concept))
+}
+
+fn concept_codes(concept: &CodeableConcept) -> Vec<String> {
+  concept
+    .coding()
+    .unwrap_or_default()
+    .iter()
+    .filter_map(|c| c.code().map(|code| code.to_string()))
+    .collect()
+}
+
+impl DosageRenderer {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the template for a phrase, e.g. `("route.26643006", "par voie
+  /// orale")`.
+  pub fn phrase(&mut self, key: &str, template: &str) -> &mut Self {
+    self.phrases.insert(key.to_string(), template.to_string());
+    self
+  }
+
+  fn lookup(&self, key: &str) -> Option<&str> {
+    self.phrases.get(key).map(|p| p.as_str())
+  }
+
+  fn say(&self, key: &str, args: &[&str]) -> String {
+    fill(self.lookup(key).unwrap_or_default(), args)
+  }
+
+  fn list(&self, items: &[String]) -> String {
+    match items.len() {
+      0 => String::new(),
+      1 => items[0].clone(),
+      n => {
+        let head = items[..n - 1]
+          .iter()
+          .skip(1)
+          .fold(items[0].clone(), |acc, item| {
+            self.say("list", &[&acc, item])
+          });
+        self.say("list.last", &[&head, &items[n - 1]])
+      }
+    }
+  }
+
+  fn plural(&self, prefix: &str, code: &str, value: f64) -> Option<&str> {
+    let form = if value == 1.0 { "one" } else { "other" };
+    self.lookup(&format!("{}.{}.{}", prefix, code, form))
+  }
+
+  /// The unit of a time span, e.g. "days" for `d`.
+  fn time_unit(&self, code: &str, value: f64) -> String {
+    self.plural("unit", code, value).unwrap_or(code).to_string()
+  }
+
+  /// A dose or rate, with dose forms such as `TAB` rendered as words.
+  fn quantity(&self, quantity: &Quantity) -> String {
+    let value = quantity.value().unwrap_or(1.0);
+    let code = quantity.code().unwrap_or_default();
+    match self.plural("form", code, value) {
+      Some(form) => format!("{} {}", number(value), form),
+      None => super::render::quantity(quantity),
+    }
+  }
+
+  fn range(&self, range: &Range) -> String {
+    match (range.low(), range.high()) {
+      (Some(low), Some(high)) => {
+        let unit = high.code().unwrap_or_default();
+        let low_value = number(low.value().unwrap_or_default());
+        if low.code().unwrap_or_default() == unit {
+          self.say("dose.range", &[&low_value, &self.quantity(&high)])
+        } else {
+          self.say("dose.range", &[&self.quantity(&low), &self.quantity(&high)])
+        }
+      }
+      (Some(low), None) => self.quantity(&low),
+      (None, Some(high)) => self.say("max", &[&self.quantity(&high)]),
+      (None, None) => String::new(),
+    }
+  }
+
+  fn frequency(&self, repeat: &Timing_Repeat) -> Option<String> {
+    let unit = repeat.period_unit().map(|u| u.to_string());
+    let unit = match unit {
+      Some(unit) => unit,
+      None if repeat.time_of_day().is_some() || repeat.value.get("when").is_some() => {
+        return self.lookup("per.d").map(|p| p.to_string());
+      }
+      None => return None,
+    };
+    let frequency = repeat.frequency().unwrap_or(1);
+    let period = repeat.period().unwrap_or(1.0);
+    let periods = match repeat.period_max() {
+      Some(max) => self.say("range", &[&number(period), &number(max)]),
+      None => number(period),
+    };
+    let single_period = period == 1.0 && repeat.period_max().is_none();
+    if frequency == 1 && repeat.frequency_max().is_none() {
+      if single_period && matches!(unit.as_str(), "d" | "wk" | "mo" | "a") {
+        return Some(self.say(&format!("once.per.{}", unit), &[]));
+      }
+      if single_period {
+        return Some(self.say("every.one", &[&self.time_unit(&unit, 1.0)]));
+      }
+      let units = self.time_unit(&unit, repeat.period_max().unwrap_or(period));
+      return Some(self.say("every", &[&periods, &units]));
+    }
+    let times = match repeat.frequency_max() {
+      Some(max) => self.say("times.range", &[&frequency.to_string(), &max.to_string()]),
+      None => match self.lookup(&format!("times.{}", frequency)) {
+        Some(times) => times.to_string(),
+        None => self.say("times.n", &[&frequency.to_string()]),
+      },
+    };
+    if single_period {
+      let per = self.say(&format!("per.{}", unit), &[]);
+      return Some(self.say("frequency.per", &[&times, &per]));
+    }
+    let units = self.time_unit(&unit, repeat.period_max().unwrap_or(period));
+    Some(self.say("frequency.every", &[&times, &periods, &units]))
+  }
+
+  fn when(&self, repeat: &Timing_Repeat) -> Option<String> {
+    let codes = repeat.value.get("when")?.as_array()?;
+    let offset = repeat.offset();
+    let phrases: Vec<String> = codes
+      .iter()
+      .filter_map(|code| code.as_str())
+      .map(|code| {
+        let phrase = self
+          .lookup(&format!("when.{}", code))
+          .unwrap_or(code)
+          .to_string();
+        match offset {
+          Some(minutes) if code.starts_with("AC") || code.starts_with("PC") => {
+            self.say("offset", &[&minutes.to_string(), &phrase])
+          }
+          _ => phrase,
+        }
+      })
+      .collect();
+    Some(self.list(&phrases))
+  }
+
+  fn bounds(&self, repeat: &Timing_Repeat) -> Option<String> {
+    if let Some(duration) = repeat.bounds_duration() {
+      let value = duration.value().unwrap_or_default();
+      let code = duration
+        .code()
+        .or_else(|| duration.unit())
+        .unwrap_or_default();
+      return Some(self.say("for", &[&number(value), &self.time_unit(code, value)]));
+    }
+    if let Some(range) = repeat.bounds_range() {
+      let high = range.high()?;
+      let code = high.code().unwrap_or_default();
+      let value = high.value().unwrap_or_default();
+      let low = range.low().and_then(|l| l.value()).unwrap_or_default();
+      let span = self.say("range", &[&number(low), &number(value)]);
+      return Some(self.say("for", &[&span, &self.time_unit(code, value)]));
+    }
+    let period = repeat.bounds_period()?;
+    match (period.start(), period.end()) {
+      (Some(start), Some(end)) => Some(self.say("from_to", &[start, end])),
+      (Some(start), None) => Some(self.say("from", &[start])),
+      (None, Some(end)) => Some(self.say("until", &[end])),
+      (None, None) => None,
+    }
+  }
+
+  fn timing(&self, timing: &Timing) -> Vec<String> {
+    let repeat = match timing.repeat() {
+      Some(repeat) => repeat.value.into_owned(),
+      None => match timing
+        .code()
+        .and_then(|c| concept_codes(&c).iter().find_map(|code| abbreviation(code)))
+      {
+        Some(repeat) => repeat,
+        None => {
+          return timing
+            .code()
+            .map(|c| vec![concept_text(&c)])
+            .unwrap_or_default()
+        }
+      },
+    };
+    let repeat = Timing_Repeat::new(&repeat);
+    let mut parts = vec![];
+    parts.extend(self.frequency(&repeat));
+    if let Some(times) = repeat.time_of_day() {
+      let times: Vec<String> = times
+        .iter()
+        .map(|t| t.get(..5).unwrap_or(t).to_string())
+        .collect();
+      parts.push(self.say("at", &[&self.list(&times)]));
+    }
+    parts.extend(self.when(&repeat));
+    if let Some(days) = repeat.day_of_week() {
+      let days: Vec<String> = days
+        .iter()
+        .map(|d| self.lookup(&format!("day.{}", d)).unwrap_or(d).to_string())
+        .collect();
+      parts.push(self.say("on", &[&self.list(&days)]));
+    }
+    if let Some(duration) = repeat.duration() {
+      let unit = repeat
+        .duration_unit()
+        .map(|u| u.to_string())
+        .unwrap_or_default();
+      let span = match repeat.duration_max() {
+        Some(max) => self.say("range", &[&number(duration), &number(max)]),
+        None => number(duration),
+      };
+      let units = self.time_unit(&unit, repeat.duration_max().unwrap_or(duration));
+      parts.push(self.say("over", &[&span, &units]));
+    }
+    match repeat.count() {
+      Some(1) => parts.push(self.say("count.1", &[])),
+      Some(count) => parts.push(self.say("count.n", &[&count.to_string()])),
+      None => {}
+    }
+    parts.extend(self.bounds(&repeat));
+    parts
+  }
+
+  /// Whether the structured fields say enough to render the instruction.
+  fn is_structured(dosage: &Dosage) -> bool {
+    dosage.dose_and_rate().is_some() || dosage.timing().is_some()
+  }
+
+  /// The instruction as a sentence, rendered from the structured fields.
+  /// Falls back to `Dosage.text` when there is neither a dose nor a timing.
+  pub fn render(&self, dosage: &Dosage) -> String {
+    match dosage.text() {
+      Some(text) if !Self::is_structured(dosage) => text.to_string(),
+      _ => self.render_structured(dosage),
+    }
+  }
+
+  /// The instruction rendered from the structured fields only.
+  pub fn render_structured(&self, dosage: &Dosage) -> String {
+    let verb = dosage
+      .method()
+      .and_then(|m| {
+        concept_codes(&m)
+          .iter()
+          .find_map(|code| self.lookup(&format!("method.{}", code)))
+          .map(|v| v.to_string())
+      })
+      .unwrap_or_else(|| self.say("verb", &[]));
+    let mut parts = vec![verb];
+    let dose_and_rate = dosage.dose_and_rate().unwrap_or_default();
+    if let Some(first) = dose_and_rate.first() {
+      if let Some(dose) = first.dose_quantity() {
+        parts.push(self.quantity(&dose));
+      } else if let Some(dose) = first.dose_range() {
+        parts.push(self.range(&dose));
+      }
+    }
+    if let Some(route) = dosage.route() {
+      let phrase = concept_codes(&route)
+        .iter()
+        .find_map(|code| self.lookup(&format!("route.{}", code)))
+        .map(|p| p.to_string());
+      parts.push(phrase.unwrap_or_else(|| self.say("route", &[&lowercase(&concept_text(&route))])));
+    }
+    if let Some(site) = dosage.site() {
+      parts.push(self.say("site", &[&lowercase(&concept_text(&site))]));
+    }
+    if let Some(timing) = dosage.timing() {
+      parts.extend(self.timing(&timing));
+    }
+    if let Some(first) = dose_and_rate.first() {
+      let rate = if let Some(rate) = first.rate_quantity() {
+        Some(self.quantity(&rate))
+      } else if let Some(rate) = first.rate_range() {
+        Some(self.range(&rate))
+      } else {
+        first.rate_ratio().and_then(|ratio| {
+          let numerator = self.quantity(&ratio.numerator()?);
+          let denominator = ratio.denominator()?;
+          let per = match denominator.value() {
+            Some(value) if value != 1.0 => self.quantity(&denominator),
+            _ => denominator
+              .unit()
+              .or_else(|| denominator.code())
+              .unwrap_or_default()
+              .to_string(),
+          };
+          Some(self.say("ratio", &[&numerator, &per]))
+        })
+      };
+      parts.extend(rate.map(|rate| self.say("rate", &[&rate])));
+    }
+    let mut sentence = parts
+      .into_iter()
+      .filter(|p| !p.is_empty())
+      .collect::<Vec<_>>()
+      .join(" ");
+
+    let as_needed = match dosage.as_needed_codeable_concept() {
+      Some(reason) => Some(self.say("as_needed_for", &[&lowercase(&concept_text(&reason))])),
+      None if dosage.as_needed_boolean() == Some(true) => Some(self.say("as_needed", &[])),
+      None => None,
+    };
+    if let Some(as_needed) = as_needed {
+      sentence = self.say("clause", &[&sentence, &as_needed]);
+    }
+    if let Some(max) = dosage.max_dose_per_period() {
+      if let (Some(numerator), Some(denominator)) = (max.numerator(), max.denominator()) {
+        let value = denominator.value().unwrap_or(1.0);
+        let code = denominator
+          .code()
+          .or_else(|| denominator.unit())
+          .unwrap_or_default();
+        let per = if value == 1.0 {
+          self.time_unit(code, 1.0)
+        } else {
+          format!("{} {}", number(value), self.time_unit(code, value))
+        };
+        let limit = self.say("ratio", &[&self.quantity(&numerator), &per]);
+        sentence = self.say("clause", &[&sentence, &self.say("max", &[&limit])]);
+      }
+    }
+    for instruction in dosage.additional_instruction().unwrap_or_default() {
+      sentence = self.say("sentence", &[&sentence, &concept_text(&instruction)]);
+    }
+    capitalise(&sentence)
+  }
+}
+
+/// The structured field a `DosageWarning` is about.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DosageField {
+  Dose,
+  Frequency,
+  AsNeeded,
+  Duration,
+}
+
+/// A place where `Dosage.text` appears to disagree with the structured
+/// fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DosageWarning {
+  pub field: DosageField,
+  pub message: String,
+}
+
+impl fmt::Display for DosageWarning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:?}: {}", self.field, self.message)
+  }
+}
+
+const NUMBER_WORDS: &[(&str, f64)] = &[
+  ("half", 0.5),
+  ("one", 1.0),
+  ("two", 2.0),
+  ("three", 3.0),
+  ("four", 4.0),
+  ("five", 5.0),
+  ("six", 6.0),
+  ("seven", 7.0),
+  ("eight", 8.0),
+  ("nine", 9.0),
+  ("ten", 10.0),
+  ("twelve", 12.0),
+];
+
+/// The words of the text, lowercased, with numbers split from units and
+/// ranges ("4-6", "5mg") split into their parts.
+fn words(text: &str) -> Vec<String> {
+  let mut words = vec![];
+  let mut current = String::new();
+  let mut numeric = false;
+  for c in text.to_lowercase().chars() {
+    let digit = c.is_ascii_digit() || (c == '.' && numeric && !current.is_empty());
+    if c.is_alphanumeric() || c == '.' {
+      if !current.is_empty() && digit != numeric {
+        words.push(std::mem::take(&mut current));
+      }
+      numeric = digit;
+      current.push(c);
+    } else if !current.is_empty() {
+      words.push(std::mem::take(&mut current));
+    }
+  }
+  if !current.is_empty() {
+    words.push(current);
+  }
+  words
+    .into_iter()
+    .map(|w| w.trim_end_matches('.').to_string())
+    .filter(|w| !w.is_empty())
+    .collect()
+}
+
+fn word_value(word: &str) -> Option<f64> {
+  word.parse::<f64>().ok().or_else(|| {
+    NUMBER_WORDS
+      .iter()
+      .find(|(name, _)| *name == word)
+      .map(|(_, value)| *value)
+  })
+}
+
+fn unit_days(unit: &str) -> Option<f64> {
+  match unit.trim_end_matches('s') {
+    "s" | "sec" | "second" => Some(1.0 / 86_400.0),
+    "min" | "minute" => Some(1.0 / 1440.0),
+    "h" | "hr" | "hour" => Some(1.0 / 24.0),
+    "d" | "day" => Some(1.0),
+    "wk" | "week" => Some(7.0),
+    "mo" | "month" => Some(30.0),
+    "a" | "year" => Some(365.0),
+    _ => None,
+  }
+}
+
+/// Doses per day stated by an English instruction, if it states one.
+fn text_doses_per_day(words: &[String]) -> Option<f64> {
+  let find = |word: &str| words.iter().position(|w| w == word);
+  // "every 4 hours", "every 4-6 hours", "every other day"
+  if let Some(i) = find("every") {
+    if words.get(i + 1).map(|w| w == "other").unwrap_or(false) {
+      return Some(0.5);
+    }
+    let count = words.get(i + 1).and_then(|w| word_value(w));
+    let unit_at = if count.is_some() { i + 2 } else { i + 1 };
+    let unit = words
+      .iter()
+      .skip(unit_at)
+      .take(3)
+      .find_map(|w| unit_days(w));
+    if let Some(unit) = unit {
+      return Some(1.0 / (count.unwrap_or(1.0) * unit));
+    }
+  }
+  for word in words {
+    // "q4h", "q6h"
+    if let Some(hours) = word
+      .strip_prefix('q')
+      .and_then(|w| w.strip_suffix('h'))
+      .and_then(|w| w.parse::<f64>().ok())
+    {
+      return Some(24.0 / hours);
+    }
+    let per_day = match word.as_str() {
+      "qd" | "od" => 1.0,
+      "bid" | "bd" => 2.0,
+      "tid" | "tds" => 3.0,
+      "qid" | "qds" => 4.0,
+      "hourly" => 24.0,
+      _ => continue,
+    };
+    return Some(per_day);
+  }
+  let times = if find("once").is_some() {
+    Some(1.0)
+  } else if find("twice").is_some() {
+    Some(2.0)
+  } else {
+    find("times").and_then(|i| words.get(i.checked_sub(1)?).and_then(|w| word_value(w)))
+  };
+  let weekly = ["weekly", "week", "wk"].iter().any(|w| find(w).is_some());
+  let daily = ["daily", "day", "nightly", "morning", "evening", "bedtime"]
+    .iter()
+    .any(|w| find(w).is_some());
+  match (times, weekly, daily) {
+    (Some(times), true, _) => Some(times / 7.0),
+    (Some(times), false, _) => Some(times),
+    (None, true, _) => Some(1.0 / 7.0),
+    (None, false, true) => Some(1.0),
+    _ => None,
+  }
+}
+
+fn structured_doses_per_day(dosage: &Dosage) -> Option<f64> {
+  let timing = dosage.timing()?;
+  let repeat = match timing.repeat() {
+    Some(repeat) => repeat.value.into_owned(),
+    None => concept_codes(&timing.code()?)
+      .iter()
+      .find_map(|code| abbreviation(code))?,
+  };
+  let repeat = Timing_Repeat::new(&repeat);
+  let unit = unit_days(&repeat.period_unit()?.to_string())?;
+  let frequency = repeat.frequency().unwrap_or(1) as f64;
+  Some(frequency / (repeat.period().unwrap_or(1.0) * unit))
+}
+
+fn close(a: f64, b: f64) -> bool {
+  (a - b).abs() <= 1e-9 * a.abs().max(b.abs())
+}
+
+/// Compares `Dosage.text` with the structured fields and reports apparent
+/// disagreements: a dose amount that the text does not mention, a different
+/// number of doses per day, a different as-needed status or a different
+/// treatment length. The text is read as English.
+pub fn check(dosage: &Dosage) -> Vec<DosageWarning> {
+  let text = match dosage.text() {
+    Some(text) => text,
+    None => return vec![],
+  };
+  let words = words(text);
+  let numbers: Vec<f64> = words.iter().filter_map(|w| word_value(w)).collect();
+  let mut warnings = vec![];
+
+  let mut doses = vec![];
+  for dose_and_rate in dosage.dose_and_rate().unwrap_or_default() {
+    if let Some(dose) = dose_and_rate.dose_quantity() {
+      doses.extend(dose.value());
+    } else if let Some(range) = dose_and_rate.dose_range() {
+      doses.extend(range.low().and_then(|q| q.value()));
+      doses.extend(range.high().and_then(|q| q.value()));
+    }
+  }
+  for dose in doses {
+    if !numbers.iter().any(|n| close(*n, dose)) {
+      warnings.push(DosageWarning {
+        field: DosageField::Dose,
+        message: format!("The text does not mention the dose of {}", number(dose)),
+      });
+    }
+  }
+
+  if let (Some(stated), Some(structured)) =
+    (text_doses_per_day(&words), structured_doses_per_day(dosage))
+  {
+    if !close(stated, structured) {
+      warnings.push(DosageWarning {
+        field: DosageField::Frequency,
+        message: format!(
+          "The text gives {} doses per day but the timing gives {}",
+          number(stated),
+          number(structured)
+        ),
+      });
+    }
+  }
+
+  let text_as_needed = words.iter().any(|w| w == "prn")
+    || words
+      .windows(2)
+      .any(|pair| pair[0] == "as" && (pair[1] == "needed" || pair[1] == "required"));
+  let as_needed =
+    dosage.as_needed_boolean() == Some(true) || dosage.as_needed_codeable_concept().is_some();
+  if text_as_needed != as_needed {
+    warnings.push(DosageWarning {
+      field: DosageField::AsNeeded,
+      message: if as_needed {
+        "The dose is as needed but the text does not say so".to_string()
+      } else {
+        "The text says as needed but the dose is not".to_string()
+      },
+    });
+  }
+
+  let timing = dosage.timing();
+  let repeat = timing.as_ref().and_then(|t| t.repeat());
+  if let Some(bounds) = repeat.as_ref().and_then(|r| r.bounds_duration()) {
+    let structured = bounds
+      .value()
+      .zip(bounds.code().or_else(|| bounds.unit()).and_then(unit_days));
+    let stated = words.windows(3).find_map(|w| {
+      if w[0] != "for" {
+        return None;
+      }
+      Some(word_value(&w[1])? * unit_days(&w[2])?)
+    });
+    if let (Some((value, unit)), Some(stated)) = (structured, stated) {
+      if !close(value * unit, stated) {
+        warnings.push(DosageWarning {
+          field: DosageField::Duration,
+          message: format!(
+            "The text gives a treatment length of {} days but the timing gives {}",
+            number(stated),
+            number(value * unit)
+          ),
+        });
+      }
+    }
+  }
+  warnings
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::MedicationRequest::MedicationRequest;
+  use serde_json::{json, Value};
+  use std::fs;
+
+  fn dosages(file: &str) -> Vec<Value> {
+    let contents = fs::read_to_string(format!("examples-json/{}", file)).unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let request = MedicationRequest::new(&value);
+    request
+      .dosage_instruction()
+      .unwrap()
+      .iter()
+      .map(|d| d.to_json())
+      .collect()
+  }
+
+  #[test]
+  fn test_render_dosage() {
+    let renderer = DosageRenderer::new();
+    let value = json!({
+      "doseAndRate": [{"doseQuantity": {"value": 2, "unit": "TAB", "code": "TAB"}}],
+      "route": {"coding": [{"system": "http://snomed.info/sct", "code": "26643006"}]},
+      "timing": {"repeat": {
+        "frequency": 2, "period": 1, "periodUnit": "d",
+        "boundsDuration": {"value": 7, "unit": "days", "code": "d"}
+      }},
+      "asNeededCodeableConcept": {"text": "pain"}
+    });
+    assert_eq!(
+      renderer.render(&Dosage::new(&value)),
+      "Take 2 tablets by mouth twice daily for 7 days, as needed for pain"
+    );
+
+    let rib_pain = &dosages("medicationrequest0301.json")[0];
+    let dosage = Dosage::new(rib_pain);
+    assert_eq!(
+      renderer.render_structured(&dosage),
+      "Swallow 1 to 2 tablets by mouth every 4 to 6 hours, as needed for rib pain. \
+       Warning. May cause drowsiness. If affected do not drive or operate machinery. \
+       Avoid alcoholic drink"
+    );
+
+    let text_only = json!({"text": "Use as directed"});
+    assert_eq!(renderer.render(&Dosage::new(&text_only)), "Use as directed");
+
+    let mut french = DosageRenderer::new();
+    french
+      .phrase("verb", "Prendre")
+      .phrase("form.TAB.other", "comprimés")
+      .phrase("route.26643006", "par voie orale")
+      .phrase("times.2", "deux fois")
+      .phrase("per.d", "par jour")
+      .phrase("for", "pendant {0} {1}")
+      .phrase("unit.d.other", "jours")
+      .phrase("clause", "{0}, {1}")
+      .phrase("as_needed_for", "si besoin pour {0}");
+    assert_eq!(
+      french.render(&Dosage::new(&value)),
+      "Prendre 2 comprimés par voie orale deux fois par jour pendant 7 jours, si besoin pour pain"
+    );
+
+    let daily = json!({"timing": {"repeat": {"frequency": 1, "period": 1, "periodUnit": "d"}}});
+    assert!(renderer.render(&Dosage::new(&daily)).contains("once daily"));
+    french.phrase("once.per.d", "une fois par jour");
+    assert!(french
+      .render(&Dosage::new(&daily))
+      .contains("une fois par jour"));
+  }
+
+  #[test]
+  fn test_check_dosage_text() {
+    let rib_pain = &dosages("medicationrequest0301.json")[0];
+    assert_eq!(check(&Dosage::new(rib_pain)), vec![]);
+
+    let tapering = &dosages("medicationrequest0302.json")[1];
+    let warnings = check(&Dosage::new(tapering));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].field, DosageField::Frequency);
+
+    let value = json!({
+      "text": "2 tablets twice daily for 10 days",
+      "doseAndRate": [{"doseQuantity": {"value": 1, "code": "TAB"}}],
+      "timing": {"repeat": {
+        "frequency": 2, "period": 1, "periodUnit": "d",
+        "boundsDuration": {"value": 7, "code": "d"}
+      }},
+      "asNeededBoolean": true
+    });
+    let fields: Vec<DosageField> = check(&Dosage::new(&value))
+      .into_iter()
+      .map(|w| w.field)
+      .collect();
+    assert_eq!(
+      fields,
+      vec![
+        DosageField::Dose,
+        DosageField::AsNeeded,
+        DosageField::Duration
+      ]
+    );
+  }
+}
@@ -0,0 +1,278 @@
+//! Generates `text.div` for any resource.
+//!
+//! Without a template, a resource is rendered as a table of its top-level
+//! elements. Each value is formatted by the type the model gives its element
+//! (see `elements`), with the datatype renderers in `render` for the common
+//! datatypes and element by element for the others.
+
+use super::render::{self, escape};
+use crate::elements;
+use crate::model::Address::Address;
+use crate::model::Attachment::Attachment;
+use crate::model::CodeableConcept::CodeableConcept;
+use crate::model::Coding::Coding;
+use crate::model::ContactPoint::ContactPoint;
+use crate::model::HumanName::HumanName;
+use crate::model::Identifier::Identifier;
+use crate::model::Narrative::{Narrative, NarrativeBuilder, NarrativeStatus};
+use crate::model::Period::Period;
+use crate::model::Quantity::Quantity;
+use crate::model::Reference::Reference;
+use crate::model::ResourceList::ResourceList;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+pub const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// Elements that are never rendered by the default template.
+const SKIPPED_ELEMENTS: &[&str] = &[
+  "resourceType",
+  "id",
+  "meta",
+  "implicitRules",
+  "language",
+  "text",
+  "contained",
+  "extension",
+  "modifierExtension",
+];
+
+/// Nested objects without a datatype renderer are rendered inline up to this
+/// depth.
+const MAX_DEPTH: usize = 3;
+
+/// Renders the content of the narrative `div` (without the `div` itself) for
+/// a resource. The returned markup must already be escaped.
+pub type Template = Box<dyn Fn(&ResourceList) -> String>;
+
+#[derive(Default)]
+pub struct NarrativeGenerator {
+  templates: HashMap<String, Template>,
+}
+
+impl NarrativeGenerator {
+  pub fn new() -> NarrativeGenerator {
+    NarrativeGenerator {
+      templates: HashMap::new(),
+    }
+  }
+
+  /// Overrides the rendering of one resource type.
+  pub fn template<F>(&mut self, resource_type: &str, template: F) -> &mut NarrativeGenerator
+  where
+    F: Fn(&ResourceList) -> String + 'static,
+  {
+    self
+      .templates
+      .insert(resource_type.to_string(), Box::new(template));
+    self
+  }
+
+  /// Renders the complete `div`, including the xhtml namespace.
+  pub fn render(&self, resource: &ResourceList) -> String {
+    let resource_type = resource_type(resource);
+    let content = match self.templates.get(resource_type) {
+      Some(template) => template(resource),
+      None => default_template(resource),
+    };
+    format!("<div xmlns=\"{}\">{}</div>", XHTML_NAMESPACE, content)
+  }
+
+  pub fn narrative(&self, resource: &ResourceList) -> Narrative<'static> {
+    let mut builder = NarrativeBuilder::new(&self.render(resource));
+    builder.status(NarrativeStatus::Generated);
+    Narrative {
+      value: Cow::Owned(builder.build().to_json()),
+    }
+  }
+
+  /// Returns a copy of `resource` with `text` replaced by a generated
+  /// narrative.
+  pub fn apply(&self, resource: &ResourceList) -> ResourceList<'static> {
+    let mut value = resource.to_json();
+    value["text"] = self.narrative(resource).to_json();
+    ResourceList {
+      value: Cow::Owned(value),
+    }
+  }
+}
+
+fn resource_type<'a>(resource: &'a ResourceList) -> &'a str {
+  resource
+    .value
+    .get("resourceType")
+    .and_then(|t| t.as_str())
+    .unwrap_or_default()
+}
+
+/// A heading with the resource type and id, followed by a table of the
+/// populated top-level elements.
+pub fn default_template(resource: &ResourceList) -> String {
+  let mut html = format!("<p><b>{}</b>", escape(resource_type(resource)));
+  if let Some(id) = resource.value.get("id").and_then(|id| id.as_str()) {
+    html.push_str(&format!(" {}", escape(id)));
+  }
+  html.push_str("</p>");
+
+  let mut rows = String::new();
+  if let Value::Object(map) = &*resource.value {
+    for (key, value) in map {
+      if key.starts_with('_') || SKIPPED_ELEMENTS.contains(&key.as_str()) {
+        continue;
+      }
+      let type_name = element_type(resource_type(resource), key);
+      let rendered = render_value(type_name, value, 0);
+      if rendered.is_empty() {
+        continue;
+      }
+      rows.push_str(&format!(
+        "<tr><th>{}</th><td>{}</td></tr>",
+        escape(key),
+        rendered
+      ));
+    }
+  }
+  if !rows.is_empty() {
+    html.push_str(&format!("<table>{}</table>", rows));
+  }
+  html
+}
+
+fn element_type(parent: &str, key: &str) -> &'static str {
+  elements::element(parent, key)
+    .map(|element| element.type_name)
+    .unwrap_or_default()
+}
+
+/// Renders a value of a type, such as `CodeableConcept` or `Patient_Contact`,
+/// as escaped XHTML text.
+pub fn render_element(type_name: &str, value: &Value) -> String {
+  render_value(type_name, value, 0)
+}
+
+fn render_value(type_name: &str, value: &Value, depth: usize) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::Bool(b) => b.to_string(),
+    Value::Number(n) => n.to_string(),
+    Value::String(s) => escape(s),
+    Value::Array(values) => values
+      .iter()
+      .map(|v| render_value(type_name, v, depth))
+      .filter(|s| !s.is_empty())
+      .collect::<Vec<_>>()
+      .join(", "),
+    Value::Object(_) => render_object(type_name, value, depth),
+  }
+}
+
+fn render_object(type_name: &str, value: &Value, depth: usize) -> String {
+  let text = match type_name {
+    "HumanName" => render::human_name(&HumanName::new(value)),
+    "Address" => render::address(&Address::new(value)),
+    "CodeableConcept" => render::codeable_concept(&CodeableConcept::new(value)),
+    "Coding" => render::coding(&Coding::new(value)),
+    "Quantity" | "Age" | "Count" | "Distance" | "Duration" => {
+      render::quantity(&Quantity::new(value))
+    }
+    "Period" => render::period(&Period::new(value)),
+    "Reference" => render::reference(&Reference::new(value)),
+    "Identifier" => render::identifier(&Identifier::new(value)),
+    "ContactPoint" => render::contact_point(&ContactPoint::new(value)),
+    "Attachment" => Attachment::new(value)
+      .title()
+      .unwrap_or_default()
+      .to_string(),
+    _ => return render_generic(type_name, value, depth),
+  };
+  escape(&text)
+}
+
+/// The elements of an object, each as `name: value`. A contained resource
+/// is typed by its `resourceType`.
+fn render_generic(type_name: &str, value: &Value, depth: usize) -> String {
+  if depth >= MAX_DEPTH {
+    return String::new();
+  }
+  let map = match value {
+    Value::Object(map) => map,
+    _ => return render_value(type_name, value, depth),
+  };
+  let type_name = value["resourceType"].as_str().unwrap_or(type_name);
+  map
+    .iter()
+    .filter(|(key, _)| !key.starts_with('_') && !SKIPPED_ELEMENTS.contains(&key.as_str()))
+    .map(|(key, child)| {
+      let rendered = render_value(element_type(type_name, key), child, depth + 1);
+      (key, rendered)
+    })
+    .filter(|(_, rendered)| !rendered.is_empty())
+    .map(|(key, rendered)| format!("{}: {}", escape(key), rendered))
+    .collect::<Vec<_>>()
+    .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::fs;
+
+  #[test]
+  fn test_generate_patient_narrative() {
+    let contents = fs::read_to_string("examples-json/patient-example.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let patient = ResourceList::new(&value);
+
+    let generator = NarrativeGenerator::new();
+    let div = generator.render(&patient);
+    assert!(
+      div.starts_with("<div xmlns=\"http://www.w3.org/1999/xhtml\"><p><b>Patient</b> example</p>")
+    );
+    assert!(
+      div.contains("<tr><th>name</th><td>Peter James Chalmers, Jim, Peter James Windsor</td></tr>")
+    );
+    assert!(div.contains("<tr><th>birthDate</th><td>1974-12-25</td></tr>"));
+    assert!(div.contains("534 Erewhon St PeasantVille, Rainbow, Vic  3999"));
+    assert!(div.contains("<tr><th>managingOrganization</th><td>Organization/1</td></tr>"));
+    assert!(div.contains(
+      "<tr><th>telecom</th><td>phone: (03) 5555 6473 (work), phone: (03) 3410 5613 (mobile), \
+       phone: (03) 5555 8834 (old)</td></tr>"
+    ));
+    assert!(div.contains("period: from 2012; relationship: N; telecom: phone: +33 (237) 998327"));
+
+    let updated = generator.apply(&patient);
+    assert!(updated.validate());
+    assert_eq!(updated.to_json()["text"]["status"], "generated");
+    assert_eq!(updated.to_json()["name"], value["name"]);
+  }
+
+  #[test]
+  fn test_template_override() {
+    let value = serde_json::json!({
+      "resourceType": "Observation",
+      "status": "final",
+      "code": {"coding": [{"system": "http://loinc.org", "code": "29463-7", "display": "Body weight"}]},
+      "valueQuantity": {"value": 72.5, "unit": "kg", "system": "http://unitsofmeasure.org", "code": "kg"},
+      "effectivePeriod": {"start": "2020-01-01"}
+    });
+    let observation = ResourceList::new(&value);
+
+    let mut generator = NarrativeGenerator::new();
+    let div = generator.render(&observation);
+    assert!(div.contains("<tr><th>code</th><td>Body weight</td></tr>"));
+    assert!(div.contains("<tr><th>valueQuantity</th><td>72.5 kg</td></tr>"));
+    assert!(div.contains("<tr><th>effectivePeriod</th><td>from 2020-01-01</td></tr>"));
+
+    generator.template("Observation", |resource| {
+      format!(
+        "<p>{} &amp; more</p>",
+        render_element("CodeableConcept", &resource.to_json()["code"])
+      )
+    });
+    assert_eq!(
+      generator.render(&observation),
+      "<div xmlns=\"http://www.w3.org/1999/xhtml\"><p>Body weight &amp; more</p></div>"
+    );
+  }
+}
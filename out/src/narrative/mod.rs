@@ -0,0 +1,13 @@
+//! Human-readable narratives (`Resource.text`).
+
+pub mod dosage;
+pub mod generator;
+pub mod render;
+pub mod validation;
+pub mod xhtml;
+
+pub use dosage::{DosageField, DosageRenderer, DosageWarning};
+pub use generator::{NarrativeGenerator, Template};
+pub use validation::{
+  sanitize_div, sanitize_resource, validate_div, validate_resource, XhtmlIssue, XhtmlIssueKind,
+};
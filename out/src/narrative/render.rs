@@ -0,0 +1,137 @@
+//! Plain-text renderings of the common datatypes, used when building
+//! narratives. The results are not escaped; use `escape` before embedding
+//! them in XHTML.
+
+use crate::model::Address::Address;
+use crate::model::CodeableConcept::CodeableConcept;
+use crate::model::Coding::Coding;
+use crate::model::ContactPoint::ContactPoint;
+use crate::model::HumanName::HumanName;
+use crate::model::Identifier::Identifier;
+use crate::model::Period::Period;
+use crate::model::Quantity::Quantity;
+use crate::model::Reference::Reference;
+
+/// Escapes text for use in XHTML element content and attribute values.
+pub fn escape(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#39;"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+fn join(parts: Vec<&str>, separator: &str) -> String {
+  parts
+    .into_iter()
+    .filter(|p| !p.is_empty())
+    .collect::<Vec<_>>()
+    .join(separator)
+}
+
+/// `text` if present, otherwise the parts with the given names first; see
+/// `HumanName::format`.
+pub fn human_name(name: &HumanName) -> String {
+  name.format()
+}
+
+/// `text` if present, otherwise the lines of `Address::format_lines` on one
+/// line.
+pub fn address(address: &Address) -> String {
+  match address.text() {
+    Some(text) => text.to_string(),
+    None => address.format_lines().join(", "),
+  }
+}
+
+pub fn coding(coding: &Coding) -> String {
+  coding
+    .display()
+    .or_else(|| coding.code())
+    .unwrap_or_default()
+    .to_string()
+}
+
+/// `text` if present, otherwise the first coding with a display, otherwise
+/// the first code.
+pub fn codeable_concept(concept: &CodeableConcept) -> String {
+  if let Some(text) = concept.text() {
+    return text.to_string();
+  }
+  let codings = concept.coding().unwrap_or_default();
+  if let Some(display) = codings.iter().find_map(|c| c.display()) {
+    return display.to_string();
+  }
+  codings
+    .iter()
+    .find_map(|c| c.code())
+    .unwrap_or_default()
+    .to_string()
+}
+
+/// The comparator, value and unit, e.g. `<= 5 mg`. Falls back to the unit
+/// code when there is no human readable unit.
+pub fn quantity(quantity: &Quantity) -> String {
+  let value = quantity.value().map(|v| v.to_string()).unwrap_or_default();
+  let comparator = quantity
+    .comparator()
+    .map(|c| c.to_string())
+    .unwrap_or_default();
+  let unit = quantity
+    .unit()
+    .or_else(|| quantity.code())
+    .unwrap_or_default();
+  join(vec![&comparator, &value, unit], " ")
+}
+
+pub fn period(period: &Period) -> String {
+  match (period.start(), period.end()) {
+    (Some(start), Some(end)) => format!("{} to {}", start, end),
+    (Some(start), None) => format!("from {}", start),
+    (None, Some(end)) => format!("until {}", end),
+    (None, None) => String::new(),
+  }
+}
+
+/// The display, falling back to the literal reference and then to the
+/// logical identifier.
+pub fn reference(reference: &Reference) -> String {
+  if let Some(display) = reference.display() {
+    return display.to_string();
+  }
+  if let Some(literal) = reference.reference() {
+    return literal.to_string();
+  }
+  reference
+    .identifier()
+    .map(|i| identifier(&i))
+    .unwrap_or_default()
+}
+
+pub fn identifier(identifier: &Identifier) -> String {
+  identifier.value().unwrap_or_default().to_string()
+}
+
+/// The value after its system and followed by its use, e.g.
+/// `phone: (03) 5555 6473 (work)`.
+pub fn contact_point(contact_point: &ContactPoint) -> String {
+  let value = match contact_point.value() {
+    Some(value) => value,
+    None => return String::new(),
+  };
+  let mut text = match contact_point.system() {
+    Some(system) => format!("{}: {}", system.to_string(), value),
+    None => value.to_string(),
+  };
+  if let Some(fhir_use) = contact_point.fhir_use() {
+    text.push_str(&format!(" ({})", fhir_use.to_string()));
+  }
+  text
+}
@@ -0,0 +1,541 @@
+//! Checks narrative XHTML against the FHIR rules (the restricted element and
+//! attribute set, no scripts or event handlers, no active content) and
+//! repairs narratives that break them.
+//!
+//! Every `text.div` in a resource is checked, including those of contained
+//! resources and resources nested in elements such as `Bundle.entry`.
+
+use super::generator::XHTML_NAMESPACE;
+use super::render::escape;
+use super::xhtml::{self, Element, Node};
+use crate::model::Narrative::{Narrative, NarrativeBuilder};
+use crate::model::ResourceList::ResourceList;
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// The elements the FHIR specification allows in a narrative.
+pub const ALLOWED_ELEMENTS: &[&str] = &[
+  "a",
+  "abbr",
+  "acronym",
+  "b",
+  "big",
+  "blockquote",
+  "br",
+  "caption",
+  "cite",
+  "code",
+  "col",
+  "colgroup",
+  "dd",
+  "dfn",
+  "div",
+  "dl",
+  "dt",
+  "em",
+  "h1",
+  "h2",
+  "h3",
+  "h4",
+  "h5",
+  "h6",
+  "hr",
+  "i",
+  "img",
+  "li",
+  "ol",
+  "p",
+  "pre",
+  "q",
+  "samp",
+  "small",
+  "span",
+  "strong",
+  "sub",
+  "sup",
+  "table",
+  "tbody",
+  "td",
+  "tfoot",
+  "th",
+  "thead",
+  "tr",
+  "tt",
+  "ul",
+  "var",
+];
+
+/// The attributes the FHIR specification allows in a narrative.
+pub const ALLOWED_ATTRIBUTES: &[&str] = &[
+  "abbr",
+  "accesskey",
+  "align",
+  "alt",
+  "axis",
+  "bgcolor",
+  "border",
+  "cellhalign",
+  "cellpadding",
+  "cellspacing",
+  "cellvalign",
+  "char",
+  "charoff",
+  "charset",
+  "cite",
+  "class",
+  "colspan",
+  "compact",
+  "coords",
+  "dir",
+  "frame",
+  "headers",
+  "height",
+  "href",
+  "hreflang",
+  "hspace",
+  "id",
+  "lang",
+  "longdesc",
+  "name",
+  "nowrap",
+  "rel",
+  "rev",
+  "rowspan",
+  "rules",
+  "scope",
+  "shape",
+  "span",
+  "src",
+  "start",
+  "style",
+  "summary",
+  "tabindex",
+  "title",
+  "type",
+  "valign",
+  "value",
+  "vspace",
+  "width",
+  "xmlns",
+  "xml:lang",
+];
+
+/// Elements whose content is dropped along with them when sanitizing, since
+/// it is not meant to be read as text.
+const DROPPED_ELEMENTS: &[&str] = &[
+  "script", "style", "iframe", "frame", "frameset", "object", "embed", "applet", "head", "title",
+  "meta", "link", "base", "form", "input", "button", "select", "textarea", "svg", "math",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum XhtmlIssueKind {
+  /// The div is not well-formed XML.
+  Malformed,
+  /// The root element is not `div`.
+  WrongRoot,
+  /// The root `div` is not in the XHTML namespace.
+  MissingNamespace,
+  DisallowedElement,
+  DisallowedAttribute,
+  /// An `on*` event handler attribute.
+  EventAttribute,
+  /// A `javascript:` style URL, or a url() in a style attribute.
+  UnsafeReference,
+  /// The div has no text content and no image (txt-2).
+  Empty,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct XhtmlIssue {
+  pub kind: XhtmlIssueKind,
+  /// Where the div is, e.g. `Patient.contained[0].text.div`.
+  pub path: String,
+  pub message: String,
+}
+
+/// Validates a single narrative div.
+pub fn validate_div(div: &str) -> Vec<XhtmlIssue> {
+  let mut issues = vec![];
+  check_div(div, "div", &mut issues);
+  issues
+}
+
+/// Validates every narrative in a resource.
+pub fn validate_resource(resource: &ResourceList) -> Vec<XhtmlIssue> {
+  let mut issues = vec![];
+  let resource_type = resource
+    .value
+    .get("resourceType")
+    .and_then(|t| t.as_str())
+    .unwrap_or("Resource");
+  visit(&resource.value, resource_type, &mut |div, path| {
+    check_div(div, path, &mut issues)
+  });
+  issues
+}
+
+fn visit<F>(value: &Value, path: &str, f: &mut F)
+where
+  F: FnMut(&str, &str),
+{
+  match value {
+    Value::Object(map) => {
+      for (key, child) in map {
+        let child_path = format!("{}.{}", path, key);
+        if key == "text" {
+          if let Some(div) = child.get("div").and_then(|d| d.as_str()) {
+            f(div, &format!("{}.div", child_path));
+            continue;
+          }
+        }
+        visit(child, &child_path, f);
+      }
+    }
+    Value::Array(values) => {
+      for (index, child) in values.iter().enumerate() {
+        visit(child, &format!("{}[{}]", path, index), f);
+      }
+    }
+    _ => {}
+  }
+}
+
+fn check_div(div: &str, path: &str, issues: &mut Vec<XhtmlIssue>) {
+  let mut issue = |kind: XhtmlIssueKind, message: String| {
+    issues.push(XhtmlIssue {
+      kind,
+      path: path.to_string(),
+      message,
+    })
+  };
+
+  let root = match xhtml::parse(div) {
+    Ok(root) => root,
+    Err(error) => {
+      issue(XhtmlIssueKind::Malformed, error.to_string());
+      return;
+    }
+  };
+  if root.local_name() != "div" {
+    issue(
+      XhtmlIssueKind::WrongRoot,
+      format!("The root element is '{}', not 'div'", root.name),
+    );
+  }
+  if root.namespace(None) != Some(XHTML_NAMESPACE) {
+    issue(
+      XhtmlIssueKind::MissingNamespace,
+      format!(
+        "The root element is not in the {} namespace",
+        XHTML_NAMESPACE
+      ),
+    );
+  }
+  if !has_content(&root) {
+    issue(
+      XhtmlIssueKind::Empty,
+      "The narrative has no text or image content".to_string(),
+    );
+  }
+  check_element(&root, &mut issue);
+}
+
+fn check_element<F>(element: &Element, issue: &mut F)
+where
+  F: FnMut(XhtmlIssueKind, String),
+{
+  let name = element.local_name();
+  if !ALLOWED_ELEMENTS.contains(&name) {
+    issue(
+      XhtmlIssueKind::DisallowedElement,
+      format!("Element '{}' is not allowed in a narrative", element.name),
+    );
+  }
+  for (attribute, value) in &element.attributes {
+    if let Some((kind, message)) = check_attribute(name, attribute, value) {
+      issue(kind, message);
+    }
+  }
+  for child in &element.children {
+    if let Node::Element(child) = child {
+      check_element(child, issue);
+    }
+  }
+}
+
+fn check_attribute(
+  element: &str,
+  attribute: &str,
+  value: &str,
+) -> Option<(XhtmlIssueKind, String)> {
+  let lower = attribute.to_ascii_lowercase();
+  if lower.starts_with("on") {
+    return Some((
+      XhtmlIssueKind::EventAttribute,
+      format!(
+        "Event attribute '{}' on '{}' is not allowed",
+        attribute, element
+      ),
+    ));
+  }
+  if !ALLOWED_ATTRIBUTES.contains(&attribute) && !attribute.starts_with("xmlns:") {
+    return Some((
+      XhtmlIssueKind::DisallowedAttribute,
+      format!("Attribute '{}' on '{}' is not allowed", attribute, element),
+    ));
+  }
+  if attribute == "src" && element != "img" {
+    return Some((
+      XhtmlIssueKind::DisallowedAttribute,
+      format!(
+        "Attribute 'src' is only allowed on 'img', not '{}'",
+        element
+      ),
+    ));
+  }
+  if (attribute == "href" || attribute == "src") && !is_safe_url(element, value) {
+    return Some((
+      XhtmlIssueKind::UnsafeReference,
+      format!("'{}' on '{}' has an unsafe URL", attribute, element),
+    ));
+  }
+  if attribute == "style" && value.to_ascii_lowercase().contains("url(") {
+    return Some((
+      XhtmlIssueKind::UnsafeReference,
+      format!("The style on '{}' references an external resource", element),
+    ));
+  }
+  None
+}
+
+fn is_safe_url(element: &str, url: &str) -> bool {
+  let normalized: String = url
+    .chars()
+    .filter(|c| !c.is_whitespace() && !c.is_control())
+    .collect::<String>()
+    .to_ascii_lowercase();
+  if normalized.starts_with("javascript:") || normalized.starts_with("vbscript:") {
+    return false;
+  }
+  if normalized.starts_with("data:") {
+    return element == "img" && normalized.starts_with("data:image/");
+  }
+  true
+}
+
+fn has_content(element: &Element) -> bool {
+  element.children.iter().any(|child| match child {
+    Node::Text(text) => !text.trim().is_empty(),
+    Node::Element(e) => e.local_name() == "img" || has_content(e),
+    Node::Comment(_) => false,
+  })
+}
+
+/// Returns a narrative div that passes `validate_div`, keeping as much of
+/// the original content as possible. Disallowed elements are unwrapped
+/// (or dropped with their content for scripts, styles and embedded
+/// objects), offending attributes are removed, and input that is not
+/// well-formed is kept as escaped text.
+pub fn sanitize_div(div: &str) -> String {
+  let root = match xhtml::parse(div) {
+    Ok(root) => root,
+    Err(_) => {
+      return format!(
+        "<div xmlns=\"{}\"><pre>{}</pre></div>",
+        XHTML_NAMESPACE,
+        escape(div)
+      )
+    }
+  };
+
+  let mut children = vec![];
+  if root.local_name() == "div" {
+    sanitize_children(&root.children, &mut children);
+  } else {
+    sanitize_node(&Node::Element(root.clone()), &mut children);
+  }
+  let mut attributes: Vec<(String, String)> =
+    vec![("xmlns".to_string(), XHTML_NAMESPACE.to_string())];
+  if root.local_name() == "div" {
+    attributes.extend(
+      sanitize_attributes(&root)
+        .into_iter()
+        .filter(|(name, _)| name != "xmlns"),
+    );
+  }
+  let mut sanitized = Element {
+    name: "div".to_string(),
+    attributes,
+    children,
+  };
+  if !has_content(&sanitized) {
+    sanitized.children = vec![Node::Text("No narrative content".to_string())];
+  }
+  sanitized.to_xml()
+}
+
+fn sanitize_children(nodes: &[Node], out: &mut Vec<Node>) {
+  for node in nodes {
+    sanitize_node(node, out);
+  }
+}
+
+fn sanitize_node(node: &Node, out: &mut Vec<Node>) {
+  let element = match node {
+    Node::Element(element) => element,
+    Node::Text(_) => {
+      out.push(node.clone());
+      return;
+    }
+    Node::Comment(_) => return,
+  };
+  let name = element.local_name().to_ascii_lowercase();
+  if DROPPED_ELEMENTS.contains(&name.as_str()) {
+    return;
+  }
+  if !ALLOWED_ELEMENTS.contains(&name.as_str()) {
+    sanitize_children(&element.children, out);
+    return;
+  }
+  let mut children = vec![];
+  sanitize_children(&element.children, &mut children);
+  out.push(Node::Element(Element {
+    name,
+    attributes: sanitize_attributes(element),
+    children,
+  }));
+}
+
+fn sanitize_attributes(element: &Element) -> Vec<(String, String)> {
+  let name = element.local_name();
+  element
+    .attributes
+    .iter()
+    .filter(|(attribute, value)| {
+      !attribute.starts_with("xmlns:") && check_attribute(name, attribute, value).is_none()
+    })
+    .cloned()
+    .collect()
+}
+
+/// The narrative with its div sanitized. The status, id and extensions are
+/// kept.
+pub fn sanitize_narrative(narrative: &Narrative) -> Narrative<'static> {
+  let mut builder = NarrativeBuilder::new(&sanitize_div(narrative.div()));
+  if let Some(status) = narrative.status() {
+    builder.status(status);
+  }
+  if let Some(status) = narrative._status() {
+    builder._status(status);
+  }
+  if let Some(id) = narrative.id() {
+    builder.id(id);
+  }
+  if let Some(extension) = narrative.extension() {
+    builder.extension(extension);
+  }
+  Narrative {
+    value: Cow::Owned(builder.build().to_json()),
+  }
+}
+
+/// Returns a copy of `resource` with every narrative div sanitized. The
+/// narrative status and other elements are kept.
+pub fn sanitize_resource(resource: &ResourceList) -> ResourceList<'static> {
+  let mut value = resource.to_json();
+  sanitize_value(&mut value);
+  ResourceList {
+    value: Cow::Owned(value),
+  }
+}
+
+fn sanitize_value(value: &mut Value) {
+  match value {
+    Value::Object(map) => {
+      for (key, child) in map.iter_mut() {
+        if key == "text" && child["div"].is_string() {
+          let sanitized = sanitize_narrative(&Narrative::new(child)).to_json();
+          *child = sanitized;
+          continue;
+        }
+        sanitize_value(child);
+      }
+    }
+    Value::Array(values) => values.iter_mut().for_each(sanitize_value),
+    _ => {}
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::fs;
+
+  #[test]
+  fn test_validate_example_narratives() {
+    for name in &["patient-example.json", "bundle-example.json"] {
+      let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+      let value: Value = serde_json::from_str(&contents).unwrap();
+      let resource = ResourceList::new(&value);
+      assert_eq!(validate_resource(&resource), vec![], "{}", name);
+    }
+  }
+
+  #[test]
+  fn test_sanitize_unsafe_narrative() {
+    let value = serde_json::json!({
+      "resourceType": "Patient",
+      "text": {
+        "id": "n1",
+        "status": "additional",
+        "div": "<div xmlns=\"http://www.w3.org/1999/xhtml\"><script>alert(1)</script><p onclick=\"steal()\">Jim &amp; <font>Jane</font></p><a href=\" javascript:alert(1)\">link</a></div>"
+      },
+      "contained": [{"resourceType": "Organization", "text": {"status": "generated", "div": "<p>unclosed"}}]
+    });
+    let patient = ResourceList::new(&value);
+
+    let kinds: Vec<(XhtmlIssueKind, String)> = validate_resource(&patient)
+      .into_iter()
+      .map(|i| (i.kind, i.path))
+      .collect();
+    assert_eq!(
+      kinds,
+      vec![
+        (
+          XhtmlIssueKind::Malformed,
+          "Patient.contained[0].text.div".to_string()
+        ),
+        (
+          XhtmlIssueKind::DisallowedElement,
+          "Patient.text.div".to_string()
+        ),
+        (
+          XhtmlIssueKind::EventAttribute,
+          "Patient.text.div".to_string()
+        ),
+        (
+          XhtmlIssueKind::DisallowedElement,
+          "Patient.text.div".to_string()
+        ),
+        (
+          XhtmlIssueKind::UnsafeReference,
+          "Patient.text.div".to_string()
+        ),
+      ]
+    );
+
+    let sanitized = sanitize_resource(&patient);
+    assert_eq!(validate_resource(&sanitized), vec![]);
+    let json = sanitized.to_json();
+    assert_eq!(json["text"]["status"], "additional");
+    assert_eq!(json["text"]["id"], "n1");
+    assert_eq!(
+      json["text"]["div"],
+      "<div xmlns=\"http://www.w3.org/1999/xhtml\"><p>Jim &amp; Jane</p><a>link</a></div>"
+    );
+    assert_eq!(
+      json["contained"][0]["text"]["div"],
+      "<div xmlns=\"http://www.w3.org/1999/xhtml\"><pre>&lt;p&gt;unclosed</pre></div>"
+    );
+  }
+}
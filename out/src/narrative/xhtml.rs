@@ -0,0 +1,339 @@
+//! A minimal XML parser and serializer for narrative XHTML.
+//!
+//! Narratives are small, namespace-light documents, so this only handles what
+//! they need: elements, attributes, text, comments, CDATA sections and the
+//! five predefined entities plus character references. Processing
+//! instructions and doctype declarations are skipped.
+
+use super::render::escape;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+  Element(Element),
+  Text(String),
+  Comment(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+  /// The qualified name as written, e.g. `div` or `xhtml:div`.
+  pub name: String,
+  pub attributes: Vec<(String, String)>,
+  pub children: Vec<Node>,
+}
+
+impl Element {
+  /// The name without any namespace prefix.
+  pub fn local_name(&self) -> &str {
+    match self.name.find(':') {
+      Some(index) => &self.name[index + 1..],
+      None => &self.name,
+    }
+  }
+
+  pub fn attribute(&self, name: &str) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|(n, _)| n == name)
+      .map(|(_, v)| v.as_str())
+  }
+
+  /// The namespace this element is in, resolved from its own `xmlns`
+  /// declarations or the `inherited` default namespace.
+  pub fn namespace<'a>(&'a self, inherited: Option<&'a str>) -> Option<&'a str> {
+    match self.name.find(':') {
+      Some(index) => self.attribute(&format!("xmlns:{}", &self.name[..index])),
+      None => self.attribute("xmlns").or(inherited),
+    }
+  }
+
+  /// All text content, with whitespace preserved.
+  pub fn text(&self) -> String {
+    let mut text = String::new();
+    for child in &self.children {
+      match child {
+        Node::Text(t) => text.push_str(t),
+        Node::Element(e) => text.push_str(&e.text()),
+        Node::Comment(_) => {}
+      }
+    }
+    text
+  }
+
+  pub fn to_xml(&self) -> String {
+    let mut xml = String::new();
+    write_element(self, &mut xml);
+    xml
+  }
+}
+
+fn write_element(element: &Element, xml: &mut String) {
+  xml.push('<');
+  xml.push_str(&element.name);
+  for (name, value) in &element.attributes {
+    xml.push_str(&format!(" {}=\"{}\"", name, escape(value)));
+  }
+  if element.children.is_empty() {
+    xml.push_str("/>");
+    return;
+  }
+  xml.push('>');
+  for child in &element.children {
+    match child {
+      Node::Element(e) => write_element(e, xml),
+      Node::Text(t) => xml.push_str(&escape_text(t)),
+      Node::Comment(c) => xml.push_str(&format!("<!--{}-->", c)),
+    }
+  }
+  xml.push_str(&format!("</{}>", element.name));
+}
+
+/// Text content only needs `&` and `<` escaped; leaving quotes alone keeps
+/// round-tripped narratives close to their source.
+fn escape_text(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+  pub offset: usize,
+  pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{} at offset {}", self.message, self.offset)
+  }
+}
+
+/// Parses a document with a single root element.
+pub fn parse(input: &str) -> Result<Element, ParseError> {
+  let mut parser = Parser { input, pos: 0 };
+  parser.skip_prolog()?;
+  let root = parser.element()?;
+  parser.skip_misc()?;
+  if parser.pos < input.len() {
+    return Err(parser.error("Content after the root element"));
+  }
+  Ok(root)
+}
+
+struct Parser<'a> {
+  input: &'a str,
+  pos: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn error(&self, message: &str) -> ParseError {
+    ParseError {
+      offset: self.pos,
+      message: message.to_string(),
+    }
+  }
+
+  fn rest(&self) -> &'a str {
+    &self.input[self.pos..]
+  }
+
+  fn starts_with(&self, s: &str) -> bool {
+    self.rest().starts_with(s)
+  }
+
+  fn skip_whitespace(&mut self) {
+    let trimmed = self.rest().trim_start();
+    self.pos = self.input.len() - trimmed.len();
+  }
+
+  fn skip_past(&mut self, terminator: &str) -> Result<&'a str, ParseError> {
+    match self.rest().find(terminator) {
+      Some(index) => {
+        let skipped = &self.rest()[..index];
+        self.pos += index + terminator.len();
+        Ok(skipped)
+      }
+      None => Err(self.error(&format!("Expected '{}'", terminator))),
+    }
+  }
+
+  fn skip_prolog(&mut self) -> Result<(), ParseError> {
+    if self.starts_with("\u{feff}") {
+      self.pos += "\u{feff}".len();
+    }
+    loop {
+      self.skip_whitespace();
+      if self.starts_with("<?") {
+        self.skip_past("?>")?;
+      } else if self.starts_with("<!--") {
+        self.skip_past("-->")?;
+      } else if self.starts_with("<!DOCTYPE") {
+        self.skip_past(">")?;
+      } else {
+        return Ok(());
+      }
+    }
+  }
+
+  fn skip_misc(&mut self) -> Result<(), ParseError> {
+    loop {
+      self.skip_whitespace();
+      if self.starts_with("<?") {
+        self.skip_past("?>")?;
+      } else if self.starts_with("<!--") {
+        self.skip_past("-->")?;
+      } else {
+        return Ok(());
+      }
+    }
+  }
+
+  fn name(&mut self) -> Result<String, ParseError> {
+    let end = self
+      .rest()
+      .find(|c: char| c.is_whitespace() || c == '>' || c == '/' || c == '=')
+      .unwrap_or_else(|| self.rest().len());
+    if end == 0 {
+      return Err(self.error("Expected a name"));
+    }
+    let name = self.rest()[..end].to_string();
+    self.pos += end;
+    Ok(name)
+  }
+
+  fn element(&mut self) -> Result<Element, ParseError> {
+    if !self.starts_with("<") {
+      return Err(self.error("Expected an element"));
+    }
+    self.pos += 1;
+    let name = self.name()?;
+    let mut attributes: Vec<(String, String)> = vec![];
+    loop {
+      self.skip_whitespace();
+      if self.starts_with("/>") {
+        self.pos += 2;
+        return Ok(Element {
+          name,
+          attributes,
+          children: vec![],
+        });
+      }
+      if self.starts_with(">") {
+        self.pos += 1;
+        break;
+      }
+      let attribute = self.name()?;
+      self.skip_whitespace();
+      if !self.starts_with("=") {
+        return Err(self.error("Expected '=' after attribute name"));
+      }
+      self.pos += 1;
+      self.skip_whitespace();
+      let quote = match self.rest().chars().next() {
+        Some(q) if q == '"' || q == '\'' => q,
+        _ => return Err(self.error("Expected a quoted attribute value")),
+      };
+      self.pos += 1;
+      let raw = self.skip_past(&quote.to_string())?;
+      if raw.contains('<') {
+        return Err(self.error("'<' is not allowed in attribute values"));
+      }
+      if attributes.iter().any(|(n, _)| *n == attribute) {
+        return Err(self.error(&format!("Duplicate attribute '{}'", attribute)));
+      }
+      let value = self.decode(raw)?;
+      attributes.push((attribute, value));
+    }
+
+    let mut children = vec![];
+    loop {
+      if self.pos >= self.input.len() {
+        return Err(self.error(&format!("Unclosed element '{}'", name)));
+      }
+      if self.starts_with("</") {
+        self.pos += 2;
+        let closing = self.name()?;
+        if closing != name {
+          return Err(self.error(&format!(
+            "Expected '</{}>' but found '</{}>'",
+            name, closing
+          )));
+        }
+        self.skip_whitespace();
+        if !self.starts_with(">") {
+          return Err(self.error("Expected '>'"));
+        }
+        self.pos += 1;
+        return Ok(Element {
+          name,
+          attributes,
+          children,
+        });
+      } else if self.starts_with("<!--") {
+        self.pos += 4;
+        let comment = self.skip_past("-->")?;
+        children.push(Node::Comment(comment.to_string()));
+      } else if self.starts_with("<![CDATA[") {
+        self.pos += 9;
+        let text = self.skip_past("]]>")?;
+        push_text(&mut children, text);
+      } else if self.starts_with("<?") {
+        self.skip_past("?>")?;
+      } else if self.starts_with("<") {
+        children.push(Node::Element(self.element()?));
+      } else {
+        let end = self.rest().find('<').unwrap_or_else(|| self.rest().len());
+        let raw = &self.rest()[..end];
+        let text = self.decode(raw)?;
+        self.pos += end;
+        push_text(&mut children, &text);
+      }
+    }
+  }
+
+  fn decode(&self, raw: &str) -> Result<String, ParseError> {
+    let mut decoded = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(index) = rest.find('&') {
+      decoded.push_str(&rest[..index]);
+      rest = &rest[index..];
+      let end = match rest.find(';') {
+        Some(end) => end,
+        None => return Err(self.error("Unterminated entity reference")),
+      };
+      let entity = &rest[1..end];
+      let c = match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+          .ok()
+          .and_then(std::char::from_u32),
+        _ if entity.starts_with('#') => entity[1..]
+          .parse::<u32>()
+          .ok()
+          .and_then(std::char::from_u32),
+        _ => None,
+      };
+      match c {
+        Some(c) => decoded.push(c),
+        None => return Err(self.error(&format!("Unknown entity '&{};'", entity))),
+      }
+      rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+    Ok(decoded)
+  }
+}
+
+fn push_text(children: &mut Vec<Node>, text: &str) {
+  if let Some(Node::Text(previous)) = children.last_mut() {
+    previous.push_str(text);
+  } else {
+    children.push(Node::Text(text.to_string()));
+  }
+}
@@ -0,0 +1,698 @@
+//! FHIRPath Patch: a `Parameters` resource with one `operation` parameter
+//! per change, each with a `type` (`add`, `insert`, `delete`, `replace` or
+//! `move`), a FHIRPath `path` and, depending on the type, a `name`,
+//! `value`, `index`, `source` and `destination`.
+//!
+//! Element types come from the table the generator writes alongside the
+//! model (`crate::elements`): it tells which elements repeat, which are
+//! choices, and datatypes (sent as `value[x]` of their type, e.g.
+//! `valueHumanName`) from backbone elements (sent as parts). Primitives
+//! outside choices are typed from their JSON value, so a `date` is sent as
+//! a `valueString`. A primitive's `_element` extensions travel with it as
+//! `_value[x]`.
+
+use super::{align, Edit, PatchError};
+use crate::elements::{self, ElementType};
+use crate::fhirpath::Expression;
+use crate::model::Parameters::Parameters;
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+
+/// What the model knows about an element of a type.
+struct Field {
+  /// The type of a complex value, e.g. `HumanName` or `Patient_Contact`;
+  /// `None` for primitives and resources.
+  type_name: Option<String>,
+  repeats: bool,
+  choice: bool,
+}
+
+fn complex_type(element: &ElementType) -> Option<String> {
+  if element.is_complex() {
+    Some(element.type_name.to_string())
+  } else {
+    None
+  }
+}
+
+fn field(parent: Option<&str>, name: &str) -> Option<Field> {
+  let parent = parent?;
+  if let Some(element) = elements::element(parent, name) {
+    return Some(Field {
+      type_name: complex_type(&element),
+      repeats: element.repeats,
+      choice: false,
+    });
+  }
+  elements::elements(parent)
+    .find(|element| element.name == name)
+    .map(|_| Field {
+      type_name: None,
+      repeats: false,
+      choice: true,
+    })
+}
+
+/// The element a JSON key holds: `deceasedBoolean` is the `deceased`
+/// element with the type `Boolean`.
+struct Element {
+  name: String,
+  type_name: Option<String>,
+  /// The type in a choice element's key.
+  choice: Option<String>,
+}
+
+fn describe(parent: Option<&str>, key: &str) -> Element {
+  match parent.and_then(|parent| elements::element(parent, key)) {
+    Some(element) => Element {
+      name: element.name.to_string(),
+      type_name: complex_type(&element),
+      choice: if element.is_choice() {
+        Some(key[element.name.len()..].to_string())
+      } else {
+        None
+      },
+    },
+    None => Element {
+      name: key.to_string(),
+      type_name: None,
+      choice: None,
+    },
+  }
+}
+
+/// The type of the value at a JSON pointer in a resource.
+fn type_at(resource: &Value, pointer: &str) -> Option<String> {
+  let mut type_name = resource["resourceType"].as_str().map(|t| t.to_string());
+  let mut value = resource;
+  for token in pointer.split('/').skip(1) {
+    match token.parse::<usize>() {
+      Ok(index) => value = &value[index],
+      Err(_) => {
+        type_name = describe(type_name.as_deref(), token).type_name;
+        value = &value[token];
+      }
+    }
+    if let Some(resource_type) = value["resourceType"].as_str() {
+      type_name = Some(resource_type.to_string());
+    }
+  }
+  type_name
+}
+
+/// An item of an element: its value and, for a primitive, the extensions
+/// in `_element`.
+type Item = (Value, Option<Value>);
+
+/// Takes the items of an element out of an object. The flag is whether
+/// the element is an array.
+fn take_items(object: &mut Map<String, Value>, key: &str) -> (Vec<Item>, bool) {
+  let values = object.remove(key);
+  let extensions = object.remove(&format!("_{}", key));
+  let extension = |value: Option<&Value>| value.filter(|value| !value.is_null()).cloned();
+  match (values, extensions) {
+    (None, None) => (vec![], false),
+    (Some(Value::Array(values)), extensions) => {
+      let extensions = match extensions {
+        Some(Value::Array(extensions)) => extensions,
+        _ => vec![],
+      };
+      let items = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| (value, extension(extensions.get(i))))
+        .collect();
+      (items, true)
+    }
+    (None, Some(Value::Array(extensions))) => {
+      let items = extensions
+        .iter()
+        .map(|value| (Value::Null, extension(Some(value))))
+        .collect();
+      (items, true)
+    }
+    (value, extensions) => (
+      vec![(value.unwrap_or(Value::Null), extension(extensions.as_ref()))],
+      false,
+    ),
+  }
+}
+
+/// Writes the items of an element into an object, `_element` included.
+fn put_items(object: &mut Map<String, Value>, key: &str, items: Vec<Item>, array: bool) {
+  let extension_key = format!("_{}", key);
+  if items.is_empty() {
+    return;
+  }
+  let has_extensions = items.iter().any(|(_, extension)| extension.is_some());
+  if array {
+    let (values, extensions): (Vec<Value>, Vec<Option<Value>>) = items.into_iter().unzip();
+    object.insert(key.to_string(), Value::Array(values));
+    if has_extensions {
+      let extensions = extensions
+        .into_iter()
+        .map(|extension| extension.unwrap_or(Value::Null))
+        .collect();
+      object.insert(extension_key, Value::Array(extensions));
+    }
+  } else if let Some((value, extension)) = items.into_iter().next() {
+    if !value.is_null() {
+      object.insert(key.to_string(), value);
+    }
+    if let Some(extension) = extension {
+      object.insert(extension_key, extension);
+    }
+  }
+}
+
+/// The items of an element of an object, for diffing.
+fn items(object: &Map<String, Value>, key: &str) -> Vec<Item> {
+  take_items(&mut object.clone(), key).0
+}
+
+fn primitive_type(value: &Value) -> &'static str {
+  match value {
+    Value::Bool(_) => "Boolean",
+    Value::Number(number) if number.is_f64() => "Decimal",
+    Value::Number(_) => "Integer",
+    _ => "String",
+  }
+}
+
+/// A part named `name` holding an item.
+fn value_part(name: &str, element: &Element, item: &Item) -> Value {
+  let (value, extension) = item;
+  let mut part = json!({ "name": name });
+  match value {
+    Value::Object(_) if value.get("resourceType").is_some() => part["resource"] = value.clone(),
+    Value::Object(object) => match &element.type_name {
+      Some(type_name) if !type_name.contains('_') => {
+        part[format!("value{}", type_name)] = value.clone()
+      }
+      type_name => {
+        let mut parts = vec![];
+        let mut keys: Vec<&str> = vec![];
+        for key in object.keys().map(|key| key.trim_start_matches('_')) {
+          if !keys.contains(&key) {
+            keys.push(key);
+          }
+        }
+        for key in keys {
+          let child = describe(type_name.as_deref(), key);
+          for item in items(object, key) {
+            parts.push(value_part(&child.name, &child, &item));
+          }
+        }
+        part["part"] = Value::Array(parts);
+      }
+    },
+    _ => {
+      let type_name = element
+        .choice
+        .as_deref()
+        .unwrap_or_else(|| primitive_type(value));
+      if !value.is_null() {
+        part[format!("value{}", type_name)] = value.clone();
+      }
+      if let Some(extension) = extension {
+        part[format!("_value{}", type_name)] = extension.clone();
+      }
+    }
+  }
+  part
+}
+
+fn operation(operation_type: &str, path: &str, parts: Vec<Value>) -> Value {
+  let mut part = vec![
+    json!({"name": "type", "valueCode": operation_type}),
+    json!({"name": "path", "valueString": path}),
+  ];
+  part.extend(parts);
+  json!({"name": "operation", "part": part})
+}
+
+/// The FHIRPath Patch that turns `old` into `new`.
+pub fn diff(old: &Value, new: &Value) -> Parameters<'static> {
+  let mut operations = vec![];
+  if let (Value::Object(old), Value::Object(new)) = (old, new) {
+    let resource_type = new["resourceType"].as_str().unwrap_or_default();
+    diff_object(
+      old,
+      new,
+      resource_type,
+      Some(resource_type),
+      &mut operations,
+    );
+  }
+  Parameters {
+    value: Cow::Owned(json!({"resourceType": "Parameters", "parameter": operations})),
+  }
+}
+
+fn diff_object(
+  old: &Map<String, Value>,
+  new: &Map<String, Value>,
+  path: &str,
+  type_name: Option<&str>,
+  operations: &mut Vec<Value>,
+) {
+  let mut keys: Vec<&str> = vec![];
+  for key in old.keys().chain(new.keys()) {
+    let key = key.trim_start_matches('_');
+    if key != "resourceType" && !keys.contains(&key) {
+      keys.push(key);
+    }
+  }
+  for key in keys {
+    let element = describe(type_name, key);
+    let element_path = format!("{}.{}", path, element.name);
+    let (old_items, new_items) = (items(old, key), items(new, key));
+    let repeats =
+      old.get(key).is_some_and(Value::is_array) || new.get(key).is_some_and(Value::is_array);
+    if old_items == new_items {
+      continue;
+    }
+    if old_items.is_empty() {
+      for item in &new_items {
+        operations.push(operation(
+          "add",
+          path,
+          vec![
+            json!({"name": "name", "valueString": element.name}),
+            value_part("value", &element, item),
+          ],
+        ));
+      }
+      continue;
+    }
+    if !repeats {
+      if new_items.is_empty() {
+        operations.push(operation("delete", &element_path, vec![]));
+      } else {
+        diff_item(
+          &old_items[0],
+          &new_items[0],
+          &element,
+          &element_path,
+          operations,
+        );
+      }
+      continue;
+    }
+    // `index` follows the array as the operations so far leave it.
+    let mut index = 0;
+    for edit in align(&old_items, &new_items) {
+      let item_path = format!("{}[{}]", element_path, index);
+      match edit {
+        Edit::Keep(_, _) => index += 1,
+        Edit::Change(i, j) => {
+          diff_item(
+            &old_items[i],
+            &new_items[j],
+            &element,
+            &item_path,
+            operations,
+          );
+          index += 1;
+        }
+        Edit::Remove(_) => operations.push(operation("delete", &item_path, vec![])),
+        Edit::Insert(j) => {
+          operations.push(operation(
+            "insert",
+            &element_path,
+            vec![
+              json!({"name": "index", "valueInteger": index}),
+              value_part("value", &element, &new_items[j]),
+            ],
+          ));
+          index += 1;
+        }
+      }
+    }
+  }
+}
+
+fn diff_item(old: &Item, new: &Item, element: &Element, path: &str, operations: &mut Vec<Value>) {
+  match (old, new) {
+    ((Value::Object(old), None), (Value::Object(new), None))
+      if old.get("resourceType") == new.get("resourceType") =>
+    {
+      let type_name = new
+        .get("resourceType")
+        .and_then(Value::as_str)
+        .map(|t| t.to_string())
+        .or_else(|| element.type_name.clone());
+      diff_object(old, new, path, type_name.as_deref(), operations)
+    }
+    _ => operations.push(operation(
+      "replace",
+      path,
+      vec![value_part("value", element, new)],
+    )),
+  }
+}
+
+/// The part of an operation with the name.
+fn part<'p>(operation: &'p Value, name: &str) -> Option<&'p Value> {
+  operation["part"]
+    .as_array()?
+    .iter()
+    .find(|part| part["name"] == name)
+}
+
+/// The `value[x]` of a part as a string, whatever the type.
+fn text<'p>(operation: &'p Value, name: &str) -> Option<&'p str> {
+  let part = part(operation, name)?.as_object()?;
+  part
+    .iter()
+    .find(|(key, _)| key.starts_with("value"))
+    .and_then(|(_, value)| value.as_str())
+}
+
+fn number(operation: &Value, name: &str) -> Result<usize, PatchError> {
+  part(operation, name)
+    .and_then(|part| part["valueInteger"].as_u64())
+    .map(|number| number as usize)
+    .ok_or_else(|| PatchError::Invalid(format!("The operation needs an integer {}", name)))
+}
+
+/// Reads the item in a `value` part, with the type of a choice value.
+fn decode(part: &Value, type_name: Option<&str>) -> Result<(Item, Option<String>), PatchError> {
+  if let Some(resource) = part.get("resource") {
+    return Ok(((resource.clone(), None), None));
+  }
+  if let Some(parts) = part["part"].as_array() {
+    let mut object = Map::new();
+    for child in parts {
+      let name = child["name"]
+        .as_str()
+        .ok_or_else(|| PatchError::Invalid("A part needs a name".to_string()))?;
+      let field = field(type_name, name);
+      let (item, choice) = decode(child, field.as_ref().and_then(|f| f.type_name.as_deref()))?;
+      let key = match (field.as_ref().is_some_and(|f| f.choice), choice) {
+        (true, Some(choice)) => format!("{}{}", name, choice),
+        _ => name.to_string(),
+      };
+      let (mut items, array) = take_items(&mut object, &key);
+      items.push(item);
+      let array = array || items.len() > 1 || field.is_some_and(|f| f.repeats);
+      put_items(&mut object, &key, items, array);
+    }
+    return Ok(((Value::Object(object), None), None));
+  }
+  let object = part
+    .as_object()
+    .ok_or_else(|| PatchError::Invalid("A value must be a part".to_string()))?;
+  let key = object
+    .keys()
+    .find(|key| key.starts_with("value") || key.starts_with("_value"))
+    .ok_or_else(|| PatchError::Invalid("The operation needs a value".to_string()))?;
+  let choice = key.trim_start_matches('_')["value".len()..].to_string();
+  let value = object
+    .get(&format!("value{}", choice))
+    .cloned()
+    .unwrap_or(Value::Null);
+  let extension = object.get(&format!("_value{}", choice)).cloned();
+  Ok(((value, extension), Some(choice)))
+}
+
+/// Splits a pointer to an element or an item of one into the pointer of
+/// the object holding it, the key and the index.
+fn split(pointer: &str) -> (String, String, Option<usize>) {
+  let mut tokens: Vec<&str> = pointer.split('/').collect();
+  let index = tokens.last().and_then(|token| token.parse::<usize>().ok());
+  if index.is_some() {
+    tokens.pop();
+  }
+  let key = tokens.pop().unwrap_or_default().to_string();
+  (tokens.join("/"), key, index)
+}
+
+fn object<'v>(
+  resource: &'v mut Value,
+  pointer: &str,
+) -> Result<&'v mut Map<String, Value>, PatchError> {
+  resource
+    .pointer_mut(pointer)
+    .and_then(Value::as_object_mut)
+    .ok_or_else(|| PatchError::Path(format!("Nothing can be added at {}", pointer)))
+}
+
+/// The pointers of the items a path selects.
+fn select(resource: &Value, path: &str) -> Result<Vec<String>, PatchError> {
+  let expression =
+    Expression::parse(path).map_err(|error| PatchError::Invalid(format!("{}: {}", path, error)))?;
+  Ok(
+    expression
+      .evaluate(resource)
+      .into_iter()
+      .filter_map(|node| node.pointer)
+      .collect(),
+  )
+}
+
+fn single(resource: &Value, path: &str) -> Result<String, PatchError> {
+  match select(resource, path)?.as_slice() {
+    [pointer] => Ok(pointer.clone()),
+    [] => Err(PatchError::Path(format!("{} selects nothing", path))),
+    _ => Err(PatchError::Path(format!(
+      "{} selects more than one element",
+      path
+    ))),
+  }
+}
+
+/// The object and key of the list a path selects, for `insert` and `move`.
+fn list(resource: &Value, path: &str) -> Result<(String, String), PatchError> {
+  let pointers = select(resource, path)?;
+  if let Some(pointer) = pointers.first() {
+    let (parent, key, _) = split(pointer);
+    return Ok((parent, key));
+  }
+  // An empty list: its parent must exist.
+  match path.rfind('.') {
+    Some(dot) => Ok((single(resource, &path[..dot])?, path[dot + 1..].to_string())),
+    None => Err(PatchError::Path(format!("{} selects nothing", path))),
+  }
+}
+
+/// Applies the operations of a FHIRPath Patch in order.
+pub fn apply(resource: &Value, patch: &Parameters) -> Result<Value, PatchError> {
+  let mut patched = resource.clone();
+  let operations = patch.value["parameter"]
+    .as_array()
+    .cloned()
+    .unwrap_or_default();
+  for operation in operations.iter().filter(|p| p["name"] == "operation") {
+    let operation_type = text(operation, "type")
+      .ok_or_else(|| PatchError::Invalid("The operation needs a type".to_string()))?;
+    let path = text(operation, "path")
+      .ok_or_else(|| PatchError::Invalid("The operation needs a path".to_string()))?;
+    let value = || {
+      part(operation, "value")
+        .ok_or_else(|| PatchError::Invalid("The operation needs a value".to_string()))
+    };
+    match operation_type {
+      "add" => {
+        let name = text(operation, "name")
+          .ok_or_else(|| PatchError::Invalid("add needs a name".to_string()))?;
+        let pointer = single(&patched, path)?;
+        let parent_type = type_at(&patched, &pointer);
+        let field = field(parent_type.as_deref(), name);
+        let (item, choice) = decode(
+          value()?,
+          field.as_ref().and_then(|f| f.type_name.as_deref()),
+        )?;
+        let key = match (field.as_ref().is_some_and(|f| f.choice), choice) {
+          (true, Some(choice)) => format!("{}{}", name, choice),
+          _ => name.to_string(),
+        };
+        let object = object(&mut patched, &pointer)?;
+        let (mut items, array) = take_items(object, &key);
+        let repeats = array || field.is_some_and(|f| f.repeats);
+        if !repeats && !items.is_empty() {
+          put_items(object, &key, items, repeats);
+          return Err(PatchError::Path(format!(
+            "{}.{} already has a value: use replace",
+            path, key
+          )));
+        }
+        items.push(item);
+        put_items(object, &key, items, repeats);
+      }
+      "insert" => {
+        let index = number(operation, "index")?;
+        let (parent, key) = list(&patched, path)?;
+        let item_type = type_at(&patched, &parent)
+          .and_then(|parent_type| field(Some(&parent_type), &key))
+          .and_then(|field| field.type_name);
+        let (item, _) = decode(value()?, item_type.as_deref())?;
+        let object = object(&mut patched, &parent)?;
+        let (mut items, _) = take_items(object, &key);
+        if index > items.len() {
+          put_items(object, &key, items, true);
+          return Err(PatchError::Path(format!("{} has no index {}", path, index)));
+        }
+        items.insert(index, item);
+        put_items(object, &key, items, true);
+      }
+      "delete" => {
+        let pointer = match select(&patched, path)?.as_slice() {
+          [] => continue,
+          [pointer] => pointer.clone(),
+          _ => {
+            return Err(PatchError::Path(format!(
+              "{} selects more than one element",
+              path
+            )))
+          }
+        };
+        let (parent, key, index) = split(&pointer);
+        let object = object(&mut patched, &parent)?;
+        let (mut items, array) = take_items(object, &key);
+        if let Some(index) = index {
+          items.remove(index);
+          put_items(object, &key, items, array);
+        }
+      }
+      "replace" => {
+        let pointer = single(&patched, path)?;
+        let (item, choice) = decode(value()?, type_at(&patched, &pointer).as_deref())?;
+        let (parent, key, index) = split(&pointer);
+        let parent_type = type_at(&patched, &parent);
+        let element = describe(parent_type.as_deref(), &key);
+        let new_key = match (element.choice, choice) {
+          (Some(_), Some(choice)) => format!("{}{}", element.name, choice),
+          _ => key.clone(),
+        };
+        let object = object(&mut patched, &parent)?;
+        let (mut items, array) = take_items(object, &key);
+        match index {
+          Some(index) => items[index] = item,
+          None => items = vec![item],
+        }
+        put_items(object, &new_key, items, array);
+      }
+      "move" => {
+        let (source, destination) = (
+          number(operation, "source")?,
+          number(operation, "destination")?,
+        );
+        let (parent, key) = list(&patched, path)?;
+        let object = object(&mut patched, &parent)?;
+        let (mut items, array) = take_items(object, &key);
+        if source >= items.len() || destination >= items.len() {
+          put_items(object, &key, items, array);
+          return Err(PatchError::Path(format!(
+            "{} has no index {}",
+            path,
+            source.max(destination)
+          )));
+        }
+        let item = items.remove(source);
+        items.insert(destination, item);
+        put_items(object, &key, items, array);
+      }
+      other => {
+        return Err(PatchError::Invalid(format!(
+          "Unknown operation type {}",
+          other
+        )))
+      }
+    }
+  }
+  Ok(patched)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_apply_fhirpath_patch() {
+    let resource = json!({
+      "resourceType": "Patient",
+      "name": [{"family": "Chalmers"}],
+      "contact": [{"gender": "male"}]
+    });
+    let patch = json!({
+      "resourceType": "Parameters",
+      "parameter": [
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "add"},
+          {"name": "path", "valueString": "Patient"},
+          {"name": "name", "valueString": "deceased"},
+          {"name": "value", "valueBoolean": false}
+        ]},
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "insert"},
+          {"name": "path", "valueString": "Patient.name"},
+          {"name": "index", "valueInteger": 0},
+          {"name": "value", "valueHumanName": {"given": ["Jim"]}}
+        ]},
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "add"},
+          {"name": "path", "valueString": "Patient"},
+          {"name": "name", "valueString": "contact"},
+          {"name": "value", "part": [
+            {"name": "relationship", "valueCodeableConcept": {"text": "partner"}},
+            {"name": "gender", "valueCode": "female"}
+          ]}
+        ]},
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "move"},
+          {"name": "path", "valueString": "Patient.contact"},
+          {"name": "source", "valueInteger": 1},
+          {"name": "destination", "valueInteger": 0}
+        ]},
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "replace"},
+          {"name": "path", "valueString": "Patient.name[1].family"},
+          {"name": "value", "valueString": "Windsor"}
+        ]},
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "delete"},
+          {"name": "path", "valueString": "Patient.contact[1]"}
+        ]},
+        {"name": "operation", "part": [
+          {"name": "type", "valueCode": "delete"},
+          {"name": "path", "valueString": "Patient.birthDate"}
+        ]}
+      ]
+    });
+    assert_eq!(
+      apply(&resource, &Parameters::new(&patch)).unwrap(),
+      json!({
+        "resourceType": "Patient",
+        "deceasedBoolean": false,
+        "name": [{"given": ["Jim"]}, {"family": "Windsor"}],
+        "contact": [{"relationship": [{"text": "partner"}], "gender": "female"}]
+      })
+    );
+
+    let replace_missing = json!({"resourceType": "Parameters", "parameter": [
+      {"name": "operation", "part": [
+        {"name": "type", "valueCode": "replace"},
+        {"name": "path", "valueString": "Patient.gender"},
+        {"name": "value", "valueCode": "male"}
+      ]}
+    ]});
+    assert_eq!(
+      apply(&resource, &Parameters::new(&replace_missing)).unwrap_err(),
+      PatchError::Path("Patient.gender selects nothing".to_string())
+    );
+
+    let add_existing = json!({"resourceType": "Parameters", "parameter": [
+      {"name": "operation", "part": [
+        {"name": "type", "valueCode": "add"},
+        {"name": "path", "valueString": "Patient.contact[0]"},
+        {"name": "name", "valueString": "gender"},
+        {"name": "value", "valueCode": "female"}
+      ]}
+    ]});
+    assert_eq!(
+      apply(&resource, &Parameters::new(&add_existing)).unwrap_err(),
+      PatchError::Path("Patient.contact[0].gender already has a value: use replace".to_string())
+    );
+  }
+}
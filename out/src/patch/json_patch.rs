@@ -0,0 +1,325 @@
+//! JSON Patch (RFC 6902) documents: computing them and applying them.
+
+use super::{align, Edit, PatchError};
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation {
+  Add { path: String, value: Value },
+  Remove { path: String },
+  Replace { path: String, value: Value },
+  Move { from: String, path: String },
+  Copy { from: String, path: String },
+  Test { path: String, value: Value },
+}
+
+impl Operation {
+  pub fn to_json(&self) -> Value {
+    match self {
+      Operation::Add { path, value } => json!({"op": "add", "path": path, "value": value}),
+      Operation::Remove { path } => json!({"op": "remove", "path": path}),
+      Operation::Replace { path, value } => {
+        json!({"op": "replace", "path": path, "value": value})
+      }
+      Operation::Move { from, path } => json!({"op": "move", "from": from, "path": path}),
+      Operation::Copy { from, path } => json!({"op": "copy", "from": from, "path": path}),
+      Operation::Test { path, value } => json!({"op": "test", "path": path, "value": value}),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonPatch {
+  pub operations: Vec<Operation>,
+}
+
+impl JsonPatch {
+  /// Reads a JSON Patch document: an array of operations.
+  pub fn parse(document: &Value) -> Result<JsonPatch, PatchError> {
+    let items = document
+      .as_array()
+      .ok_or_else(|| PatchError::Invalid("A JSON Patch is an array".to_string()))?;
+    let mut operations = vec![];
+    for item in items {
+      let text = |name: &str| {
+        item[name]
+          .as_str()
+          .map(|text| text.to_string())
+          .ok_or_else(|| PatchError::Invalid(format!("The operation needs a {}", name)))
+      };
+      let value = || {
+        item
+          .get("value")
+          .cloned()
+          .ok_or_else(|| PatchError::Invalid("The operation needs a value".to_string()))
+      };
+      let operation = match text("op")?.as_str() {
+        "add" => Operation::Add {
+          path: text("path")?,
+          value: value()?,
+        },
+        "remove" => Operation::Remove {
+          path: text("path")?,
+        },
+        "replace" => Operation::Replace {
+          path: text("path")?,
+          value: value()?,
+        },
+        "move" => Operation::Move {
+          from: text("from")?,
+          path: text("path")?,
+        },
+        "copy" => Operation::Copy {
+          from: text("from")?,
+          path: text("path")?,
+        },
+        "test" => Operation::Test {
+          path: text("path")?,
+          value: value()?,
+        },
+        other => return Err(PatchError::Invalid(format!("Unknown op {}", other))),
+      };
+      operations.push(operation);
+    }
+    Ok(JsonPatch { operations })
+  }
+
+  pub fn to_json(&self) -> Value {
+    Value::Array(self.operations.iter().map(Operation::to_json).collect())
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.operations.is_empty()
+  }
+
+  /// Applies the operations in order. Nothing is changed if one fails.
+  pub fn apply(&self, resource: &Value) -> Result<Value, PatchError> {
+    let mut patched = resource.clone();
+    for operation in &self.operations {
+      match operation {
+        Operation::Add { path, value } => add(&mut patched, path, value.clone())?,
+        Operation::Remove { path } => {
+          remove(&mut patched, path)?;
+        }
+        Operation::Replace { path, value } => *get(&mut patched, path)? = value.clone(),
+        Operation::Move { from, path } => {
+          if path.starts_with(&format!("{}/", from)) {
+            return Err(PatchError::Path(format!(
+              "Cannot move {} into itself",
+              from
+            )));
+          }
+          let value = remove(&mut patched, from)?;
+          add(&mut patched, path, value)?;
+        }
+        Operation::Copy { from, path } => {
+          let value = get(&mut patched, from)?.clone();
+          add(&mut patched, path, value)?;
+        }
+        Operation::Test { path, value } => {
+          if *get(&mut patched, path)? != *value {
+            return Err(PatchError::TestFailed(path.clone()));
+          }
+        }
+      }
+    }
+    Ok(patched)
+  }
+}
+
+/// The JSON Patch that turns `old` into `new`.
+pub fn diff(old: &Value, new: &Value) -> JsonPatch {
+  let mut operations = vec![];
+  diff_into(old, new, "", &mut operations);
+  JsonPatch { operations }
+}
+
+fn diff_into(old: &Value, new: &Value, path: &str, operations: &mut Vec<Operation>) {
+  match (old, new) {
+    _ if old == new => {}
+    (Value::Object(old), Value::Object(new)) => {
+      for (key, value) in old {
+        let path = format!("{}/{}", path, escape(key));
+        match new.get(key) {
+          Some(new_value) => diff_into(value, new_value, &path, operations),
+          None => operations.push(Operation::Remove { path }),
+        }
+      }
+      for (key, value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+        operations.push(Operation::Add {
+          path: format!("{}/{}", path, escape(key)),
+          value: value.clone(),
+        });
+      }
+    }
+    (Value::Array(old_items), Value::Array(new_items)) => {
+      // `index` follows the array as the operations so far leave it.
+      let mut index = 0;
+      for edit in align(old_items, new_items) {
+        let item = format!("{}/{}", path, index);
+        match edit {
+          Edit::Keep(_, _) => index += 1,
+          Edit::Change(i, j) => {
+            diff_into(&old_items[i], &new_items[j], &item, operations);
+            index += 1;
+          }
+          Edit::Remove(_) => operations.push(Operation::Remove { path: item }),
+          Edit::Insert(j) => {
+            operations.push(Operation::Add {
+              path: item,
+              value: new_items[j].clone(),
+            });
+            index += 1;
+          }
+        }
+      }
+    }
+    _ => operations.push(Operation::Replace {
+      path: path.to_string(),
+      value: new.clone(),
+    }),
+  }
+}
+
+/// Escapes a key as a JSON Pointer reference token.
+fn escape(key: &str) -> String {
+  key.replace('~', "~0").replace('/', "~1")
+}
+
+/// The reference tokens of a JSON Pointer, unescaped.
+fn tokens(pointer: &str) -> Result<Vec<String>, PatchError> {
+  if pointer.is_empty() {
+    return Ok(vec![]);
+  }
+  match pointer.strip_prefix('/') {
+    Some(rest) => Ok(
+      rest
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect(),
+    ),
+    None => Err(PatchError::Invalid(format!(
+      "{} is not a JSON Pointer",
+      pointer
+    ))),
+  }
+}
+
+fn missing(pointer: &str) -> PatchError {
+  PatchError::Path(format!("Nothing is at {}", pointer))
+}
+
+fn index(token: &str, len: usize, pointer: &str) -> Result<usize, PatchError> {
+  match token.parse::<usize>() {
+    Ok(index) if index < len && (token == "0" || !token.starts_with('0')) => Ok(index),
+    _ => Err(missing(pointer)),
+  }
+}
+
+fn child<'v>(
+  value: &'v mut Value,
+  token: &str,
+  pointer: &str,
+) -> Result<&'v mut Value, PatchError> {
+  match value {
+    Value::Object(object) => object.get_mut(token).ok_or_else(|| missing(pointer)),
+    Value::Array(items) => {
+      let i = index(token, items.len(), pointer)?;
+      Ok(&mut items[i])
+    }
+    _ => Err(missing(pointer)),
+  }
+}
+
+fn get<'v>(value: &'v mut Value, pointer: &str) -> Result<&'v mut Value, PatchError> {
+  tokens(pointer)?
+    .iter()
+    .try_fold(value, |value, token| child(value, token, pointer))
+}
+
+/// The parent of the value at `pointer`, and the last token.
+fn parent<'v>(value: &'v mut Value, pointer: &str) -> Result<(&'v mut Value, String), PatchError> {
+  let mut tokens = tokens(pointer)?;
+  let last = tokens
+    .pop()
+    .ok_or_else(|| PatchError::Path("The whole document cannot be removed".to_string()))?;
+  let parent = tokens
+    .iter()
+    .try_fold(value, |value, token| child(value, token, pointer))?;
+  Ok((parent, last))
+}
+
+fn add(document: &mut Value, pointer: &str, value: Value) -> Result<(), PatchError> {
+  if pointer.is_empty() {
+    *document = value;
+    return Ok(());
+  }
+  let (parent, last) = parent(document, pointer)?;
+  match parent {
+    Value::Object(object) => {
+      object.insert(last, value);
+    }
+    Value::Array(items) if last == "-" => items.push(value),
+    Value::Array(items) => {
+      let i = index(&last, items.len() + 1, pointer)?;
+      items.insert(i, value);
+    }
+    _ => return Err(missing(pointer)),
+  }
+  Ok(())
+}
+
+fn remove(document: &mut Value, pointer: &str) -> Result<Value, PatchError> {
+  let (parent, last) = parent(document, pointer)?;
+  match parent {
+    Value::Object(object) => object.remove(&last).ok_or_else(|| missing(pointer)),
+    Value::Array(items) => {
+      let i = index(&last, items.len(), pointer)?;
+      Ok(items.remove(i))
+    }
+    _ => Err(missing(pointer)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_apply_json_patch() {
+    let resource = json!({"resourceType": "Patient", "name": [{"given": ["a", "b"]}]});
+    let patch = JsonPatch::parse(&json!([
+      {"op": "test", "path": "/resourceType", "value": "Patient"},
+      {"op": "add", "path": "/name/0/given/-", "value": "c"},
+      {"op": "copy", "from": "/name/0", "path": "/name/1"},
+      {"op": "move", "from": "/name/1/given/0", "path": "/active"},
+      {"op": "replace", "path": "/active", "value": true},
+      {"op": "remove", "path": "/name/0/given/1"},
+    ]))
+    .unwrap();
+    assert_eq!(
+      patch.apply(&resource).unwrap(),
+      json!({"resourceType": "Patient", "active": true,
+        "name": [{"given": ["a", "c"]}, {"given": ["b", "c"]}]})
+    );
+    let failing = JsonPatch::parse(&json!([{"op": "test", "path": "/id", "value": "1"}])).unwrap();
+    assert!(matches!(failing.apply(&resource), Err(PatchError::Path(_))));
+    let failing = JsonPatch::parse(&json!([
+      {"op": "test", "path": "/resourceType", "value": "Group"}
+    ]))
+    .unwrap();
+    assert_eq!(
+      failing.apply(&resource),
+      Err(PatchError::TestFailed("/resourceType".to_string()))
+    );
+    assert_eq!(
+      diff(
+        &resource,
+        &json!({"resourceType": "Patient", "name": [{"given": ["x", "a", "b"]}]})
+      )
+      .to_json(),
+      json!([{"op": "add", "path": "/name/0/given/0", "value": "x"}])
+    );
+    assert!(diff(&resource, &resource).is_empty());
+  }
+}
@@ -0,0 +1,236 @@
+//! Diffs between versions of a resource, as JSON Patch (RFC 6902) and as
+//! FHIRPath Patch, and applying either to a resource.
+//!
+//! Arrays are compared item by item along their longest common
+//! subsequence, so inserting one `name` is one `add` rather than a
+//! `replace` of every later name. Items that are only changed are diffed
+//! in place.
+
+pub mod fhirpath_patch;
+pub mod json_patch;
+
+pub use json_patch::{JsonPatch, Operation};
+
+use crate::base64;
+use crate::model::Parameters::Parameters;
+use crate::model::ResourceList::ResourceList;
+use crate::rest::Resource;
+use serde_json::{json, Value};
+use std::fmt;
+
+/// The media type of a JSON Patch document.
+pub const JSON_PATCH: &str = "application/json-patch+json";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchError {
+  /// The patch is not a JSON Patch document or a FHIRPath Patch.
+  Invalid(String),
+  /// A path selects nothing, or more than the operation can work on.
+  Path(String),
+  /// A JSON Patch `test` operation failed.
+  TestFailed(String),
+}
+
+impl PatchError {
+  /// The HTTP status code for the error.
+  pub fn status(&self) -> u16 {
+    match self {
+      PatchError::Invalid(_) => 400,
+      PatchError::Path(_) | PatchError::TestFailed(_) => 422,
+    }
+  }
+}
+
+impl fmt::Display for PatchError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      PatchError::Invalid(message) => write!(f, "Invalid patch: {}", message),
+      PatchError::Path(message) => write!(f, "{}", message),
+      PatchError::TestFailed(path) => write!(f, "The test of {} failed", path),
+    }
+  }
+}
+
+impl std::error::Error for PatchError {}
+
+/// One step of aligning an old array with a new one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Edit {
+  /// The items at these indexes are equal.
+  Keep(usize, usize),
+  /// The old item became the new one.
+  Change(usize, usize),
+  Remove(usize),
+  Insert(usize),
+}
+
+/// Aligns two arrays along their longest common subsequence. Between two
+/// kept items, removed and inserted items are paired up as changes.
+pub(crate) fn align<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+  let (n, m) = (old.len(), new.len());
+  // lengths[i][j] is the length of the LCS of old[i..] and new[j..].
+  let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+  for i in (0..n).rev() {
+    for j in (0..m).rev() {
+      lengths[i][j] = if old[i] == new[j] {
+        lengths[i + 1][j + 1] + 1
+      } else {
+        lengths[i + 1][j].max(lengths[i][j + 1])
+      };
+    }
+  }
+  let mut edits = vec![];
+  let (mut removed, mut inserted) = (vec![], vec![]);
+  let flush = |edits: &mut Vec<Edit>, removed: &mut Vec<usize>, inserted: &mut Vec<usize>| {
+    let pairs = removed.len().min(inserted.len());
+    for k in 0..pairs {
+      edits.push(Edit::Change(removed[k], inserted[k]));
+    }
+    edits.extend(removed[pairs..].iter().map(|&i| Edit::Remove(i)));
+    edits.extend(inserted[pairs..].iter().map(|&j| Edit::Insert(j)));
+    removed.clear();
+    inserted.clear();
+  };
+  let (mut i, mut j) = (0, 0);
+  while i < n || j < m {
+    if i < n && j < m && old[i] == new[j] {
+      flush(&mut edits, &mut removed, &mut inserted);
+      edits.push(Edit::Keep(i, j));
+      i += 1;
+      j += 1;
+    } else if j == m || (i < n && lengths[i + 1][j] >= lengths[i][j + 1]) {
+      removed.push(i);
+      i += 1;
+    } else {
+      inserted.push(j);
+      j += 1;
+    }
+  }
+  flush(&mut edits, &mut removed, &mut inserted);
+  edits
+}
+
+/// The JSON Patch that turns `old` into `new`.
+pub fn json_patch<R: Resource>(old: &R, new: &R) -> JsonPatch {
+  json_patch::diff(old.json(), new.json())
+}
+
+/// The FHIRPath Patch that turns `old` into `new`.
+pub fn fhirpath_patch<R: Resource>(old: &R, new: &R) -> Parameters<'static> {
+  fhirpath_patch::diff(old.json(), new.json())
+}
+
+/// Applies a patch to a resource: a JSON Patch array, a `Binary` holding
+/// one, or a FHIRPath Patch `Parameters`.
+pub fn apply(resource: &Value, patch: &Value) -> Result<Value, PatchError> {
+  match patch {
+    Value::Array(_) => JsonPatch::parse(patch)?.apply(resource),
+    _ if patch["resourceType"] == "Parameters" => {
+      fhirpath_patch::apply(resource, &Parameters::new(patch))
+    }
+    _ if patch["resourceType"] == "Binary" => {
+      if patch["contentType"] != JSON_PATCH {
+        return Err(PatchError::Invalid(format!(
+          "A Binary patch must be {}",
+          JSON_PATCH
+        )));
+      }
+      let data = patch["data"].as_str().unwrap_or_default();
+      let document = base64::decode(data)
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(|| PatchError::Invalid("The Binary data is not JSON".to_string()))?;
+      JsonPatch::parse(&document)?.apply(resource)
+    }
+    _ => Err(PatchError::Invalid(
+      "Expected a JSON Patch, a Binary or a Parameters".to_string(),
+    )),
+  }
+}
+
+/// `apply` to a generated resource type, with the patch as a `Binary` or a
+/// `Parameters`.
+pub fn apply_to<R: Resource>(resource: &R, patch: &ResourceList) -> Result<R, PatchError> {
+  apply(resource.json(), &patch.to_json()).map(R::from_json)
+}
+
+/// A JSON Patch as the `Binary` a transaction entry carries.
+pub fn binary(patch: &JsonPatch) -> ResourceList<'static> {
+  let data = base64::encode(patch.to_json().to_string().as_bytes());
+  ResourceList {
+    value: std::borrow::Cow::Owned(json!({
+      "resourceType": "Binary",
+      "contentType": JSON_PATCH,
+      "data": data,
+    })),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_align() {
+    let edits = align(&["a", "b", "c"], &["a", "x", "b", "d"]);
+    assert_eq!(
+      edits,
+      vec![
+        Edit::Keep(0, 0),
+        Edit::Insert(1),
+        Edit::Keep(1, 2),
+        Edit::Change(2, 3)
+      ]
+    );
+  }
+
+  #[test]
+  fn test_patch_round_trip() {
+    let old = Patient::from_json(load("patient-example.json"));
+    let mut json = old.to_json();
+    json["name"]
+      .as_array_mut()
+      .unwrap()
+      .insert(0, json!({"use": "nickname", "given": ["Jim"]}));
+    json["name"][2]["given"] = json!(["Jimmy"]);
+    json["gender"] = json!("other");
+    json.as_object_mut().unwrap().remove("deceasedBoolean");
+    json["deceasedDateTime"] = json!("2015-02-14T13:42:00+10:00");
+    json["_birthDate"]["extension"][0]["valueDateTime"] = json!("1974-12-25T14:36:00-05:00");
+    let new = Patient::from_json(json);
+
+    let patch = json_patch(&old, &new);
+    assert!(patch.operations.contains(&Operation::Add {
+      path: "/name/0".to_string(),
+      value: json!({"use": "nickname", "given": ["Jim"]}),
+    }));
+    assert!(patch.operations.contains(&Operation::Replace {
+      path: "/name/2/given/0".to_string(),
+      value: json!("Jimmy"),
+    }));
+    assert_eq!(patch.apply(old.json()).unwrap(), *new.json());
+    let binary = binary(&patch);
+    assert_eq!(apply_to(&old, &binary).unwrap().json(), new.json());
+
+    let parameters = fhirpath_patch(&old, &new);
+    let operations = parameters.parameter().unwrap_or_default();
+    assert!(operations.len() < 10);
+    assert_eq!(
+      apply_to(&old, &ResourceList::new(&parameters.to_json()))
+        .unwrap()
+        .json(),
+      new.json()
+    );
+
+    assert_eq!(
+      apply(old.json(), &json!({"resourceType": "Patient"})).unwrap_err(),
+      PatchError::Invalid("Expected a JSON Patch, a Binary or a Parameters".to_string())
+    );
+  }
+}
@@ -0,0 +1,499 @@
+//! `_summary` and `_elements` projections of resources.
+//!
+//! Which elements are part of the summary and which are mandatory comes
+//! from the StructureDefinitions a `Projector` is given: `isSummary` and
+//! `min` of each element of the snapshot. Summaries recurse into datatypes
+//! and backbone elements whose definitions are known; elements of types
+//! without definitions are kept whole. Every projected resource is tagged
+//! `SUBSETTED`, as servers must do.
+
+use crate::model::Bundle::Bundle;
+use crate::model::ResourceList::ResourceList;
+use crate::model::StructureDefinition::{StructureDefinition, StructureDefinitionDerivation};
+use crate::rest::{Resource, Summary};
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The system of the `SUBSETTED` tag.
+pub const SUBSETTED_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v3-ObservationValue";
+pub const SUBSETTED: &str = "SUBSETTED";
+
+/// The elements every projection keeps.
+const ALWAYS_KEPT: &[&str] = &["resourceType", "id", "meta"];
+
+#[derive(Debug, Clone)]
+struct ElementInfo {
+  summary: bool,
+  min: u64,
+  types: Vec<String>,
+  /// The path of the element whose children this element has, such as
+  /// `Questionnaire.item` for `Questionnaire.item.item`.
+  content_reference: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct Projector {
+  elements: HashMap<String, ElementInfo>,
+  /// The paths of elements that have children, e.g. `HumanName` and
+  /// `Patient.contact`.
+  parents: HashSet<String>,
+}
+
+impl Projector {
+  pub fn new() -> Projector {
+    Projector::default()
+  }
+
+  /// Adds the elements of a definition's snapshot. Returns whether it was
+  /// added: profiles that constrain another definition and definitions
+  /// without a snapshot are not.
+  pub fn add(&mut self, definition: &StructureDefinition) -> bool {
+    if let Some(StructureDefinitionDerivation::Constraint) = definition.derivation() {
+      return false;
+    }
+    let snapshot = match definition.snapshot() {
+      Some(snapshot) => snapshot,
+      None => return false,
+    };
+    for element in snapshot.element() {
+      let path = match element.path() {
+        Some(path) => path,
+        None => continue,
+      };
+      if let Some(index) = path.rfind('.') {
+        self.parents.insert(path[..index].to_string());
+      }
+      self.elements.insert(
+        path.to_string(),
+        ElementInfo {
+          summary: element.is_summary().unwrap_or(false),
+          min: element.min().unwrap_or(0),
+          types: element
+            .fhir_type()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|element_type| element_type.code().map(|code| code.to_string()))
+            .collect(),
+          content_reference: element
+            .content_reference()
+            .map(|reference| reference.trim_start_matches('#').to_string()),
+        },
+      );
+    }
+    true
+  }
+
+  /// Adds the StructureDefinitions in a Bundle, such as
+  /// `profiles-resources.json`, and returns how many were added.
+  pub fn add_bundle(&mut self, bundle: &Bundle) -> usize {
+    let mut added = 0;
+    for entry in bundle.value["entry"].as_array().into_iter().flatten() {
+      let resource = &entry["resource"];
+      if resource["resourceType"] == "StructureDefinition"
+        && self.add(&StructureDefinition::new(resource))
+      {
+        added += 1;
+      }
+    }
+    added
+  }
+
+  /// Adds the StructureDefinitions in the `*.profile.json` files of a
+  /// directory, and returns how many were added.
+  pub fn add_directory(&mut self, directory: &Path) -> io::Result<usize> {
+    let mut added = 0;
+    for entry in fs::read_dir(directory)? {
+      let path = entry?.path();
+      let is_profile = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".profile.json"));
+      if !is_profile {
+        continue;
+      }
+      let value: Value = serde_json::from_str(&fs::read_to_string(&path)?)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+      if value["resourceType"] == "StructureDefinition"
+        && self.add(&StructureDefinition::new(&value))
+      {
+        added += 1;
+      }
+    }
+    Ok(added)
+  }
+
+  /// Whether no definitions have been added, so that summaries cannot be
+  /// told from full resources.
+  pub fn is_empty(&self) -> bool {
+    self.elements.is_empty()
+  }
+
+  /// The resource as returned for `_summary`. `Count` and `False` return
+  /// it unchanged.
+  pub fn summary(&self, resource: &ResourceList, mode: Summary) -> ResourceList<'static> {
+    ResourceList {
+      value: Cow::Owned(self.summary_json(&resource.value, mode)),
+    }
+  }
+
+  /// The resource as returned for `_elements`: the listed elements, such
+  /// as `name` or `Patient.birthDate`, and the mandatory ones.
+  pub fn elements(&self, resource: &ResourceList, elements: &[&str]) -> ResourceList<'static> {
+    ResourceList {
+      value: Cow::Owned(self.elements_json(&resource.value, elements)),
+    }
+  }
+
+  /// `summary` of a generated resource type.
+  pub fn summary_of<R: Resource>(&self, resource: &R, mode: Summary) -> R {
+    R::from_json(self.summary_json(resource.json(), mode))
+  }
+
+  /// `elements` of a generated resource type.
+  pub fn elements_of<R: Resource>(&self, resource: &R, elements: &[&str]) -> R {
+    R::from_json(self.elements_json(resource.json(), elements))
+  }
+
+  /// Applies the `_summary` and `_elements` of search or read parameters.
+  pub fn project(&self, resource: &Value, params: &[(String, String)]) -> Value {
+    let param = |name: &str| {
+      params
+        .iter()
+        .rev()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+    };
+    if let Some(mode) = param("_summary").and_then(Summary::parse) {
+      return self.summary_json(resource, mode);
+    }
+    match param("_elements") {
+      Some(elements) => {
+        let elements: Vec<&str> = elements
+          .split(',')
+          .map(|element| element.trim())
+          .filter(|element| !element.is_empty())
+          .collect();
+        self.elements_json(resource, &elements)
+      }
+      None => resource.clone(),
+    }
+  }
+
+  fn summary_json(&self, resource: &Value, mode: Summary) -> Value {
+    let resource_type = resource["resourceType"].as_str().unwrap_or_default();
+    let mut projected = match (mode, resource) {
+      (Summary::Count, _) | (Summary::False, _) | (_, Value::Null) => return resource.clone(),
+      (Summary::True, _) => self.summarise(resource_type, resource),
+      (Summary::Text, Value::Object(object)) => Value::Object(
+        object
+          .iter()
+          .filter(|(key, _)| {
+            ALWAYS_KEPT.contains(&key.as_str())
+              || self.name(resource_type, key) == "text"
+              || self.mandatory(resource_type, key)
+          })
+          .map(|(key, value)| (key.clone(), value.clone()))
+          .collect(),
+      ),
+      (Summary::Data, Value::Object(object)) => Value::Object(
+        object
+          .iter()
+          .filter(|(key, _)| self.name(resource_type, key) != "text")
+          .map(|(key, value)| (key.clone(), value.clone()))
+          .collect(),
+      ),
+      _ => resource.clone(),
+    };
+    tag_subsetted(&mut projected);
+    projected
+  }
+
+  fn elements_json(&self, resource: &Value, elements: &[&str]) -> Value {
+    let resource_type = resource["resourceType"].as_str().unwrap_or_default();
+    let listed: Vec<&str> = elements
+      .iter()
+      .map(|element| {
+        element
+          .strip_prefix(resource_type)
+          .and_then(|rest| rest.strip_prefix('.'))
+          .unwrap_or(element)
+      })
+      .map(|element| element.trim_end_matches("[x]"))
+      .collect();
+    let mut projected = match resource {
+      Value::Object(object) => Value::Object(
+        object
+          .iter()
+          .filter(|(key, _)| {
+            ALWAYS_KEPT.contains(&key.as_str())
+              || listed.contains(&self.name(resource_type, key))
+              || self.mandatory(resource_type, key)
+          })
+          .map(|(key, value)| (key.clone(), value.clone()))
+          .collect(),
+      ),
+      _ => return resource.clone(),
+    };
+    tag_subsetted(&mut projected);
+    projected
+  }
+
+  /// The summary of a value whose definition is at `path`.
+  fn summarise(&self, path: &str, value: &Value) -> Value {
+    match value {
+      Value::Array(items) => Value::Array(
+        items
+          .iter()
+          .map(|item| self.summarise(path, item))
+          .collect(),
+      ),
+      Value::Object(object) if self.parents.contains(path) => {
+        let mut summary = Map::new();
+        for (key, child) in object {
+          let element = key.strip_prefix('_').unwrap_or(key);
+          if key == "resourceType" {
+            summary.insert(key.clone(), child.clone());
+            continue;
+          }
+          let (child_path, info) = match self.element(path, element) {
+            Some(found) => found,
+            None => continue,
+          };
+          if !info.summary {
+            continue;
+          }
+          // `_element` holds the id and extensions of a primitive.
+          if key.starts_with('_') {
+            summary.insert(key.clone(), child.clone());
+            continue;
+          }
+          let context = self.context(&child_path, info, element, child);
+          summary.insert(key.clone(), self.summarise(&context, child));
+        }
+        Value::Object(summary)
+      }
+      _ => value.clone(),
+    }
+  }
+
+  /// The path of the definitions of the children of an element: itself for
+  /// backbone elements, or its type.
+  fn context(&self, path: &str, info: &ElementInfo, key: &str, value: &Value) -> String {
+    if let Some(reference) = &info.content_reference {
+      return reference.clone();
+    }
+    if self.parents.contains(path) {
+      return path.to_string();
+    }
+    let element_type = if info.types.len() == 1 {
+      info.types[0].clone()
+    } else {
+      // A choice, such as `valueQuantity` of `Observation.value[x]`.
+      let name = path.rsplit('.').next().unwrap_or_default();
+      let suffix = key
+        .get(name.trim_end_matches("[x]").len()..)
+        .unwrap_or_default();
+      info
+        .types
+        .iter()
+        .find(|element_type| element_type.eq_ignore_ascii_case(suffix))
+        .cloned()
+        .unwrap_or_default()
+    };
+    if element_type == "Resource" {
+      let item = match value {
+        Value::Array(items) => items.first().unwrap_or(&Value::Null),
+        _ => value,
+      };
+      return item["resourceType"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    }
+    element_type
+  }
+
+  /// The definition of the child element `key` of the element at `parent`,
+  /// looking through choices.
+  fn element(&self, parent: &str, key: &str) -> Option<(String, &ElementInfo)> {
+    let path = format!("{}.{}", parent, key);
+    if let Some(info) = self.elements.get(&path) {
+      return Some((path, info));
+    }
+    key
+      .char_indices()
+      .filter(|(_, c)| c.is_ascii_uppercase())
+      .find_map(|(index, _)| {
+        let path = format!("{}.{}[x]", parent, &key[..index]);
+        self.elements.get(&path).map(|info| (path, info))
+      })
+  }
+
+  /// The name of the top-level element that a key of a resource holds,
+  /// e.g. `deceased` for `deceasedBoolean` and `birthDate` for
+  /// `_birthDate`.
+  fn name<'k>(&self, resource_type: &str, key: &'k str) -> &'k str {
+    let key = key.strip_prefix('_').unwrap_or(key);
+    match self.element(resource_type, key) {
+      Some((path, _)) if path.ends_with("[x]") => &key[..path.len() - resource_type.len() - 4],
+      _ => key,
+    }
+  }
+
+  fn mandatory(&self, resource_type: &str, key: &str) -> bool {
+    let key = key.strip_prefix('_').unwrap_or(key);
+    self
+      .element(resource_type, key)
+      .is_some_and(|(_, info)| info.min > 0)
+  }
+}
+
+/// Adds the `SUBSETTED` tag to `meta.tag`, unless it is there.
+fn tag_subsetted(resource: &mut Value) {
+  let object = match resource.as_object_mut() {
+    Some(object) => object,
+    None => return,
+  };
+  let meta = object.entry("meta").or_insert_with(|| json!({}));
+  if !meta.is_object() {
+    *meta = json!({});
+  }
+  let tags = meta
+    .as_object_mut()
+    .map(|meta| meta.entry("tag").or_insert_with(|| json!([])))
+    .filter(|tags| tags.is_array());
+  if let Some(Value::Array(tags)) = tags {
+    let tagged = tags
+      .iter()
+      .any(|tag| tag["system"] == SUBSETTED_SYSTEM && tag["code"] == SUBSETTED);
+    if !tagged {
+      tags.push(json!({"system": SUBSETTED_SYSTEM, "code": SUBSETTED, "display": "subsetted"}));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  fn projector() -> Projector {
+    let mut projector = Projector::new();
+    for name in &[
+      "patient.profile.json",
+      "observation.profile.json",
+      "humanname.profile.json",
+      "contactpoint.profile.json",
+    ] {
+      assert!(projector.add(&StructureDefinition::new(&load(name))));
+    }
+    assert!(!projector.add(&StructureDefinition::new(&load("bmi.profile.json"))));
+    projector
+  }
+
+  fn keys(value: &Value) -> Vec<&str> {
+    let mut keys: Vec<&str> = value
+      .as_object()
+      .unwrap()
+      .keys()
+      .map(|key| key.as_str())
+      .collect();
+    keys.sort_unstable();
+    keys
+  }
+
+  #[test]
+  fn test_summary() {
+    let projector = projector();
+    let json = load("patient-example.json");
+    let patient = Patient::new(&json);
+
+    let summary = projector.summary_of(&patient, Summary::True).to_json();
+    assert_eq!(
+      keys(&summary),
+      vec![
+        "_birthDate",
+        "active",
+        "address",
+        "birthDate",
+        "deceasedBoolean",
+        "gender",
+        "id",
+        "identifier",
+        "managingOrganization",
+        "meta",
+        "name",
+        "resourceType",
+        "telecom"
+      ]
+    );
+    assert_eq!(summary["name"][0], json["name"][0]);
+    assert_eq!(summary["telecom"][1], json["telecom"][1]);
+    assert_eq!(
+      summary["meta"]["tag"][1],
+      json!({"system": SUBSETTED_SYSTEM, "code": "SUBSETTED", "display": "subsetted"})
+    );
+    // Tagging twice does not add a second tag.
+    let twice = projector.summary(&ResourceList::new(&summary), Summary::True);
+    assert_eq!(twice.to_json()["meta"]["tag"], summary["meta"]["tag"]);
+
+    let text = projector.summary(&ResourceList::new(&json), Summary::Text);
+    assert_eq!(
+      keys(&text.to_json()),
+      vec!["id", "meta", "resourceType", "text"]
+    );
+    let data = projector.summary(&ResourceList::new(&json), Summary::Data);
+    assert!(data.to_json().get("text").is_none());
+    assert!(data.to_json().get("contact").is_some());
+    assert_eq!(
+      projector
+        .summary(&ResourceList::new(&json), Summary::False)
+        .to_json(),
+      json
+    );
+  }
+
+  #[test]
+  fn test_elements() {
+    let projector = projector();
+    let json = load("observation-example.json");
+    let params = vec![(
+      "_elements".to_string(),
+      "Observation.subject,value".to_string(),
+    )];
+    let projected = projector.project(&json, &params);
+    assert_eq!(
+      keys(&projected),
+      vec![
+        "code",
+        "id",
+        "meta",
+        "resourceType",
+        "status",
+        "subject",
+        "valueQuantity"
+      ]
+    );
+    assert_eq!(projected["valueQuantity"], json["valueQuantity"]);
+    assert_eq!(projected["meta"]["tag"][1]["code"], "SUBSETTED");
+    assert_eq!(projector.project(&json, &[]), json);
+
+    let text = projector
+      .summary(&ResourceList::new(&json), Summary::Text)
+      .to_json();
+    assert_eq!(
+      keys(&text),
+      vec!["code", "id", "meta", "resourceType", "status", "text"]
+    );
+  }
+}
@@ -0,0 +1,195 @@
+//! Evaluation of `Questionnaire.item.enableWhen`.
+
+use super::AnswerValue;
+use crate::model::Questionnaire::Questionnaire;
+use crate::model::QuestionnaireResponse::QuestionnaireResponse;
+use crate::model::QuestionnaireResponse_Item::QuestionnaireResponse_Item;
+use crate::model::Questionnaire_EnableWhen::{
+  Questionnaire_EnableWhen, Questionnaire_EnableWhenOperator,
+};
+use crate::model::Questionnaire_Item::{Questionnaire_Item, Questionnaire_ItemEnableBehavior};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// The answers of a response, indexed by `linkId`.
+///
+/// enableWhen conditions are resolved against every answer to the referenced
+/// question, wherever it appears in the response.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseAnswers {
+  by_link_id: HashMap<String, Vec<AnswerValue>>,
+}
+
+impl ResponseAnswers {
+  pub fn new() -> ResponseAnswers {
+    ResponseAnswers {
+      by_link_id: HashMap::new(),
+    }
+  }
+
+  pub fn from_response(response: &QuestionnaireResponse) -> ResponseAnswers {
+    let mut answers = ResponseAnswers::new();
+    if let Some(items) = response.item() {
+      answers.collect(&items);
+    }
+    answers
+  }
+
+  fn collect(&mut self, items: &[QuestionnaireResponse_Item]) {
+    for item in items {
+      if let (Some(link_id), Some(answers)) = (item.link_id(), item.answer()) {
+        for answer in &answers {
+          if let Some(value) = AnswerValue::from_answer(answer) {
+            self
+              .by_link_id
+              .entry(link_id.to_string())
+              .or_default()
+              .push(value);
+          }
+          if let Some(children) = answer.item() {
+            self.collect(&children);
+          }
+        }
+      }
+      if let Some(children) = item.item() {
+        self.collect(&children);
+      }
+    }
+  }
+
+  pub fn answers(&self, link_id: &str) -> &[AnswerValue] {
+    match self.by_link_id.get(link_id) {
+      Some(values) => values,
+      None => &[],
+    }
+  }
+
+  /// Replaces every answer recorded for `link_id`.
+  pub fn set(&mut self, link_id: &str, values: Vec<AnswerValue>) {
+    if values.is_empty() {
+      self.by_link_id.remove(link_id);
+    } else {
+      self.by_link_id.insert(link_id.to_string(), values);
+    }
+  }
+
+  pub fn link_ids(&self) -> impl Iterator<Item = &String> {
+    self.by_link_id.keys()
+  }
+
+  fn retain<F: Fn(&str) -> bool>(&mut self, keep: F) {
+    self.by_link_id.retain(|link_id, _| keep(link_id));
+  }
+}
+
+/// Evaluates a single enableWhen condition. Apart from `exists`, a condition
+/// on a question without answers is false.
+pub fn evaluate(condition: &Questionnaire_EnableWhen, answers: &ResponseAnswers) -> bool {
+  let question = match condition.question() {
+    Some(question) => question,
+    None => return false,
+  };
+  let given = answers.answers(question);
+  let operator = match condition.operator() {
+    Some(operator) => operator,
+    None => return false,
+  };
+  if let Questionnaire_EnableWhenOperator::Exists = operator {
+    let expected = condition.answer_boolean().unwrap_or(true);
+    return given.is_empty() != expected;
+  }
+  let expected = match AnswerValue::from_enable_when(condition) {
+    Some(expected) => expected,
+    None => return false,
+  };
+  if given.is_empty() {
+    return false;
+  }
+  match operator {
+    Questionnaire_EnableWhenOperator::Equal => given.iter().any(|a| a.matches(&expected)),
+    Questionnaire_EnableWhenOperator::NotEqual => given.iter().all(|a| !a.matches(&expected)),
+    Questionnaire_EnableWhenOperator::GreaterThan => given
+      .iter()
+      .any(|a| a.compare(&expected) == Some(Ordering::Greater)),
+    Questionnaire_EnableWhenOperator::LessThan => given
+      .iter()
+      .any(|a| a.compare(&expected) == Some(Ordering::Less)),
+    Questionnaire_EnableWhenOperator::GreaterThanOrEqual => given.iter().any(|a| {
+      matches!(
+        a.compare(&expected),
+        Some(Ordering::Greater) | Some(Ordering::Equal)
+      )
+    }),
+    Questionnaire_EnableWhenOperator::LessThanOrEqual => given.iter().any(|a| {
+      matches!(
+        a.compare(&expected),
+        Some(Ordering::Less) | Some(Ordering::Equal)
+      )
+    }),
+    Questionnaire_EnableWhenOperator::Exists => unreachable!(),
+  }
+}
+
+/// Whether an item's own enableWhen conditions hold, combined according to
+/// `enableBehavior` (which defaults to `all`). Ancestors are not considered.
+pub fn is_enabled(item: &Questionnaire_Item, answers: &ResponseAnswers) -> bool {
+  let conditions = match item.enable_when() {
+    Some(conditions) if !conditions.is_empty() => conditions,
+    _ => return true,
+  };
+  match item.enable_behavior() {
+    Some(Questionnaire_ItemEnableBehavior::Any) => conditions.iter().any(|c| evaluate(c, answers)),
+    _ => conditions.iter().all(|c| evaluate(c, answers)),
+  }
+}
+
+/// Computes the linkIds of every enabled item in a questionnaire.
+///
+/// An item is enabled when its own conditions hold and its parent is
+/// enabled. Answers to disabled items do not count towards other items'
+/// conditions, so the computation is repeated until it stabilises (bounded by
+/// the number of items, in case of conditions that oscillate).
+pub fn enabled_items(questionnaire: &Questionnaire, answers: &ResponseAnswers) -> HashSet<String> {
+  let items = questionnaire.item().unwrap_or_default();
+  let mut known = HashSet::new();
+  collect_link_ids(&items, &mut known);
+
+  let mut enabled = HashSet::new();
+  walk(&items, answers, &mut enabled);
+  for _ in 0..known.len() {
+    let mut current = answers.clone();
+    current.retain(|link_id| enabled.contains(link_id) || !known.contains(link_id));
+    let mut next = HashSet::new();
+    walk(&items, &current, &mut next);
+    if next == enabled {
+      break;
+    }
+    enabled = next;
+  }
+  enabled
+}
+
+fn walk(items: &[Questionnaire_Item], answers: &ResponseAnswers, enabled: &mut HashSet<String>) {
+  for item in items {
+    if !is_enabled(item, answers) {
+      continue;
+    }
+    if let Some(link_id) = item.link_id() {
+      enabled.insert(link_id.to_string());
+    }
+    if let Some(children) = item.item() {
+      walk(&children, answers, enabled);
+    }
+  }
+}
+
+fn collect_link_ids(items: &[Questionnaire_Item], link_ids: &mut HashSet<String>) {
+  for item in items {
+    if let Some(link_id) = item.link_id() {
+      link_ids.insert(link_id.to_string());
+    }
+    if let Some(children) = item.item() {
+      collect_link_ids(&children, link_ids);
+    }
+  }
+}
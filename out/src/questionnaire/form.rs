@@ -0,0 +1,379 @@
+//! A server-side form runtime: tracks answers to a `Questionnaire`, keeps the
+//! set of enabled items up to date and renders the current state back out as
+//! a `QuestionnaireResponse`.
+//!
+//! Answers are tracked per `linkId`, so items nested inside repeating groups
+//! share their answers across repetitions.
+
+use super::enable_when::{enabled_items, ResponseAnswers};
+use super::AnswerValue;
+use crate::model::Questionnaire::Questionnaire;
+use crate::model::QuestionnaireResponse::{
+  QuestionnaireResponse, QuestionnaireResponseBuilder, QuestionnaireResponseStatus,
+};
+use crate::model::QuestionnaireResponse_Answer::QuestionnaireResponse_Answer;
+use crate::model::QuestionnaireResponse_Item::{
+  QuestionnaireResponse_Item, QuestionnaireResponse_ItemBuilder,
+};
+use crate::model::Questionnaire_Item::{Questionnaire_Item, Questionnaire_ItemType};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// The state of one questionnaire item, as presented to a UI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemState {
+  pub link_id: String,
+  pub text: Option<String>,
+  /// The `Questionnaire.item.type` code, e.g. `"choice"`.
+  pub item_type: Option<String>,
+  pub enabled: bool,
+  pub required: bool,
+  pub repeats: bool,
+  pub read_only: bool,
+  pub answers: Vec<AnswerValue>,
+  pub items: Vec<ItemState>,
+}
+
+/// The linkIds whose enabled state changed after an update.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormChange {
+  pub enabled: Vec<String>,
+  pub disabled: Vec<String>,
+}
+
+impl FormChange {
+  pub fn is_empty(&self) -> bool {
+    self.enabled.is_empty() && self.disabled.is_empty()
+  }
+}
+
+pub struct FormEngine<'a, 'b> {
+  questionnaire: &'a Questionnaire<'b>,
+  answers: ResponseAnswers,
+  enabled: HashSet<String>,
+}
+
+impl<'a, 'b> FormEngine<'a, 'b> {
+  /// Starts a new form with every item's `initial` values (or initially
+  /// selected answer options) filled in.
+  pub fn new(questionnaire: &'a Questionnaire<'b>) -> FormEngine<'a, 'b> {
+    FormEngine::with_answers(questionnaire, ResponseAnswers::new())
+  }
+
+  /// Resumes a form from a partial response. Items the response does not
+  /// answer fall back to their initial values.
+  pub fn with_response(
+    questionnaire: &'a Questionnaire<'b>,
+    response: &QuestionnaireResponse,
+  ) -> FormEngine<'a, 'b> {
+    FormEngine::with_answers(questionnaire, ResponseAnswers::from_response(response))
+  }
+
+  fn with_answers(
+    questionnaire: &'a Questionnaire<'b>,
+    mut answers: ResponseAnswers,
+  ) -> FormEngine<'a, 'b> {
+    populate_initial(&questionnaire.item().unwrap_or_default(), &mut answers);
+    let enabled = enabled_items(questionnaire, &answers);
+    FormEngine {
+      questionnaire,
+      answers,
+      enabled,
+    }
+  }
+
+  pub fn is_enabled(&self, link_id: &str) -> bool {
+    self.enabled.contains(link_id)
+  }
+
+  pub fn answers(&self, link_id: &str) -> &[AnswerValue] {
+    self.answers.answers(link_id)
+  }
+
+  /// Replaces the answers to `link_id` and reports which items were enabled
+  /// or disabled as a result. Answers to items that become disabled are kept
+  /// so they reappear if the item is enabled again, but they are left out of
+  /// `response()`.
+  pub fn set_answers(&mut self, link_id: &str, values: Vec<AnswerValue>) -> FormChange {
+    self.answers.set(link_id, values);
+    self.refresh()
+  }
+
+  pub fn clear_answers(&mut self, link_id: &str) -> FormChange {
+    self.set_answers(link_id, vec![])
+  }
+
+  fn refresh(&mut self) -> FormChange {
+    let enabled = enabled_items(self.questionnaire, &self.answers);
+    let mut change = FormChange {
+      enabled: enabled.difference(&self.enabled).cloned().collect(),
+      disabled: self.enabled.difference(&enabled).cloned().collect(),
+    };
+    change.enabled.sort();
+    change.disabled.sort();
+    self.enabled = enabled;
+    change
+  }
+
+  /// The current state of every item, in questionnaire order.
+  pub fn items(&self) -> Vec<ItemState> {
+    self.item_states(&self.questionnaire.item().unwrap_or_default(), true)
+  }
+
+  fn item_states(&self, items: &[Questionnaire_Item], parent_enabled: bool) -> Vec<ItemState> {
+    items
+      .iter()
+      .filter_map(|item| {
+        let link_id = item.link_id()?;
+        let enabled = parent_enabled && self.enabled.contains(link_id);
+        Some(ItemState {
+          link_id: link_id.to_string(),
+          text: item.text().map(|s| s.to_string()),
+          item_type: item.fhir_type().map(|t| t.to_string()),
+          enabled,
+          required: item.required().unwrap_or(false),
+          repeats: item.repeats().unwrap_or(false),
+          read_only: item.read_only().unwrap_or(false),
+          answers: self.answers.answers(link_id).to_vec(),
+          items: self.item_states(&item.item().unwrap_or_default(), enabled),
+        })
+      })
+      .collect()
+  }
+
+  /// Renders the enabled, answered items as an `in-progress` response.
+  pub fn response(&self) -> QuestionnaireResponse<'static> {
+    let items = self.response_items(&self.questionnaire.item().unwrap_or_default());
+    build_response(self.questionnaire, items)
+  }
+
+  fn response_items(&self, items: &[Questionnaire_Item]) -> Vec<Value> {
+    let mut result = vec![];
+    for item in items {
+      let link_id = match item.link_id() {
+        Some(link_id) => link_id,
+        None => continue,
+      };
+      if !self.enabled.contains(link_id) {
+        continue;
+      }
+      if let Some(Questionnaire_ItemType::Display) = item.fhir_type() {
+        continue;
+      }
+      let children = self.response_items(&item.item().unwrap_or_default());
+      let answers = self.answers.answers(link_id);
+      if answers.is_empty() && children.is_empty() {
+        continue;
+      }
+      let mut builder = response_item(item);
+      if answers.is_empty() {
+        builder.item(to_items(&children));
+      } else {
+        let answers: Vec<Value> = answers
+          .iter()
+          .enumerate()
+          .map(|(index, answer)| {
+            let mut answer = answer.to_answer();
+            // Children of a question are nested under its answer.
+            if index == 0 && !children.is_empty() {
+              answer.item(to_items(&children));
+            }
+            answer.build().to_json()
+          })
+          .collect();
+        builder.answer(
+          answers
+            .iter()
+            .map(QuestionnaireResponse_Answer::new)
+            .collect(),
+        );
+      }
+      result.push(builder.build().to_json());
+    }
+    result
+  }
+}
+
+/// Builds an unanswered `in-progress` response that mirrors the structure of
+/// the questionnaire: one item per group and question, without display items.
+pub fn response_skeleton(questionnaire: &Questionnaire) -> QuestionnaireResponse<'static> {
+  let items = skeleton_items(&questionnaire.item().unwrap_or_default());
+  build_response(questionnaire, items)
+}
+
+fn skeleton_items(items: &[Questionnaire_Item]) -> Vec<Value> {
+  items
+    .iter()
+    .filter(|item| item.link_id().is_some())
+    .filter(|item| !matches!(item.fhir_type(), Some(Questionnaire_ItemType::Display)))
+    .map(|item| {
+      let mut builder = response_item(item);
+      let children = skeleton_items(&item.item().unwrap_or_default());
+      if !children.is_empty() {
+        builder.item(to_items(&children));
+      }
+      builder.build().to_json()
+    })
+    .collect()
+}
+
+fn response_item(item: &Questionnaire_Item) -> QuestionnaireResponse_ItemBuilder {
+  let mut builder = QuestionnaireResponse_ItemBuilder::new();
+  builder.link_id(item.link_id().unwrap_or_default());
+  if let Some(text) = item.text() {
+    builder.text(text);
+  }
+  if let Some(definition) = item.definition() {
+    builder.definition(definition);
+  }
+  builder
+}
+
+fn to_items(values: &[Value]) -> Vec<QuestionnaireResponse_Item<'_>> {
+  values.iter().map(QuestionnaireResponse_Item::new).collect()
+}
+
+fn build_response(
+  questionnaire: &Questionnaire,
+  items: Vec<Value>,
+) -> QuestionnaireResponse<'static> {
+  let mut builder = QuestionnaireResponseBuilder::new();
+  builder.status(QuestionnaireResponseStatus::InProgress);
+  if let Some(url) = questionnaire.url() {
+    builder.questionnaire(url);
+  } else if let Some(id) = questionnaire.id() {
+    builder.questionnaire(&format!("Questionnaire/{}", id));
+  }
+  if !items.is_empty() {
+    builder.item(to_items(&items));
+  }
+  QuestionnaireResponse {
+    value: Cow::Owned(builder.build().to_json()),
+  }
+}
+
+fn populate_initial(items: &[Questionnaire_Item], answers: &mut ResponseAnswers) {
+  for item in items {
+    if let Some(link_id) = item.link_id() {
+      if answers.answers(link_id).is_empty() {
+        let mut values: Vec<AnswerValue> = item
+          .initial()
+          .unwrap_or_default()
+          .iter()
+          .filter_map(AnswerValue::from_initial)
+          .collect();
+        if values.is_empty() {
+          values = item
+            .answer_option()
+            .unwrap_or_default()
+            .iter()
+            .filter(|option| option.initial_selected() == Some(true))
+            .filter_map(AnswerValue::from_answer_option)
+            .collect();
+        }
+        answers.set(link_id, values);
+      }
+    }
+    populate_initial(&item.item().unwrap_or_default(), answers);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn test_form_engine_updates_enabled_items() {
+    let questionnaire = json!({
+      "resourceType": "Questionnaire",
+      "url": "http://example.org/Questionnaire/intake",
+      "status": "active",
+      "item": [
+        {"linkId": "intro", "type": "display", "text": "Welcome"},
+        {"linkId": "smoker", "type": "boolean", "initial": [{"valueBoolean": false}]},
+        {
+          "linkId": "smoking", "type": "group",
+          "enableWhen": [{"question": "smoker", "operator": "=", "answerBoolean": true}],
+          "item": [{"linkId": "packs", "type": "integer", "initial": [{"valueInteger": 1}]}]
+        },
+        {
+          "linkId": "colour", "type": "choice",
+          "answerOption": [
+            {"valueString": "red"},
+            {"valueString": "blue", "initialSelected": true}
+          ]
+        }
+      ]
+    });
+    let questionnaire = Questionnaire::new(&questionnaire);
+
+    let skeleton = response_skeleton(&questionnaire).to_json();
+    assert_eq!(
+      skeleton["item"],
+      json!([
+        {"linkId": "smoker"},
+        {"linkId": "smoking", "item": [{"linkId": "packs"}]},
+        {"linkId": "colour"}
+      ])
+    );
+    assert_eq!(skeleton["status"], "in-progress");
+
+    let mut form = FormEngine::new(&questionnaire);
+    assert!(!form.is_enabled("smoking"));
+    assert_eq!(
+      form.answers("colour"),
+      &[AnswerValue::String("blue".to_string())]
+    );
+    let states = form.items();
+    assert!(!states[2].items[0].enabled);
+
+    let change = form.set_answers("smoker", vec![AnswerValue::Boolean(true)]);
+    assert_eq!(change.enabled, vec!["packs", "smoking"]);
+    assert!(change.disabled.is_empty());
+    assert_eq!(
+      form.response().to_json()["item"][1],
+      json!({"linkId": "smoking", "item": [{"linkId": "packs", "answer": [{"valueInteger": 1}]}]})
+    );
+
+    let change = form.clear_answers("smoker");
+    assert_eq!(change.disabled, vec!["packs", "smoking"]);
+    assert_eq!(
+      form.response().to_json()["item"].as_array().unwrap().len(),
+      1
+    );
+  }
+
+  #[test]
+  fn test_form_engine_resumes_partial_response() {
+    let questionnaire = json!({
+      "resourceType": "Questionnaire",
+      "id": "q",
+      "status": "active",
+      "item": [
+        {"linkId": "1", "type": "string", "initial": [{"valueString": "default"}]},
+        {"linkId": "2", "type": "string", "initial": [{"valueString": "default"}]}
+      ]
+    });
+    let response = json!({
+      "resourceType": "QuestionnaireResponse",
+      "status": "in-progress",
+      "item": [{"linkId": "1", "answer": [{"valueString": "given"}]}]
+    });
+    let questionnaire = Questionnaire::new(&questionnaire);
+    let form = FormEngine::with_response(&questionnaire, &QuestionnaireResponse::new(&response));
+    assert_eq!(
+      form.answers("1"),
+      &[AnswerValue::String("given".to_string())]
+    );
+    assert_eq!(
+      form.answers("2"),
+      &[AnswerValue::String("default".to_string())]
+    );
+    assert_eq!(
+      form.response().to_json()["questionnaire"],
+      "Questionnaire/q"
+    );
+  }
+}
@@ -0,0 +1,442 @@
+//! Runtime behaviour for `Questionnaire` and `QuestionnaireResponse`.
+//!
+//! The generated models expose each `value[x]` choice as a separate getter,
+//! which makes comparing answers awkward. `AnswerValue` folds those getters
+//! into a single comparable value that the `enable_when` and `validation`
+//! modules work with.
+
+pub mod enable_when;
+pub mod form;
+pub mod validation;
+
+pub use enable_when::{enabled_items, is_enabled, ResponseAnswers};
+pub use form::{response_skeleton, FormEngine, ItemState};
+pub use validation::{validate_response, QuestionnaireValidator, ValidationIssue};
+
+use crate::model::Attachment::Attachment;
+use crate::model::Coding::CodingBuilder;
+use crate::model::Quantity::QuantityBuilder;
+use crate::model::QuestionnaireResponse_Answer::{
+  QuestionnaireResponse_Answer, QuestionnaireResponse_AnswerBuilder,
+};
+use crate::model::Questionnaire_AnswerOption::Questionnaire_AnswerOption;
+use crate::model::Questionnaire_EnableWhen::Questionnaire_EnableWhen;
+use crate::model::Questionnaire_Initial::Questionnaire_Initial;
+use crate::model::Questionnaire_Item::Questionnaire_ItemType;
+use crate::model::Reference::ReferenceBuilder;
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+
+/// A single answer (or answer option, initial value or enableWhen operand)
+/// with its `[x]` type resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerValue {
+  Boolean(bool),
+  Decimal(f64),
+  Integer(i64),
+  Date(String),
+  DateTime(String),
+  Time(String),
+  String(String),
+  Uri(String),
+  Coding {
+    system: Option<String>,
+    code: Option<String>,
+    display: Option<String>,
+  },
+  Quantity {
+    value: Option<f64>,
+    unit: Option<String>,
+    system: Option<String>,
+    code: Option<String>,
+  },
+  Reference(Option<String>),
+  Attachment(Value),
+}
+
+fn owned(value: Option<&str>) -> Option<String> {
+  value.map(|s| s.to_string())
+}
+
+impl AnswerValue {
+  pub fn from_answer(answer: &QuestionnaireResponse_Answer) -> Option<AnswerValue> {
+    if let Some(val) = answer.value_boolean() {
+      return Some(AnswerValue::Boolean(val));
+    }
+    if let Some(val) = answer.value_decimal() {
+      return Some(AnswerValue::Decimal(val));
+    }
+    if let Some(val) = answer.value_integer() {
+      return Some(AnswerValue::Integer(val as i64));
+    }
+    if let Some(val) = answer.value_date() {
+      return Some(AnswerValue::Date(val.to_string()));
+    }
+    if let Some(val) = answer.value_date_time() {
+      return Some(AnswerValue::DateTime(val.to_string()));
+    }
+    if let Some(val) = answer.value_time() {
+      return Some(AnswerValue::Time(val.to_string()));
+    }
+    if let Some(val) = answer.value_string() {
+      return Some(AnswerValue::String(val.to_string()));
+    }
+    if let Some(val) = answer.value_uri() {
+      return Some(AnswerValue::Uri(val.to_string()));
+    }
+    if let Some(val) = answer.value_coding() {
+      return Some(AnswerValue::Coding {
+        system: owned(val.system()),
+        code: owned(val.code()),
+        display: owned(val.display()),
+      });
+    }
+    if let Some(val) = answer.value_quantity() {
+      return Some(AnswerValue::Quantity {
+        value: val.value(),
+        unit: owned(val.unit()),
+        system: owned(val.system()),
+        code: owned(val.code()),
+      });
+    }
+    if let Some(val) = answer.value_reference() {
+      return Some(AnswerValue::Reference(owned(val.reference())));
+    }
+    if let Some(val) = answer.value_attachment() {
+      return Some(AnswerValue::Attachment(val.to_json()));
+    }
+    None
+  }
+
+  pub fn from_enable_when(condition: &Questionnaire_EnableWhen) -> Option<AnswerValue> {
+    if let Some(val) = condition.answer_boolean() {
+      return Some(AnswerValue::Boolean(val));
+    }
+    if let Some(val) = condition.answer_decimal() {
+      return Some(AnswerValue::Decimal(val));
+    }
+    if let Some(val) = condition.answer_integer() {
+      return Some(AnswerValue::Integer(val as i64));
+    }
+    if let Some(val) = condition.answer_date() {
+      return Some(AnswerValue::Date(val.to_string()));
+    }
+    if let Some(val) = condition.answer_date_time() {
+      return Some(AnswerValue::DateTime(val.to_string()));
+    }
+    if let Some(val) = condition.answer_time() {
+      return Some(AnswerValue::Time(val.to_string()));
+    }
+    if let Some(val) = condition.answer_string() {
+      return Some(AnswerValue::String(val.to_string()));
+    }
+    if let Some(val) = condition.answer_coding() {
+      return Some(AnswerValue::Coding {
+        system: owned(val.system()),
+        code: owned(val.code()),
+        display: owned(val.display()),
+      });
+    }
+    if let Some(val) = condition.answer_quantity() {
+      return Some(AnswerValue::Quantity {
+        value: val.value(),
+        unit: owned(val.unit()),
+        system: owned(val.system()),
+        code: owned(val.code()),
+      });
+    }
+    if let Some(val) = condition.answer_reference() {
+      return Some(AnswerValue::Reference(owned(val.reference())));
+    }
+    None
+  }
+
+  pub fn from_answer_option(option: &Questionnaire_AnswerOption) -> Option<AnswerValue> {
+    if let Some(val) = option.value_integer() {
+      return Some(AnswerValue::Integer(val as i64));
+    }
+    if let Some(val) = option.value_date() {
+      return Some(AnswerValue::Date(val.to_string()));
+    }
+    if let Some(val) = option.value_time() {
+      return Some(AnswerValue::Time(val.to_string()));
+    }
+    if let Some(val) = option.value_string() {
+      return Some(AnswerValue::String(val.to_string()));
+    }
+    if let Some(val) = option.value_coding() {
+      return Some(AnswerValue::Coding {
+        system: owned(val.system()),
+        code: owned(val.code()),
+        display: owned(val.display()),
+      });
+    }
+    if let Some(val) = option.value_reference() {
+      return Some(AnswerValue::Reference(owned(val.reference())));
+    }
+    None
+  }
+
+  pub fn from_initial(initial: &Questionnaire_Initial) -> Option<AnswerValue> {
+    if let Some(val) = initial.value_boolean() {
+      return Some(AnswerValue::Boolean(val));
+    }
+    if let Some(val) = initial.value_decimal() {
+      return Some(AnswerValue::Decimal(val));
+    }
+    if let Some(val) = initial.value_integer() {
+      return Some(AnswerValue::Integer(val as i64));
+    }
+    if let Some(val) = initial.value_date() {
+      return Some(AnswerValue::Date(val.to_string()));
+    }
+    if let Some(val) = initial.value_date_time() {
+      return Some(AnswerValue::DateTime(val.to_string()));
+    }
+    if let Some(val) = initial.value_time() {
+      return Some(AnswerValue::Time(val.to_string()));
+    }
+    if let Some(val) = initial.value_string() {
+      return Some(AnswerValue::String(val.to_string()));
+    }
+    if let Some(val) = initial.value_uri() {
+      return Some(AnswerValue::Uri(val.to_string()));
+    }
+    if let Some(val) = initial.value_coding() {
+      return Some(AnswerValue::Coding {
+        system: owned(val.system()),
+        code: owned(val.code()),
+        display: owned(val.display()),
+      });
+    }
+    if let Some(val) = initial.value_quantity() {
+      return Some(AnswerValue::Quantity {
+        value: val.value(),
+        unit: owned(val.unit()),
+        system: owned(val.system()),
+        code: owned(val.code()),
+      });
+    }
+    if let Some(val) = initial.value_reference() {
+      return Some(AnswerValue::Reference(owned(val.reference())));
+    }
+    if let Some(val) = initial.value_attachment() {
+      return Some(AnswerValue::Attachment(val.to_json()));
+    }
+    None
+  }
+
+  /// Builds a `QuestionnaireResponse.item.answer` holding this value.
+  pub fn to_answer(&self) -> QuestionnaireResponse_AnswerBuilder {
+    let mut answer = QuestionnaireResponse_AnswerBuilder::new();
+    match self {
+      AnswerValue::Boolean(val) => {
+        answer.value_boolean(*val);
+      }
+      AnswerValue::Decimal(val) => {
+        answer.value_decimal(*val);
+      }
+      AnswerValue::Integer(val) => {
+        // The generated setter takes an f64, which would write `1.0`.
+        answer.value["valueInteger"] = json!(*val);
+      }
+      AnswerValue::Date(val) => {
+        answer.value_date(val);
+      }
+      AnswerValue::DateTime(val) => {
+        answer.value_date_time(val);
+      }
+      AnswerValue::Time(val) => {
+        answer.value_time(val);
+      }
+      AnswerValue::String(val) => {
+        answer.value_string(val);
+      }
+      AnswerValue::Uri(val) => {
+        answer.value_uri(val);
+      }
+      AnswerValue::Coding {
+        system,
+        code,
+        display,
+      } => {
+        let mut coding = CodingBuilder::new();
+        if let Some(system) = system {
+          coding.system(system);
+        }
+        if let Some(code) = code {
+          coding.code(code);
+        }
+        if let Some(display) = display {
+          coding.display(display);
+        }
+        answer.value_coding(coding.build());
+      }
+      AnswerValue::Quantity {
+        value,
+        unit,
+        system,
+        code,
+      } => {
+        let mut quantity = QuantityBuilder::new();
+        if let Some(value) = value {
+          quantity.value(*value);
+        }
+        if let Some(unit) = unit {
+          quantity.unit(unit);
+        }
+        if let Some(system) = system {
+          quantity.system(system);
+        }
+        if let Some(code) = code {
+          quantity.code(code);
+        }
+        answer.value_quantity(quantity.build());
+      }
+      AnswerValue::Reference(reference) => {
+        let mut builder = ReferenceBuilder::new();
+        if let Some(reference) = reference {
+          builder.reference(reference);
+        }
+        answer.value_reference(builder.build());
+      }
+      AnswerValue::Attachment(val) => {
+        answer.value_attachment(Attachment::new(val));
+      }
+    }
+    answer
+  }
+
+  /// The name of the `value[x]` type, e.g. `"Coding"` or `"dateTime"`.
+  pub fn type_name(&self) -> &'static str {
+    match self {
+      AnswerValue::Boolean(_) => "boolean",
+      AnswerValue::Decimal(_) => "decimal",
+      AnswerValue::Integer(_) => "integer",
+      AnswerValue::Date(_) => "date",
+      AnswerValue::DateTime(_) => "dateTime",
+      AnswerValue::Time(_) => "time",
+      AnswerValue::String(_) => "string",
+      AnswerValue::Uri(_) => "uri",
+      AnswerValue::Coding { .. } => "Coding",
+      AnswerValue::Quantity { .. } => "Quantity",
+      AnswerValue::Reference(_) => "Reference",
+      AnswerValue::Attachment(_) => "Attachment",
+    }
+  }
+
+  /// Whether this value is an allowed answer type for an item of the given
+  /// type, following the table in the `QuestionnaireResponse` specification.
+  pub fn is_valid_for(&self, item_type: &Questionnaire_ItemType) -> bool {
+    matches!(
+      (item_type, self),
+      (Questionnaire_ItemType::Boolean, AnswerValue::Boolean(_))
+        | (Questionnaire_ItemType::Decimal, AnswerValue::Decimal(_))
+        | (Questionnaire_ItemType::Integer, AnswerValue::Integer(_))
+        | (Questionnaire_ItemType::Date, AnswerValue::Date(_))
+        | (Questionnaire_ItemType::DateTime, AnswerValue::DateTime(_))
+        | (Questionnaire_ItemType::Time, AnswerValue::Time(_))
+        | (Questionnaire_ItemType::String, AnswerValue::String(_))
+        | (Questionnaire_ItemType::Text, AnswerValue::String(_))
+        | (Questionnaire_ItemType::Url, AnswerValue::Uri(_))
+        | (Questionnaire_ItemType::Choice, AnswerValue::Coding { .. })
+        | (
+          Questionnaire_ItemType::OpenChoice,
+          AnswerValue::Coding { .. }
+        )
+        | (Questionnaire_ItemType::OpenChoice, AnswerValue::String(_))
+        | (
+          Questionnaire_ItemType::Attachment,
+          AnswerValue::Attachment(_)
+        )
+        | (Questionnaire_ItemType::Reference, AnswerValue::Reference(_))
+        | (
+          Questionnaire_ItemType::Quantity,
+          AnswerValue::Quantity { .. }
+        )
+    )
+  }
+
+  /// Equality as used by `enableWhen` and `answerOption`: codings match on
+  /// system and code (a missing system on either side matches any system),
+  /// quantities on value and unit code, and numbers across integer/decimal.
+  pub fn matches(&self, other: &AnswerValue) -> bool {
+    match (self, other) {
+      (
+        AnswerValue::Coding {
+          system: s1,
+          code: c1,
+          ..
+        },
+        AnswerValue::Coding {
+          system: s2,
+          code: c2,
+          ..
+        },
+      ) => {
+        c1.is_some()
+          && c1 == c2
+          && match (s1, s2) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+          }
+      }
+      (
+        AnswerValue::Quantity {
+          value: v1,
+          code: c1,
+          unit: u1,
+          ..
+        },
+        AnswerValue::Quantity {
+          value: v2,
+          code: c2,
+          unit: u2,
+          ..
+        },
+      ) => v1 == v2 && (c1.as_ref().or(u1.as_ref()) == c2.as_ref().or(u2.as_ref())),
+      _ => self.compare(other) == Some(Ordering::Equal),
+    }
+  }
+
+  /// Ordering for the `<`, `>`, `<=` and `>=` enableWhen operators. Dates,
+  /// dateTimes and times are compared lexically, which is correct for values
+  /// of the same precision and timezone.
+  pub fn compare(&self, other: &AnswerValue) -> Option<Ordering> {
+    match (self, other) {
+      (AnswerValue::Boolean(a), AnswerValue::Boolean(b)) => a.partial_cmp(b),
+      (AnswerValue::Decimal(a), AnswerValue::Decimal(b)) => a.partial_cmp(b),
+      (AnswerValue::Integer(a), AnswerValue::Integer(b)) => a.partial_cmp(b),
+      (AnswerValue::Decimal(a), AnswerValue::Integer(b)) => a.partial_cmp(&(*b as f64)),
+      (AnswerValue::Integer(a), AnswerValue::Decimal(b)) => (*a as f64).partial_cmp(b),
+      (AnswerValue::Date(a), AnswerValue::Date(b))
+      | (AnswerValue::DateTime(a), AnswerValue::DateTime(b))
+      | (AnswerValue::Date(a), AnswerValue::DateTime(b))
+      | (AnswerValue::DateTime(a), AnswerValue::Date(b))
+      | (AnswerValue::Time(a), AnswerValue::Time(b))
+      | (AnswerValue::String(a), AnswerValue::String(b))
+      | (AnswerValue::Uri(a), AnswerValue::Uri(b)) => a.partial_cmp(b),
+      (AnswerValue::Reference(a), AnswerValue::Reference(b)) if a == b => Some(Ordering::Equal),
+      (
+        AnswerValue::Quantity {
+          value: Some(a),
+          code: c1,
+          ..
+        },
+        AnswerValue::Quantity {
+          value: Some(b),
+          code: c2,
+          ..
+        },
+      ) if c1 == c2 => a.partial_cmp(b),
+      _ => None,
+    }
+  }
+
+  pub fn string_length(&self) -> Option<usize> {
+    match self {
+      AnswerValue::String(s) | AnswerValue::Uri(s) => Some(s.chars().count()),
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,563 @@
+//! Validation of a `QuestionnaireResponse` against its `Questionnaire`.
+
+use super::enable_when::{enabled_items, ResponseAnswers};
+use super::AnswerValue;
+use crate::model::Questionnaire::Questionnaire;
+use crate::model::QuestionnaireResponse::QuestionnaireResponse;
+use crate::model::QuestionnaireResponse_Item::QuestionnaireResponse_Item;
+use crate::model::Questionnaire_Item::{Questionnaire_Item, Questionnaire_ItemType};
+use crate::model::ResourceList::ResourceListEnum;
+use crate::model::ValueSet::ValueSet;
+use crate::model::ValueSet_Contains::ValueSet_Contains;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueSeverity {
+  Error,
+  Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueKind {
+  /// `QuestionnaireResponse.questionnaire` does not point at this questionnaire.
+  QuestionnaireMismatch,
+  /// A response item has a linkId that the questionnaire does not define at
+  /// that level.
+  UnknownItem,
+  MissingRequired,
+  RepeatsNotAllowed,
+  /// A group or display item carries answers.
+  AnswerNotAllowed,
+  WrongAnswerType,
+  NotInAnswerOptions,
+  NotInValueSet,
+  /// The answer value set could not be resolved, so membership was not checked.
+  UnknownValueSet,
+  MaxLengthExceeded,
+  /// An item that is disabled by its enableWhen conditions has answers.
+  AnsweredWhileDisabled,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+  pub severity: IssueSeverity,
+  pub kind: IssueKind,
+  pub link_id: Option<String>,
+  /// A FHIRPath-style location in the response, e.g.
+  /// `QuestionnaireResponse.item[1].answer[0]`.
+  pub path: String,
+  pub message: String,
+}
+
+/// Validates responses against a questionnaire.
+///
+/// ValueSets contained in the questionnaire are available to `answerValueSet`
+/// as `#id`. Other value sets can be registered with `add_value_set`; only
+/// enumerated `compose.include.concept` and `expansion.contains` codes are
+/// used, filters are not evaluated.
+pub struct QuestionnaireValidator<'a, 'b> {
+  questionnaire: &'a Questionnaire<'b>,
+  value_sets: HashMap<String, Vec<(Option<String>, String)>>,
+}
+
+impl<'a, 'b> QuestionnaireValidator<'a, 'b> {
+  pub fn new(questionnaire: &'a Questionnaire<'b>) -> QuestionnaireValidator<'a, 'b> {
+    let mut validator = QuestionnaireValidator {
+      questionnaire,
+      value_sets: HashMap::new(),
+    };
+    let contained = questionnaire.contained().unwrap_or_default();
+    for resource in &contained {
+      if let Some(ResourceListEnum::ResourceValueSet(value_set)) = resource.resource() {
+        if let Some(id) = value_set.id() {
+          let codes = value_set_codes(&value_set);
+          validator.value_sets.insert(format!("#{}", id), codes);
+        }
+      }
+    }
+    validator
+  }
+
+  /// Registers a value set under its canonical `url`.
+  pub fn add_value_set(&mut self, value_set: &ValueSet) -> &mut QuestionnaireValidator<'a, 'b> {
+    if let Some(url) = value_set.url() {
+      self
+        .value_sets
+        .insert(url.to_string(), value_set_codes(value_set));
+    }
+    self
+  }
+
+  pub fn validate(&self, response: &QuestionnaireResponse) -> Vec<ValidationIssue> {
+    let mut issues = vec![];
+    if let Some(reference) = response.questionnaire() {
+      if !self.is_questionnaire_reference(reference) {
+        issues.push(ValidationIssue {
+          severity: IssueSeverity::Warning,
+          kind: IssueKind::QuestionnaireMismatch,
+          link_id: None,
+          path: "QuestionnaireResponse.questionnaire".to_string(),
+          message: format!("Response is for questionnaire '{}'", reference),
+        });
+      }
+    }
+    let answers = ResponseAnswers::from_response(response);
+    let enabled = enabled_items(self.questionnaire, &answers);
+    let questions = self.questionnaire.item().unwrap_or_default();
+    let given = response.item().unwrap_or_default();
+    self.validate_items(
+      &questions,
+      &given,
+      "QuestionnaireResponse",
+      &enabled,
+      &mut issues,
+    );
+    issues
+  }
+
+  fn is_questionnaire_reference(&self, reference: &str) -> bool {
+    let canonical = reference.split('|').next().unwrap_or(reference);
+    if let Some(url) = self.questionnaire.url() {
+      if canonical == url {
+        return true;
+      }
+    }
+    if let Some(id) = self.questionnaire.id() {
+      if canonical == format!("Questionnaire/{}", id)
+        || canonical.ends_with(&format!("/Questionnaire/{}", id))
+      {
+        return true;
+      }
+    }
+    self.questionnaire.url().is_none() && self.questionnaire.id().is_none()
+  }
+
+  fn validate_items(
+    &self,
+    questions: &[Questionnaire_Item],
+    given: &[QuestionnaireResponse_Item],
+    path: &str,
+    enabled: &HashSet<String>,
+    issues: &mut Vec<ValidationIssue>,
+  ) {
+    for (index, item) in given.iter().enumerate() {
+      let known = questions
+        .iter()
+        .any(|q| q.link_id().is_some() && q.link_id() == item.link_id());
+      if !known {
+        issues.push(ValidationIssue {
+          severity: IssueSeverity::Error,
+          kind: IssueKind::UnknownItem,
+          link_id: item.link_id().map(|s| s.to_string()),
+          path: format!("{}.item[{}]", path, index),
+          message: format!(
+            "Item '{}' is not defined by the questionnaire at this level",
+            item.link_id().unwrap_or("")
+          ),
+        });
+      }
+    }
+
+    for question in questions {
+      let link_id = match question.link_id() {
+        Some(link_id) => link_id,
+        None => continue,
+      };
+      let occurrences: Vec<(usize, &QuestionnaireResponse_Item)> = given
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.link_id() == Some(link_id))
+        .collect();
+      let item_type = question.fhir_type();
+
+      if !enabled.contains(link_id) {
+        for (index, item) in &occurrences {
+          if has_answers(item) {
+            issues.push(issue(
+              IssueSeverity::Warning,
+              IssueKind::AnsweredWhileDisabled,
+              link_id,
+              format!("{}.item[{}]", path, index),
+              "Item is disabled by its enableWhen conditions but has answers",
+            ));
+          }
+        }
+        continue;
+      }
+
+      if let Some(Questionnaire_ItemType::Group) = item_type {
+        self.validate_group(question, &occurrences, path, enabled, issues);
+        continue;
+      }
+      if let Some(Questionnaire_ItemType::Display) = item_type {
+        for (index, item) in &occurrences {
+          if has_answers(item) {
+            issues.push(issue(
+              IssueSeverity::Error,
+              IssueKind::AnswerNotAllowed,
+              link_id,
+              format!("{}.item[{}]", path, index),
+              "Display items cannot have answers",
+            ));
+          }
+        }
+        continue;
+      }
+
+      if occurrences.len() > 1 {
+        let (index, _) = occurrences[1];
+        issues.push(issue(
+          IssueSeverity::Error,
+          IssueKind::RepeatsNotAllowed,
+          link_id,
+          format!("{}.item[{}]", path, index),
+          "Question items must appear once; repeated answers belong in item.answer",
+        ));
+      }
+      let answer_count: usize = occurrences
+        .iter()
+        .map(|(_, item)| item.answer().map(|a| a.len()).unwrap_or(0))
+        .sum();
+      if answer_count == 0 && question.required() == Some(true) {
+        issues.push(issue(
+          IssueSeverity::Error,
+          IssueKind::MissingRequired,
+          link_id,
+          match occurrences.first() {
+            Some((index, _)) => format!("{}.item[{}]", path, index),
+            None => path.to_string(),
+          },
+          "Required question has no answer",
+        ));
+      }
+      if answer_count > 1 && question.repeats() != Some(true) {
+        issues.push(issue(
+          IssueSeverity::Error,
+          IssueKind::RepeatsNotAllowed,
+          link_id,
+          format!("{}.item[{}]", path, occurrences[0].0),
+          "Question does not allow more than one answer",
+        ));
+      }
+      for (index, item) in &occurrences {
+        let item_path = format!("{}.item[{}]", path, index);
+        self.validate_answers(question, item, &item_path, enabled, issues);
+      }
+    }
+  }
+
+  fn validate_group(
+    &self,
+    question: &Questionnaire_Item,
+    occurrences: &[(usize, &QuestionnaireResponse_Item)],
+    path: &str,
+    enabled: &HashSet<String>,
+    issues: &mut Vec<ValidationIssue>,
+  ) {
+    let link_id = question.link_id().unwrap_or("");
+    if occurrences.is_empty() && question.required() == Some(true) {
+      issues.push(issue(
+        IssueSeverity::Error,
+        IssueKind::MissingRequired,
+        link_id,
+        path.to_string(),
+        "Required group is missing",
+      ));
+    }
+    if occurrences.len() > 1 && question.repeats() != Some(true) {
+      issues.push(issue(
+        IssueSeverity::Error,
+        IssueKind::RepeatsNotAllowed,
+        link_id,
+        format!("{}.item[{}]", path, occurrences[1].0),
+        "Group does not repeat",
+      ));
+    }
+    let children = question.item().unwrap_or_default();
+    for (index, item) in occurrences {
+      let item_path = format!("{}.item[{}]", path, index);
+      if has_answers(item) {
+        issues.push(issue(
+          IssueSeverity::Error,
+          IssueKind::AnswerNotAllowed,
+          link_id,
+          item_path.clone(),
+          "Group items cannot have answers",
+        ));
+      }
+      let given = item.item().unwrap_or_default();
+      self.validate_items(&children, &given, &item_path, enabled, issues);
+    }
+  }
+
+  fn validate_answers(
+    &self,
+    question: &Questionnaire_Item,
+    item: &QuestionnaireResponse_Item,
+    item_path: &str,
+    enabled: &HashSet<String>,
+    issues: &mut Vec<ValidationIssue>,
+  ) {
+    let link_id = question.link_id().unwrap_or("");
+    let children = question.item().unwrap_or_default();
+    let options: Vec<AnswerValue> = question
+      .answer_option()
+      .unwrap_or_default()
+      .iter()
+      .filter_map(AnswerValue::from_answer_option)
+      .collect();
+    let mut reported_value_set = false;
+
+    for (index, answer) in item.answer().unwrap_or_default().iter().enumerate() {
+      let answer_path = format!("{}.answer[{}]", item_path, index);
+      let value = match AnswerValue::from_answer(answer) {
+        Some(value) => value,
+        None => continue,
+      };
+
+      if let Some(item_type) = question.fhir_type() {
+        if !value.is_valid_for(&item_type) {
+          issues.push(issue(
+            IssueSeverity::Error,
+            IssueKind::WrongAnswerType,
+            link_id,
+            answer_path.clone(),
+            &format!(
+              "Answer of type {} is not valid for a '{}' question",
+              value.type_name(),
+              item_type.to_string()
+            ),
+          ));
+          continue;
+        }
+      }
+
+      if let (Some(max_length), Some(length)) = (question.max_length(), value.string_length()) {
+        if length as i64 > max_length {
+          issues.push(issue(
+            IssueSeverity::Error,
+            IssueKind::MaxLengthExceeded,
+            link_id,
+            answer_path.clone(),
+            &format!("Answer is {} characters, maximum is {}", length, max_length),
+          ));
+        }
+      }
+
+      // Free-text answers to open-choice questions need not be in the list.
+      let is_free_text = matches!(
+        (question.fhir_type(), &value),
+        (
+          Some(Questionnaire_ItemType::OpenChoice),
+          AnswerValue::String(_)
+        )
+      );
+      if !options.is_empty() && !is_free_text && !options.iter().any(|o| o.matches(&value)) {
+        issues.push(issue(
+          IssueSeverity::Error,
+          IssueKind::NotInAnswerOptions,
+          link_id,
+          answer_path.clone(),
+          "Answer is not one of the permitted answer options",
+        ));
+      }
+
+      if let (Some(url), AnswerValue::Coding { system, code, .. }) =
+        (question.answer_value_set(), &value)
+      {
+        match self.value_sets.get(url) {
+          Some(codes) => {
+            let member = codes.iter().any(|(s, c)| {
+              Some(c) == code.as_ref()
+                && match (s, system) {
+                  (Some(a), Some(b)) => a == b,
+                  _ => true,
+                }
+            });
+            if !member {
+              issues.push(issue(
+                IssueSeverity::Error,
+                IssueKind::NotInValueSet,
+                link_id,
+                answer_path.clone(),
+                &format!("Answer is not in value set '{}'", url),
+              ));
+            }
+          }
+          None if !reported_value_set => {
+            reported_value_set = true;
+            issues.push(issue(
+              IssueSeverity::Warning,
+              IssueKind::UnknownValueSet,
+              link_id,
+              answer_path.clone(),
+              &format!("Value set '{}' is not available to the validator", url),
+            ));
+          }
+          None => {}
+        }
+      }
+
+      let nested = answer.item().unwrap_or_default();
+      self.validate_items(&children, &nested, &answer_path, enabled, issues);
+    }
+
+    let nested = item.item().unwrap_or_default();
+    if !nested.is_empty() {
+      self.validate_items(&children, &nested, item_path, enabled, issues);
+    }
+  }
+}
+
+/// Validates `response` against `questionnaire` using only the value sets
+/// contained in the questionnaire.
+pub fn validate_response(
+  questionnaire: &Questionnaire,
+  response: &QuestionnaireResponse,
+) -> Vec<ValidationIssue> {
+  QuestionnaireValidator::new(questionnaire).validate(response)
+}
+
+fn issue(
+  severity: IssueSeverity,
+  kind: IssueKind,
+  link_id: &str,
+  path: String,
+  message: &str,
+) -> ValidationIssue {
+  ValidationIssue {
+    severity,
+    kind,
+    link_id: Some(link_id.to_string()),
+    path,
+    message: message.to_string(),
+  }
+}
+
+fn has_answers(item: &QuestionnaireResponse_Item) -> bool {
+  item.answer().map(|a| !a.is_empty()).unwrap_or(false)
+}
+
+fn value_set_codes(value_set: &ValueSet) -> Vec<(Option<String>, String)> {
+  let mut codes = vec![];
+  if let Some(compose) = value_set.compose() {
+    for include in compose.include() {
+      for concept in include.concept().unwrap_or_default() {
+        if let Some(code) = concept.code() {
+          codes.push((include.system().map(|s| s.to_string()), code.to_string()));
+        }
+      }
+    }
+  }
+  if let Some(expansion) = value_set.expansion() {
+    expansion_codes(&expansion.contains().unwrap_or_default(), &mut codes);
+  }
+  codes
+}
+
+fn expansion_codes(contains: &[ValueSet_Contains], codes: &mut Vec<(Option<String>, String)>) {
+  for entry in contains {
+    if let Some(code) = entry.code() {
+      codes.push((entry.system().map(|s| s.to_string()), code.to_string()));
+    }
+    expansion_codes(&entry.contains().unwrap_or_default(), codes);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::{json, Value};
+  use std::fs;
+
+  fn load(name: &str) -> Value {
+    let contents = fs::read_to_string(format!("examples-json/{}", name)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+  }
+
+  #[test]
+  fn test_validate_example_responses() {
+    let questionnaire = load("questionnaire-example-gcs.json");
+    let response = load("questionnaireresponse-example-gcs.json");
+    let issues = validate_response(
+      &Questionnaire::new(&questionnaire),
+      &QuestionnaireResponse::new(&response),
+    );
+    assert_eq!(issues, vec![]);
+
+    // The lifelines response answers boolean questions with free text and
+    // nests a question that the questionnaire does not define.
+    let questionnaire = load("questionnaire-example-f201-lifelines.json");
+    let response = load("questionnaireresponse-example-f201-lifelines.json");
+    let issues = validate_response(
+      &Questionnaire::new(&questionnaire),
+      &QuestionnaireResponse::new(&response),
+    );
+    let kinds: Vec<_> = issues.iter().map(|i| (i.kind, i.path.as_str())).collect();
+    assert!(kinds.contains(&(
+      IssueKind::UnknownItem,
+      "QuestionnaireResponse.item[0].item[0]"
+    )));
+    assert!(kinds.contains(&(
+      IssueKind::WrongAnswerType,
+      "QuestionnaireResponse.item[2].item[0].answer[0]"
+    )));
+  }
+
+  #[test]
+  fn test_validate_enable_when_and_constraints() {
+    let questionnaire = json!({
+      "resourceType": "Questionnaire",
+      "url": "http://example.org/Questionnaire/intake",
+      "status": "active",
+      "item": [
+        {"linkId": "smoker", "type": "boolean", "required": true},
+        {
+          "linkId": "packs", "type": "integer", "required": true,
+          "enableWhen": [{"question": "smoker", "operator": "=", "answerBoolean": true}]
+        },
+        {"linkId": "initials", "type": "string", "maxLength": 3},
+        {
+          "linkId": "colour", "type": "choice",
+          "answerOption": [{"valueCoding": {"system": "http://example.org", "code": "red"}}]
+        }
+      ]
+    });
+    let questionnaire = Questionnaire::new(&questionnaire);
+
+    let response = json!({
+      "resourceType": "QuestionnaireResponse",
+      "questionnaire": "http://example.org/Questionnaire/intake",
+      "status": "completed",
+      "item": [{"linkId": "smoker", "answer": [{"valueBoolean": false}]}]
+    });
+    assert_eq!(
+      validate_response(&questionnaire, &QuestionnaireResponse::new(&response)),
+      vec![]
+    );
+
+    let response = json!({
+      "resourceType": "QuestionnaireResponse",
+      "questionnaire": "http://example.org/Questionnaire/intake",
+      "status": "completed",
+      "item": [
+        {"linkId": "smoker", "answer": [{"valueBoolean": true}]},
+        {"linkId": "initials", "answer": [{"valueString": "ABCD"}, {"valueString": "E"}]},
+        {"linkId": "colour", "answer": [{"valueCoding": {"system": "http://example.org", "code": "blue"}}]}
+      ]
+    });
+    let kinds: Vec<_> = validate_response(&questionnaire, &QuestionnaireResponse::new(&response))
+      .into_iter()
+      .map(|i| (i.kind, i.link_id.unwrap()))
+      .collect();
+    assert_eq!(
+      kinds,
+      vec![
+        (IssueKind::MissingRequired, "packs".to_string()),
+        (IssueKind::RepeatsNotAllowed, "initials".to_string()),
+        (IssueKind::MaxLengthExceeded, "initials".to_string()),
+        (IssueKind::NotInAnswerOptions, "colour".to_string()),
+      ]
+    );
+  }
+}
@@ -0,0 +1,395 @@
+//! The graph of references between the entries of a Bundle.
+//!
+//! Every `Reference` anywhere in an entry's resource, including inside
+//! `contained` resources, becomes an edge from that entry to the entry it
+//! resolves to (see `BundleResolver` for the resolution rules).
+
+use super::resolver::BundleResolver;
+use super::target::{ReferenceKind, ReferenceTarget};
+use crate::model::Bundle::Bundle;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// A reference from one entry to another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+  pub from: usize,
+  pub to: usize,
+  /// Where the reference is, e.g. `Composition.section[0].entry[1]`.
+  pub path: String,
+}
+
+/// A reference that does not resolve within the Bundle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnresolvedReference {
+  pub entry: usize,
+  pub path: String,
+  pub reference: String,
+  /// Whether the reference must resolve within the Bundle: `urn:` and
+  /// contained references always, and every literal reference in a
+  /// document or message.
+  pub internal: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphIssueKind {
+  /// A reference that must resolve within the Bundle does not.
+  Dangling,
+  /// An entry of a document or message that cannot be reached from the
+  /// first entry.
+  Unreferenced,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphIssue {
+  pub kind: GraphIssueKind,
+  pub entry: usize,
+  pub path: String,
+  pub message: String,
+}
+
+impl fmt::Display for GraphIssue {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "entry[{}] {}: {}", self.entry, self.path, self.message)
+  }
+}
+
+/// The reference graph of a Bundle. Entries are identified by their index.
+#[derive(Debug, Clone)]
+pub struct BundleGraph {
+  bundle_type: Option<String>,
+  entries: usize,
+  edges: Vec<Edge>,
+  unresolved: Vec<UnresolvedReference>,
+}
+
+/// The paths of all `Reference` elements below `value`, with their
+/// `reference` strings. References in `contained` resources are reported
+/// as references of the container.
+pub(crate) fn collect_references(value: &Value, path: &str, found: &mut Vec<(String, String)>) {
+  match value {
+    Value::Object(map) => {
+      if let Some(Value::String(reference)) = map.get("reference") {
+        found.push((path.to_string(), reference.clone()));
+      }
+      for (key, child) in map {
+        if key != "reference" {
+          collect_references(child, &format!("{}.{}", path, key), found);
+        }
+      }
+    }
+    Value::Array(items) => {
+      for (i, item) in items.iter().enumerate() {
+        collect_references(item, &format!("{}[{}]", path, i), found);
+      }
+    }
+    _ => {}
+  }
+}
+
+impl BundleGraph {
+  pub fn new(bundle: &Bundle) -> BundleGraph {
+    let value = &bundle.value;
+    let resolver = BundleResolver::new(bundle);
+    let entries = value["entry"].as_array().map(|e| e.len()).unwrap_or(0);
+    let bundle_type = value["type"].as_str().map(|t| t.to_string());
+    let self_contained = matches!(bundle_type.as_deref(), Some("document") | Some("message"));
+    let mut graph = BundleGraph {
+      bundle_type,
+      entries,
+      edges: vec![],
+      unresolved: vec![],
+    };
+    for index in 0..entries {
+      let entry = &value["entry"][index];
+      let resource = &entry["resource"];
+      let resource_type = resource["resourceType"].as_str().unwrap_or("Resource");
+      let contained: BTreeSet<&str> = resource["contained"]
+        .as_array()
+        .map(|c| c.iter().filter_map(|r| r["id"].as_str()).collect())
+        .unwrap_or_default();
+      let scoped = match entry["fullUrl"].as_str() {
+        Some(full_url) => resolver.for_entry(full_url),
+        None => resolver.for_entry(""),
+      };
+      let mut references = vec![];
+      collect_references(resource, resource_type, &mut references);
+      for (path, reference) in references {
+        let target = ReferenceTarget::parse(&reference);
+        let (to, internal) = match &target {
+          Some(target) if target.kind == ReferenceKind::Contained => {
+            let found = match &target.id {
+              Some(id) => contained.contains(id.as_str()),
+              None => true,
+            };
+            if found {
+              continue;
+            }
+            (None, true)
+          }
+          Some(target) => (
+            scoped.resolve_entry(target),
+            target.kind == ReferenceKind::Urn || self_contained,
+          ),
+          // Conditional references such as `Patient?identifier=...`.
+          None => (None, self_contained),
+        };
+        match to {
+          Some(to) => graph.edges.push(Edge {
+            from: index,
+            to,
+            path,
+          }),
+          None => graph.unresolved.push(UnresolvedReference {
+            entry: index,
+            path,
+            reference,
+            internal,
+          }),
+        }
+      }
+    }
+    graph
+  }
+
+  pub fn edges(&self) -> &[Edge] {
+    &self.edges
+  }
+
+  /// The edges out of an entry.
+  pub fn references_from(&self, entry: usize) -> impl Iterator<Item = &Edge> {
+    self.edges.iter().filter(move |e| e.from == entry)
+  }
+
+  /// The edges into an entry.
+  pub fn referenced_by(&self, entry: usize) -> impl Iterator<Item = &Edge> {
+    self.edges.iter().filter(move |e| e.to == entry)
+  }
+
+  /// References that do not resolve within the Bundle, whether or not they
+  /// have to.
+  pub fn unresolved(&self) -> &[UnresolvedReference] {
+    &self.unresolved
+  }
+
+  /// References that must resolve within the Bundle but do not.
+  pub fn dangling(&self) -> Vec<&UnresolvedReference> {
+    self.unresolved.iter().filter(|r| r.internal).collect()
+  }
+
+  /// The entries reachable from `root` by following references, including
+  /// `root`.
+  pub fn reachable_from(&self, root: usize) -> BTreeSet<usize> {
+    let mut reached = BTreeSet::new();
+    let mut pending = vec![root];
+    while let Some(entry) = pending.pop() {
+      if entry < self.entries && reached.insert(entry) {
+        pending.extend(self.references_from(entry).map(|e| e.to));
+      }
+    }
+    reached
+  }
+
+  /// The entries that cannot be reached from `root`.
+  pub fn unreachable_from(&self, root: usize) -> Vec<usize> {
+    let reached = self.reachable_from(root);
+    (0..self.entries).filter(|i| !reached.contains(i)).collect()
+  }
+
+  /// Groups of entries that reference each other in a cycle, each in entry
+  /// order. An entry that references itself is a cycle of one.
+  pub fn cycles(&self) -> Vec<Vec<usize>> {
+    // Tarjan's strongly connected components.
+    struct State {
+      index: Vec<Option<usize>>,
+      low: Vec<usize>,
+      on_stack: Vec<bool>,
+      stack: Vec<usize>,
+      next: usize,
+      components: Vec<Vec<usize>>,
+    }
+    fn visit(graph: &BundleGraph, node: usize, state: &mut State) {
+      state.index[node] = Some(state.next);
+      state.low[node] = state.next;
+      state.next += 1;
+      state.stack.push(node);
+      state.on_stack[node] = true;
+      for edge in graph.references_from(node) {
+        match state.index[edge.to] {
+          None => {
+            visit(graph, edge.to, state);
+            state.low[node] = state.low[node].min(state.low[edge.to]);
+          }
+          Some(index) if state.on_stack[edge.to] => {
+            state.low[node] = state.low[node].min(index);
+          }
+          _ => {}
+        }
+      }
+      if Some(state.low[node]) == state.index[node] {
+        let mut component = vec![];
+        while let Some(member) = state.stack.pop() {
+          state.on_stack[member] = false;
+          component.push(member);
+          if member == node {
+            break;
+          }
+        }
+        component.sort_unstable();
+        state.components.push(component);
+      }
+    }
+
+    let mut state = State {
+      index: vec![None; self.entries],
+      low: vec![0; self.entries],
+      on_stack: vec![false; self.entries],
+      stack: vec![],
+      next: 0,
+      components: vec![],
+    };
+    for node in 0..self.entries {
+      if state.index[node].is_none() {
+        visit(self, node, &mut state);
+      }
+    }
+    let mut cycles: Vec<Vec<usize>> = state
+      .components
+      .into_iter()
+      .filter(|c| c.len() > 1 || self.references_from(c[0]).any(|e| e.to == c[0]))
+      .collect();
+    cycles.sort();
+    cycles
+  }
+
+  /// An order in which the entries can be created so that every entry comes
+  /// after the entries it references, with ties in entry order. Fails with
+  /// the entries that form cycles.
+  pub fn creation_order(&self) -> Result<Vec<usize>, Vec<Vec<usize>>> {
+    let mut dependencies: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); self.entries];
+    for edge in &self.edges {
+      if edge.from != edge.to {
+        dependencies[edge.from].insert(edge.to);
+      }
+    }
+    let mut order = vec![];
+    let mut done = vec![false; self.entries];
+    while order.len() < self.entries {
+      let ready = (0..self.entries).find(|&i| !done[i] && dependencies[i].iter().all(|&d| done[d]));
+      match ready {
+        Some(entry) => {
+          done[entry] = true;
+          order.push(entry);
+        }
+        None => {
+          let cycles = self.cycles().into_iter().filter(|c| c.len() > 1).collect();
+          return Err(cycles);
+        }
+      }
+    }
+    Ok(order)
+  }
+
+  /// Dangling references, and for documents and messages the entries that
+  /// the first entry (the `Composition` or `MessageHeader`) does not lead
+  /// to.
+  pub fn check(&self) -> Vec<GraphIssue> {
+    let mut issues: Vec<GraphIssue> = self
+      .dangling()
+      .into_iter()
+      .map(|r| GraphIssue {
+        kind: GraphIssueKind::Dangling,
+        entry: r.entry,
+        path: r.path.clone(),
+        message: format!(
+          "Reference '{}' does not resolve within the Bundle",
+          r.reference
+        ),
+      })
+      .collect();
+    if matches!(
+      self.bundle_type.as_deref(),
+      Some("document") | Some("message")
+    ) && self.entries > 0
+    {
+      for entry in self.unreachable_from(0) {
+        issues.push(GraphIssue {
+          kind: GraphIssueKind::Unreferenced,
+          entry,
+          path: String::new(),
+          message: "The entry is not referenced from the first entry".to_string(),
+        });
+      }
+    }
+    issues
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use std::fs;
+
+  #[test]
+  fn test_document_graph() {
+    let contents =
+      fs::read_to_string("examples-json/document-example-dischargesummary.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let graph = BundleGraph::new(&Bundle::new(&value));
+    assert!(graph
+      .edges()
+      .iter()
+      .any(|e| e.from == 0 && e.to == 2 && e.path == "Composition.subject"));
+    assert!(graph
+      .edges()
+      .iter()
+      .any(|e| e.from == 0 && e.to == 4 && e.path.starts_with("Composition.section[")));
+    assert_eq!(graph.unreachable_from(0), Vec::<usize>::new());
+    assert_eq!(graph.check(), vec![]);
+  }
+
+  #[test]
+  fn test_transaction_graph() {
+    let value = json!({
+      "resourceType": "Bundle",
+      "type": "transaction",
+      "entry": [
+        {"fullUrl": "urn:uuid:obs", "resource": {"resourceType": "Observation",
+          "subject": {"reference": "urn:uuid:pat"},
+          "performer": [{"reference": "Practitioner/existing"}],
+          "hasMember": [{"reference": "urn:uuid:gone"}]}},
+        {"fullUrl": "urn:uuid:pat", "resource": {"resourceType": "Patient",
+          "contained": [{"resourceType": "Organization", "id": "org"}],
+          "managingOrganization": {"reference": "#org"},
+          "generalPractitioner": [{"reference": "#missing"}]}},
+        {"fullUrl": "urn:uuid:a", "resource": {"resourceType": "Basic",
+          "subject": {"reference": "urn:uuid:b"}}},
+        {"fullUrl": "urn:uuid:b", "resource": {"resourceType": "Basic",
+          "subject": {"reference": "urn:uuid:a"}}}
+      ]
+    });
+    let graph = BundleGraph::new(&Bundle::new(&value));
+    let dangling: Vec<(usize, &str)> = graph
+      .dangling()
+      .iter()
+      .map(|r| (r.entry, r.path.as_str()))
+      .collect();
+    assert_eq!(
+      dangling,
+      vec![
+        (0, "Observation.hasMember[0]"),
+        (1, "Patient.generalPractitioner[0]")
+      ]
+    );
+    assert_eq!(graph.unresolved().len(), 3);
+    assert_eq!(graph.cycles(), vec![vec![2, 3]]);
+    assert_eq!(graph.creation_order(), Err(vec![vec![2, 3]]));
+
+    let mut acyclic = value.clone();
+    acyclic["entry"].as_array_mut().unwrap().truncate(3);
+    let graph = BundleGraph::new(&Bundle::new(&acyclic));
+    assert_eq!(graph.creation_order(), Ok(vec![1, 0, 2]));
+  }
+}
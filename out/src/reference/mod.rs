@@ -0,0 +1,11 @@
+//! Parsing of `Reference.reference` and resolution of references against
+//! contained resources, Bundles and local stores, and the reference graph of
+//! a Bundle.
+
+pub mod graph;
+pub mod resolver;
+pub mod target;
+
+pub use graph::{BundleGraph, Edge, GraphIssue, GraphIssueKind, UnresolvedReference};
+pub use resolver::{BundleResolver, ContainedResolver, ReferenceResolver, ResolverChain};
+pub use target::{ReferenceKind, ReferenceTarget};
@@ -0,0 +1,253 @@
+//! Resolution of references to resources.
+//!
+//! Resolvers are combined with `ResolverChain`: typically the referring
+//! resource's `contained`, then the Bundle it arrived in, then a local store.
+
+use super::target::{ReferenceKind, ReferenceTarget};
+use crate::model::Bundle::Bundle;
+use crate::model::Reference::Reference;
+use crate::model::ResourceList::ResourceList;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Finds the resource a reference points to.
+pub trait ReferenceResolver {
+  fn resolve(&self, target: &ReferenceTarget) -> Option<ResourceList<'_>>;
+}
+
+/// A store keyed by `[type]/[id]`. Versioned references resolve only when
+/// the stored resource has that `meta.versionId`.
+impl ReferenceResolver for HashMap<String, Value> {
+  fn resolve(&self, target: &ReferenceTarget) -> Option<ResourceList<'_>> {
+    if target.kind == ReferenceKind::Contained {
+      return None;
+    }
+    let resource = self.get(&target.relative()?)?;
+    if !has_version(resource, target.version.as_deref()) {
+      return None;
+    }
+    Some(ResourceList::new(resource))
+  }
+}
+
+fn has_version(resource: &Value, version: Option<&str>) -> bool {
+  match version {
+    Some(version) => resource["meta"]["versionId"].as_str() == Some(version),
+    None => true,
+  }
+}
+
+/// Resolves `#id` references against the referring resource's `contained`.
+/// `#` on its own resolves to the referring resource.
+pub struct ContainedResolver<'a> {
+  container: &'a Value,
+}
+
+impl<'a> ContainedResolver<'a> {
+  pub fn new(container: &'a ResourceList) -> ContainedResolver<'a> {
+    ContainedResolver {
+      container: &container.value,
+    }
+  }
+}
+
+impl ReferenceResolver for ContainedResolver<'_> {
+  fn resolve(&self, target: &ReferenceTarget) -> Option<ResourceList<'_>> {
+    if target.kind != ReferenceKind::Contained {
+      return None;
+    }
+    let id = match &target.id {
+      Some(id) => id,
+      None => return Some(ResourceList::new(self.container)),
+    };
+    self.container["contained"]
+      .as_array()?
+      .iter()
+      .find(|r| r["id"].as_str() == Some(id.as_str()))
+      .map(ResourceList::new)
+  }
+}
+
+/// Resolves references against the entries of a Bundle by `fullUrl`.
+///
+/// Relative references resolve against the base of the referring entry's
+/// `fullUrl` when that is a RESTful URL. When it is not, or the referring
+/// entry is unknown, they match entries by type and id. Versioned references
+/// must match `meta.versionId`; otherwise the last matching entry is used.
+pub struct BundleResolver<'a> {
+  bundle: &'a Value,
+  base: Option<String>,
+}
+
+impl<'a> BundleResolver<'a> {
+  pub fn new(bundle: &'a Bundle) -> BundleResolver<'a> {
+    BundleResolver {
+      bundle: &bundle.value,
+      base: None,
+    }
+  }
+
+  /// A resolver for the references made by the entry with `full_url`.
+  pub fn for_entry(&self, full_url: &str) -> BundleResolver<'a> {
+    let base = ReferenceTarget::parse(full_url).and_then(|target| target.base);
+    BundleResolver {
+      bundle: self.bundle,
+      base,
+    }
+  }
+
+  fn entries(&self) -> impl Iterator<Item = (usize, &'a str, &'a Value)> {
+    self.bundle["entry"]
+      .as_array()
+      .map(|entries| entries.as_slice())
+      .unwrap_or_default()
+      .iter()
+      .enumerate()
+      .filter_map(|(i, entry)| {
+        Some((
+          i,
+          entry["fullUrl"].as_str().unwrap_or_default(),
+          entry.get("resource")?,
+        ))
+      })
+  }
+
+  /// The index of the entry a reference resolves to.
+  pub fn resolve_entry(&self, target: &ReferenceTarget) -> Option<usize> {
+    let version = target.version.as_deref();
+    let found = match (target.kind, &self.base) {
+      (ReferenceKind::Contained, _) => None,
+      (ReferenceKind::Relative, None) => {
+        let relative = target.relative()?;
+        self
+          .entries()
+          .filter(|(_, _, resource)| {
+            resource["resourceType"].as_str() == target.resource_type.as_deref()
+              && resource["id"].as_str() == target.id.as_deref()
+          })
+          .filter(|(_, full_url, _)| {
+            ReferenceTarget::parse(full_url)
+              .and_then(|t| t.relative())
+              .map(|r| r == relative)
+              .unwrap_or(true)
+          })
+          .filter(|(_, _, resource)| has_version(resource, version))
+          .last()
+      }
+      (_, base) => {
+        let url = target.absolute(base.as_deref().unwrap_or_default())?;
+        self
+          .entries()
+          .filter(|(_, full_url, resource)| *full_url == url && has_version(resource, version))
+          .last()
+      }
+    };
+    found.map(|(i, _, _)| i)
+  }
+}
+
+impl ReferenceResolver for BundleResolver<'_> {
+  fn resolve(&self, target: &ReferenceTarget) -> Option<ResourceList<'_>> {
+    let index = self.resolve_entry(target)?;
+    Some(ResourceList::new(&self.bundle["entry"][index]["resource"]))
+  }
+}
+
+/// Tries each resolver in turn.
+#[derive(Default)]
+pub struct ResolverChain<'r> {
+  resolvers: Vec<&'r dyn ReferenceResolver>,
+}
+
+impl<'r> ResolverChain<'r> {
+  pub fn new() -> ResolverChain<'r> {
+    Self::default()
+  }
+
+  pub fn add(&mut self, resolver: &'r dyn ReferenceResolver) -> &mut ResolverChain<'r> {
+    self.resolvers.push(resolver);
+    self
+  }
+}
+
+impl ReferenceResolver for ResolverChain<'_> {
+  fn resolve(&self, target: &ReferenceTarget) -> Option<ResourceList<'_>> {
+    self.resolvers.iter().find_map(|r| r.resolve(target))
+  }
+}
+
+impl Reference<'_> {
+  /// The resource this reference points to. Logical references (only an
+  /// `identifier`) do not resolve.
+  pub fn resolve<'r>(&self, resolver: &'r dyn ReferenceResolver) -> Option<ResourceList<'r>> {
+    resolver.resolve(&self.target()?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::ResourceList::ResourceListEnum;
+  use serde_json::json;
+  use std::fs;
+
+  #[test]
+  fn test_resolve_in_bundle() {
+    let contents = fs::read_to_string("examples-json/bundle-references.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let bundle = Bundle::new(&value);
+    let resolver = BundleResolver::new(&bundle);
+    let resolve = |index: usize| {
+      let entry = &value["entry"][index];
+      let reference = Reference::new(&entry["resource"]["subject"]);
+      let scoped = resolver.for_entry(entry["fullUrl"].as_str().unwrap());
+      reference.resolve(&scoped).map(|r| r.to_json())
+    };
+    assert_eq!(resolve(2), Some(value["entry"][0]["resource"].clone()));
+    assert_eq!(resolve(3), Some(value["entry"][0]["resource"].clone()));
+    assert_eq!(resolve(4), Some(value["entry"][1]["resource"].clone()));
+    assert_eq!(resolve(5), None);
+    assert_eq!(resolve(6), None);
+    assert_eq!(resolve(9), Some(value["entry"][8]["resource"].clone()));
+    assert_eq!(resolve(10), None);
+
+    let patient = resolver.resolve(&ReferenceTarget::parse("Patient/23").unwrap());
+    assert!(matches!(
+      patient.unwrap().resource(),
+      Some(ResourceListEnum::ResourcePatient(_))
+    ));
+  }
+
+  #[test]
+  fn test_resolver_chain() {
+    let request = json!({
+      "resourceType": "MedicationRequest",
+      "id": "rx",
+      "contained": [{"resourceType": "Medication", "id": "med1"}],
+      "medicationReference": {"reference": "#med1"},
+      "subject": {"reference": "Patient/p1"},
+      "requester": {"reference": "Practitioner/missing"}
+    });
+    let container = ResourceList::new(&request);
+    let contained = ContainedResolver::new(&container);
+    let mut store = HashMap::new();
+    store.insert(
+      "Patient/p1".to_string(),
+      json!({"resourceType": "Patient", "id": "p1", "meta": {"versionId": "3"}}),
+    );
+    let mut chain = ResolverChain::new();
+    chain.add(&contained).add(&store);
+
+    let medication = Reference::new(&request["medicationReference"]).resolve(&chain);
+    assert_eq!(medication.unwrap().to_json()["id"], "med1");
+    let subject = Reference::new(&request["subject"]).resolve(&chain);
+    assert_eq!(subject.unwrap().to_json()["id"], "p1");
+    assert!(Reference::new(&request["requester"])
+      .resolve(&chain)
+      .is_none());
+    let stale = ReferenceTarget::parse("Patient/p1/_history/2").unwrap();
+    assert!(chain.resolve(&stale).is_none());
+    let itself = ReferenceTarget::parse("#").unwrap();
+    assert_eq!(chain.resolve(&itself).unwrap().to_json()["id"], "rx");
+  }
+}
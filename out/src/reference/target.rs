@@ -0,0 +1,212 @@
+//! Parsing of `Reference.reference` strings.
+
+use crate::model::Reference::Reference;
+use crate::rest::is_resource_type;
+use std::fmt;
+
+/// The form a reference takes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReferenceKind {
+  /// `Patient/123`, relative to the base of the referring resource.
+  Relative,
+  /// `http://example.org/fhir/Patient/123`, or any other absolute URL.
+  Absolute,
+  /// `#p1`, a resource in the referring resource's `contained`, or `#` for
+  /// the referring resource itself.
+  Contained,
+  /// `urn:uuid:...` or `urn:oid:...`, the `fullUrl` of a Bundle entry.
+  Urn,
+}
+
+/// A parsed reference. Absolute URLs that do not follow the RESTful
+/// `[base]/[type]/[id]` pattern have no `resource_type` or `id`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceTarget {
+  pub kind: ReferenceKind,
+  pub resource_type: Option<String>,
+  pub id: Option<String>,
+  pub version: Option<String>,
+  /// The service base URL of an absolute RESTful reference, without a
+  /// trailing slash.
+  pub base: Option<String>,
+  /// The reference as written.
+  pub reference: String,
+}
+
+fn is_id(segment: &str) -> bool {
+  !segment.is_empty()
+    && segment.len() <= 64
+    && segment
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+}
+
+/// The type, id and version at the end of a path, and the segments before
+/// them.
+fn split_rest(segments: &[&str]) -> Option<(usize, String, String, Option<String>)> {
+  let n = segments.len();
+  if n >= 4 && segments[n - 2] == "_history" {
+    let (resource_type, id, version) = (segments[n - 4], segments[n - 3], segments[n - 1]);
+    if is_resource_type(resource_type) && is_id(id) && is_id(version) {
+      return Some((
+        n - 4,
+        resource_type.to_string(),
+        id.to_string(),
+        Some(version.to_string()),
+      ));
+    }
+  }
+  if n >= 2 && is_resource_type(segments[n - 2]) && is_id(segments[n - 1]) {
+    return Some((
+      n - 2,
+      segments[n - 2].to_string(),
+      segments[n - 1].to_string(),
+      None,
+    ));
+  }
+  None
+}
+
+impl ReferenceTarget {
+  /// Parses a reference. `None` for a relative reference that is not
+  /// `[type]/[id]` or `[type]/[id]/_history/[version]`.
+  pub fn parse(reference: &str) -> Option<ReferenceTarget> {
+    let mut target = ReferenceTarget {
+      kind: ReferenceKind::Relative,
+      resource_type: None,
+      id: None,
+      version: None,
+      base: None,
+      reference: reference.to_string(),
+    };
+    if let Some(id) = reference.strip_prefix('#') {
+      target.kind = ReferenceKind::Contained;
+      target.id = Some(id.to_string()).filter(|id| !id.is_empty());
+      return Some(target);
+    }
+    if reference.starts_with("urn:") {
+      target.kind = ReferenceKind::Urn;
+      target.id = reference
+        .strip_prefix("urn:uuid:")
+        .or_else(|| reference.strip_prefix("urn:oid:"))
+        .map(|id| id.to_string());
+      return Some(target);
+    }
+    let path = reference.split(&['?', '#'][..]).next().unwrap_or_default();
+    let absolute = path.contains("://");
+    let segments: Vec<&str> = path.split('/').collect();
+    match split_rest(&segments) {
+      Some((start, resource_type, id, version)) => {
+        target.resource_type = Some(resource_type);
+        target.id = Some(id);
+        target.version = version;
+        if start > 0 {
+          if !absolute {
+            return None;
+          }
+          target.kind = ReferenceKind::Absolute;
+          target.base = Some(segments[..start].join("/"));
+        }
+        Some(target)
+      }
+      None if absolute => {
+        target.kind = ReferenceKind::Absolute;
+        Some(target)
+      }
+      None => None,
+    }
+  }
+
+  /// `[type]/[id]`, if known.
+  pub fn relative(&self) -> Option<String> {
+    Some(format!(
+      "{}/{}",
+      self.resource_type.as_ref()?,
+      self.id.as_ref()?
+    ))
+  }
+
+  /// The absolute URL of a RESTful reference resolved against `base`, without
+  /// the version. Absolute references keep their own base.
+  pub fn absolute(&self, base: &str) -> Option<String> {
+    match self.kind {
+      ReferenceKind::Relative => Some(format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        self.relative()?
+      )),
+      ReferenceKind::Absolute => match &self.base {
+        Some(own) => Some(format!("{}/{}", own, self.relative()?)),
+        None => Some(self.reference.clone()),
+      },
+      ReferenceKind::Urn => Some(self.reference.clone()),
+      ReferenceKind::Contained => None,
+    }
+  }
+}
+
+impl fmt::Display for ReferenceTarget {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.reference)
+  }
+}
+
+impl Reference<'_> {
+  /// The parsed `reference`. The `type` element supplies the resource type
+  /// when the reference itself does not say.
+  pub fn target(&self) -> Option<ReferenceTarget> {
+    let mut target = ReferenceTarget::parse(self.reference()?)?;
+    if target.resource_type.is_none() && target.kind != ReferenceKind::Contained {
+      target.resource_type = self.fhir_type().map(|t| t.to_string());
+    }
+    Some(target)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_references() {
+    let relative = ReferenceTarget::parse("Patient/123").unwrap();
+    assert_eq!(relative.kind, ReferenceKind::Relative);
+    assert_eq!(relative.resource_type.as_deref(), Some("Patient"));
+    assert_eq!(relative.id.as_deref(), Some("123"));
+    assert_eq!(
+      relative.absolute("http://example.org/fhir/").as_deref(),
+      Some("http://example.org/fhir/Patient/123")
+    );
+
+    let versioned = ReferenceTarget::parse("Patient/45/_history/2").unwrap();
+    assert_eq!(versioned.version.as_deref(), Some("2"));
+    assert_eq!(versioned.relative().as_deref(), Some("Patient/45"));
+
+    let absolute =
+      ReferenceTarget::parse("http://example.org/fhir/Observation/1/_history/3").unwrap();
+    assert_eq!(absolute.kind, ReferenceKind::Absolute);
+    assert_eq!(absolute.base.as_deref(), Some("http://example.org/fhir"));
+    assert_eq!(absolute.resource_type.as_deref(), Some("Observation"));
+    assert_eq!(absolute.version.as_deref(), Some("3"));
+
+    let logical = ReferenceTarget::parse("http://example.org/ValueSet-x.html").unwrap();
+    assert_eq!(logical.kind, ReferenceKind::Absolute);
+    assert_eq!(logical.id, None);
+
+    let contained = ReferenceTarget::parse("#med1").unwrap();
+    assert_eq!(contained.kind, ReferenceKind::Contained);
+    assert_eq!(contained.id.as_deref(), Some("med1"));
+    assert_eq!(ReferenceTarget::parse("#").unwrap().id, None);
+
+    let urn = ReferenceTarget::parse("urn:uuid:04121321-4af5-424c-a0e1-ed3aab1c349d").unwrap();
+    assert_eq!(urn.kind, ReferenceKind::Urn);
+    assert_eq!(
+      urn.id.as_deref(),
+      Some("04121321-4af5-424c-a0e1-ed3aab1c349d")
+    );
+
+    assert_eq!(ReferenceTarget::parse("patient/123"), None);
+    assert_eq!(ReferenceTarget::parse("fhir/Patient/123"), None);
+    assert_eq!(ReferenceTarget::parse("Foo/1"), None);
+  }
+}
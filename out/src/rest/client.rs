@@ -0,0 +1,544 @@
+//! A client for the FHIR RESTful API that returns generated types.
+//!
+//! Updates send `If-Match` with the resource's `meta.versionId`, so a
+//! concurrent change surfaces as `ClientError::PreconditionFailed` rather
+//! than being overwritten. Error statuses are mapped to `ClientError`
+//! variants carrying the OperationOutcome the server sent, if any.
+
+use super::resource::Resource;
+use super::search::{SearchBuilder, SearchIter};
+use super::transport::{HttpRequest, HttpResponse, Transport};
+use crate::bundle::status_line;
+use crate::model::Bundle::Bundle;
+use crate::model::OperationOutcome::OperationOutcome;
+use crate::reference::ReferenceTarget;
+use crate::store::criteria::percent_encode;
+use crate::store::etag_version;
+use serde_json::Value;
+use std::fmt;
+
+const FHIR_JSON: &str = "application/fhir+json";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientError {
+  /// The request could not be exchanged.
+  Transport(String),
+  /// The response is not what was asked for.
+  InvalidResponse(String),
+  /// An update of a resource that has no id.
+  MissingId,
+  /// 400; each variant holds the OperationOutcome in the response, if any.
+  BadRequest(Option<Value>),
+  /// 404
+  NotFound(Option<Value>),
+  /// 409
+  Conflict(Option<Value>),
+  /// 410
+  Gone(Option<Value>),
+  /// 412
+  PreconditionFailed(Option<Value>),
+  /// 422
+  Unprocessable(Option<Value>),
+  /// Any other error status.
+  Status(u16, Option<Value>),
+}
+
+impl ClientError {
+  fn from_response(status: u16, outcome: Option<Value>) -> ClientError {
+    match status {
+      400 => ClientError::BadRequest(outcome),
+      404 => ClientError::NotFound(outcome),
+      409 => ClientError::Conflict(outcome),
+      410 => ClientError::Gone(outcome),
+      412 => ClientError::PreconditionFailed(outcome),
+      422 => ClientError::Unprocessable(outcome),
+      _ => ClientError::Status(status, outcome),
+    }
+  }
+
+  /// The HTTP status, for errors the server answered with.
+  pub fn status(&self) -> Option<u16> {
+    match self {
+      ClientError::Transport(_) | ClientError::InvalidResponse(_) | ClientError::MissingId => None,
+      ClientError::BadRequest(_) => Some(400),
+      ClientError::NotFound(_) => Some(404),
+      ClientError::Conflict(_) => Some(409),
+      ClientError::Gone(_) => Some(410),
+      ClientError::PreconditionFailed(_) => Some(412),
+      ClientError::Unprocessable(_) => Some(422),
+      ClientError::Status(status, _) => Some(*status),
+    }
+  }
+
+  fn outcome_json(&self) -> Option<&Value> {
+    match self {
+      ClientError::BadRequest(outcome)
+      | ClientError::NotFound(outcome)
+      | ClientError::Conflict(outcome)
+      | ClientError::Gone(outcome)
+      | ClientError::PreconditionFailed(outcome)
+      | ClientError::Unprocessable(outcome)
+      | ClientError::Status(_, outcome) => outcome.as_ref(),
+      _ => None,
+    }
+  }
+
+  /// The OperationOutcome the server sent with the error.
+  pub fn outcome(&self) -> Option<OperationOutcome<'_>> {
+    self.outcome_json().map(OperationOutcome::new)
+  }
+}
+
+impl fmt::Display for ClientError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ClientError::Transport(message) => write!(f, "Transport failure: {}", message),
+      ClientError::InvalidResponse(message) => write!(f, "Invalid response: {}", message),
+      ClientError::MissingId => write!(f, "The resource has no id"),
+      _ => {
+        write!(f, "{}", status_line(self.status().unwrap_or_default()))?;
+        let issues = self
+          .outcome_json()
+          .and_then(|o| o["issue"].as_array())
+          .map(|i| i.as_slice())
+          .unwrap_or_default();
+        for issue in issues {
+          let text = issue["details"]["text"]
+            .as_str()
+            .or_else(|| issue["diagnostics"].as_str());
+          if let Some(text) = text {
+            write!(f, ": {}", text)?;
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl std::error::Error for ClientError {}
+
+/// What the server should return from a create, update or patch (the
+/// `Prefer` header).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreferReturn {
+  Minimal,
+  Representation,
+  OperationOutcome,
+}
+
+impl PreferReturn {
+  fn header(self) -> &'static str {
+    match self {
+      PreferReturn::Minimal => "return=minimal",
+      PreferReturn::Representation => "return=representation",
+      PreferReturn::OperationOutcome => "return=OperationOutcome",
+    }
+  }
+}
+
+/// The result of a create, update, patch or delete.
+#[derive(Debug)]
+pub struct Written<R> {
+  pub status: u16,
+  /// The `Location` header, e.g. `[base]/Patient/123/_history/2`.
+  pub location: Option<ReferenceTarget>,
+  /// The version in the `ETag` header.
+  pub version_id: Option<String>,
+  pub last_modified: Option<String>,
+  /// The resource, unless the server returned less.
+  pub resource: Option<R>,
+  pub outcome: Option<OperationOutcome<'static>>,
+}
+
+impl<R> Written<R> {
+  /// The id of the written resource, from the `Location` header.
+  pub fn id(&self) -> Option<&str> {
+    self.location.as_ref()?.id.as_deref()
+  }
+}
+
+/// A FHIR RESTful API client.
+pub struct Client<T: Transport> {
+  base: String,
+  transport: T,
+  prefer: Option<PreferReturn>,
+}
+
+impl<T: Transport> Client<T> {
+  /// A client for the server at `base`, e.g. `http://example.org/fhir`.
+  pub fn new(base: &str, transport: T) -> Self {
+    Client {
+      base: base.trim_end_matches('/').to_string(),
+      transport,
+      prefer: None,
+    }
+  }
+
+  /// Sends `Prefer: return=...` with creates, updates and patches.
+  pub fn prefer(&mut self, prefer: PreferReturn) -> &mut Self {
+    self.prefer = Some(prefer);
+    self
+  }
+
+  pub fn transport(&self) -> &T {
+    &self.transport
+  }
+
+  pub fn transport_mut(&mut self) -> &mut T {
+    &mut self.transport
+  }
+
+  fn url(&self, path: &str) -> String {
+    format!("{}/{}", self.base, path)
+  }
+
+  fn query(params: &[(&str, &str)]) -> String {
+    params
+      .iter()
+      .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+      .collect::<Vec<String>>()
+      .join("&")
+  }
+
+  fn request(&self, method: &str, url: String, body: Option<(&str, &Value)>) -> HttpRequest {
+    let mut headers = vec![("Accept".to_string(), FHIR_JSON.to_string())];
+    if let Some((content_type, _)) = body {
+      headers.push(("Content-Type".to_string(), content_type.to_string()));
+      if let Some(prefer) = self.prefer {
+        headers.push(("Prefer".to_string(), prefer.header().to_string()));
+      }
+    }
+    HttpRequest {
+      method: method.to_string(),
+      url,
+      headers,
+      body: body.map(|(_, body)| body.to_string()),
+    }
+  }
+
+  /// Sends a request. Error statuses become errors.
+  pub fn send(&mut self, request: HttpRequest) -> Result<HttpResponse, ClientError> {
+    let response = self
+      .transport
+      .send(request)
+      .map_err(ClientError::Transport)?;
+    if response.status >= 400 {
+      let outcome = parse_body(&response)
+        .ok()
+        .flatten()
+        .filter(|body| body["resourceType"] == "OperationOutcome");
+      return Err(ClientError::from_response(response.status, outcome));
+    }
+    Ok(response)
+  }
+
+  /// `GET [base]/[path]`, expecting a resource of type `R`.
+  fn get<R: Resource>(&mut self, path: &str) -> Result<R, ClientError> {
+    let url = self.url(path);
+    self.fetch(url)
+  }
+
+  fn fetch<R: Resource>(&mut self, url: String) -> Result<R, ClientError> {
+    let request = self.request("GET", url, None);
+    let response = self.send(request)?;
+    let body = parse_body(&response)?
+      .ok_or_else(|| ClientError::InvalidResponse("The response has no body".to_string()))?;
+    typed(body)
+  }
+
+  pub fn read<R: Resource>(&mut self, id: &str) -> Result<R, ClientError> {
+    self.get(&format!("{}/{}", R::RESOURCE_TYPE, id))
+  }
+
+  pub fn vread<R: Resource>(&mut self, id: &str, version_id: &str) -> Result<R, ClientError> {
+    self.get(&format!(
+      "{}/{}/_history/{}",
+      R::RESOURCE_TYPE,
+      id,
+      version_id
+    ))
+  }
+
+  /// The history Bundle of a resource.
+  pub fn history<R: Resource>(&mut self, id: &str) -> Result<Bundle<'static>, ClientError> {
+    self.get(&format!("{}/{}/_history", R::RESOURCE_TYPE, id))
+  }
+
+  /// The searchset Bundle for a search of resources of type `R`.
+  pub fn search<R: Resource>(
+    &mut self,
+    params: &[(&str, &str)],
+  ) -> Result<Bundle<'static>, ClientError> {
+    self.get(&format!("{}?{}", R::RESOURCE_TYPE, Self::query(params)))
+  }
+
+  /// Searches and iterates over the entries of every page of the result.
+  pub fn search_all<R: Resource>(
+    &mut self,
+    search: &SearchBuilder<R>,
+  ) -> Result<SearchIter<'_, T>, ClientError> {
+    let first: Bundle = self.get(&search.url())?;
+    Ok(SearchIter::new(self, &first))
+  }
+
+  /// `GET` of an absolute URL returning a Bundle, such as a paging link.
+  pub fn follow(&mut self, url: &str) -> Result<Bundle<'static>, ClientError> {
+    self.fetch(url.to_string())
+  }
+
+  fn write<R: Resource>(&mut self, request: HttpRequest) -> Result<Written<R>, ClientError> {
+    let response = self.send(request)?;
+    let body = parse_body(&response)?;
+    let (resource, outcome) = match body {
+      Some(body) if body["resourceType"] == "OperationOutcome" => {
+        (None, Some(OperationOutcome::from_json(body)))
+      }
+      Some(body) => (Some(typed(body)?), None),
+      None => (None, None),
+    };
+    Ok(Written {
+      status: response.status,
+      location: response
+        .header("Location")
+        .or_else(|| response.header("Content-Location"))
+        .and_then(ReferenceTarget::parse),
+      version_id: response
+        .header("ETag")
+        .map(|etag| etag_version(etag).to_string()),
+      last_modified: response.header("Last-Modified").map(|l| l.to_string()),
+      resource,
+      outcome,
+    })
+  }
+
+  pub fn create<R: Resource>(&mut self, resource: &R) -> Result<Written<R>, ClientError> {
+    let request = self.request(
+      "POST",
+      self.url(R::RESOURCE_TYPE),
+      Some((FHIR_JSON, resource.json())),
+    );
+    self.write(request)
+  }
+
+  /// Creates a resource unless one matches `criteria`, e.g.
+  /// `identifier=http://example.org/ids|123` (`If-None-Exist`).
+  pub fn conditional_create<R: Resource>(
+    &mut self,
+    resource: &R,
+    criteria: &str,
+  ) -> Result<Written<R>, ClientError> {
+    let mut request = self.request(
+      "POST",
+      self.url(R::RESOURCE_TYPE),
+      Some((FHIR_JSON, resource.json())),
+    );
+    request.headers.push((
+      "If-None-Exist".to_string(),
+      criteria.trim_start_matches('?').to_string(),
+    ));
+    self.write(request)
+  }
+
+  /// Updates a resource by id, with `If-Match` when it has a
+  /// `meta.versionId`.
+  pub fn update<R: Resource>(&mut self, resource: &R) -> Result<Written<R>, ClientError> {
+    let id = resource.id().ok_or(ClientError::MissingId)?;
+    let mut request = self.request(
+      "PUT",
+      self.url(&format!("{}/{}", R::RESOURCE_TYPE, id)),
+      Some((FHIR_JSON, resource.json())),
+    );
+    if let Some(version_id) = resource.version_id() {
+      request
+        .headers
+        .push(("If-Match".to_string(), format!("W/\"{}\"", version_id)));
+    }
+    self.write(request)
+  }
+
+  /// Updates the resource matching `criteria`, or creates it if none does.
+  pub fn conditional_update<R: Resource>(
+    &mut self,
+    resource: &R,
+    criteria: &str,
+  ) -> Result<Written<R>, ClientError> {
+    let url = self.url(&format!(
+      "{}?{}",
+      R::RESOURCE_TYPE,
+      criteria.trim_start_matches('?')
+    ));
+    let request = self.request("PUT", url, Some((FHIR_JSON, resource.json())));
+    self.write(request)
+  }
+
+  /// Patches a resource with a JSON Patch array or a FHIRPath Patch
+  /// `Parameters` resource, optionally only if it is at `version_id`.
+  pub fn patch<R: Resource>(
+    &mut self,
+    id: &str,
+    patch: &Value,
+    version_id: Option<&str>,
+  ) -> Result<Written<R>, ClientError> {
+    let content_type = if patch.is_array() {
+      "application/json-patch+json"
+    } else {
+      FHIR_JSON
+    };
+    let mut request = self.request(
+      "PATCH",
+      self.url(&format!("{}/{}", R::RESOURCE_TYPE, id)),
+      Some((content_type, patch)),
+    );
+    if let Some(version_id) = version_id {
+      request
+        .headers
+        .push(("If-Match".to_string(), format!("W/\"{}\"", version_id)));
+    }
+    self.write(request)
+  }
+
+  pub fn delete<R: Resource>(&mut self, id: &str) -> Result<Written<R>, ClientError> {
+    let request = self.request(
+      "DELETE",
+      self.url(&format!("{}/{}", R::RESOURCE_TYPE, id)),
+      None,
+    );
+    self.write(request)
+  }
+}
+
+fn parse_body(response: &HttpResponse) -> Result<Option<Value>, ClientError> {
+  if response.body.trim().is_empty() {
+    return Ok(None);
+  }
+  serde_json::from_str(&response.body)
+    .map(Some)
+    .map_err(|e| ClientError::InvalidResponse(e.to_string()))
+}
+
+fn typed<R: Resource>(body: Value) -> Result<R, ClientError> {
+  match body["resourceType"].as_str() {
+    Some(resource_type) if resource_type == R::RESOURCE_TYPE => Ok(R::from_json(body)),
+    resource_type => Err(ClientError::InvalidResponse(format!(
+      "Expected a {} but got {}",
+      R::RESOURCE_TYPE,
+      resource_type.unwrap_or("no resource")
+    ))),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use crate::rest::FakeTransport;
+  use serde_json::json;
+  use std::fs;
+
+  #[test]
+  fn test_client_requests() {
+    let contents = fs::read_to_string("examples-json/patient-example.json").unwrap();
+    let mut patient: Value = serde_json::from_str(&contents).unwrap();
+    patient["meta"] = json!({"versionId": "1"});
+
+    let mut transport = FakeTransport::new();
+    transport
+      .respond(HttpResponse::json(200, &patient))
+      .respond(HttpResponse {
+        status: 200,
+        headers: vec![
+          (
+            "location".to_string(),
+            "http://example.org/fhir/Patient/example/_history/2".to_string(),
+          ),
+          ("ETag".to_string(), "W/\"2\"".to_string()),
+        ],
+        body: String::new(),
+      })
+      .respond(HttpResponse::json(
+        201,
+        &json!({"resourceType": "Patient", "id": "7", "meta": {"versionId": "1"}}),
+      ))
+      .respond(HttpResponse::json(
+        200,
+        &json!({"resourceType": "Bundle", "type": "searchset", "total": 0}),
+      ));
+    let mut client = Client::new("http://example.org/fhir/", transport);
+    client.prefer(PreferReturn::Minimal);
+
+    let read: Patient = client.read("example").unwrap();
+    assert_eq!(read.id(), Some("example"));
+    let written = client.update(&read).unwrap();
+    assert_eq!(written.id(), Some("example"));
+    assert_eq!(written.version_id.as_deref(), Some("2"));
+    assert!(written.resource.is_none());
+    let created = client
+      .conditional_create(
+        &Patient::from_json(json!({"resourceType": "Patient"})),
+        "identifier=http://example.org/ids|123",
+      )
+      .unwrap();
+    assert_eq!(created.status, 201);
+    assert_eq!(created.resource.unwrap().id(), Some("7"));
+    let found = client
+      .search::<Patient>(&[("name", "peter james")])
+      .unwrap();
+    assert_eq!(found.total(), Some(0));
+
+    let requests = client.transport().requests();
+    assert_eq!(requests[0].url, "http://example.org/fhir/Patient/example");
+    assert_eq!(requests[0].header("accept"), Some("application/fhir+json"));
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(requests[1].header("If-Match"), Some("W/\"1\""));
+    assert_eq!(requests[1].header("Prefer"), Some("return=minimal"));
+    assert_eq!(
+      requests[2].header("If-None-Exist"),
+      Some("identifier=http://example.org/ids|123")
+    );
+    assert_eq!(
+      requests[3].url,
+      "http://example.org/fhir/Patient?name=peter%20james"
+    );
+  }
+
+  #[test]
+  fn test_client_errors() {
+    let outcome = json!({
+      "resourceType": "OperationOutcome",
+      "issue": [{"severity": "error", "code": "not-found", "details": {"text": "Patient/1 is not known"}}]
+    });
+    let mut client = Client::new(
+      "http://example.org/fhir",
+      |request: HttpRequest| match request.method.as_str() {
+        "GET" => Ok(HttpResponse::json(404, &outcome)),
+        "DELETE" => Ok(HttpResponse::json(
+          200,
+          &json!({"resourceType": "Observation"}),
+        )),
+        _ => Err("connection refused".to_string()),
+      },
+    );
+    let error = client.read::<Patient>("1").unwrap_err();
+    assert_eq!(error.status(), Some(404));
+    assert!(matches!(error, ClientError::NotFound(Some(_))));
+    assert_eq!(error.outcome().unwrap().issue().len(), 1);
+    assert_eq!(error.to_string(), "404 Not Found: Patient/1 is not known");
+    assert_eq!(
+      client.delete::<Patient>("1").unwrap_err(),
+      ClientError::InvalidResponse("Expected a Patient but got Observation".to_string())
+    );
+    assert_eq!(
+      client
+        .create(&Patient::from_json(json!({"resourceType": "Patient"})))
+        .unwrap_err(),
+      ClientError::Transport("connection refused".to_string())
+    );
+    assert_eq!(
+      client
+        .update(&Patient::from_json(json!({"resourceType": "Patient"})))
+        .unwrap_err(),
+      ClientError::MissingId
+    );
+  }
+}
@@ -0,0 +1,12 @@
+//! The FHIR RESTful API: a typed client over a pluggable HTTP transport,
+//! with typed search queries and searchset paging.
+
+pub mod client;
+pub mod resource;
+pub mod search;
+pub mod transport;
+
+pub use client::{Client, ClientError, PreferReturn, Written};
+pub use resource::{downcast, is_resource_type, Resource, RESOURCE_TYPES};
+pub use search::{escape, next_link, Prefix, SearchBuilder, SearchEntry, SearchIter, Summary};
+pub use transport::{below_base, FakeTransport, HttpRequest, HttpResponse, Transport};
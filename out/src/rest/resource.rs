@@ -0,0 +1,89 @@
+//! The resource types as Rust types, so that the client can be asked for a
+//! `Patient` rather than a `ResourceList`.
+
+use crate::model::ResourceList::ResourceList;
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// A generated resource type.
+pub trait Resource: Sized {
+  /// The `resourceType`, e.g. `Patient`.
+  const RESOURCE_TYPE: &'static str;
+
+  /// Wraps a JSON resource without checking its `resourceType`.
+  fn from_json(value: Value) -> Self;
+
+  fn json(&self) -> &Value;
+
+  fn id(&self) -> Option<&str> {
+    self.json()["id"].as_str()
+  }
+
+  /// `meta.versionId`, if the resource has one.
+  fn version_id(&self) -> Option<&str> {
+    self.json()["meta"]["versionId"].as_str()
+  }
+}
+
+/// Whether `name` is a resource type, e.g. `Patient` but not `HumanName`.
+pub fn is_resource_type(name: &str) -> bool {
+  RESOURCE_TYPES.contains(&name)
+}
+
+/// Converts a resource of any type into its generated type, if it has that
+/// type.
+pub fn downcast<R: Resource>(resource: &ResourceList) -> Option<R> {
+  if resource.value["resourceType"].as_str() == Some(R::RESOURCE_TYPE) {
+    Some(R::from_json(resource.to_json()))
+  } else {
+    None
+  }
+}
+
+macro_rules! resources {
+  ($($name:ident),*) => {
+    /// The names of all resource types.
+    pub const RESOURCE_TYPES: &[&str] = &[$(stringify!($name)),*];
+
+    $(
+      impl<'a> Resource for crate::model::$name::$name<'a> {
+        const RESOURCE_TYPE: &'static str = stringify!($name);
+
+        fn from_json(value: Value) -> Self {
+          crate::model::$name::$name {
+            value: Cow::Owned(value),
+          }
+        }
+
+        fn json(&self) -> &Value {
+          &self.value
+        }
+      }
+    )*
+  };
+}
+
+// `resources!(Account, ...)`, generated from the schema by src/main.rs.
+include!("resource_types.rs");
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Observation::Observation;
+  use crate::model::Patient::Patient;
+  use std::fs;
+
+  #[test]
+  fn test_downcast() {
+    let contents = fs::read_to_string("examples-json/patient-example.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let resource = ResourceList::new(&value);
+    let patient: Patient = downcast(&resource).unwrap();
+    assert_eq!(Patient::RESOURCE_TYPE, "Patient");
+    assert_eq!(patient.id(), Some("example"));
+    assert_eq!(patient.gender().unwrap().to_string(), "male");
+    assert!(downcast::<Observation>(&resource).is_none());
+    assert!(is_resource_type("Patient"));
+    assert!(!is_resource_type("HumanName"));
+  }
+}
@@ -0,0 +1,465 @@
+//! Search: building the query for one resource type, and iterating over the
+//! entries of a searchset across its pages.
+//!
+//! Values are escaped as the search specification requires (`\,`, `\|`,
+//! `\$`, `\\`) and then percent-encoded when the query is built.
+
+use super::client::{Client, ClientError};
+use super::resource::{downcast, Resource};
+use super::transport::Transport;
+use crate::model::Bundle::Bundle;
+use crate::model::ResourceList::ResourceList;
+use crate::store::criteria::percent_encode;
+pub use crate::store::criteria::Prefix;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// The `_summary` modes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Summary {
+  True,
+  Text,
+  Data,
+  Count,
+  False,
+}
+
+impl Summary {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Summary::True => "true",
+      Summary::Text => "text",
+      Summary::Data => "data",
+      Summary::Count => "count",
+      Summary::False => "false",
+    }
+  }
+
+  pub fn parse(text: &str) -> Option<Summary> {
+    match text {
+      "true" => Some(Summary::True),
+      "text" => Some(Summary::Text),
+      "data" => Some(Summary::Data),
+      "count" => Some(Summary::Count),
+      "false" => Some(Summary::False),
+      _ => None,
+    }
+  }
+}
+
+/// Escapes the characters that separate search values.
+pub fn escape(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for c in value.chars() {
+    if matches!(c, '\\' | ',' | '|' | '$') {
+      escaped.push('\\');
+    }
+    escaped.push(c);
+  }
+  escaped
+}
+
+/// Builds the search query for resources of type `R`.
+#[derive(Debug, Clone)]
+pub struct SearchBuilder<R> {
+  params: Vec<(String, String)>,
+  sort: Vec<String>,
+  resource: PhantomData<fn() -> R>,
+}
+
+impl<R: Resource> Default for SearchBuilder<R> {
+  fn default() -> Self {
+    SearchBuilder {
+      params: vec![],
+      sort: vec![],
+      resource: PhantomData,
+    }
+  }
+}
+
+impl<R: Resource> SearchBuilder<R> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a parameter as it is, without escaping.
+  pub fn param(&mut self, name: &str, value: &str) -> &mut Self {
+    self.params.push((name.to_string(), value.to_string()));
+    self
+  }
+
+  /// A string parameter, matching values that start with `value`.
+  pub fn string(&mut self, name: &str, value: &str) -> &mut Self {
+    self.param(name, &escape(value))
+  }
+
+  /// A string parameter matching `value` exactly (`:exact`).
+  pub fn string_exact(&mut self, name: &str, value: &str) -> &mut Self {
+    self.param(&format!("{}:exact", name), &escape(value))
+  }
+
+  /// A string parameter matching values containing `value` (`:contains`).
+  pub fn string_contains(&mut self, name: &str, value: &str) -> &mut Self {
+    self.param(&format!("{}:contains", name), &escape(value))
+  }
+
+  /// A token: `[system]|[code]`, or any system when `system` is `None`.
+  pub fn token(&mut self, name: &str, system: Option<&str>, code: &str) -> &mut Self {
+    let value = match system {
+      Some(system) => format!("{}|{}", escape(system), escape(code)),
+      None => escape(code),
+    };
+    self.param(name, &value)
+  }
+
+  /// A reference, e.g. `Patient/123`.
+  pub fn reference(&mut self, name: &str, reference: &str) -> &mut Self {
+    self.param(name, &escape(reference))
+  }
+
+  /// A date, e.g. `Prefix::Ge` and `2020-01-01`.
+  pub fn date(&mut self, name: &str, prefix: Prefix, date: &str) -> &mut Self {
+    self.param(name, &format!("{}{}", prefix.as_str(), date))
+  }
+
+  pub fn number(&mut self, name: &str, prefix: Prefix, number: &str) -> &mut Self {
+    self.param(name, &format!("{}{}", prefix.as_str(), number))
+  }
+
+  /// A quantity: `[prefix][number]|[system]|[code]`.
+  pub fn quantity(
+    &mut self,
+    name: &str,
+    prefix: Prefix,
+    number: &str,
+    system: Option<&str>,
+    code: Option<&str>,
+  ) -> &mut Self {
+    let mut value = format!("{}{}", prefix.as_str(), number);
+    if system.is_some() || code.is_some() {
+      value.push_str(&format!(
+        "|{}|{}",
+        escape(system.unwrap_or_default()),
+        escape(code.unwrap_or_default())
+      ));
+    }
+    self.param(name, &value)
+  }
+
+  /// A chained parameter: `reference` resolves to a resource (of
+  /// `target_type`, if given) whose `name` matches `value`, e.g.
+  /// `subject:Patient.name=peter`.
+  pub fn chained(
+    &mut self,
+    reference: &str,
+    target_type: Option<&str>,
+    name: &str,
+    value: &str,
+  ) -> &mut Self {
+    let param = match target_type {
+      Some(target_type) => format!("{}:{}.{}", reference, target_type, name),
+      None => format!("{}.{}", reference, name),
+    };
+    self.param(&param, &escape(value))
+  }
+
+  /// A reverse chain: resources referred to by a `resource_type` through
+  /// `reference` whose `name` matches `value`, e.g.
+  /// `_has:Observation:patient:code=1234-5`.
+  pub fn has(
+    &mut self,
+    resource_type: &str,
+    reference: &str,
+    name: &str,
+    value: &str,
+  ) -> &mut Self {
+    let param = format!("_has:{}:{}:{}", resource_type, reference, name);
+    self.param(&param, &escape(value))
+  }
+
+  /// Includes the resources referred to, e.g. `Observation:subject`.
+  pub fn include(&mut self, include: &str) -> &mut Self {
+    self.param("_include", include)
+  }
+
+  /// Includes the resources referring to the matches, e.g.
+  /// `Provenance:target`.
+  pub fn revinclude(&mut self, include: &str) -> &mut Self {
+    self.param("_revinclude", include)
+  }
+
+  /// Sorts by a parameter; later calls sort within earlier ones.
+  pub fn sort(&mut self, name: &str, descending: bool) -> &mut Self {
+    let key = if descending {
+      format!("-{}", name)
+    } else {
+      name.to_string()
+    };
+    self.sort.push(key);
+    self
+  }
+
+  pub fn count(&mut self, count: u32) -> &mut Self {
+    self.param("_count", &count.to_string())
+  }
+
+  pub fn summary(&mut self, summary: Summary) -> &mut Self {
+    self.param("_summary", summary.as_str())
+  }
+
+  pub fn elements(&mut self, elements: &[&str]) -> &mut Self {
+    self.param("_elements", &elements.join(","))
+  }
+
+  /// The parameters, before percent-encoding.
+  pub fn params(&self) -> Vec<(String, String)> {
+    let mut params = self.params.clone();
+    if !self.sort.is_empty() {
+      params.push(("_sort".to_string(), self.sort.join(",")));
+    }
+    params
+  }
+
+  /// The percent-encoded query, without the `?`.
+  pub fn query(&self) -> String {
+    self
+      .params()
+      .iter()
+      .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+      .collect::<Vec<String>>()
+      .join("&")
+  }
+
+  /// `[type]?[query]`, relative to the server base.
+  pub fn url(&self) -> String {
+    format!("{}?{}", R::RESOURCE_TYPE, self.query())
+  }
+}
+
+/// One entry of a searchset, by its `search.mode`.
+#[derive(Debug)]
+pub enum SearchEntry {
+  /// A resource matching the search. Entries without a mode count as
+  /// matches.
+  Match(ResourceList<'static>),
+  /// A resource added by `_include` or `_revinclude`.
+  Include(ResourceList<'static>),
+  /// An OperationOutcome about the search.
+  Outcome(ResourceList<'static>),
+}
+
+impl SearchEntry {
+  fn from_entry(entry: &Value) -> SearchEntry {
+    let resource = ResourceList {
+      value: Cow::Owned(entry["resource"].clone()),
+    };
+    match entry["search"]["mode"].as_str() {
+      Some("include") => SearchEntry::Include(resource),
+      Some("outcome") => SearchEntry::Outcome(resource),
+      _ => SearchEntry::Match(resource),
+    }
+  }
+
+  pub fn resource(&self) -> &ResourceList<'static> {
+    match self {
+      SearchEntry::Match(resource)
+      | SearchEntry::Include(resource)
+      | SearchEntry::Outcome(resource) => resource,
+    }
+  }
+
+  pub fn is_match(&self) -> bool {
+    matches!(self, SearchEntry::Match(_))
+  }
+
+  /// The resource as a generated type, if it has that type.
+  pub fn typed<T: Resource>(&self) -> Option<T> {
+    downcast(self.resource())
+  }
+}
+
+/// The `next` link of a Bundle.
+pub fn next_link(bundle: &Bundle) -> Option<String> {
+  bundle.value["link"]
+    .as_array()?
+    .iter()
+    .find(|link| link["relation"] == "next")?["url"]
+    .as_str()
+    .map(|url| url.to_string())
+}
+
+/// The entries of a searchset, fetching the following pages through the
+/// `next` links as they are reached. A failed fetch ends the iteration
+/// after yielding the error.
+pub struct SearchIter<'c, T: Transport> {
+  client: &'c mut Client<T>,
+  entries: VecDeque<Value>,
+  next: Option<String>,
+  pages: usize,
+}
+
+impl<'c, T: Transport> SearchIter<'c, T> {
+  /// Iterates from the first page, which has already been fetched.
+  pub fn new(client: &'c mut Client<T>, first: &Bundle) -> Self {
+    let mut iter = SearchIter {
+      client,
+      entries: VecDeque::new(),
+      next: None,
+      pages: 0,
+    };
+    iter.load(first);
+    iter
+  }
+
+  fn load(&mut self, page: &Bundle) {
+    self.entries = page.value["entry"]
+      .as_array()
+      .map(|entries| entries.iter().cloned().collect())
+      .unwrap_or_default();
+    self.next = next_link(page);
+    self.pages += 1;
+  }
+
+  /// The number of pages fetched so far.
+  pub fn pages(&self) -> usize {
+    self.pages
+  }
+}
+
+impl<T: Transport> Iterator for SearchIter<'_, T> {
+  type Item = Result<SearchEntry, ClientError>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(entry) = self.entries.pop_front() {
+        return Some(Ok(SearchEntry::from_entry(&entry)));
+      }
+      let url = self.next.take()?;
+      match self.client.follow(&url) {
+        Ok(page) => self.load(&page),
+        Err(error) => return Some(Err(error)),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Observation::Observation;
+  use crate::model::Patient::Patient;
+  use crate::rest::{FakeTransport, HttpResponse};
+  use serde_json::json;
+
+  #[test]
+  fn test_search_builder() {
+    let mut search = SearchBuilder::<Observation>::new();
+    search
+      .token("code", Some("http://loinc.org"), "29463-7")
+      .reference("subject", "Patient/123")
+      .date("date", Prefix::Ge, "2020-01-01")
+      .date("date", Prefix::Lt, "2021-01-01")
+      .quantity(
+        "value-quantity",
+        Prefix::Gt,
+        "5.4",
+        Some("http://unitsofmeasure.org"),
+        Some("mg"),
+      )
+      .string_contains("subject:Patient.name", "o'brien, j")
+      .chained("subject", Some("Patient"), "birthdate", "1974-12-25")
+      .has("Provenance", "target", "agent", "Practitioner/1")
+      .include("Observation:subject")
+      .revinclude("Provenance:target")
+      .sort("date", true)
+      .sort("code", false)
+      .count(20)
+      .summary(Summary::Data)
+      .elements(&["status", "code"]);
+    let params = search.params();
+    assert_eq!(
+      params[0],
+      ("code".to_string(), "http://loinc.org|29463-7".to_string())
+    );
+    assert_eq!(params[2].1, "ge2020-01-01");
+    assert_eq!(params[4].1, "gt5.4|http://unitsofmeasure.org|mg");
+    assert_eq!(
+      params[5],
+      (
+        "subject:Patient.name:contains".to_string(),
+        "o'brien\\, j".to_string()
+      )
+    );
+    assert_eq!(params[6].0, "subject:Patient.birthdate");
+    assert_eq!(params[7].0, "_has:Provenance:target:agent");
+    assert_eq!(
+      params.last().unwrap(),
+      &("_sort".to_string(), "-date,code".to_string())
+    );
+    assert!(search.url().starts_with("Observation?code=http%3A%2F%2Floinc.org%7C29463-7&subject=Patient%2F123&date=ge2020-01-01&date=lt2021-01-01&"));
+    assert_eq!(
+      SearchBuilder::<Patient>::new()
+        .string_exact("family", "Chalmers")
+        .url(),
+      "Patient?family%3Aexact=Chalmers"
+    );
+  }
+
+  #[test]
+  fn test_search_pages() {
+    let page = |entries: Value, next: Option<&str>| {
+      let mut bundle = json!({"resourceType": "Bundle", "type": "searchset", "entry": entries,
+        "link": [{"relation": "self", "url": "http://example.org/fhir/Patient?name=peter"}]});
+      if let Some(next) = next {
+        bundle["link"]
+          .as_array_mut()
+          .unwrap()
+          .push(json!({"relation": "next", "url": next}));
+      }
+      HttpResponse::json(200, &bundle)
+    };
+    let mut transport = FakeTransport::new();
+    transport
+      .respond(page(
+        json!([
+          {"resource": {"resourceType": "Patient", "id": "1"}, "search": {"mode": "match"}},
+          {"resource": {"resourceType": "Organization", "id": "2"}, "search": {"mode": "include"}}
+        ]),
+        Some("http://example.org/fhir?_getpages=abc&_offset=2"),
+      ))
+      .respond(page(
+        json!([{"resource": {"resourceType": "Patient", "id": "3"}, "search": {"mode": "match"}}]),
+        Some("http://example.org/fhir?_getpages=abc&_offset=3"),
+      ));
+    let mut client = Client::new("http://example.org/fhir", transport);
+    let mut search = SearchBuilder::<Patient>::new();
+    search
+      .string("name", "peter")
+      .include("Patient:organization");
+
+    let mut iter = client.search_all(&search).unwrap();
+    let first = iter.next().unwrap().unwrap();
+    assert!(first.is_match());
+    assert_eq!(first.typed::<Patient>().unwrap().id(), Some("1"));
+    assert!(matches!(iter.next(), Some(Ok(SearchEntry::Include(_)))));
+    assert_eq!(
+      iter.next().unwrap().unwrap().resource().to_json()["id"],
+      "3"
+    );
+    assert_eq!(iter.pages(), 2);
+    assert!(matches!(iter.next(), Some(Err(ClientError::Transport(_)))));
+    assert!(iter.next().is_none());
+
+    let requests = client.transport().requests();
+    assert_eq!(
+      requests[0].url,
+      "http://example.org/fhir/Patient?name=peter&_include=Patient%3Aorganization"
+    );
+    assert_eq!(
+      requests[1].url,
+      "http://example.org/fhir?_getpages=abc&_offset=2"
+    );
+  }
+}
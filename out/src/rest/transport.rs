@@ -0,0 +1,117 @@
+//! The HTTP exchange underneath the client, kept behind a trait so that any
+//! HTTP library, or an in-process server, can carry it.
+
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+  pub method: String,
+  /// The absolute URL, including the query.
+  pub url: String,
+  pub headers: Vec<(String, String)>,
+  pub body: Option<String>,
+}
+
+impl HttpRequest {
+  /// The first value of a header, matching its name case-insensitively.
+  pub fn header(&self, name: &str) -> Option<&str> {
+    find_header(&self.headers, name)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+  pub status: u16,
+  pub headers: Vec<(String, String)>,
+  pub body: String,
+}
+
+impl HttpResponse {
+  /// A response with a FHIR JSON body.
+  pub fn json(status: u16, body: &serde_json::Value) -> HttpResponse {
+    HttpResponse {
+      status,
+      headers: vec![(
+        "Content-Type".to_string(),
+        "application/fhir+json".to_string(),
+      )],
+      body: body.to_string(),
+    }
+  }
+
+  /// The first value of a header, matching its name case-insensitively.
+  pub fn header(&self, name: &str) -> Option<&str> {
+    find_header(&self.headers, name)
+  }
+}
+
+fn find_header<'h>(headers: &'h [(String, String)], name: &str) -> Option<&'h str> {
+  headers
+    .iter()
+    .find(|(key, _)| key.eq_ignore_ascii_case(name))
+    .map(|(_, value)| value.as_str())
+}
+
+/// The rest of `url` after the service base `base`, which must end at a
+/// path segment: `http://x/fhir` is the base of `http://x/fhir/Patient` and
+/// `http://x/fhir?_id=1`, but not of `http://x/fhirx/Patient`.
+pub fn below_base<'u>(url: &'u str, base: &str) -> Option<&'u str> {
+  let rest = url.strip_prefix(base.trim_end_matches('/'))?;
+  match rest.chars().next() {
+    None | Some('/') | Some('?') => Some(rest),
+    _ => None,
+  }
+}
+
+/// Sends a request and returns the response. Errors are failures to
+/// exchange at all; error statuses are responses.
+pub trait Transport {
+  fn send(&mut self, request: HttpRequest) -> Result<HttpResponse, String>;
+}
+
+/// Any function from request to response is a transport, e.g. a closure
+/// calling an in-process server.
+impl<F> Transport for F
+where
+  F: FnMut(HttpRequest) -> Result<HttpResponse, String>,
+{
+  fn send(&mut self, request: HttpRequest) -> Result<HttpResponse, String> {
+    self(request)
+  }
+}
+
+/// A transport for tests: records the requests and answers with the queued
+/// responses in order.
+#[derive(Debug, Clone, Default)]
+pub struct FakeTransport {
+  responses: VecDeque<HttpResponse>,
+  requests: Vec<HttpRequest>,
+}
+
+impl FakeTransport {
+  pub fn new() -> FakeTransport {
+    FakeTransport::default()
+  }
+
+  /// Queues the response to the next request.
+  pub fn respond(&mut self, response: HttpResponse) -> &mut Self {
+    self.responses.push_back(response);
+    self
+  }
+
+  /// The requests sent so far.
+  pub fn requests(&self) -> &[HttpRequest] {
+    &self.requests
+  }
+}
+
+impl Transport for FakeTransport {
+  fn send(&mut self, request: HttpRequest) -> Result<HttpResponse, String> {
+    let response = self
+      .responses
+      .pop_front()
+      .ok_or_else(|| format!("No response queued for {} {}", request.method, request.url));
+    self.requests.push(request);
+    response
+  }
+}
@@ -0,0 +1,73 @@
+//! The CapabilityStatement returned by `GET [base]/metadata`.
+
+use crate::model::CapabilityStatement::CapabilityStatement;
+use crate::rest::RESOURCE_TYPES;
+use crate::temporal::{DateTime, Precision};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+/// The FHIR version of the generated models.
+pub const FHIR_VERSION: &str = "4.0.1";
+
+const TYPE_INTERACTIONS: &[&str] = &[
+  "read",
+  "vread",
+  "update",
+  "patch",
+  "delete",
+  "history-instance",
+  "history-type",
+  "create",
+  "search-type",
+];
+
+/// Describes a server at `base` supporting every resource type, with the
+/// interactions `Server` implements.
+pub fn capability_statement(base: &str) -> CapabilityStatement<'static> {
+  let interactions: Vec<Value> = TYPE_INTERACTIONS
+    .iter()
+    .map(|code| json!({ "code": code }))
+    .collect();
+  let resources: Vec<Value> = RESOURCE_TYPES
+    .iter()
+    .map(|resource_type| {
+      json!({
+        "type": resource_type,
+        "interaction": interactions,
+        "versioning": "versioned-update",
+        "readHistory": true,
+        "updateCreate": true,
+        "conditionalCreate": true,
+        "conditionalUpdate": true,
+        "conditionalDelete": "single"
+      })
+    })
+    .collect();
+  CapabilityStatement {
+    value: Cow::Owned(json!({
+      "resourceType": "CapabilityStatement",
+      "status": "active",
+      "date": DateTime::now(Precision::Second).to_string(),
+      "kind": "instance",
+      "implementation": {
+        "description": "In-process FHIR server",
+        "url": base
+      },
+      "fhirVersion": FHIR_VERSION,
+      "format": ["json"],
+      "rest": [{
+        "mode": "server",
+        "resource": resources,
+        "interaction": [
+          {"code": "transaction"},
+          {"code": "batch"},
+          {"code": "history-system"}
+        ],
+        "operation": [{
+          "name": "graphql",
+          "definition": "http://hl7.org/fhir/OperationDefinition/Resource-graphql"
+        }]
+      }]
+    })),
+  }
+}
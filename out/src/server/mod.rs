@@ -0,0 +1,778 @@
+//! An in-process FHIR server: the RESTful API over a `ResourceStore`.
+//!
+//! `Server::handle` answers an `HttpRequest` with an `HttpResponse`, and the
+//! server is a `Transport`, so a `rest::Client` can talk to it directly.
+//! Interactions on a single resource are run as a one-entry batch by the
+//! `BundleProcessor`, so they behave exactly as the entries of a batch do.
+//! History is available for an instance, a type and the whole system.
+//! Searches are paged with `_count` and `_offset`, and `_include` follows
+//! the references a search parameter selects in the matches. Reads and
+//! searches honour `_summary` and `_elements` through a `Projector`, which
+//! must be given the StructureDefinitions to apply them with. `$graphql`
+//! runs GraphQL queries against the store. Only JSON is supported: asking
+//! for another `_format` is answered with `406 Not Acceptable`.
+//! Errors are answered with an OperationOutcome.
+
+pub mod capability;
+
+pub use capability::{capability_statement, FHIR_VERSION};
+
+use crate::bundle::{error_outcome, status_line, BundleProcessor, ProcessError};
+use crate::graphql::Executor;
+use crate::index::{IndexValue, SearchIndexer};
+use crate::model::Bundle::Bundle;
+use crate::model::ResourceList::ResourceList;
+use crate::patch::{binary, JsonPatch, PatchError, JSON_PATCH};
+use crate::projection::Projector;
+use crate::reference::ReferenceKind;
+use crate::rest::{below_base, is_resource_type, HttpRequest, HttpResponse, Transport};
+use crate::store::criteria::{parse_query, percent_encode};
+use crate::store::{ResourceStore, StoreError};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+const FHIR_JSON: &str = "application/fhir+json";
+const JSON: &str = "application/json";
+
+/// The media type to answer with for a `_format` value or an `Accept`
+/// media range, or `None` if it is not JSON.
+fn json_media_type(format: &str) -> Option<&'static str> {
+  match format.split(';').next().unwrap_or_default().trim() {
+    "json" | "application/fhir+json" | "application/json+fhir" | "application/*" | "*/*" => {
+      Some(FHIR_JSON)
+    }
+    "application/json" => Some(JSON),
+    _ => None,
+  }
+}
+
+/// Chooses the media type of the response: `_format` overrides `Accept`.
+fn negotiate(format: Option<&str>, accept: Option<&str>) -> Result<&'static str, Failure> {
+  let media_type = match (format, accept) {
+    (Some(format), _) => json_media_type(format),
+    (None, Some(accept)) if !accept.trim().is_empty() => {
+      accept.split(',').find_map(json_media_type)
+    }
+    _ => Some(FHIR_JSON),
+  };
+  media_type.ok_or_else(|| Failure::new(406, "Only JSON is supported"))
+}
+
+#[derive(Debug, Clone)]
+struct Failure {
+  status: u16,
+  outcome: Value,
+}
+
+impl Failure {
+  fn new(status: u16, message: &str) -> Failure {
+    Failure {
+      status,
+      outcome: error_outcome(status, message).to_json(),
+    }
+  }
+}
+
+impl From<StoreError> for Failure {
+  fn from(error: StoreError) -> Failure {
+    Failure::new(error.status(), &error.to_string())
+  }
+}
+
+impl From<PatchError> for Failure {
+  fn from(error: PatchError) -> Failure {
+    Failure::new(error.status(), &error.to_string())
+  }
+}
+
+impl From<ProcessError> for Failure {
+  fn from(error: ProcessError) -> Failure {
+    Failure {
+      status: error.status(),
+      outcome: error.outcome().to_json(),
+    }
+  }
+}
+
+/// A response before it is serialised.
+struct Reply {
+  status: u16,
+  headers: Vec<(String, String)>,
+  body: Option<Value>,
+}
+
+impl Reply {
+  fn new(status: u16, body: Option<Value>) -> Reply {
+    Reply {
+      status,
+      headers: vec![],
+      body,
+    }
+  }
+}
+
+fn query(params: &[(String, String)]) -> String {
+  params
+    .iter()
+    .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+    .collect::<Vec<String>>()
+    .join("&")
+}
+
+pub struct Server<S: ResourceStore> {
+  base: String,
+  store: S,
+  page_size: usize,
+  projector: Projector,
+}
+
+impl<S: ResourceStore> Server<S> {
+  /// A server at `base`, e.g. `http://localhost/fhir`, over `store`.
+  pub fn new(base: &str, store: S) -> Self {
+    Server {
+      base: base.trim_end_matches('/').to_string(),
+      store,
+      page_size: 50,
+      projector: Projector::new(),
+    }
+  }
+
+  /// The number of matches on a page when the search has no `_count`.
+  pub fn page_size(&mut self, page_size: usize) -> &mut Self {
+    self.page_size = page_size;
+    self
+  }
+
+  /// The definitions that `_summary` and `_elements` are applied with.
+  /// Without them, both are answered with `400 Bad Request`.
+  pub fn projector(&mut self, projector: Projector) -> &mut Self {
+    self.projector = projector;
+    self
+  }
+
+  pub fn store(&self) -> &S {
+    &self.store
+  }
+
+  pub fn store_mut(&mut self) -> &mut S {
+    &mut self.store
+  }
+
+  /// The path below the base, without slashes at either end, and the query.
+  fn split<'u>(&self, url: &'u str) -> (&'u str, &'u str) {
+    let url = below_base(url, &self.base).unwrap_or(url);
+    let mut parts = url.splitn(2, '?');
+    let path = parts.next().unwrap_or_default().trim_matches('/');
+    (path, parts.next().unwrap_or_default())
+  }
+
+  pub fn handle(&mut self, request: &HttpRequest) -> HttpResponse {
+    let (path, query) = self.split(&request.url);
+    let params = parse_query(query);
+    let param = |name: &str| {
+      params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+    };
+    let pretty = param("_pretty") == Some("true");
+    let negotiated = negotiate(param("_format"), request.header("Accept"));
+    let media_type = negotiated
+      .as_ref()
+      .map_or(FHIR_JSON, |media_type| *media_type);
+    let result = negotiated
+      .and_then(|_| self.projectable(&params))
+      .and_then(|_| self.route(request, path, query, &params));
+    let mut reply =
+      result.unwrap_or_else(|failure| Reply::new(failure.status, Some(failure.outcome)));
+    let mut body = String::new();
+    if let Some(value) = reply.body.take() {
+      reply.headers.push((
+        "Content-Type".to_string(),
+        format!("{}; charset=utf-8", media_type),
+      ));
+      if request.method != "HEAD" {
+        body = if pretty {
+          serde_json::to_string_pretty(&value).unwrap_or_default()
+        } else {
+          value.to_string()
+        };
+      }
+    }
+    HttpResponse {
+      status: reply.status,
+      headers: reply.headers,
+      body,
+    }
+  }
+
+  fn route(
+    &mut self,
+    request: &HttpRequest,
+    path: &str,
+    query: &str,
+    params: &[(String, String)],
+  ) -> Result<Reply, Failure> {
+    let segments: Vec<&str> = if path.is_empty() {
+      vec![]
+    } else {
+      path.split('/').collect()
+    };
+    match (request.method.as_str(), segments.as_slice()) {
+      ("POST", []) => self.process(request),
+      ("GET", ["_history"]) => self.interact(request, path, query),
+      ("GET", ["metadata"]) => Ok(Reply::new(
+        200,
+        Some(capability_statement(&self.base).to_json()),
+      )),
+      ("GET", ["$graphql"]) | ("POST", ["$graphql"]) => self.graphql(request, params),
+      (_, [resource_type, ..]) if !is_resource_type(resource_type) => Err(Failure::new(
+        404,
+        &format!("{} is not a resource type", resource_type),
+      )),
+      ("GET", [resource_type]) | ("HEAD", [resource_type]) => self.search(resource_type, params),
+      ("POST", [resource_type, "_search"]) => {
+        let mut params = params.to_vec();
+        params.extend(parse_query(request.body.as_deref().unwrap_or_default()));
+        self.search(resource_type, &params)
+      }
+      (_, [_, ..]) => self.interact(request, path, query),
+      _ => Err(Failure::new(
+        400,
+        &format!("{} of the base URL is not supported", request.method),
+      )),
+    }
+  }
+
+  /// `[base]/$graphql`, with the query in the parameters of a GET or in the
+  /// JSON body of a POST. The response is GraphQL's, not a FHIR resource.
+  fn graphql(&self, request: &HttpRequest, params: &[(String, String)]) -> Result<Reply, Failure> {
+    let graphql_request = if request.method == "POST" {
+      Self::body(request)?.unwrap_or_default()
+    } else {
+      let param = |name: &str| {
+        params
+          .iter()
+          .find(|(key, _)| key == name)
+          .map(|(_, value)| value.as_str())
+      };
+      json!({
+        "query": param("query"),
+        "operationName": param("operationName"),
+        "variables": param("variables").and_then(|variables| serde_json::from_str::<Value>(variables).ok()),
+      })
+    };
+    let response = Executor::new(&self.store).execute_request(&graphql_request);
+    Ok(Reply::new(200, Some(response)))
+  }
+
+  /// The JSON body of a request, if it has one.
+  fn body(request: &HttpRequest) -> Result<Option<Value>, Failure> {
+    let text = match request.body.as_deref() {
+      Some(text) if !text.trim().is_empty() => text,
+      _ => return Ok(None),
+    };
+    if let Some(content_type) = request.header("Content-Type") {
+      let media_type = content_type.split(';').next().unwrap_or_default().trim();
+      if json_media_type(media_type).is_none() && media_type != JSON_PATCH {
+        return Err(Failure::new(
+          415,
+          &format!("{} is not supported", media_type),
+        ));
+      }
+    }
+    serde_json::from_str(text)
+      .map(Some)
+      .map_err(|error| Failure::new(400, &format!("The body is not JSON: {}", error)))
+  }
+
+  /// `POST [base]` of a transaction or batch Bundle.
+  fn process(&mut self, request: &HttpRequest) -> Result<Reply, Failure> {
+    let body = Self::body(request)?
+      .ok_or_else(|| Failure::new(400, "A transaction or batch Bundle is needed"))?;
+    let bundle = Bundle {
+      value: Cow::Owned(body),
+    };
+    let response = BundleProcessor::new(&mut self.store)
+      .base(&self.base)
+      .process(&bundle)?;
+    Ok(Reply::new(200, Some(response.to_json())))
+  }
+
+  /// Fails if the parameters ask for a projection, which needs the
+  /// definitions of the projector.
+  fn projectable(&self, params: &[(String, String)]) -> Result<(), Failure> {
+    let projects = params.iter().any(|(key, value)| {
+      key == "_elements" || (key == "_summary" && !matches!(value.as_str(), "count" | "false"))
+    });
+    if projects && self.projector.is_empty() {
+      return Err(Failure::new(
+        400,
+        "_summary and _elements need StructureDefinitions, and none are loaded",
+      ));
+    }
+    Ok(())
+  }
+
+  /// A search of one type, paged with `_count` and `_offset`.
+  fn search(&self, resource_type: &str, params: &[(String, String)]) -> Result<Reply, Failure> {
+    let number = |name: &str, default: usize| match params.iter().rev().find(|(key, _)| key == name)
+    {
+      Some((_, value)) => value
+        .parse::<usize>()
+        .map_err(|_| Failure::new(400, &format!("{} must be a number", name))),
+      None => Ok(default),
+    };
+    let count = number("_count", self.page_size)?;
+    let offset = number("_offset", 0)?;
+    let summary_count = params
+      .iter()
+      .any(|(key, value)| key == "_summary" && value == "count");
+    let matches = self.store.search(resource_type, params)?;
+    let total = matches.len();
+    let page: Vec<Value> = if summary_count {
+      vec![]
+    } else {
+      matches.into_iter().skip(offset).take(count).collect()
+    };
+
+    let mut included: Vec<Value> = vec![];
+    for (_, include) in params.iter().filter(|(key, _)| key == "_include") {
+      let parts: Vec<&str> = include.split(':').collect();
+      let (source, name, target) = match parts.as_slice() {
+        [source, name] => (*source, *name, None),
+        [source, name, target] => (*source, *name, Some(*target)),
+        _ => return Err(Failure::new(400, &format!("Invalid _include {}", include))),
+      };
+      if source != resource_type {
+        continue;
+      }
+      for resource in &page {
+        for value in SearchIndexer::fhir().values(resource, name) {
+          let reference = match value {
+            IndexValue::Reference {
+              target: Some(reference),
+              ..
+            } => reference,
+            _ => continue,
+          };
+          let local = match reference.kind {
+            ReferenceKind::Relative => true,
+            ReferenceKind::Absolute => reference.base.as_deref() == Some(self.base.as_str()),
+            _ => false,
+          };
+          let (include_type, id) = match (&reference.resource_type, &reference.id) {
+            (Some(include_type), Some(id)) if local => (include_type.as_str(), id.as_str()),
+            _ => continue,
+          };
+          if target.is_some_and(|target| target != include_type) {
+            continue;
+          }
+          if let Ok(resource) = self.store.read(include_type, id) {
+            if !included.contains(&resource) && !page.contains(&resource) {
+              included.push(resource);
+            }
+          }
+        }
+      }
+    }
+
+    let entry = |resource: Value, mode: &str| {
+      let full_url = format!(
+        "{}/{}/{}",
+        self.base,
+        resource["resourceType"].as_str().unwrap_or_default(),
+        resource["id"].as_str().unwrap_or_default()
+      );
+      json!({"fullUrl": full_url, "resource": resource, "search": {"mode": mode}})
+    };
+    let mut entries: Vec<Value> = page
+      .iter()
+      .map(|resource| entry(self.projector.project(resource, params), "match"))
+      .collect();
+    entries.extend(included.into_iter().map(|r| entry(r, "include")));
+
+    let url =
+      |params: &[(String, String)]| format!("{}/{}?{}", self.base, resource_type, query(params));
+    let mut links = vec![json!({"relation": "self", "url": url(params)})];
+    if !summary_count && offset + count < total {
+      let mut next: Vec<(String, String)> = params
+        .iter()
+        .filter(|(key, _)| key != "_offset")
+        .cloned()
+        .collect();
+      next.push(("_offset".to_string(), (offset + count).to_string()));
+      links.push(json!({"relation": "next", "url": url(&next)}));
+    }
+    Ok(Reply::new(
+      200,
+      Some(json!({
+        "resourceType": "Bundle",
+        "type": "searchset",
+        "total": total,
+        "link": links,
+        "entry": entries,
+      })),
+    ))
+  }
+
+  /// Any other interaction, run as a batch of one entry.
+  fn interact(&mut self, request: &HttpRequest, path: &str, query: &str) -> Result<Reply, Failure> {
+    let method = request.method.as_str();
+    let writes = matches!(method, "POST" | "PUT");
+    let body = Self::body(request)?;
+    let url = if query.is_empty() {
+      path.to_string()
+    } else {
+      format!("{}?{}", path, query)
+    };
+    let mut entry = json!({"request": {"method": method, "url": url}});
+    let headers = [
+      ("If-Match", "ifMatch"),
+      ("If-None-Match", "ifNoneMatch"),
+      ("If-None-Exist", "ifNoneExist"),
+      ("If-Modified-Since", "ifModifiedSince"),
+    ];
+    for (header, field) in headers.iter() {
+      if let Some(value) = request.header(header) {
+        entry["request"][*field] = json!(value);
+      }
+    }
+    if writes {
+      let resource = body.ok_or_else(|| Failure::new(400, "A resource is needed"))?;
+      let resource_type = path.split('/').next().unwrap_or_default();
+      if resource["resourceType"] == resource_type && !ResourceList::new(&resource).validate() {
+        return Err(Failure::new(
+          422,
+          &format!("The resource is not a valid {}", resource_type),
+        ));
+      }
+      entry["resource"] = resource;
+    } else if method == "PATCH" {
+      let patch = body.ok_or_else(|| Failure::new(400, "A patch is needed"))?;
+      // A JSON Patch travels in the batch as a Binary.
+      entry["resource"] = if patch.is_array() {
+        binary(&JsonPatch::parse(&patch)?).to_json()
+      } else {
+        patch
+      };
+    }
+
+    let batch = Bundle {
+      value: Cow::Owned(json!({"resourceType": "Bundle", "type": "batch", "entry": [entry]})),
+    };
+    let response = BundleProcessor::new(&mut self.store)
+      .base(&self.base)
+      .process(&batch)?;
+    let entry = &response.value["entry"][0];
+    let outcome = &entry["response"];
+    let status = outcome["status"]
+      .as_str()
+      .and_then(|line| line.split(' ').next())
+      .and_then(|code| code.parse().ok())
+      .unwrap_or(500);
+    let mut reply = Reply::new(status, None);
+    if let Some(location) = outcome["location"].as_str() {
+      let name = if status == 201 {
+        "Location"
+      } else {
+        "Content-Location"
+      };
+      reply
+        .headers
+        .push((name.to_string(), format!("{}/{}", self.base, location)));
+    }
+    if let Some(etag) = outcome["etag"].as_str() {
+      reply.headers.push(("ETag".to_string(), etag.to_string()));
+    }
+    if let Some(last_modified) = outcome["lastModified"].as_str() {
+      reply
+        .headers
+        .push(("Last-Modified".to_string(), last_modified.to_string()));
+    }
+    reply.body = outcome
+      .get("outcome")
+      .or_else(|| entry.get("resource"))
+      .cloned();
+    let segments: Vec<&str> = path.split('/').collect();
+    let reads = matches!(segments.as_slice(), [_, _] | [_, _, "_history", _]);
+    if reads
+      && matches!(method, "GET" | "HEAD")
+      && status == 200
+      && outcome.get("outcome").is_none()
+    {
+      let params = parse_query(query);
+      reply.body = reply
+        .body
+        .map(|resource| self.projector.project(&resource, &params));
+    }
+
+    if (writes || method == "PATCH") && status < 300 {
+      match request.header("Prefer").map(|prefer| prefer.trim()) {
+        Some("return=minimal") => reply.body = None,
+        Some("return=OperationOutcome") => {
+          reply.body = Some(json!({
+            "resourceType": "OperationOutcome",
+            "issue": [{
+              "severity": "information",
+              "code": "informational",
+              "details": {"text": status_line(status)}
+            }]
+          }))
+        }
+        _ => {}
+      }
+    }
+    Ok(reply)
+  }
+}
+
+impl<S: ResourceStore> Transport for Server<S> {
+  fn send(&mut self, request: HttpRequest) -> Result<HttpResponse, String> {
+    Ok(self.handle(&request))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Patient::Patient;
+  use crate::rest::{Client, ClientError, Resource, SearchBuilder};
+  use crate::store::MemoryStore;
+  use std::fs;
+  use std::path::Path;
+
+  const BASE: &str = "http://localhost/fhir";
+
+  fn get(server: &mut Server<MemoryStore>, url: &str, accept: Option<&str>) -> HttpResponse {
+    server.handle(&HttpRequest {
+      method: "GET".to_string(),
+      url: format!("{}/{}", BASE, url),
+      headers: accept
+        .map(|accept| vec![("Accept".to_string(), accept.to_string())])
+        .unwrap_or_default(),
+      body: None,
+    })
+  }
+
+  #[test]
+  fn test_client_against_server() {
+    let contents = fs::read_to_string("examples-json/patient-example.json").unwrap();
+    let example: Value = serde_json::from_str(&contents).unwrap();
+    let mut client = Client::new(BASE, Server::new(BASE, MemoryStore::new()));
+    client.transport_mut().page_size(2);
+
+    let created = client.create(&Patient::from_json(example)).unwrap();
+    assert_eq!(created.status, 201);
+    let id = created.id().unwrap().to_string();
+    let patient: Patient = client.read(&id).unwrap();
+    assert_eq!(patient.version_id(), Some("1"));
+
+    let updated = client.update(&patient).unwrap();
+    assert_eq!(updated.version_id.as_deref(), Some("2"));
+    assert!(matches!(
+      client.update(&patient),
+      Err(ClientError::PreconditionFailed(Some(_)))
+    ));
+    assert!(client.vread::<Patient>(&id, "1").is_ok());
+
+    for family in &["Chalmers", "Chalmers", "Windsor"] {
+      let patient = json!({"resourceType": "Patient", "name": [{"family": family}]});
+      client.create(&Patient::from_json(patient)).unwrap();
+    }
+    let mut search = SearchBuilder::<Patient>::new();
+    search.string("family", "Chalmers");
+    let found: Vec<_> = client.search_all(&search).unwrap().collect();
+    assert_eq!(found.len(), 3);
+    assert_eq!(client.transport().store().len(), 4);
+
+    let history = client
+      .follow(&format!("{}/Patient/_history", BASE))
+      .unwrap();
+    assert_eq!(history.value["entry"].as_array().unwrap().len(), 5);
+    assert_eq!(history.value["entry"][4]["request"]["method"], "POST");
+
+    client.delete::<Patient>(&id).unwrap();
+    let history = client.follow(&format!("{}/_history", BASE)).unwrap();
+    assert_eq!(history.value["entry"][0]["request"]["method"], "DELETE");
+    assert!(matches!(
+      client.read::<Patient>(&id),
+      Err(ClientError::Gone(_))
+    ));
+    assert!(matches!(
+      client.read::<Patient>("missing"),
+      Err(ClientError::NotFound(_))
+    ));
+  }
+
+  #[test]
+  fn test_server_negotiation() {
+    let mut server = Server::new(BASE, MemoryStore::new());
+    let response = get(&mut server, "metadata", Some("application/json"));
+    assert_eq!(response.status, 200);
+    assert_eq!(
+      response.header("Content-Type"),
+      Some("application/json; charset=utf-8")
+    );
+    let capability: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(capability["fhirVersion"], FHIR_VERSION);
+    assert_eq!(
+      capability["rest"][0]["interaction"][2]["code"],
+      "history-system"
+    );
+    let patient = capability["rest"][0]["resource"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .find(|resource| resource["type"] == "Patient")
+      .unwrap();
+    assert!(patient["interaction"]
+      .as_array()
+      .unwrap()
+      .contains(&json!({"code": "history-type"})));
+    assert!(patient.get("searchInclude").is_none());
+
+    let response = get(&mut server, "Patient?_format=xml", None);
+    assert_eq!(response.status, 406);
+    let outcome: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(outcome["issue"][0]["code"], "not-supported");
+    assert_eq!(
+      get(&mut server, "Patient", Some("application/fhir+xml")).status,
+      406
+    );
+    assert_eq!(
+      get(&mut server, "Patient?_format=json", Some("text/xml")).status,
+      200
+    );
+
+    let response = get(&mut server, "HumanName/1", None);
+    assert_eq!(response.status, 404);
+    assert!(response.body.contains("HumanName is not a resource type"));
+
+    let response = server.handle(&HttpRequest {
+      method: "POST".to_string(),
+      url: format!("{}/Patient", BASE),
+      headers: vec![(
+        "Content-Type".to_string(),
+        "application/fhir+xml".to_string(),
+      )],
+      body: Some("<Patient xmlns=\"http://hl7.org/fhir\"/>".to_string()),
+    });
+    assert_eq!(response.status, 415);
+
+    let response = get(
+      &mut server,
+      "$graphql?query=%7BPatientList%7Bid%7D%7D",
+      None,
+    );
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, r#"{"data":{"PatientList":[]}}"#);
+
+    server
+      .store_mut()
+      .update(
+        json!({"resourceType": "Patient", "id": "1", "gender": "male", "active": true}),
+        None,
+      )
+      .unwrap();
+    assert_eq!(
+      get(&mut server, "Patient/1?_elements=gender", None).status,
+      400
+    );
+    let mut projector = Projector::new();
+    projector.add_directory(Path::new("examples-json")).unwrap();
+    server.projector(projector);
+    let response = get(&mut server, "Patient/1?_elements=gender", None);
+    let patient: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(patient["gender"], "male");
+    assert!(patient.get("active").is_none());
+    assert_eq!(patient["meta"]["tag"][0]["code"], "SUBSETTED");
+    let response = server.handle(&HttpRequest {
+      method: "PATCH".to_string(),
+      url: format!("{}/Patient/1", BASE),
+      headers: vec![("Content-Type".to_string(), JSON_PATCH.to_string())],
+      body: Some(r#"[{"op": "replace", "path": "/gender", "value": "female"}]"#.to_string()),
+    });
+    assert_eq!(response.status, 200);
+    assert_eq!(response.header("ETag"), Some("W/\"2\""));
+    assert_eq!(
+      server.store().read("Patient", "1").unwrap()["gender"],
+      "female"
+    );
+    let response = get(&mut server, "Patient?_elements=active", None);
+    let bundle: Value = serde_json::from_str(&response.body).unwrap();
+    assert!(bundle["entry"][0]["resource"].get("gender").is_none());
+  }
+
+  #[test]
+  fn test_server_summary() {
+    let mut server = Server::new(BASE, MemoryStore::new());
+    let contents = fs::read_to_string("examples-json/observation-example.json").unwrap();
+    let observation: Value = serde_json::from_str(&contents).unwrap();
+    server.store_mut().update(observation, None).unwrap();
+    assert_eq!(
+      get(&mut server, "Observation/example?_summary=true", None).status,
+      400
+    );
+    assert_eq!(
+      get(&mut server, "Observation/example?_summary=false", None).status,
+      200
+    );
+
+    let mut projector = Projector::new();
+    assert!(projector.add_directory(Path::new("examples-json")).unwrap() > 100);
+    server.projector(projector);
+    for summary in &["true", "text"] {
+      let url = format!("Observation/example?_summary={}", summary);
+      let response = get(&mut server, &url, None);
+      assert_eq!(response.status, 200);
+      let summarised: Value = serde_json::from_str(&response.body).unwrap();
+      assert_eq!(summarised["status"], "final");
+      assert!(summarised.get("code").is_some());
+      assert_eq!(summarised.get("text").is_some(), *summary == "text");
+      assert_eq!(
+        summarised.get("valueQuantity").is_some(),
+        *summary == "true"
+      );
+      assert_eq!(summarised["meta"]["tag"][1]["code"], "SUBSETTED");
+    }
+  }
+
+  #[test]
+  fn test_server_include() {
+    let mut server = Server::new(BASE, MemoryStore::new());
+    let store = server.store_mut();
+    store
+      .update(json!({"resourceType": "Patient", "id": "p1"}), None)
+      .unwrap();
+    store
+      .update(json!({"resourceType": "Practitioner", "id": "d1"}), None)
+      .unwrap();
+    store
+      .update(
+        json!({"resourceType": "Observation", "id": "o1", "status": "final",
+          "code": {"text": "weight"}, "subject": {"reference": "Patient/p1"},
+          "performer": [{"reference": "Practitioner/d1"}]}),
+        None,
+      )
+      .unwrap();
+    let response = get(
+      &mut server,
+      "Observation?_include=Observation:patient",
+      None,
+    );
+    let bundle: Value = serde_json::from_str(&response.body).unwrap();
+    let entries = bundle["entry"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1]["resource"]["id"], "p1");
+    assert_eq!(entries[1]["search"]["mode"], "include");
+
+    let response = server.handle(&HttpRequest {
+      method: "GET".to_string(),
+      url: "http://localhost/fhirx/Patient/p1".to_string(),
+      headers: vec![],
+      body: None,
+    });
+    assert_ne!(response.status, 200);
+  }
+}
@@ -0,0 +1,331 @@
+//! Search parameters as they appear in a query string, such as
+//! `identifier=http://example.org/ids|123` or `date=ge2020-01-01`: decoding
+//! them, splitting them into criteria, and the prefixes and escapes their
+//! values use. `SearchIndexer::query` evaluates the criteria.
+
+use super::StoreError;
+use crate::temporal::Interval;
+
+fn hex(byte: u8) -> Option<u8> {
+  (byte as char).to_digit(16).map(|d| d as u8)
+}
+
+/// Decodes `%XX` escapes and `+`.
+pub fn percent_decode(text: &str) -> String {
+  let bytes = text.as_bytes();
+  let mut decoded = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'%' if i + 2 < bytes.len() => match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+        (Some(high), Some(low)) => {
+          decoded.push(high * 16 + low);
+          i += 3;
+          continue;
+        }
+        _ => decoded.push(b'%'),
+      },
+      b'+' => decoded.push(b' '),
+      byte => decoded.push(byte),
+    }
+    i += 1;
+  }
+  String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Encodes a query parameter name or value: everything but unreserved
+/// characters becomes `%XX`.
+pub fn percent_encode(text: &str) -> String {
+  text
+    .bytes()
+    .map(|byte| match byte {
+      b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+        (byte as char).to_string()
+      }
+      _ => format!("%{:02X}", byte),
+    })
+    .collect()
+}
+
+/// The parameters of a query string, with or without the leading `?`.
+pub fn parse_query(query: &str) -> Vec<(String, String)> {
+  query
+    .trim_start_matches('?')
+    .split('&')
+    .filter(|pair| !pair.is_empty())
+    .map(|pair| {
+      let mut parts = pair.splitn(2, '=');
+      let name = parts.next().unwrap_or_default();
+      let value = parts.next().unwrap_or_default();
+      (percent_decode(name), percent_decode(value))
+    })
+    .collect()
+}
+
+/// The comparison a prefix such as `ge` in `ge2020-01-01` asks for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Prefix {
+  Eq,
+  Ne,
+  Gt,
+  Lt,
+  Ge,
+  Le,
+  /// Starts after.
+  Sa,
+  /// Ends before.
+  Eb,
+  /// Approximately.
+  Ap,
+}
+
+const PREFIXES: &[Prefix] = &[
+  Prefix::Eq,
+  Prefix::Ne,
+  Prefix::Gt,
+  Prefix::Lt,
+  Prefix::Ge,
+  Prefix::Le,
+  Prefix::Sa,
+  Prefix::Eb,
+  Prefix::Ap,
+];
+
+/// The step of the last digit of a number: 1 for `100`, 0.01 for `1.50`.
+fn precision(number: &str) -> f64 {
+  let (mantissa, exponent) = match number.find(['e', 'E']) {
+    Some(index) => (&number[..index], number[index + 1..].parse().unwrap_or(0)),
+    None => (number, 0),
+  };
+  let decimals = mantissa.find('.').map_or(0, |dot| mantissa.len() - dot - 1);
+  10f64.powi(exponent - decimals as i32)
+}
+
+impl Prefix {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Prefix::Eq => "eq",
+      Prefix::Ne => "ne",
+      Prefix::Gt => "gt",
+      Prefix::Lt => "lt",
+      Prefix::Ge => "ge",
+      Prefix::Le => "le",
+      Prefix::Sa => "sa",
+      Prefix::Eb => "eb",
+      Prefix::Ap => "ap",
+    }
+  }
+
+  /// The prefix of a number, date or quantity value and the rest of the
+  /// value. A value without a prefix is compared with `eq`.
+  pub fn split(value: &str) -> (Prefix, &str) {
+    for prefix in PREFIXES {
+      if let Some(rest) = value.strip_prefix(prefix.as_str()) {
+        if rest.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') {
+          return (*prefix, rest);
+        }
+      }
+    }
+    (Prefix::Eq, value)
+  }
+
+  /// Whether the instants of a `target` element satisfy the prefix for the
+  /// searched instants: `eq` when the search includes all of the target,
+  /// `gt` when some of the target is after the search, and so on.
+  pub fn dates(self, target: &Interval, search: &Interval) -> bool {
+    let eq = search.includes(target);
+    let gt = match (target.high, search.high) {
+      (None, _) => true,
+      (_, None) => false,
+      (Some(target), Some(search)) => target > search,
+    };
+    let lt = match (target.low, search.low) {
+      (None, _) => true,
+      (_, None) => false,
+      (Some(target), Some(search)) => target < search,
+    };
+    match self {
+      Prefix::Eq => eq,
+      Prefix::Ne => !eq,
+      Prefix::Gt => gt,
+      Prefix::Lt => lt,
+      Prefix::Ge => gt || eq,
+      Prefix::Le => lt || eq,
+      Prefix::Sa => matches!((target.low, search.high), (Some(t), Some(s)) if t >= s),
+      Prefix::Eb => matches!((target.high, search.low), (Some(t), Some(s)) if t <= s),
+      Prefix::Ap => target.overlaps(search),
+    }
+  }
+
+  /// Whether a `target` number satisfies the prefix for a searched
+  /// `value`, which stands for the range `value ± half`; see
+  /// `number_range`.
+  pub fn numbers(self, target: f64, value: f64, half: f64) -> bool {
+    let eq = value - half <= target && target < value + half;
+    match self {
+      Prefix::Eq => eq,
+      Prefix::Ne => !eq,
+      Prefix::Gt | Prefix::Sa => target > value,
+      Prefix::Lt | Prefix::Eb => target < value,
+      Prefix::Ge => target >= value,
+      Prefix::Le => target <= value,
+      Prefix::Ap => (target - value).abs() <= (value * 0.1).abs().max(half),
+    }
+  }
+}
+
+/// A searched number and half the step of its last digit: `100` stands
+/// for anything from 99.5 up to 100.5, `100.0` from 99.95 up to 100.05.
+pub fn number_range(text: &str) -> Option<(f64, f64)> {
+  let value: f64 = text.parse().ok()?;
+  Some((value, precision(text) / 2.0))
+}
+
+/// Splits a parameter value at every `separator` not escaped with a
+/// backslash. The escapes are kept, for `unescape` to remove.
+pub fn split_escaped(text: &str, separator: char) -> Vec<&str> {
+  let mut parts = vec![];
+  let (mut start, mut escaped) = (0, false);
+  for (index, c) in text.char_indices() {
+    if escaped {
+      escaped = false;
+    } else if c == '\\' {
+      escaped = true;
+    } else if c == separator {
+      parts.push(&text[start..index]);
+      start = index + c.len_utf8();
+    }
+  }
+  parts.push(&text[start..]);
+  parts
+}
+
+/// Removes the backslashes that escape `\`, `,`, `$` and `|`.
+pub fn unescape(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut chars = text.chars();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => result.extend(chars.next()),
+      c => result.push(c),
+    }
+  }
+  result
+}
+
+/// Parameters that shape the results rather than select resources.
+pub const RESULT_PARAMETERS: &[&str] = &[
+  "_count",
+  "_offset",
+  "_sort",
+  "_include",
+  "_revinclude",
+  "_summary",
+  "_elements",
+  "_total",
+  "_contained",
+  "_containedType",
+  "_format",
+  "_pretty",
+];
+
+/// One search parameter: `name:modifier=value,value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Criterion {
+  pub name: String,
+  pub modifier: Option<String>,
+  /// The alternatives, still escaped.
+  pub values: Vec<String>,
+}
+
+impl Criterion {
+  pub fn parse(name: &str, value: &str) -> Criterion {
+    let mut parts = name.splitn(2, ':');
+    Criterion {
+      name: parts.next().unwrap_or_default().to_string(),
+      modifier: parts.next().map(|modifier| modifier.to_string()),
+      values: split_escaped(value, ',')
+        .into_iter()
+        .map(|value| value.to_string())
+        .collect(),
+    }
+  }
+
+  /// The value of a `:missing` modifier.
+  pub fn missing(&self) -> Result<bool, StoreError> {
+    match self.values.as_slice() {
+      [value] if value == "true" => Ok(true),
+      [value] if value == "false" => Ok(false),
+      _ => Err(StoreError::Search(format!(
+        "{}:missing must be true or false",
+        self.name
+      ))),
+    }
+  }
+
+  pub fn unsupported(&self) -> StoreError {
+    match &self.modifier {
+      Some(modifier) => StoreError::Search(format!(
+        "The modifier :{} of {} is not supported",
+        modifier, self.name
+      )),
+      None => StoreError::Search(format!("{} is not supported", self.name)),
+    }
+  }
+}
+
+/// The criteria among search parameters, leaving out result parameters.
+pub fn criteria(params: &[(String, String)]) -> Vec<Criterion> {
+  params
+    .iter()
+    .filter(|(name, _)| !RESULT_PARAMETERS.contains(&name.as_str()))
+    .map(|(name, value)| Criterion::parse(name, value))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_criteria() {
+    let params = parse_query("family:exact=a\\,b,c&_count=10&date=ge2020");
+    assert_eq!(
+      criteria(&params),
+      vec![
+        Criterion {
+          name: "family".to_string(),
+          modifier: Some("exact".to_string()),
+          values: vec!["a\\,b".to_string(), "c".to_string()],
+        },
+        Criterion {
+          name: "date".to_string(),
+          modifier: None,
+          values: vec!["ge2020".to_string()],
+        }
+      ]
+    );
+    assert_eq!(Prefix::split("ge2020"), (Prefix::Ge, "2020"));
+    assert_eq!(Prefix::split("gene"), (Prefix::Eq, "gene"));
+    for prefix in PREFIXES {
+      let value = format!("{}5", prefix.as_str());
+      assert_eq!(Prefix::split(&value), (*prefix, "5"));
+    }
+    assert_eq!(number_range("1.50"), Some((1.5, 0.005)));
+    assert!(Prefix::Eq.numbers(100.4, 100.0, 0.5) && !Prefix::Eq.numbers(100.5, 100.0, 0.5));
+    assert_eq!(split_escaped("a\\,b,c\\|d", ','), vec!["a\\,b", "c\\|d"]);
+    assert_eq!(unescape("a\\,b\\\\"), "a,b\\");
+    assert_eq!(
+      parse_query("?name=peter%20james&x=a+b"),
+      vec![
+        ("name".to_string(), "peter james".to_string()),
+        ("x".to_string(), "a b".to_string())
+      ]
+    );
+    assert_eq!(percent_encode("a b|c"), "a%20b%7Cc");
+    assert_eq!(
+      percent_decode(&percent_encode("Müller & Co")),
+      "Müller & Co"
+    );
+  }
+}
@@ -0,0 +1,368 @@
+//! A `ResourceStore` persisted to a file.
+//!
+//! The file is a log with one line per commit: a JSON array of the versions
+//! written, each with its resource type and id. A line is appended with a
+//! single write and synced to disk before the commit returns, and opening
+//! the store replays the log into memory. A crash can only leave a partial
+//! last line; opening the store drops it by writing the complete lines to a
+//! temporary file that is renamed over the log, so a commit is either
+//! entirely in the log or not at all. A complete line that is corrupt fails
+//! the open and leaves the file as it is.
+//!
+//! Writes are committed as they are made, except in a transaction: the
+//! writes between `begin` and `commit` are committed as one line, and
+//! `rollback` undoes them without touching the file.
+
+use super::memory::MemoryStore;
+use super::{Change, ResourceStore, StoreError, Version};
+use serde_json::{json, Value};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+fn storage(error: std::io::Error) -> StoreError {
+  StoreError::Storage(error.to_string())
+}
+
+fn to_json(change: &Change) -> Value {
+  json!({
+    "resourceType": change.resource_type,
+    "id": change.id,
+    "versionId": change.version.version_id,
+    "lastUpdated": change.version.last_updated,
+    "resource": change.version.resource,
+  })
+}
+
+fn from_json(value: &Value) -> Option<Change> {
+  let text = |name: &str| value[name].as_str().map(|s| s.to_string());
+  Some(Change {
+    resource_type: text("resourceType")?,
+    id: text("id")?,
+    version: Version {
+      version_id: text("versionId")?,
+      last_updated: text("lastUpdated")?,
+      resource: Some(value["resource"].clone()).filter(|r| r.is_object()),
+    },
+  })
+}
+
+/// The commits in a log, and the length of the log they take up: an
+/// unterminated last line is left from an interrupted write. A complete
+/// line that cannot be read is an error, as the commits after it would be
+/// lost.
+fn parse_log(log: &str) -> Result<(Vec<Vec<Change>>, usize), StoreError> {
+  let mut commits = vec![];
+  let mut length = 0;
+  for (number, line) in log.split_inclusive('\n').enumerate() {
+    if !line.ends_with('\n') {
+      break;
+    }
+    let changes: Option<Vec<Change>> = serde_json::from_str::<Vec<Value>>(line)
+      .ok()
+      .and_then(|values| values.iter().map(from_json).collect());
+    match changes {
+      Some(changes) => commits.push(changes),
+      None => {
+        return Err(StoreError::Storage(format!(
+          "Line {} of the log is corrupt",
+          number + 1
+        )))
+      }
+    }
+    length += line.len();
+  }
+  Ok((commits, length))
+}
+
+/// Syncs a directory, so that a file created or renamed in it persists.
+/// Not every platform can open a directory, so failures are ignored.
+fn sync_directory(path: &Path) {
+  let directory = match path.parent() {
+    Some(directory) if !directory.as_os_str().is_empty() => directory,
+    _ => Path::new("."),
+  };
+  if let Ok(directory) = File::open(directory) {
+    let _ = directory.sync_all();
+  }
+}
+
+/// Resources and their versions, kept in memory and logged to a file.
+#[derive(Debug)]
+pub struct FileStore {
+  path: PathBuf,
+  file: Arc<Mutex<File>>,
+  memory: MemoryStore,
+  staged: Vec<Change>,
+  deferred: bool,
+}
+
+impl FileStore {
+  /// Opens the store logged to `path`, creating the file if needed.
+  pub fn open<P: AsRef<Path>>(path: P) -> Result<FileStore, StoreError> {
+    let path = path.as_ref().to_path_buf();
+    let created = !path.exists();
+    let mut file = OpenOptions::new()
+      .read(true)
+      .append(true)
+      .create(true)
+      .open(&path)
+      .map_err(storage)?;
+    if created {
+      sync_directory(&path);
+    }
+    let mut log = String::new();
+    file.read_to_string(&mut log).map_err(storage)?;
+    let (commits, length) = parse_log(&log)?;
+    if length < log.len() {
+      let repaired = path.with_extension("repair");
+      let mut temporary = File::create(&repaired).map_err(storage)?;
+      temporary
+        .write_all(&log.as_bytes()[..length])
+        .map_err(storage)?;
+      temporary.sync_all().map_err(storage)?;
+      fs::rename(&repaired, &path).map_err(storage)?;
+      sync_directory(&path);
+      file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .open(&path)
+        .map_err(storage)?;
+    }
+    let mut memory = MemoryStore::new();
+    for change in commits.into_iter().flatten() {
+      memory.restore(change);
+    }
+    Ok(FileStore {
+      path,
+      file: Arc::new(Mutex::new(file)),
+      memory,
+      staged: vec![],
+      deferred: false,
+    })
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// The number of resources that are not deleted.
+  pub fn len(&self) -> usize {
+    self.memory.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.memory.is_empty()
+  }
+
+  /// Stages the newest version of a resource and, unless writes are
+  /// deferred, commits it.
+  fn written(&mut self, resource_type: &str, id: &str) -> Result<(), StoreError> {
+    if let Some(change) = self.memory.latest(resource_type, id) {
+      self.staged.push(change);
+    }
+    if self.deferred {
+      Ok(())
+    } else {
+      self.commit()
+    }
+  }
+
+  fn append(&self, line: &str) -> Result<(), StoreError> {
+    let mut file = self
+      .file
+      .lock()
+      .map_err(|_| StoreError::Storage("The log is poisoned".to_string()))?;
+    let length = file.metadata().map_err(storage)?.len();
+    let result = file
+      .write_all(line.as_bytes())
+      .and_then(|_| file.sync_data());
+    if let Err(error) = result {
+      // Cut off what was written, so the next commit starts a new line.
+      let _ = file.set_len(length);
+      return Err(storage(error));
+    }
+    Ok(())
+  }
+}
+
+impl ResourceStore for FileStore {
+  fn read(&self, resource_type: &str, id: &str) -> Result<Value, StoreError> {
+    self.memory.read(resource_type, id)
+  }
+
+  fn vread(&self, resource_type: &str, id: &str, version_id: &str) -> Result<Value, StoreError> {
+    self.memory.vread(resource_type, id, version_id)
+  }
+
+  fn create(&mut self, resource: Value) -> Result<Value, StoreError> {
+    let created = self.memory.create(resource)?;
+    let resource_type = created["resourceType"].as_str().unwrap_or_default();
+    let id = created["id"].as_str().unwrap_or_default();
+    self.written(resource_type, id)?;
+    Ok(created)
+  }
+
+  fn update(
+    &mut self,
+    resource: Value,
+    if_match: Option<&str>,
+  ) -> Result<(Value, bool), StoreError> {
+    let (updated, created) = self.memory.update(resource, if_match)?;
+    let resource_type = updated["resourceType"].as_str().unwrap_or_default();
+    let id = updated["id"].as_str().unwrap_or_default();
+    self.written(resource_type, id)?;
+    Ok((updated, created))
+  }
+
+  fn delete(&mut self, resource_type: &str, id: &str) -> Result<bool, StoreError> {
+    let deleted = self.memory.delete(resource_type, id)?;
+    if deleted {
+      self.written(resource_type, id)?;
+    }
+    Ok(deleted)
+  }
+
+  fn history(&self, resource_type: &str, id: &str) -> Result<Vec<Version>, StoreError> {
+    self.memory.history(resource_type, id)
+  }
+
+  fn type_history(&self, resource_type: &str) -> Result<Vec<Change>, StoreError> {
+    self.memory.type_history(resource_type)
+  }
+
+  fn system_history(&self) -> Result<Vec<Change>, StoreError> {
+    self.memory.system_history()
+  }
+
+  fn all(&self, resource_type: &str) -> Result<Vec<Value>, StoreError> {
+    self.memory.all(resource_type)
+  }
+
+  fn begin(&mut self) -> Result<(), StoreError> {
+    self.memory.begin()?;
+    self.deferred = true;
+    Ok(())
+  }
+
+  /// Appends the staged versions to the log as one line. If that fails,
+  /// they are undone.
+  fn commit(&mut self) -> Result<(), StoreError> {
+    if self.staged.is_empty() {
+      self.deferred = false;
+      return self.memory.commit();
+    }
+    let line = Value::Array(self.staged.iter().map(to_json).collect()).to_string() + "\n";
+    let result = self.append(&line);
+    let staged = std::mem::take(&mut self.staged);
+    if result.is_err() {
+      for change in staged.iter().rev() {
+        self.memory.undo(&change.resource_type, &change.id);
+      }
+    }
+    self.deferred = false;
+    self.memory.commit()?;
+    result
+  }
+
+  fn rollback(&mut self) -> Result<(), StoreError> {
+    self.staged.clear();
+    self.deferred = false;
+    self.memory.rollback()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::bundle::{BundleProcessor, ProcessError};
+  use crate::model::Bundle::Bundle;
+  use std::env;
+
+  fn log_path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("fhir-rs-{}-{}.log", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    path
+  }
+
+  #[test]
+  fn test_file_store() {
+    let path = log_path("store");
+    let mut store = FileStore::open(&path).unwrap();
+    let patient = store
+      .create(json!({"resourceType": "Patient", "gender": "female"}))
+      .unwrap();
+    let id = patient["id"].as_str().unwrap().to_string();
+    let mut changed = patient.clone();
+    changed["gender"] = json!("other");
+    store.update(changed.clone(), Some("W/\"1\"")).unwrap();
+    assert_eq!(
+      store.update(changed, Some("W/\"1\"")).unwrap_err().status(),
+      412
+    );
+    store.delete("Patient", &id).unwrap();
+
+    // A torn write at the end of the log is dropped when it is reopened.
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(b"[{\"resourceType\": \"Pat").unwrap();
+    drop(file);
+    let reopened = FileStore::open(&path).unwrap();
+    assert_eq!(reopened.read("Patient", &id).unwrap_err().status(), 410);
+    assert_eq!(reopened.vread("Patient", &id, "1").unwrap(), patient);
+    assert_eq!(reopened.history("Patient", &id).unwrap().len(), 3);
+    assert_eq!(
+      reopened.system_history().unwrap(),
+      store.system_history().unwrap()
+    );
+    assert!(fs::read_to_string(&path).unwrap().ends_with("]\n"));
+
+    // A corrupt complete line is not dropped with the commits after it.
+    let log = fs::read_to_string(&path).unwrap();
+    let corrupt = log.replacen("[{", "[{]", 1);
+    fs::write(&path, &corrupt).unwrap();
+    assert!(matches!(
+      FileStore::open(&path),
+      Err(StoreError::Storage(_))
+    ));
+    assert_eq!(fs::read_to_string(&path).unwrap(), corrupt);
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn test_file_store_transaction() {
+    let path = log_path("transaction");
+    let mut store = FileStore::open(&path).unwrap();
+    let transaction = |resources: Vec<Value>| {
+      let entries: Vec<Value> = resources
+        .into_iter()
+        .map(
+          |resource| json!({"resource": resource, "request": {"method": "POST", "url": "Patient"}}),
+        )
+        .collect();
+      json!({"resourceType": "Bundle", "type": "transaction", "entry": entries})
+    };
+    let ok = transaction(vec![
+      json!({"resourceType": "Patient"}),
+      json!({"resourceType": "Patient"}),
+    ]);
+    BundleProcessor::new(&mut store)
+      .process(&Bundle::new(&ok))
+      .unwrap();
+    let failing = transaction(vec![
+      json!({"resourceType": "Patient"}),
+      json!({"resourceType": "Observation"}),
+    ]);
+    assert!(matches!(
+      BundleProcessor::new(&mut store).process(&Bundle::new(&failing)),
+      Err(ProcessError::Failed { entry: 1, .. })
+    ));
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+    assert_eq!(FileStore::open(&path).unwrap().len(), 2);
+    assert_eq!(store.len(), 2);
+
+    store.create(json!({"resourceType": "Patient"})).unwrap();
+    assert_eq!(FileStore::open(&path).unwrap().len(), 3);
+    fs::remove_file(&path).unwrap();
+  }
+}
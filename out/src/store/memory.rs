@@ -0,0 +1,352 @@
+//! A `ResourceStore` held in memory.
+
+use super::{etag_version, Change, ResourceStore, StoreError, Version};
+use crate::temporal::{DateTime, Precision};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Resources and their versions in memory. A transaction is rolled back by
+/// removing the versions logged since it began.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+  resources: BTreeMap<(String, String), Vec<Version>>,
+  /// The resource of every version, in the order they were written.
+  log: Vec<(String, String)>,
+  next_id: u64,
+  /// The length of the log and the last id when the transaction began.
+  savepoint: Option<(usize, u64)>,
+}
+
+impl MemoryStore {
+  pub fn new() -> MemoryStore {
+    MemoryStore::default()
+  }
+
+  /// The number of resources that are not deleted.
+  pub fn len(&self) -> usize {
+    self
+      .resources
+      .values()
+      .filter(|versions| versions.last().is_some_and(|v| v.resource.is_some()))
+      .count()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  fn key(resource_type: &str, id: &str) -> (String, String) {
+    (resource_type.to_string(), id.to_string())
+  }
+
+  fn current(&self, resource_type: &str, id: &str) -> Option<&Version> {
+    self
+      .resources
+      .get(&MemoryStore::key(resource_type, id))
+      .and_then(|versions| versions.last())
+  }
+
+  /// The changes in the log matching `keep`, newest first.
+  fn changes(&self, keep: impl Fn(&str) -> bool) -> Vec<Change> {
+    let mut seen: BTreeMap<&(String, String), usize> = BTreeMap::new();
+    let mut changes: Vec<Change> = self
+      .log
+      .iter()
+      .filter_map(|key| {
+        let index = seen.entry(key).or_insert(0);
+        *index += 1;
+        if !keep(&key.0) {
+          return None;
+        }
+        Some(Change {
+          resource_type: key.0.clone(),
+          id: key.1.clone(),
+          version: self.resources[key][*index - 1].clone(),
+        })
+      })
+      .collect();
+    changes.reverse();
+    changes
+  }
+
+  /// The newest version of a resource.
+  pub(crate) fn latest(&self, resource_type: &str, id: &str) -> Option<Change> {
+    self.current(resource_type, id).map(|version| Change {
+      resource_type: resource_type.to_string(),
+      id: id.to_string(),
+      version: version.clone(),
+    })
+  }
+
+  /// Adds a version exactly as it was written before, e.g. when replaying a
+  /// log.
+  pub(crate) fn restore(&mut self, change: Change) {
+    if let Ok(number) = change.id.parse::<u64>() {
+      self.next_id = self.next_id.max(number);
+    }
+    let key = MemoryStore::key(&change.resource_type, &change.id);
+    self.log.push(key.clone());
+    self.resources.entry(key).or_default().push(change.version);
+  }
+
+  /// Removes the newest version of a resource, undoing its last write.
+  pub(crate) fn undo(&mut self, resource_type: &str, id: &str) {
+    let key = MemoryStore::key(resource_type, id);
+    if let Some(position) = self.log.iter().rposition(|k| *k == key) {
+      self.log.remove(position);
+    }
+    if let Some(versions) = self.resources.get_mut(&key) {
+      versions.pop();
+      if versions.is_empty() {
+        self.resources.remove(&key);
+      }
+    }
+  }
+
+  /// Adds a version, setting `meta.versionId` and `meta.lastUpdated` on the
+  /// resource.
+  fn write(&mut self, resource_type: &str, id: &str, resource: Option<Value>) -> Option<Value> {
+    let versions = self
+      .resources
+      .entry(MemoryStore::key(resource_type, id))
+      .or_default();
+    let version_id = (versions.len() + 1).to_string();
+    let last_updated = DateTime::now(Precision::Millisecond).to_string();
+    let resource = resource.map(|mut resource| {
+      resource["id"] = Value::from(id);
+      if !resource["meta"].is_object() {
+        resource["meta"] = Value::Object(Default::default());
+      }
+      resource["meta"]["versionId"] = Value::from(version_id.as_str());
+      resource["meta"]["lastUpdated"] = Value::from(last_updated.as_str());
+      resource
+    });
+    versions.push(Version {
+      version_id,
+      last_updated,
+      resource: resource.clone(),
+    });
+    self.log.push(MemoryStore::key(resource_type, id));
+    resource
+  }
+}
+
+fn resource_type(resource: &Value) -> Result<String, StoreError> {
+  resource["resourceType"]
+    .as_str()
+    .map(|t| t.to_string())
+    .ok_or_else(|| StoreError::Invalid("resourceType is missing".to_string()))
+}
+
+impl ResourceStore for MemoryStore {
+  fn read(&self, resource_type: &str, id: &str) -> Result<Value, StoreError> {
+    match self.current(resource_type, id) {
+      Some(Version {
+        resource: Some(resource),
+        ..
+      }) => Ok(resource.clone()),
+      Some(_) => Err(StoreError::Deleted {
+        resource_type: resource_type.to_string(),
+        id: id.to_string(),
+      }),
+      None => Err(StoreError::NotFound {
+        resource_type: resource_type.to_string(),
+        id: id.to_string(),
+      }),
+    }
+  }
+
+  fn vread(&self, resource_type: &str, id: &str, version_id: &str) -> Result<Value, StoreError> {
+    let version = self
+      .history(resource_type, id)?
+      .into_iter()
+      .find(|v| v.version_id == version_id);
+    match version {
+      Some(Version {
+        resource: Some(resource),
+        ..
+      }) => Ok(resource),
+      Some(_) => Err(StoreError::Deleted {
+        resource_type: resource_type.to_string(),
+        id: id.to_string(),
+      }),
+      None => Err(StoreError::NotFound {
+        resource_type: resource_type.to_string(),
+        id: format!("{}/_history/{}", id, version_id),
+      }),
+    }
+  }
+
+  fn create(&mut self, resource: Value) -> Result<Value, StoreError> {
+    let resource_type = resource_type(&resource)?;
+    let id = loop {
+      self.next_id += 1;
+      let id = self.next_id.to_string();
+      if self.current(&resource_type, &id).is_none() {
+        break id;
+      }
+    };
+    Ok(self.write(&resource_type, &id, Some(resource)).unwrap())
+  }
+
+  fn update(
+    &mut self,
+    resource: Value,
+    if_match: Option<&str>,
+  ) -> Result<(Value, bool), StoreError> {
+    let resource_type = resource_type(&resource)?;
+    let id = resource["id"]
+      .as_str()
+      .ok_or_else(|| StoreError::Invalid("id is missing".to_string()))?
+      .to_string();
+    let current = self
+      .current(&resource_type, &id)
+      .filter(|v| v.resource.is_some());
+    if let Some(expected) = if_match {
+      let expected = etag_version(expected);
+      let actual = current.map(|v| v.version_id.clone());
+      if actual.as_deref() != Some(expected) {
+        return Err(StoreError::VersionConflict {
+          expected: expected.to_string(),
+          actual,
+        });
+      }
+    }
+    let created = current.is_none();
+    Ok((
+      self.write(&resource_type, &id, Some(resource)).unwrap(),
+      created,
+    ))
+  }
+
+  fn delete(&mut self, resource_type: &str, id: &str) -> Result<bool, StoreError> {
+    match self.current(resource_type, id) {
+      Some(Version {
+        resource: Some(_), ..
+      }) => {
+        self.write(resource_type, id, None);
+        Ok(true)
+      }
+      _ => Ok(false),
+    }
+  }
+
+  fn history(&self, resource_type: &str, id: &str) -> Result<Vec<Version>, StoreError> {
+    match self.resources.get(&MemoryStore::key(resource_type, id)) {
+      Some(versions) => Ok(versions.iter().rev().cloned().collect()),
+      None => Err(StoreError::NotFound {
+        resource_type: resource_type.to_string(),
+        id: id.to_string(),
+      }),
+    }
+  }
+
+  fn type_history(&self, resource_type: &str) -> Result<Vec<Change>, StoreError> {
+    Ok(self.changes(|t| t == resource_type))
+  }
+
+  fn system_history(&self) -> Result<Vec<Change>, StoreError> {
+    Ok(self.changes(|_| true))
+  }
+
+  fn all(&self, resource_type: &str) -> Result<Vec<Value>, StoreError> {
+    Ok(
+      self
+        .resources
+        .iter()
+        .filter(|((t, _), _)| t == resource_type)
+        .filter_map(|(_, versions)| versions.last()?.resource.clone())
+        .collect(),
+    )
+  }
+
+  fn begin(&mut self) -> Result<(), StoreError> {
+    self.savepoint = Some((self.log.len(), self.next_id));
+    Ok(())
+  }
+
+  /// Writes are made as they come, so this only ends the transaction.
+  fn commit(&mut self) -> Result<(), StoreError> {
+    self.savepoint = None;
+    Ok(())
+  }
+
+  fn rollback(&mut self) -> Result<(), StoreError> {
+    if let Some((length, next_id)) = self.savepoint.take() {
+      while self.log.len() > length {
+        let (resource_type, id) = self.log[self.log.len() - 1].clone();
+        self.undo(&resource_type, &id);
+      }
+      self.next_id = next_id;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use std::fs;
+
+  #[test]
+  fn test_memory_store() {
+    let contents = fs::read_to_string("examples-json/patient-example.json").unwrap();
+    let patient: Value = serde_json::from_str(&contents).unwrap();
+    let mut store = MemoryStore::new();
+
+    let (stored, created) = store.update(patient.clone(), None).unwrap();
+    assert!(created);
+    assert_eq!(stored["meta"]["versionId"], json!("1"));
+    assert_eq!(store.read("Patient", "example").unwrap(), stored);
+
+    let conflict = store.update(patient.clone(), Some("W/\"2\""));
+    assert_eq!(
+      conflict,
+      Err(StoreError::VersionConflict {
+        expected: "2".to_string(),
+        actual: Some("1".to_string())
+      })
+    );
+    let (stored, created) = store.update(patient, Some("W/\"1\"")).unwrap();
+    assert!(!created);
+    assert_eq!(stored["meta"]["versionId"], json!("2"));
+
+    let other = store.create(json!({"resourceType": "Patient"})).unwrap();
+    let id = other["id"].as_str().unwrap();
+    assert_eq!(store.len(), 2);
+    let found = store
+      .search("Patient", &[("family".to_string(), "chalmers".to_string())])
+      .unwrap();
+    assert_eq!(found.len(), 1);
+
+    assert_eq!(store.delete("Patient", id), Ok(true));
+    assert_eq!(store.delete("Patient", id), Ok(false));
+    assert_eq!(store.read("Patient", id).unwrap_err().status(), 410);
+    assert_eq!(store.read("Patient", "unknown").unwrap_err().status(), 404);
+    let history = store.history("Patient", id).unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].resource, None);
+    assert_eq!(store.vread("Patient", id, "1").unwrap(), other);
+    assert_eq!(store.len(), 1);
+
+    let changes = store.system_history().unwrap();
+    let versions: Vec<(&str, &str)> = changes
+      .iter()
+      .map(|c| (c.id.as_str(), c.version.version_id.as_str()))
+      .collect();
+    assert_eq!(
+      versions,
+      vec![(id, "2"), (id, "1"), ("example", "2"), ("example", "1")]
+    );
+    assert_eq!(store.type_history("Patient").unwrap(), changes);
+    assert!(store.type_history("Observation").unwrap().is_empty());
+
+    store.begin().unwrap();
+    store.create(json!({"resourceType": "Patient"})).unwrap();
+    store.delete("Patient", "example").unwrap();
+    store.rollback().unwrap();
+    assert_eq!(store.system_history().unwrap(), changes);
+    assert_eq!(store.read("Patient", "example").unwrap(), stored);
+  }
+}
@@ -0,0 +1,171 @@
+//! Versioned storage of resources, and the search parameters stores take.
+//!
+//! Every write creates a new version with `meta.versionId` and
+//! `meta.lastUpdated` set by the store. Deleting a resource records a
+//! deleted version, so its history is kept.
+
+pub mod criteria;
+pub mod file;
+pub mod memory;
+
+pub use file::FileStore;
+pub use memory::MemoryStore;
+
+use crate::index::SearchIndexer;
+use serde_json::Value;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreError {
+  NotFound {
+    resource_type: String,
+    id: String,
+  },
+  /// The resource existed but its current version is deleted.
+  Deleted {
+    resource_type: String,
+    id: String,
+  },
+  /// An `If-Match` precondition failed: `actual` is the current version,
+  /// `None` if there is none.
+  VersionConflict {
+    expected: String,
+    actual: Option<String>,
+  },
+  /// The resource cannot be stored, e.g. it has no `resourceType`.
+  Invalid(String),
+  /// The underlying storage failed.
+  Storage(String),
+  /// A search parameter or modifier is unknown or cannot be evaluated.
+  Search(String),
+}
+
+impl StoreError {
+  /// The HTTP status code for the error.
+  pub fn status(&self) -> u16 {
+    match self {
+      StoreError::NotFound { .. } => 404,
+      StoreError::Deleted { .. } => 410,
+      StoreError::VersionConflict { .. } => 412,
+      StoreError::Invalid(_) => 400,
+      StoreError::Storage(_) => 500,
+      StoreError::Search(_) => 400,
+    }
+  }
+}
+
+impl fmt::Display for StoreError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      StoreError::NotFound { resource_type, id } => {
+        write!(f, "{}/{} is not known", resource_type, id)
+      }
+      StoreError::Deleted { resource_type, id } => {
+        write!(f, "{}/{} has been deleted", resource_type, id)
+      }
+      StoreError::VersionConflict { expected, actual } => match actual {
+        Some(actual) => write!(
+          f,
+          "Version {} was expected but the current version is {}",
+          expected, actual
+        ),
+        None => write!(
+          f,
+          "Version {} was expected but the resource does not exist",
+          expected
+        ),
+      },
+      StoreError::Invalid(message) => write!(f, "Invalid resource: {}", message),
+      StoreError::Storage(message) => write!(f, "Storage failure: {}", message),
+      StoreError::Search(message) => write!(f, "Invalid search: {}", message),
+    }
+  }
+}
+
+impl std::error::Error for StoreError {}
+
+/// One version of a resource. `resource` is `None` for a deletion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Version {
+  pub version_id: String,
+  pub last_updated: String,
+  pub resource: Option<Value>,
+}
+
+/// A version of any resource, as type and system history list them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+  pub resource_type: String,
+  pub id: String,
+  pub version: Version,
+}
+
+/// The version in an ETag or `If-Match` value: `2` for `W/"2"`.
+pub fn etag_version(etag: &str) -> &str {
+  etag.trim_start_matches("W/").trim_matches('"')
+}
+
+/// A store of versioned resources, addressed by type and id.
+pub trait ResourceStore {
+  /// The current version of a resource.
+  fn read(&self, resource_type: &str, id: &str) -> Result<Value, StoreError>;
+
+  /// A specific version of a resource.
+  fn vread(&self, resource_type: &str, id: &str, version_id: &str) -> Result<Value, StoreError>;
+
+  /// Stores a new resource under an id assigned by the store and returns it
+  /// as stored.
+  fn create(&mut self, resource: Value) -> Result<Value, StoreError>;
+
+  /// Stores the resource under its `id`, creating it if needed. With
+  /// `if_match`, fails unless the current version has that version id.
+  /// Returns the resource as stored and whether it was created.
+  fn update(
+    &mut self,
+    resource: Value,
+    if_match: Option<&str>,
+  ) -> Result<(Value, bool), StoreError>;
+
+  /// Deletes a resource. Returns whether there was a current version to
+  /// delete.
+  fn delete(&mut self, resource_type: &str, id: &str) -> Result<bool, StoreError>;
+
+  /// All versions of a resource, newest first.
+  fn history(&self, resource_type: &str, id: &str) -> Result<Vec<Version>, StoreError>;
+
+  /// All versions of all resources of a type, newest first.
+  fn type_history(&self, resource_type: &str) -> Result<Vec<Change>, StoreError>;
+
+  /// All versions of all resources, newest first.
+  fn system_history(&self) -> Result<Vec<Change>, StoreError>;
+
+  /// The current versions of all resources of a type that are not deleted.
+  fn all(&self, resource_type: &str) -> Result<Vec<Value>, StoreError>;
+
+  /// The current resources of a type matching search parameters, as the
+  /// FHIR SearchParameters define them; see `SearchIndexer::query`.
+  fn search(
+    &self,
+    resource_type: &str,
+    params: &[(String, String)],
+  ) -> Result<Vec<Value>, StoreError> {
+    let query = SearchIndexer::fhir().query(resource_type, params)?;
+    Ok(
+      self
+        .all(resource_type)?
+        .into_iter()
+        .filter(|resource| query.matches(resource))
+        .collect(),
+    )
+  }
+
+  /// Starts a transaction: the writes until `commit` are made durable
+  /// together, or undone together by `rollback`.
+  fn begin(&mut self) -> Result<(), StoreError>;
+
+  /// Makes the writes of the transaction durable and ends it.
+  fn commit(&mut self) -> Result<(), StoreError>;
+
+  /// Undoes the writes of the transaction and ends it.
+  fn rollback(&mut self) -> Result<(), StoreError>;
+}
@@ -0,0 +1,341 @@
+//! FHIR `date`, `dateTime` and `instant` values with their precision.
+//!
+//! A value is treated as the interval of instants it could denote: `2020-03`
+//! covers all of March 2020. Values without a time zone are interpreted in
+//! UTC.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const MILLIS_PER_SECOND: i64 = 1000;
+pub const MILLIS_PER_MINUTE: i64 = 60 * MILLIS_PER_SECOND;
+pub const MILLIS_PER_HOUR: i64 = 60 * MILLIS_PER_MINUTE;
+pub const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precision {
+  Year,
+  Month,
+  Day,
+  Second,
+  Millisecond,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DateTime {
+  pub year: i32,
+  pub month: u32,
+  pub day: u32,
+  pub hour: u32,
+  pub minute: u32,
+  pub second: u32,
+  pub millisecond: u32,
+  /// The time zone offset in minutes, if one was given.
+  pub offset: Option<i32>,
+  pub precision: Precision,
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+pub fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+  let year = i64::from(year) - if month <= 2 { 1 } else { 0 };
+  let era = if year >= 0 { year } else { year - 399 } / 400;
+  let year_of_era = year - era * 400;
+  let month = i64::from(month);
+  let day_of_year =
+    (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  era * 146_097 + day_of_era - 719_468
+}
+
+/// The date of a day number returned by `days_from_civil`.
+pub fn civil_from_days(days: i64) -> (i32, u32, u32) {
+  let days = days + 719_468;
+  let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+  let day_of_era = days - era * 146_097;
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let mp = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+  (year as i32, month, day)
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+  match month {
+    2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+fn number<T: std::str::FromStr>(text: &str, digits: usize) -> Option<T> {
+  if text.len() != digits || !text.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  text.parse().ok()
+}
+
+impl DateTime {
+  /// Parses a `date`, `dateTime` or `instant`.
+  pub fn parse(text: &str) -> Option<DateTime> {
+    let (date, time) = match text.find('T') {
+      Some(index) => (&text[..index], Some(&text[index + 1..])),
+      None => (text, None),
+    };
+    let mut parts = date.split('-');
+    let year: i32 = number(parts.next()?, 4)?;
+    let month: Option<u32> = match parts.next() {
+      Some(m) => Some(number(m, 2)?),
+      None => None,
+    };
+    let day: Option<u32> = match parts.next() {
+      Some(d) => Some(number(d, 2)?),
+      None => None,
+    };
+    if parts.next().is_some() {
+      return None;
+    }
+    let mut value = DateTime {
+      year,
+      month: month.unwrap_or(1),
+      day: day.unwrap_or(1),
+      hour: 0,
+      minute: 0,
+      second: 0,
+      millisecond: 0,
+      offset: None,
+      precision: match (month, day) {
+        (None, _) => Precision::Year,
+        (Some(_), None) => Precision::Month,
+        (Some(_), Some(_)) => Precision::Day,
+      },
+    };
+    if !(1..=12).contains(&value.month)
+      || value.day < 1
+      || value.day > days_in_month(year, value.month)
+    {
+      return None;
+    }
+    let time = match time {
+      Some(time) if value.precision == Precision::Day => time,
+      Some(_) => return None,
+      None => return Some(value),
+    };
+
+    let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+      (clock, Some(0))
+    } else if let Some(index) = time.rfind(&['+', '-'][..]) {
+      let zone = &time[index + 1..];
+      let hours: i32 = number(zone.get(..2)?, 2)?;
+      let minutes: i32 = number(zone.get(3..)?, 2)?;
+      if zone.get(2..3) != Some(":") || hours > 14 || minutes > 59 {
+        return None;
+      }
+      let sign = if time[index..].starts_with('-') {
+        -1
+      } else {
+        1
+      };
+      (&time[..index], Some(sign * (hours * 60 + minutes)))
+    } else {
+      (time, None)
+    };
+    let (clock, fraction) = match clock.find('.') {
+      Some(index) => (&clock[..index], Some(&clock[index + 1..])),
+      None => (clock, None),
+    };
+    let mut fields = clock.split(':');
+    value.hour = number(fields.next()?, 2)?;
+    value.minute = number(fields.next()?, 2)?;
+    value.second = number(fields.next()?, 2)?;
+    if fields.next().is_some() || value.hour > 23 || value.minute > 59 || value.second > 60 {
+      return None;
+    }
+    value.offset = offset;
+    value.precision = Precision::Second;
+    if let Some(fraction) = fraction {
+      if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+      }
+      let millis = format!("{:0<3}", &fraction[..fraction.len().min(3)]);
+      value.millisecond = millis.parse().ok()?;
+      value.precision = Precision::Millisecond;
+    }
+    Some(value)
+  }
+
+  /// The instant `millis` milliseconds after the epoch, expressed in the
+  /// time zone `offset` (minutes east of UTC).
+  pub fn from_millis(millis: i64, offset: Option<i32>, precision: Precision) -> DateTime {
+    let local = millis + i64::from(offset.unwrap_or(0)) * MILLIS_PER_MINUTE;
+    let days = local.div_euclid(MILLIS_PER_DAY);
+    let time = local.rem_euclid(MILLIS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    DateTime {
+      year,
+      month,
+      day,
+      hour: (time / MILLIS_PER_HOUR) as u32,
+      minute: (time % MILLIS_PER_HOUR / MILLIS_PER_MINUTE) as u32,
+      second: (time % MILLIS_PER_MINUTE / MILLIS_PER_SECOND) as u32,
+      millisecond: (time % MILLIS_PER_SECOND) as u32,
+      offset,
+      precision,
+    }
+  }
+
+  /// The current instant in UTC.
+  pub fn now(precision: Precision) -> DateTime {
+    let millis = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|d| d.as_millis() as i64)
+      .unwrap_or(0);
+    DateTime::from_millis(millis, Some(0), precision)
+  }
+
+  /// The first instant this value could denote, in milliseconds since the
+  /// epoch.
+  pub fn low(&self) -> i64 {
+    days_from_civil(self.year, self.month, self.day) * MILLIS_PER_DAY
+      + i64::from(self.hour) * MILLIS_PER_HOUR
+      + i64::from(self.minute) * MILLIS_PER_MINUTE
+      + i64::from(self.second) * MILLIS_PER_SECOND
+      + i64::from(self.millisecond)
+      - i64::from(self.offset.unwrap_or(0)) * MILLIS_PER_MINUTE
+  }
+
+  /// The first instant after the interval this value denotes.
+  pub fn high(&self) -> i64 {
+    let next_day = |year, month, day| {
+      (days_from_civil(year, month, day) * MILLIS_PER_DAY)
+        - i64::from(self.offset.unwrap_or(0)) * MILLIS_PER_MINUTE
+    };
+    match self.precision {
+      Precision::Year => next_day(self.year + 1, 1, 1),
+      Precision::Month if self.month == 12 => next_day(self.year + 1, 1, 1),
+      Precision::Month => next_day(self.year, self.month + 1, 1),
+      Precision::Day => next_day(self.year, self.month, self.day) + MILLIS_PER_DAY,
+      Precision::Second => self.low() + MILLIS_PER_SECOND,
+      Precision::Millisecond => self.low() + 1,
+    }
+  }
+
+  /// The same time `months` calendar months later, with the day clamped to
+  /// the end of shorter months.
+  pub fn add_months(&self, months: i32) -> DateTime {
+    let index = self.year * 12 + self.month as i32 - 1 + months;
+    let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+    DateTime {
+      year,
+      month,
+      day: self.day.min(days_in_month(year, month)),
+      ..*self
+    }
+  }
+
+  /// The date part, with day precision.
+  pub fn date(&self) -> DateTime {
+    DateTime {
+      hour: 0,
+      minute: 0,
+      second: 0,
+      millisecond: 0,
+      offset: None,
+      precision: self.precision.min(Precision::Day),
+      ..*self
+    }
+  }
+}
+
+/// Values are ordered when the intervals they denote do not overlap, or are
+/// the same. `2020-03` and `2020-03-15` are unordered.
+impl PartialOrd for DateTime {
+  fn partial_cmp(&self, other: &DateTime) -> Option<Ordering> {
+    let (low, high) = (self.low(), self.high());
+    let (other_low, other_high) = (other.low(), other.high());
+    if low == other_low && high == other_high {
+      Some(Ordering::Equal)
+    } else if high <= other_low {
+      Some(Ordering::Less)
+    } else if low >= other_high {
+      Some(Ordering::Greater)
+    } else {
+      None
+    }
+  }
+}
+
+impl PartialEq for DateTime {
+  fn eq(&self, other: &DateTime) -> bool {
+    self.partial_cmp(other) == Some(Ordering::Equal)
+  }
+}
+
+impl fmt::Display for DateTime {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:04}", self.year)?;
+    if self.precision >= Precision::Month {
+      write!(f, "-{:02}", self.month)?;
+    }
+    if self.precision >= Precision::Day {
+      write!(f, "-{:02}", self.day)?;
+    }
+    if self.precision >= Precision::Second {
+      write!(f, "T{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+    }
+    if self.precision == Precision::Millisecond {
+      write!(f, ".{:03}", self.millisecond)?;
+    }
+    if self.precision >= Precision::Second {
+      match self.offset {
+        Some(0) => write!(f, "Z")?,
+        Some(offset) => write!(
+          f,
+          "{}{:02}:{:02}",
+          if offset < 0 { '-' } else { '+' },
+          offset.abs() / 60,
+          offset.abs() % 60
+        )?,
+        None => {}
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_and_compare() {
+    let march = DateTime::parse("2020-03").unwrap();
+    assert_eq!(march.precision, Precision::Month);
+    assert_eq!(march.high() - march.low(), 31 * MILLIS_PER_DAY);
+
+    let instant = DateTime::parse("2020-03-15T10:30:00.25+01:00").unwrap();
+    assert_eq!(instant.precision, Precision::Millisecond);
+    assert_eq!(instant.millisecond, 250);
+    assert_eq!(instant.to_string(), "2020-03-15T10:30:00.250+01:00");
+    assert_eq!(
+      DateTime::from_millis(instant.low(), Some(0), Precision::Second).to_string(),
+      "2020-03-15T09:30:00Z"
+    );
+
+    assert_eq!(march.partial_cmp(&instant), None);
+    assert!(DateTime::parse("2020-02-29").unwrap() < march);
+    assert!(DateTime::parse("2020").unwrap() > DateTime::parse("2019-12-31T23:59:59Z").unwrap());
+    assert!(
+      DateTime::parse("2020-03-01T00:00:00+00:00").unwrap()
+        == DateTime::parse("2020-03-01T01:00:00+01:00").unwrap()
+    );
+
+    assert!(DateTime::parse("2019-02-29").is_none());
+    assert!(DateTime::parse("2020-03-15T10:30").is_none());
+    assert!(DateTime::parse("2020-3-15").is_none());
+  }
+}
@@ -0,0 +1,256 @@
+//! Interval semantics for `Period` and `Range`.
+//!
+//! Questions that cannot be answered from the data return `None`: whether
+//! `2020-03` falls in a period starting on `2020-03-15`, or whether a value
+//! in `mg` lies in a range given in `m`.
+
+use super::datetime::DateTime;
+use crate::model::Period::Period;
+use crate::model::Range::Range;
+use crate::ucum::quantity::{compare_quantities, UcumQuantity};
+use std::cmp::Ordering;
+use std::time::Duration;
+
+/// A half-open interval of instants, in milliseconds since the epoch. A
+/// missing bound is unbounded: a missing `high` means ongoing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+  pub low: Option<i64>,
+  pub high: Option<i64>,
+}
+
+impl Interval {
+  /// The interval a single `date` or `dateTime` denotes.
+  pub fn of(value: &DateTime) -> Interval {
+    Interval {
+      low: Some(value.low()),
+      high: Some(value.high()),
+    }
+  }
+
+  fn starts_before_end_of(&self, other: &Interval) -> bool {
+    match (self.low, other.high) {
+      (Some(low), Some(high)) => low < high,
+      _ => true,
+    }
+  }
+
+  /// Whether any instant is in both intervals.
+  pub fn overlaps(&self, other: &Interval) -> bool {
+    self.starts_before_end_of(other) && other.starts_before_end_of(self)
+  }
+
+  /// Whether `other` lies entirely within this interval.
+  pub fn includes(&self, other: &Interval) -> bool {
+    let low = match (self.low, other.low) {
+      (None, _) => true,
+      (Some(_), None) => false,
+      (Some(a), Some(b)) => a <= b,
+    };
+    let high = match (self.high, other.high) {
+      (None, _) => true,
+      (Some(_), None) => false,
+      (Some(a), Some(b)) => b <= a,
+    };
+    low && high
+  }
+
+  /// `Some(true)` when every instant of `other` is in this interval,
+  /// `Some(false)` when none is, and `None` when only some are.
+  pub fn contains(&self, other: &Interval) -> Option<bool> {
+    if self.includes(other) {
+      Some(true)
+    } else if !self.overlaps(other) {
+      Some(false)
+    } else {
+      None
+    }
+  }
+
+  /// `Less` when this interval ends before `other` starts, `Greater` when it
+  /// starts after `other` ends, `Equal` when they are the same, and `None`
+  /// when they overlap.
+  pub fn compare(&self, other: &Interval) -> Option<Ordering> {
+    if self == other {
+      return Some(Ordering::Equal);
+    }
+    if !other.starts_before_end_of(self) {
+      return Some(Ordering::Less);
+    }
+    if !self.starts_before_end_of(other) {
+      return Some(Ordering::Greater);
+    }
+    None
+  }
+
+  /// The length of a bounded interval.
+  pub fn duration(&self) -> Option<Duration> {
+    match (self.low, self.high) {
+      (Some(low), Some(high)) if high >= low => Some(Duration::from_millis((high - low) as u64)),
+      _ => None,
+    }
+  }
+}
+
+impl Period<'_> {
+  /// The instants covered by this period. Both `start` and `end` are
+  /// inclusive to their precision, so an `end` of `2020-03` includes all of
+  /// March. `None` if either bound is not a valid `dateTime`.
+  pub fn interval(&self) -> Option<Interval> {
+    let low = match self.start() {
+      Some(start) => Some(DateTime::parse(start)?.low()),
+      None => None,
+    };
+    let high = match self.end() {
+      Some(end) => Some(DateTime::parse(end)?.high()),
+      None => None,
+    };
+    Some(Interval { low, high })
+  }
+
+  /// A period without an end is ongoing.
+  pub fn is_ongoing(&self) -> bool {
+    self.end().is_none()
+  }
+
+  /// Whether a `date` or `dateTime` falls within this period. Indeterminate
+  /// when the value is less precise than the period's bounds and straddles
+  /// one of them.
+  pub fn contains(&self, date_time: &str) -> Option<bool> {
+    let value = DateTime::parse(date_time)?;
+    self.interval()?.contains(&Interval::of(&value))
+  }
+
+  /// Whether `other` lies entirely within this period.
+  pub fn includes(&self, other: &Period) -> Option<bool> {
+    Some(self.interval()?.includes(&other.interval()?))
+  }
+
+  pub fn overlaps(&self, other: &Period) -> Option<bool> {
+    Some(self.interval()?.overlaps(&other.interval()?))
+  }
+
+  /// Orders periods that do not overlap; see `Interval::compare`.
+  pub fn compare(&self, other: &Period) -> Option<Ordering> {
+    self.interval()?.compare(&other.interval()?)
+  }
+
+  /// The length of the period, or `None` if it is open.
+  pub fn duration(&self) -> Option<Duration> {
+    self.interval()?.duration()
+  }
+
+  /// The length of the period in a UCUM time unit, e.g. `d` or `a`.
+  pub fn duration_in(&self, unit: &str) -> Option<f64> {
+    let millis = self.duration()?.as_millis() as f64;
+    crate::ucum::convert(millis, "ms", unit).ok()
+  }
+}
+
+impl Range<'_> {
+  /// Whether a quantity lies within this range, bounds included. A missing
+  /// bound is unbounded. `None` when the units cannot be compared.
+  pub fn contains(&self, quantity: &dyn UcumQuantity) -> Option<bool> {
+    if let Some(low) = self.low() {
+      if compare_quantities(quantity, &low).ok()? == Ordering::Less {
+        return Some(false);
+      }
+    }
+    if let Some(high) = self.high() {
+      if compare_quantities(quantity, &high).ok()? == Ordering::Greater {
+        return Some(false);
+      }
+    }
+    Some(true)
+  }
+
+  /// Whether `other` lies entirely within this range.
+  pub fn includes(&self, other: &Range) -> Option<bool> {
+    let low = match (self.low(), other.low()) {
+      (None, _) => true,
+      (Some(_), None) => false,
+      (Some(a), Some(b)) => compare_quantities(&a, &b).ok()? != Ordering::Greater,
+    };
+    let high = match (self.high(), other.high()) {
+      (None, _) => true,
+      (Some(_), None) => false,
+      (Some(a), Some(b)) => compare_quantities(&a, &b).ok()? != Ordering::Less,
+    };
+    Some(low && high)
+  }
+
+  pub fn overlaps(&self, other: &Range) -> Option<bool> {
+    if let (Some(low), Some(high)) = (self.low(), other.high()) {
+      if compare_quantities(&low, &high).ok()? == Ordering::Greater {
+        return Some(false);
+      }
+    }
+    if let (Some(high), Some(low)) = (self.high(), other.low()) {
+      if compare_quantities(&high, &low).ok()? == Ordering::Less {
+        return Some(false);
+      }
+    }
+    Some(true)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::model::Age::Age;
+  use crate::model::Period::Period;
+  use crate::model::Quantity::Quantity;
+  use crate::model::Range::Range;
+  use serde_json::json;
+  use std::time::Duration;
+
+  #[test]
+  fn test_period_semantics() {
+    let value = json!({"start": "2020-03-15", "end": "2020-04"});
+    let period = Period::new(&value);
+    assert_eq!(period.contains("2020-04-30T23:59:59Z"), Some(true));
+    assert_eq!(period.contains("2020-05-01"), Some(false));
+    assert_eq!(period.contains("2020-03"), None);
+    assert_eq!(period.contains("March"), None);
+    assert_eq!(
+      period.duration(),
+      Some(Duration::from_secs(47 * 24 * 60 * 60))
+    );
+    assert!((period.duration_in("wk").unwrap() - 47.0 / 7.0).abs() < 1e-9);
+
+    let ongoing_value = json!({"start": "2020-04-30"});
+    let ongoing = Period::new(&ongoing_value);
+    assert!(ongoing.is_ongoing());
+    assert_eq!(ongoing.duration(), None);
+    assert_eq!(ongoing.contains("2030"), Some(true));
+    assert_eq!(period.overlaps(&ongoing), Some(true));
+    assert_eq!(ongoing.includes(&period), Some(false));
+    assert_eq!(period.compare(&ongoing), None);
+
+    let later_value = json!({"start": "2020-05-01T00:00:00Z"});
+    let later = Period::new(&later_value);
+    assert_eq!(period.overlaps(&later), Some(false));
+    assert_eq!(period.compare(&later), Some(std::cmp::Ordering::Less));
+  }
+
+  #[test]
+  fn test_range_contains() {
+    let value = json!({
+      "low": {"value": 18, "unit": "a", "system": "http://unitsofmeasure.org", "code": "a"},
+      "high": {"value": 65, "unit": "a", "system": "http://unitsofmeasure.org", "code": "a"}
+    });
+    let range = Range::new(&value);
+    let adult = json!({"value": 30, "system": "http://unitsofmeasure.org", "code": "a"});
+    let infant = json!({"value": 6, "system": "http://unitsofmeasure.org", "code": "mo"});
+    let weight = json!({"value": 30, "system": "http://unitsofmeasure.org", "code": "kg"});
+    assert_eq!(range.contains(&Age::new(&adult)), Some(true));
+    assert_eq!(range.contains(&Age::new(&infant)), Some(false));
+    assert_eq!(range.contains(&Quantity::new(&weight)), None);
+
+    let open_value =
+      json!({"low": {"value": 12, "system": "http://unitsofmeasure.org", "code": "a"}});
+    let open = Range::new(&open_value);
+    assert_eq!(open.includes(&range), Some(true));
+    assert_eq!(range.includes(&open), Some(false));
+    assert_eq!(range.overlaps(&open), Some(true));
+  }
+}
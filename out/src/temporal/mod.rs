@@ -0,0 +1,10 @@
+//! Date and interval arithmetic that respects FHIR precision, and expansion
+//! of `Timing` schedules.
+
+pub mod datetime;
+pub mod interval;
+pub mod timing;
+
+pub use datetime::{DateTime, Precision};
+pub use interval::Interval;
+pub use timing::{TimingError, TimingExpander};
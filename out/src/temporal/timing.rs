@@ -0,0 +1,662 @@
+//! Expands `Timing` schedules into the instants they describe.
+//!
+//! A schedule starts at `boundsPeriod.start`, or at a caller supplied anchor
+//! such as `MedicationRequest.authoredOn`, or at the start of the requested
+//! window. Doses are placed at `timeOfDay`, at the clock times configured for
+//! the `when` event codes, or spread evenly over each period. Schedules that
+//! start on a date rather than an instant use conventional dosing times
+//! (09:00 and 21:00 for twice daily, and so on).
+
+use super::datetime::{
+  days_from_civil, days_in_month, DateTime, Precision, MILLIS_PER_DAY, MILLIS_PER_HOUR,
+  MILLIS_PER_MINUTE, MILLIS_PER_SECOND,
+};
+use super::interval::Interval;
+use crate::model::MedicationRequest::MedicationRequest;
+use crate::model::Timing::Timing;
+use crate::model::Timing_Repeat::Timing_Repeat;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+const DAYS_OF_WEEK: &[&str] = &["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+/// Schedules without an end are cut off after this many days.
+const MAX_DAYS: i64 = 100 * 366;
+
+const DEFAULT_EVENT_TIMES: &[(&str, u32, u32)] = &[
+  ("WAKE", 7, 0),
+  ("MORN", 8, 0),
+  ("MORN.early", 6, 0),
+  ("MORN.late", 10, 0),
+  ("NOON", 12, 0),
+  ("AFT", 14, 0),
+  ("AFT.early", 13, 0),
+  ("AFT.late", 16, 0),
+  ("EVE", 18, 0),
+  ("EVE.early", 17, 0),
+  ("EVE.late", 20, 0),
+  ("NIGHT", 22, 0),
+  ("PHS", 23, 0),
+  ("HS", 22, 0),
+  ("CM", 7, 30),
+  ("CD", 12, 30),
+  ("CV", 18, 30),
+  ("ACM", 7, 30),
+  ("ACD", 12, 30),
+  ("ACV", 18, 30),
+  ("PCM", 7, 30),
+  ("PCD", 12, 30),
+  ("PCV", 18, 30),
+];
+
+const DEFAULT_DAILY_TIMES: &[&[(u32, u32)]] = &[
+  &[(9, 0)],
+  &[(9, 0), (21, 0)],
+  &[(8, 0), (14, 0), (20, 0)],
+  &[(8, 0), (12, 0), (16, 0), (20, 0)],
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimingError {
+  InvalidDate(String),
+  InvalidTime(String),
+  /// A `Timing.code` with no known meaning.
+  UnknownCode(String),
+  /// A repeat that cannot be expanded, e.g. a zero period.
+  InvalidRepeat(String),
+  /// Neither the bounds, the window nor an anchor say when to start.
+  MissingStart,
+  /// Neither the bounds, the window nor a count say when to stop.
+  Unbounded,
+}
+
+impl fmt::Display for TimingError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TimingError::InvalidDate(date) => write!(f, "Invalid dateTime '{}'", date),
+      TimingError::InvalidTime(time) => write!(f, "Invalid time '{}'", time),
+      TimingError::UnknownCode(code) => write!(f, "Unknown timing code '{}'", code),
+      TimingError::InvalidRepeat(message) => write!(f, "Invalid repeat: {}", message),
+      TimingError::MissingStart => write!(f, "The schedule has no start"),
+      TimingError::Unbounded => write!(f, "The schedule has no end"),
+    }
+  }
+}
+
+impl std::error::Error for TimingError {}
+
+fn clock(hour: u32, minute: u32) -> i64 {
+  i64::from(hour) * MILLIS_PER_HOUR + i64::from(minute) * MILLIS_PER_MINUTE
+}
+
+/// Parses a FHIR `time` into milliseconds after midnight.
+fn parse_time(time: &str) -> Result<i64, TimingError> {
+  let invalid = || TimingError::InvalidTime(time.to_string());
+  let mut fields = time.split(':');
+  let mut field = |max: i64| -> Result<i64, TimingError> {
+    let text = fields.next().ok_or_else(invalid)?;
+    let text = text.split('.').next().unwrap_or_default();
+    match text.parse::<i64>() {
+      Ok(value) if text.len() == 2 && value <= max => Ok(value),
+      _ => Err(invalid()),
+    }
+  };
+  let (hour, minute, second) = (field(23)?, field(59)?, field(59)?);
+  Ok(hour * MILLIS_PER_HOUR + minute * MILLIS_PER_MINUTE + second * MILLIS_PER_SECOND)
+}
+
+/// The repeat a `Timing.code` abbreviation (v3 GTSAbbreviation) stands for.
+pub fn abbreviation(code: &str) -> Option<Value> {
+  let (frequency, period, unit) = match code {
+    "QD" => (1, 1, "d"),
+    "BID" => (2, 1, "d"),
+    "TID" => (3, 1, "d"),
+    "QID" => (4, 1, "d"),
+    "QOD" => (1, 2, "d"),
+    "Q1H" => (1, 1, "h"),
+    "Q2H" => (1, 2, "h"),
+    "Q3H" => (1, 3, "h"),
+    "Q4H" => (1, 4, "h"),
+    "Q6H" => (1, 6, "h"),
+    "Q8H" => (1, 8, "h"),
+    "WK" => (1, 1, "wk"),
+    "MO" => (1, 1, "mo"),
+    "AM" => return Some(json!({"frequency": 1, "period": 1, "periodUnit": "d", "when": ["MORN"]})),
+    "PM" => return Some(json!({"frequency": 1, "period": 1, "periodUnit": "d", "when": ["AFT"]})),
+    "BED" => return Some(json!({"frequency": 1, "period": 1, "periodUnit": "d", "when": ["HS"]})),
+    _ => return None,
+  };
+  Some(json!({"frequency": frequency, "period": period, "periodUnit": unit}))
+}
+
+/// Collects occurrences in order until the schedule ends.
+struct Collector {
+  window_low: Option<i64>,
+  end: Option<i64>,
+  count: Option<usize>,
+  limit: usize,
+  emitted: usize,
+  occurrences: Vec<i64>,
+}
+
+impl Collector {
+  /// Returns false once no further occurrences are wanted.
+  fn push(&mut self, millis: i64) -> bool {
+    if self.end.map(|end| millis >= end).unwrap_or(false)
+      || self
+        .count
+        .map(|count| self.emitted >= count)
+        .unwrap_or(false)
+      || self.occurrences.len() >= self.limit
+    {
+      return false;
+    }
+    self.emitted += 1;
+    if self.window_low.map(|low| millis >= low).unwrap_or(true) {
+      self.occurrences.push(millis);
+    }
+    true
+  }
+}
+
+pub struct TimingExpander {
+  event_times: HashMap<String, i64>,
+  daily_times: Vec<Vec<i64>>,
+  offset: i32,
+  limit: usize,
+}
+
+impl Default for TimingExpander {
+  fn default() -> TimingExpander {
+    TimingExpander::new()
+  }
+}
+
+impl TimingExpander {
+  pub fn new() -> TimingExpander {
+    TimingExpander {
+      event_times: DEFAULT_EVENT_TIMES
+        .iter()
+        .map(|(code, hour, minute)| (code.to_string(), clock(*hour, *minute)))
+        .collect(),
+      daily_times: DEFAULT_DAILY_TIMES
+        .iter()
+        .map(|times| times.iter().map(|(h, m)| clock(*h, *m)).collect())
+        .collect(),
+      offset: 0,
+      limit: 10_000,
+    }
+  }
+
+  /// Sets the clock time of a `when` event code such as `ACM` or `HS`. For
+  /// meal codes this is the meal time; `offset` moves doses before (`AC*`)
+  /// or after it.
+  pub fn event_time(&mut self, code: &str, hour: u32, minute: u32) -> &mut TimingExpander {
+    self
+      .event_times
+      .insert(code.to_string(), clock(hour, minute));
+    self
+  }
+
+  /// Sets the clock times used for `frequency` doses a day when a schedule
+  /// starting on a date gives no times.
+  pub fn daily_times(&mut self, frequency: usize, times: &[(u32, u32)]) -> &mut TimingExpander {
+    if frequency == 0 {
+      return self;
+    }
+    if self.daily_times.len() < frequency {
+      self.daily_times.resize(frequency, vec![]);
+    }
+    self.daily_times[frequency - 1] = times.iter().map(|(h, m)| clock(*h, *m)).collect();
+    self
+  }
+
+  /// The time zone clock times are in, in minutes east of UTC. Defaults to
+  /// UTC.
+  pub fn time_zone(&mut self, offset: i32) -> &mut TimingExpander {
+    self.offset = offset;
+    self
+  }
+
+  /// The most occurrences returned by one expansion.
+  pub fn limit(&mut self, limit: usize) -> &mut TimingExpander {
+    self.limit = limit;
+    self
+  }
+
+  fn offset_millis(&self) -> i64 {
+    i64::from(self.offset) * MILLIS_PER_MINUTE
+  }
+
+  /// The occurrences of `timing` within `window`, in order.
+  pub fn expand(&self, timing: &Timing, window: &Interval) -> Result<Vec<DateTime>, TimingError> {
+    self.expand_from(timing, None, window)
+  }
+
+  /// Like `expand`, with `anchor` as the start of a schedule that has no
+  /// `boundsPeriod.start`.
+  pub fn expand_from(
+    &self,
+    timing: &Timing,
+    anchor: Option<&str>,
+    window: &Interval,
+  ) -> Result<Vec<DateTime>, TimingError> {
+    let mut occurrences = vec![];
+    for event in timing.event().unwrap_or_default() {
+      let value =
+        DateTime::parse(event).ok_or_else(|| TimingError::InvalidDate(event.to_string()))?;
+      if window.contains(&Interval::of(&value)) != Some(false) {
+        occurrences.push(value.low());
+      }
+    }
+
+    let repeat = match (timing.value.get("repeat"), timing.code()) {
+      (Some(repeat), _) => Some(repeat.clone()),
+      (None, Some(code)) => {
+        let codings = code.coding().unwrap_or_default();
+        let codes: Vec<&str> = codings
+          .iter()
+          .filter_map(|c| c.code())
+          .chain(code.text())
+          .collect();
+        let repeat = codes.iter().find_map(|c| abbreviation(c));
+        Some(repeat.ok_or_else(|| TimingError::UnknownCode(codes.join(", ")))?)
+      }
+      (None, None) => None,
+    };
+    if let Some(repeat) = repeat {
+      occurrences.extend(self.expand_repeat(&Timing_Repeat::new(&repeat), anchor, window)?);
+    }
+
+    occurrences.sort_unstable();
+    occurrences.dedup();
+    occurrences.truncate(self.limit);
+    Ok(
+      occurrences
+        .into_iter()
+        .map(|millis| DateTime::from_millis(millis, Some(self.offset), Precision::Second))
+        .collect(),
+    )
+  }
+
+  /// The administration times of every dosage instruction of a
+  /// `MedicationRequest`, starting from `authoredOn` when the timing has no
+  /// bounds.
+  pub fn expand_medication_request(
+    &self,
+    request: &MedicationRequest,
+    window: &Interval,
+  ) -> Result<Vec<DateTime>, TimingError> {
+    let mut occurrences = vec![];
+    for dosage in request.dosage_instruction().unwrap_or_default() {
+      if let Some(timing) = dosage.timing() {
+        occurrences.extend(self.expand_from(&timing, request.authored_on(), window)?);
+      }
+    }
+    occurrences.sort_by_key(|o| o.low());
+    occurrences.dedup_by_key(|o| o.low());
+    Ok(occurrences)
+  }
+
+  /// The start of a schedule and whether it is only a date.
+  fn start(&self, start: &str) -> Result<(i64, bool), TimingError> {
+    let value =
+      DateTime::parse(start).ok_or_else(|| TimingError::InvalidDate(start.to_string()))?;
+    if value.precision <= Precision::Day {
+      let midnight = days_from_civil(value.year, value.month, value.day) * MILLIS_PER_DAY;
+      Ok((midnight - self.offset_millis(), true))
+    } else {
+      Ok((value.low(), false))
+    }
+  }
+
+  fn when_times(&self, repeat: &Timing_Repeat) -> Result<Vec<i64>, TimingError> {
+    let offset = repeat.offset().unwrap_or(0) as i64 * MILLIS_PER_MINUTE;
+    let codes = match repeat.value.get("when") {
+      Some(Value::Array(codes)) => codes.iter().filter_map(|c| c.as_str()).collect(),
+      _ => vec![],
+    };
+    let mut times = vec![];
+    for code in codes {
+      let expanded: Vec<String> = match code {
+        "C" | "AC" | "PC" => ["M", "D", "V"]
+          .iter()
+          .map(|meal| format!("{}{}", code, meal))
+          .collect(),
+        _ => vec![code.to_string()],
+      };
+      for code in expanded {
+        let time = *self
+          .event_times
+          .get(&code)
+          .ok_or_else(|| TimingError::UnknownCode(code.clone()))?;
+        let time = if code.starts_with("AC") {
+          time - offset
+        } else {
+          time + offset
+        };
+        times.push(time.rem_euclid(MILLIS_PER_DAY));
+      }
+    }
+    Ok(times)
+  }
+
+  fn expand_repeat(
+    &self,
+    repeat: &Timing_Repeat,
+    anchor: Option<&str>,
+    window: &Interval,
+  ) -> Result<Vec<i64>, TimingError> {
+    let mut bounds = Interval {
+      low: None,
+      high: None,
+    };
+    let mut start = None;
+    if let Some(period) = repeat.bounds_period() {
+      bounds = period
+        .interval()
+        .ok_or_else(|| TimingError::InvalidDate(period.to_json().to_string()))?;
+      if let Some(period_start) = period.start() {
+        start = Some(self.start(period_start)?);
+      }
+    }
+    if start.is_none() {
+      start = match (anchor, window.low) {
+        (Some(anchor), _) => Some(self.start(anchor)?),
+        (None, Some(low)) => Some((low, false)),
+        (None, None) => None,
+      };
+    }
+    let (start, date_only) = start.ok_or(TimingError::MissingStart)?;
+
+    let bounds_length = match (repeat.bounds_duration(), repeat.bounds_range()) {
+      (Some(duration), _) => Some((duration.value(), duration.code().map(|c| c.to_string()))),
+      (None, Some(range)) => range
+        .high()
+        .map(|high| (high.value(), high.code().map(|c| c.to_string()))),
+      (None, None) => None,
+    };
+    if let Some((value, code)) = bounds_length {
+      let (value, code) = match (value, code) {
+        (Some(value), Some(code)) => (value, code),
+        _ => {
+          return Err(TimingError::InvalidRepeat(
+            "bounds without a value or unit".to_string(),
+          ))
+        }
+      };
+      let millis = crate::ucum::convert(value, &code, "ms")
+        .map_err(|e| TimingError::InvalidRepeat(e.to_string()))?;
+      bounds.high = Some(start + millis as i64);
+    }
+
+    let end = match (window.high, bounds.high) {
+      (Some(a), Some(b)) => Some(a.min(b)),
+      (a, b) => a.or(b),
+    };
+    let count = repeat.count().map(|c| c.max(0) as usize);
+    if end.is_none() && count.is_none() {
+      return Err(TimingError::Unbounded);
+    }
+    let mut collector = Collector {
+      window_low: window.low,
+      end,
+      count,
+      limit: self.limit,
+      emitted: 0,
+      occurrences: vec![],
+    };
+
+    let frequency = repeat.frequency().unwrap_or(1).max(1) as usize;
+    let period = repeat.period().unwrap_or(1.0);
+    if period <= 0.0 {
+      return Err(TimingError::InvalidRepeat(format!("period {}", period)));
+    }
+    let unit = repeat
+      .period_unit()
+      .map(|u| u.to_string())
+      .unwrap_or_else(|| "d".to_string());
+
+    let mut times = repeat
+      .time_of_day()
+      .unwrap_or_default()
+      .iter()
+      .map(|t| parse_time(t))
+      .collect::<Result<Vec<_>, _>>()?;
+    times.extend(self.when_times(repeat)?);
+    let calendar_unit = matches!(unit.as_str(), "d" | "wk" | "mo" | "a");
+    let once_per_day = frequency == 1 || (unit == "d" && period <= 1.0);
+    if times.is_empty() && date_only && calendar_unit && once_per_day {
+      times = self
+        .daily_times
+        .get(frequency - 1)
+        .cloned()
+        .unwrap_or_default();
+    }
+    let days_of_week: Vec<i64> = repeat
+      .day_of_week()
+      .unwrap_or_default()
+      .iter()
+      .filter_map(|d| DAYS_OF_WEEK.iter().position(|w| w == d))
+      .map(|d| d as i64)
+      .collect();
+
+    if !times.is_empty() || !days_of_week.is_empty() {
+      if times.is_empty() {
+        times.push((start + self.offset_millis()).rem_euclid(MILLIS_PER_DAY));
+      }
+      times.sort_unstable();
+      times.dedup();
+      self.expand_daily(&mut collector, start, &unit, period, &times, &days_of_week);
+    } else {
+      self.expand_evenly(&mut collector, start, &unit, period, frequency);
+    }
+    Ok(collector.occurrences)
+  }
+
+  /// Places doses at clock times on the days the schedule falls on.
+  fn expand_daily(
+    &self,
+    collector: &mut Collector,
+    start: i64,
+    unit: &str,
+    period: f64,
+    times: &[i64],
+    days_of_week: &[i64],
+  ) {
+    let offset = self.offset_millis();
+    let first_day = (start + offset).div_euclid(MILLIS_PER_DAY);
+    let start_date = DateTime::from_millis(start, Some(self.offset), Precision::Day);
+    let period = (period.round() as i64).max(1);
+    for day in first_day..first_day + MAX_DAYS {
+      let index = day - first_day;
+      if !days_of_week.is_empty() && !days_of_week.contains(&(day + 3).rem_euclid(7)) {
+        continue;
+      }
+      let on_schedule = match unit {
+        "d" => index % period == 0,
+        "wk" if !days_of_week.is_empty() => (index / 7) % period == 0,
+        "wk" => index % (7 * period) == 0,
+        "mo" | "a" if days_of_week.is_empty() => {
+          let months = if unit == "a" { 12 * period } else { period };
+          let date = DateTime::from_millis(day * MILLIS_PER_DAY, None, Precision::Day);
+          let elapsed = i64::from(date.year * 12 + date.month as i32)
+            - i64::from(start_date.year * 12 + start_date.month as i32);
+          elapsed % months == 0
+            && date.day == start_date.day.min(days_in_month(date.year, date.month))
+        }
+        _ => true,
+      };
+      if !on_schedule {
+        continue;
+      }
+      for time in times {
+        let millis = day * MILLIS_PER_DAY + time - offset;
+        if millis < start {
+          continue;
+        }
+        if !collector.push(millis) {
+          return;
+        }
+      }
+    }
+  }
+
+  /// Spreads `frequency` doses evenly over each period.
+  fn expand_evenly(
+    &self,
+    collector: &mut Collector,
+    start: i64,
+    unit: &str,
+    period: f64,
+    frequency: usize,
+  ) {
+    let unit_millis = match unit {
+      "s" => MILLIS_PER_SECOND,
+      "min" => MILLIS_PER_MINUTE,
+      "h" => MILLIS_PER_HOUR,
+      "d" => MILLIS_PER_DAY,
+      "wk" => 7 * MILLIS_PER_DAY,
+      _ => {
+        let months = (period * if unit == "a" { 12.0 } else { 1.0 })
+          .round()
+          .max(1.0) as i32;
+        let start_date = DateTime::from_millis(start, Some(self.offset), Precision::Millisecond);
+        for cycle in 0.. {
+          let cycle_start = start_date.add_months(cycle * months).low();
+          let cycle_end = start_date.add_months((cycle + 1) * months).low();
+          let step = (cycle_end - cycle_start) / frequency as i64;
+          for dose in 0..frequency as i64 {
+            if !collector.push(cycle_start + dose * step) {
+              return;
+            }
+          }
+        }
+        return;
+      }
+    };
+    let step = ((period * unit_millis as f64) / frequency as f64)
+      .round()
+      .max(1.0) as i64;
+    let mut millis = start;
+    while collector.push(millis) {
+      millis += step;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::fs;
+
+  fn window(low: &str, high: &str) -> Interval {
+    Interval {
+      low: DateTime::parse(low).map(|d| d.low()),
+      high: DateTime::parse(high).map(|d| d.high()),
+    }
+  }
+
+  fn strings(occurrences: Vec<DateTime>) -> Vec<String> {
+    occurrences.iter().map(|o| o.to_string()).collect()
+  }
+
+  #[test]
+  fn test_expand_timings() {
+    let expander = TimingExpander::new();
+    let bid = json!({
+      "code": {"coding": [{"system": "http://terminology.hl7.org/CodeSystem/v3-GTSAbbreviation", "code": "BID"}]},
+      "repeat": {"boundsDuration": {"value": 7, "unit": "days", "system": "http://unitsofmeasure.org", "code": "d"}, "frequency": 2, "period": 1, "periodUnit": "d"}
+    });
+    let occurrences = expander
+      .expand_from(
+        &Timing::new(&bid),
+        Some("2020-01-01"),
+        &window("2020", "2020"),
+      )
+      .unwrap();
+    assert_eq!(occurrences.len(), 14);
+    assert_eq!(occurrences[0].to_string(), "2020-01-01T09:00:00Z");
+    assert_eq!(occurrences[13].to_string(), "2020-01-07T21:00:00Z");
+
+    let q4h = json!({"code": {"text": "Q4H"}});
+    let occurrences = expander
+      .expand(
+        &Timing::new(&q4h),
+        &window("2020-01-01T06:00:00Z", "2020-01-01"),
+      )
+      .unwrap();
+    assert_eq!(
+      strings(occurrences),
+      vec![
+        "2020-01-01T06:00:00Z",
+        "2020-01-01T10:00:00Z",
+        "2020-01-01T14:00:00Z",
+        "2020-01-01T18:00:00Z",
+        "2020-01-01T22:00:00Z"
+      ]
+    );
+
+    let mut expander = TimingExpander::new();
+    expander.event_time("CM", 8, 0).time_zone(60);
+    let before_breakfast = json!({
+      "repeat": {"when": ["CM", "HS"], "offset": 30, "count": 3, "dayOfWeek": ["mon", "wed"]}
+    });
+    let occurrences = expander
+      .expand(
+        &Timing::new(&before_breakfast),
+        &window("2020-01-01", "2020-12-31"),
+      )
+      .unwrap();
+    assert_eq!(
+      strings(occurrences),
+      vec![
+        "2020-01-01T08:30:00+01:00",
+        "2020-01-01T22:30:00+01:00",
+        "2020-01-06T08:30:00+01:00"
+      ]
+    );
+
+    let unknown = json!({"code": {"text": "QXYZ"}});
+    assert_eq!(
+      expander.expand(&Timing::new(&unknown), &window("2020-01-01", "2020-12-31")),
+      Err(TimingError::UnknownCode("QXYZ".to_string()))
+    );
+    let open = json!({"repeat": {"frequency": 1, "period": 1, "periodUnit": "d"}});
+    let unbounded = Interval {
+      low: Some(0),
+      high: None,
+    };
+    assert_eq!(
+      expander.expand(&Timing::new(&open), &unbounded),
+      Err(TimingError::Unbounded)
+    );
+  }
+
+  #[test]
+  fn test_expand_medication_request() {
+    let contents = fs::read_to_string("examples-json/medicationrequest0309.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let request = MedicationRequest::new(&value);
+
+    let expander = TimingExpander::new();
+    let occurrences = expander
+      .expand_medication_request(&request, &window("2016", "2016"))
+      .unwrap();
+    let dates: Vec<String> = occurrences.iter().map(|o| o.date().to_string()).collect();
+    assert_eq!(
+      dates,
+      vec![
+        "2016-01-22",
+        "2016-01-24",
+        "2016-01-26",
+        "2016-01-28",
+        "2016-01-30",
+        "2016-02-01",
+        "2016-02-03"
+      ]
+    );
+    assert_eq!(occurrences[0].to_string(), "2016-01-22T09:00:00Z");
+  }
+}
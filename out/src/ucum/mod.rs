@@ -0,0 +1,58 @@
+//! UCUM units of measure: parsing unit expressions, converting between
+//! units and normalising to canonical units.
+//!
+//! The quantity datatypes (`Quantity`, `Age`, `Duration`, `Distance` and
+//! `Count`; `SimpleQuantity` is a profile of `Quantity`) gain `convert_to`,
+//! `canonical` and `compare`, and can be compared with `<` and `==` when
+//! their units are compatible.
+
+pub mod quantity;
+pub mod table;
+pub mod unit;
+
+pub use unit::Unit;
+
+use std::fmt;
+
+pub const UCUM_SYSTEM: &str = "http://unitsofmeasure.org";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UcumError {
+  /// The quantity's unit is from a code system other than UCUM.
+  NotUcum(String),
+  /// The quantity has no coded unit.
+  MissingCode,
+  MissingValue,
+  InvalidUnit {
+    unit: String,
+    message: String,
+  },
+  /// The units measure different kinds of things, e.g. mass and length.
+  Incompatible {
+    from: String,
+    to: String,
+  },
+}
+
+impl fmt::Display for UcumError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      UcumError::NotUcum(system) => write!(f, "'{}' is not the UCUM system", system),
+      UcumError::MissingCode => write!(f, "The quantity has no unit code"),
+      UcumError::MissingValue => write!(f, "The quantity has no value"),
+      UcumError::InvalidUnit { unit, message } => {
+        write!(f, "Invalid UCUM unit '{}': {}", unit, message)
+      }
+      UcumError::Incompatible { from, to } => {
+        write!(f, "Cannot convert from '{}' to '{}'", from, to)
+      }
+    }
+  }
+}
+
+impl std::error::Error for UcumError {}
+
+/// Converts `value` from one UCUM unit to another.
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, UcumError> {
+  Unit::parse(from)?.convert(value, &Unit::parse(to)?)
+}
@@ -0,0 +1,222 @@
+//! UCUM operations on the generated quantity datatypes.
+
+use super::{UcumError, Unit, UCUM_SYSTEM};
+use crate::model::Age::Age;
+use crate::model::Count::Count;
+use crate::model::Distance::Distance;
+use crate::model::Duration::Duration;
+use crate::model::Quantity::Quantity;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// Converted values are rounded to this many significant digits so that
+/// floating point noise does not show up in the result.
+const SIGNIFICANT_DIGITS: i32 = 12;
+
+/// The value and parsed unit of a quantity. A quantity without a `system`
+/// is assumed to be coded in UCUM.
+fn measure(quantity: &Value) -> Result<(f64, Unit), UcumError> {
+  if let Some(system) = quantity.get("system").and_then(|s| s.as_str()) {
+    if system != UCUM_SYSTEM {
+      return Err(UcumError::NotUcum(system.to_string()));
+    }
+  }
+  let code = quantity
+    .get("code")
+    .and_then(|c| c.as_str())
+    .ok_or(UcumError::MissingCode)?;
+  let value = quantity
+    .get("value")
+    .and_then(|v| v.as_f64())
+    .ok_or(UcumError::MissingValue)?;
+  Ok((value, Unit::parse(code)?))
+}
+
+fn round(value: f64) -> f64 {
+  if value == 0.0 || !value.is_finite() {
+    return value;
+  }
+  let scale = 10f64.powi(SIGNIFICANT_DIGITS - 1 - value.abs().log10().floor() as i32);
+  if !scale.is_finite() {
+    return value;
+  }
+  (value * scale).round() / scale
+}
+
+fn with_unit(quantity: &Value, value: f64, code: &str) -> Value {
+  let mut converted = quantity.clone();
+  converted["value"] = serde_json::json!(round(value));
+  converted["unit"] = Value::String(code.to_string());
+  converted["system"] = Value::String(UCUM_SYSTEM.to_string());
+  converted["code"] = Value::String(code.to_string());
+  converted
+}
+
+fn convert_to(quantity: &Value, code: &str) -> Result<Value, UcumError> {
+  let (value, unit) = measure(quantity)?;
+  let target = Unit::parse(code)?;
+  let converted = unit.convert(value, &target)?;
+  Ok(with_unit(quantity, converted, code))
+}
+
+fn canonical(quantity: &Value) -> Result<Value, UcumError> {
+  let (value, unit) = measure(quantity)?;
+  Ok(with_unit(
+    quantity,
+    unit.to_canonical(value),
+    &unit.canonical_code(),
+  ))
+}
+
+fn compare(a: &Value, b: &Value) -> Result<Ordering, UcumError> {
+  let (a_value, a_unit) = measure(a)?;
+  let (b_value, b_unit) = measure(b)?;
+  let b_value = b_unit.convert(b_value, &a_unit)?;
+  let tolerance = 1e-12 * a_value.abs().max(b_value.abs());
+  if (a_value - b_value).abs() <= tolerance {
+    return Ok(Ordering::Equal);
+  }
+  a_value.partial_cmp(&b_value).ok_or(UcumError::MissingValue)
+}
+
+/// Implemented by the quantity datatypes so that different kinds can be
+/// compared, e.g. an `Age` against the `Quantity` bounds of a `Range`.
+pub trait UcumQuantity {
+  fn quantity_value(&self) -> &Value;
+}
+
+/// Compares two quantities of any kind; see `Quantity::compare`.
+pub fn compare_quantities(
+  a: &dyn UcumQuantity,
+  b: &dyn UcumQuantity,
+) -> Result<Ordering, UcumError> {
+  compare(a.quantity_value(), b.quantity_value())
+}
+
+macro_rules! ucum_quantity {
+  ($($name:ident),*) => {
+    $(
+      impl $name<'_> {
+        /// Converts this quantity to another UCUM unit. The result is coded
+        /// in UCUM, with `unit` set to the new code.
+        pub fn convert_to(&self, code: &str) -> Result<$name<'static>, UcumError> {
+          Ok($name {
+            value: Cow::Owned(convert_to(&self.value, code)?),
+          })
+        }
+
+        /// Converts this quantity to canonical UCUM units, e.g. `1.2 g.m-3`
+        /// for `120 mg/dL`.
+        pub fn canonical(&self) -> Result<$name<'static>, UcumError> {
+          Ok($name {
+            value: Cow::Owned(canonical(&self.value)?),
+          })
+        }
+
+        /// Compares the values of two quantities after converting them to
+        /// the same unit. Comparators (`<`, `>=`, ...) are ignored.
+        pub fn compare(&self, other: &$name) -> Result<Ordering, UcumError> {
+          compare(&self.value, &other.value)
+        }
+      }
+
+      impl UcumQuantity for $name<'_> {
+        fn quantity_value(&self) -> &Value {
+          &self.value
+        }
+      }
+
+      /// Quantities are equal when they measure the same amount in compatible
+      /// UCUM units, so `5 mg` equals `0.005 g`, or when their JSON is equal,
+      /// so that a quantity without a value or a UCUM unit equals itself.
+      impl PartialEq for $name<'_> {
+        fn eq(&self, other: &Self) -> bool {
+          self.partial_cmp(other) == Some(Ordering::Equal)
+        }
+      }
+
+      /// Quantities with incompatible or non-UCUM units, or with a
+      /// comparator, are unordered unless their JSON is equal.
+      impl PartialOrd for $name<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+          if self.value == other.value {
+            return Some(Ordering::Equal);
+          }
+          if self.value.get("comparator").is_some() || other.value.get("comparator").is_some() {
+            return None;
+          }
+          compare(&self.value, &other.value).ok()
+        }
+      }
+    )*
+  };
+}
+
+ucum_quantity!(Quantity, Age, Count, Distance, Duration);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::Observation::Observation;
+  use std::fs;
+
+  fn quantity(value: f64, code: &str) -> Value {
+    serde_json::json!({"value": value, "unit": code, "system": UCUM_SYSTEM, "code": code})
+  }
+
+  #[test]
+  fn test_compare_quantities() {
+    let (a, b, c) = (
+      quantity(5.0, "mg"),
+      quantity(0.005, "g"),
+      quantity(1.0, "[lb_av]"),
+    );
+    let (mg, g, lb) = (Quantity::new(&a), Quantity::new(&b), Quantity::new(&c));
+    assert!(mg == g);
+    assert!(mg < lb);
+    assert_eq!(lb.compare(&g), Ok(Ordering::Greater));
+
+    let metres = quantity(1.0, "m");
+    assert_eq!(mg.partial_cmp(&Quantity::new(&metres)), None);
+    assert_eq!(
+      mg.compare(&Quantity::new(&metres)),
+      Err(UcumError::Incompatible {
+        from: "m".to_string(),
+        to: "mg".to_string()
+      })
+    );
+
+    let snomed =
+      serde_json::json!({"value": 1, "system": "http://snomed.info/sct", "code": "258684004"});
+    assert_eq!(Quantity::new(&snomed), Quantity::new(&snomed.clone()));
+    let unknown = serde_json::json!({"system": UCUM_SYSTEM, "code": "mg"});
+    assert_eq!(Quantity::new(&unknown), Quantity::new(&unknown));
+    assert_ne!(Quantity::new(&unknown), mg);
+    assert_eq!(
+      Quantity::new(&snomed).convert_to("mg"),
+      Err(UcumError::NotUcum("http://snomed.info/sct".to_string()))
+    );
+    let age = serde_json::json!({"value": 18, "system": UCUM_SYSTEM, "code": "a"});
+    let days = Age::new(&age).convert_to("d").unwrap();
+    assert_eq!(days.value(), Some(6574.5));
+  }
+
+  #[test]
+  fn test_convert_observation_weight() {
+    let contents = fs::read_to_string("examples-json/observation-example.json").unwrap();
+    let value: Value = serde_json::from_str(&contents).unwrap();
+    let observation = Observation::new(&value);
+    let weight = observation.value_quantity().unwrap();
+    assert_eq!(weight.code(), Some("[lb_av]"));
+
+    let kilograms = weight.convert_to("kg").unwrap();
+    assert_eq!(kilograms.value(), Some(83.91458845));
+    assert_eq!(kilograms.code(), Some("kg"));
+    assert_eq!(kilograms.unit(), Some("kg"));
+
+    let canonical = weight.canonical().unwrap();
+    assert_eq!(canonical.code(), Some("g"));
+    assert!(canonical == kilograms);
+  }
+}
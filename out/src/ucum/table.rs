@@ -0,0 +1,219 @@
+//! The UCUM prefixes and unit atoms that are understood, taken from the
+//! UCUM specification. Defined units are expressed in terms of other units
+//! exactly as the specification does, and resolved when parsed.
+
+/// The dimensions every unit is reduced to.
+pub const BASE_UNITS: &[&str] = &["m", "s", "g", "rad", "K", "C", "cd"];
+
+pub const PREFIXES: &[(&str, f64)] = &[
+  ("Y", 1e24),
+  ("Z", 1e21),
+  ("E", 1e18),
+  ("P", 1e15),
+  ("T", 1e12),
+  ("G", 1e9),
+  ("M", 1e6),
+  ("k", 1e3),
+  ("h", 1e2),
+  ("da", 1e1),
+  ("d", 1e-1),
+  ("c", 1e-2),
+  ("m", 1e-3),
+  ("u", 1e-6),
+  ("n", 1e-9),
+  ("p", 1e-12),
+  ("f", 1e-15),
+  ("a", 1e-18),
+  ("z", 1e-21),
+  ("y", 1e-24),
+  ("Ki", 1024.0),
+  ("Mi", 1_048_576.0),
+  ("Gi", 1_073_741_824.0),
+  ("Ti", 1_099_511_627_776.0),
+];
+
+/// How a defined unit relates to other units.
+pub enum Definition {
+  /// One of `BASE_UNITS`.
+  Base,
+  /// `factor` times the unit expression.
+  Scaled(f64, &'static str),
+  /// A unit on an arbitrary scale, only comparable with itself.
+  Arbitrary,
+  /// A temperature scale with an offset from kelvin: `K = value * factor +
+  /// offset`. These cannot be combined with other units.
+  Offset(f64, f64),
+}
+
+pub struct UnitAtom {
+  pub code: &'static str,
+  /// Whether the unit accepts a prefix.
+  pub metric: bool,
+  pub definition: Definition,
+}
+
+const fn base(code: &'static str) -> UnitAtom {
+  UnitAtom {
+    code,
+    metric: true,
+    definition: Definition::Base,
+  }
+}
+
+const fn metric(code: &'static str, factor: f64, unit: &'static str) -> UnitAtom {
+  UnitAtom {
+    code,
+    metric: true,
+    definition: Definition::Scaled(factor, unit),
+  }
+}
+
+const fn unit(code: &'static str, factor: f64, unit: &'static str) -> UnitAtom {
+  UnitAtom {
+    code,
+    metric: false,
+    definition: Definition::Scaled(factor, unit),
+  }
+}
+
+const fn arbitrary(code: &'static str) -> UnitAtom {
+  UnitAtom {
+    code,
+    metric: false,
+    definition: Definition::Arbitrary,
+  }
+}
+
+pub const UNITS: &[UnitAtom] = &[
+  // Base units
+  base("m"),
+  base("s"),
+  base("g"),
+  base("rad"),
+  base("K"),
+  base("C"),
+  base("cd"),
+  // Dimensionless
+  unit("10*", 10.0, "1"),
+  unit("10^", 10.0, "1"),
+  unit("[pi]", std::f64::consts::PI, "1"),
+  unit("%", 1e-2, "1"),
+  unit("[ppth]", 1e-3, "1"),
+  unit("[ppm]", 1e-6, "1"),
+  unit("[ppb]", 1e-9, "1"),
+  unit("[pptr]", 1e-12, "1"),
+  metric("mol", 6.022_136_7e23, "1"),
+  metric("sr", 1.0, "rad2"),
+  // SI derived units
+  metric("Hz", 1.0, "s-1"),
+  metric("N", 1.0, "kg.m/s2"),
+  metric("Pa", 1.0, "N/m2"),
+  metric("J", 1.0, "N.m"),
+  metric("W", 1.0, "J/s"),
+  metric("A", 1.0, "C/s"),
+  metric("V", 1.0, "J/C"),
+  metric("F", 1.0, "C/V"),
+  metric("Ohm", 1.0, "V/A"),
+  metric("S", 1.0, "Ohm-1"),
+  metric("Wb", 1.0, "V.s"),
+  metric("T", 1.0, "Wb/m2"),
+  metric("H", 1.0, "Wb/A"),
+  metric("lm", 1.0, "cd.sr"),
+  metric("lx", 1.0, "lm/m2"),
+  metric("Bq", 1.0, "s-1"),
+  metric("Gy", 1.0, "J/kg"),
+  metric("Sv", 1.0, "J/kg"),
+  metric("kat", 1.0, "mol/s"),
+  metric("U", 1.0, "umol/min"),
+  UnitAtom {
+    code: "Cel",
+    metric: true,
+    definition: Definition::Offset(1.0, 273.15),
+  },
+  // Time
+  unit("min", 60.0, "s"),
+  unit("h", 60.0, "min"),
+  unit("d", 24.0, "h"),
+  unit("a_t", 365.242_19, "d"),
+  unit("a_j", 365.25, "d"),
+  unit("a_g", 365.242_5, "d"),
+  unit("a", 1.0, "a_j"),
+  unit("wk", 7.0, "d"),
+  unit("mo_s", 29.530_59, "d"),
+  unit("mo_j", 1.0 / 12.0, "a_j"),
+  unit("mo_g", 1.0 / 12.0, "a_g"),
+  unit("mo", 1.0, "mo_j"),
+  // Other metric units
+  unit("gon", 0.9, "deg"),
+  unit("deg", 2.0 / 360.0, "[pi].rad"),
+  unit("'", 1.0 / 60.0, "deg"),
+  unit("''", 1.0 / 60.0, "'"),
+  metric("l", 1.0, "dm3"),
+  metric("L", 1.0, "l"),
+  metric("ar", 100.0, "m2"),
+  metric("t", 1e3, "kg"),
+  metric("bar", 1e5, "Pa"),
+  metric("u", 1.660_540_2e-24, "g"),
+  metric("eV", 1.602_177_33e-19, "J"),
+  metric("atm", 101_325.0, "Pa"),
+  metric("m[Hg]", 133.322, "kPa"),
+  metric("m[H2O]", 9.806_65, "kPa"),
+  metric("cal", 4.184, "J"),
+  unit("[Cal]", 1.0, "kcal"),
+  metric("eq", 1.0, "mol"),
+  metric("osm", 1.0, "mol"),
+  unit("g%", 1.0, "g/dl"),
+  unit("[drp]", 1.0, "ml/20"),
+  // International customary units
+  unit("[in_i]", 2.54, "cm"),
+  unit("[ft_i]", 12.0, "[in_i]"),
+  unit("[yd_i]", 3.0, "[ft_i]"),
+  unit("[mi_i]", 5280.0, "[ft_i]"),
+  unit("[nmi_i]", 1852.0, "m"),
+  unit("[sin_i]", 1.0, "[in_i]2"),
+  unit("[sft_i]", 1.0, "[ft_i]2"),
+  unit("[cin_i]", 1.0, "[in_i]3"),
+  unit("[cft_i]", 1.0, "[ft_i]3"),
+  unit("[gr]", 64.798_91, "mg"),
+  unit("[lb_av]", 7000.0, "[gr]"),
+  unit("[oz_av]", 1.0 / 16.0, "[lb_av]"),
+  unit("[dr_av]", 1.0 / 16.0, "[oz_av]"),
+  unit("[stone_av]", 14.0, "[lb_av]"),
+  unit("[psi]", 1.0, "[lbf_av]/[in_i]2"),
+  unit("[lbf_av]", 1.0, "[lb_av].[g]"),
+  metric("[g]", 9.806_65, "m/s2"),
+  // US volumes
+  unit("[gal_us]", 231.0, "[in_i]3"),
+  unit("[qt_us]", 1.0 / 4.0, "[gal_us]"),
+  unit("[pt_us]", 1.0 / 2.0, "[qt_us]"),
+  unit("[cup_us]", 16.0, "[tbs_us]"),
+  unit("[foz_us]", 1.0 / 128.0, "[gal_us]"),
+  unit("[tbs_us]", 1.0 / 2.0, "[foz_us]"),
+  unit("[tsp_us]", 1.0 / 3.0, "[tbs_us]"),
+  // British volumes
+  unit("[gal_br]", 4.546_09, "l"),
+  unit("[pt_br]", 1.0 / 8.0, "[gal_br]"),
+  unit("[foz_br]", 1.0 / 20.0, "[pt_br]"),
+  // Temperature
+  UnitAtom {
+    code: "[degF]",
+    metric: false,
+    definition: Definition::Offset(5.0 / 9.0, 459.67 * 5.0 / 9.0),
+  },
+  // Arbitrary units
+  arbitrary("[iU]"),
+  unit("[IU]", 1.0, "[iU]"),
+  arbitrary("[arb'U]"),
+  arbitrary("[USP'U]"),
+  arbitrary("[CFU]"),
+  arbitrary("[HPF]"),
+  arbitrary("[LPF]"),
+];
+
+pub fn prefix(code: &str) -> Option<f64> {
+  PREFIXES.iter().find(|(p, _)| *p == code).map(|(_, f)| *f)
+}
+
+pub fn atom(code: &str) -> Option<&'static UnitAtom> {
+  UNITS.iter().find(|u| u.code == code)
+}
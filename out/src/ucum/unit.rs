@@ -0,0 +1,381 @@
+//! Parsing of UCUM unit expressions (`mg/dL`, `kg.m/s2`, `10*3/uL`,
+//! `[lb_av]`) into a factor and dimensions over the base units.
+
+use super::table::{self, Definition};
+use super::UcumError;
+use std::collections::BTreeMap;
+
+/// Definitions are resolved recursively; this guards against a bad table.
+const MAX_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unit {
+  /// The expression this unit was parsed from.
+  pub code: String,
+  /// The value of one of this unit in canonical units.
+  pub factor: f64,
+  /// Set for temperature scales that do not start at zero kelvin.
+  pub offset: Option<f64>,
+  dimensions: BTreeMap<&'static str, i32>,
+}
+
+impl Unit {
+  /// Parses a UCUM expression. Annotations (`{cells}`) are ignored, as the
+  /// specification requires.
+  pub fn parse(code: &str) -> Result<Unit, UcumError> {
+    let mut unit = parse_expression(code, 0)?;
+    unit.code = code.to_string();
+    Ok(unit)
+  }
+
+  fn dimensionless(factor: f64) -> Unit {
+    Unit {
+      code: String::new(),
+      factor,
+      offset: None,
+      dimensions: BTreeMap::new(),
+    }
+  }
+
+  /// Whether values in this unit can be converted to `other`.
+  pub fn is_compatible(&self, other: &Unit) -> bool {
+    self.dimensions == other.dimensions
+  }
+
+  /// The unit made of base units only that this unit is normalised to,
+  /// e.g. `g.m-3` for `mg/dL` or `1` for `%`.
+  pub fn canonical_code(&self) -> String {
+    if self.dimensions.is_empty() {
+      return "1".to_string();
+    }
+    self
+      .dimensions
+      .iter()
+      .map(|(unit, exponent)| match exponent {
+        1 => unit.to_string(),
+        _ => format!("{}{}", unit, exponent),
+      })
+      .collect::<Vec<_>>()
+      .join(".")
+  }
+
+  /// Converts a value in this unit to canonical units.
+  pub fn to_canonical(&self, value: f64) -> f64 {
+    value * self.factor + self.offset.unwrap_or(0.0)
+  }
+
+  /// Converts a value in canonical units to this unit.
+  pub fn from_canonical(&self, value: f64) -> f64 {
+    (value - self.offset.unwrap_or(0.0)) / self.factor
+  }
+
+  /// Converts a value in this unit to `target`.
+  pub fn convert(&self, value: f64, target: &Unit) -> Result<f64, UcumError> {
+    if !self.is_compatible(target) {
+      return Err(UcumError::Incompatible {
+        from: self.code.clone(),
+        to: target.code.clone(),
+      });
+    }
+    Ok(target.from_canonical(self.to_canonical(value)))
+  }
+
+  fn multiply(mut self, other: Unit, exponent: i32) -> Unit {
+    self.factor *= other.factor.powi(exponent);
+    for (unit, e) in other.dimensions {
+      let total = self.dimensions.get(unit).copied().unwrap_or(0) + e * exponent;
+      if total == 0 {
+        self.dimensions.remove(unit);
+      } else {
+        self.dimensions.insert(unit, total);
+      }
+    }
+    self
+  }
+}
+
+fn invalid(code: &str, message: &str) -> UcumError {
+  UcumError::InvalidUnit {
+    unit: code.to_string(),
+    message: message.to_string(),
+  }
+}
+
+fn parse_expression(code: &str, depth: usize) -> Result<Unit, UcumError> {
+  if depth > MAX_DEPTH {
+    return Err(invalid(code, "Unit definitions are nested too deeply"));
+  }
+  if code.is_empty() {
+    return Err(invalid(code, "Empty unit"));
+  }
+  let mut parser = Parser {
+    code,
+    pos: 0,
+    depth,
+    components: 0,
+  };
+  let unit = parser.term()?;
+  if parser.pos < code.len() {
+    return Err(invalid(
+      code,
+      &format!("Unexpected '{}'", &code[parser.pos..]),
+    ));
+  }
+  if unit.offset.is_some() && parser.components > 1 {
+    return Err(invalid(
+      code,
+      "Temperature scales cannot be combined with other units",
+    ));
+  }
+  Ok(unit)
+}
+
+struct Parser<'a> {
+  code: &'a str,
+  pos: usize,
+  depth: usize,
+  components: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn peek(&self) -> Option<char> {
+    self.code[self.pos..].chars().next()
+  }
+
+  fn term(&mut self) -> Result<Unit, UcumError> {
+    let mut unit = if self.peek() == Some('/') {
+      self.pos += 1;
+      let unit = self.component()?;
+      if unit.offset.is_some() {
+        return Err(invalid(
+          self.code,
+          "Temperature scales cannot be combined with other units",
+        ));
+      }
+      Unit::dimensionless(1.0).multiply(unit, -1)
+    } else {
+      self.component()?
+    };
+    while let Some(operator) = self.peek() {
+      let exponent = match operator {
+        '.' => 1,
+        '/' => -1,
+        _ => break,
+      };
+      self.pos += 1;
+      let next = self.component()?;
+      if unit.offset.is_some() || next.offset.is_some() {
+        return Err(invalid(
+          self.code,
+          "Temperature scales cannot be combined with other units",
+        ));
+      }
+      unit = unit.multiply(next, exponent);
+    }
+    Ok(unit)
+  }
+
+  fn component(&mut self) -> Result<Unit, UcumError> {
+    self.components += 1;
+    let unit = match self.peek() {
+      Some('(') => {
+        self.pos += 1;
+        let unit = self.term()?;
+        if self.peek() != Some(')') {
+          return Err(invalid(self.code, "Missing ')'"));
+        }
+        self.pos += 1;
+        unit
+      }
+      Some('{') => {
+        self.annotation()?;
+        return Ok(Unit::dimensionless(1.0));
+      }
+      Some(c) if c.is_ascii_digit() => self.number()?,
+      Some(_) => self.annotatable()?,
+      None => return Err(invalid(self.code, "Expected a unit")),
+    };
+    if self.peek() == Some('{') {
+      self.annotation()?;
+    }
+    Ok(unit)
+  }
+
+  fn annotation(&mut self) -> Result<(), UcumError> {
+    match self.code[self.pos..].find('}') {
+      Some(end) => {
+        self.pos += end + 1;
+        Ok(())
+      }
+      None => Err(invalid(self.code, "Unterminated annotation")),
+    }
+  }
+
+  /// An integer factor, or the special units `10*` and `10^`.
+  fn number(&mut self) -> Result<Unit, UcumError> {
+    let rest = &self.code[self.pos..];
+    let end = rest
+      .find(|c: char| !c.is_ascii_digit())
+      .unwrap_or(rest.len());
+    let digits = &rest[..end];
+    if digits == "10" && (rest[end..].starts_with('*') || rest[end..].starts_with('^')) {
+      return self.annotatable();
+    }
+    self.pos += end;
+    match digits.parse::<f64>() {
+      Ok(factor) => Ok(Unit::dimensionless(factor)),
+      Err(_) => Err(invalid(self.code, "Invalid number")),
+    }
+  }
+
+  fn annotatable(&mut self) -> Result<Unit, UcumError> {
+    let rest = &self.code[self.pos..];
+    let mut end = 0;
+    if rest.starts_with("10*") || rest.starts_with("10^") {
+      end = 3;
+    } else {
+      while end < rest.len() {
+        let c = rest[end..].chars().next().unwrap();
+        if c == '[' {
+          match rest[end..].find(']') {
+            Some(close) => end += close + 1,
+            None => return Err(invalid(self.code, "Missing ']'")),
+          }
+          continue;
+        }
+        if c.is_ascii_digit() || "+-./(){}".contains(c) {
+          break;
+        }
+        end += c.len_utf8();
+      }
+    }
+    if end == 0 {
+      return Err(invalid(self.code, "Expected a unit"));
+    }
+    let symbol = &rest[..end];
+    self.pos += end;
+    let exponent = self.exponent()?;
+    let unit = self.symbol(symbol)?;
+    if unit.offset.is_some() {
+      if exponent != 1 {
+        return Err(invalid(
+          self.code,
+          "Temperature scales cannot have an exponent",
+        ));
+      }
+      return Ok(unit);
+    }
+    Ok(Unit::dimensionless(1.0).multiply(unit, exponent))
+  }
+
+  fn exponent(&mut self) -> Result<i32, UcumError> {
+    let rest = &self.code[self.pos..];
+    let sign_length = if rest.starts_with('+') || rest.starts_with('-') {
+      1
+    } else {
+      0
+    };
+    let end = rest[sign_length..]
+      .find(|c: char| !c.is_ascii_digit())
+      .map(|e| e + sign_length)
+      .unwrap_or(rest.len());
+    if end == sign_length {
+      if sign_length == 1 {
+        return Err(invalid(self.code, "Expected an exponent"));
+      }
+      return Ok(1);
+    }
+    self.pos += end;
+    rest[..end]
+      .parse::<i32>()
+      .map_err(|_| invalid(self.code, "Invalid exponent"))
+  }
+
+  /// Resolves a unit symbol, with or without a prefix.
+  fn symbol(&self, symbol: &str) -> Result<Unit, UcumError> {
+    if let Some(atom) = table::atom(symbol) {
+      return self.atom(atom);
+    }
+    for length in &[2, 1] {
+      if symbol.len() <= *length || !symbol.is_char_boundary(*length) {
+        continue;
+      }
+      let (prefix, rest) = symbol.split_at(*length);
+      if let (Some(factor), Some(atom)) = (table::prefix(prefix), table::atom(rest)) {
+        if atom.metric {
+          let mut unit = self.atom(atom)?;
+          unit.factor *= factor;
+          return Ok(unit);
+        }
+      }
+    }
+    Err(invalid(self.code, &format!("Unknown unit '{}'", symbol)))
+  }
+
+  fn atom(&self, atom: &'static table::UnitAtom) -> Result<Unit, UcumError> {
+    match atom.definition {
+      Definition::Base | Definition::Arbitrary => {
+        let mut unit = Unit::dimensionless(1.0);
+        unit.dimensions.insert(atom.code, 1);
+        Ok(unit)
+      }
+      Definition::Scaled(factor, expression) => {
+        let mut unit = parse_expression(expression, self.depth + 1)?;
+        unit.factor *= factor;
+        Ok(unit)
+      }
+      Definition::Offset(factor, offset) => {
+        let mut unit = Unit::dimensionless(factor);
+        unit.dimensions.insert("K", 1);
+        unit.offset = Some(offset);
+        Ok(unit)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(b.abs())
+  }
+
+  #[test]
+  fn test_parse_units() {
+    let mg_dl = Unit::parse("mg/dL").unwrap();
+    assert_eq!(mg_dl.canonical_code(), "g.m-3");
+    assert!(close(mg_dl.factor, 10.0));
+
+    let newton = Unit::parse("kg.m/s2").unwrap();
+    assert_eq!(newton.canonical_code(), "g.m.s-2");
+    assert!(close(newton.factor, 1000.0));
+    assert!(newton.is_compatible(&Unit::parse("N").unwrap()));
+
+    let cells = Unit::parse("10*3/uL{cells}").unwrap();
+    assert_eq!(cells.canonical_code(), "m-3");
+    assert!(close(cells.factor, 1e12));
+
+    assert!(close(Unit::parse("mm[Hg]").unwrap().factor, 133_322.0));
+    assert_eq!(Unit::parse("%").unwrap().canonical_code(), "1");
+    assert_eq!(Unit::parse("[IU]/mL").unwrap().canonical_code(), "[iU].m-3");
+    assert_eq!(Unit::parse("/min").unwrap().canonical_code(), "s-1");
+    assert_eq!(Unit::parse("{beats}/min").unwrap().canonical_code(), "s-1");
+
+    assert!(Unit::parse("foo").is_err());
+    assert!(Unit::parse("mg/").is_err());
+    assert!(Unit::parse("Cel/s").is_err());
+    assert!(Unit::parse("[degF]2").is_err());
+  }
+
+  #[test]
+  fn test_convert_temperatures() {
+    let celsius = Unit::parse("Cel").unwrap();
+    let fahrenheit = Unit::parse("[degF]").unwrap();
+    let kelvin = Unit::parse("K").unwrap();
+    assert!(close(celsius.convert(100.0, &fahrenheit).unwrap(), 212.0));
+    assert!(close(fahrenheit.convert(32.0, &kelvin).unwrap(), 273.15));
+    assert!(celsius.convert(1.0, &Unit::parse("m").unwrap()).is_err());
+  }
+}
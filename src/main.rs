@@ -1,7 +1,7 @@
 use inflector::Inflector;
 use maplit::hashmap;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::prelude::*;
@@ -226,6 +226,19 @@ fn main() {
   }
 
   write_string_to_file(&model_mod_contents, "out/src/model/mod.rs");
+
+  let resource_types_contents = generate_resource_types(&resource_types);
+  write_string_to_file(&resource_types_contents, "out/src/rest/resource_types.rs");
+
+  let element_types = generate_element_types(&fhir_schema);
+  write_string_to_file(&element_types, "out/src/elements/element_types.rs");
+
+  let search_parameter_table = generate_search_parameters("out/examples-json/search-parameters.json");
+  write_string_to_file(&search_parameter_table, "out/src/index/search_parameters.rs");
+
+  let search_parameters = search_parameter_codes("out/examples-json/search-parameters.json");
+  let graphql_schema = generate_graphql_schema(&fhir_schema, &resource_types, &search_parameters);
+  write_string_to_file(&graphql_schema, "out/schema.graphql");
 }
 
 fn write_string_to_file(contents: &str, path_string: &str) -> bool {
@@ -1106,3 +1119,401 @@ fn extract_type_from_ref(fhir_ref: &str) -> &str {
   let split: Vec<&str> = fhir_ref.split("/").collect();
   return split[2];
 }
+
+// The search parameter codes of each base type in a Bundle of
+// SearchParameters, used as the arguments of the GraphQL list queries.
+fn search_parameter_codes(path_string: &str) -> BTreeMap<String, BTreeSet<String>> {
+  let mut codes: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+  let contents = match fs::read_to_string(path_string) {
+    Ok(contents) => contents,
+    Err(why) => {
+      println!("! couldn't read {}: {}", path_string, why);
+      return codes;
+    }
+  };
+  let bundle: serde_json::Value = serde_json::from_str(&contents).unwrap();
+  if let Some(entries) = bundle["entry"].as_array() {
+    for entry in entries {
+      let resource = &entry["resource"];
+      if let (Some(code), Some(bases)) = (resource["code"].as_str(), resource["base"].as_array()) {
+        for base in bases.iter().filter_map(|base| base.as_str()) {
+          codes
+            .entry(base.to_string())
+            .or_insert_with(BTreeSet::new)
+            .insert(code.to_string());
+        }
+      }
+    }
+  }
+  return codes;
+}
+
+// The table of indexable SearchParameters that out/src/index/mod.rs includes,
+// so the library does not embed the whole definitions Bundle. Composite and
+// special parameters and those without an expression are left out.
+fn generate_search_parameters(path_string: &str) -> String {
+  let contents = match fs::read_to_string(path_string) {
+    Ok(contents) => contents,
+    Err(why) => {
+      println!("! couldn't read {}: {}", path_string, why);
+      return String::new();
+    }
+  };
+  let bundle: serde_json::Value = serde_json::from_str(&contents).unwrap();
+
+  let mut string = String::new();
+  string.push_str("// Generated from examples-json/search-parameters.json by fhir-rs-parser. Do not edit.\n\n");
+  string.push_str("/// `(code, base, type, expression)` of the SearchParameters of the FHIR\n");
+  string.push_str("/// specification that can be indexed.\n");
+  string.push_str("pub(crate) const SEARCH_PARAMETERS: &[(&str, &[&str], &str, &str)] = &[\n");
+  for entry in bundle["entry"].as_array().into_iter().flatten() {
+    let resource = &entry["resource"];
+    if resource["resourceType"] != "SearchParameter" {
+      continue;
+    }
+    let (code, bases, fhir_type, expression) = match (
+      resource["code"].as_str(),
+      resource["base"].as_array(),
+      resource["type"].as_str(),
+      resource["expression"].as_str(),
+    ) {
+      (Some(code), Some(bases), Some(fhir_type), Some(expression)) => {
+        (code, bases, fhir_type, expression)
+      }
+      _ => continue,
+    };
+    if fhir_type == "composite" || fhir_type == "special" {
+      continue;
+    }
+    let bases: Vec<String> = bases
+      .iter()
+      .filter_map(|base| base.as_str())
+      .map(|base| format!("{:?}", base))
+      .collect();
+    string.push_str(&format!(
+      "    ({:?}, &[{}], {:?}, {:?}),\n",
+      code,
+      bases.join(", "),
+      fhir_type,
+      expression
+    ));
+  }
+  string.push_str("];\n");
+  return string;
+}
+
+// The `resources!` invocation that out/src/rest/resource.rs includes, which
+// implements `Resource` for every resource type and lists them in
+// `RESOURCE_TYPES`.
+fn generate_resource_types(resource_types: &Vec<String>) -> String {
+  let mut sorted_resource_types = resource_types.clone();
+  sorted_resource_types.sort();
+
+  let mut string = String::new();
+  string.push_str("// Generated from src/fhir.schema.json by fhir-rs-parser. Do not edit.\n\n");
+  string.push_str("resources!(\n");
+  for (index, resource_type) in sorted_resource_types.iter().enumerate() {
+    string.push_str("  ");
+    string.push_str(resource_type);
+    if index + 1 < sorted_resource_types.len() {
+      string.push_str(",");
+    }
+    string.push_str("\n");
+  }
+  string.push_str(");\n");
+  return string;
+}
+
+// The choice elements of a definition: the properties that share a prefix
+// followed by a type name, as `deceasedBoolean` and `deceasedDateTime` do,
+// by prefix, with the property name and FHIR type of each member. Primitive
+// types are inlined in the schema, so only the name shows their type.
+fn choice_elements<'d>(
+  properties: &'d BTreeMap<String, Property>,
+  fhir_schema: &Schema,
+) -> BTreeMap<String, Vec<(&'d String, String)>> {
+  let mut choices: BTreeMap<String, Vec<(&String, String)>> = BTreeMap::new();
+  for (property_name, property) in properties {
+    let fhir_type = match property {
+      Property::Reference { fhir_ref, .. } => Some(extract_type_from_ref(&fhir_ref)),
+      Property::PatternedTyped { .. } | Property::Typed { .. } => None,
+      _ => continue,
+    };
+    for (index, c) in property_name.char_indices().skip(1) {
+      if !c.is_uppercase() {
+        continue;
+      }
+      let suffix = &property_name[index..];
+      let member = match fhir_type {
+        Some(fhir_type) if fhir_type == suffix || fhir_type == lower_first(suffix) => {
+          fhir_type.to_string()
+        }
+        None
+          if fhir_schema.definitions.contains_key(&lower_first(suffix))
+            && is_graphql_scalar(&lower_first(suffix), fhir_schema) =>
+        {
+          lower_first(suffix)
+        }
+        _ => continue,
+      };
+      choices
+        .entry(property_name[..index].to_string())
+        .or_insert_with(Vec::new)
+        .push((property_name, member));
+      break;
+    }
+  }
+  choices.retain(|_, members| members.len() > 1);
+  return choices;
+}
+
+// The table of element types that out/src/elements/mod.rs includes: the
+// names of the datatypes and resource types, and for every property of
+// every definition its element name, FHIR type and whether it repeats.
+fn generate_element_types(fhir_schema: &Schema) -> String {
+  let mut definition_names: Vec<&String> = fhir_schema.definitions.keys().collect();
+  definition_names.sort();
+
+  let mut string = String::new();
+  string.push_str("// Generated from src/fhir.schema.json by fhir-rs-parser. Do not edit.\n\n");
+  string.push_str("/// The names of the FHIR datatypes and resource types.\n");
+  string.push_str("pub(crate) const TYPE_NAMES: &[&str] = &[\n");
+  for name in &definition_names {
+    if !name.contains("_") && *name != "ResourceList" {
+      string.push_str(&format!("    \"{}\",\n", name));
+    }
+  }
+  string.push_str("];\n\n");
+
+  string.push_str("/// `(type, JSON key, element, element type, repeats)` for the elements of\n");
+  string.push_str("/// every type, sorted by type and key. The key of a choice element names\n");
+  string.push_str("/// its type, as `deceasedBoolean` does for the element `deceased`.\n");
+  string.push_str("pub(crate) const ELEMENTS: &[(&str, &str, &str, &str, bool)] = &[\n");
+  for name in &definition_names {
+    let properties = match &fhir_schema.definitions[*name].properties {
+      Some(properties) => properties,
+      None => continue,
+    };
+    let choices = choice_elements(properties, fhir_schema);
+    for (property_name, property) in properties {
+      if property_name.starts_with("_") {
+        continue;
+      }
+      let choice = choices.iter().find_map(|(prefix, members)| {
+        members
+          .iter()
+          .find(|(member, _)| *member == property_name)
+          .map(|(_, fhir_type)| (prefix.to_string(), fhir_type.to_string()))
+      });
+      let (element, fhir_type, repeats) = match (choice, property) {
+        (_, Property::Const { .. }) => continue,
+        (Some((prefix, fhir_type)), _) => (prefix, fhir_type, false),
+        (None, Property::Reference { fhir_ref, .. }) => (
+          property_name.to_string(),
+          extract_type_from_ref(&fhir_ref).to_string(),
+          false,
+        ),
+        (None, Property::Array { items, .. }) => {
+          let fhir_type = match items.get("$ref") {
+            Some(Item::Ref(item_ref)) => extract_type_from_ref(&item_ref).to_string(),
+            _ => "code".to_string(),
+          };
+          (property_name.to_string(), fhir_type, true)
+        }
+        (None, Property::Enum { .. }) => (property_name.to_string(), "code".to_string(), false),
+        (None, Property::PatternedTyped { fhir_type, .. })
+        | (None, Property::Typed { fhir_type, .. }) => {
+          let fhir_type = match fhir_type.as_str() {
+            "number" => "decimal",
+            other => other,
+          };
+          (property_name.to_string(), fhir_type.to_string(), false)
+        }
+      };
+      string.push_str(&format!(
+        "    (\"{}\", \"{}\", \"{}\", \"{}\", {}),\n",
+        name, property_name, element, fhir_type, repeats
+      ));
+    }
+  }
+  string.push_str("];\n");
+  return string;
+}
+
+// GraphQL SDL for the schema: an object type per definition, with choice
+// elements such as `valueQuantity` and `valueString` merged into a union
+// field `value`, a union `ResourceList` of all resources, and a `Query`
+// type with `[Type](id)` and `[Type]List(search parameters)` per resource.
+fn generate_graphql_schema(
+  fhir_schema: &Schema,
+  resource_types: &Vec<String>,
+  search_parameters: &BTreeMap<String, BTreeSet<String>>,
+) -> String {
+  let mut definition_names: Vec<&String> = fhir_schema.definitions.keys().collect();
+  definition_names.sort();
+  let mut sorted_resource_types = resource_types.clone();
+  sorted_resource_types.sort();
+
+  let mut string = String::new();
+  string.push_str("# Generated from src/fhir.schema.json by fhir-rs-parser. Do not edit.\n\n");
+  string.push_str("schema {\n  query: Query\n}\n\n");
+
+  string.push_str("type Query {\n");
+  for resource_type in &sorted_resource_types {
+    string.push_str(&format!("  {}(id: ID!): {}\n", resource_type, resource_type));
+    string.push_str(&format!("  {}List(_count: Int, _offset: Int", resource_type));
+    let mut codes: BTreeSet<&String> = BTreeSet::new();
+    let mut bases = vec![resource_type.as_str(), "Resource"];
+    if let Some(properties) = &fhir_schema.definitions[resource_type].properties {
+      if properties.contains_key("text") {
+        bases.push("DomainResource");
+      }
+    }
+    for base in bases {
+      if let Some(base_codes) = search_parameters.get(base) {
+        codes.extend(base_codes);
+      }
+    }
+    for code in codes {
+      string.push_str(", ");
+      string.push_str(&code.replace("-", "_"));
+      string.push_str(": String");
+    }
+    string.push_str(&format!("): [{}!]!\n", resource_type));
+  }
+  string.push_str("}\n\n");
+
+  string.push_str("union ResourceList =");
+  for resource_type in &sorted_resource_types {
+    string.push_str(" | ");
+    string.push_str(resource_type);
+  }
+  string.push_str("\n\n");
+
+  // Primitive types are scalars, and are wrapped in an object type where
+  // they are members of a choice union.
+  for name in &definition_names {
+    if is_graphql_scalar(name, fhir_schema) {
+      string.push_str(&format!(
+        "type {}Value {{\n  value: {}\n}}\n\n",
+        upper_first(name),
+        graphql_type_from_fhir_type(name, fhir_schema)
+      ));
+    }
+  }
+
+  for name in &definition_names {
+    string.push_str(&generate_graphql_type(
+      name,
+      &fhir_schema.definitions[*name],
+      fhir_schema,
+    ));
+  }
+
+  return string;
+}
+
+fn generate_graphql_type(name: &str, definition: &Definition, fhir_schema: &Schema) -> String {
+  let properties = match &definition.properties {
+    Some(properties) => properties,
+    None => return String::new(),
+  };
+
+  let choices = choice_elements(properties, fhir_schema);
+  let choice_of = |property_name: &str| {
+    choices
+      .iter()
+      .find(|(_, members)| members.iter().any(|(member, _)| *member == property_name))
+  };
+
+  let mut string = String::new();
+  let mut unions = String::new();
+  string.push_str("type ");
+  string.push_str(name);
+  string.push_str(" {\n");
+  for (property_name, property) in properties {
+    if property_name.starts_with("_") {
+      continue;
+    }
+    if let Some((prefix, members)) = choice_of(property_name) {
+      if members[0].0 != property_name {
+        continue;
+      }
+      let union_name = format!("{}{}", name, upper_first(prefix));
+      string.push_str(&format!("  {}: {}\n", prefix, union_name));
+      unions.push_str("union ");
+      unions.push_str(&union_name);
+      unions.push_str(" =");
+      for (_, fhir_type) in members {
+        // Primitives are wrapped in an object type, e.g. `BooleanValue`.
+        unions.push_str(" | ");
+        if is_graphql_scalar(fhir_type, fhir_schema) {
+          unions.push_str(&format!("{}Value", upper_first(fhir_type)));
+        } else {
+          unions.push_str(fhir_type);
+        }
+      }
+      unions.push_str("\n\n");
+      continue;
+    }
+    let graphql_type = match property {
+      Property::Reference { fhir_ref, .. } => {
+        graphql_type_from_fhir_type(extract_type_from_ref(&fhir_ref), fhir_schema)
+      }
+      Property::Array { items, .. } => match items.get("$ref") {
+        Some(Item::Ref(item_ref)) => format!(
+          "[{}!]",
+          graphql_type_from_fhir_type(extract_type_from_ref(&item_ref), fhir_schema)
+        ),
+        _ => "[String!]".to_string(),
+      },
+      Property::PatternedTyped { fhir_type, .. } | Property::Typed { fhir_type, .. } => {
+        graphql_type_from_fhir_type(&fhir_type, fhir_schema)
+      }
+      Property::Enum { .. } => "String".to_string(),
+      Property::Const { .. } => continue,
+    };
+    string.push_str(&format!("  {}: {}\n", property_name, graphql_type));
+  }
+  if name == "Reference" {
+    // Resolved by the executor, see `graphql::Executor`.
+    string.push_str("  resource: ResourceList\n");
+  }
+  string.push_str("}\n\n");
+  string.push_str(&unions);
+  return string;
+}
+
+fn is_graphql_scalar(fhir_type: &str, fhir_schema: &Schema) -> bool {
+  match fhir_schema.definitions.get(fhir_type) {
+    Some(definition) => definition.properties.is_none() && definition.one_of.is_none(),
+    None => true,
+  }
+}
+
+fn graphql_type_from_fhir_type(fhir_type: &str, fhir_schema: &Schema) -> String {
+  let graphql_type = match fhir_type {
+    "boolean" => "Boolean",
+    "int" | "integer" | "positiveInt" | "unsignedInt" => "Int",
+    "number" | "decimal" => "Float",
+    _ if is_graphql_scalar(fhir_type, fhir_schema) => "String",
+    _ => fhir_type,
+  };
+  return graphql_type.to_string();
+}
+
+fn upper_first(string: &str) -> String {
+  let mut chars = string.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+fn lower_first(string: &str) -> String {
+  let mut chars = string.chars();
+  match chars.next() {
+    Some(first) => first.to_lowercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}